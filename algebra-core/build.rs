@@ -26,4 +26,17 @@ fn main() {
         fs::write(&dest_path, generate_macro_string(NUM_LIMBS)).unwrap();
         println!("cargo:rustc-cfg=use_asm");
     }
+
+    // Unlike the assembly, the intrinsics build on stable. The target's
+    // features are read from the environment, as cfg! in a build script
+    // describes the host.
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has_target_feature = |f: &str| target_features.split(',').any(|g| g == f);
+    let should_use_intrinsics = env::var_os("CARGO_FEATURE_X86_INTRINSICS").is_some()
+        && env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("x86_64")
+        && has_target_feature("bmi2")
+        && has_target_feature("adx");
+    if should_use_intrinsics {
+        println!("cargo:rustc-cfg=use_intrinsics");
+    }
 }
@@ -282,13 +282,15 @@ macro_rules! bigint_impl {
         }
 
         impl core::convert::TryFrom<&'_ [u64]> for $name {
-            type Error = std::array::TryFromSliceError;
+            type Error = core::array::TryFromSliceError;
             #[inline]
-            fn try_from(val: &[u64]) -> Result<$name,std::array::TryFromSliceError> {
+            fn try_from(val: &[u64]) -> Result<$name,core::array::TryFromSliceError> {
                 <[u64; $num_limbs]>::try_from(val).map($name::from)
             }
         }
 
+        impl_serde!(impl<> for $name);
+
         #[cfg(feature = "ocaml_types")]
         unsafe impl ocaml::FromValue for $name {
             fn from_value(value: ocaml::Value) -> Self {
@@ -5,9 +5,9 @@ use crate::{
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
         AffineCurve,
     },
+    io::{Result as IoResult, Write},
 };
 use derivative::Derivative;
-use std::io::{Result as IoResult, Write};
 use num_traits::Zero;
 
 pub type G1Affine<P> = GroupAffine<<P as BnParameters>::G1Parameters>;
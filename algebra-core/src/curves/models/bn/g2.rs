@@ -6,12 +6,11 @@ use crate::{
         AffineCurve,
     },
     fields::{fp6_3over2::Fp6Parameters, Field, Fp2},
-};
-use derivative::Derivative;
-use std::{
     io::{Result as IoResult, Write},
-    ops::{AddAssign, MulAssign, Neg, SubAssign},
+    Vec,
 };
+use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use derivative::Derivative;
 use num_traits::One;
 
 pub type G2Affine<P> = GroupAffine<<P as BnParameters>::G2Parameters>;
@@ -13,7 +13,7 @@ use crate::{
 use derivative::Derivative;
 use num_traits::One;
 
-use std::{marker::PhantomData, ops::MulAssign};
+use core::{marker::PhantomData, ops::MulAssign};
 
 pub mod g1;
 pub mod g2;
@@ -68,42 +68,13 @@ impl<P: BnParameters> Bn<P> {
     fn exp_by_x(f: &mut Fp12<P::Fp12Params>) {
         *f = f.pow(&P::U);
     }
-}
-
-impl<P: BnParameters> PairingEngine for Bn<P>
-/*
-where
-    G1Affine<P>: PairingCurve<
-        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
-        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
-        Projective = G1Projective<P>,
-        PairWith = G2Affine<P>,
-        Prepared = G1Prepared<P>,
-        PairingResult = Fp12<P::Fp12Params>,
-    >,
-    G2Affine<P>: PairingCurve<
-        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
-        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
-        Projective = G2Projective<P>,
-        PairWith = G1Affine<P>,
-        Prepared = G2Prepared<P>,
-        PairingResult = Fp12<P::Fp12Params>,
-    >, */
-{
-    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
-    type G1Projective = G1Projective<P>;
-    type G1Affine = G1Affine<P>;
-    type G1Prepared = G1Prepared<P>;
-    type G2Projective = G2Projective<P>;
-    type G2Affine = G2Affine<P>;
-    type G2Prepared = G2Prepared<P>;
-    type Fq = P::Fp;
-    type Fqe = Fp2<P::Fp2Params>;
-    type Fqk = Fp12<P::Fp12Params>;
 
-    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    /// Performs a Miller loop over pairs of borrowed prepared elements, so
+    /// that prepared G2 elements can be kept and shared between loops instead
+    /// of being cloned into each table.
+    pub fn miller_loop_prepared<'a, I>(i: I) -> Fp12<P::Fp12Params>
     where
-        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+        I: IntoIterator<Item = (&'a G1Prepared<P>, &'a G2Prepared<P>)>,
     {
         let mut pairs = vec![];
         for (p, q) in i {
@@ -112,7 +83,7 @@ where
             }
         }
 
-        let mut f = Self::Fqk::one();
+        let mut f = Fp12::<P::Fp12Params>::one();
 
         for i in (1..P::SIX_U_PLUS_2_NAF.len()).rev() {
             if i != P::SIX_U_PLUS_2_NAF.len() - 1 {
@@ -154,6 +125,54 @@ where
         f
     }
 
+    /// Computes a product of pairings over borrowed prepared elements, as
+    /// `PairingEngine::product_of_pairings` does.
+    pub fn product_of_prepared_pairings<'a, I>(i: I) -> Fp12<P::Fp12Params>
+    where
+        I: IntoIterator<Item = (&'a G1Prepared<P>, &'a G2Prepared<P>)>,
+    {
+        Self::final_exponentiation(&Self::miller_loop_prepared(i)).unwrap()
+    }
+}
+
+impl<P: BnParameters> PairingEngine for Bn<P>
+/*
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp12<P::Fp12Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp12<P::Fp12Params>,
+    >, */
+{
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Projective = G1Projective<P>;
+    type G1Affine = G1Affine<P>;
+    type G1Prepared = G1Prepared<P>;
+    type G2Projective = G2Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Prepared = G2Prepared<P>;
+    type Fq = P::Fp;
+    type Fqe = Fp2<P::Fp2Params>;
+    type Fqk = Fp12<P::Fp12Params>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        Self::miller_loop_prepared(i.into_iter().map(|(p, q)| (p, q)))
+    }
+
     fn final_exponentiation(r: &Self::Fqk) -> Option<Self::Fqk> {
         let mut f1 = *r;
         f1.conjugate();
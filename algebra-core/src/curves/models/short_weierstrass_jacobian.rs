@@ -202,12 +202,19 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
         })
     }
 
+    #[cfg(not(feature = "constant_time"))]
     #[inline]
     fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> GroupProjective<P> {
         let bits = BitIterator::new(by.into());
         self.mul_bits(bits)
     }
 
+    #[cfg(feature = "constant_time")]
+    #[inline]
+    fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> GroupProjective<P> {
+        self.into_projective().mul_constant_time(by)
+    }
+
     fn mul_by_cofactor(&self) -> Self {
         self.scale_by_cofactor().into()
     }
@@ -442,6 +449,17 @@ impl<P: Parameters> Display for GroupProjective<P> {
 }
 
 impl<P: Parameters> PartialEq for GroupProjective<P> {
+    #[cfg(feature = "constant_time")]
+    fn eq(&self, other: &Self) -> bool {
+        let (zero1, zero2) = (self.is_zero(), other.is_zero());
+        let z1 = self.z.square();
+        let z2 = other.z.square();
+        let x = self.x * &z2 == other.x * &z1;
+        let y = self.y * &(z2 * &other.z) == other.y * &(z1 * &self.z);
+        (zero1 & zero2) | (!zero1 & !zero2 & x & y)
+    }
+
+    #[cfg(not(feature = "constant_time"))]
     fn eq(&self, other: &Self) -> bool {
         if self.is_zero() {
             return other.is_zero();
@@ -510,45 +528,110 @@ impl<P: Parameters> GroupProjective<P> {
             _params: PhantomData,
         }
     }
-}
 
-impl<P: Parameters> Zero for GroupProjective<P> {
-    // The point at infinity is always represented by
-    // Z = 0.
-    #[inline]
-    fn zero() -> Self {
-        Self::new(
-            P::BaseField::one(),
+    /// self + other with the complete addition formulas of Renes, Costello
+    /// and Batina (https://eprint.iacr.org/2015/1060, Algorithm 1), in
+    /// homogeneous projective coordinates. The same field operations are
+    /// performed whatever the points, including for doublings, inverses and
+    /// the point at infinity.
+    pub fn add_complete(&self, other: &Self) -> Self {
+        Self::from_homogeneous(Self::add_homogeneous(
+            self.to_homogeneous(),
+            other.to_homogeneous(),
+        ))
+    }
+
+    /// self * by, as a double-and-add over every bit of by, including the
+    /// leading zeros, with complete additions and a masked selection of
+    /// the sum, so that neither the sequence of field operations nor the
+    /// memory accesses depend on by.
+    pub fn mul_constant_time<S: Into<<P::ScalarField as PrimeField>::BigInt>>(
+        &self,
+        by: S,
+    ) -> Self {
+        let base = self.to_homogeneous();
+        let mut res = (
+            P::BaseField::zero(),
             P::BaseField::one(),
             P::BaseField::zero(),
-        )
+        );
+        for bit in BitIterator::new(by.into()) {
+            res = Self::add_homogeneous(res, res);
+            let sum = Self::add_homogeneous(res, base);
+            res = (
+                P::BaseField::conditional_select(&res.0, &sum.0, bit),
+                P::BaseField::conditional_select(&res.1, &sum.1, bit),
+                P::BaseField::conditional_select(&res.2, &sum.2, bit),
+            );
+        }
+        Self::from_homogeneous(res)
     }
 
-    // The point at infinity is always represented by
-    // Z = 0.
-    #[inline]
-    fn is_zero(&self) -> bool {
-        self.z.is_zero()
+    // (X, Y, Z) in Jacobian coordinates is (XZ, Y, Z^3) in homogeneous ones.
+    fn to_homogeneous(&self) -> (P::BaseField, P::BaseField, P::BaseField) {
+        (self.x * &self.z, self.y, self.z.square() * &self.z)
     }
-}
-
-impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
-    type BaseField = P::BaseField;
-    type ScalarField = P::ScalarField;
-    type Affine = GroupAffine<P>;
 
-    #[inline]
-    fn prime_subgroup_generator() -> Self {
-        GroupAffine::prime_subgroup_generator().into()
+    // (X, Y, Z) in homogeneous coordinates is (XZ, YZ^2, Z) in Jacobian ones,
+    // except for the point at infinity, which is selected rather than
+    // branched on.
+    fn from_homogeneous(p: (P::BaseField, P::BaseField, P::BaseField)) -> Self {
+        let (x, y, z) = p;
+        let zz = z.square();
+        let infinity = z.is_zero();
+        let zero = Self::zero();
+        Self::new(
+            P::BaseField::conditional_select(&(x * &z), &zero.x, infinity),
+            P::BaseField::conditional_select(&(y * &zz), &zero.y, infinity),
+            z,
+        )
     }
 
-    #[inline]
-    fn is_normalized(&self) -> bool {
-        self.is_zero() || self.z.is_one()
+    fn add_homogeneous(
+        p1: (P::BaseField, P::BaseField, P::BaseField),
+        p2: (P::BaseField, P::BaseField, P::BaseField),
+    ) -> (P::BaseField, P::BaseField, P::BaseField) {
+        let (x1, y1, z1) = p1;
+        let (x2, y2, z2) = p2;
+        let b3 = P::COEFF_B.double() + &P::COEFF_B;
+
+        let mut t0 = x1 * &x2;
+        let mut t1 = y1 * &y2;
+        let mut t2 = z1 * &z2;
+        let mut t3 = (x1 + &y1) * &(x2 + &y2);
+        let mut t4 = t0 + &t1;
+        t3 -= &t4;
+        t4 = (x1 + &z1) * &(x2 + &z2);
+        let mut t5 = t0 + &t2;
+        t4 -= &t5;
+        t5 = (y1 + &z1) * &(y2 + &z2);
+        let mut x3 = t1 + &t2;
+        t5 -= &x3;
+        let mut z3 = P::mul_by_a(&t4);
+        x3 = b3 * &t2;
+        z3 += &x3;
+        x3 = t1 - &z3;
+        z3 += &t1;
+        let mut y3 = x3 * &z3;
+        t1 = t0.double() + &t0;
+        t2 = P::mul_by_a(&t2);
+        t4 *= &b3;
+        t1 += &t2;
+        t2 = P::mul_by_a(&(t0 - &t2));
+        t4 += &t2;
+        t0 = t1 * &t4;
+        y3 += &t0;
+        t0 = t5 * &t4;
+        x3 *= &t3;
+        x3 -= &t0;
+        t0 = t3 * &t1;
+        z3 *= &t5;
+        z3 += &t0;
+        (x3, y3, z3)
     }
 
-    #[inline]
-    fn batch_normalization(v: &mut [Self]) {
+    // Montgomery's trick over the whole slice, with a single inversion.
+    fn batch_normalization_serial(v: &mut [Self]) {
         // Montgomery’s Trick and Fast Implementation of Masked AES
         // Genelle, Prouff and Quisquater
         // Section 3.2
@@ -595,6 +678,66 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             g.z = P::BaseField::one(); // z = 1
         });
     }
+}
+
+impl<P: Parameters> Zero for GroupProjective<P> {
+    // The point at infinity is always represented by
+    // Z = 0.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(
+            P::BaseField::one(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+        )
+    }
+
+    // The point at infinity is always represented by
+    // Z = 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Affine = GroupAffine<P>;
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        GroupAffine::prime_subgroup_generator().into()
+    }
+
+    #[cfg(feature = "constant_time")]
+    #[inline]
+    fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(self, other: S) -> Self {
+        self.mul_constant_time(other)
+    }
+
+    #[inline]
+    fn is_normalized(&self) -> bool {
+        self.is_zero() || self.z.is_one()
+    }
+
+    #[inline]
+    fn batch_normalization(v: &mut [Self]) {
+        // With the parallel feature, large slices are split into one chunk per
+        // thread, at the cost of one inversion per chunk.
+        #[cfg(feature = "parallel")]
+        {
+            const MIN_CHUNK: usize = 1 << 12;
+            let threads = rayon::current_num_threads();
+            let chunk = (v.len() + threads - 1) / threads;
+            if threads > 1 && chunk >= MIN_CHUNK {
+                v.par_chunks_mut(chunk)
+                    .for_each(|c| Self::batch_normalization_serial(c));
+                return;
+            }
+        }
+        Self::batch_normalization_serial(v)
+    }
 
     fn double_in_place(&mut self) -> &mut Self {
         if self.is_zero() {
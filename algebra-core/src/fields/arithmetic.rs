@@ -7,7 +7,6 @@
 macro_rules! impl_field_mul_assign {
     ($limbs:expr) => {
         #[inline]
-        #[unroll_for_loops]
         fn mul_assign(&mut self, other: &Self) {
             // Checking the modulus at compile time
             let first_bit_set = P::MODULUS.0[$limbs - 1] >> 63 != 0;
@@ -17,7 +16,6 @@ macro_rules! impl_field_mul_assign {
             }
             let _no_carry: bool = !(first_bit_set || all_bits_set);
 
-            // No-carry optimisation applied to CIOS
             if _no_carry {
                 #[cfg(use_asm)]
                 #[allow(unsafe_code, unused_mut)]
@@ -28,6 +26,43 @@ macro_rules! impl_field_mul_assign {
                         return;
                     }
                 }
+                #[cfg(use_intrinsics)]
+                {
+                    if $limbs == 6 {
+                        intrinsics::mul_assign(
+                            &mut (self.0).0,
+                            &(other.0).0,
+                            &P::MODULUS.0,
+                            P::INV,
+                        );
+                        self.reduce();
+                        return;
+                    }
+                }
+            }
+            self.mul_assign_portable(other);
+        }
+    };
+}
+
+/// The multiplication without the assembly and intrinsics backends, which the
+/// tests of those backends compare against.
+macro_rules! impl_field_mul_assign_portable {
+    ($limbs:expr) => {
+        #[doc(hidden)]
+        #[inline(always)]
+        #[unroll_for_loops]
+        pub fn mul_assign_portable(&mut self, other: &Self) {
+            // Checking the modulus at compile time
+            let first_bit_set = P::MODULUS.0[$limbs - 1] >> 63 != 0;
+            let mut all_bits_set = P::MODULUS.0[$limbs - 1] == !0 - (1 << 63);
+            for i in 1..$limbs {
+                all_bits_set &= P::MODULUS.0[$limbs - i - 1] == !0u64;
+            }
+            let _no_carry: bool = !(first_bit_set || all_bits_set);
+
+            // No-carry optimisation applied to CIOS
+            if _no_carry {
                 let mut r = [0u64; $limbs];
                 let mut carry1 = 0u64;
                 let mut carry2 = 0u64;
@@ -69,7 +104,7 @@ macro_rules! impl_field_mul_assign {
                     _carry2 = carry;
                 }
                 (self.0).0.copy_from_slice(&r[$limbs..]);
-                self.reduce();
+                self.reduce_with_carry(_carry2 != 0);
             }
         }
     };
@@ -103,7 +138,6 @@ macro_rules! impl_field_into_repr {
 macro_rules! impl_field_square_in_place {
     ($limbs: expr) => {
         #[inline]
-        #[unroll_for_loops]
         #[allow(unused_braces)]
         fn square_in_place(&mut self) -> &mut Self {
             // Checking the modulus at compile time
@@ -123,6 +157,29 @@ macro_rules! impl_field_square_in_place {
                     return self;
                 }
             }
+            #[cfg(use_intrinsics)]
+            {
+                if $limbs == 6 && _no_carry {
+                    let a = (self.0).0;
+                    intrinsics::mul_assign(&mut (self.0).0, &a, &P::MODULUS.0, P::INV);
+                    self.reduce();
+                    return self;
+                }
+            }
+            self.square_in_place_portable();
+            self
+        }
+    };
+}
+
+/// The squaring without the assembly and intrinsics backends, which the tests
+/// of those backends compare against.
+macro_rules! impl_field_square_in_place_portable {
+    ($limbs: expr) => {
+        #[doc(hidden)]
+        #[inline(always)]
+        #[unroll_for_loops]
+        pub fn square_in_place_portable(&mut self) {
             let mut r = [0u64; $limbs * 2];
 
             let mut carry = 0;
@@ -169,8 +226,7 @@ macro_rules! impl_field_square_in_place {
                 _carry2 = carry;
             }
             (self.0).0.copy_from_slice(&r[$limbs..]);
-            self.reduce();
-            self
+            self.reduce_with_carry(_carry2 != 0);
         }
     };
 }
@@ -9,9 +9,9 @@ macro_rules! impl_Fp {
             Clone(bound = ""),
             Copy(bound = ""),
             Debug(bound = ""),
-            PartialEq(bound = ""),
             Eq(bound = "")
         )]
+        #[cfg_attr(not(feature = "constant_time"), derivative(PartialEq(bound = "")))]
         pub struct $Fp<P>(
             pub $BigIntegerType,
             #[derivative(Debug = "ignore")]
@@ -32,12 +32,77 @@ macro_rules! impl_Fp {
                 self.0 < P::MODULUS
             }
 
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             fn reduce(&mut self) {
                 if !self.is_valid() {
                     self.0.sub_noborrow(&P::MODULUS);
                 }
             }
+
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn reduce(&mut self) {
+                let mut tmp = self.0;
+                let borrow = tmp.sub_noborrow(&P::MODULUS);
+                Self::select_limbs(&mut self.0, &tmp, ct_mask(!borrow));
+            }
+
+            // Reduces a value that overflowed the limbs, by `carry`. Only moduli
+            // that use the top bit of the limbs can overflow them.
+            #[cfg(not(feature = "constant_time"))]
+            #[inline]
+            fn reduce_with_carry(&mut self, carry: bool) {
+                if carry || !self.is_valid() {
+                    self.0.sub_noborrow(&P::MODULUS);
+                }
+            }
+
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn reduce_with_carry(&mut self, carry: bool) {
+                let mut tmp = self.0;
+                let borrow = tmp.sub_noborrow(&P::MODULUS);
+                Self::select_limbs(&mut self.0, &tmp, ct_mask(carry | !borrow));
+            }
+
+            impl_field_mul_assign_portable!($limbs);
+
+            impl_field_square_in_place_portable!($limbs);
+
+            // Sets a to b where mask is all ones, and leaves it where mask is
+            // zero, without branching on either.
+            #[inline]
+            fn select_limbs(a: &mut $BigIntegerType, b: &$BigIntegerType, mask: u64) {
+                for (a, b) in a.0.iter_mut().zip(b.0.iter()) {
+                    *a ^= mask & (*a ^ *b);
+                }
+            }
+
+            // The bitwise or of the limbs of self xor other, which is zero
+            // exactly when they are equal, computed without early exits.
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn limbs_diff(&self, other: &$BigIntegerType) -> u64 {
+                (self.0)
+                    .0
+                    .iter()
+                    .zip(other.0.iter())
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+            }
+        }
+
+        #[cfg(feature = "constant_time")]
+        impl<P> PartialEq for $Fp<P> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                (self.0)
+                    .0
+                    .iter()
+                    .zip((other.0).0.iter())
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+                    == 0
+            }
         }
 
         impl<P: $FpParameters> Zero for $Fp<P> {
@@ -46,10 +111,17 @@ macro_rules! impl_Fp {
                 $Fp::<P>($BigInteger::from(0), PhantomData)
             }
 
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             fn is_zero(&self) -> bool {
                 self.0.is_zero()
             }
+
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.limbs_diff(&$BigInteger::from(0)) == 0
+            }
         }
 
         impl<P: $FpParameters> One for $Fp<P> {
@@ -58,10 +130,17 @@ macro_rules! impl_Fp {
                 $Fp::<P>(P::R, PhantomData)
             }
 
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             fn is_one(&self) -> bool {
                 self.0 == P::R
             }
+
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn is_one(&self) -> bool {
+                self.limbs_diff(&P::R) == 0
+            }
         }
 
         impl<P: $FpParameters> Field for $Fp<P> {
@@ -74,13 +153,21 @@ macro_rules! impl_Fp {
 
             #[inline]
             fn double_in_place(&mut self) -> &mut Self {
-                // This cannot exceed the backing capacity.
+                // This only exceeds the backing capacity if the modulus uses its
+                // top bit, in which case the reduction drops the carry.
+                let carry = (self.0).0[$limbs - 1] >> 63 != 0;
                 self.0.mul2();
-                // However, it may need to be reduced.
-                self.reduce();
+                self.reduce_with_carry(carry);
                 self
             }
 
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+                let mut res = *a;
+                Self::select_limbs(&mut res.0, &b.0, ct_mask(choice));
+                res
+            }
+
             #[inline]
             fn characteristic<'a>() -> &'a [u64] {
                 P::MODULUS.as_ref()
@@ -96,7 +183,8 @@ macro_rules! impl_Fp {
                 let mask: u64 = 0xffffffffffffffff >> P::REPR_SHAVE_BITS;
                 // the flags will be at the same byte with the lowest shaven bits or the one after
                 let flags_byte_position: usize = 7 - P::REPR_SHAVE_BITS as usize / 8;
-                let flags_mask: u8 = ((1 << P::REPR_SHAVE_BITS % 8) - 1) << (8 - P::REPR_SHAVE_BITS % 8);
+                // There are no flags if no bits are shaven.
+                let flags_mask: u8 = 0xffu8.checked_shl(8 - P::REPR_SHAVE_BITS % 8).unwrap_or(0);
                 // take the last 8 bytes and pass the mask
                 let last_bytes = &mut result_bytes[($limbs - 1) * 8..];
                 let mut flags: u8 = 0;
@@ -119,6 +207,21 @@ macro_rules! impl_Fp {
 
             impl_field_square_in_place!($limbs);
 
+            // By Fermat's little theorem, as self^(p - 2), so that the
+            // sequence of operations only depends on the modulus.
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                if self.is_zero() {
+                    None
+                } else {
+                    let mut exp = P::MODULUS;
+                    exp.sub_noborrow(&$BigInteger::from(2));
+                    Some(self.pow(exp))
+                }
+            }
+
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             fn inverse(&self) -> Option<Self> {
                 if self.is_zero() {
@@ -143,8 +246,9 @@ macro_rules! impl_Fp {
                             if b.0.is_even() {
                                 b.0.div2();
                             } else {
-                                b.0.add_nocarry(&P::MODULUS);
+                                let carry = b.0.add_nocarry(&P::MODULUS);
                                 b.0.div2();
+                                (b.0).0[$limbs - 1] |= (carry as u64) << 63;
                             }
                         }
 
@@ -154,8 +258,9 @@ macro_rules! impl_Fp {
                             if c.0.is_even() {
                                 c.0.div2();
                             } else {
-                                c.0.add_nocarry(&P::MODULUS);
+                                let carry = c.0.add_nocarry(&P::MODULUS);
                                 c.0.div2();
+                                (c.0).0[$limbs - 1] |= (carry as u64) << 63;
                             }
                         }
 
@@ -362,6 +467,7 @@ macro_rules! impl_Fp {
 
         impl<P: $FpParameters> Neg for $Fp<P> {
             type Output = Self;
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             #[must_use]
             fn neg(self) -> Self {
@@ -373,6 +479,20 @@ macro_rules! impl_Fp {
                     self
                 }
             }
+
+            // p - self, masked to zero when self is zero.
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            #[must_use]
+            fn neg(self) -> Self {
+                let mut tmp = P::MODULUS.clone();
+                tmp.sub_noborrow(&self.0);
+                let nonzero = ct_mask(!self.is_zero());
+                for l in tmp.0.iter_mut() {
+                    *l &= nonzero;
+                }
+                $Fp::<P>(tmp, PhantomData)
+            }
         }
 
         impl<'a, P: $FpParameters> Add<&'a $Fp<P>> for $Fp<P> {
@@ -425,14 +545,15 @@ macro_rules! impl_Fp {
         impl<'a, P: $FpParameters> AddAssign<&'a Self> for $Fp<P> {
             #[inline]
             fn add_assign(&mut self, other: &Self) {
-                // This cannot exceed the backing capacity.
-                self.0.add_nocarry(&other.0);
-                // However, it may need to be reduced
-                self.reduce();
+                // This only exceeds the backing capacity if the modulus uses its
+                // top bit, in which case the reduction drops the carry.
+                let carry = self.0.add_nocarry(&other.0);
+                self.reduce_with_carry(carry);
             }
         }
 
         impl<'a, P: $FpParameters> SubAssign<&'a Self> for $Fp<P> {
+            #[cfg(not(feature = "constant_time"))]
             #[inline]
             fn sub_assign(&mut self, other: &Self) {
                 // If `other` is larger than `self`, add the modulus to self first.
@@ -441,6 +562,20 @@ macro_rules! impl_Fp {
                 }
                 self.0.sub_noborrow(&other.0);
             }
+
+            // Subtracts, and adds back the modulus, masked to zero unless the
+            // subtraction borrowed.
+            #[cfg(feature = "constant_time")]
+            #[inline]
+            fn sub_assign(&mut self, other: &Self) {
+                let borrow = self.0.sub_noborrow(&other.0);
+                let mut modulus = P::MODULUS;
+                let mask = ct_mask(borrow);
+                for l in modulus.0.iter_mut() {
+                    *l &= mask;
+                }
+                self.0.add_nocarry(&modulus);
+            }
         }
 
         impl<'a, P: $FpParameters> MulAssign<&'a Self> for $Fp<P> {
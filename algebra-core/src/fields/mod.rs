@@ -132,6 +132,19 @@ pub trait Field:
     // Sets `self` to `self`'s inverse if it exists. Otherwise it is a no-op.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Replaces every nonzero element of `v` with its inverse, using
+    /// Montgomery's trick so that only one inversion is computed. Zero
+    /// elements are left as they are.
+    fn batch_inversion(v: &mut [Self]) {
+        batch_inversion(v)
+    }
+
+    /// Returns `b` if `choice` is set and `a` otherwise. The limbs of the
+    /// representations are masked rather than branched on, so that neither
+    /// the instructions executed nor the memory accessed depend on `choice`.
+    #[must_use]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self;
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
     fn frobenius_map(&mut self, power: usize);
@@ -340,6 +353,37 @@ pub trait PrimeField:
     fn modulus_minus_one_div_two() -> Self::BigInt {
         Self::Params::MODULUS_MINUS_ONE_DIV_TWO
     }
+
+    /// Returns the canonical little-endian encoding of the element, of 8
+    /// bytes per limb of `Self::BigInt`.
+    fn to_bytes(&self) -> Vec<u8> {
+        let repr = self.into_repr();
+        let mut res = Vec::with_capacity(8 * repr.as_ref().len());
+        for limb in repr.as_ref() {
+            res.extend_from_slice(&limb.to_le_bytes());
+        }
+        res
+    }
+
+    /// Reads the canonical little-endian encoding of an element, as returned
+    /// by `to_bytes`. Returns `None` if `bytes` has the wrong length or
+    /// encodes an integer which is not less than the modulus.
+    fn from_bytes_checked(bytes: &[u8]) -> Option<Self> {
+        let mut repr = Self::BigInt::default();
+        if bytes.len() != 8 * repr.as_ref().len() {
+            return None;
+        }
+        for (limb, chunk) in repr.as_mut().iter_mut().zip(bytes.chunks(8)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        if repr < Self::Params::MODULUS {
+            Some(Self::from_repr(repr))
+        } else {
+            None
+        }
+    }
 }
 
 /// The interface for a field that supports an efficient square-root operation.
@@ -279,6 +279,14 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
         self.c1.double_in_place();
         self
     }
+
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
     fn frobenius_map(&mut self, power: usize) {
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
@@ -535,6 +543,7 @@ impl<P: Fp12Parameters> ConstantSerializedSize for Fp12<P> {
     const SERIALIZED_SIZE: usize =
         2 * <Fp6<P::Fp6Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp6<P::Fp6Params> as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp12Parameters> CanonicalDeserializeWithFlags for Fp12<P> {
@@ -117,6 +117,14 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
         self
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+
     fn square(&self) -> Self {
         let mut result = *self;
         result.square_in_place();
@@ -441,6 +449,7 @@ impl<P: Fp2Parameters> CanonicalSerialize for Fp2<P> {
 impl<P: Fp2Parameters> ConstantSerializedSize for Fp2<P> {
     const SERIALIZED_SIZE: usize = 2 * <P::Fp as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <P::Fp as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp2Parameters> CanonicalDeserializeWithFlags for Fp2<P> {
@@ -146,6 +146,15 @@ impl<P: Fp3Parameters> Field for Fp3<P> {
         self
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+            Field::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+
     #[inline]
     fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
         let split_at = bytes.len() / 3;
@@ -544,6 +553,7 @@ impl<P: Fp3Parameters> CanonicalSerialize for Fp3<P> {
 impl<P: Fp3Parameters> ConstantSerializedSize for Fp3<P> {
     const SERIALIZED_SIZE: usize = 3 * <P::Fp as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <P::Fp as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp3Parameters> CanonicalDeserializeWithFlags for Fp3<P> {
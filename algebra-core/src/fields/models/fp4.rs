@@ -142,6 +142,14 @@ impl<P: Fp4Parameters> Field for Fp4<P> {
         self
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+
     fn square(&self) -> Self {
         let mut result = *self;
         result.square_in_place();
@@ -430,6 +438,7 @@ impl<P: Fp4Parameters> ConstantSerializedSize for Fp4<P> {
     const SERIALIZED_SIZE: usize =
         2 * <Fp2<P::Fp2Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp2<P::Fp2Params> as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp4Parameters> CanonicalDeserializeWithFlags for Fp4<P> {
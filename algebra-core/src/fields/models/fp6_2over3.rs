@@ -151,6 +151,14 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
         self
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+
     fn square(&self) -> Self {
         let mut result = *self;
         result.square_in_place();
@@ -450,6 +458,7 @@ impl<P: Fp6Parameters> ConstantSerializedSize for Fp6<P> {
     const SERIALIZED_SIZE: usize =
         2 * <Fp3<P::Fp3Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp3<P::Fp3Params> as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp6Parameters> CanonicalDeserializeWithFlags for Fp6<P> {
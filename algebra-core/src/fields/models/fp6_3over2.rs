@@ -189,6 +189,15 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
         self
     }
 
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            Field::conditional_select(&a.c0, &b.c0, choice),
+            Field::conditional_select(&a.c1, &b.c1, choice),
+            Field::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+
     #[inline]
     fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
         let split_at = bytes.len() / 3;
@@ -529,6 +538,7 @@ impl<P: Fp6Parameters> ConstantSerializedSize for Fp6<P> {
     const SERIALIZED_SIZE: usize =
         3 * <Fp2<P::Fp2Params> as ConstantSerializedSize>::SERIALIZED_SIZE;
     const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const SPARE_BITS: usize = <Fp2<P::Fp2Params> as ConstantSerializedSize>::SPARE_BITS;
 }
 
 impl<P: Fp6Parameters> CanonicalDeserializeWithFlags for Fp6<P> {
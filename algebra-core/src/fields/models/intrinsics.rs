@@ -0,0 +1,55 @@
+//! Montgomery multiplication of 6-limb prime field elements with the MULX and
+//! ADCX/ADOX instructions, through `core::arch` intrinsics.
+//!
+//! This backend is used for 384-bit fields when the `x86_intrinsics` feature
+//! is enabled and the target supports BMI2 and ADX (see `build.rs`). MULX
+//! leaves the flags alone, so the low and high words of each row of products
+//! are accumulated in two independent carry chains.
+#![allow(unsafe_code)]
+
+use core::arch::x86_64::{_addcarryx_u64, _mulx_u64};
+
+/// Adds `x * y` to the words `t[..7]`, returning the carry out of `t[6]`.
+#[inline(always)]
+unsafe fn mul_add_row(t: &mut [u64; 8], x: &[u64; 6], y: u64) -> u8 {
+    let mut lo_carry = 0u8;
+    let mut hi_carry = 0u8;
+    let mut prev_hi = 0u64;
+    for (j, &x_j) in x.iter().enumerate() {
+        let mut hi = 0u64;
+        let lo = _mulx_u64(x_j, y, &mut hi);
+        lo_carry = _addcarryx_u64(lo_carry, t[j], lo, &mut t[j]);
+        hi_carry = _addcarryx_u64(hi_carry, t[j], prev_hi, &mut t[j]);
+        prev_hi = hi;
+    }
+    let carry = _addcarryx_u64(lo_carry, t[6], prev_hi, &mut t[6]);
+    carry + _addcarryx_u64(hi_carry, t[6], 0, &mut t[6])
+}
+
+/// Sets `a` to `a * b * R^{-1}` modulo `modulus`, where `inv` is
+/// `-modulus^{-1} mod 2^64`, using the CIOS method. As with the portable
+/// implementation, the result is less than twice the modulus and still
+/// needs a conditional subtraction.
+#[inline]
+pub(crate) fn mul_assign(a: &mut [u64], b: &[u64], modulus: &[u64], inv: u64) {
+    let mut x = [0u64; 6];
+    x.copy_from_slice(a);
+    let mut m = [0u64; 6];
+    m.copy_from_slice(modulus);
+
+    let mut t = [0u64; 8];
+    for &y in b {
+        unsafe {
+            let carry = mul_add_row(&mut t, &x, y);
+            t[7] += carry as u64;
+
+            let k = t[0].wrapping_mul(inv);
+            let carry = mul_add_row(&mut t, &m, k);
+            t[7] += carry as u64;
+        }
+        // t[0] is now zero, so dividing by 2^64 is a shift by one word.
+        t.copy_within(1.., 0);
+        t[7] = 0;
+    }
+    a.copy_from_slice(&t[..6]);
+}
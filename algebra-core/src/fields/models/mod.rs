@@ -20,11 +20,20 @@ use crate::{
 };
 
 #[cfg(use_asm)]
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
 
 #[cfg(use_asm)]
 include!(concat!(env!("OUT_DIR"), "/field_assembly.rs"));
 
+#[cfg(use_intrinsics)]
+mod intrinsics;
+
+// All ones if choice is set, and zero otherwise.
+#[inline]
+fn ct_mask(choice: bool) -> u64 {
+    0u64.wrapping_sub(choice as u64)
+}
+
 impl_Fp!(Fp256, Fp256Parameters, BigInteger256, BigInteger256, 4);
 impl_Fp!(Fp320, Fp320Parameters, BigInteger320, BigInteger320, 5);
 impl_Fp!(Fp384, Fp384Parameters, BigInteger384, BigInteger384, 6);
@@ -6,8 +6,14 @@
 #![deny(unused_comparisons, bare_trait_objects, const_err, unused_must_use)]
 #![deny(unused_mut, unused_unsafe, private_in_public)]
 #![cfg_attr(use_asm, feature(llvm_asm))]
-#![cfg_attr(all(not(use_asm), not(feature = "ocaml_types")), forbid(unsafe_code))]
-#![cfg_attr(all(use_asm, not(feature = "ocaml_types")), deny(unsafe_code))]
+#![cfg_attr(
+    all(not(use_asm), not(use_intrinsics), not(feature = "ocaml_types")),
+    forbid(unsafe_code)
+)]
+#![cfg_attr(
+    all(any(use_asm, use_intrinsics), not(feature = "ocaml_types")),
+    deny(unsafe_code)
+)]
 
 #[cfg(all(test, not(feature = "std")))]
 #[macro_use]
@@ -0,0 +1,106 @@
+use crate::{AffineCurve, FpParameters, PrimeField, ProjectiveCurve, Vec};
+use num_traits::Zero;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A table of multiples of a fixed base, which multiplies it by scalars with
+/// additions only.
+///
+/// Scalars are recoded into signed digits of `window` bits, in
+/// `(-2^(window - 1), 2^(window - 1)]`, and the table holds
+/// `k * 2^(window * j) * base` for each digit position `j` and `k` in
+/// `1..=2^(window - 1)`, in affine form. A multiplication then costs one
+/// mixed addition per non-zero digit. Signed digits halve the size of the
+/// table compared to the unsigned windows of `FixedBaseMSM`.
+#[derive(Clone, Debug)]
+pub struct FixedBaseWnafTable<G: AffineCurve> {
+    window: usize,
+    table: Vec<Vec<G>>,
+}
+
+// The `w` bits of the little-endian limbs starting at bit `pos`.
+fn bits_at(limbs: &[u64], pos: usize, w: usize) -> u64 {
+    let (i, shift) = (pos / 64, pos % 64);
+    if i >= limbs.len() {
+        return 0;
+    }
+    let mut res = limbs[i] >> shift;
+    if shift + w > 64 && i + 1 < limbs.len() {
+        res |= limbs[i + 1] << (64 - shift);
+    }
+    res & ((1 << w) - 1)
+}
+
+impl<G: AffineCurve> FixedBaseWnafTable<G> {
+    /// Builds the table of `base` for digits of `window` bits, which must be
+    /// between 1 and 32. The table has `2^(window - 1)` points per digit.
+    pub fn new(base: G, window: usize) -> Self {
+        assert!((1..=32).contains(&window), "Unsupported window size {}", window);
+        let scalar_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        // The recoding may carry into one more digit.
+        let digits = (scalar_bits + window - 1) / window + 1;
+        let half = 1 << (window - 1);
+
+        let mut multiples = Vec::with_capacity(digits * half);
+        let mut g = base.into_projective();
+        for _ in 0..digits {
+            let mut kg = g;
+            for _ in 0..half {
+                multiples.push(kg);
+                kg += &g;
+            }
+            for _ in 0..window {
+                g.double_in_place();
+            }
+        }
+        let multiples = G::Projective::batch_normalization_into_affine(&multiples);
+
+        Self {
+            window,
+            table: multiples.chunks(half).map(|c| c.to_vec()).collect(),
+        }
+    }
+
+    /// The size of the digits of the table, in bits.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Multiplies the base by `scalar`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G::Projective {
+        let repr = scalar.into_repr();
+        let limbs = repr.as_ref();
+        let w = self.window;
+        let half = 1u64 << (w - 1);
+
+        let mut res = G::Projective::zero();
+        let mut carry = 0u64;
+        for (j, row) in self.table.iter().enumerate() {
+            let d = bits_at(limbs, j * w, w) + carry;
+            if d > half {
+                // d - 2^w, which is negative.
+                let k = (1u64 << w) - d;
+                if k != 0 {
+                    res.add_assign_mixed(&-row[k as usize - 1]);
+                }
+                carry = 1;
+            } else {
+                if d != 0 {
+                    res.add_assign_mixed(&row[d as usize - 1]);
+                }
+                carry = 0;
+            }
+        }
+        res
+    }
+
+    /// Multiplies the base by each of `scalars`.
+    pub fn mul_many(&self, scalars: &[G::ScalarField]) -> Vec<G::Projective> {
+        #[cfg(feature = "parallel")]
+        let scalars = scalars.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let scalars = scalars.iter();
+
+        scalars.map(|s| self.mul(s)).collect()
+    }
+}
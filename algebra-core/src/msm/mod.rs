@@ -1,6 +1,8 @@
 mod fixed_base;
+mod fixed_base_wnaf;
 mod variable_base;
 pub use fixed_base::*;
+pub use fixed_base_wnaf::*;
 pub use variable_base::*;
 
 /// The result of this function is only approximately `ln(a)`
@@ -7,6 +7,19 @@ use rayon::prelude::*;
 
 pub struct VariableBaseMSM;
 
+// The window size of the affine MSM of n >= 32 scalars, about
+// 2/3 log2(n) - 2. Without std there is no floating-point logarithm, so the
+// integer ceiling of log2(n) is used instead, which only affects performance.
+#[cfg(feature = "std")]
+fn window_size(n: usize) -> usize {
+    (2.0 / 3.0 * (f64::from(n as u32)).log2() - 2.0).ceil() as usize
+}
+
+#[cfg(not(feature = "std"))]
+fn window_size(n: usize) -> usize {
+    (2 * crate::log2(n) as usize + 2) / 3 - 2
+}
+
 impl VariableBaseMSM {
     pub fn multi_scalar_mul_affine<G: AffineCurve>(
         bases: &[G],
@@ -15,7 +28,7 @@ impl VariableBaseMSM {
         let c = if scalars.len() < 32 {
             3
         } else {
-            (2.0 / 3.0 * (f64::from(scalars.len() as u32)).log2() - 2.0).ceil() as usize
+            window_size(scalars.len())
         };
         let cc = 1 << c;
 
@@ -88,6 +101,7 @@ impl VariableBaseMSM {
     fn msm_inner<G: AffineCurve>(
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
     ) -> G::Projective
     where
         G::Projective: ProjectiveCurve<Affine = G>,
@@ -98,7 +112,6 @@ impl VariableBaseMSM {
             super::ln_without_floats(scalars.len()) + 2
         };
 
-        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
         let fr_one = G::ScalarField::one().into_repr();
 
         let zero = G::Projective::zero();
@@ -178,6 +191,60 @@ impl VariableBaseMSM {
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInt],
     ) -> G::Projective {
-        Self::msm_inner(bases, scalars)
+        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        Self::msm_inner(bases, scalars, num_bits)
+    }
+
+    /// As `multi_scalar_mul_parallel`, for scalars which are all below
+    /// `2^num_bits`, skipping the windows above.
+    pub fn multi_scalar_mul_bounded<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+    ) -> G::Projective {
+        Self::msm_parallel(bases, scalars, num_bits)
+    }
+
+    /// As `multi_scalar_mul`, but also splits the input into one chunk per
+    /// thread, so that all the threads are busy even when there are fewer
+    /// windows than threads. Each chunk chooses its window size from its own
+    /// length. Without the `parallel` feature this is `multi_scalar_mul`.
+    pub fn multi_scalar_mul_parallel<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        Self::msm_parallel(bases, scalars, num_bits)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn msm_parallel<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+    ) -> G::Projective {
+        // Below this, the chunks are too small for the split to pay off.
+        const MIN_CHUNK: usize = 1 << 10;
+
+        let n = bases.len().min(scalars.len());
+        let threads = rayon::current_num_threads();
+        let chunk = (n + threads - 1) / threads;
+        if threads == 1 || chunk < MIN_CHUNK {
+            return Self::msm_inner(bases, scalars, num_bits);
+        }
+        bases[..n]
+            .par_chunks(chunk)
+            .zip(scalars[..n].par_chunks(chunk))
+            .map(|(bases, scalars)| Self::msm_inner(bases, scalars, num_bits))
+            .reduce(G::Projective::zero, |a, b| a + &b)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn msm_parallel<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+    ) -> G::Projective {
+        Self::msm_inner(bases, scalars, num_bits)
     }
 }
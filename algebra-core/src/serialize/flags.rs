@@ -1,8 +1,15 @@
 pub trait Flags: Default + Clone + Copy + Sized {
+    /// Number of bits required for these flags.
+    const BIT_SIZE: usize;
+
     fn u8_bitmask(&self) -> u8;
     fn from_u8(value: u8) -> Self;
     fn from_u8_remove_flags(value: &mut u8) -> Self;
-    fn len() -> usize;
+
+    #[inline]
+    fn len() -> usize {
+        Self::BIT_SIZE
+    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -10,6 +17,8 @@ pub trait Flags: Default + Clone + Copy + Sized {
 pub struct EmptyFlags;
 
 impl Flags for EmptyFlags {
+    const BIT_SIZE: usize = 0;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         0
@@ -24,11 +33,6 @@ impl Flags for EmptyFlags {
     fn from_u8_remove_flags(_value: &mut u8) -> Self {
         EmptyFlags
     }
-
-    #[inline]
-    fn len() -> usize {
-        0
-    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -82,6 +86,8 @@ impl Default for SWFlags {
 }
 
 impl Flags for SWFlags {
+    const BIT_SIZE: usize = 2;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         let mut mask = 0;
@@ -110,12 +116,6 @@ impl Flags for SWFlags {
         *value &= 0x3F;
         flags
     }
-
-    /// Number of bits required for these flags.
-    #[inline]
-    fn len() -> usize {
-        2
-    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -154,6 +154,8 @@ impl Default for EdwardsFlags {
 }
 
 impl Flags for EdwardsFlags {
+    const BIT_SIZE: usize = 1;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         let mut mask = 0;
@@ -180,10 +182,4 @@ impl Flags for EdwardsFlags {
         *value &= 0x7F;
         flags
     }
-
-    /// Number of bits required for these flags.
-    #[inline]
-    fn len() -> usize {
-        1
-    }
 }
@@ -1,5 +1,8 @@
 mod error;
 mod flags;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod serde_impls;
 pub use crate::io::{Read, Write};
 pub use error::*;
 pub use flags::*;
@@ -24,6 +27,17 @@ pub trait CanonicalSerializeWithFlags: CanonicalSerialize {
 pub trait ConstantSerializedSize: CanonicalSerialize {
     const SERIALIZED_SIZE: usize;
     const UNCOMPRESSED_SIZE: usize;
+    /// Number of unused bits of the last byte of the serialization, in which
+    /// `serialize_with_flags` encodes the flags.
+    const SPARE_BITS: usize = 0;
+}
+
+/// The size of a serialization of `size` bytes with `spare_bits` unused bits,
+/// with `flag_bits` bits of flags, which take an extra byte if they do not
+/// fit in the unused bits.
+#[inline]
+pub const fn serialized_size_with_flags(size: usize, spare_bits: usize, flag_bits: usize) -> usize {
+    size + (flag_bits > spare_bits) as usize
 }
 
 /// Serializer in little endian format.
@@ -197,6 +211,26 @@ pub const fn buffer_byte_size(modulus_bits: usize) -> usize {
     (modulus_bits + 7) / 8
 }
 
+/// Implements `serde::Serialize` and `serde::Deserialize` with the canonical
+/// serialization, when the `serde` feature is enabled.
+macro_rules! impl_serde {
+    (impl<$($param: ident: $bound: path),*> for $type: ty) => {
+        #[cfg(feature = "serde")]
+        impl<$($param: $bound),*> serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serialize::serde_impls::serialize(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($param: $bound),*> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serialize::serde_impls::deserialize(deserializer)
+            }
+        }
+    };
+}
+
 macro_rules! impl_prime_field_serializer {
     ($field: ident, $params: ident, $byte_size: expr) => {
         impl<P: $params> CanonicalSerializeWithFlags for $field<P> {
@@ -208,14 +242,19 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(), crate::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    crate::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                if F::BIT_SIZE > 8 {
                     return Err(crate::serialize::SerializationError::NotEnoughSpace);
                 }
+                // The flags take an extra byte if they do not fit in the
+                // unused bits of the last byte.
+                let output_byte_size = crate::serialize::serialized_size_with_flags(
+                    Self::SERIALIZED_SIZE,
+                    Self::SPARE_BITS,
+                    F::BIT_SIZE,
+                );
 
-                let mut bytes = [0u8; BYTE_SIZE];
-                self.write(&mut bytes[..])?;
+                let mut bytes = [0u8; BYTE_SIZE + 1];
+                self.write(&mut bytes[..BYTE_SIZE])?;
 
                 bytes[output_byte_size - 1] |= flags.u8_bitmask();
 
@@ -229,6 +268,7 @@ macro_rules! impl_prime_field_serializer {
                 <$field<P> as crate::PrimeField>::Params::MODULUS_BITS as usize,
             );
             const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+            const SPARE_BITS: usize = 8 * Self::SERIALIZED_SIZE - P::MODULUS_BITS as usize;
         }
 
         impl<P: $params> CanonicalSerialize for $field<P> {
@@ -254,18 +294,24 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(Self, F), crate::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    crate::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                if F::BIT_SIZE > 8 {
                     return Err(crate::serialize::SerializationError::NotEnoughSpace);
                 }
+                let output_byte_size = crate::serialize::serialized_size_with_flags(
+                    Self::SERIALIZED_SIZE,
+                    Self::SPARE_BITS,
+                    F::BIT_SIZE,
+                );
 
-                let mut masked_bytes = [0; BYTE_SIZE];
+                let mut masked_bytes = [0; BYTE_SIZE + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
 
                 let flags = F::from_u8_remove_flags(&mut masked_bytes[output_byte_size - 1]);
+                if masked_bytes[BYTE_SIZE] != 0 {
+                    return Err(crate::serialize::SerializationError::InvalidData);
+                }
 
-                Ok((Self::read(&masked_bytes[..])?, flags))
+                Ok((Self::read(&masked_bytes[..BYTE_SIZE])?, flags))
             }
         }
 
@@ -285,6 +331,8 @@ macro_rules! impl_prime_field_serializer {
                 Ok(Self::read(&masked_bytes[..])?)
             }
         }
+
+        impl_serde!(impl<P: $params> for $field<P>);
     };
 }
 
@@ -335,10 +383,13 @@ macro_rules! impl_sw_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
-            const SERIALIZED_SIZE: usize =
-                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = crate::serialize::serialized_size_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                <crate::serialize::SWFlags as crate::serialize::Flags>::BIT_SIZE,
+            );
             const UNCOMPRESSED_SIZE: usize =
-                2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE + Self::SERIALIZED_SIZE;
         }
 
         impl<P: $params> CanonicalDeserialize for GroupAffine<P> {
@@ -375,6 +426,8 @@ macro_rules! impl_sw_curve_serializer {
                 Ok(p)
             }
         }
+
+        impl_serde!(impl<P: $params> for GroupAffine<P>);
     };
 }
 
@@ -420,8 +473,11 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
-            const SERIALIZED_SIZE: usize =
-                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = crate::serialize::serialized_size_with_flags(
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE,
+                <P::BaseField as ConstantSerializedSize>::SPARE_BITS,
+                <crate::serialize::EdwardsFlags as crate::serialize::Flags>::BIT_SIZE,
+            );
             const UNCOMPRESSED_SIZE: usize =
                 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
@@ -459,6 +515,8 @@ macro_rules! impl_edwards_curve_serializer {
                 Ok(p)
             }
         }
+
+        impl_serde!(impl<P: $params> for GroupAffine<P>);
     };
 }
 
@@ -0,0 +1,109 @@
+//! `serde` support for the types with a canonical serialization, with the
+//! `serde` feature.
+//!
+//! Values are encoded as their compressed canonical serialization: a string
+//! of lowercase hex digits for human-readable formats such as JSON, and a
+//! byte string otherwise. Decoding checks the encoding as
+//! `CanonicalDeserialize` does, including that points are on the curve and in
+//! the right subgroup, and rejects trailing bytes.
+
+use crate::{CanonicalDeserialize, CanonicalSerialize, String, Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error as _, SeqAccess, Visitor},
+    ser::Error as _,
+    Deserializer, Serializer,
+};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut res = String::with_capacity(2 * bytes.len());
+    for b in bytes {
+        res.push(HEX_DIGITS[(b >> 4) as usize] as char);
+        res.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+    }
+    res
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    fn digit(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+    let s = s.as_bytes();
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.chunks(2)
+        .map(|c| Some((digit(c[0])? << 4) | digit(c[1])?))
+        .collect()
+}
+
+pub(crate) fn serialize<T: CanonicalSerialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut bytes = Vec::with_capacity(value.serialized_size());
+    value.serialize(&mut bytes).map_err(S::Error::custom)?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex_encode(&bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+struct CanonicalVisitor<T>(PhantomData<T>);
+
+impl<T: CanonicalDeserialize> CanonicalVisitor<T> {
+    fn decode<E: serde::de::Error>(bytes: &[u8]) -> Result<T, E> {
+        let mut reader = bytes;
+        let value = T::deserialize(&mut reader).map_err(E::custom)?;
+        if !reader.is_empty() {
+            return Err(E::invalid_length(bytes.len(), &"a canonical serialization"));
+        }
+        Ok(value)
+    }
+}
+
+impl<'de, T: CanonicalDeserialize> Visitor<'de> for CanonicalVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a canonical serialization, as bytes or a hex string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<T, E> {
+        let bytes = hex_decode(s).ok_or_else(|| E::custom("invalid hex string"))?;
+        Self::decode(&bytes)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<T, E> {
+        Self::decode(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        // The size hint comes from the input, so it only bounds the initial
+        // allocation.
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(b) = seq.next_element::<u8>()? {
+            bytes.push(b);
+        }
+        Self::decode(&bytes)
+    }
+}
+
+pub(crate) fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let visitor = CanonicalVisitor(PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
@@ -17,7 +17,8 @@ use rand_xorshift::XorShiftRng;
 use crate::{
     bls12_377::{Fq, Fq12, Fq2, Fq2Parameters, Fq6, Fq6Parameters, FqParameters, Fr},
     tests::fields::{
-        field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test,
+        field_serialization_test, field_test, fp384_backend_test, frobenius_test, primefield_test,
+        sqrt_field_test,
     },
 };
 
@@ -53,6 +54,11 @@ fn test_fq() {
     }
 }
 
+#[test]
+fn test_fq_backend() {
+    fp384_backend_test::<FqParameters>();
+}
+
 #[test]
 fn test_fq2() {
     let mut rng = test_rng();
@@ -17,7 +17,9 @@ use crate::{
     bls12_381::{
         Fq, Fq12, Fq12Parameters, Fq2, Fq2Parameters, Fq6, Fq6Parameters, FqParameters, Fr,
     },
-    tests::fields::{field_test, frobenius_test, primefield_test, sqrt_field_test},
+    tests::fields::{
+        field_test, fp384_backend_test, frobenius_test, primefield_test, sqrt_field_test,
+    },
 };
 
 pub(crate) const ITERATIONS: usize = 5;
@@ -46,6 +48,11 @@ fn test_fq() {
     }
 }
 
+#[test]
+fn test_fq_backend() {
+    fp384_backend_test::<FqParameters>();
+}
+
 #[test]
 fn test_fq2() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -1,10 +1,10 @@
 use crate::{
-    biginteger::BigInteger384,
+    biginteger::{BigInteger256, BigInteger384},
     curves::{
         models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
         ModelParameters, SWModelParameters},
     field_new, Zero,
-    bn_382::*
+    bn_382::{glv::GLVParameters, *}
 };
 
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
@@ -51,6 +51,94 @@ impl SWModelParameters for Bn_382GParameters {
     }
 }
 
+impl GLVParameters for Bn_382GParameters {
+    /// ENDO_COEFF =
+    /// 5543634365110765627805495722462279803748702526157693378764350024314183473112624743494975036155897327035736177770495
+    const ENDO_COEFF: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0x7acc33d798c0b83a,
+            0xb7a9285d8c531bc9,
+            0xae4f977142866758,
+            0xee40836440a57356,
+            0xd2017cb45ac0ad1e,
+            0x180ee33b28c48d8e
+        ])
+    );
+
+    /// LAMBDA =
+    /// 5543634365110765627805495722602203594796039480095757091176104893736770445571446829751520329808852020896773965873151
+    const LAMBDA: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x43ac10f69cd08675,
+            0xb67658df049b19a2,
+            0xe4f95ea6b5f8ead6,
+            0xd65fd263b6fcff0c,
+            0x6b4f8626c0a86f82,
+            0xb005f791c4b9abd
+        ])
+    );
+
+    /// A1 = 2354492379497280434509787787277140623526403269844485013505
+    const A1: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xfbd15b4e1fb53920,
+            0xc3942792814a2356,
+            0x2322d42496e71523,
+            0x2dfea6f6be787a38,
+            0x57767aca10e8278,
+            0x161ce81789e745a9
+        ])
+    );
+
+    /// B1 = -39618954886916442860308922368
+    const B1: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x1af043c918e7d128,
+            0xdfaa19cd6d1a440e,
+            0x9ec3e8c6e6507ec9,
+            0x4b481765dda449b8,
+            0x9e5d6d70d98d079b,
+            0x110eae3540df52ae
+        ])
+    );
+
+    /// A2 = 39618954886916442860308922368
+    const A2: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xe50fbc36e7182ed9,
+            0x2055e63412f1d409,
+            0xe15447ca9cb282b6,
+            0x69421eae4af6bf48,
+            0xd2f2cef8d710b7ed,
+            0x12f5db0a99f934df
+        ])
+    );
+
+    /// B2 = 2354492379497280434509787787316759578413319712704793935873
+    const B2: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xe0e1178506cd67f8,
+            0xe3ea0dc5142fdf48,
+            0x845eeb5db0969659,
+            0xe2b68f90e0d4307f,
+            0x6719fa3bc7817adc,
+            0x50e39e24907f2fa
+        ])
+    );
+
+    /// B2_OVER_R = round(2^384 * B2 / r)
+    const B2_OVER_R: BigInteger256 = BigInteger256([0x1f1ee87af9329808, 0xefd50cebb0711ff7, 0xaa7fac5d4f8f6164, 0x2]);
+
+    /// MINUS_B1_OVER_R = round(2^384 * -B1 / r)
+    const MINUS_B1_OVER_R: BigInteger256 = BigInteger256([0x1af043c918e7d128, 0x38de2e84e, 0x0, 0x0]);
+}
+
 /// G_GENERATOR_X =
 /// 1
 pub const G_GENERATOR_X: Fp = field_new!(
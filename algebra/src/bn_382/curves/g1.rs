@@ -1,6 +1,6 @@
 use crate::{
-    biginteger::BigInteger384,
-    bn_382::*,
+    biginteger::{BigInteger256, BigInteger384},
+    bn_382::{glv::GLVParameters, *},
     curves::{
         models::{ModelParameters, SWModelParameters},
     },
@@ -58,6 +58,94 @@ impl SWModelParameters for Bn_382G1Parameters {
     }
 }
 
+impl GLVParameters for Bn_382G1Parameters {
+    /// ENDO_COEFF =
+    /// 139923791047336953938063712416463854181581533327841661831258050719555166172167993819137
+    const ENDO_COEFF: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xbc53ef09632f7993,
+            0x4989a72cfbc5a71d,
+            0x1bc825e5621f2129,
+            0xcdf1de3d8ddb48ff,
+            0x1f325d26c4458cc2,
+            0x1523ea85ba78a1b6
+        ])
+    );
+
+    /// LAMBDA =
+    /// 279847582094673907876127424825864231224671225352153960300565822703870373206221605830657
+    const LAMBDA: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0x8533cc28673f47ce,
+            0x4856d7ae740da4f6,
+            0x52418bf7cf8ba1a7,
+            0xb6112d3d0432d4b2,
+            0xb88066992a2d4f26,
+            0x81566c3adffaee4
+        ])
+    );
+
+    /// A1 = 39618954886916442860308922369
+    const A1: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0xe50fbc36e7182ed2,
+            0x2055e629929d2b61,
+            0x734060ad5dee6976,
+            0x2417e07823bdf8ad,
+            0xb9c12816580b10c7,
+            0x16d61a4b9e0d7ffa
+        ])
+    );
+
+    /// B1 = -2354492379497280434509787787277140623526403269844485013504
+    const B1: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0x42ea4b1e04ac6e1,
+            0x3c6bd86efec1f4c1,
+            0x81516b33322897fc,
+            0xdbc607cb542b7faa,
+            0x6bd8d4bfba24683d,
+            0xde7a12850f141e5
+        ])
+    );
+
+    /// A2 = 2354492379497280434509787787316759578413319712704793935873
+    const A2: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0xe0e1178506cd67f1,
+            0xe3ea0dba93db36a0,
+            0xf1eef57a2bc5d179,
+            0x4851d8accf927902,
+            0x4de853569de6a889,
+            0x8ee79234d1c3e15
+        ])
+    );
+
+    /// B2 = 39618954886916442860308922369
+    const B2: Fp = field_new!(
+        Fp,
+        BigInteger384([
+            0xe50fbc36e7182ed2,
+            0x2055e629929d2b61,
+            0x734060ad5dee6976,
+            0x2417e07823bdf8ad,
+            0xb9c12816580b10c7,
+            0x16d61a4b9e0d7ffa
+        ])
+    );
+
+    /// B2_OVER_R = round(2^384 * B2 / r)
+    const B2_OVER_R: BigInteger256 = BigInteger256([0x1af043c918e7d12f, 0x38de2e84e, 0x0, 0x0]);
+
+    /// MINUS_B1_OVER_R = round(2^384 * -B1 / r)
+    const MINUS_B1_OVER_R: BigInteger256 = BigInteger256([0x42ea4b1e04ac6e1, 0xefd50ce8228e37a9, 0xaa7fac5d4f8f6164, 0x2]);
+}
+
 /// G1_GENERATOR_X =
 /// 1
 pub const G1_GENERATOR_X: Fq = field_new!(
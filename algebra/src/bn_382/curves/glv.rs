@@ -0,0 +1,142 @@
+use crate::{
+    biginteger::{BigInteger, BigInteger256, BigInteger384},
+    curves::{
+        models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        AffineCurve, ProjectiveCurve, SWModelParameters,
+    },
+    fields::{FpParameters, PrimeField},
+    msm::VariableBaseMSM,
+    Vec, Zero,
+};
+
+/// Both bn_382 curves have j-invariant 0, so (x, y) -> (ENDO_COEFF * x, y),
+/// for a cube root of unity ENDO_COEFF of the base field, is an endomorphism
+/// acting on the group as multiplication by a cube root of unity LAMBDA of the
+/// scalar field. A scalar k then splits as k1 + k2 * LAMBDA with k1 and k2 of
+/// about half its size (GLV), so that k * P = k1 * P + k2 * phi(P) takes half
+/// as many doublings.
+///
+/// (A1, B1) and (A2, B2) are a short basis of the lattice of (a, b) with
+/// a + b * LAMBDA = 0 mod r, whose determinant A1 * B2 - A2 * B1 is r. The
+/// decomposition rounds k against it with the two precomputed quotients.
+pub trait GLVParameters: SWModelParameters {
+    const ENDO_COEFF: Self::BaseField;
+    const LAMBDA: Self::ScalarField;
+    const A1: Self::ScalarField;
+    const B1: Self::ScalarField;
+    const A2: Self::ScalarField;
+    const B2: Self::ScalarField;
+    const B2_OVER_R: BigInteger256;
+    const MINUS_B1_OVER_R: BigInteger256;
+}
+
+/// The halves of a decomposed scalar are below 2^GLV_SCALAR_BITS in absolute
+/// value.
+pub const GLV_SCALAR_BITS: usize = 192;
+
+// floor(k * g / 2^384)
+fn mul_shift(k: &BigInteger384, g: &BigInteger256) -> BigInteger384 {
+    let mut prod = [0u64; 10];
+    for (i, &x) in k.0.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in g.0.iter().enumerate() {
+            let t = (x as u128) * (y as u128) + (prod[i + j] as u128) + (carry as u128);
+            prod[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        prod[i + 4] = carry;
+    }
+    BigInteger384([prod[6], prod[7], prod[8], prod[9], 0, 0])
+}
+
+// The sign and absolute value of a scalar close to 0 or to r.
+fn signed<F: PrimeField<BigInt = BigInteger384>>(x: F) -> (bool, BigInteger384) {
+    let repr = x.into_repr();
+    if repr > F::Params::MODULUS_MINUS_ONE_DIV_TWO {
+        (true, (-x).into_repr())
+    } else {
+        (false, repr)
+    }
+}
+
+/// Splits k into k1 + k2 * LAMBDA, with k1 and k2 given by their signs (true
+/// for negative) and absolute values, both below 2^GLV_SCALAR_BITS.
+pub fn glv_decompose<P: GLVParameters>(
+    k: &P::ScalarField,
+) -> ((bool, BigInteger384), (bool, BigInteger384))
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    let repr = k.into_repr();
+    let c1 = P::ScalarField::from_repr(mul_shift(&repr, &P::B2_OVER_R));
+    let c2 = P::ScalarField::from_repr(mul_shift(&repr, &P::MINUS_B1_OVER_R));
+
+    let k1 = *k - &(c1 * &P::A1) - &(c2 * &P::A2);
+    let k2 = -(c1 * &P::B1) - &(c2 * &P::B2);
+    (signed(k1), signed(k2))
+}
+
+/// The endomorphism, multiplying p by LAMBDA.
+pub fn endo<P: GLVParameters>(p: &GroupAffine<P>) -> GroupAffine<P> {
+    GroupAffine::new(P::ENDO_COEFF * &p.x, p.y, p.infinity)
+}
+
+fn glv_bases<P: GLVParameters>(
+    p: &GroupAffine<P>,
+    neg1: bool,
+    neg2: bool,
+) -> (GroupAffine<P>, GroupAffine<P>) {
+    let p1 = if neg1 { -*p } else { *p };
+    let p2 = if neg2 { -endo(p) } else { endo(p) };
+    (p1, p2)
+}
+
+/// k * p, as k1 * p + k2 * endo(p) with a joint double-and-add over the bits
+/// of k1 and k2.
+pub fn glv_mul<P: GLVParameters>(p: &GroupProjective<P>, k: &P::ScalarField) -> GroupProjective<P>
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    let ((neg1, k1), (neg2, k2)) = glv_decompose::<P>(k);
+    let (p1, p2) = glv_bases(&p.into_affine(), neg1, neg2);
+    let mut p12 = p1.into_projective();
+    p12.add_assign_mixed(&p2);
+    let p12 = p12.into_affine();
+
+    let bits = k1.num_bits().max(k2.num_bits()) as usize;
+    let mut res = GroupProjective::<P>::zero();
+    for i in (0..bits).rev() {
+        res.double_in_place();
+        match (k1.get_bit(i), k2.get_bit(i)) {
+            (true, true) => res.add_assign_mixed(&p12),
+            (true, false) => res.add_assign_mixed(&p1),
+            (false, true) => res.add_assign_mixed(&p2),
+            (false, false) => (),
+        }
+    }
+    res
+}
+
+/// As VariableBaseMSM::multi_scalar_mul, over twice as many bases with scalars
+/// of half the size: each base p with scalar k contributes k1 * p and
+/// k2 * endo(p).
+pub fn glv_multi_scalar_mul<P: GLVParameters>(
+    bases: &[GroupAffine<P>],
+    scalars: &[BigInteger384],
+) -> GroupProjective<P>
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    let n = bases.len().min(scalars.len());
+    let mut glv_bases = Vec::with_capacity(2 * n);
+    let mut glv_scalars = Vec::with_capacity(2 * n);
+    for (p, k) in bases.iter().zip(scalars) {
+        let ((neg1, k1), (neg2, k2)) = glv_decompose::<P>(&P::ScalarField::from_repr(*k));
+        let (p1, p2) = glv_bases(p, neg1, neg2);
+        glv_bases.push(p1);
+        glv_bases.push(p2);
+        glv_scalars.push(k1);
+        glv_scalars.push(k2);
+    }
+    VariableBaseMSM::multi_scalar_mul_bounded(&glv_bases, &glv_scalars, GLV_SCALAR_BITS)
+}
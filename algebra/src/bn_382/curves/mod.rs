@@ -1,6 +1,7 @@
 pub mod g1;
 pub mod g2;
 pub mod g;
+pub mod glv;
 #[cfg(test)]
 mod tests;
 
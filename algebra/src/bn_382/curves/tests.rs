@@ -11,10 +11,10 @@ use rand::Rng;
 
 use crate::{
     bn_382::{
-        g1, g2, Bn_382, Fq, Fq12, Fq2, Fp, G1Affine, G1Projective, G2Affine, G2Projective,
+        g, g1, g2, glv, Bn_382, Fq, Fq12, Fq2, Fp, G1Affine, G1Projective, G2Affine, G2Projective,
     },
     tests::{
-        curves::{curve_tests, sw_tests},
+        curves::{curve_tests, large_batch_normalization_test, sw_tests},
         groups::group_test,
     },
 };
@@ -24,6 +24,11 @@ fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
 }
 
+#[test]
+fn test_g1_batch_normalization() {
+    large_batch_normalization_test::<G1Projective>();
+}
+
 #[test]
 fn test_g1_projective_group() {
     let mut rng = test_rng();
@@ -81,6 +86,32 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fp::characteristic()), Fq12::one());
 }
 
+#[test]
+fn test_product_of_prepared_pairings() {
+    use algebra_core::UniformRand;
+    type G1Prepared = <Bn_382 as PairingEngine>::G1Prepared;
+    type G2Prepared = <Bn_382 as PairingEngine>::G2Prepared;
+
+    let mut rng = test_rng();
+    let g1: Vec<G1Affine> = (0..3).map(|_| G1Projective::rand(&mut rng).into()).collect();
+    let g2: Vec<G2Affine> = (0..3).map(|_| G2Projective::rand(&mut rng).into()).collect();
+    let table: Vec<(G1Prepared, G2Prepared)> = g1
+        .iter()
+        .zip(&g2)
+        .map(|(p, q)| ((*p).into(), (*q).into()))
+        .collect();
+
+    let expected = g1
+        .iter()
+        .zip(&g2)
+        .fold(Fq12::one(), |acc, (p, q)| acc * &Bn_382::pairing(*p, *q));
+    assert_eq!(Bn_382::product_of_pairings(&table), expected);
+    assert_eq!(
+        Bn_382::product_of_prepared_pairings(table.iter().map(|(p, q)| (p, q))),
+        expected
+    );
+}
+
 #[test]
 fn test_g1_generator_raw() {
     let mut x = Fq::zero();
@@ -200,3 +231,93 @@ fn test_g1_addition_correctness() {
         )
     );
 }
+
+fn glv_test<P: glv::GLVParameters>()
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    use algebra_core::{
+        curves::models::short_weierstrass_jacobian::GroupProjective, msm::VariableBaseMSM,
+        UniformRand,
+    };
+
+    let mut rng = test_rng();
+
+    let g = GroupProjective::<P>::prime_subgroup_generator();
+    assert_eq!(
+        glv::endo(&g.into_affine()).into_projective(),
+        g.mul(P::LAMBDA.into_repr())
+    );
+
+    for _ in 0..20 {
+        let k = P::ScalarField::rand(&mut rng);
+        let ((neg1, k1), (neg2, k2)) = glv::glv_decompose::<P>(&k);
+        assert!(k1.num_bits() as usize <= glv::GLV_SCALAR_BITS);
+        assert!(k2.num_bits() as usize <= glv::GLV_SCALAR_BITS);
+        let k1 = P::ScalarField::from_repr(k1);
+        let k2 = P::ScalarField::from_repr(k2);
+        let k1 = if neg1 { -k1 } else { k1 };
+        let k2 = if neg2 { -k2 } else { k2 };
+        assert_eq!(k1 + &(k2 * &P::LAMBDA), k);
+
+        let p = GroupProjective::<P>::rand(&mut rng);
+        assert_eq!(glv::glv_mul(&p, &k), p.mul(k.into_repr()));
+    }
+
+    let zero = P::ScalarField::zero();
+    let p = GroupProjective::<P>::rand(&mut rng);
+    assert!(glv::glv_mul(&p, &zero).is_zero());
+
+    let bases: Vec<_> = (0..100)
+        .map(|_| GroupProjective::<P>::rand(&mut rng).into_affine())
+        .collect();
+    let scalars: Vec<_> = (0..100)
+        .map(|_| P::ScalarField::rand(&mut rng).into_repr())
+        .collect();
+    assert_eq!(
+        glv::glv_multi_scalar_mul(&bases, &scalars),
+        VariableBaseMSM::multi_scalar_mul(&bases, &scalars)
+    );
+}
+
+#[test]
+fn test_g_glv() {
+    glv_test::<g::Bn_382GParameters>();
+}
+
+#[test]
+fn test_g1_glv() {
+    glv_test::<g1::Bn_382G1Parameters>();
+}
+
+#[cfg(feature = "serde")]
+macro_rules! serde_round_trip {
+    ($type: ty, $value: expr) => {
+        let value: $type = $value;
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<$type>(&json).unwrap(), value);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<$type>(&bytes).unwrap(), value);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use algebra_core::UniformRand;
+
+    let mut rng = test_rng();
+    serde_round_trip!(Fp, rng.gen());
+    serde_round_trip!(Fq, rng.gen());
+    serde_round_trip!(BigInteger384, rng.gen());
+    serde_round_trip!(G1Affine, G1Projective::rand(&mut rng).into_affine());
+    serde_round_trip!(g::Affine, g::Projective::rand(&mut rng).into_affine());
+    serde_round_trip!(G1Affine, G1Affine::zero());
+
+    // Coordinates out of range, and trailing bytes, are rejected.
+    let x = format!("\"{}3f\"", "ff".repeat(47));
+    assert!(serde_json::from_str::<G1Affine>(&x).is_err());
+    let p = serde_json::to_string(&G1Affine::prime_subgroup_generator()).unwrap();
+    let p = format!("{}00\"", &p[..p.len() - 1]);
+    assert!(serde_json::from_str::<G1Affine>(&p).is_err());
+}
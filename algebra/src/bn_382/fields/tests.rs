@@ -17,7 +17,9 @@ use crate::{
     bn_382::{
         Fq, Fq12, Fq12Parameters, Fq2, Fq2Parameters, Fq6, Fq6Parameters, FqParameters, Fp,
     },
-    tests::fields::{field_test, frobenius_test, primefield_test, sqrt_field_test},
+    tests::fields::{
+        field_test, fp384_backend_test, frobenius_test, primefield_test, sqrt_field_test,
+    },
 };
 
 
@@ -47,6 +49,16 @@ fn test_bn_382_fq() {
     }
 }
 
+#[test]
+fn test_bn_382_fp_backend() {
+    fp384_backend_test::<crate::bn_382::FpParameters>();
+}
+
+#[test]
+fn test_bn_382_fq_backend() {
+    fp384_backend_test::<FqParameters>();
+}
+
 #[test]
 fn test_bn_382_fq2() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
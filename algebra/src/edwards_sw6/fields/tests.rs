@@ -1,8 +1,8 @@
-use crate::tests::fields::{field_test, primefield_test};
+use crate::tests::fields::{field_test, fp384_backend_test, primefield_test};
 use algebra_core::test_rng;
 use rand::Rng;
 
-use crate::edwards_sw6::{Fq, Fr};
+use crate::edwards_sw6::{Fq, Fr, FrParameters};
 
 #[test]
 fn test_fr() {
@@ -13,6 +13,11 @@ fn test_fr() {
     primefield_test::<Fr>();
 }
 
+#[test]
+fn test_fr_backend() {
+    fp384_backend_test::<FrParameters>();
+}
+
 #[test]
 fn test_fq() {
     let mut rng = test_rng();
@@ -126,5 +126,15 @@ pub use bn_382::Bn_382;
 pub mod tweedle;
 ///////////////////////////////////////////////////////////////////////////////
 
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "pasta")]
+pub mod pasta;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 pub(crate) mod tests;
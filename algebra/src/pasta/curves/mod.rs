@@ -0,0 +1,5 @@
+pub mod pallas;
+pub mod vesta;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+use algebra_core::{
+    fields::FpParameters as _, test_rng, AffineCurve, PrimeField, ProjectiveCurve, Zero,
+};
+use rand::Rng;
+
+use crate::pasta::*;
+
+use crate::tests::{curves::*, groups::*};
+
+#[test]
+fn test_pallas_projective_curve() {
+    curve_tests::<pallas::Projective>();
+
+    sw_tests::<pallas::PallasParameters>();
+}
+
+#[test]
+fn test_pallas_projective_group() {
+    let mut rng = test_rng();
+    let a: pallas::Projective = rng.gen();
+    let b: pallas::Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_pallas_generator() {
+    let generator = pallas::Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_vesta_projective_curve() {
+    curve_tests::<vesta::Projective>();
+
+    sw_tests::<vesta::VestaParameters>();
+}
+
+#[test]
+fn test_vesta_projective_group() {
+    let mut rng = test_rng();
+    let a: vesta::Projective = rng.gen();
+    let b: vesta::Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_vesta_generator() {
+    let generator = vesta::Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+// The curves form a cycle: the order of each is the size of the base field
+// of the other.
+#[test]
+fn test_cycle() {
+    let pallas = pallas::Projective::prime_subgroup_generator();
+    assert!(pallas.mul(<Fq as PrimeField>::Params::MODULUS).is_zero());
+
+    let vesta = vesta::Projective::prime_subgroup_generator();
+    assert!(vesta.mul(<Fp as PrimeField>::Params::MODULUS).is_zero());
+}
@@ -0,0 +1,82 @@
+use crate::{
+    biginteger::BigInteger256,
+    curves::{
+        models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        ModelParameters, SWModelParameters},
+    field_new, Zero,
+    pasta::*
+};
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct VestaParameters;
+
+impl ModelParameters for VestaParameters {
+    type BaseField = Fq;
+    type ScalarField = Fp;
+}
+
+pub type Affine = GroupAffine<VestaParameters>;
+pub type Projective = GroupProjective<VestaParameters>;
+
+impl SWModelParameters for VestaParameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = 5
+    const COEFF_B: Fq = field_new!(
+        Fq,
+        BigInteger256([
+            0x96bc8c8cffffffed,
+            0x74c2a54b49f7778e,
+            0xfffffffffffffffd,
+            0x3fffffffffffffff
+        ])
+    );
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = 1
+    const COFACTOR_INV: Fp = field_new!(
+        Fp,
+        BigInteger256([
+            0x34786d38fffffffd,
+            0x992c350be41914ad,
+            0xffffffffffffffff,
+            0x3fffffffffffffff
+        ])
+    );
+
+    /// AFFINE_GENERATOR_COEFFS = (G_GENERATOR_X, G_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G_GENERATOR_X, G_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G_GENERATOR_X =
+/// -1
+pub const G_GENERATOR_X: Fq = field_new!(
+    Fq,
+    BigInteger256([
+        0x311bac8400000004,
+        0x891a63f02652a376,
+        0x0,
+        0x0
+    ])
+);
+
+/// G_GENERATOR_Y =
+/// 2
+pub const G_GENERATOR_Y: Fq = field_new!(
+    Fq,
+    BigInteger256([
+        0x2a0f9218fffffff9,
+        0x1011d11bbcef61f1,
+        0xffffffffffffffff,
+        0x3fffffffffffffff
+    ])
+);
@@ -0,0 +1,77 @@
+use algebra_core::{
+    biginteger::BigInteger256 as BigInteger,
+    fields::{FftParameters, Fp256, Fp256Parameters},
+};
+
+pub type Fp = Fp256<FpParameters>;
+
+pub struct FpParameters;
+
+impl Fp256Parameters for FpParameters {}
+impl FftParameters for FpParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 32;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xa28db849bad6dbf0,
+        0x9083cd03d3b539df,
+        0xfba6b9ca9dc8448e,
+        0x3ec928747b89c6da,
+    ]);
+
+}
+
+impl algebra_core::fields::FpParameters for FpParameters {
+    // 28948022309329048855892746252171976963363056481941560715954676764349967630337
+    const MODULUS: BigInteger = BigInteger([
+        11037532056220336129,
+        2469829653914515739,
+        0,
+        4611686018427387904,
+    ]);
+
+    const R: BigInteger = BigInteger([
+        3780891978758094845,
+        11037255111966004397,
+        18446744073709551615,
+        4611686018427387903,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        10122100416058490895,
+        15551789045973377255,
+        8617542898466512152,
+        679271340751763220,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcc96987680000000,
+        0x11234c7e04a67c8d,
+        0x0,
+        0x2000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger([0x94cf91b992d30ed, 0x224698fc, 0x0, 0x40000000]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger =
+        BigInteger([0x4a67c8dcc969876, 0x11234c7e, 0x0, 0x20000000]);
+
+    // GENERATOR = 5
+    const GENERATOR: BigInteger = BigInteger([
+        11647819816328232941,
+        8413468796752855795,
+        18446744073709551613,
+        4611686018427387903,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    const INV: u64 = 11037532056220336127;
+}
@@ -0,0 +1,77 @@
+use algebra_core::{
+    biginteger::BigInteger256 as BigInteger,
+    fields::{FftParameters, Fp256, Fp256Parameters},
+};
+
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 32;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x218077428c9942de,
+        0xcc49578921b60494,
+        0xac2e5d27b2efbee2,
+        0xb79fa897f2db056,
+    ]);
+
+}
+
+impl algebra_core::fields::FpParameters for FqParameters {
+    // 28948022309329048855892746252171976963363056481941647379679742748393362948097
+    const MODULUS: BigInteger = BigInteger([
+        10108024940646105089,
+        2469829653919213789,
+        0,
+        4611686018427387904,
+    ]);
+
+    const R: BigInteger = BigInteger([
+        6569413325480787965,
+        11037255111951910247,
+        18446744073709551615,
+        4611686018427387903,
+    ]);
+
+    const R2: BigInteger = BigInteger([
+        18200867980676431887,
+        7474641938123724515,
+        9200329640471491984,
+        679271340771891881,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xc623759080000000,
+        0x11234c7e04ca546e,
+        0x0,
+        0x2000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger([0x994a8dd8c46eb21, 0x224698fc, 0x0, 0x40000000]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger =
+        BigInteger([0x4ca546ec6237590, 0x11234c7e, 0x0, 0x20000000]);
+
+    // GENERATOR = 5
+    const GENERATOR: BigInteger = BigInteger([
+        10861710938529071085,
+        8413468796663592846,
+        18446744073709551613,
+        4611686018427387903,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    const INV: u64 = 10108024940646105087;
+}
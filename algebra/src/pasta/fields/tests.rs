@@ -0,0 +1,26 @@
+use algebra_core::{test_rng, Field};
+use rand::Rng;
+
+use crate::pasta::*;
+
+use crate::tests::fields::{field_test, primefield_test, sqrt_field_test};
+
+#[test]
+fn test_fp() {
+    let mut rng = test_rng();
+    let a: Fp = rng.gen();
+    let b: Fp = rng.gen();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fp>();
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    let a: Fq = rng.gen();
+    let b: Fq = rng.gen();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fq>();
+}
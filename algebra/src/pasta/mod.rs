@@ -0,0 +1,7 @@
+#[cfg(feature = "pasta")]
+mod curves;
+mod fields;
+
+#[cfg(feature = "pasta")]
+pub use curves::*;
+pub use fields::*;
@@ -0,0 +1,81 @@
+use crate::secp256k1::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<Secp256k1Parameters>;
+pub type Projective = GroupProjective<Secp256k1Parameters>;
+
+/// secp256k1 is the short Weierstrass curve y² = x³ + 7 over the field of
+/// `q = 2^256 - 2^32 - 977`, of prime order
+/// `r = 115792089237316195423570985008687907852837564279074904382605163141518161494337`,
+/// from <https://www.secg.org/sec2-v2.pdf>.
+///
+/// Neither field has spare bits in its representation, so the compressed
+/// serialization of points, which needs two of them for its flags, fails with
+/// `NotEnoughSpace`; the uncompressed one and `ToBytes` work.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Secp256k1Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = 7
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([0x700001ab7, 0x0, 0x0, 0x0]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = 1
+    const COFACTOR_INV: Fr = field_new!(
+        Fr,
+        BigInteger256([0x402da1732fc9bebf, 0x4551231950b75fc4, 0x1, 0x0])
+    );
+
+    /// AFFINE_GENERATOR_COEFFS = (G_GENERATOR_X, G_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G_GENERATOR_X, G_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G_GENERATOR_X =
+/// 55066263022277343669578718895168534326250603453777594175500187360389116729240
+pub const G_GENERATOR_X: Fq = field_new!(
+    Fq,
+    BigInteger256([
+        0xd7362e5a487e2097,
+        0x231e295329bc66db,
+        0x979f48c033fd129c,
+        0x9981e643e9089f48
+    ])
+);
+
+/// G_GENERATOR_Y =
+/// 32670510020758816978083085130507043184471273380659243275938904335757337482424
+pub const G_GENERATOR_Y: Fq = field_new!(
+    Fq,
+    BigInteger256([
+        0xb15ea6d2d3dbabe2,
+        0x8dfc5d5d1f1dc64d,
+        0x70b6b59aac19c136,
+        0xcf3f851fd4a582d6
+    ])
+);
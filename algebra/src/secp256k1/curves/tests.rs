@@ -0,0 +1,87 @@
+use algebra_core::{
+    fields::FpParameters as _, test_rng, AffineCurve, CanonicalSerialize, PrimeField,
+    ProjectiveCurve, Zero,
+};
+use core::str::FromStr;
+use rand::Rng;
+
+use crate::secp256k1::*;
+
+use crate::tests::{curves::*, groups::*};
+
+// The flags of the compressed serialization do not fit in 256 bits, and take
+// an extra byte, so the bytes are not those from_random_bytes expects.
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Projective>();
+
+    sw_curve_serialization_test::<Secp256k1Parameters>();
+    sw_complete_addition_test::<Secp256k1Parameters>();
+}
+
+#[test]
+fn test_serialized_size() {
+    assert_eq!(Affine::zero().serialized_size(), 33);
+    assert_eq!(Affine::zero().uncompressed_size(), 65);
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a: Projective = rng.gen();
+    let b: Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(generator.mul(<Fr as PrimeField>::Params::MODULUS).is_zero());
+}
+
+// (r - 2) * G = -2 * G.
+#[test]
+fn test_scalar_multiplication() {
+    let k = Fr::from_str(
+        "115792089237316195423570985008687907852837564279074904382605163141518161494335",
+    )
+    .unwrap();
+    let expected = Affine::new(
+        Fq::from_str(
+            "89565891926547004231252920425935692360644145829622209833684329913297188986597",
+        )
+        .unwrap(),
+        Fq::from_str(
+            "103633689937622365100603176395974509217114616778598935862658712053120463017733",
+        )
+        .unwrap(),
+        false,
+    );
+    assert!(expected.is_on_curve());
+
+    let g = Affine::prime_subgroup_generator();
+    assert_eq!(g.mul(k).into_affine(), expected);
+    assert_eq!(-expected, g.into_projective().double().into_affine());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use algebra_core::UniformRand;
+
+    let mut rng = test_rng();
+    for a in &[Projective::rand(&mut rng).into_affine(), Affine::zero()] {
+        let json = serde_json::to_string(a).unwrap();
+        assert_eq!(json.len(), 2 + 2 * 33);
+        assert_eq!(&serde_json::from_str::<Affine>(&json).unwrap(), a);
+        let bytes = bincode::serialize(a).unwrap();
+        assert_eq!(&bincode::deserialize::<Affine>(&bytes).unwrap(), a);
+    }
+
+    // Bits of the extra byte other than the flags are rejected.
+    let p = serde_json::to_string(&Affine::prime_subgroup_generator()).unwrap();
+    let p = format!("{}{}\"", &p[..p.len() - 3], "01");
+    assert!(serde_json::from_str::<Affine>(&p).is_err());
+}
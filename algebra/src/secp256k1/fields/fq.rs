@@ -0,0 +1,79 @@
+use algebra_core::{
+    biginteger::BigInteger256 as BigInteger,
+    fields::{FftParameters, Fp256, Fp256Parameters, FpParameters},
+};
+
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    // -1
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xfffffffdfffff85e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+}
+
+impl FpParameters for FqParameters {
+    // 115792089237316195423570985008687907853269984665640564039457584007908834671663
+    // = 2^256 - 2^32 - 977
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([0x1000003d1, 0x0, 0x0, 0x0]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([0x7a2000e90a1, 0x1, 0x0, 0x0]);
+
+    const INV: u64 = 0xd838091dd2253531;
+
+    // GENERATOR = 3
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([0x300000b73, 0x0, 0x0, 0x0]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+}
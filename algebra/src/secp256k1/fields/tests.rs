@@ -0,0 +1,45 @@
+use algebra_core::{test_rng, PrimeField};
+use rand::Rng;
+
+use crate::secp256k1::*;
+
+use crate::tests::fields::{field_test, primefield_test, sqrt_field_test};
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    let a: Fq = rng.gen();
+    let b: Fq = rng.gen();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fq>();
+}
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    let a: Fr = rng.gen();
+    let b: Fr = rng.gen();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fr>();
+}
+
+// The moduli use the top bit of the limbs, so that sums and products of large
+// elements overflow them before being reduced.
+fn full_width_modulus_test<F: PrimeField>() {
+    let minus_one = -F::one();
+    let minus_two = -F::from(2u64);
+    assert_eq!(minus_one + &minus_one, minus_two);
+    assert_eq!(minus_one.double(), minus_two);
+    assert_eq!(minus_one * &minus_one, F::one());
+    assert_eq!(minus_two.square(), F::from(4u64));
+    assert_eq!(minus_two.inverse().unwrap() * &minus_two, F::one());
+    assert!(F::from_random_bytes(&[0xff; 32]).is_none());
+}
+
+#[test]
+fn test_full_width_modulus() {
+    full_width_modulus_test::<Fq>();
+    full_width_modulus_test::<Fr>();
+}
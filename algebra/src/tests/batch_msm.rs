@@ -1,7 +1,7 @@
 #![cfg(feature = "bn_382")]
 use crate::bn_382::{Fp, G1Affine, G1Projective};
 use algebra_core::{
-    msm::{FixedBaseMSM, VariableBaseMSM}, AffineCurve, PrimeField, ProjectiveCurve, UniformRand, Zero,
+    msm::{FixedBaseMSM, FixedBaseWnafTable, VariableBaseMSM}, AffineCurve, PrimeField, ProjectiveCurve, One, UniformRand, Zero,
 };
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
@@ -71,6 +71,26 @@ fn test_with_bn_382_unequal_numbers() {
     assert_eq!(naive, affine)
 }
 
+#[test]
+fn test_fixed_base_wnaf_table_with_bn_382() {
+    const SAMPLES: usize = 1 << 6;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let g = G1Projective::rand(&mut rng).into_affine();
+    let mut v = (0..SAMPLES).map(|_| Fp::rand(&mut rng)).collect::<Vec<_>>();
+    v.push(Fp::zero());
+    v.push(-Fp::one());
+
+    for window in 1..=8 {
+        let table = FixedBaseWnafTable::new(g, window);
+        let fast = table.mul_many(&v);
+        for (s, fast) in v.iter().zip(fast) {
+            assert_eq!(g.mul(*s), fast);
+        }
+    }
+}
+
 #[test]
 fn batch_addition()
 {
@@ -223,6 +223,33 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+// Batch normalization of a slice long enough to be split across threads with
+// the parallel feature.
+pub fn large_batch_normalization_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let g = G::rand(&mut rng);
+    let mut acc = G::rand(&mut rng);
+    let mut v = Vec::with_capacity(1 << 16);
+    for _ in 0..(1 << 16) {
+        acc += &g;
+        v.push(acc);
+    }
+    v[1 << 15] = G::zero();
+
+    let expected_v = v
+        .iter()
+        .map(|v| v.into_affine().into_projective())
+        .collect::<Vec<_>>();
+    G::batch_normalization(&mut v);
+
+    for i in &v {
+        assert!(i.is_normalized());
+    }
+
+    assert_eq!(v, expected_v);
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -294,6 +321,31 @@ pub fn curve_tests<G: ProjectiveCurve>() {
 pub fn sw_tests<P: SWModelParameters>() {
     sw_curve_serialization_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_complete_addition_test::<P>();
+}
+
+pub fn sw_complete_addition_test<P: SWModelParameters>() {
+    use algebra_core::curves::models::short_weierstrass_jacobian::GroupProjective;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let zero = GroupProjective::<P>::zero();
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let b = GroupProjective::<P>::rand(&mut rng);
+
+        assert_eq!(a.add_complete(&b), a + &b);
+        assert_eq!(a.add_complete(&a), a.double());
+        assert!(a.add_complete(&-a).is_zero());
+        assert_eq!(a.add_complete(&zero), a);
+        assert_eq!(zero.add_complete(&a), a);
+        assert!(zero.add_complete(&zero).is_zero());
+
+        let k = P::ScalarField::rand(&mut rng).into_repr();
+        assert_eq!(a.mul_constant_time(k), a.mul(k));
+        assert!(a.mul_constant_time(P::ScalarField::zero().into_repr()).is_zero());
+        assert!(zero.mul_constant_time(k).is_zero());
+    }
 }
 
 pub fn sw_from_random_bytes<P: SWModelParameters>() {
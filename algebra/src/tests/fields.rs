@@ -1,10 +1,13 @@
 #![allow(unused)]
 use crate::{
-    fields::{FftField, FftParameters, Field, LegendreSymbol, PrimeField, SquareRootField},
+    fields::{
+        FftField, FftParameters, Field, Fp384, Fp384Parameters, LegendreSymbol, PrimeField,
+        SquareRootField,
+    },
     io::Cursor,
     Flags, SWFlags,
 };
-use algebra_core::buffer_bit_byte_size;
+use algebra_core::{buffer_bit_byte_size, One, Zero};
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
@@ -89,6 +92,20 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
+    let mut v: Vec<F> = (0..ITERATIONS).map(|_| F::rand(rng)).collect();
+    v[(ITERATIONS / 2) as usize] = F::zero();
+    let expected: Vec<F> = v
+        .iter()
+        .map(|a| a.inverse().unwrap_or_else(F::zero))
+        .collect();
+    F::batch_inversion(&mut v);
+    assert_eq!(v, expected);
+
+    let mut empty: Vec<F> = vec![];
+    F::batch_inversion(&mut empty);
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -111,6 +128,17 @@ fn random_squaring_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_selection_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(F::conditional_select(&a, &b, false), a);
+        assert_eq!(F::conditional_select(&a, &b, true), b);
+        assert_eq!(F::conditional_select(&a, &a, true), a);
+    }
+}
+
 fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         // Compare (a + b)(c + d) and (a*c + b*c + a*d + b*d)
@@ -164,8 +192,10 @@ fn random_field_tests<F: Field>() {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_batch_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
+    random_selection_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
 
     assert!(F::zero().is_zero());
@@ -344,9 +374,41 @@ pub fn primefield_test<F: PrimeField>() {
     let one = F::one();
     assert_eq!(F::from_repr(one.into_repr()), one);
 
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        assert_eq!(F::from_bytes_checked(&a.to_bytes()), Some(a));
+    }
+    let modulus = F::Params::MODULUS;
+    let mut bytes = F::zero().to_bytes();
+    for (chunk, limb) in bytes.chunks_mut(8).zip(modulus.as_ref()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    assert_eq!(F::from_bytes_checked(&bytes), None);
+    assert_eq!(F::from_bytes_checked(&bytes[1..]), None);
+
     fft_field_test::<F>();
 }
 
+// Checks that the multiplication and squaring used for a field, which may be
+// the assembly or MULX/ADX backend, agree with the portable code.
+pub fn fp384_backend_test<P: Fp384Parameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let edges = [Fp384::<P>::zero(), Fp384::<P>::one(), -Fp384::<P>::one()];
+    let randoms: Vec<Fp384<P>> = (0..ITERATIONS).map(|_| rng.gen()).collect();
+    let values: Vec<_> = edges.iter().chain(randoms.iter()).collect();
+    for &a in &values {
+        let mut square = *a;
+        square.square_in_place_portable();
+        assert_eq!(a.square(), square);
+        for &b in &values {
+            let mut product = *a;
+            product.mul_assign_portable(b);
+            assert_eq!(*a * b, product);
+        }
+    }
+}
+
 pub fn sqrt_field_test<F: SquareRootField>(elem: F) {
     let square = elem.square();
     let sqrt = square.sqrt().unwrap();
@@ -418,6 +480,8 @@ pub fn field_serialization_test<F: Field>(buf_size: usize) {
         #[derive(Default, Clone, Copy, Debug)]
         struct DummyFlags;
         impl Flags for DummyFlags {
+            const BIT_SIZE: usize = 200;
+
             fn u8_bitmask(&self) -> u8 {
                 0
             }
@@ -429,10 +493,6 @@ pub fn field_serialization_test<F: Field>(buf_size: usize) {
             fn from_u8_remove_flags(_value: &mut u8) -> Self {
                 DummyFlags
             }
-
-            fn len() -> usize {
-                200
-            }
         }
 
         use crate::serialize::SerializationError;
@@ -55,3 +55,22 @@ fn test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn test_parallel_with_bls12() {
+    const SAMPLES: usize = 1 << 13;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES - 1)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+    let parallel = VariableBaseMSM::multi_scalar_mul_parallel(g.as_slice(), v.as_slice());
+
+    assert_eq!(fast.into_affine(), parallel.into_affine());
+}
@@ -1,2 +1,5 @@
 pub mod dee;
 pub mod dum;
+
+#[cfg(test)]
+mod tests;
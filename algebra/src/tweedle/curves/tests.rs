@@ -0,0 +1,63 @@
+use algebra_core::{
+    fields::FpParameters as _, test_rng, AffineCurve, PrimeField, ProjectiveCurve, Zero,
+};
+use rand::Rng;
+
+use crate::tweedle::*;
+
+use crate::tests::{curves::*, groups::*};
+
+#[test]
+fn test_dee_projective_curve() {
+    curve_tests::<dee::Projective>();
+
+    sw_tests::<dee::TweedledeeParameters>();
+}
+
+#[test]
+fn test_dee_projective_group() {
+    let mut rng = test_rng();
+    let a: dee::Projective = rng.gen();
+    let b: dee::Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_dee_generator() {
+    let generator = dee::Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_dum_projective_curve() {
+    curve_tests::<dum::Projective>();
+
+    sw_tests::<dum::TweedledumParameters>();
+}
+
+#[test]
+fn test_dum_projective_group() {
+    let mut rng = test_rng();
+    let a: dum::Projective = rng.gen();
+    let b: dum::Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_dum_generator() {
+    let generator = dum::Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+// The curves form a cycle: the order of each is the size of the base field
+// of the other.
+#[test]
+fn test_cycle() {
+    let dee = dee::Projective::prime_subgroup_generator();
+    assert!(dee.mul(<Fp as PrimeField>::Params::MODULUS).is_zero());
+
+    let dum = dum::Projective::prime_subgroup_generator();
+    assert!(dum.mul(<Fq as PrimeField>::Params::MODULUS).is_zero());
+}
@@ -8,3 +8,5 @@ pub mod fq;
 #[cfg(feature = "tweedle")]
 pub use self::fq::*;
 
+#[cfg(all(feature = "tweedle", test))]
+mod tests;
@@ -48,7 +48,7 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
     /// having `num_coeffs` coefficients.
     fn new(num_coeffs: usize) -> Option<Self> {
         // Compute the size of our evaluation domain
-        let size = num_coeffs.next_power_of_two() as u64;
+        let size = num_coeffs.checked_next_power_of_two()? as u64;
         let log_size_of_group = size.trailing_zeros();
 
         // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
@@ -76,7 +76,7 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
     }
 
     fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
-        let size = num_coeffs.next_power_of_two();
+        let size = num_coeffs.checked_next_power_of_two()?;
         if size.trailing_zeros() > F::FftParams::TWO_ADICITY {
             None
         } else {
@@ -260,6 +260,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn size_overflow() {
+        let too_large = usize::max_value() / 2 + 2;
+        assert!(Radix2EvaluationDomain::<Fr>::compute_size_of_domain(too_large).is_none());
+        assert!(Radix2EvaluationDomain::<Fr>::new(too_large).is_none());
+    }
+
     #[test]
     fn elements_contents() {
         for coeffs in 1..10 {
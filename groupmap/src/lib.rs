@@ -1,6 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 use algebra::{
     curves::models::SWModelParameters,
-    fields::{SquareRootField, Field}, Zero, One,
+    fields::{SquareRootField, Field}, Zero, One, Vec,
 };
 
 pub trait GroupMap<F> {
@@ -0,0 +1,182 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Generates, in OUT_DIR, the ABI manifest returned by
+// zexe_bn382_abi_manifest_json and, with the versioned-symbols feature, the
+// version script giving every stub the ABI version.
+//
+// Both list the stubs declared in snarky_bn382.h, which cbindgen generates
+// from the sources (see header/src/main.rs) and CI checks is up to date.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=snarky_bn382.h");
+    println!("cargo:rerun-if-changed=src/abi.rs");
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let crate_dir = Path::new(&crate_dir);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    let header = fs::read_to_string(crate_dir.join("snarky_bn382.h")).unwrap();
+    let stubs = stubs(&header);
+    let abi_version = abi_version(crate_dir);
+    fs::write(
+        out_dir.join("abi_manifest.json"),
+        abi_manifest(abi_version, &stubs),
+    )
+    .unwrap();
+    if env::var_os("CARGO_FEATURE_VERSIONED_SYMBOLS").is_some() {
+        let script = out_dir.join("versioned_symbols.map");
+        fs::write(&script, version_script(abi_version, &stubs)).unwrap();
+        println!(
+            "cargo:rustc-cdylib-link-arg=-Wl,--version-script={}",
+            script.display()
+        );
+    }
+}
+
+// ABI_VERSION, as defined in src/abi.rs.
+fn abi_version(crate_dir: &Path) -> u32 {
+    let abi = fs::read_to_string(crate_dir.join("src").join("abi.rs")).unwrap();
+    abi.lines()
+        .find_map(|l| l.trim().strip_prefix("pub const ABI_VERSION: u32 = "))
+        .and_then(|v| v.trim_end_matches(';').parse().ok())
+        .expect("src/abi.rs defines pub const ABI_VERSION: u32")
+}
+
+struct Stub {
+    name: String,
+    args: Vec<String>,
+    ret: String,
+}
+
+// The functions declared in the header, in its order. The header only holds
+// declarations, so every statement outside of braces with parentheses which
+// is not a typedef declares a function, ret name(args).
+fn stubs(header: &str) -> Vec<Stub> {
+    let mut statements = vec![];
+    let mut statement = String::new();
+    let mut depth = 0;
+    for line in header.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+        for c in line.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                ';' if depth == 0 => {
+                    statements.push(std::mem::replace(&mut statement, String::new()));
+                    continue;
+                },
+                _ => (),
+            }
+            if depth == 0 && c != '}' {
+                statement.push(c);
+            }
+        }
+        statement.push(' ');
+    }
+
+    let mut res = vec![];
+    for s in statements {
+        let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        let open = match s.find('(') {
+            Some(open) if !s.starts_with("typedef") => open,
+            _ => continue,
+        };
+        let decl = s[..open].trim_end();
+        let name_start = decl
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        let args = s[open + 1..s.rfind(')').unwrap()].trim();
+        let args = if args.is_empty() || args == "void" {
+            vec![]
+        } else {
+            split_args(args)
+        };
+        res.push(Stub {
+            name: decl[name_start..].to_string(),
+            args,
+            ret: decl[..name_start].trim().to_string(),
+        });
+    }
+    res
+}
+
+// Splits at the commas outside of any parentheses.
+fn split_args(args: &str) -> Vec<String> {
+    let mut res = vec![];
+    let mut arg = String::new();
+    let mut depth = 0;
+    for c in args.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                res.push(arg.trim().to_string());
+                arg.clear();
+                continue;
+            },
+            _ => (),
+        }
+        arg.push(c);
+    }
+    res.push(arg.trim().to_string());
+    res
+}
+
+// ABI manifest
+//
+// A JSON object with the versions of the crate and of the ABI and, for every
+// stub of the header, in its order, its arguments and return type as declared
+// there. The opaque types of the header are named after the Rust types behind
+// the handles.
+
+fn abi_manifest(abi_version: u32, stubs: &[Stub]) -> String {
+    let mut entries = vec![];
+    for stub in stubs {
+        entries.push(format!(
+            "{{\"name\":{},\"args\":{},\"ret\":{}}}",
+            json_string(&stub.name),
+            json_strings(&stub.args),
+            json_string(&stub.ret)
+        ));
+    }
+    format!(
+        "{{\"version\":{},\"abi_version\":{},\"functions\":[{}]}}",
+        json_string(&env::var("CARGO_PKG_VERSION").unwrap()),
+        abi_version,
+        entries.join(",")
+    )
+}
+
+// Versioned symbols
+//
+// With the versioned-symbols feature, the shared library gives every stub the
+// symbol version SNARKY_BN382_<ABI_VERSION>, through a linker version script,
+// and hides its other symbols. Bindings linked against one ABI version then
+// fail to load against a library of another, rather than calling stubs whose
+// signatures changed, and libraries of different ABI versions can be loaded in
+// the same process.
+
+fn version_script(abi_version: u32, stubs: &[Stub]) -> String {
+    let mut res = format!("SNARKY_BN382_{} {{\n  global:\n", abi_version);
+    for stub in stubs {
+        res.push_str(&format!("    {};\n", stub.name));
+    }
+    res.push_str("  local:\n    *;\n};\n");
+    res
+}
+
+fn json_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn json_strings(v: &[String]) -> String {
+    let v: Vec<String> = v.iter().map(|s| json_string(s)).collect();
+    format!("[{}]", v.join(","))
+}
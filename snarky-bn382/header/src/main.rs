@@ -0,0 +1,288 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+// Generates snarky-bn382/snarky_bn382.h from the signatures of the stubs with
+// cbindgen, configured by snarky-bn382/cbindgen.toml.
+//
+//   cargo +nightly run --manifest-path snarky-bn382/header/Cargo.toml
+//
+// rewrites the header, and with -- --check fails if it is out of date instead.
+// The crate is expanded before it is parsed, for the stubs generated by
+// macros, which takes a nightly toolchain. CI runs the check, so that the
+// checked-in header is always the one the sources produce.
+
+fn main() {
+    let check = match env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("unknown argument {}, expected --check or nothing", arg);
+            process::exit(2);
+        },
+    };
+
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let header = match header(&crate_dir) {
+        Ok(header) => header,
+        Err(e) => {
+            eprintln!("could not generate snarky_bn382.h: {}", e);
+            process::exit(1);
+        },
+    };
+
+    let path = crate_dir.join("snarky_bn382.h");
+    let current = fs::read_to_string(&path).ok();
+    if current.as_ref() == Some(&header) {
+        return;
+    }
+    if check {
+        eprintln!(
+            "{} is out of date, regenerate it with \
+             cargo +nightly run --manifest-path snarky-bn382/header/Cargo.toml",
+            path.display()
+        );
+        process::exit(1);
+    }
+    if let Err(e) = fs::write(&path, header) {
+        eprintln!("could not write {}: {}", path.display(), e);
+        process::exit(1);
+    }
+}
+
+fn header(crate_dir: &Path) -> Result<String, String> {
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))?;
+    let header = generate(crate_dir, config.clone())?;
+
+    // cbindgen only declares types defined in this crate. The others, such as
+    // proofs, indexes and URSs, are only ever passed behind pointers, so they
+    // are declared as opaque structs and the header is generated again.
+    let opaque = undeclared_types(&header);
+    if opaque.is_empty() {
+        return Ok(header);
+    }
+    let mut decls = String::from("\n// Rust types passed behind pointers\n");
+    for t in opaque.iter() {
+        decls.push_str(&format!("typedef struct {} {};\n", t, t));
+    }
+    let mut config = config;
+    config.after_includes = Some(decls);
+    generate(crate_dir, config)
+}
+
+fn generate(crate_dir: &Path, config: cbindgen::Config) -> Result<String, String> {
+    let bindings = cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .map_err(|e| e.to_string())?;
+    let mut out = vec![];
+    bindings.write(&mut out);
+    let header = String::from_utf8(out).map_err(|e| e.to_string())?;
+    Ok(header
+        .lines()
+        .map(|l| array_pointers_as_element_pointers(&mangle_generics(l)) + "\n")
+        .collect())
+}
+
+// Pointers to arrays of opaque types, T (*x)[N], are not valid C, as the size
+// of T is unknown, and are declared as pointers to their first element, T *x.
+fn array_pointers_as_element_pointers(line: &str) -> String {
+    let mut res = String::new();
+    let mut rest = line;
+    while let Some(open) = rest.find("(*") {
+        let mut depth = 0;
+        let close = rest[open..].char_indices().find_map(|(i, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => (),
+            }
+            if depth == 0 {
+                Some(open + i)
+            } else {
+                None
+            }
+        });
+        let close = match close {
+            Some(close) => close,
+            None => break,
+        };
+        let after = &rest[close + 1..];
+        let len = after.strip_prefix('[').and_then(|a| a.split_once(']'));
+        match len {
+            Some((n, after_len)) if !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()) => {
+                res.push_str(&rest[..open]);
+                res.push_str(&rest[open + 1..close]);
+                rest = after_len;
+            },
+            _ => {
+                res.push_str(&rest[..close + 1]);
+                rest = after;
+            },
+        }
+    }
+    res.push_str(rest);
+    res
+}
+
+// cbindgen writes the generic types of other crates, such as SRS<GAffine>, as
+// they are in Rust. They are renamed as cbindgen names the instances of the
+// generic types it knows, SRS_GAffine.
+fn mangle_generics(line: &str) -> String {
+    if line.starts_with('#') || line.trim_start().starts_with("//") {
+        return line.to_string();
+    }
+    let mut res = String::new();
+    let mut rest = line;
+    while let Some(open) = rest.find('<') {
+        let start = rest[..open]
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        let close = match closing_bracket(rest, open) {
+            Some(close) if start < open => close,
+            _ => {
+                res.push_str(&rest[..open + 1]);
+                rest = &rest[open + 1..];
+                continue;
+            },
+        };
+        res.push_str(&rest[..start]);
+        res.push_str(&mangle(&rest[start..close + 1], true));
+        rest = &rest[close + 1..];
+    }
+    res.push_str(rest);
+    res
+}
+
+// The index of the > closing the < at open.
+fn closing_bracket(s: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s[open..].char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            },
+            _ => (),
+        }
+    }
+    None
+}
+
+// Name<A, B> as Name_A__B, with the separators of cbindgen: one underscore
+// after the name, two between the arguments and three closing an argument
+// which is not the last one.
+fn mangle(ty: &str, last: bool) -> String {
+    let ty = ty.trim();
+    let open = match ty.find('<') {
+        Some(open) => open,
+        None => return ty.to_string(),
+    };
+    let inner = &ty[open + 1..ty.len() - 1];
+    let mut args = vec![];
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&inner[arg_start..i]);
+                arg_start = i + 1;
+            },
+            _ => (),
+        }
+    }
+    args.push(&inner[arg_start..]);
+
+    let mut res = format!("{}_", &ty[..open]);
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            res.push_str("__");
+        }
+        res.push_str(&mangle(arg, last && i == args.len() - 1));
+    }
+    if !last {
+        res.push_str("___");
+    }
+    res
+}
+
+// The type names used behind a pointer in the header which it neither
+// declares nor are C types.
+fn undeclared_types(header: &str) -> BTreeSet<String> {
+    const C_TYPES: &[&str] = &[
+        "void",
+        "char",
+        "bool",
+        "float",
+        "double",
+        "size_t",
+        "uintptr_t",
+        "intptr_t",
+        "int8_t",
+        "int16_t",
+        "int32_t",
+        "int64_t",
+        "uint8_t",
+        "uint16_t",
+        "uint32_t",
+        "uint64_t",
+        "const",
+    ];
+
+    let mut declared = BTreeSet::new();
+    let mut used = BTreeSet::new();
+    for line in header.lines() {
+        if line.trim_start().starts_with("//") || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with("typedef") || line.starts_with('}') {
+            if let Some(name) = typedef_name(line) {
+                declared.insert(name.to_string());
+            }
+        }
+
+        // A word followed, possibly after spaces, by a star.
+        let mut word = String::new();
+        let mut last_word = String::new();
+        for c in line.chars() {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                word.push(c);
+                continue;
+            }
+            if !word.is_empty() {
+                last_word = std::mem::take(&mut word);
+            }
+            if c == '*' && !last_word.is_empty() {
+                used.insert(last_word.clone());
+            }
+            if c != ' ' {
+                last_word.clear();
+            }
+        }
+    }
+
+    used.into_iter()
+        .filter(|t| !declared.contains(t) && !C_TYPES.contains(&t.as_str()))
+        .filter(|t| !t.starts_with(|c: char| c.is_ascii_digit()))
+        .collect()
+}
+
+// The name a typedef declares, or which closes the declaration of a struct,
+// given its last line: T Name;, T Name[N]; or T (*Name)(args);.
+fn typedef_name(line: &str) -> Option<&str> {
+    let decl = match line.find("(*") {
+        Some(i) => &line[i + 2..],
+        None => line,
+    };
+    let decl = decl.split(|c| c == '[' || c == ')' || c == ';').next()?;
+    decl.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .rfind(|w| !w.is_empty())
+}
@@ -1,1761 +1,3509 @@
-// Defines bool
+#ifndef SNARKY_BN382_H
+#define SNARKY_BN382_H
+
+// Generated by snarky-bn382-header from the Rust signatures. Do not edit by hand.
+
 #include <stdbool.h>
-#include <stdint.h>
-// Defines size_t
 #include <stddef.h>
+#include <stdint.h>
+
+// Rust types passed behind pointers
+typedef struct ArithmeticSpongeParams_Fp ArithmeticSpongeParams_Fp;
+typedef struct ArithmeticSpongeParams_Fq ArithmeticSpongeParams_Fq;
+typedef struct ArithmeticSponge_Fp__SC ArithmeticSponge_Fp__SC;
+typedef struct ArithmeticSponge_Fq__SC ArithmeticSponge_Fq__SC;
+typedef struct BigInteger BigInteger;
+typedef struct BigInteger256 BigInteger256;
+typedef struct BigInteger384 BigInteger384;
+typedef struct ConstraintSystem_Fp ConstraintSystem_Fp;
+typedef struct ConstraintSystem_Fq ConstraintSystem_Fq;
+typedef struct DlogIndex_GAffine DlogIndex_GAffine;
+typedef struct DlogProofEvaluations_Fp DlogProofEvaluations_Fp;
+typedef struct DlogProofEvaluations_Fq DlogProofEvaluations_Fq;
+typedef struct DlogProofEvaluations_Vec_Fp DlogProofEvaluations_Vec_Fp;
+typedef struct DlogProofEvaluations_Vec_Fq DlogProofEvaluations_Vec_Fq;
+typedef struct DlogProof_GAffine DlogProof_GAffine;
+typedef struct DlogVerifierIndex_GAffine DlogVerifierIndex_GAffine;
+typedef struct Fp Fp;
+typedef struct Fq Fq;
+typedef struct G1Affine G1Affine;
+typedef struct G1Projective G1Projective;
+typedef struct G2Affine G2Affine;
+typedef struct GAffine GAffine;
+typedef struct GProjective GProjective;
+typedef struct Index_Bn_382 Index_Bn_382;
+typedef struct OpeningProof_GAffine OpeningProof_GAffine;
+typedef struct PallasAffine PallasAffine;
+typedef struct PallasProjective PallasProjective;
+typedef struct PolyComm_GAffine PolyComm_GAffine;
+typedef struct ProverProof_Bn_382 ProverProof_Bn_382;
+typedef struct RandomOracles_Fp RandomOracles_Fp;
+typedef struct SRS_GAffine SRS_GAffine;
+typedef struct URS_Bn_382 URS_Bn_382;
+typedef struct VerifierIndex_Bn_382 VerifierIndex_Bn_382;
+typedef struct VestaAffine VestaAffine;
+typedef struct VestaProjective VestaProjective;
+
+
+#define ABI_VERSION 5
+
+#define VERIFY_BYTES_VALID 0
+
+#define VERIFY_BYTES_INVALID 1
+
+#define VERIFY_BYTES_MALFORMED_PROOF 2
+
+#define VERIFY_BYTES_MALFORMED_PUBLIC_INPUT 3
+
+#define VERIFY_BYTES_REJECTED_LIMITS 4
+
+#define VERIFY_BYTES_UNKNOWN_INDEX 5
+
+#define URS_DIGEST_SIZE 32
+
+#define PROOF_KEY_MIN_BYTES 16
+
+#define VERIFY_OK 0
+
+#define VERIFY_FAILED_PUBLIC_INPUT 1
+
+#define VERIFY_FAILED_SUMCHECK_1 2
+
+#define VERIFY_FAILED_SUMCHECK_2 3
+
+#define VERIFY_FAILED_SUMCHECK_3 4
+
+#define VERIFY_FAILED_DEGREE_BOUND 5
+
+#define VERIFY_FAILED_OPENING 6
+
+#define VERIFY_FAILED_MALFORMED 7
+
+#define WNAF_WINDOW 5
+
+#define MAX_DOMAIN_SIZE_LOG2 28
+
+#define MAX_CIRCUIT_SIZE (1 << MAX_DOMAIN_SIZE_LOG2)
+
+#define FIXED_MONOMIALS 16
+
+#define PAIRING_PROOF_VERSION 1
+
+#define INDEX_FILE_VERSION 1
+
+#define ONE 0
+
+typedef struct AffinePair_G1Affine AffinePair_G1Affine;
+
+typedef struct AffinePair_GAffine AffinePair_GAffine;
+
+typedef struct Bn382Cycle Bn382Cycle;
+
+typedef struct CircuitMerge_Fp CircuitMerge_Fp;
+
+typedef struct CircuitMerge_Fq CircuitMerge_Fq;
+
+typedef struct Csr_Fp Csr_Fp;
+
+typedef struct Csr_Fq Csr_Fq;
+
+typedef struct DegreeBoundAccumulator DegreeBoundAccumulator;
+
+typedef struct DegreeBoundedCommitment DegreeBoundedCommitment;
+
+typedef struct FixedCommitments_G1Affine FixedCommitments_G1Affine;
+
+typedef struct FixedCommitments_PolyComm_GAffine FixedCommitments_PolyComm_GAffine;
+
+typedef struct FpOracles FpOracles;
+
+typedef struct FqAccumulator FqAccumulator;
+
+typedef struct FqOpeningAccumulator FqOpeningAccumulator;
+
+typedef struct FqOpeningChallenges FqOpeningChallenges;
+
+typedef struct FqOracles FqOracles;
+
+typedef struct FqProofEvaluationsBuilder FqProofEvaluationsBuilder;
+
+typedef struct FqPublicInput FqPublicInput;
+
+typedef struct FqRecordedProof FqRecordedProof;
+
+typedef struct FqTranscript FqTranscript;
+
+typedef struct FqVerifyPhase1 FqVerifyPhase1;
+
+typedef struct KzgOpening KzgOpening;
+
+typedef struct PairVectorCursor_G1Affine PairVectorCursor_G1Affine;
+
+typedef struct PairVectorCursor_GAffine PairVectorCursor_GAffine;
+
+typedef struct PairingAccumulators PairingAccumulators;
+
+typedef struct Vec_AffinePair_G1Affine Vec_AffinePair_G1Affine;
+
+typedef struct Vec_AffinePair_GAffine Vec_AffinePair_GAffine;
+
+typedef struct Vec_CircuitGate_Fp Vec_CircuitGate_Fp;
+
+typedef struct Vec_CircuitGate_Fq Vec_CircuitGate_Fq;
+
+typedef struct Vec_DlogProof_GAffine Vec_DlogProof_GAffine;
+
+typedef struct Vec_Fp Vec_Fp;
+
+typedef struct Vec_Fq Vec_Fq;
+
+typedef struct Vec_FqOracles Vec_FqOracles;
+
+typedef struct Vec_G1Affine Vec_G1Affine;
 
-struct det_sqrt_witness {
-  void* c;
+typedef struct Vec_G1Projective Vec_G1Projective;
+
+typedef struct Vec_G2Affine Vec_G2Affine;
+
+typedef struct Vec_GAffine Vec_GAffine;
+
+typedef struct Vec_GProjective Vec_GProjective;
+
+typedef struct Vec_Gate_Fp Vec_Gate_Fp;
+
+typedef struct Vec_Gate_Fq Vec_Gate_Fq;
+
+typedef struct Vec_KzgOpening Vec_KzgOpening;
+
+typedef struct Vec_PallasAffine Vec_PallasAffine;
+
+typedef struct Vec_PolyComm_GAffine Vec_PolyComm_GAffine;
+
+typedef struct Vec_ProverProof_Bn_382 Vec_ProverProof_Bn_382;
+
+typedef struct Vec_Row_Fp Vec_Row_Fp;
+
+typedef struct Vec_Row_Fq Vec_Row_Fq;
+
+typedef struct Vec_String Vec_String;
+
+typedef struct Vec_VestaAffine Vec_VestaAffine;
+
+typedef struct Vec______DlogVerifierIndex_GAffine Vec______DlogVerifierIndex_GAffine;
+
+typedef struct Vec______FqVerifyPhase1 Vec______FqVerifyPhase1;
+
+typedef struct Vec______OpeningProof_GAffine Vec______OpeningProof_GAffine;
+
+typedef struct Vec______Vec_Fp Vec______Vec_Fp;
+
+typedef struct Vec______Vec_Fq Vec______Vec_Fq;
+
+typedef struct Vec______Vec_PolyComm_GAffine Vec______Vec_PolyComm_GAffine;
+
+typedef struct Vec______VerifierIndex_Bn_382 Vec______VerifierIndex_Bn_382;
+
+typedef struct Vec______c_void Vec______c_void;
+
+typedef struct Vec_i64 Vec_i64;
+
+typedef struct Vec_u8 Vec_u8;
+
+typedef struct Vec_usize Vec_usize;
+
+typedef struct VrfProof VrfProof;
+
+typedef uint8_t UrsDigest[URS_DIGEST_SIZE];
+
+typedef struct {
+  const SRS_GAffine *a;
+  const UrsDigest *b;
+} PointerPair_SRS_GAffine_____UrsDigest;
+
+typedef bool (*RowSource)(void*, uintptr_t, uintptr_t, uintptr_t*, const uintptr_t**, const uint64_t**);
+
+typedef struct {
+  Fp *c;
+  uint64_t d;
+  Fp *square_root;
+  bool success;
+} DetSqrtWitness_Fp;
+
+typedef void (*FillBytes)(uint8_t*, uintptr_t);
+
+typedef struct {
+  Fq *c;
   uint64_t d;
-  void* square_root;
+  Fq *square_root;
   bool success;
-};
+} DetSqrtWitness_Fq;
+
+typedef struct {
+  const URS_Bn_382 *a;
+  const UrsDigest *b;
+} PointerPair_URS_Bn_382_____UrsDigest;
+
+typedef struct {
+  uintptr_t num_threads;
+  uintptr_t x_hat_cache_capacity;
+} Config;
+
+typedef struct {
+  const Fp *a;
+  const Fp *b;
+} PointerPair_Fp__Fp;
+
+typedef struct {
+  const Fq *a;
+  const Fq *b;
+} PointerPair_Fq__Fq;
+
+BigInteger384 *zexe_bigint384_of_decimal_string(const int8_t *s);
+
+int32_t zexe_bigint384_num_limbs(void);
+
+uint64_t *zexe_bigint384_to_data(BigInteger384 *x);
+
+BigInteger384 *zexe_bigint384_of_data(uint64_t *x);
+
+int32_t zexe_bigint384_bytes_per_limb(void);
+
+BigInteger384 *zexe_bigint384_div(const BigInteger384 *x, const BigInteger384 *y);
+
+BigInteger384 *zexe_bigint384_of_numeral(const uint8_t *s, uint32_t len, uint32_t base);
+
+BigInteger384 *zexe_bigint384_of_hex(const uint8_t *s, uint32_t len);
+
+BigInteger384 *zexe_bigint384_of_bytes_le(const uint8_t *s, uint32_t len);
+
+uint8_t zexe_bigint384_compare(const BigInteger384 *x, const BigInteger384 *y);
+
+int32_t zexe_bigint384_cmp(const BigInteger384 *x, const BigInteger384 *y);
+
+bool zexe_bigint384_is_zero(const BigInteger384 *x);
+
+BigInteger384 *zexe_bigint384_min(const BigInteger384 *x, const BigInteger384 *y);
+
+BigInteger384 *zexe_bigint384_max(const BigInteger384 *x, const BigInteger384 *y);
+
+BigInteger384 *zexe_bigint384_copy(const BigInteger384 *x);
+
+bool zexe_bigint384_test_bit(const BigInteger384 *x, int32_t i);
+
+void zexe_bigint384_delete(BigInteger384 *x);
+
+void zexe_bigint384_print(const BigInteger384 *x);
+
+const Vec_i64 *zexe_bigint384_find_wnaf(uintptr_t _size, const BigInteger384 *x);
+
+BigInteger256 *zexe_bigint256_of_decimal_string(const int8_t *s);
+
+int32_t zexe_bigint256_num_limbs(void);
+
+uint64_t *zexe_bigint256_to_data(BigInteger256 *x);
+
+BigInteger256 *zexe_bigint256_of_data(uint64_t *x);
+
+int32_t zexe_bigint256_bytes_per_limb(void);
+
+BigInteger256 *zexe_bigint256_div(const BigInteger256 *x, const BigInteger256 *y);
+
+BigInteger256 *zexe_bigint256_of_numeral(const uint8_t *s, uint32_t len, uint32_t base);
+
+BigInteger256 *zexe_bigint256_of_hex(const uint8_t *s, uint32_t len);
+
+BigInteger256 *zexe_bigint256_of_bytes_le(const uint8_t *s, uint32_t len);
+
+uint8_t zexe_bigint256_compare(const BigInteger256 *x, const BigInteger256 *y);
+
+int32_t zexe_bigint256_cmp(const BigInteger256 *x, const BigInteger256 *y);
+
+bool zexe_bigint256_is_zero(const BigInteger256 *x);
+
+BigInteger256 *zexe_bigint256_min(const BigInteger256 *x, const BigInteger256 *y);
+
+BigInteger256 *zexe_bigint256_max(const BigInteger256 *x, const BigInteger256 *y);
+
+BigInteger256 *zexe_bigint256_copy(const BigInteger256 *x);
+
+bool zexe_bigint256_test_bit(const BigInteger256 *x, int32_t i);
+
+void zexe_bigint256_delete(BigInteger256 *x);
+
+void zexe_bigint256_print(const BigInteger256 *x);
+
+const Vec_i64 *zexe_bigint256_find_wnaf(uintptr_t _size, const BigInteger256 *x);
+
+ArithmeticSpongeParams_Fp *camlsnark_bn382_fp_sponge_params_create(void);
+
+void camlsnark_bn382_fp_sponge_params_delete(ArithmeticSpongeParams_Fp *x);
+
+ArithmeticSponge_Fp__SC *camlsnark_bn382_fp_sponge_create(void);
+
+void camlsnark_bn382_fp_sponge_delete(ArithmeticSponge_Fp__SC *x);
+
+void camlsnark_bn382_fp_sponge_absorb(ArithmeticSponge_Fp__SC *sponge, const ArithmeticSpongeParams_Fp *params, const Fp *x);
+
+void camlsnark_bn382_fp_sponge_absorb_vector(ArithmeticSponge_Fp__SC *sponge, const ArithmeticSpongeParams_Fp *params, const Vec_Fp *v);
+
+void camlsnark_bn382_fp_sponge_absorb_raw(ArithmeticSponge_Fp__SC *sponge, const ArithmeticSpongeParams_Fp *params, const Fp *xs, uintptr_t len);
+
+void camlsnark_bn382_fp_sponge_absorb_g_vector(ArithmeticSponge_Fp__SC *sponge, const ArithmeticSpongeParams_Fp *params, const Vec_GAffine *v);
+
+Fp *camlsnark_bn382_fp_sponge_squeeze(ArithmeticSponge_Fp__SC *sponge, const ArithmeticSpongeParams_Fp *params);
+
+Vec_u8 *camlsnark_bn382_fp_sponge_to_bytes(const ArithmeticSponge_Fp__SC *sponge);
+
+ArithmeticSponge_Fp__SC *camlsnark_bn382_fp_sponge_of_bytes(const uint8_t *data, uintptr_t len);
+
+uint32_t zexe_bn382_abi_version(void);
+
+const char *zexe_bn382_abi_manifest_json(void);
+
+char *zexe_bn382_fq_verify_bench(const DlogVerifierIndex_GAffine *index, const Vec_DlogProof_GAffine *proofs, const uintptr_t *batch_sizes, uintptr_t batch_sizes_len, uintptr_t samples);
+
+void zexe_bn382_fq_verify_bench_report_delete(char *s);
+
+const Fp *zexe_bn382_bls_secret_key_random(void);
+
+const G1Affine *zexe_bn382_bls_public_key(const Fp *sk);
+
+const G2Affine *zexe_bn382_bls_hash_to_g2(const uint8_t *msg, uintptr_t len);
+
+const G2Affine *zexe_bn382_bls_sign(const Fp *sk, const uint8_t *msg, uintptr_t len);
+
+bool zexe_bn382_bls_verify(const G1Affine *pk, const uint8_t *msg, uintptr_t len, const G2Affine *sig);
+
+const G2Affine *zexe_bn382_bls_aggregate_signatures(const Vec_G2Affine *sigs);
+
+const G1Affine *zexe_bn382_bls_aggregate_public_keys(const Vec_G1Affine *pks);
+
+bool zexe_bn382_bls_verify_aggregate(const Vec_G1Affine *pks, const uint8_t *msg, uintptr_t len, const G2Affine *sig);
+
+const G2Affine *zexe_bn382_bls_pop_prove(const Fp *sk);
+
+bool zexe_bn382_bls_pop_verify(const G1Affine *pk, const G2Affine *pop);
+
+Vec_u8 *zexe_bn382_g2_affine_to_bytes(const G2Affine *p);
+
+const G2Affine *zexe_bn382_g2_affine_of_bytes(const uint8_t *data, uintptr_t len);
+
+void zexe_bn382_g2_affine_delete(G2Affine *x);
+
+Vec_G2Affine *zexe_bn382_g2_affine_vector_create(void);
+
+int32_t zexe_bn382_g2_affine_vector_length(const Vec_G2Affine *v);
+
+void zexe_bn382_g2_affine_vector_emplace_back(Vec_G2Affine *v, const G2Affine *x);
+
+const G2Affine *zexe_bn382_g2_affine_vector_get(const Vec_G2Affine *v, uint32_t i);
+
+void zexe_bn382_g2_affine_vector_delete(Vec_G2Affine *x);
+
+Bn382Cycle *zexe_bn382_cycle_create(URS_Bn_382 *fp_urs, SRS_GAffine *fq_srs, uintptr_t num_threads);
+
+void zexe_bn382_cycle_delete(Bn382Cycle *x);
+
+const URS_Bn_382 *zexe_bn382_cycle_fp_urs(const Bn382Cycle *ctx);
+
+const SRS_GAffine *zexe_bn382_cycle_fq_urs(const Bn382Cycle *ctx);
+
+const ArithmeticSpongeParams_Fp *zexe_bn382_cycle_fp_sponge_params(const Bn382Cycle *ctx);
+
+const ArithmeticSpongeParams_Fq *zexe_bn382_cycle_fq_sponge_params(const Bn382Cycle *ctx);
+
+uintptr_t zexe_bn382_cycle_num_threads(const Bn382Cycle *ctx);
+
+Index_Bn_382 *zexe_bn382_cycle_fp_index_create(const Bn382Cycle *ctx, Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t vars, uintptr_t public_inputs);
+
+const VerifierIndex_Bn_382 *zexe_bn382_cycle_fp_verifier_index_create(const Bn382Cycle *ctx, const Index_Bn_382 *index);
+
+void zexe_bn382_cycle_fp_verifier_index_delete(const Bn382Cycle *ctx, VerifierIndex_Bn_382 *index);
+
+const ProverProof_Bn_382 *zexe_bn382_cycle_fp_proof_create(const Bn382Cycle *ctx, const Index_Bn_382 *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+bool zexe_bn382_cycle_fp_proof_verify(const Bn382Cycle *ctx, const VerifierIndex_Bn_382 *index, const ProverProof_Bn_382 *proof);
+
+DlogIndex_GAffine *zexe_bn382_cycle_fq_index_create(const Bn382Cycle *ctx, Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t vars, uintptr_t public_inputs);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_cycle_fq_verifier_index_create(const Bn382Cycle *ctx, const DlogIndex_GAffine *index);
+
+void zexe_bn382_cycle_fq_verifier_index_delete(const Bn382Cycle *ctx, DlogVerifierIndex_GAffine *index);
+
+const DlogProof_GAffine *zexe_bn382_cycle_fq_proof_create(const Bn382Cycle *ctx, const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+bool zexe_bn382_cycle_fq_proof_verify(const Bn382Cycle *ctx, const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+char *zexe_bn382_cycle_differential_test(const Bn382Cycle *ctx, const Vec_Row_Fp *a, const Vec_Row_Fp *b, const Vec_Row_Fp *c, uintptr_t vars, uintptr_t public_inputs, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+void zexe_bn382_cycle_differential_report_delete(char *s);
+
+const SRS_GAffine *zexe_bn382_fq_urs_create(uintptr_t depth);
+
+void zexe_bn382_fq_urs_delete(SRS_GAffine *x);
+
+void zexe_bn382_fq_urs_write(SRS_GAffine *urs, char *path);
+
+const SRS_GAffine *zexe_bn382_fq_urs_read(char *path);
+
+const SRS_GAffine *zexe_bn382_fq_urs_read_parallel(char *path);
+
+const SRS_GAffine *zexe_bn382_fq_urs_read_unchecked(char *path, const UrsDigest *digest);
+
+UrsDigest *zexe_bn382_fq_urs_digest(const SRS_GAffine *urs);
+
+UrsDigest *zexe_bn382_fq_urs_write_with_digest(const SRS_GAffine *urs, char *path);
+
+PointerPair_SRS_GAffine_____UrsDigest zexe_bn382_fq_urs_read_with_digest(char *path);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_lagrange_commitment(const SRS_GAffine *urs, uintptr_t domain_size, uintptr_t i);
+
+const Vec_PolyComm_GAffine *zexe_bn382_fq_urs_lagrange_commitments(const SRS_GAffine *urs, uintptr_t domain_size);
+
+const FixedCommitments_PolyComm_GAffine *zexe_bn382_fq_urs_fixed_commitments_create(const SRS_GAffine *urs, uintptr_t h_size, uintptr_t k_size);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_fixed_commitments_z_h(const FixedCommitments_PolyComm_GAffine *c);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_fixed_commitments_z_k(const FixedCommitments_PolyComm_GAffine *c);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_fixed_commitments_monomial(const FixedCommitments_PolyComm_GAffine *c, uintptr_t i);
+
+void zexe_bn382_fq_urs_fixed_commitments_delete(FixedCommitments_PolyComm_GAffine *x);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_commit_evaluations(const SRS_GAffine *urs, uintptr_t domain_size, const Vec_Fq *evals);
+
+const Vec_PolyComm_GAffine *zexe_bn382_fq_urs_commit_evaluations_batch(const SRS_GAffine *urs, uintptr_t domain_size, const Vec______Vec_Fq *evals);
+
+const PolyComm_GAffine *zexe_bn382_fq_urs_b_poly_commitment(const SRS_GAffine *urs, const Vec_Fq *chals);
+
+bool zexe_bn382_fq_urs_batch_accumulator_check(const SRS_GAffine *urs, const Vec_GAffine *comms, const Vec_Fq *chals);
+
+FqOpeningAccumulator *zexe_bn382_fq_urs_opening_accumulator(const SRS_GAffine *urs, const Vec_GAffine *comms, const Vec_Fq *points, const Vec_Fq *values, const Vec______OpeningProof_GAffine *proofs, const Fq *r);
+
+bool zexe_bn382_fq_urs_opening_accumulator_check(const SRS_GAffine *urs, const FqOpeningAccumulator *acc);
+
+const GAffine *zexe_bn382_fq_opening_accumulator_pending(const FqOpeningAccumulator *acc);
+
+const Vec_Fq *zexe_bn382_fq_opening_accumulator_challenges(const FqOpeningAccumulator *acc);
+
+void zexe_bn382_fq_opening_accumulator_delete(FqOpeningAccumulator *x);
+
+uintptr_t zexe_bn382_fq_urs_lr_rounds(const SRS_GAffine *urs);
+
+const GAffine *zexe_bn382_fq_urs_h(const SRS_GAffine *urs);
+
+uintptr_t zexe_bn382_fq_index_domain_h_size(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_index_domain_k_size(const DlogIndex_GAffine *i);
+
+DlogIndex_GAffine *zexe_bn382_fq_index_create(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t vars, uintptr_t public_inputs, SRS_GAffine *srs);
+
+DlogIndex_GAffine *zexe_bn382_fq_index_create_streaming(uintptr_t rows, uintptr_t vars, uintptr_t public_inputs, SRS_GAffine *srs, RowSource source, void *ctx);
+
+void zexe_bn382_fq_index_delete(DlogIndex_GAffine *x);
+
+uintptr_t zexe_bn382_fq_index_nonzero_entries(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_nonzero_entries_a(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_nonzero_entries_b(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_nonzero_entries_c(const DlogIndex_GAffine *index);
+
+Csr_Fq *zexe_bn382_fq_index_csr(const DlogIndex_GAffine *index, uintptr_t matrix);
+
+uintptr_t zexe_bn382_fq_csr_rows(const Csr_Fq *m);
+
+uintptr_t zexe_bn382_fq_csr_cols(const Csr_Fq *m);
+
+Vec_usize *zexe_bn382_fq_csr_indptr(const Csr_Fq *m);
+
+Vec_usize *zexe_bn382_fq_csr_indices(const Csr_Fq *m);
+
+Vec_Fq *zexe_bn382_fq_csr_values(const Csr_Fq *m);
+
+void zexe_bn382_fq_csr_delete(Csr_Fq *x);
+
+uintptr_t zexe_bn382_fq_index_domain_k_padded_size(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_max_degree(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_lr_rounds(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_num_variables(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_index_public_inputs(const DlogIndex_GAffine *index);
+
+void zexe_bn382_fq_index_write(const DlogIndex_GAffine *index, const char *path);
+
+const DlogIndex_GAffine *zexe_bn382_fq_index_read(const SRS_GAffine *srs, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_create(const DlogIndex_GAffine *index);
+
+const SRS_GAffine *zexe_bn382_fq_verifier_index_urs(const DlogVerifierIndex_GAffine *index);
+
+const SRS_GAffine *zexe_bn382_fq_verifier_index_urs_ref(const DlogVerifierIndex_GAffine *index);
+
+uintptr_t zexe_bn382_fq_verifier_index_lr_rounds(const DlogVerifierIndex_GAffine *index);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_make(uintptr_t public_inputs, uintptr_t variables, uintptr_t constraints, uintptr_t nonzero_entries, uintptr_t max_poly_size, const SRS_GAffine *urs, const PolyComm_GAffine *row_a, const PolyComm_GAffine *col_a, const PolyComm_GAffine *val_a, const PolyComm_GAffine *rc_a, const PolyComm_GAffine *row_b, const PolyComm_GAffine *col_b, const PolyComm_GAffine *val_b, const PolyComm_GAffine *rc_b, const PolyComm_GAffine *row_c, const PolyComm_GAffine *col_c, const PolyComm_GAffine *val_c, const PolyComm_GAffine *rc_c);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_make_shared(uintptr_t public_inputs, uintptr_t variables, uintptr_t constraints, uintptr_t nonzero_entries, uintptr_t max_poly_size, const SRS_GAffine *urs, const PolyComm_GAffine *row_a, const PolyComm_GAffine *col_a, const PolyComm_GAffine *val_a, const PolyComm_GAffine *rc_a, const PolyComm_GAffine *row_b, const PolyComm_GAffine *col_b, const PolyComm_GAffine *val_b, const PolyComm_GAffine *rc_b, const PolyComm_GAffine *row_c, const PolyComm_GAffine *col_c, const PolyComm_GAffine *val_c, const PolyComm_GAffine *rc_c);
+
+void zexe_bn382_fq_verifier_index_delete(DlogVerifierIndex_GAffine *x);
+
+void zexe_bn382_fq_verifier_index_write(const DlogVerifierIndex_GAffine *index, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_read(const SRS_GAffine *srs, const char *path);
+
+Vec_u8 *zexe_bn382_fq_verifier_index_to_bytes(const DlogVerifierIndex_GAffine *index);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_of_bytes(const SRS_GAffine *srs, const uint8_t *data, uintptr_t len);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_a_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_a_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_a_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_a_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_b_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_b_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_b_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_b_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_c_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_c_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_c_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_bn382_fq_verifier_index_c_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const Vec______DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_vector_create(void);
+
+int32_t zexe_bn382_fq_verifier_index_vector_length(const Vec______DlogVerifierIndex_GAffine *v);
+
+void zexe_bn382_fq_verifier_index_vector_emplace_back(Vec______DlogVerifierIndex_GAffine *v, const DlogVerifierIndex_GAffine *x);
+
+const DlogVerifierIndex_GAffine *zexe_bn382_fq_verifier_index_vector_get(Vec______DlogVerifierIndex_GAffine *v, uint32_t i);
+
+void zexe_bn382_fq_verifier_index_vector_delete(Vec______DlogVerifierIndex_GAffine *x);
+
+const Fp *zexe_bn382_fq_endo_base(void);
+
+const Fq *zexe_bn382_fq_endo_scalar(void);
+
+int32_t zexe_bn382_fq_size_in_bits(void);
+
+BigInteger384 *zexe_bn382_fq_size(void);
+
+BigInteger384 *zexe_bn382_fq_characteristic(void);
+
+int32_t zexe_bn382_fq_size_in_bytes(void);
+
+void zexe_bn382_fq_modulus_bytes(uint8_t *out);
+
+Fq *zexe_bn382_fq_zero(void);
+
+Fq *zexe_bn382_fq_one(void);
+
+Fq *zexe_bn382_fq_multiplicative_generator(void);
+
+bool zexe_bn382_fq_is_square(const Fq *x);
+
+Fq *zexe_bn382_fq_sqrt(const Fq *x);
+
+Fp *zexe_bn382_fp_det_sqrt(const Fp *x);
+
+DetSqrtWitness_Fp zexe_bn382_fp_det_sqrt_witness(const Fp *x);
+
+Fq *zexe_bn382_fq_two_adic_root_of_unity(void);
+
+Fq *zexe_bn382_fq_random(void);
+
+Fq *zexe_bn382_fq_of_int(uint64_t i);
+
+Fq *zexe_bn382_fq_domain_generator(uintptr_t log2_size);
+
+char *zexe_bn382_fq_to_string(const Fq *x);
+
+Fq *zexe_bn382_fq_of_decimal_string(const char *s);
+
+Fq *zexe_bn382_fq_of_hex_string(const char *s);
+
+void zexe_bn382_fq_to_bytes(const Fq *x, uint8_t *out);
+
+Fq *zexe_bn382_fq_of_bytes(const uint8_t *bytes, uintptr_t len);
+
+Fq *zexe_bn382_fq_inv(const Fq *x);
+
+Fq *zexe_bn382_fq_square(const Fq *x);
+
+Fq *zexe_bn382_fq_add(const Fq *x, const Fq *y);
+
+Fq *zexe_bn382_fq_negate(const Fq *x);
+
+Fq *zexe_bn382_fq_mul(const Fq *x, const Fq *y);
+
+Fq *zexe_bn382_fq_div(const Fq *x, const Fq *y);
+
+Fq *zexe_bn382_fq_sub(const Fq *x, const Fq *y);
+
+void zexe_bn382_fq_mut_add(Fq *x, const Fq *y);
+
+void zexe_bn382_fq_mut_mul(Fq *x, const Fq *y);
+
+void zexe_bn382_fq_mut_square(Fq *x);
+
+void zexe_bn382_fq_mut_sub(Fq *x, const Fq *y);
+
+void zexe_bn382_fq_copy(Fq *x, const Fq *y);
+
+Fq *zexe_bn382_fq_rng(int32_t i);
+
+void zexe_bn382_fq_delete(Fq *x);
+
+void zexe_bn382_fq_print(const Fq *x);
+
+bool zexe_bn382_fq_equal(const Fq *x, const Fq *y);
+
+bool zexe_bn382_fq_is_zero(const Fq *x);
+
+bool zexe_bn382_fq_is_one(const Fq *x);
+
+bool zexe_bn382_fq_to_u64_checked(const Fq *x, uint64_t *out);
+
+BigInteger384 *zexe_bn382_fq_to_bigint(const Fq *x);
+
+Fq *zexe_bn382_fq_of_bigint(const BigInteger384 *x);
+
+BigInteger384 *zexe_bn382_fq_to_bigint_raw(const Fq *x);
+
+const BigInteger384 *zexe_bn382_fq_to_bigint_raw_noalloc(const Fq *x);
+
+Fq *zexe_bn382_fq_of_bigint_raw(const BigInteger384 *x);
+
+Vec_Fp *zexe_bn382_fq_to_fp_chunks(const Fq *x, uintptr_t chunk_bits);
+
+Fq *zexe_bn382_fq_of_fp_chunks(const Vec_Fp *v, uintptr_t chunk_bits);
+
+Vec_Fq *zexe_bn382_fq_vector_create(void);
+
+int32_t zexe_bn382_fq_vector_length(const Vec_Fq *v);
+
+void zexe_bn382_fq_vector_emplace_back(Vec_Fq *v, const Fq *x);
+
+Fq *zexe_bn382_fq_vector_get(Vec_Fq *v, uint32_t i);
+
+void zexe_bn382_fq_vector_delete(Vec_Fq *x);
+
+Vec_Fq *zexe_bn382_fq_vector_batch_inverse(const Vec_Fq *v);
+
+Vec_Fq *zexe_bn382_fq_vector_of_raw(const uint64_t *limbs, uintptr_t len);
+
+void zexe_bn382_fq_vector_to_raw(const Vec_Fq *v, uint64_t *limbs);
+
+Vec_Fq *zexe_bn382_fq_vector_random(uintptr_t n, const uint8_t *seed);
+
+Vec_Row_Fq *zexe_bn382_fq_constraint_matrix_create(void);
+
+void zexe_bn382_fq_constraint_matrix_append_row(Vec_Row_Fq *m, Vec_usize *indices, Vec_Fq *coefficients);
+
+bool zexe_bn382_fq_constraint_matrix_append_csr(Vec_Row_Fq *m, const uintptr_t *row_ptrs, uintptr_t rows, const uintptr_t *cols, const uint64_t *values, uintptr_t nnz);
+
+void zexe_bn382_fq_constraint_matrix_delete(Vec_Row_Fq *x);
+
+const Fq *zexe_bn382_fq_triple_0(const Fq *evals);
+
+const Fq *zexe_bn382_fq_triple_1(const Fq *evals);
+
+const Fq *zexe_bn382_fq_triple_2(const Fq *evals);
+
+void zexe_bn382_fq_triple_delete(Fq *x);
+
+const Vec_Fq *zexe_bn382_fq_vector_triple_0(const Vec_Fq *evals);
+
+const Vec_Fq *zexe_bn382_fq_vector_triple_1(const Vec_Fq *evals);
+
+const Vec_Fq *zexe_bn382_fq_vector_triple_2(const Vec_Fq *evals);
+
+void zexe_bn382_fq_vector_triple_delete(Vec_Fq *x);
+
+const GProjective *zexe_bn382_g_random(void);
+
+void zexe_bn382_g_delete(GProjective *x);
+
+const GProjective *zexe_bn382_g_one(void);
+
+const GProjective *zexe_bn382_g_add(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_bn382_g_double(const GProjective *x);
+
+const GProjective *zexe_bn382_g_scale(const GProjective *x, const Fq *s);
+
+const Vec_GProjective *zexe_bn382_g_scale_many(const GProjective *x, const Vec_Fq *s);
+
+const GProjective *zexe_bn382_g_sub(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_bn382_g_negate(const GProjective *x);
+
+const GAffine *zexe_bn382_g_to_affine(const GProjective *p);
+
+const GProjective *zexe_bn382_g_of_affine(const GAffine *p);
+
+const GProjective *zexe_bn382_g_of_affine_coordinates(const Fp *x, const Fp *y);
+
+const GAffine *zexe_bn382_g_affine_create(const Fp *x, const Fp *y);
+
+const GAffine *zexe_bn382_g_affine_create_infinity(void);
+
+const GAffine *zexe_bn382_g_affine_create_checked(const Fp *x, const Fp *y);
+
+bool zexe_bn382_g_affine_is_on_curve(const GAffine *p);
+
+bool zexe_bn382_g_affine_is_in_correct_subgroup(const GAffine *p);
+
+bool zexe_bn382_g_affine_is_infinity(const GAffine *p);
+
+const Fp *zexe_bn382_g_affine_x(const GAffine *p);
+
+const Fp *zexe_bn382_g_affine_y(const GAffine *p);
+
+bool zexe_bn382_g_affine_is_zero(const GAffine *p);
+
+void zexe_bn382_g_affine_delete(GAffine *x);
+
+const GAffine *zexe_bn382_g_affine_pair_0(const AffinePair_GAffine *p);
+
+const GAffine *zexe_bn382_g_affine_pair_1(const AffinePair_GAffine *p);
+
+const AffinePair_GAffine *zexe_bn382_g_affine_pair_make(const GAffine *x0, const GAffine *x1);
+
+void zexe_bn382_g_affine_pair_delete(AffinePair_GAffine *x);
+
+Vec_AffinePair_GAffine *zexe_bn382_g_affine_pair_vector_create(void);
+
+int32_t zexe_bn382_g_affine_pair_vector_length(const Vec_AffinePair_GAffine *v);
+
+void zexe_bn382_g_affine_pair_vector_emplace_back(Vec_AffinePair_GAffine *v, const AffinePair_GAffine *x);
+
+AffinePair_GAffine *zexe_bn382_g_affine_pair_vector_get(Vec_AffinePair_GAffine *v, uint32_t i);
+
+void zexe_bn382_g_affine_pair_vector_delete(Vec_AffinePair_GAffine *x);
+
+PairVectorCursor_GAffine *zexe_bn382_g_affine_pair_vector_cursor_create(const Vec_AffinePair_GAffine *v);
+
+const AffinePair_GAffine *zexe_bn382_g_affine_pair_vector_cursor_next(PairVectorCursor_GAffine *c);
+
+void zexe_bn382_g_affine_pair_vector_cursor_delete(PairVectorCursor_GAffine *x);
+
+Vec_GProjective *zexe_bn382_g_vector_create(void);
+
+int32_t zexe_bn382_g_vector_length(const Vec_GProjective *v);
+
+void zexe_bn382_g_vector_emplace_back(Vec_GProjective *v, const GProjective *x);
+
+GProjective *zexe_bn382_g_vector_get(Vec_GProjective *v, uint32_t i);
+
+void zexe_bn382_g_vector_delete(Vec_GProjective *x);
+
+Vec_GAffine *zexe_bn382_g_affine_vector_create(void);
+
+int32_t zexe_bn382_g_affine_vector_length(const Vec_GAffine *v);
+
+void zexe_bn382_g_affine_vector_emplace_back(Vec_GAffine *v, const GAffine *x);
+
+GAffine *zexe_bn382_g_affine_vector_get(Vec_GAffine *v, uint32_t i);
+
+void zexe_bn382_g_affine_vector_delete(Vec_GAffine *x);
+
+const GAffine *zexe_bn382_g_affine_vector_sum(const Vec_GAffine *v);
+
+const GAffine *zexe_bn382_g_affine_vector_linear_combination(const Vec_GAffine *v, const Vec_Fq *s);
+
+void zexe_bn382_fq_x_hat_cache_set_capacity(uintptr_t capacity);
+
+FqPublicInput *zexe_bn382_fq_public_input_create(void);
+
+FqPublicInput *zexe_bn382_fq_public_input_create_streaming(const DlogVerifierIndex_GAffine *index);
+
+void zexe_bn382_fq_public_input_push(FqPublicInput *b, const Fq *x);
+
+uintptr_t zexe_bn382_fq_public_input_length(const FqPublicInput *b);
+
+bool zexe_bn382_fq_public_input_finish(FqPublicInput *b, const DlogVerifierIndex_GAffine *index);
+
+const Vec_Fq *zexe_bn382_fq_public_input_primary(const FqPublicInput *b);
+
+const Vec_Fq *zexe_bn382_fq_public_input_public(const FqPublicInput *b);
+
+const PolyComm_GAffine *zexe_bn382_fq_public_input_x_hat_comm(const FqPublicInput *b);
+
+void zexe_bn382_fq_public_input_delete(FqPublicInput *x);
+
+const FqOracles *zexe_bn382_fq_oracles_create(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+const FqOracles *zexe_bn382_fq_oracles_create_with_public_input(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof, const FqPublicInput *public_input);
+
+const FqOracles *zexe_bn382_fq_oracles_create_with_x_hat_comm(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof, const PolyComm_GAffine *x_hat_comm);
+
+Vec_FqOracles *zexe_bn382_fq_oracles_create_batch(const DlogVerifierIndex_GAffine *index, const Vec_DlogProof_GAffine *proofs);
+
+uintptr_t zexe_bn382_fq_oracles_vector_length(const Vec_FqOracles *v);
+
+const FqOracles *zexe_bn382_fq_oracles_vector_get(const Vec_FqOracles *v, uintptr_t i);
+
+void zexe_bn382_fq_oracles_vector_delete(Vec_FqOracles *x);
+
+const Vec_Fq *zexe_bn382_fq_oracles_opening_prechallenges(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_alpha(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_eta_a(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_eta_b(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_eta_c(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_beta1(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_beta2(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_beta3(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_polys(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_evals(const FqOracles *oracles);
+
+const Vec_Fq *zexe_bn382_fq_oracles_x_hat_nocopy(const FqOracles *oracles);
+
+const Fq *zexe_bn382_fq_oracles_digest_before_evaluations(const FqOracles *oracles);
+
+void zexe_bn382_fq_oracles_delete(FqOracles *x);
+
+FqOpeningChallenges *zexe_bn382_fq_opening_challenges(const DlogVerifierIndex_GAffine *index, const uint8_t *proof, uintptr_t proof_len, const uint8_t *public_input, uintptr_t public_input_len);
+
+const Vec_Fq *zexe_bn382_fq_opening_challenges_prechallenges(const FqOpeningChallenges *c);
+
+const Vec_Fq *zexe_bn382_fq_opening_challenges_challenges(const FqOpeningChallenges *c);
+
+const Vec_Fq *zexe_bn382_fq_opening_challenges_challenge_invs(const FqOpeningChallenges *c);
+
+const Vec_Fq *zexe_bn382_fq_opening_challenges_evaluation_points(const FqOpeningChallenges *c);
+
+const Vec_Fq *zexe_bn382_fq_opening_challenges_b_evals(const FqOpeningChallenges *c);
+
+const Fq *zexe_bn382_fq_opening_challenges_combined_b(const FqOpeningChallenges *c);
+
+const Fq *zexe_bn382_fq_opening_challenges_polyscale(const FqOpeningChallenges *c);
+
+const Fq *zexe_bn382_fq_opening_challenges_evalscale(const FqOpeningChallenges *c);
+
+void zexe_bn382_fq_opening_challenges_delete(FqOpeningChallenges *x);
+
+bool zexe_bn382_fq_proof_fast_path(const DlogIndex_GAffine *index, const Vec_Fq *auxiliary_input);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_create(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_create_with_public_input(const DlogIndex_GAffine *index, const FqPublicInput *public_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_create_with_seed(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs, const uint8_t *seed);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_create_deterministic(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs, const uint8_t *key, uintptr_t key_len);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_create_with_rng(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs, FillBytes fill_bytes);
+
+bool zexe_bn382_fq_proof_verify(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+bool zexe_bn382_fq_proof_verify_with_x_hat_comm(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof, const PolyComm_GAffine *x_hat_comm);
+
+int32_t zexe_bn382_fq_proof_verify_diagnostic(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+FqAccumulator *zexe_bn382_fq_proof_verify_accumulator(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+const GAffine *zexe_bn382_fq_accumulator_sg(const FqAccumulator *a);
+
+const Vec_Fq *zexe_bn382_fq_accumulator_challenges(const FqAccumulator *a);
+
+void zexe_bn382_fq_accumulator_delete(FqAccumulator *x);
+
+bool zexe_bn382_fq_proof_batch_verify(const Vec______DlogVerifierIndex_GAffine *indexes, const Vec_DlogProof_GAffine *proofs);
+
+bool zexe_bn382_fq_proof_batch_verify_shared(const DlogVerifierIndex_GAffine *index, const Vec_DlogProof_GAffine *proofs);
+
+FqVerifyPhase1 *zexe_bn382_fq_proof_verify_phase1(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+bool zexe_bn382_fq_proof_verify_phase2(const DlogVerifierIndex_GAffine *index, const Vec______FqVerifyPhase1 *results);
+
+Vec_u8 *zexe_bn382_fq_verify_phase1_to_bytes(const FqVerifyPhase1 *r);
+
+FqVerifyPhase1 *zexe_bn382_fq_verify_phase1_of_bytes(const DlogVerifierIndex_GAffine *index, const uint8_t *data, uintptr_t len);
+
+const DlogProof_GAffine *zexe_bn382_fq_verify_phase1_proof(const FqVerifyPhase1 *r);
+
+void zexe_bn382_fq_verify_phase1_delete(FqVerifyPhase1 *x);
+
+UrsDigest *zexe_bn382_fq_verifier_index_register(DlogVerifierIndex_GAffine *index);
+
+bool zexe_bn382_fq_verifier_index_unregister(const UrsDigest *digest);
+
+int32_t zexe_bn382_fq_proof_verify_bytes(const uint8_t *index_digest, const uint8_t *proof, uintptr_t proof_len, const uint8_t *public_input, uintptr_t public_input_len);
+
+int32_t zexe_bn382_fq_proof_verify_bytes_bounded(const uint8_t *index_digest, const uint8_t *proof, uintptr_t proof_len, const uint8_t *public_input, uintptr_t public_input_len, uintptr_t max_proof_bytes, uintptr_t max_public_inputs, uintptr_t max_lr_rounds);
+
+Vec_u8 *zexe_bn382_fq_proof_to_bytes(const DlogProof_GAffine *proof);
+
+const DlogProof_GAffine *zexe_bn382_fq_proof_make(const Vec_Fq *primary_input, const PolyComm_GAffine *w_comm, const PolyComm_GAffine *za_comm, const PolyComm_GAffine *zb_comm, const PolyComm_GAffine *h1_comm, const PolyComm_GAffine *g1_comm, const PolyComm_GAffine *h2_comm, const PolyComm_GAffine *g2_comm, const PolyComm_GAffine *h3_comm, const PolyComm_GAffine *g3_comm, const Fq *sigma2, const Fq *sigma3, const Vec_AffinePair_GAffine *lr, const Fq *z1, const Fq *z2, const GAffine *delta, const GAffine *sg, const DlogProofEvaluations_Fq *evals0, const DlogProofEvaluations_Fq *evals1, const DlogProofEvaluations_Fq *evals2, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+bool zexe_bn382_fq_proof_validate_structure(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+void zexe_bn382_fq_proof_delete(DlogProof_GAffine *x);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_w_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_za_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_zb_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_h1_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_g1_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_h2_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_g2_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_h3_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_bn382_fq_proof_g3_comm_nocopy(DlogProof_GAffine *p);
+
+const Fq *zexe_bn382_fq_proof_sigma2(DlogProof_GAffine *p);
+
+const Fq *zexe_bn382_fq_proof_sigma3(DlogProof_GAffine *p);
+
+const OpeningProof_GAffine *zexe_bn382_fq_proof_proof(DlogProof_GAffine *p);
+
+const DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evals_nocopy(DlogProof_GAffine *p);
+
+const Vec_Fq *zexe_bn382_fq_proof_prev_challenges(const DlogProof_GAffine *p);
+
+const Vec_GAffine *zexe_bn382_fq_proof_prev_sgs(const DlogProof_GAffine *p);
+
+Vec_DlogProof_GAffine *zexe_bn382_fq_proof_vector_create(void);
+
+int32_t zexe_bn382_fq_proof_vector_length(const Vec_DlogProof_GAffine *v);
+
+void zexe_bn382_fq_proof_vector_emplace_back(Vec_DlogProof_GAffine *v, const DlogProof_GAffine *x);
+
+DlogProof_GAffine *zexe_bn382_fq_proof_vector_get(Vec_DlogProof_GAffine *v, uint32_t i);
+
+void zexe_bn382_fq_proof_vector_delete(Vec_DlogProof_GAffine *x);
+
+void zexe_bn382_fq_opening_proof_delete(OpeningProof_GAffine *x);
+
+const GAffine *zexe_bn382_fq_opening_proof_sg(const OpeningProof_GAffine *p);
+
+const Vec_AffinePair_GAffine *zexe_bn382_fq_opening_proof_lr(const OpeningProof_GAffine *p);
+
+const Fq *zexe_bn382_fq_opening_proof_z1(const OpeningProof_GAffine *p);
+
+const Fq *zexe_bn382_fq_opening_proof_z2(const OpeningProof_GAffine *p);
+
+const GAffine *zexe_bn382_fq_opening_proof_delta(const OpeningProof_GAffine *p);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_w(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_za(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_zb(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_h1(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_h2(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_h3(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_g1(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_g2(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_g3(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_row_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_val_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_col_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_bn382_fq_proof_evaluations_rc_nocopy(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evaluations_triple_0(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evaluations_triple_1(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evaluations_triple_2(const DlogProofEvaluations_Fq *e);
+
+void zexe_bn382_fq_proof_evaluations_triple_delete(DlogProofEvaluations_Fq *x);
+
+const DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evaluations_make(const Vec_Fq *w, const Vec_Fq *za, const Vec_Fq *zb, const Vec_Fq *h1, const Vec_Fq *g1, const Vec_Fq *h2, const Vec_Fq *g2, const Vec_Fq *h3, const Vec_Fq *g3, const Vec_Fq *row_0, const Vec_Fq *row_1, const Vec_Fq *row_2, const Vec_Fq *col_0, const Vec_Fq *col_1, const Vec_Fq *col_2, const Vec_Fq *val_0, const Vec_Fq *val_1, const Vec_Fq *val_2, const Vec_Fq *rc_0, const Vec_Fq *rc_1, const Vec_Fq *rc_2);
+
+FqProofEvaluationsBuilder *zexe_bn382_fq_proof_evaluations_builder_create(void);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_w(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_za(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_zb(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_h1(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_g1(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_h2(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_g2(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_h3(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+void zexe_bn382_fq_proof_evaluations_builder_set_g3(FqProofEvaluationsBuilder *b, const Vec_Fq *v);
+
+bool zexe_bn382_fq_proof_evaluations_builder_set_row(FqProofEvaluationsBuilder *b, uintptr_t i, const Vec_Fq *v);
+
+bool zexe_bn382_fq_proof_evaluations_builder_set_col(FqProofEvaluationsBuilder *b, uintptr_t i, const Vec_Fq *v);
+
+bool zexe_bn382_fq_proof_evaluations_builder_set_val(FqProofEvaluationsBuilder *b, uintptr_t i, const Vec_Fq *v);
+
+bool zexe_bn382_fq_proof_evaluations_builder_set_rc(FqProofEvaluationsBuilder *b, uintptr_t i, const Vec_Fq *v);
+
+DlogProofEvaluations_Fq *zexe_bn382_fq_proof_evaluations_builder_finish(const FqProofEvaluationsBuilder *b);
+
+void zexe_bn382_fq_proof_evaluations_builder_delete(FqProofEvaluationsBuilder *x);
+
+void zexe_bn382_fq_proof_evaluations_delete(DlogProofEvaluations_Fq *x);
+
+const Vec_GAffine *zexe_bn382_fq_poly_comm_unshifted(const PolyComm_GAffine *c);
+
+const GAffine *zexe_bn382_fq_poly_comm_shifted(const PolyComm_GAffine *c);
+
+const PolyComm_GAffine *zexe_bn382_fq_poly_comm_make(const Vec_GAffine *unshifted, const GAffine *shifted);
+
+void zexe_bn382_fq_poly_comm_delete(PolyComm_GAffine *x);
+
+Vec_PolyComm_GAffine *zexe_bn382_fq_poly_comm_vector_create(void);
+
+int32_t zexe_bn382_fq_poly_comm_vector_length(const Vec_PolyComm_GAffine *v);
+
+void zexe_bn382_fq_poly_comm_vector_emplace_back(Vec_PolyComm_GAffine *v, const PolyComm_GAffine *x);
+
+PolyComm_GAffine *zexe_bn382_fq_poly_comm_vector_get(Vec_PolyComm_GAffine *v, uint32_t i);
+
+void zexe_bn382_fq_poly_comm_vector_delete(Vec_PolyComm_GAffine *x);
+
+const GAffine *zexe_bn382_g_of_bytes_hash(const uint8_t *msg, uintptr_t len);
+
+const G1Affine *zexe_bn382_g1_of_bytes_hash(const uint8_t *msg, uintptr_t len);
+
+const Vec_GAffine *zexe_bn382_g_hash_generators(const uint8_t *msg, uintptr_t len, uintptr_t n);
+
+const Vec_G1Affine *zexe_bn382_g1_hash_generators(const uint8_t *msg, uintptr_t len, uintptr_t n);
+
+const G1Affine *zexe_bn382_fp_kzg_commit(const URS_Bn_382 *urs, const Vec_Fp *coeffs);
+
+const KzgOpening *zexe_bn382_fp_kzg_open(const URS_Bn_382 *urs, const Vec_Fp *coeffs, const Fp *point);
+
+bool zexe_bn382_fp_kzg_verify(const URS_Bn_382 *urs, const G1Affine *comm, const KzgOpening *opening);
+
+bool zexe_bn382_fp_kzg_batch_verify(const URS_Bn_382 *urs, const Vec_G1Affine *comms, const Vec_KzgOpening *openings);
+
+const G1Affine *zexe_bn382_fp_kzg_commit_shifted(const URS_Bn_382 *urs, const Vec_Fp *coeffs, uintptr_t bound);
+
+bool zexe_bn382_fp_kzg_verify_shifted(const URS_Bn_382 *urs, const G1Affine *comm, const G1Affine *shifted, uintptr_t bound);
+
+bool zexe_bn382_fp_kzg_batch_verify_shifted(const URS_Bn_382 *urs, const Vec_G1Affine *comms, const Vec_G1Affine *shifted, const Vec_usize *bounds);
+
+const KzgOpening *zexe_bn382_fp_kzg_opening_make(const Fp *point, const Fp *value, const G1Affine *proof);
+
+const Fp *zexe_bn382_fp_kzg_opening_point(const KzgOpening *o);
+
+const Fp *zexe_bn382_fp_kzg_opening_value(const KzgOpening *o);
+
+const G1Affine *zexe_bn382_fp_kzg_opening_proof(const KzgOpening *o);
+
+void zexe_bn382_fp_kzg_opening_delete(KzgOpening *x);
+
+Vec_u8 *zexe_bn382_fp_kzg_opening_to_bytes(const KzgOpening *o);
+
+const KzgOpening *zexe_bn382_fp_kzg_opening_of_bytes(const uint8_t *data, uintptr_t len);
+
+Vec_KzgOpening *zexe_bn382_fp_kzg_opening_vector_create(void);
+
+int32_t zexe_bn382_fp_kzg_opening_vector_length(const Vec_KzgOpening *v);
+
+void zexe_bn382_fp_kzg_opening_vector_emplace_back(Vec_KzgOpening *v, const KzgOpening *x);
+
+const KzgOpening *zexe_bn382_fp_kzg_opening_vector_get(const Vec_KzgOpening *v, uint32_t i);
+
+void zexe_bn382_fp_kzg_opening_vector_delete(Vec_KzgOpening *x);
+
+const Fq *zexe_bn382_fp_endo_base(void);
+
+const Fp *zexe_bn382_fp_endo_scalar(void);
+
+int32_t zexe_bn382_fp_size_in_bits(void);
+
+BigInteger384 *zexe_bn382_fp_size(void);
+
+BigInteger384 *zexe_bn382_fp_characteristic(void);
+
+int32_t zexe_bn382_fp_size_in_bytes(void);
+
+void zexe_bn382_fp_modulus_bytes(uint8_t *out);
+
+Fp *zexe_bn382_fp_zero(void);
+
+Fp *zexe_bn382_fp_one(void);
+
+Fp *zexe_bn382_fp_multiplicative_generator(void);
+
+bool zexe_bn382_fp_is_square(const Fp *x);
+
+Fp *zexe_bn382_fp_sqrt(const Fp *x);
+
+Fq *zexe_bn382_fq_det_sqrt(const Fq *x);
+
+DetSqrtWitness_Fq zexe_bn382_fq_det_sqrt_witness(const Fq *x);
+
+Fp *zexe_bn382_fp_two_adic_root_of_unity(void);
+
+Fp *zexe_bn382_fp_random(void);
+
+Fp *zexe_bn382_fp_of_int(uint64_t i);
+
+Fp *zexe_bn382_fp_domain_generator(uintptr_t log2_size);
+
+char *zexe_bn382_fp_to_string(const Fp *x);
+
+Fp *zexe_bn382_fp_of_decimal_string(const char *s);
+
+Fp *zexe_bn382_fp_of_hex_string(const char *s);
+
+void zexe_bn382_fp_to_bytes(const Fp *x, uint8_t *out);
+
+Fp *zexe_bn382_fp_of_bytes(const uint8_t *bytes, uintptr_t len);
+
+Fp *zexe_bn382_fp_inv(const Fp *x);
+
+Fp *zexe_bn382_fp_square(const Fp *x);
+
+Fp *zexe_bn382_fp_add(const Fp *x, const Fp *y);
+
+Fp *zexe_bn382_fp_negate(const Fp *x);
+
+Fp *zexe_bn382_fp_mul(const Fp *x, const Fp *y);
+
+Fp *zexe_bn382_fp_div(const Fp *x, const Fp *y);
+
+Fp *zexe_bn382_fp_sub(const Fp *x, const Fp *y);
+
+void zexe_bn382_fp_mut_add(Fp *x, const Fp *y);
+
+void zexe_bn382_fp_mut_mul(Fp *x, const Fp *y);
+
+void zexe_bn382_fp_mut_square(Fp *x);
+
+void zexe_bn382_fp_mut_sub(Fp *x, const Fp *y);
+
+void zexe_bn382_fp_copy(Fp *x, const Fp *y);
+
+Fp *zexe_bn382_fp_rng(int32_t i);
+
+void zexe_bn382_fp_delete(Fp *x);
+
+void zexe_bn382_fp_print(const Fp *x);
+
+bool zexe_bn382_fp_equal(const Fp *x, const Fp *y);
+
+bool zexe_bn382_fp_is_zero(const Fp *x);
+
+bool zexe_bn382_fp_is_one(const Fp *x);
+
+bool zexe_bn382_fp_to_u64_checked(const Fp *x, uint64_t *out);
+
+BigInteger384 *zexe_bn382_fp_to_bigint(const Fp *x);
+
+Fp *zexe_bn382_fp_of_bigint(const BigInteger384 *x);
+
+const BigInteger384 *zexe_bn382_fp_to_bigint_raw(const Fp *x);
+
+const BigInteger384 *zexe_bn382_fp_to_bigint_raw_noalloc(const Fp *x);
+
+Fp *zexe_bn382_fp_of_bigint_raw(const BigInteger384 *x);
+
+Vec_Fq *zexe_bn382_fp_to_fq_chunks(const Fp *x, uintptr_t chunk_bits);
+
+Fp *zexe_bn382_fp_of_fq_chunks(const Vec_Fq *v, uintptr_t chunk_bits);
+
+Vec_Fp *zexe_bn382_fp_vector_create(void);
+
+int32_t zexe_bn382_fp_vector_length(const Vec_Fp *v);
+
+void zexe_bn382_fp_vector_emplace_back(Vec_Fp *v, const Fp *x);
+
+Fp *zexe_bn382_fp_vector_get(Vec_Fp *v, uint32_t i);
+
+void zexe_bn382_fp_vector_delete(Vec_Fp *x);
+
+Vec_Fp *zexe_bn382_fp_vector_batch_inverse(const Vec_Fp *v);
+
+Vec_Fp *zexe_bn382_fp_vector_of_raw(const uint64_t *limbs, uintptr_t len);
+
+void zexe_bn382_fp_vector_to_raw(const Vec_Fp *v, uint64_t *limbs);
+
+Vec_Fp *zexe_bn382_fp_vector_random(uintptr_t n, const uint8_t *seed);
+
+Vec_Row_Fp *zexe_bn382_fp_constraint_matrix_create(void);
+
+void zexe_bn382_fp_constraint_matrix_append_row(Vec_Row_Fp *m, Vec_usize *indices, Vec_Fp *coefficients);
+
+bool zexe_bn382_fp_constraint_matrix_append_csr(Vec_Row_Fp *m, const uintptr_t *row_ptrs, uintptr_t rows, const uintptr_t *cols, const uint64_t *values, uintptr_t nnz);
+
+void zexe_bn382_fp_constraint_matrix_delete(Vec_Row_Fp *x);
+
+const Fp *zexe_bn382_fp_triple_0(const Fp *evals);
+
+const Fp *zexe_bn382_fp_triple_1(const Fp *evals);
+
+const Fp *zexe_bn382_fp_triple_2(const Fp *evals);
+
+const Vec_Fp *zexe_bn382_fp_vector_triple_0(const Vec_Fp *evals);
+
+const Vec_Fp *zexe_bn382_fp_vector_triple_1(const Vec_Fp *evals);
+
+const Vec_Fp *zexe_bn382_fp_vector_triple_2(const Vec_Fp *evals);
+
+void zexe_bn382_fp_triple_delete(Fp *x);
+
+void zexe_bn382_fp_vector_triple_delete(Vec_Fp *x);
+
+bool zexe_bn382_batch_pairing_check(const URS_Bn_382 *urs, const Vec_usize *d, const Vec_G1Affine *s, const Vec_G1Affine *u, const Vec_G1Affine *t, const Vec_G1Affine *p);
+
+bool zexe_bn382_fp_urs_batch_opening_check(const URS_Bn_382 *urs, const Vec_Fp *points, const Vec_G1Affine *comms, const Vec_Fp *values, const Vec_G1Affine *proofs, const Fp *xi, const Fp *r);
+
+const AffinePair_G1Affine *zexe_bn382_fp_urs_opening_accumulator(const URS_Bn_382 *urs, const Vec_Fp *points, const Vec_G1Affine *comms, const Vec_Fp *values, const Vec_G1Affine *proofs, const Fp *xi, const Fp *r);
+
+bool zexe_bn382_fp_urs_opening_accumulator_check(const URS_Bn_382 *urs, const AffinePair_G1Affine *acc);
+
+const Vec_G1Affine *zexe_bn382_fp_urs_degree_bound_accumulator(const URS_Bn_382 *urs, const Vec_usize *bounds, const Vec_G1Affine *unshifted, const Vec_G1Affine *shifted, const Fp *r);
+
+const Vec_G1Affine *zexe_bn382_fp_urs_degree_bound_accumulator_randomized(const URS_Bn_382 *urs, const Vec_usize *bounds, const Vec_G1Affine *unshifted, const Vec_G1Affine *shifted, const Vec_Fp *randomizers);
+
+DegreeBoundAccumulator *zexe_bn382_fp_urs_degree_bound_accumulator_sampled(const URS_Bn_382 *urs, const Vec_usize *bounds, const Vec_G1Affine *unshifted, const Vec_G1Affine *shifted);
+
+const Vec_G1Affine *zexe_bn382_fp_degree_bound_accumulator_points(const DegreeBoundAccumulator *a);
+
+const Vec_Fp *zexe_bn382_fp_degree_bound_accumulator_randomizers(const DegreeBoundAccumulator *a);
+
+void zexe_bn382_fp_degree_bound_accumulator_delete(DegreeBoundAccumulator *x);
+
+bool zexe_bn382_fp_urs_degree_bound_accumulator_check(const URS_Bn_382 *urs, const Vec_usize *bounds, const Vec_G1Affine *acc);
+
+PairingAccumulators *zexe_bn382_fp_urs_pairing_accumulators(const URS_Bn_382 *urs, const Vec_Fp *points, const Vec_G1Affine *comms, const Vec_Fp *values, const Vec_G1Affine *proofs, const Vec_usize *bounds, const Vec_G1Affine *unshifted, const Vec_G1Affine *shifted, const Fp *xi, const Fp *r);
+
+const AffinePair_G1Affine *zexe_bn382_fp_pairing_accumulators_opening(const PairingAccumulators *acc);
+
+const Vec_G1Affine *zexe_bn382_fp_pairing_accumulators_degree_bounds(const PairingAccumulators *acc);
+
+bool zexe_bn382_fp_pairing_accumulators_check(const URS_Bn_382 *urs, const PairingAccumulators *acc);
+
+void zexe_bn382_fp_pairing_accumulators_delete(PairingAccumulators *x);
+
+bool zexe_bn382_fp_proof_fast_path(const Index_Bn_382 *index, const Vec_Fp *auxiliary_input);
+
+const ProverProof_Bn_382 *zexe_bn382_fp_proof_create(const Index_Bn_382 *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+bool zexe_bn382_fp_proof_batch_verify(const VerifierIndex_Bn_382 *index, const Vec_ProverProof_Bn_382 *proofs);
+
+bool zexe_bn382_fp_proof_verify(const VerifierIndex_Bn_382 *index, const ProverProof_Bn_382 *proof);
+
+int32_t zexe_bn382_fp_proof_verify_diagnostic(const VerifierIndex_Bn_382 *index, const ProverProof_Bn_382 *proof);
+
+const ProverProof_Bn_382 *zexe_bn382_fp_proof_make(const Vec_Fp *primary_input, const G1Affine *w_comm, const G1Affine *za_comm, const G1Affine *zb_comm, const G1Affine *h1_comm, const G1Affine *g1_comm_0, const G1Affine *g1_comm_1, const G1Affine *h2_comm, const G1Affine *g2_comm_0, const G1Affine *g2_comm_1, const G1Affine *h3_comm, const G1Affine *g3_comm_0, const G1Affine *g3_comm_1, const G1Affine *proof1, const G1Affine *proof2, const G1Affine *proof3, const Fp *sigma2, const Fp *sigma3, const Fp *w, const Fp *za, const Fp *zb, const Fp *h1, const Fp *g1, const Fp *h2, const Fp *g2, const Fp *h3, const Fp *g3, const Fp *row_0, const Fp *row_1, const Fp *row_2, const Fp *col_0, const Fp *col_1, const Fp *col_2, const Fp *val_0, const Fp *val_1, const Fp *val_2, const Fp *rc_0, const Fp *rc_1, const Fp *rc_2);
+
+bool zexe_bn382_fp_proof_validate_structure(const VerifierIndex_Bn_382 *index, const ProverProof_Bn_382 *proof);
+
+bool zexe_bn382_fp_proof_write(const ProverProof_Bn_382 *proof, const char *path);
+
+const ProverProof_Bn_382 *zexe_bn382_fp_proof_read(const char *path);
+
+Vec_u8 *zexe_bn382_fp_proof_to_bytes(const ProverProof_Bn_382 *proof);
+
+const ProverProof_Bn_382 *zexe_bn382_fp_proof_of_bytes(const uint8_t *data, uintptr_t len);
+
+void zexe_bn382_fp_proof_delete(ProverProof_Bn_382 *x);
+
+const G1Affine *zexe_bn382_fp_proof_w_comm(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_za_comm(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_zb_comm(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_h1_comm(ProverProof_Bn_382 *p);
+
+const AffinePair_G1Affine *zexe_bn382_fp_proof_g1_comm_nocopy(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_h2_comm(ProverProof_Bn_382 *p);
+
+const AffinePair_G1Affine *zexe_bn382_fp_proof_g2_comm_nocopy(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_h3_comm(ProverProof_Bn_382 *p);
+
+const AffinePair_G1Affine *zexe_bn382_fp_proof_g3_comm_nocopy(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_commitment_with_degree_bound_0(const AffinePair_G1Affine *p);
+
+const G1Affine *zexe_bn382_fp_proof_commitment_with_degree_bound_1(const AffinePair_G1Affine *p);
+
+void zexe_bn382_fp_proof_commitment_with_degree_bound_delete(G1Affine *x);
+
+const G1Affine *zexe_bn382_fp_proof_proof1(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_proof2(ProverProof_Bn_382 *p);
+
+const G1Affine *zexe_bn382_fp_proof_proof3(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_sigma2(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_sigma3(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_w_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_za_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_zb_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_h1_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_g1_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_h2_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_g2_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_h3_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_g3_eval(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_row_evals_nocopy(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_col_evals_nocopy(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_val_evals_nocopy(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_rc_evals_nocopy(ProverProof_Bn_382 *p);
+
+const Fp *zexe_bn382_fp_proof_evals_0(const Fp *evals);
+
+const Fp *zexe_bn382_fp_proof_evals_1(const Fp *evals);
+
+const Fp *zexe_bn382_fp_proof_evals_2(const Fp *evals);
+
+void zexe_bn382_fp_proof_evals_delete(Fp *x);
+
+Vec_ProverProof_Bn_382 *zexe_bn382_fp_proof_vector_create(void);
+
+int32_t zexe_bn382_fp_proof_vector_length(const Vec_ProverProof_Bn_382 *v);
+
+void zexe_bn382_fp_proof_vector_emplace_back(Vec_ProverProof_Bn_382 *v, const ProverProof_Bn_382 *x);
+
+ProverProof_Bn_382 *zexe_bn382_fp_proof_vector_get(Vec_ProverProof_Bn_382 *v, uint32_t i);
+
+void zexe_bn382_fp_proof_vector_delete(Vec_ProverProof_Bn_382 *x);
+
+const RandomOracles_Fp *zexe_bn382_fp_oracles_create(const VerifierIndex_Bn_382 *index, const ProverProof_Bn_382 *proof);
+
+const Fp *zexe_bn382_fp_oracles_alpha(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_eta_a(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_eta_b(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_eta_c(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_beta1(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_beta2(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_beta3(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_r_k(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_batch(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_r(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_x_hat_beta1(const RandomOracles_Fp *oracles);
+
+const Fp *zexe_bn382_fp_oracles_digest_before_evaluations(const RandomOracles_Fp *oracles);
+
+void zexe_bn382_fp_oracles_delete(RandomOracles_Fp *x);
+
+const VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_create(const Index_Bn_382 *index);
+
+const URS_Bn_382 *zexe_bn382_fp_verifier_index_urs(const VerifierIndex_Bn_382 *index);
+
+const VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_make(uintptr_t public_inputs, uintptr_t variables, uintptr_t constraints, uintptr_t nonzero_entries, uintptr_t max_degree, const URS_Bn_382 *urs, const G1Affine *row_a, const G1Affine *col_a, const G1Affine *val_a, const G1Affine *rc_a, const G1Affine *row_b, const G1Affine *col_b, const G1Affine *val_b, const G1Affine *rc_b, const G1Affine *row_c, const G1Affine *col_c, const G1Affine *val_c, const G1Affine *rc_c);
+
+void zexe_bn382_fp_verifier_index_delete(VerifierIndex_Bn_382 *x);
+
+void zexe_bn382_fp_verifier_index_write(const VerifierIndex_Bn_382 *index, const char *path);
+
+const VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_read(const char *path);
+
+Vec_u8 *zexe_bn382_fp_verifier_index_to_bytes(const VerifierIndex_Bn_382 *index);
+
+const VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_of_bytes(const uint8_t *data, uintptr_t len);
+
+const G1Affine *zexe_bn382_fp_verifier_index_a_row_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_a_col_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_a_val_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_a_rc_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_b_row_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_b_col_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_b_val_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_b_rc_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_c_row_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_c_col_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_c_val_comm(const VerifierIndex_Bn_382 *index);
+
+const G1Affine *zexe_bn382_fp_verifier_index_c_rc_comm(const VerifierIndex_Bn_382 *index);
+
+const Vec______VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_vector_create(void);
+
+int32_t zexe_bn382_fp_verifier_index_vector_length(const Vec______VerifierIndex_Bn_382 *v);
+
+void zexe_bn382_fp_verifier_index_vector_emplace_back(Vec______VerifierIndex_Bn_382 *v, const VerifierIndex_Bn_382 *x);
+
+const VerifierIndex_Bn_382 *zexe_bn382_fp_verifier_index_vector_get(Vec______VerifierIndex_Bn_382 *v, uint32_t i);
+
+void zexe_bn382_fp_verifier_index_vector_delete(Vec______VerifierIndex_Bn_382 *x);
+
+const URS_Bn_382 *zexe_bn382_fp_urs_create(uintptr_t depth);
+
+void zexe_bn382_fp_urs_delete(URS_Bn_382 *x);
+
+void zexe_bn382_fp_urs_write(URS_Bn_382 *urs, char *path);
+
+const URS_Bn_382 *zexe_bn382_fp_urs_read(char *path);
+
+UrsDigest *zexe_bn382_fp_urs_digest(const URS_Bn_382 *urs);
+
+UrsDigest *zexe_bn382_fp_urs_write_with_digest(const URS_Bn_382 *urs, char *path);
+
+PointerPair_URS_Bn_382_____UrsDigest zexe_bn382_fp_urs_read_with_digest(char *path);
+
+const G1Affine *zexe_bn382_fp_urs_lagrange_commitment(const URS_Bn_382 *urs, uintptr_t domain_size, uintptr_t i);
+
+const Vec_G1Affine *zexe_bn382_fp_urs_lagrange_commitments(const URS_Bn_382 *urs, uintptr_t domain_size);
+
+const FixedCommitments_G1Affine *zexe_bn382_fp_urs_fixed_commitments_create(const URS_Bn_382 *urs, uintptr_t h_size, uintptr_t k_size);
+
+const G1Affine *zexe_bn382_fp_urs_fixed_commitments_z_h(const FixedCommitments_G1Affine *c);
+
+const G1Affine *zexe_bn382_fp_urs_fixed_commitments_z_k(const FixedCommitments_G1Affine *c);
+
+const G1Affine *zexe_bn382_fp_urs_fixed_commitments_monomial(const FixedCommitments_G1Affine *c, uintptr_t i);
+
+void zexe_bn382_fp_urs_fixed_commitments_delete(FixedCommitments_G1Affine *x);
+
+const G1Affine *zexe_bn382_fp_urs_commit_evaluations(const URS_Bn_382 *urs, uintptr_t domain_size, const Vec_Fp *evals);
+
+const Vec_G1Affine *zexe_bn382_fp_urs_commit_evaluations_batch(const URS_Bn_382 *urs, uintptr_t domain_size, const Vec______Vec_Fp *evals);
+
+const DegreeBoundedCommitment *zexe_bn382_fp_urs_commit_with_degree_bound(const URS_Bn_382 *urs, const Vec_Fp *coefficients, uintptr_t bound);
+
+bool zexe_bn382_fp_urs_check_degree_bound(const URS_Bn_382 *urs, const DegreeBoundedCommitment *c, uintptr_t bound);
+
+const DegreeBoundedCommitment *zexe_bn382_fp_degree_bounded_commitment_make(const G1Affine *unshifted, const G1Affine *shifted);
+
+const G1Affine *zexe_bn382_fp_degree_bounded_commitment_unshifted(const DegreeBoundedCommitment *c);
+
+const G1Affine *zexe_bn382_fp_degree_bounded_commitment_shifted(const DegreeBoundedCommitment *c);
+
+void zexe_bn382_fp_degree_bounded_commitment_delete(DegreeBoundedCommitment *x);
+
+const Vec_G1Affine *zexe_bn382_fp_urs_dummy_degree_bound_checks(const URS_Bn_382 *urs, const Vec_usize *bounds);
+
+const AffinePair_G1Affine *zexe_bn382_fp_urs_dummy_opening_check(const URS_Bn_382 *urs);
+
+uintptr_t zexe_bn382_fp_index_domain_h_size(const Index_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_index_domain_k_size(const Index_Bn_382 *i);
+
+Index_Bn_382 *zexe_bn382_fp_index_create(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t vars, uintptr_t public_inputs, URS_Bn_382 *urs);
+
+Index_Bn_382 *zexe_bn382_fp_index_create_streaming(uintptr_t rows, uintptr_t vars, uintptr_t public_inputs, URS_Bn_382 *urs, RowSource source, void *ctx);
+
+void zexe_bn382_fp_index_delete(Index_Bn_382 *x);
+
+uintptr_t zexe_bn382_fp_index_nonzero_entries(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_nonzero_entries_a(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_nonzero_entries_b(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_nonzero_entries_c(const Index_Bn_382 *index);
+
+Csr_Fp *zexe_bn382_fp_index_csr(const Index_Bn_382 *index, uintptr_t matrix);
+
+uintptr_t zexe_bn382_fp_csr_rows(const Csr_Fp *m);
+
+uintptr_t zexe_bn382_fp_csr_cols(const Csr_Fp *m);
+
+Vec_usize *zexe_bn382_fp_csr_indptr(const Csr_Fp *m);
+
+Vec_usize *zexe_bn382_fp_csr_indices(const Csr_Fp *m);
+
+Vec_Fp *zexe_bn382_fp_csr_values(const Csr_Fp *m);
+
+void zexe_bn382_fp_csr_delete(Csr_Fp *x);
+
+uintptr_t zexe_bn382_fp_index_domain_k_padded_size(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_max_degree(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_num_variables(const Index_Bn_382 *index);
+
+uintptr_t zexe_bn382_fp_index_public_inputs(const Index_Bn_382 *index);
+
+void zexe_bn382_fp_index_write(const Index_Bn_382 *index, const char *path);
+
+const Index_Bn_382 *zexe_bn382_fp_index_read(const URS_Bn_382 *srs, const char *path);
+
+const G1Projective *zexe_bn382_g1_random(void);
+
+void zexe_bn382_g1_delete(G1Projective *x);
+
+const G1Projective *zexe_bn382_g1_one(void);
+
+const G1Projective *zexe_bn382_g1_add(const G1Projective *x, const G1Projective *y);
+
+const G1Projective *zexe_bn382_g1_double(const G1Projective *x);
+
+const G1Projective *zexe_bn382_g1_scale(const G1Projective *x, const Fp *s);
+
+const Vec_G1Projective *zexe_bn382_g1_scale_many(const G1Projective *x, const Vec_Fp *s);
+
+const G1Projective *zexe_bn382_g1_sub(const G1Projective *x, const G1Projective *y);
+
+const G1Projective *zexe_bn382_g1_negate(const G1Projective *x);
+
+const G1Affine *zexe_bn382_g1_to_affine(const G1Projective *p);
+
+const G1Projective *zexe_bn382_g1_of_affine(const G1Affine *p);
+
+const G1Projective *zexe_bn382_g1_of_affine_coordinates(const Fq *x, const Fq *y);
+
+const G1Affine *zexe_bn382_g1_affine_create(const Fq *x, const Fq *y);
+
+const G1Affine *zexe_bn382_g1_affine_create_infinity(void);
+
+const G1Affine *zexe_bn382_g1_affine_create_checked(const Fq *x, const Fq *y);
+
+bool zexe_bn382_g1_affine_is_on_curve(const G1Affine *p);
+
+bool zexe_bn382_g1_affine_is_in_correct_subgroup(const G1Affine *p);
+
+bool zexe_bn382_g1_affine_is_infinity(const G1Affine *p);
+
+const Fq *zexe_bn382_g1_affine_x(const G1Affine *p);
+
+const Fq *zexe_bn382_g1_affine_y(const G1Affine *p);
+
+bool zexe_bn382_g1_affine_is_zero(const G1Affine *p);
+
+void zexe_bn382_g1_affine_delete(G1Affine *x);
+
+Vec_G1Projective *zexe_bn382_g1_vector_create(void);
+
+int32_t zexe_bn382_g1_vector_length(const Vec_G1Projective *v);
+
+void zexe_bn382_g1_vector_emplace_back(Vec_G1Projective *v, const G1Projective *x);
+
+G1Projective *zexe_bn382_g1_vector_get(Vec_G1Projective *v, uint32_t i);
+
+void zexe_bn382_g1_vector_delete(Vec_G1Projective *x);
+
+Vec_G1Affine *zexe_bn382_g1_affine_vector_create(void);
+
+int32_t zexe_bn382_g1_affine_vector_length(const Vec_G1Affine *v);
+
+void zexe_bn382_g1_affine_vector_emplace_back(Vec_G1Affine *v, const G1Affine *x);
+
+G1Affine *zexe_bn382_g1_affine_vector_get(Vec_G1Affine *v, uint32_t i);
+
+void zexe_bn382_g1_affine_vector_delete(Vec_G1Affine *x);
+
+const G1Affine *zexe_bn382_g1_affine_vector_sum(const Vec_G1Affine *v);
+
+const G1Affine *zexe_bn382_g1_affine_vector_linear_combination(const Vec_G1Affine *v, const Vec_Fp *s);
+
+ArithmeticSpongeParams_Fq *zexe_bn382_fq_sponge_params_create(void);
+
+void zexe_bn382_fq_sponge_params_delete(ArithmeticSpongeParams_Fq *x);
+
+ArithmeticSponge_Fq__SC *zexe_bn382_fq_sponge_create(void);
+
+void zexe_bn382_fq_sponge_delete(ArithmeticSponge_Fq__SC *x);
+
+void zexe_bn382_fq_sponge_absorb(ArithmeticSponge_Fq__SC *sponge, const ArithmeticSpongeParams_Fq *params, const Fq *x);
+
+void zexe_bn382_fq_sponge_absorb_vector(ArithmeticSponge_Fq__SC *sponge, const ArithmeticSpongeParams_Fq *params, const Vec_Fq *v);
+
+void zexe_bn382_fq_sponge_absorb_raw(ArithmeticSponge_Fq__SC *sponge, const ArithmeticSpongeParams_Fq *params, const Fq *xs, uintptr_t len);
+
+Fq *zexe_bn382_fq_sponge_squeeze(ArithmeticSponge_Fq__SC *sponge, const ArithmeticSpongeParams_Fq *params);
+
+Vec_u8 *zexe_bn382_fq_sponge_to_bytes(const ArithmeticSponge_Fq__SC *sponge);
+
+ArithmeticSponge_Fq__SC *zexe_bn382_fq_sponge_of_bytes(const uint8_t *data, uintptr_t len);
+
+const G1Affine *zexe_bn382_g1_affine_pair_0(const AffinePair_G1Affine *p);
+
+const G1Affine *zexe_bn382_g1_affine_pair_1(const AffinePair_G1Affine *p);
+
+const AffinePair_G1Affine *zexe_bn382_g1_affine_pair_make(const G1Affine *x0, const G1Affine *x1);
+
+void zexe_bn382_g1_affine_pair_delete(AffinePair_G1Affine *x);
+
+Vec_AffinePair_G1Affine *zexe_bn382_g1_affine_pair_vector_create(void);
+
+int32_t zexe_bn382_g1_affine_pair_vector_length(const Vec_AffinePair_G1Affine *v);
+
+void zexe_bn382_g1_affine_pair_vector_emplace_back(Vec_AffinePair_G1Affine *v, const AffinePair_G1Affine *x);
+
+AffinePair_G1Affine *zexe_bn382_g1_affine_pair_vector_get(Vec_AffinePair_G1Affine *v, uint32_t i);
+
+void zexe_bn382_g1_affine_pair_vector_delete(Vec_AffinePair_G1Affine *x);
+
+PairVectorCursor_G1Affine *zexe_bn382_g1_affine_pair_vector_cursor_create(const Vec_AffinePair_G1Affine *v);
+
+const AffinePair_G1Affine *zexe_bn382_g1_affine_pair_vector_cursor_next(PairVectorCursor_G1Affine *c);
+
+void zexe_bn382_g1_affine_pair_vector_cursor_delete(PairVectorCursor_G1Affine *x);
+
+char *zexe_bn382_fq_transcript_report(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+void zexe_bn382_fq_transcript_report_delete(char *s);
+
+Vec_GAffine *zexe_bn382_fq_transcript_commitments(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+FqTranscript *zexe_bn382_fq_transcript_record_verify(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+FqRecordedProof *zexe_bn382_fq_proof_create_recorded(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+DlogProof_GAffine *zexe_bn382_fq_recorded_proof_proof(const FqRecordedProof *p);
+
+FqTranscript *zexe_bn382_fq_recorded_proof_transcript(const FqRecordedProof *p);
+
+void zexe_bn382_fq_recorded_proof_delete(FqRecordedProof *x);
+
+uintptr_t zexe_bn382_fq_transcript_length(const FqTranscript *t);
+
+char *zexe_bn382_fq_transcript_to_json(const FqTranscript *t);
+
+int64_t zexe_bn382_fq_transcript_first_divergence(const FqTranscript *a, const FqTranscript *b);
+
+char *zexe_bn382_fq_transcript_compare(const FqTranscript *a, const FqTranscript *b);
+
+void zexe_bn382_fq_transcript_delete(FqTranscript *x);
+
+const Fq *zexe_bn382_vrf_secret_key_random(void);
+
+const GAffine *zexe_bn382_vrf_public_key(const Fq *sk);
+
+const VrfProof *zexe_bn382_vrf_prove(const Fq *sk, const Vec_Fp *msg);
+
+bool zexe_bn382_vrf_verify(const GAffine *pk, const Vec_Fp *msg, const VrfProof *proof);
+
+const Fp *zexe_bn382_vrf_proof_output(const VrfProof *proof);
+
+const Fp *zexe_bn382_vrf_verify_output(const GAffine *pk, const Vec_Fp *msg, const VrfProof *proof);
+
+const VrfProof *zexe_bn382_vrf_proof_make(const GAffine *gamma, const Fq *c, const Fq *s);
+
+const GAffine *zexe_bn382_vrf_proof_gamma(const VrfProof *p);
+
+const Fq *zexe_bn382_vrf_proof_c(const VrfProof *p);
+
+const Fq *zexe_bn382_vrf_proof_s(const VrfProof *p);
+
+void zexe_bn382_vrf_proof_delete(VrfProof *x);
+
+Vec_u8 *zexe_bn382_vrf_proof_to_bytes(const VrfProof *p);
+
+const VrfProof *zexe_bn382_vrf_proof_of_bytes(const uint8_t *data, uintptr_t len);
+
+const char *zexe_last_error(void);
+
+void zexe_clear_last_error(void);
+
+void zexe_rng_mix_seed(const uint8_t *seed, uintptr_t len);
+
+bool zexe_rng_health_check(void);
+
+char *zexe_rng_source(void);
+
+uintptr_t zexe_live_handles(void);
+
+const char *zexe_verify_failure_description(int32_t code);
+
+bool zexe_bn382_set_num_threads(uintptr_t n);
+
+uintptr_t zexe_bn382_get_num_threads(void);
+
+void zexe_set_print_callback(void (*f)(const char*));
+
+uintptr_t zexe_bn382_max_domain_size_log2(void);
+
+uintptr_t zexe_bn382_max_circuit_size(void);
+
+void zexe_string_delete(char *s);
+
+Vec_usize *zexe_usize_vector_create(void);
+
+int32_t zexe_usize_vector_length(const Vec_usize *v);
+
+void zexe_usize_vector_emplace_back(Vec_usize *v, uintptr_t x);
+
+uintptr_t zexe_usize_vector_get(Vec_usize *v, uint32_t i);
+
+void zexe_usize_vector_delete(Vec_usize *x);
+
+uintptr_t zexe_byte_vector_length(const Vec_u8 *v);
+
+const uint8_t *zexe_byte_vector_data(const Vec_u8 *v);
+
+void zexe_byte_vector_delete(Vec_u8 *x);
+
+const Vec______c_void *zexe_pointer_vector_create(void);
+
+int32_t zexe_pointer_vector_length(const Vec______c_void *v);
+
+void zexe_pointer_vector_emplace_back(Vec______c_void *v, const void *x);
+
+const void *zexe_pointer_vector_get(Vec______c_void *v, uint32_t i);
+
+void zexe_pointer_vector_delete(Vec______c_void *x);
+
+int32_t zexe_urs_digest_size(void);
+
+const uint8_t *zexe_urs_digest_to_data(const UrsDigest *d);
+
+bool zexe_urs_digest_equal(const UrsDigest *x, const UrsDigest *y);
+
+void zexe_urs_digest_delete(UrsDigest *x);
+
+char *zexe_parameter_report(void);
+
+void zexe_parameter_report_delete(char *s);
+
+Fp *zexe_bn382_fp_index_domain_x_generator(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_x_generator_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_x_size_inv(const Index_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_index_domain_x_size(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_h_generator(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_h_generator_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_h_size_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_k_generator(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_k_generator_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_k_size_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_b_generator(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_b_generator_inv(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_index_domain_b_size_inv(const Index_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_index_domain_b_size(const Index_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_x_generator(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_x_generator_inv(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_x_size_inv(const VerifierIndex_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_verifier_index_domain_x_size(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_h_generator(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_h_generator_inv(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_h_size_inv(const VerifierIndex_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_verifier_index_domain_h_size(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_k_generator(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_k_generator_inv(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_k_size_inv(const VerifierIndex_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_verifier_index_domain_k_size(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_b_generator(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_b_generator_inv(const VerifierIndex_Bn_382 *i);
+
+Fp *zexe_bn382_fp_verifier_index_domain_b_size_inv(const VerifierIndex_Bn_382 *i);
+
+uintptr_t zexe_bn382_fp_verifier_index_domain_b_size(const VerifierIndex_Bn_382 *i);
+
+Fq *zexe_bn382_fq_index_domain_x_generator(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_x_generator_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_x_size_inv(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_index_domain_x_size(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_h_generator(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_h_generator_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_h_size_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_k_generator(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_k_generator_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_k_size_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_b_generator(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_b_generator_inv(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_index_domain_b_size_inv(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_index_domain_b_size(const DlogIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_x_generator(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_x_generator_inv(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_x_size_inv(const DlogVerifierIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_verifier_index_domain_x_size(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_h_generator(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_h_generator_inv(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_h_size_inv(const DlogVerifierIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_verifier_index_domain_h_size(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_k_generator(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_k_generator_inv(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_k_size_inv(const DlogVerifierIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_verifier_index_domain_k_size(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_b_generator(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_b_generator_inv(const DlogVerifierIndex_GAffine *i);
+
+Fq *zexe_bn382_fq_verifier_index_domain_b_size_inv(const DlogVerifierIndex_GAffine *i);
+
+uintptr_t zexe_bn382_fq_verifier_index_domain_b_size(const DlogVerifierIndex_GAffine *i);
+
+void zexe_bn382_fp_gadget_boolean(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t x);
+
+Vec_usize *zexe_bn382_fp_gadget_unpack(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t x, uintptr_t n, uintptr_t first_var);
+
+uintptr_t zexe_bn382_fp_gadget_pack(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, const Vec_usize *bits, uintptr_t first_var);
+
+Vec_usize *zexe_bn382_fp_gadget_range_check(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t x, uintptr_t n, uintptr_t first_var);
+
+Vec_Fp *zexe_bn382_fp_gadget_unpack_witness(const Fp *x, uintptr_t n);
+
+Fp *zexe_bn382_fp_gadget_pack_witness(const Vec_Fp *bits);
+
+Vec_usize *zexe_bn382_fp_gadget_poseidon(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, const Vec_usize *inputs, uintptr_t first_var);
+
+Vec_Fp *zexe_bn382_fp_gadget_poseidon_witness(const Vec_Fp *inputs);
+
+bool zexe_bn382_fp_gadget_poseidon_check(void);
+
+void zexe_bn382_fq_gadget_boolean(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t x);
+
+Vec_usize *zexe_bn382_fq_gadget_unpack(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t x, uintptr_t n, uintptr_t first_var);
+
+uintptr_t zexe_bn382_fq_gadget_pack(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, const Vec_usize *bits, uintptr_t first_var);
+
+Vec_usize *zexe_bn382_fq_gadget_range_check(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t x, uintptr_t n, uintptr_t first_var);
+
+Vec_Fq *zexe_bn382_fq_gadget_unpack_witness(const Fq *x, uintptr_t n);
+
+Fq *zexe_bn382_fq_gadget_pack_witness(const Vec_Fq *bits);
+
+Vec_usize *zexe_bn382_fq_gadget_poseidon(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, const Vec_usize *inputs, uintptr_t first_var);
+
+Vec_Fq *zexe_bn382_fq_gadget_poseidon_witness(const Vec_Fq *inputs);
+
+bool zexe_bn382_fq_gadget_poseidon_check(void);
+
+Vec_String *zexe_constraint_labels_create(void);
+
+void zexe_constraint_labels_push(Vec_String *v, const char *label);
+
+uintptr_t zexe_constraint_labels_length(const Vec_String *v);
+
+void zexe_constraint_labels_delete(Vec_String *x);
+
+bool zexe_bn382_fp_constraint_system_check(const Vec_Row_Fp *a, const Vec_Row_Fp *b, const Vec_Row_Fp *c, const Vec_String *labels, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+Index_Bn_382 *zexe_bn382_fp_index_create_labelled(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, const Vec_String *labels, uintptr_t vars, uintptr_t public_inputs, URS_Bn_382 *urs);
+
+bool zexe_bn382_fq_constraint_system_check(const Vec_Row_Fq *a, const Vec_Row_Fq *b, const Vec_Row_Fq *c, const Vec_String *labels, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input);
+
+DlogIndex_GAffine *zexe_bn382_fq_index_create_labelled(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, const Vec_String *labels, uintptr_t vars, uintptr_t public_inputs, SRS_GAffine *urs);
+
+int64_t zexe_bn382_fp_index_first_failing_row(const Index_Bn_382 *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+bool zexe_bn382_fp_index_check_witness(const Index_Bn_382 *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+Vec_Fp *zexe_bn382_fp_index_matrix_apply(const Index_Bn_382 *index, uintptr_t matrix, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input);
+
+int64_t zexe_bn382_fq_index_first_failing_row(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input);
+
+bool zexe_bn382_fq_index_check_witness(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input);
+
+Vec_Fq *zexe_bn382_fq_index_matrix_apply(const DlogIndex_GAffine *index, uintptr_t matrix, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input);
+
+bool zexe_bn382_init(const Config *config);
+
+bool zexe_bn382_shutdown(void);
+
+CircuitMerge_Fp *zexe_bn382_fp_circuit_merge_create(void);
+
+bool zexe_bn382_fp_circuit_merge_add(CircuitMerge_Fp *m, const Vec_Row_Fp *a, const Vec_Row_Fp *b, const Vec_Row_Fp *c, uintptr_t vars, uintptr_t public_inputs);
+
+uintptr_t zexe_bn382_fp_circuit_merge_circuits(const CircuitMerge_Fp *m);
+
+uintptr_t zexe_bn382_fp_circuit_merge_vars(const CircuitMerge_Fp *m);
+
+uintptr_t zexe_bn382_fp_circuit_merge_public_inputs(const CircuitMerge_Fp *m);
+
+uintptr_t zexe_bn382_fp_circuit_merge_public_offset(const CircuitMerge_Fp *m, uintptr_t k);
+
+uintptr_t zexe_bn382_fp_circuit_merge_aux_offset(const CircuitMerge_Fp *m, uintptr_t k);
+
+uintptr_t zexe_bn382_fp_circuit_merge_variable(const CircuitMerge_Fp *m, uintptr_t k, uintptr_t i);
+
+Vec_Row_Fp *zexe_bn382_fp_circuit_merge_a(const CircuitMerge_Fp *m);
+
+Vec_Row_Fp *zexe_bn382_fp_circuit_merge_b(const CircuitMerge_Fp *m);
+
+Vec_Row_Fp *zexe_bn382_fp_circuit_merge_c(const CircuitMerge_Fp *m);
+
+Index_Bn_382 *zexe_bn382_fp_circuit_merge_index_create(const CircuitMerge_Fp *m, URS_Bn_382 *urs);
+
+void zexe_bn382_fp_circuit_merge_delete(CircuitMerge_Fp *m);
+
+CircuitMerge_Fq *zexe_bn382_fq_circuit_merge_create(void);
+
+bool zexe_bn382_fq_circuit_merge_add(CircuitMerge_Fq *m, const Vec_Row_Fq *a, const Vec_Row_Fq *b, const Vec_Row_Fq *c, uintptr_t vars, uintptr_t public_inputs);
+
+uintptr_t zexe_bn382_fq_circuit_merge_circuits(const CircuitMerge_Fq *m);
+
+uintptr_t zexe_bn382_fq_circuit_merge_vars(const CircuitMerge_Fq *m);
+
+uintptr_t zexe_bn382_fq_circuit_merge_public_inputs(const CircuitMerge_Fq *m);
+
+uintptr_t zexe_bn382_fq_circuit_merge_public_offset(const CircuitMerge_Fq *m, uintptr_t k);
+
+uintptr_t zexe_bn382_fq_circuit_merge_aux_offset(const CircuitMerge_Fq *m, uintptr_t k);
+
+uintptr_t zexe_bn382_fq_circuit_merge_variable(const CircuitMerge_Fq *m, uintptr_t k, uintptr_t i);
+
+Vec_Row_Fq *zexe_bn382_fq_circuit_merge_a(const CircuitMerge_Fq *m);
+
+Vec_Row_Fq *zexe_bn382_fq_circuit_merge_b(const CircuitMerge_Fq *m);
+
+Vec_Row_Fq *zexe_bn382_fq_circuit_merge_c(const CircuitMerge_Fq *m);
+
+DlogIndex_GAffine *zexe_bn382_fq_circuit_merge_index_create(const CircuitMerge_Fq *m, SRS_GAffine *urs);
+
+void zexe_bn382_fq_circuit_merge_delete(CircuitMerge_Fq *m);
+
+bool zexe_bn382_fp_urs_register(const char *name, URS_Bn_382 *params);
+
+const URS_Bn_382 *zexe_bn382_fp_urs_get(const char *name);
+
+bool zexe_bn382_fp_urs_release(const char *name);
+
+bool zexe_bn382_fp_urs_unregister(const char *name);
+
+bool zexe_bn382_fq_urs_register(const char *name, SRS_GAffine *params);
+
+const SRS_GAffine *zexe_bn382_fq_urs_get(const char *name);
+
+bool zexe_bn382_fq_urs_release(const char *name);
+
+bool zexe_bn382_fq_urs_unregister(const char *name);
+
+int32_t zexe_pasta_fp_size_in_bits(void);
+
+BigInteger *zexe_pasta_fp_size(void);
+
+bool zexe_pasta_fp_is_square(const Fp *x);
+
+Fp *zexe_pasta_fp_sqrt(const Fp *x);
+
+Fp *zexe_pasta_fp_two_adic_root_of_unity(void);
+
+Fp *zexe_pasta_fp_random(void);
+
+Fp *zexe_pasta_fp_of_int(uint64_t i);
+
+Fp *zexe_pasta_fp_domain_generator(uintptr_t log2_size);
+
+char *zexe_pasta_fp_to_string(const Fp *x);
+
+Fp *zexe_pasta_fp_inv(const Fp *x);
+
+Fp *zexe_pasta_fp_square(const Fp *x);
+
+Fp *zexe_pasta_fp_add(const Fp *x, const Fp *y);
+
+Fp *zexe_pasta_fp_negate(const Fp *x);
+
+Fp *zexe_pasta_fp_mul(const Fp *x, const Fp *y);
+
+Fp *zexe_pasta_fp_div(const Fp *x, const Fp *y);
+
+Fp *zexe_pasta_fp_sub(const Fp *x, const Fp *y);
+
+void zexe_pasta_fp_mut_add(Fp *x, const Fp *y);
+
+void zexe_pasta_fp_mut_mul(Fp *x, const Fp *y);
+
+void zexe_pasta_fp_mut_square(Fp *x);
+
+void zexe_pasta_fp_mut_sub(Fp *x, const Fp *y);
+
+void zexe_pasta_fp_copy(Fp *x, const Fp *y);
+
+Fp *zexe_pasta_fp_rng(int32_t i);
+
+void zexe_pasta_fp_delete(Fp *x);
+
+void zexe_pasta_fp_print(const Fp *x);
+
+bool zexe_pasta_fp_equal(const Fp *x, const Fp *y);
+
+BigInteger *zexe_pasta_fp_to_bigint(const Fp *x);
+
+Fp *zexe_pasta_fp_of_bigint(const BigInteger *x);
+
+BigInteger *zexe_pasta_fp_to_bigint_raw(const Fp *x);
+
+const BigInteger *zexe_pasta_fp_to_bigint_raw_noalloc(const Fp *x);
+
+Fp *zexe_pasta_fp_of_bigint_raw(const BigInteger *x);
+
+Vec_Fp *zexe_pasta_fp_vector_create(void);
+
+int32_t zexe_pasta_fp_vector_length(const Vec_Fp *v);
+
+void zexe_pasta_fp_vector_emplace_back(Vec_Fp *v, const Fp *x);
+
+Fp *zexe_pasta_fp_vector_get(Vec_Fp *v, uint32_t i);
+
+void zexe_pasta_fp_vector_delete(Vec_Fp *x);
+
+int32_t zexe_pasta_fq_size_in_bits(void);
+
+BigInteger *zexe_pasta_fq_size(void);
+
+bool zexe_pasta_fq_is_square(const Fq *x);
+
+Fq *zexe_pasta_fq_sqrt(const Fq *x);
+
+Fq *zexe_pasta_fq_two_adic_root_of_unity(void);
+
+Fq *zexe_pasta_fq_random(void);
+
+Fq *zexe_pasta_fq_of_int(uint64_t i);
+
+Fq *zexe_pasta_fq_domain_generator(uintptr_t log2_size);
+
+char *zexe_pasta_fq_to_string(const Fq *x);
+
+Fq *zexe_pasta_fq_inv(const Fq *x);
+
+Fq *zexe_pasta_fq_square(const Fq *x);
+
+Fq *zexe_pasta_fq_add(const Fq *x, const Fq *y);
+
+Fq *zexe_pasta_fq_negate(const Fq *x);
+
+Fq *zexe_pasta_fq_mul(const Fq *x, const Fq *y);
+
+Fq *zexe_pasta_fq_div(const Fq *x, const Fq *y);
+
+Fq *zexe_pasta_fq_sub(const Fq *x, const Fq *y);
+
+void zexe_pasta_fq_mut_add(Fq *x, const Fq *y);
+
+void zexe_pasta_fq_mut_mul(Fq *x, const Fq *y);
+
+void zexe_pasta_fq_mut_square(Fq *x);
+
+void zexe_pasta_fq_mut_sub(Fq *x, const Fq *y);
+
+void zexe_pasta_fq_copy(Fq *x, const Fq *y);
+
+Fq *zexe_pasta_fq_rng(int32_t i);
+
+void zexe_pasta_fq_delete(Fq *x);
+
+void zexe_pasta_fq_print(const Fq *x);
+
+bool zexe_pasta_fq_equal(const Fq *x, const Fq *y);
+
+BigInteger *zexe_pasta_fq_to_bigint(const Fq *x);
+
+Fq *zexe_pasta_fq_of_bigint(const BigInteger *x);
+
+BigInteger *zexe_pasta_fq_to_bigint_raw(const Fq *x);
+
+const BigInteger *zexe_pasta_fq_to_bigint_raw_noalloc(const Fq *x);
+
+Fq *zexe_pasta_fq_of_bigint_raw(const BigInteger *x);
+
+Vec_Fq *zexe_pasta_fq_vector_create(void);
+
+int32_t zexe_pasta_fq_vector_length(const Vec_Fq *v);
+
+void zexe_pasta_fq_vector_emplace_back(Vec_Fq *v, const Fq *x);
+
+Fq *zexe_pasta_fq_vector_get(Vec_Fq *v, uint32_t i);
+
+void zexe_pasta_fq_vector_delete(Vec_Fq *x);
+
+const PallasProjective *zexe_pasta_pallas_random(void);
+
+void zexe_pasta_pallas_delete(PallasProjective *x);
+
+const PallasProjective *zexe_pasta_pallas_one(void);
+
+const PallasProjective *zexe_pasta_pallas_add(const PallasProjective *x, const PallasProjective *y);
+
+const PallasProjective *zexe_pasta_pallas_double(const PallasProjective *x);
+
+const PallasProjective *zexe_pasta_pallas_scale(const PallasProjective *x, const Fq *s);
+
+const PallasProjective *zexe_pasta_pallas_sub(const PallasProjective *x, const PallasProjective *y);
+
+const PallasProjective *zexe_pasta_pallas_negate(const PallasProjective *x);
+
+const PallasAffine *zexe_pasta_pallas_to_affine(const PallasProjective *p);
+
+const PallasProjective *zexe_pasta_pallas_of_affine(const PallasAffine *p);
+
+const PallasProjective *zexe_pasta_pallas_of_affine_coordinates(const Fp *x, const Fp *y);
+
+const PallasAffine *zexe_pasta_pallas_affine_create(const Fp *x, const Fp *y);
+
+const Fp *zexe_pasta_pallas_affine_x(const PallasAffine *p);
+
+const Fp *zexe_pasta_pallas_affine_y(const PallasAffine *p);
+
+bool zexe_pasta_pallas_affine_is_zero(const PallasAffine *p);
+
+void zexe_pasta_pallas_affine_delete(PallasAffine *x);
+
+Vec_PallasAffine *zexe_pasta_pallas_affine_vector_create(void);
+
+int32_t zexe_pasta_pallas_affine_vector_length(const Vec_PallasAffine *v);
+
+void zexe_pasta_pallas_affine_vector_emplace_back(Vec_PallasAffine *v, const PallasAffine *x);
+
+PallasAffine *zexe_pasta_pallas_affine_vector_get(Vec_PallasAffine *v, uint32_t i);
+
+void zexe_pasta_pallas_affine_vector_delete(Vec_PallasAffine *x);
+
+const VestaProjective *zexe_pasta_vesta_random(void);
+
+void zexe_pasta_vesta_delete(VestaProjective *x);
+
+const VestaProjective *zexe_pasta_vesta_one(void);
+
+const VestaProjective *zexe_pasta_vesta_add(const VestaProjective *x, const VestaProjective *y);
+
+const VestaProjective *zexe_pasta_vesta_double(const VestaProjective *x);
+
+const VestaProjective *zexe_pasta_vesta_scale(const VestaProjective *x, const Fp *s);
+
+const VestaProjective *zexe_pasta_vesta_sub(const VestaProjective *x, const VestaProjective *y);
+
+const VestaProjective *zexe_pasta_vesta_negate(const VestaProjective *x);
+
+const VestaAffine *zexe_pasta_vesta_to_affine(const VestaProjective *p);
+
+const VestaProjective *zexe_pasta_vesta_of_affine(const VestaAffine *p);
+
+const VestaProjective *zexe_pasta_vesta_of_affine_coordinates(const Fq *x, const Fq *y);
+
+const VestaAffine *zexe_pasta_vesta_affine_create(const Fq *x, const Fq *y);
+
+const Fq *zexe_pasta_vesta_affine_x(const VestaAffine *p);
+
+const Fq *zexe_pasta_vesta_affine_y(const VestaAffine *p);
+
+bool zexe_pasta_vesta_affine_is_zero(const VestaAffine *p);
+
+void zexe_pasta_vesta_affine_delete(VestaAffine *x);
+
+Vec_VestaAffine *zexe_pasta_vesta_affine_vector_create(void);
+
+int32_t zexe_pasta_vesta_affine_vector_length(const Vec_VestaAffine *v);
+
+void zexe_pasta_vesta_affine_vector_emplace_back(Vec_VestaAffine *v, const VestaAffine *x);
+
+VestaAffine *zexe_pasta_vesta_affine_vector_get(Vec_VestaAffine *v, uint32_t i);
+
+void zexe_pasta_vesta_affine_vector_delete(Vec_VestaAffine *x);
+
+const SRS_GAffine *zexe_tweedle_fp_urs_create(uintptr_t depth);
+
+void zexe_tweedle_fp_urs_delete(SRS_GAffine *x);
+
+void zexe_tweedle_fp_urs_write(SRS_GAffine *urs, char *path);
+
+const SRS_GAffine *zexe_tweedle_fp_urs_read(char *path);
+
+const PolyComm_GAffine *zexe_tweedle_fp_urs_lagrange_commitment(const SRS_GAffine *urs, uintptr_t domain_size, uintptr_t i);
+
+const PolyComm_GAffine *zexe_tweedle_fp_urs_commit_evaluations(const SRS_GAffine *urs, uintptr_t domain_size, const Vec_Fp *evals);
+
+const PolyComm_GAffine *zexe_tweedle_fp_urs_b_poly_commitment(const SRS_GAffine *urs, const Vec_Fp *chals);
+
+bool zexe_tweedle_fp_urs_batch_accumulator_check(const SRS_GAffine *urs, const Vec_GAffine *comms, const Vec_Fp *chals);
+
+const GAffine *zexe_tweedle_fp_urs_h(const SRS_GAffine *urs);
+
+uintptr_t zexe_tweedle_fp_index_domain_h_size(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_tweedle_fp_index_domain_k_size(const DlogIndex_GAffine *i);
+
+DlogIndex_GAffine *zexe_tweedle_fp_index_create(Vec_Row_Fp *a, Vec_Row_Fp *b, Vec_Row_Fp *c, uintptr_t vars, uintptr_t public_inputs, SRS_GAffine *srs);
+
+void zexe_tweedle_fp_index_delete(DlogIndex_GAffine *x);
+
+uintptr_t zexe_tweedle_fp_index_nonzero_entries(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fp_index_max_degree(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fp_index_num_variables(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fp_index_public_inputs(const DlogIndex_GAffine *index);
+
+void zexe_tweedle_fp_index_write(const DlogIndex_GAffine *index, const char *path);
+
+const DlogIndex_GAffine *zexe_tweedle_fp_index_read(const SRS_GAffine *srs, const Vec_Row_Fp *a, const Vec_Row_Fp *b, const Vec_Row_Fp *c, uintptr_t public_inputs, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fp_verifier_index_create(const DlogIndex_GAffine *index);
+
+const SRS_GAffine *zexe_tweedle_fp_verifier_index_urs(const DlogVerifierIndex_GAffine *index);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fp_verifier_index_make(uintptr_t public_inputs, uintptr_t variables, uintptr_t constraints, uintptr_t nonzero_entries, uintptr_t max_poly_size, const SRS_GAffine *urs, const PolyComm_GAffine *row_a, const PolyComm_GAffine *col_a, const PolyComm_GAffine *val_a, const PolyComm_GAffine *rc_a, const PolyComm_GAffine *row_b, const PolyComm_GAffine *col_b, const PolyComm_GAffine *val_b, const PolyComm_GAffine *rc_b, const PolyComm_GAffine *row_c, const PolyComm_GAffine *col_c, const PolyComm_GAffine *val_c, const PolyComm_GAffine *rc_c);
+
+void zexe_tweedle_fp_verifier_index_delete(DlogVerifierIndex_GAffine *x);
+
+void zexe_tweedle_fp_verifier_index_write(const DlogVerifierIndex_GAffine *index, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fp_verifier_index_read(const SRS_GAffine *srs, const char *path);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_a_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_a_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_a_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_a_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_b_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_b_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_b_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_b_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_c_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_c_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_c_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fp_verifier_index_c_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const Vec______DlogVerifierIndex_GAffine *zexe_tweedle_fp_verifier_index_vector_create(void);
+
+int32_t zexe_tweedle_fp_verifier_index_vector_length(const Vec______DlogVerifierIndex_GAffine *v);
+
+void zexe_tweedle_fp_verifier_index_vector_emplace_back(Vec______DlogVerifierIndex_GAffine *v, const DlogVerifierIndex_GAffine *x);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fp_verifier_index_vector_get(Vec______DlogVerifierIndex_GAffine *v, uint32_t i);
+
+void zexe_tweedle_fp_verifier_index_vector_delete(Vec______DlogVerifierIndex_GAffine *x);
+
+const Fq *zexe_tweedle_fp_endo_base(void);
+
+const Fp *zexe_tweedle_fp_endo_scalar(void);
+
+int32_t zexe_tweedle_fp_size_in_bits(void);
+
+BigInteger *zexe_tweedle_fp_size(void);
+
+bool zexe_tweedle_fp_is_square(const Fp *x);
+
+Fp *zexe_tweedle_fp_sqrt(const Fp *x);
+
+Fp *zexe_tweedle_fp_det_sqrt(const Fp *x);
+
+DetSqrtWitness_Fp zexe_tweedle_fp_det_sqrt_witness(const Fp *x);
+
+Fp *zexe_tweedle_fp_two_adic_root_of_unity(void);
+
+Fp *zexe_tweedle_fp_random(void);
+
+Fp *zexe_tweedle_fp_of_int(uint64_t i);
+
+Fp *zexe_tweedle_fp_domain_generator(uintptr_t log2_size);
+
+char *zexe_tweedle_fp_to_string(const Fp *x);
+
+Fp *zexe_tweedle_fp_inv(const Fp *x);
+
+Fp *zexe_tweedle_fp_square(const Fp *x);
+
+Fp *zexe_tweedle_fp_add(const Fp *x, const Fp *y);
+
+Fp *zexe_tweedle_fp_negate(const Fp *x);
+
+Fp *zexe_tweedle_fp_mul(const Fp *x, const Fp *y);
+
+Fp *zexe_tweedle_fp_div(const Fp *x, const Fp *y);
+
+Fp *zexe_tweedle_fp_sub(const Fp *x, const Fp *y);
+
+void zexe_tweedle_fp_mut_add(Fp *x, const Fp *y);
+
+void zexe_tweedle_fp_mut_mul(Fp *x, const Fp *y);
+
+void zexe_tweedle_fp_mut_square(Fp *x);
+
+void zexe_tweedle_fp_mut_sub(Fp *x, const Fp *y);
+
+void zexe_tweedle_fp_copy(Fp *x, const Fp *y);
+
+Fp *zexe_tweedle_fp_rng(int32_t i);
+
+void zexe_tweedle_fp_delete(Fp *x);
+
+void zexe_tweedle_fp_print(const Fp *x);
+
+bool zexe_tweedle_fp_equal(const Fp *x, const Fp *y);
+
+BigInteger *zexe_tweedle_fp_to_bigint(const Fp *x);
+
+Fp *zexe_tweedle_fp_of_bigint(const BigInteger *x);
+
+BigInteger *zexe_tweedle_fp_to_bigint_raw(const Fp *x);
+
+const BigInteger *zexe_tweedle_fp_to_bigint_raw_noalloc(const Fp *x);
+
+Fp *zexe_tweedle_fp_of_bigint_raw(const BigInteger *x);
+
+Vec_Fp *zexe_tweedle_fp_vector_create(void);
+
+int32_t zexe_tweedle_fp_vector_length(const Vec_Fp *v);
+
+void zexe_tweedle_fp_vector_emplace_back(Vec_Fp *v, const Fp *x);
+
+Fp *zexe_tweedle_fp_vector_get(Vec_Fp *v, uint32_t i);
+
+void zexe_tweedle_fp_vector_delete(Vec_Fp *x);
+
+Vec_Row_Fp *zexe_tweedle_fp_constraint_matrix_create(void);
+
+void zexe_tweedle_fp_constraint_matrix_append_row(Vec_Row_Fp *m, Vec_usize *indices, Vec_Fp *coefficients);
+
+void zexe_tweedle_fp_constraint_matrix_delete(Vec_Row_Fp *x);
+
+const Fp *zexe_tweedle_fp_triple_0(const Fp *evals);
+
+const Fp *zexe_tweedle_fp_triple_1(const Fp *evals);
+
+const Fp *zexe_tweedle_fp_triple_2(const Fp *evals);
+
+void zexe_tweedle_fp_triple_delete(Fp *x);
+
+const Vec_Fp *zexe_tweedle_fp_vector_triple_0(const Vec_Fp *evals);
+
+const Vec_Fp *zexe_tweedle_fp_vector_triple_1(const Vec_Fp *evals);
+
+const Vec_Fp *zexe_tweedle_fp_vector_triple_2(const Vec_Fp *evals);
+
+void zexe_tweedle_fp_vector_triple_delete(Vec_Fp *x);
+
+const GProjective *zexe_tweedle_dee_random(void);
+
+void zexe_tweedle_dee_delete(GProjective *x);
+
+const GProjective *zexe_tweedle_dee_one(void);
+
+const GProjective *zexe_tweedle_dee_add(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_tweedle_dee_double(const GProjective *x);
+
+const GProjective *zexe_tweedle_dee_scale(const GProjective *x, const Fp *s);
+
+const GProjective *zexe_tweedle_dee_sub(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_tweedle_dee_negate(const GProjective *x);
+
+const GAffine *zexe_tweedle_dee_to_affine(const GProjective *p);
+
+const GProjective *zexe_tweedle_dee_of_affine(const GAffine *p);
+
+const GProjective *zexe_tweedle_dee_of_affine_coordinates(const Fq *x, const Fq *y);
+
+const GAffine *zexe_tweedle_dee_affine_create(const Fq *x, const Fq *y);
+
+const Fq *zexe_tweedle_dee_affine_x(const GAffine *p);
+
+const Fq *zexe_tweedle_dee_affine_y(const GAffine *p);
+
+bool zexe_tweedle_dee_affine_is_zero(const GAffine *p);
+
+void zexe_tweedle_dee_affine_delete(GAffine *x);
+
+const GAffine *zexe_tweedle_dee_affine_pair_0(const AffinePair_GAffine *p);
+
+const GAffine *zexe_tweedle_dee_affine_pair_1(const AffinePair_GAffine *p);
+
+const AffinePair_GAffine *zexe_tweedle_dee_affine_pair_make(const GAffine *x0, const GAffine *x1);
+
+void zexe_tweedle_dee_affine_pair_delete(AffinePair_GAffine *x);
+
+Vec_AffinePair_GAffine *zexe_tweedle_dee_affine_pair_vector_create(void);
+
+int32_t zexe_tweedle_dee_affine_pair_vector_length(const Vec_AffinePair_GAffine *v);
+
+void zexe_tweedle_dee_affine_pair_vector_emplace_back(Vec_AffinePair_GAffine *v, const AffinePair_GAffine *x);
+
+AffinePair_GAffine *zexe_tweedle_dee_affine_pair_vector_get(Vec_AffinePair_GAffine *v, uint32_t i);
+
+void zexe_tweedle_dee_affine_pair_vector_delete(Vec_AffinePair_GAffine *x);
+
+PairVectorCursor_GAffine *zexe_tweedle_dee_affine_pair_vector_cursor_create(const Vec_AffinePair_GAffine *v);
+
+const AffinePair_GAffine *zexe_tweedle_dee_affine_pair_vector_cursor_next(PairVectorCursor_GAffine *c);
+
+void zexe_tweedle_dee_affine_pair_vector_cursor_delete(PairVectorCursor_GAffine *x);
+
+Vec_GAffine *zexe_tweedle_dee_affine_vector_create(void);
+
+int32_t zexe_tweedle_dee_affine_vector_length(const Vec_GAffine *v);
+
+void zexe_tweedle_dee_affine_vector_emplace_back(Vec_GAffine *v, const GAffine *x);
+
+GAffine *zexe_tweedle_dee_affine_vector_get(Vec_GAffine *v, uint32_t i);
+
+void zexe_tweedle_dee_affine_vector_delete(Vec_GAffine *x);
+
+const FpOracles *zexe_tweedle_fp_oracles_create(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+const Vec_Fp *zexe_tweedle_fp_oracles_opening_prechallenges(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_alpha(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_eta_a(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_eta_b(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_eta_c(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_beta1(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_beta2(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_beta3(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_polys(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_evals(const FpOracles *oracles);
+
+const Vec_Fp *zexe_tweedle_fp_oracles_x_hat_nocopy(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_fp_oracles_digest_before_evaluations(const FpOracles *oracles);
+
+void zexe_tweedle_fp_oracles_delete(FpOracles *x);
+
+const DlogProof_GAffine *zexe_tweedle_fp_proof_create(const DlogIndex_GAffine *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input, const Vec_Fp *prev_challenges, const Vec_GAffine *prev_sgs);
+
+bool zexe_tweedle_fp_proof_verify(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+bool zexe_tweedle_fp_proof_batch_verify(const Vec______DlogVerifierIndex_GAffine *indexes, const Vec_DlogProof_GAffine *proofs);
+
+const DlogProof_GAffine *zexe_tweedle_fp_proof_make(const Vec_Fp *primary_input, const PolyComm_GAffine *w_comm, const PolyComm_GAffine *za_comm, const PolyComm_GAffine *zb_comm, const PolyComm_GAffine *h1_comm, const PolyComm_GAffine *g1_comm, const PolyComm_GAffine *h2_comm, const PolyComm_GAffine *g2_comm, const PolyComm_GAffine *h3_comm, const PolyComm_GAffine *g3_comm, const Fp *sigma2, const Fp *sigma3, const Vec_AffinePair_GAffine *lr, const Fp *z1, const Fp *z2, const GAffine *delta, const GAffine *sg, const DlogProofEvaluations_Fp *evals0, const DlogProofEvaluations_Fp *evals1, const DlogProofEvaluations_Fp *evals2, const Vec_Fp *prev_challenges, const Vec_GAffine *prev_sgs);
+
+void zexe_tweedle_fp_proof_delete(DlogProof_GAffine *x);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_w_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_za_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_zb_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_h1_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_g1_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_h2_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_g2_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_h3_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fp_proof_g3_comm_nocopy(DlogProof_GAffine *p);
+
+const Fp *zexe_tweedle_fp_proof_sigma2(DlogProof_GAffine *p);
+
+const Fp *zexe_tweedle_fp_proof_sigma3(DlogProof_GAffine *p);
+
+const OpeningProof_GAffine *zexe_tweedle_fp_proof_proof(DlogProof_GAffine *p);
+
+const DlogProofEvaluations_Fp *zexe_tweedle_fp_proof_evals_nocopy(DlogProof_GAffine *p);
+
+Vec_DlogProof_GAffine *zexe_tweedle_fp_proof_vector_create(void);
+
+int32_t zexe_tweedle_fp_proof_vector_length(const Vec_DlogProof_GAffine *v);
+
+void zexe_tweedle_fp_proof_vector_emplace_back(Vec_DlogProof_GAffine *v, const DlogProof_GAffine *x);
+
+DlogProof_GAffine *zexe_tweedle_fp_proof_vector_get(Vec_DlogProof_GAffine *v, uint32_t i);
+
+void zexe_tweedle_fp_proof_vector_delete(Vec_DlogProof_GAffine *x);
+
+void zexe_tweedle_fp_opening_proof_delete(OpeningProof_GAffine *x);
+
+const GAffine *zexe_tweedle_fp_opening_proof_sg(const OpeningProof_GAffine *p);
+
+const Vec_AffinePair_GAffine *zexe_tweedle_fp_opening_proof_lr(const OpeningProof_GAffine *p);
+
+const Fp *zexe_tweedle_fp_opening_proof_z1(const OpeningProof_GAffine *p);
+
+const Fp *zexe_tweedle_fp_opening_proof_z2(const OpeningProof_GAffine *p);
+
+const GAffine *zexe_tweedle_fp_opening_proof_delta(const OpeningProof_GAffine *p);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_w(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_za(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_zb(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_h1(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_h2(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_h3(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_g1(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_g2(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_g3(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_row_nocopy(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_val_nocopy(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_col_nocopy(const DlogProofEvaluations_Fp *e);
+
+const Vec_Fp *zexe_tweedle_fp_proof_evaluations_rc_nocopy(const DlogProofEvaluations_Fp *e);
+
+const DlogProofEvaluations_Fp *zexe_tweedle_fp_proof_evaluations_triple_0(const DlogProofEvaluations_Fp *e);
+
+const DlogProofEvaluations_Fp *zexe_tweedle_fp_proof_evaluations_triple_1(const DlogProofEvaluations_Fp *e);
+
+const DlogProofEvaluations_Fp *zexe_tweedle_fp_proof_evaluations_triple_2(const DlogProofEvaluations_Fp *e);
+
+void zexe_tweedle_fp_proof_evaluations_triple_delete(DlogProofEvaluations_Fp *x);
+
+const DlogProofEvaluations_Fp *zexe_tweedle_fp_proof_evaluations_make(const Vec_Fp *w, const Vec_Fp *za, const Vec_Fp *zb, const Vec_Fp *h1, const Vec_Fp *g1, const Vec_Fp *h2, const Vec_Fp *g2, const Vec_Fp *h3, const Vec_Fp *g3, const Vec_Fp *row_0, const Vec_Fp *row_1, const Vec_Fp *row_2, const Vec_Fp *col_0, const Vec_Fp *col_1, const Vec_Fp *col_2, const Vec_Fp *val_0, const Vec_Fp *val_1, const Vec_Fp *val_2, const Vec_Fp *rc_0, const Vec_Fp *rc_1, const Vec_Fp *rc_2);
+
+void zexe_tweedle_fp_proof_evaluations_delete(DlogProofEvaluations_Fp *x);
+
+const Vec_GAffine *zexe_tweedle_fp_poly_comm_unshifted(const PolyComm_GAffine *c);
+
+const GAffine *zexe_tweedle_fp_poly_comm_shifted(const PolyComm_GAffine *c);
+
+const PolyComm_GAffine *zexe_tweedle_fp_poly_comm_make(const Vec_GAffine *unshifted, const GAffine *shifted);
+
+void zexe_tweedle_fp_poly_comm_delete(PolyComm_GAffine *x);
+
+Vec_PolyComm_GAffine *zexe_tweedle_fp_poly_comm_vector_create(void);
+
+int32_t zexe_tweedle_fp_poly_comm_vector_length(const Vec_PolyComm_GAffine *v);
+
+void zexe_tweedle_fp_poly_comm_vector_emplace_back(Vec_PolyComm_GAffine *v, const PolyComm_GAffine *x);
+
+PolyComm_GAffine *zexe_tweedle_fp_poly_comm_vector_get(Vec_PolyComm_GAffine *v, uint32_t i);
+
+void zexe_tweedle_fp_poly_comm_vector_delete(Vec_PolyComm_GAffine *x);
+
+const DlogIndex_GAffine *zexe_tweedle_plonk_fp_index_read(const SRS_GAffine *srs, const char *path);
+
+void zexe_tweedle_plonk_fp_index_write(const DlogIndex_GAffine *index, const char *path);
+
+uintptr_t zexe_tweedle_plonk_fp_index_domain_d1_size(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_tweedle_plonk_fp_index_domain_d4_size(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_tweedle_plonk_fp_index_domain_d8_size(const DlogIndex_GAffine *i);
+
+DlogIndex_GAffine *zexe_tweedle_plonk_fp_index_create(const Vec_Gate_Fp *gates, uintptr_t public_, const SRS_GAffine *srs);
+
+void zexe_tweedle_plonk_fp_index_delete(DlogIndex_GAffine *x);
+
+uintptr_t zexe_tweedle_plonk_fp_index_max_degree(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_plonk_fp_index_public_inputs(const DlogIndex_GAffine *index);
+
+PointerPair_Fp__Fp zexe_tweedle_plonk_fp_verifier_index_shifts(uintptr_t log2_size);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fp_verifier_index_read(const SRS_GAffine *srs, const char *path);
+
+void zexe_tweedle_plonk_fp_verifier_index_write(const DlogVerifierIndex_GAffine *index, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fp_verifier_index_create(const DlogIndex_GAffine *index);
+
+const SRS_GAffine *zexe_tweedle_plonk_fp_verifier_index_urs(const DlogVerifierIndex_GAffine *index);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fp_verifier_index_make(uintptr_t max_poly_size, uintptr_t max_quot_size, const SRS_GAffine *urs, const PolyComm_GAffine *sigma_comm0, const PolyComm_GAffine *sigma_comm1, const PolyComm_GAffine *sigma_comm2, const PolyComm_GAffine *ql_comm, const PolyComm_GAffine *qr_comm, const PolyComm_GAffine *qo_comm, const PolyComm_GAffine *qm_comm, const PolyComm_GAffine *qc_comm, const PolyComm_GAffine *rcm_comm0, const PolyComm_GAffine *rcm_comm1, const PolyComm_GAffine *rcm_comm2, const PolyComm_GAffine *psm_comm, const PolyComm_GAffine *add_comm, const PolyComm_GAffine *mul1_comm, const PolyComm_GAffine *mul2_comm, const PolyComm_GAffine *emul1_comm, const PolyComm_GAffine *emul2_comm, const PolyComm_GAffine *emul3_comm, const Fp *r, const Fp *o);
+
+void zexe_tweedle_plonk_fp_verifier_index_delete(DlogVerifierIndex_GAffine *x);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_0(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_1(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_2(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_ql_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_qr_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_qo_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_qm_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_qc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_0(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_1(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_2(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_psm_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_add_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_mul1_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_mul2_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_emul1_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_emul2_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_verifier_index_emul3_comm(const DlogVerifierIndex_GAffine *index);
+
+const Fp *zexe_tweedle_plonk_fp_verifier_index_r(const DlogVerifierIndex_GAffine *index);
+
+const Fp *zexe_tweedle_plonk_fp_verifier_index_o(const DlogVerifierIndex_GAffine *index);
+
+uint32_t zexe_tweedle_plonk_fp_verifier_index_domain_log2(const DlogVerifierIndex_GAffine *x);
+
+const Fp *zexe_tweedle_plonk_fp_verifier_index_domain_group_gen(const DlogVerifierIndex_GAffine *x);
+
+const DlogProof_GAffine *zexe_tweedle_plonk_fp_proof_create(const DlogIndex_GAffine *index, const Vec_Fp *primary_input, const Vec_Fp *auxiliary_input, const Vec_Fp *prev_challenges, const Vec_GAffine *prev_sgs);
+
+bool zexe_tweedle_plonk_fp_proof_verify(const Vec_PolyComm_GAffine *lgr_comm, const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+bool zexe_tweedle_plonk_fp_proof_batch_verify(const Vec______Vec_PolyComm_GAffine *lgr_comms, const Vec______DlogVerifierIndex_GAffine *indexes, const Vec_DlogProof_GAffine *proofs);
+
+const DlogProof_GAffine *zexe_tweedle_plonk_fp_proof_make(const Vec_Fp *primary_input, const PolyComm_GAffine *l_comm, const PolyComm_GAffine *r_comm, const PolyComm_GAffine *o_comm, const PolyComm_GAffine *z_comm, const PolyComm_GAffine *t_comm, const Vec_AffinePair_GAffine *lr, const Fp *z1, const Fp *z2, const GAffine *delta, const GAffine *sg, const DlogProofEvaluations_Vec_Fp *evals0, const DlogProofEvaluations_Vec_Fp *evals1, const Vec_Fp *prev_challenges, const Vec_GAffine *prev_sgs);
+
+void zexe_tweedle_plonk_fp_proof_delete(DlogProof_GAffine *x);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_proof_l_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_proof_r_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_proof_o_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_proof_z_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_plonk_fp_proof_t_comm(DlogProof_GAffine *p);
+
+const OpeningProof_GAffine *zexe_tweedle_plonk_fp_proof_proof(DlogProof_GAffine *p);
+
+const DlogProofEvaluations_Vec_Fp *zexe_tweedle_plonk_fp_proof_evals_nocopy(DlogProof_GAffine *p);
+
+Vec_DlogProof_GAffine *zexe_tweedle_plonk_fp_proof_vector_create(void);
+
+int32_t zexe_tweedle_plonk_fp_proof_vector_length(const Vec_DlogProof_GAffine *v);
+
+void zexe_tweedle_plonk_fp_proof_vector_emplace_back(Vec_DlogProof_GAffine *v, const DlogProof_GAffine *x);
+
+DlogProof_GAffine *zexe_tweedle_plonk_fp_proof_vector_get(Vec_DlogProof_GAffine *v, uint32_t i);
+
+void zexe_tweedle_plonk_fp_proof_vector_delete(Vec_DlogProof_GAffine *x);
+
+void zexe_tweedle_plonk_fp_opening_proof_delete(OpeningProof_GAffine *x);
+
+const GAffine *zexe_tweedle_plonk_fp_opening_proof_sg(const OpeningProof_GAffine *p);
+
+const Vec_AffinePair_GAffine *zexe_tweedle_plonk_fp_opening_proof_lr(const OpeningProof_GAffine *p);
+
+const Fp *zexe_tweedle_plonk_fp_opening_proof_z1(const OpeningProof_GAffine *p);
+
+const Fp *zexe_tweedle_plonk_fp_opening_proof_z2(const OpeningProof_GAffine *p);
+
+const GAffine *zexe_tweedle_plonk_fp_opening_proof_delta(const OpeningProof_GAffine *p);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_l(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_r(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_o(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_z(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_t(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_f(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_sigma1(const DlogProofEvaluations_Vec_Fp *e);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_sigma2(const DlogProofEvaluations_Vec_Fp *e);
+
+const DlogProofEvaluations_Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_make(const Vec_Fp *l, const Vec_Fp *r, const Vec_Fp *o, const Vec_Fp *z, const Vec_Fp *t, const Vec_Fp *f, const Vec_Fp *sigma1, const Vec_Fp *sigma2);
+
+void zexe_tweedle_plonk_fp_proof_evaluations_delete(DlogProofEvaluations_Vec_Fp *x);
+
+const DlogProofEvaluations_Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_pair_0(const DlogProofEvaluations_Vec_Fp *e);
+
+const DlogProofEvaluations_Vec_Fp *zexe_tweedle_plonk_fp_proof_evaluations_pair_1(const DlogProofEvaluations_Vec_Fp *e);
+
+void zexe_tweedle_plonk_fp_proof_evaluations_pair_delete(DlogProofEvaluations_Vec_Fp *x);
+
+const FpOracles *zexe_tweedle_plonk_fp_oracles_create(const Vec_PolyComm_GAffine *lgr_comm, const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+const Vec_Fp *zexe_tweedle_plonk_fp_oracles_opening_prechallenges(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_digest_before_evaluations(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_p_eval1(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_p_eval2(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_alpha(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_beta(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_gamma(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_zeta(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_v(const FpOracles *oracles);
+
+const Fp *zexe_tweedle_plonk_fp_oracles_u(const FpOracles *oracles);
+
+void zexe_tweedle_plonk_fp_oracles_delete(FpOracles *x);
+
+Vec_Gate_Fp *zexe_tweedle_plonk_fp_gate_vector_create(void);
+
+int32_t zexe_tweedle_plonk_fp_gate_vector_length(const Vec_Gate_Fp *v);
+
+void zexe_tweedle_plonk_fp_gate_vector_add(Vec_Gate_Fp *v, int typ, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_zero(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_generic(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_poseidon(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_add1(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_add2(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_vbmul1(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_vbmul2(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_vbmul3(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_endomul1(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_endomul2(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_endomul3(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_add_endomul4(Vec_Gate_Fp *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fp *c);
+
+void zexe_tweedle_plonk_fp_gate_vector_wrap(Vec_Gate_Fp *v, uintptr_t trow, int tcol, uintptr_t hrow, int hcol);
+
+void zexe_tweedle_plonk_fp_gate_vector_delete(Vec_Gate_Fp *x);
+
+ConstraintSystem_Fp *zexe_tweedle_plonk_fp_constraint_system_create(Vec_CircuitGate_Fp *v, uintptr_t public_);
+
+void zexe_tweedle_plonk_fp_constraint_system_delete(ConstraintSystem_Fp *x);
+
+const SRS_GAffine *zexe_tweedle_fq_urs_create(uintptr_t depth);
+
+void zexe_tweedle_fq_urs_delete(SRS_GAffine *x);
+
+void zexe_tweedle_fq_urs_write(SRS_GAffine *urs, char *path);
+
+const SRS_GAffine *zexe_tweedle_fq_urs_read(char *path);
+
+const PolyComm_GAffine *zexe_tweedle_fq_urs_lagrange_commitment(const SRS_GAffine *urs, uintptr_t domain_size, uintptr_t i);
+
+const PolyComm_GAffine *zexe_tweedle_fq_urs_commit_evaluations(const SRS_GAffine *urs, uintptr_t domain_size, const Vec_Fq *evals);
+
+const PolyComm_GAffine *zexe_tweedle_fq_urs_b_poly_commitment(const SRS_GAffine *urs, const Vec_Fq *chals);
+
+bool zexe_tweedle_fq_urs_batch_accumulator_check(const SRS_GAffine *urs, const Vec_GAffine *comms, const Vec_Fq *chals);
+
+const GAffine *zexe_tweedle_fq_urs_h(const SRS_GAffine *urs);
+
+uintptr_t zexe_tweedle_fq_index_domain_h_size(const DlogIndex_GAffine *i);
+
+uintptr_t zexe_tweedle_fq_index_domain_k_size(const DlogIndex_GAffine *i);
+
+DlogIndex_GAffine *zexe_tweedle_fq_index_create(Vec_Row_Fq *a, Vec_Row_Fq *b, Vec_Row_Fq *c, uintptr_t vars, uintptr_t public_inputs, SRS_GAffine *srs);
+
+void zexe_tweedle_fq_index_delete(DlogIndex_GAffine *x);
+
+uintptr_t zexe_tweedle_fq_index_nonzero_entries(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fq_index_max_degree(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fq_index_num_variables(const DlogIndex_GAffine *index);
+
+uintptr_t zexe_tweedle_fq_index_public_inputs(const DlogIndex_GAffine *index);
+
+void zexe_tweedle_fq_index_write(const DlogIndex_GAffine *index, const char *path);
+
+const DlogIndex_GAffine *zexe_tweedle_fq_index_read(const SRS_GAffine *srs, const Vec_Row_Fq *a, const Vec_Row_Fq *b, const Vec_Row_Fq *c, uintptr_t public_inputs, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fq_verifier_index_create(const DlogIndex_GAffine *index);
+
+const SRS_GAffine *zexe_tweedle_fq_verifier_index_urs(const DlogVerifierIndex_GAffine *index);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fq_verifier_index_make(uintptr_t public_inputs, uintptr_t variables, uintptr_t constraints, uintptr_t nonzero_entries, uintptr_t max_poly_size, const SRS_GAffine *urs, const PolyComm_GAffine *row_a, const PolyComm_GAffine *col_a, const PolyComm_GAffine *val_a, const PolyComm_GAffine *rc_a, const PolyComm_GAffine *row_b, const PolyComm_GAffine *col_b, const PolyComm_GAffine *val_b, const PolyComm_GAffine *rc_b, const PolyComm_GAffine *row_c, const PolyComm_GAffine *col_c, const PolyComm_GAffine *val_c, const PolyComm_GAffine *rc_c);
+
+void zexe_tweedle_fq_verifier_index_delete(DlogVerifierIndex_GAffine *x);
+
+void zexe_tweedle_fq_verifier_index_write(const DlogVerifierIndex_GAffine *index, const char *path);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fq_verifier_index_read(const SRS_GAffine *srs, const char *path);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_a_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_a_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_a_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_a_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_b_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_b_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_b_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_b_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_c_row_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_c_col_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_c_val_comm(const DlogVerifierIndex_GAffine *index);
+
+const PolyComm_GAffine *zexe_tweedle_fq_verifier_index_c_rc_comm(const DlogVerifierIndex_GAffine *index);
+
+const Vec______DlogVerifierIndex_GAffine *zexe_tweedle_fq_verifier_index_vector_create(void);
+
+int32_t zexe_tweedle_fq_verifier_index_vector_length(const Vec______DlogVerifierIndex_GAffine *v);
+
+void zexe_tweedle_fq_verifier_index_vector_emplace_back(Vec______DlogVerifierIndex_GAffine *v, const DlogVerifierIndex_GAffine *x);
+
+const DlogVerifierIndex_GAffine *zexe_tweedle_fq_verifier_index_vector_get(Vec______DlogVerifierIndex_GAffine *v, uint32_t i);
+
+void zexe_tweedle_fq_verifier_index_vector_delete(Vec______DlogVerifierIndex_GAffine *x);
+
+const Fp *zexe_tweedle_fq_endo_base(void);
+
+const Fq *zexe_tweedle_fq_endo_scalar(void);
+
+int32_t zexe_tweedle_fq_size_in_bits(void);
+
+BigInteger *zexe_tweedle_fq_size(void);
+
+bool zexe_tweedle_fq_is_square(const Fq *x);
+
+Fq *zexe_tweedle_fq_sqrt(const Fq *x);
+
+Fq *zexe_tweedle_fq_det_sqrt(const Fq *x);
+
+DetSqrtWitness_Fq zexe_tweedle_fq_det_sqrt_witness(const Fq *x);
+
+Fq *zexe_tweedle_fq_two_adic_root_of_unity(void);
+
+Fq *zexe_tweedle_fq_random(void);
+
+Fq *zexe_tweedle_fq_of_int(uint64_t i);
+
+Fq *zexe_tweedle_fq_domain_generator(uintptr_t log2_size);
+
+char *zexe_tweedle_fq_to_string(const Fq *x);
+
+Fq *zexe_tweedle_fq_inv(const Fq *x);
+
+Fq *zexe_tweedle_fq_square(const Fq *x);
+
+Fq *zexe_tweedle_fq_add(const Fq *x, const Fq *y);
+
+Fq *zexe_tweedle_fq_negate(const Fq *x);
+
+Fq *zexe_tweedle_fq_mul(const Fq *x, const Fq *y);
+
+Fq *zexe_tweedle_fq_div(const Fq *x, const Fq *y);
+
+Fq *zexe_tweedle_fq_sub(const Fq *x, const Fq *y);
+
+void zexe_tweedle_fq_mut_add(Fq *x, const Fq *y);
+
+void zexe_tweedle_fq_mut_mul(Fq *x, const Fq *y);
+
+void zexe_tweedle_fq_mut_square(Fq *x);
+
+void zexe_tweedle_fq_mut_sub(Fq *x, const Fq *y);
+
+void zexe_tweedle_fq_copy(Fq *x, const Fq *y);
+
+Fq *zexe_tweedle_fq_rng(int32_t i);
+
+void zexe_tweedle_fq_delete(Fq *x);
+
+void zexe_tweedle_fq_print(const Fq *x);
+
+bool zexe_tweedle_fq_equal(const Fq *x, const Fq *y);
+
+BigInteger *zexe_tweedle_fq_to_bigint(const Fq *x);
+
+Fq *zexe_tweedle_fq_of_bigint(const BigInteger *x);
+
+BigInteger *zexe_tweedle_fq_to_bigint_raw(const Fq *x);
+
+const BigInteger *zexe_tweedle_fq_to_bigint_raw_noalloc(const Fq *x);
+
+Fq *zexe_tweedle_fq_of_bigint_raw(const BigInteger *x);
+
+Vec_Fq *zexe_tweedle_fq_vector_create(void);
+
+int32_t zexe_tweedle_fq_vector_length(const Vec_Fq *v);
+
+void zexe_tweedle_fq_vector_emplace_back(Vec_Fq *v, const Fq *x);
+
+Fq *zexe_tweedle_fq_vector_get(Vec_Fq *v, uint32_t i);
+
+void zexe_tweedle_fq_vector_delete(Vec_Fq *x);
+
+Vec_Row_Fq *zexe_tweedle_fq_constraint_matrix_create(void);
+
+void zexe_tweedle_fq_constraint_matrix_append_row(Vec_Row_Fq *m, Vec_usize *indices, Vec_Fq *coefficients);
+
+void zexe_tweedle_fq_constraint_matrix_delete(Vec_Row_Fq *x);
+
+const Fq *zexe_tweedle_fq_triple_0(const Fq *evals);
+
+const Fq *zexe_tweedle_fq_triple_1(const Fq *evals);
+
+const Fq *zexe_tweedle_fq_triple_2(const Fq *evals);
+
+void zexe_tweedle_fq_triple_delete(Fq *x);
+
+const Vec_Fq *zexe_tweedle_fq_vector_triple_0(const Vec_Fq *evals);
+
+const Vec_Fq *zexe_tweedle_fq_vector_triple_1(const Vec_Fq *evals);
+
+const Vec_Fq *zexe_tweedle_fq_vector_triple_2(const Vec_Fq *evals);
+
+void zexe_tweedle_fq_vector_triple_delete(Vec_Fq *x);
+
+const GProjective *zexe_tweedle_dum_random(void);
+
+void zexe_tweedle_dum_delete(GProjective *x);
+
+const GProjective *zexe_tweedle_dum_one(void);
+
+const GProjective *zexe_tweedle_dum_add(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_tweedle_dum_double(const GProjective *x);
+
+const GProjective *zexe_tweedle_dum_scale(const GProjective *x, const Fq *s);
+
+const GProjective *zexe_tweedle_dum_sub(const GProjective *x, const GProjective *y);
+
+const GProjective *zexe_tweedle_dum_negate(const GProjective *x);
+
+const GAffine *zexe_tweedle_dum_to_affine(const GProjective *p);
+
+const GProjective *zexe_tweedle_dum_of_affine(const GAffine *p);
+
+const GProjective *zexe_tweedle_dum_of_affine_coordinates(const Fp *x, const Fp *y);
+
+const GAffine *zexe_tweedle_dum_affine_create(const Fp *x, const Fp *y);
+
+const Fp *zexe_tweedle_dum_affine_x(const GAffine *p);
+
+const Fp *zexe_tweedle_dum_affine_y(const GAffine *p);
+
+bool zexe_tweedle_dum_affine_is_zero(const GAffine *p);
+
+void zexe_tweedle_dum_affine_delete(GAffine *x);
+
+const GAffine *zexe_tweedle_dum_affine_pair_0(const AffinePair_GAffine *p);
+
+const GAffine *zexe_tweedle_dum_affine_pair_1(const AffinePair_GAffine *p);
+
+const AffinePair_GAffine *zexe_tweedle_dum_affine_pair_make(const GAffine *x0, const GAffine *x1);
+
+void zexe_tweedle_dum_affine_pair_delete(AffinePair_GAffine *x);
+
+Vec_AffinePair_GAffine *zexe_tweedle_dum_affine_pair_vector_create(void);
+
+int32_t zexe_tweedle_dum_affine_pair_vector_length(const Vec_AffinePair_GAffine *v);
+
+void zexe_tweedle_dum_affine_pair_vector_emplace_back(Vec_AffinePair_GAffine *v, const AffinePair_GAffine *x);
+
+AffinePair_GAffine *zexe_tweedle_dum_affine_pair_vector_get(Vec_AffinePair_GAffine *v, uint32_t i);
+
+void zexe_tweedle_dum_affine_pair_vector_delete(Vec_AffinePair_GAffine *x);
+
+PairVectorCursor_GAffine *zexe_tweedle_dum_affine_pair_vector_cursor_create(const Vec_AffinePair_GAffine *v);
+
+const AffinePair_GAffine *zexe_tweedle_dum_affine_pair_vector_cursor_next(PairVectorCursor_GAffine *c);
+
+void zexe_tweedle_dum_affine_pair_vector_cursor_delete(PairVectorCursor_GAffine *x);
+
+Vec_GAffine *zexe_tweedle_dum_affine_vector_create(void);
+
+int32_t zexe_tweedle_dum_affine_vector_length(const Vec_GAffine *v);
+
+void zexe_tweedle_dum_affine_vector_emplace_back(Vec_GAffine *v, const GAffine *x);
+
+GAffine *zexe_tweedle_dum_affine_vector_get(Vec_GAffine *v, uint32_t i);
+
+void zexe_tweedle_dum_affine_vector_delete(Vec_GAffine *x);
+
+const FqOracles *zexe_tweedle_fq_oracles_create(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+const Vec_Fq *zexe_tweedle_fq_oracles_opening_prechallenges(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_alpha(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_eta_a(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_eta_b(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_eta_c(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_beta1(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_beta2(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_beta3(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_polys(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_evals(const FqOracles *oracles);
+
+const Vec_Fq *zexe_tweedle_fq_oracles_x_hat_nocopy(const FqOracles *oracles);
+
+const Fq *zexe_tweedle_fq_oracles_digest_before_evaluations(const FqOracles *oracles);
+
+void zexe_tweedle_fq_oracles_delete(FqOracles *x);
+
+const DlogProof_GAffine *zexe_tweedle_fq_proof_create(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+bool zexe_tweedle_fq_proof_verify(const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
+
+bool zexe_tweedle_fq_proof_batch_verify(const Vec______DlogVerifierIndex_GAffine *indexes, const Vec_DlogProof_GAffine *proofs);
+
+const DlogProof_GAffine *zexe_tweedle_fq_proof_make(const Vec_Fq *primary_input, const PolyComm_GAffine *w_comm, const PolyComm_GAffine *za_comm, const PolyComm_GAffine *zb_comm, const PolyComm_GAffine *h1_comm, const PolyComm_GAffine *g1_comm, const PolyComm_GAffine *h2_comm, const PolyComm_GAffine *g2_comm, const PolyComm_GAffine *h3_comm, const PolyComm_GAffine *g3_comm, const Fq *sigma2, const Fq *sigma3, const Vec_AffinePair_GAffine *lr, const Fq *z1, const Fq *z2, const GAffine *delta, const GAffine *sg, const DlogProofEvaluations_Fq *evals0, const DlogProofEvaluations_Fq *evals1, const DlogProofEvaluations_Fq *evals2, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
+
+void zexe_tweedle_fq_proof_delete(DlogProof_GAffine *x);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_w_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_za_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_zb_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_h1_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_g1_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_h2_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_g2_comm_nocopy(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_h3_comm(DlogProof_GAffine *p);
+
+const PolyComm_GAffine *zexe_tweedle_fq_proof_g3_comm_nocopy(DlogProof_GAffine *p);
+
+const Fq *zexe_tweedle_fq_proof_sigma2(DlogProof_GAffine *p);
+
+const Fq *zexe_tweedle_fq_proof_sigma3(DlogProof_GAffine *p);
+
+const OpeningProof_GAffine *zexe_tweedle_fq_proof_proof(DlogProof_GAffine *p);
+
+const DlogProofEvaluations_Fq *zexe_tweedle_fq_proof_evals_nocopy(DlogProof_GAffine *p);
+
+Vec_DlogProof_GAffine *zexe_tweedle_fq_proof_vector_create(void);
+
+int32_t zexe_tweedle_fq_proof_vector_length(const Vec_DlogProof_GAffine *v);
+
+void zexe_tweedle_fq_proof_vector_emplace_back(Vec_DlogProof_GAffine *v, const DlogProof_GAffine *x);
+
+DlogProof_GAffine *zexe_tweedle_fq_proof_vector_get(Vec_DlogProof_GAffine *v, uint32_t i);
+
+void zexe_tweedle_fq_proof_vector_delete(Vec_DlogProof_GAffine *x);
+
+void zexe_tweedle_fq_opening_proof_delete(OpeningProof_GAffine *x);
+
+const GAffine *zexe_tweedle_fq_opening_proof_sg(const OpeningProof_GAffine *p);
+
+const Vec_AffinePair_GAffine *zexe_tweedle_fq_opening_proof_lr(const OpeningProof_GAffine *p);
+
+const Fq *zexe_tweedle_fq_opening_proof_z1(const OpeningProof_GAffine *p);
+
+const Fq *zexe_tweedle_fq_opening_proof_z2(const OpeningProof_GAffine *p);
+
+const GAffine *zexe_tweedle_fq_opening_proof_delta(const OpeningProof_GAffine *p);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_w(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_za(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_zb(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_h1(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_h2(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_h3(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_g1(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_g2(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_g3(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_row_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_val_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_col_nocopy(const DlogProofEvaluations_Fq *e);
+
+const Vec_Fq *zexe_tweedle_fq_proof_evaluations_rc_nocopy(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_tweedle_fq_proof_evaluations_triple_0(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_tweedle_fq_proof_evaluations_triple_1(const DlogProofEvaluations_Fq *e);
+
+const DlogProofEvaluations_Fq *zexe_tweedle_fq_proof_evaluations_triple_2(const DlogProofEvaluations_Fq *e);
+
+void zexe_tweedle_fq_proof_evaluations_triple_delete(DlogProofEvaluations_Fq *x);
+
+const DlogProofEvaluations_Fq *zexe_tweedle_fq_proof_evaluations_make(const Vec_Fq *w, const Vec_Fq *za, const Vec_Fq *zb, const Vec_Fq *h1, const Vec_Fq *g1, const Vec_Fq *h2, const Vec_Fq *g2, const Vec_Fq *h3, const Vec_Fq *g3, const Vec_Fq *row_0, const Vec_Fq *row_1, const Vec_Fq *row_2, const Vec_Fq *col_0, const Vec_Fq *col_1, const Vec_Fq *col_2, const Vec_Fq *val_0, const Vec_Fq *val_1, const Vec_Fq *val_2, const Vec_Fq *rc_0, const Vec_Fq *rc_1, const Vec_Fq *rc_2);
+
+void zexe_tweedle_fq_proof_evaluations_delete(DlogProofEvaluations_Fq *x);
+
+const Vec_GAffine *zexe_tweedle_fq_poly_comm_unshifted(const PolyComm_GAffine *c);
+
+const GAffine *zexe_tweedle_fq_poly_comm_shifted(const PolyComm_GAffine *c);
+
+const PolyComm_GAffine *zexe_tweedle_fq_poly_comm_make(const Vec_GAffine *unshifted, const GAffine *shifted);
+
+void zexe_tweedle_fq_poly_comm_delete(PolyComm_GAffine *x);
+
+Vec_PolyComm_GAffine *zexe_tweedle_fq_poly_comm_vector_create(void);
+
+int32_t zexe_tweedle_fq_poly_comm_vector_length(const Vec_PolyComm_GAffine *v);
+
+void zexe_tweedle_fq_poly_comm_vector_emplace_back(Vec_PolyComm_GAffine *v, const PolyComm_GAffine *x);
 
-struct pointer_pair {
-  void* a;
-  void* b;
-};
+PolyComm_GAffine *zexe_tweedle_fq_poly_comm_vector_get(Vec_PolyComm_GAffine *v, uint32_t i);
 
-// usize vector
+void zexe_tweedle_fq_poly_comm_vector_delete(Vec_PolyComm_GAffine *x);
 
-void *zexe_usize_vector_create();
+const DlogIndex_GAffine *zexe_tweedle_plonk_fq_index_read(const SRS_GAffine *srs, const char *path);
 
-int zexe_usize_vector_length(void *);
+void zexe_tweedle_plonk_fq_index_write(const DlogIndex_GAffine *index, const char *path);
 
-void zexe_usize_vector_emplace_back(void *, size_t);
+uintptr_t zexe_tweedle_plonk_fq_index_domain_d1_size(const DlogIndex_GAffine *i);
 
-size_t zexe_usize_vector_get(void *, int);
+uintptr_t zexe_tweedle_plonk_fq_index_domain_d4_size(const DlogIndex_GAffine *i);
 
-void zexe_usize_vector_delete(void *);
+uintptr_t zexe_tweedle_plonk_fq_index_domain_d8_size(const DlogIndex_GAffine *i);
 
-// pointer vector
+DlogIndex_GAffine *zexe_tweedle_plonk_fq_index_create(const Vec_Gate_Fq *gates, uintptr_t public_, SRS_GAffine *srs);
 
-void *zexe_pointer_vector_create();
+void zexe_tweedle_plonk_fq_index_delete(DlogIndex_GAffine *x);
 
-int zexe_pointer_vector_length(void *);
+uintptr_t zexe_tweedle_plonk_fq_index_max_degree(const DlogIndex_GAffine *index);
 
-void zexe_pointer_vector_emplace_back(void *, void *);
+uintptr_t zexe_tweedle_plonk_fq_index_public_inputs(const DlogIndex_GAffine *index);
 
-void* zexe_pointer_vector_get(void *, int);
+PointerPair_Fq__Fq zexe_tweedle_plonk_fq_verifier_index_shifts(uintptr_t log2_size);
 
-void zexe_pointer_vector_delete(void *);
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fq_verifier_index_read(const SRS_GAffine *srs, const char *path);
 
-// Bigint
+void zexe_tweedle_plonk_fq_verifier_index_write(const DlogVerifierIndex_GAffine *index, const char *path);
 
-// 256
-void *zexe_bigint256_of_decimal_string(char *);
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fq_verifier_index_create(const DlogIndex_GAffine *index);
 
-int zexe_bigint256_num_limbs();
+const SRS_GAffine *zexe_tweedle_plonk_fq_verifier_index_urs(const DlogVerifierIndex_GAffine *index);
 
-char *zexe_bigint256_to_data(void *);
+const DlogVerifierIndex_GAffine *zexe_tweedle_plonk_fq_verifier_index_make(uintptr_t max_poly_size, uintptr_t max_quot_size, const SRS_GAffine *urs, const PolyComm_GAffine *sigma_comm0, const PolyComm_GAffine *sigma_comm1, const PolyComm_GAffine *sigma_comm2, const PolyComm_GAffine *ql_comm, const PolyComm_GAffine *qr_comm, const PolyComm_GAffine *qo_comm, const PolyComm_GAffine *qm_comm, const PolyComm_GAffine *qc_comm, const PolyComm_GAffine *rcm_comm0, const PolyComm_GAffine *rcm_comm1, const PolyComm_GAffine *rcm_comm2, const PolyComm_GAffine *psm_comm, const PolyComm_GAffine *add_comm, const PolyComm_GAffine *mul1_comm, const PolyComm_GAffine *mul2_comm, const PolyComm_GAffine *emul1_comm, const PolyComm_GAffine *emul2_comm, const PolyComm_GAffine *emul3_comm, const Fq *r, const Fq *o);
 
-void *zexe_bigint256_of_data(char *);
+void zexe_tweedle_plonk_fq_verifier_index_delete(DlogVerifierIndex_GAffine *x);
 
-int zexe_bigint256_bytes_per_limb();
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_0(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint256_div(void *, void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_1(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint256_of_numeral(char *, int, int);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_2(const DlogVerifierIndex_GAffine *index);
 
-bool zexe_bigint256_compare(void *, void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_ql_comm(const DlogVerifierIndex_GAffine *index);
 
-bool zexe_bigint256_test_bit(void *, int);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_qr_comm(const DlogVerifierIndex_GAffine *index);
 
-void zexe_bigint256_delete(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_qo_comm(const DlogVerifierIndex_GAffine *index);
 
-void zexe_bigint256_print(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_qm_comm(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint256_find_wnaf(size_t, void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_qc_comm(const DlogVerifierIndex_GAffine *index);
 
-// 384
-void *zexe_bigint384_of_decimal_string(char *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_0(const DlogVerifierIndex_GAffine *index);
 
-int zexe_bigint384_num_limbs();
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_1(const DlogVerifierIndex_GAffine *index);
 
-char *zexe_bigint384_to_data(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_2(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint384_of_data(char *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_psm_comm(const DlogVerifierIndex_GAffine *index);
 
-int zexe_bigint384_bytes_per_limb();
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_add_comm(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint384_div(void *, void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_mul1_comm(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint384_of_numeral(char *, int, int);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_mul2_comm(const DlogVerifierIndex_GAffine *index);
 
-bool zexe_bigint384_compare(void *, void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_emul1_comm(const DlogVerifierIndex_GAffine *index);
 
-bool zexe_bigint384_test_bit(void *, int);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_emul2_comm(const DlogVerifierIndex_GAffine *index);
 
-void zexe_bigint384_delete(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_verifier_index_emul3_comm(const DlogVerifierIndex_GAffine *index);
 
-void zexe_bigint384_print(void *);
+const Fq *zexe_tweedle_plonk_fq_verifier_index_r(const DlogVerifierIndex_GAffine *index);
 
-void *zexe_bigint384_find_wnaf(size_t, void *);
+const Fq *zexe_tweedle_plonk_fq_verifier_index_o(const DlogVerifierIndex_GAffine *index);
 
-// Fp
+uint32_t zexe_tweedle_plonk_fq_verifier_index_domain_log2(const DlogVerifierIndex_GAffine *x);
 
-int zexe_bn382_fp_size_in_bits();
+const Fq *zexe_tweedle_plonk_fq_verifier_index_domain_group_gen(const DlogVerifierIndex_GAffine *x);
 
-void* zexe_bn382_fp_endo_base();
+const DlogProof_GAffine *zexe_tweedle_plonk_fq_proof_create(const DlogIndex_GAffine *index, const Vec_Fq *primary_input, const Vec_Fq *auxiliary_input, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
 
-void* zexe_bn382_fp_endo_scalar();
+bool zexe_tweedle_plonk_fq_proof_verify(const Vec_PolyComm_GAffine *lgr_comm, const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
 
-void *zexe_bn382_fp_size();
+bool zexe_tweedle_plonk_fq_proof_batch_verify(const Vec______Vec_PolyComm_GAffine *lgr_comms, const Vec______DlogVerifierIndex_GAffine *indexes, const Vec_DlogProof_GAffine *proofs);
 
-bool zexe_bn382_fp_is_square(void *);
+const DlogProof_GAffine *zexe_tweedle_plonk_fq_proof_make(const Vec_Fq *primary_input, const PolyComm_GAffine *l_comm, const PolyComm_GAffine *r_comm, const PolyComm_GAffine *o_comm, const PolyComm_GAffine *z_comm, const PolyComm_GAffine *t_comm, const Vec_AffinePair_GAffine *lr, const Fq *z1, const Fq *z2, const GAffine *delta, const GAffine *sg, const DlogProofEvaluations_Vec_Fq *evals0, const DlogProofEvaluations_Vec_Fq *evals1, const Vec_Fq *prev_challenges, const Vec_GAffine *prev_sgs);
 
-void *zexe_bn382_fp_sqrt(void *);
+void zexe_tweedle_plonk_fq_proof_delete(DlogProof_GAffine *x);
 
-void *zexe_bn382_fp_det_sqrt(void *);
-struct det_sqrt_witness zexe_bn382_fp_det_sqrt_witness(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_proof_l_comm(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_random();
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_proof_r_comm(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_two_adic_root_of_unity();
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_proof_o_comm(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_of_int(uint64_t);
-void *zexe_bn382_fp_domain_generator(size_t);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_proof_z_comm(DlogProof_GAffine *p);
 
-char *zexe_bn382_fp_to_string(void *);
+const PolyComm_GAffine *zexe_tweedle_plonk_fq_proof_t_comm(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_inv(void *);
+const OpeningProof_GAffine *zexe_tweedle_plonk_fq_proof_proof(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_square(void *);
+const DlogProofEvaluations_Vec_Fq *zexe_tweedle_plonk_fq_proof_evals_nocopy(DlogProof_GAffine *p);
 
-void *zexe_bn382_fp_add(void *, void *);
+Vec_DlogProof_GAffine *zexe_tweedle_plonk_fq_proof_vector_create(void);
 
-void *zexe_bn382_fp_negate(void *);
+int32_t zexe_tweedle_plonk_fq_proof_vector_length(const Vec_DlogProof_GAffine *v);
 
-void *zexe_bn382_fp_mul(void *, void *);
+void zexe_tweedle_plonk_fq_proof_vector_emplace_back(Vec_DlogProof_GAffine *v, const DlogProof_GAffine *x);
 
-void *zexe_bn382_fp_div(void *, void *);
+DlogProof_GAffine *zexe_tweedle_plonk_fq_proof_vector_get(Vec_DlogProof_GAffine *v, uint32_t i);
 
-void *zexe_bn382_fp_sub(void *, void *);
+void zexe_tweedle_plonk_fq_proof_vector_delete(Vec_DlogProof_GAffine *x);
 
-void zexe_bn382_fp_mut_add(void *, void *);
+void zexe_tweedle_plonk_fq_opening_proof_delete(OpeningProof_GAffine *x);
 
-void zexe_bn382_fp_mut_mul(void *, void *);
+const GAffine *zexe_tweedle_plonk_fq_opening_proof_sg(const OpeningProof_GAffine *p);
 
-void zexe_bn382_fp_mut_square(void *);
+const Vec_AffinePair_GAffine *zexe_tweedle_plonk_fq_opening_proof_lr(const OpeningProof_GAffine *p);
 
-void zexe_bn382_fp_mut_sub(void *, void *);
+const Fq *zexe_tweedle_plonk_fq_opening_proof_z1(const OpeningProof_GAffine *p);
 
-void zexe_bn382_fp_copy(void *, void *);
+const Fq *zexe_tweedle_plonk_fq_opening_proof_z2(const OpeningProof_GAffine *p);
 
-void *zexe_bn382_fp_rng(int i);
+const GAffine *zexe_tweedle_plonk_fq_opening_proof_delta(const OpeningProof_GAffine *p);
 
-void zexe_bn382_fp_delete(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_l(const DlogProofEvaluations_Vec_Fq *e);
 
-void zexe_bn382_fp_print(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_r(const DlogProofEvaluations_Vec_Fq *e);
 
-bool zexe_bn382_fp_equal(void *, void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_o(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_to_bigint(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_z(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_of_bigint(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_t(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_to_bigint_raw(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_f(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_to_bigint_raw_noalloc(void *);
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_sigma1(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_of_bigint_raw(void *);
-// Fp vector
+const Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_sigma2(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_vector_create();
+const DlogProofEvaluations_Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_make(const Vec_Fq *l, const Vec_Fq *r, const Vec_Fq *o, const Vec_Fq *z, const Vec_Fq *t, const Vec_Fq *f, const Vec_Fq *sigma1, const Vec_Fq *sigma2);
 
-int zexe_bn382_fp_vector_length(void *);
+void zexe_tweedle_plonk_fq_proof_evaluations_delete(DlogProofEvaluations_Vec_Fq *x);
 
-void zexe_bn382_fp_vector_emplace_back(void *, void *);
+const DlogProofEvaluations_Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_pair_0(const DlogProofEvaluations_Vec_Fq *e);
 
-void *zexe_bn382_fp_vector_get(void *, int);
+const DlogProofEvaluations_Vec_Fq *zexe_tweedle_plonk_fq_proof_evaluations_pair_1(const DlogProofEvaluations_Vec_Fq *e);
 
-void zexe_bn382_fp_vector_delete(void *);
+void zexe_tweedle_plonk_fq_proof_evaluations_pair_delete(DlogProofEvaluations_Vec_Fq *x);
 
-// Fp constraint matrix
+const FqOracles *zexe_tweedle_plonk_fq_oracles_create(const Vec_PolyComm_GAffine *lgr_comm, const DlogVerifierIndex_GAffine *index, const DlogProof_GAffine *proof);
 
-void *zexe_bn382_fp_constraint_matrix_create();
+const Vec_Fq *zexe_tweedle_plonk_fq_oracles_opening_prechallenges(const FqOracles *oracles);
 
-void zexe_bn382_fp_constraint_matrix_append_row(void *, void*, void*);
+const Fq *zexe_tweedle_plonk_fq_oracles_digest_before_evaluations(const FqOracles *oracles);
 
-void zexe_bn382_fp_constraint_matrix_delete(void *);
+const Fq *zexe_tweedle_plonk_fq_oracles_p_eval1(const FqOracles *oracles);
 
-// Fp sponge
+const Fq *zexe_tweedle_plonk_fq_oracles_p_eval2(const FqOracles *oracles);
 
-void *zexe_bn382_fp_sponge_params();
+const Fq *zexe_tweedle_plonk_fq_oracles_alpha(const FqOracles *oracles);
 
-void zexe_bn382_fp_sponge_params_delete(void *);
+const Fq *zexe_tweedle_plonk_fq_oracles_beta(const FqOracles *oracles);
 
-void *zexe_bn382_fp_sponge_create();
+const Fq *zexe_tweedle_plonk_fq_oracles_gamma(const FqOracles *oracles);
 
-void zexe_bn382_fp_sponge_delete(void *);
+const Fq *zexe_tweedle_plonk_fq_oracles_zeta(const FqOracles *oracles);
 
-void zexe_bn382_fp_sponge_absorb(void *, void *, void *);
+const Fq *zexe_tweedle_plonk_fq_oracles_v(const FqOracles *oracles);
 
-void *zexe_bn382_fp_sponge_squeeze(void *, void *);
+const Fq *zexe_tweedle_plonk_fq_oracles_u(const FqOracles *oracles);
 
-// Fq
+void zexe_tweedle_plonk_fq_oracles_delete(FqOracles *x);
 
-int zexe_bn382_fq_size_in_bits();
-void* zexe_bn382_fq_endo_base();
-void* zexe_bn382_fq_endo_scalar();
-void *zexe_bn382_fq_size();
-bool zexe_bn382_fq_is_square(void *);
-void *zexe_bn382_fq_sqrt(void *);
-void *zexe_bn382_fq_random();
-void *zexe_bn382_fq_two_adic_root_of_unity();
-void *zexe_bn382_fq_of_int(uint64_t);
-void *zexe_bn382_fq_domain_generator(size_t);
-char *zexe_bn382_fq_to_string(void *);
-void *zexe_bn382_fq_inv(void *);
-void *zexe_bn382_fq_square(void *);
-void *zexe_bn382_fq_add(void *, void *);
-void *zexe_bn382_fq_negate(void *);
-void *zexe_bn382_fq_mul(void *, void *);
-void *zexe_bn382_fq_div(void *, void *);
-void *zexe_bn382_fq_sub(void *, void *);
-void zexe_bn382_fq_mut_add(void *, void *);
-void zexe_bn382_fq_mut_mul(void *, void *);
-void zexe_bn382_fq_mut_square(void *);
-void zexe_bn382_fq_mut_sub(void *, void *);
-void zexe_bn382_fq_copy(void *, void *);
-void *zexe_bn382_fq_rng(int i);
-void zexe_bn382_fq_delete(void *);
-void zexe_bn382_fq_print(void *);
-bool zexe_bn382_fq_equal(void *, void *);
-void *zexe_bn382_fq_to_bigint(void *);
-void *zexe_bn382_fq_of_bigint(void *);
-void *zexe_bn382_fq_to_bigint_raw(void *);
-void *zexe_bn382_fq_to_bigint_raw_noalloc(void *);
-void *zexe_bn382_fq_of_bigint_raw(void *);
+Vec_Gate_Fq *zexe_tweedle_plonk_fq_gate_vector_create(void);
 
-void *zexe_bn382_fq_det_sqrt(void *);
-struct det_sqrt_witness zexe_bn382_fq_det_sqrt_witness(void *);
+int32_t zexe_tweedle_plonk_fq_gate_vector_length(const Vec_Gate_Fq *v);
 
-// Fq vector
+void zexe_tweedle_plonk_fq_gate_vector_add(Vec_Gate_Fq *v, int typ, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fq_vector_create();
-int zexe_bn382_fq_vector_length(void *);
-void zexe_bn382_fq_vector_emplace_back(void *, void *);
-void *zexe_bn382_fq_vector_get(void *, int);
-void zexe_bn382_fq_vector_delete(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_zero(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-// Fq CsMat
-
-void *zexe_bn382_fq_csmat_create(int, int);
-void *zexe_bn382_fq_csmat_append_row(void *, void *, void *);
-void zexe_bn382_fq_csmat_delete(void *);
-
-// Fq constraint matrix
-
-void *zexe_bn382_fq_constraint_matrix_create();
-void zexe_bn382_fq_constraint_matrix_append_row(void *, void*, void*);
-void zexe_bn382_fq_constraint_matrix_delete(void *);
-
-// Fq sponge
-
-void *zexe_bn382_fq_sponge_params();
-void zexe_bn382_fq_sponge_params_delete(void *);
-void *zexe_bn382_fq_sponge_create();
-void zexe_bn382_fq_sponge_delete(void *);
-void zexe_bn382_fq_sponge_absorb(void *, void *, void *);
-void *zexe_bn382_fq_sponge_squeeze(void *, void *);
-
-// Fp oracles
-void *zexe_bn382_fp_oracles_create(void*, void*);
-void zexe_bn382_fp_oracles_delete(void*);
-
-void* zexe_bn382_fp_oracles_alpha(void*);
-void* zexe_bn382_fp_oracles_eta_a(void*);
-void* zexe_bn382_fp_oracles_eta_b(void*);
-void* zexe_bn382_fp_oracles_eta_c(void*);
-void* zexe_bn382_fp_oracles_beta1(void*);
-void* zexe_bn382_fp_oracles_beta2(void*);
-void* zexe_bn382_fp_oracles_beta3(void*);
-void* zexe_bn382_fp_oracles_batch(void*);
-void* zexe_bn382_fp_oracles_r(void*);
-void* zexe_bn382_fp_oracles_r_k(void*);
-void* zexe_bn382_fp_oracles_x_hat_beta1(void*);
-void* zexe_bn382_fp_oracles_digest_before_evaluations(void*);
-
-// Fq oracles
-void *zexe_bn382_fq_oracles_create(void*, void*);
-void zexe_bn382_fq_oracles_delete(void*);
-
-void* zexe_bn382_fq_oracles_opening_prechallenges(void*);
-void* zexe_bn382_fq_oracles_alpha(void*);
-void* zexe_bn382_fq_oracles_eta_a(void*);
-void* zexe_bn382_fq_oracles_eta_b(void*);
-void* zexe_bn382_fq_oracles_eta_c(void*);
-void* zexe_bn382_fq_oracles_beta1(void*);
-void* zexe_bn382_fq_oracles_beta2(void*);
-void* zexe_bn382_fq_oracles_beta3(void*);
-void* zexe_bn382_fq_oracles_polys(void*);
-void* zexe_bn382_fq_oracles_evals(void*);
-void* zexe_bn382_fq_oracles_digest_before_evaluations(void*);
-void* zexe_bn382_fq_oracles_x_hat_nocopy(void*);
-
-// Fp verifier index
-void *zexe_bn382_fp_verifier_index_create(void*);
-void zexe_bn382_fp_verifier_index_delete(void*);
-void *zexe_bn382_fp_verifier_index_urs(void*);
-
-void *zexe_bn382_fp_verifier_index_make(
-    size_t, size_t, size_t, size_t, size_t,
-    void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void* );
-
-void zexe_bn382_fp_verifier_index_write(void*, void*);
-void* zexe_bn382_fp_verifier_index_read(void*);
-
-void *zexe_bn382_fp_verifier_index_a_row_comm(void*);
-void *zexe_bn382_fp_verifier_index_a_col_comm(void*);
-void *zexe_bn382_fp_verifier_index_a_val_comm(void*);
-void *zexe_bn382_fp_verifier_index_a_rc_comm(void*);
-
-void *zexe_bn382_fp_verifier_index_b_row_comm(void*);
-void *zexe_bn382_fp_verifier_index_b_col_comm(void*);
-void *zexe_bn382_fp_verifier_index_b_val_comm(void*);
-void *zexe_bn382_fp_verifier_index_b_rc_comm(void*);
-
-void *zexe_bn382_fp_verifier_index_c_row_comm(void*);
-void *zexe_bn382_fp_verifier_index_c_col_comm(void*);
-void *zexe_bn382_fp_verifier_index_c_val_comm(void*);
-void *zexe_bn382_fp_verifier_index_c_rc_comm(void*);
-
-void *zexe_bn382_fp_verifier_index_vector_create();
-int zexe_bn382_fp_verifier_index_vector_length(void *);
-void zexe_bn382_fp_verifier_index_vector_emplace_back(void *, void *);
-void *zexe_bn382_fp_verifier_index_vector_get(void *, int);
-void zexe_bn382_fp_verifier_index_vector_delete(void *);
-
-// Fq verifier index
-void *zexe_bn382_fq_verifier_index_create(void*);
-void zexe_bn382_fq_verifier_index_delete(void*);
-void *zexe_bn382_fq_verifier_index_urs(void*);
-
-void *zexe_bn382_fq_verifier_index_make(
-    size_t, size_t, size_t, size_t, size_t,
-    void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void* );
-
-void zexe_bn382_fq_verifier_index_write(void*, void*);
-void* zexe_bn382_fq_verifier_index_read(void*, void*);
-
-void *zexe_bn382_fq_verifier_index_a_row_comm(void*);
-void *zexe_bn382_fq_verifier_index_a_col_comm(void*);
-void *zexe_bn382_fq_verifier_index_a_val_comm(void*);
-void *zexe_bn382_fq_verifier_index_a_rc_comm(void*);
-
-void *zexe_bn382_fq_verifier_index_b_row_comm(void*);
-void *zexe_bn382_fq_verifier_index_b_col_comm(void*);
-void *zexe_bn382_fq_verifier_index_b_val_comm(void*);
-void *zexe_bn382_fq_verifier_index_b_rc_comm(void*);
-
-void *zexe_bn382_fq_verifier_index_c_row_comm(void*);
-void *zexe_bn382_fq_verifier_index_c_col_comm(void*);
-void *zexe_bn382_fq_verifier_index_c_val_comm(void*);
-void *zexe_bn382_fq_verifier_index_c_rc_comm(void*);
-
-void *zexe_bn382_fq_verifier_index_vector_create();
-int zexe_bn382_fq_verifier_index_vector_length(void *);
-void zexe_bn382_fq_verifier_index_vector_emplace_back(void *, void *);
-void *zexe_bn382_fq_verifier_index_vector_get(void *, int);
-void zexe_bn382_fq_verifier_index_vector_delete(void *);
-
-// Fp URS
-void *zexe_bn382_fp_urs_create(size_t);
-void zexe_bn382_fp_urs_delete(void*);
-void zexe_bn382_fp_urs_write(void*, char*);
-void* zexe_bn382_fp_urs_read(char*);
-void* zexe_bn382_fp_urs_lagrange_commitment(void*, size_t, size_t);
-void* zexe_bn382_fp_urs_commit_evaluations(void*, size_t, void*);
-void* zexe_bn382_fp_urs_dummy_opening_check(void*);
-void* zexe_bn382_fp_urs_dummy_degree_bound_checks(void*, void*);
-
-// Fq URS
-void *zexe_bn382_fq_urs_create(size_t);
-void zexe_bn382_fq_urs_delete(void *);
-void zexe_bn382_fq_urs_write(void*, char*);
-void* zexe_bn382_fq_urs_read(char*);
-void* zexe_bn382_fq_urs_lagrange_commitment(void*, size_t, size_t);
-void* zexe_bn382_fq_urs_commit_evaluations(void*, size_t, void*);
-void* zexe_bn382_fq_urs_b_poly_commitment(void*, void*);
-void* zexe_bn382_fq_urs_h(void*);
-void* zexe_bn382_fq_urs_batch_accumulator_check(void*, void*, void*);
-
-// Fp index
-
-size_t zexe_bn382_fp_index_domain_h_size(void*);
-size_t zexe_bn382_fp_index_domain_k_size(void*);
-
-void *zexe_bn382_fp_index_create(void*, void*, void*, size_t, size_t, void*);
-
-void zexe_bn382_fp_index_delete(void *);
-
-size_t zexe_bn382_fp_index_num_variables(void*);
-size_t zexe_bn382_fp_index_public_inputs(void*);
-size_t zexe_bn382_fp_index_nonzero_entries(void*);
-size_t zexe_bn382_fp_index_max_degree(void*);
-
-void zexe_bn382_fp_index_write(void*, char*);
-void* zexe_bn382_fp_index_read(void*, void*, void*, void*, size_t, char*);
-// Fq index
-
-size_t zexe_bn382_fq_index_domain_h_size(void*);
-size_t zexe_bn382_fq_index_domain_k_size(void*);
-
-void *zexe_bn382_fq_index_create(void*, void*, void*, size_t, size_t, void*);
-
-void zexe_bn382_fq_index_delete(void *);
-
-size_t zexe_bn382_fq_index_num_variables(void*);
-size_t zexe_bn382_fq_index_public_inputs(void*);
-size_t zexe_bn382_fq_index_nonzero_entries(void*);
-size_t zexe_bn382_fq_index_max_degree(void*);
-
-void zexe_bn382_fq_index_write(void*, char*);
-void* zexe_bn382_fq_index_read(void*, void*, void*, void*, size_t, char*);
-
-// Fp proof
-
-void zexe_bn382_fp_proof_delete(void *);
-void *zexe_bn382_fp_proof_create(void *, void* , void*);
-bool *zexe_bn382_fp_proof_verify(void *, void*);
-bool zexe_bn382_fp_proof_batch_verify(void *, void*);
-void *zexe_bn382_fp_proof_make(void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *,void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_generic(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_w_comm(void *);
-void *zexe_bn382_fp_proof_za_comm(void *);
-void *zexe_bn382_fp_proof_zb_comm(void *);
-void *zexe_bn382_fp_proof_h1_comm(void *);
-void *zexe_bn382_fp_proof_h2_comm(void *);
-void *zexe_bn382_fp_proof_h3_comm(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_poseidon(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_g1_comm_nocopy(void *);
-void *zexe_bn382_fp_proof_g2_comm_nocopy(void *);
-void *zexe_bn382_fp_proof_g3_comm_nocopy(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_add1(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_commitment_with_degree_bound_0(void *);
-void *zexe_bn382_fp_proof_commitment_with_degree_bound_1(void *);
-void zexe_bn382_fp_proof_commitment_with_degree_bound_delete(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_add2(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_proof1(void *);
-void *zexe_bn382_fp_proof_proof2(void *);
-void *zexe_bn382_fp_proof_proof3(void *);
-void *zexe_bn382_fp_proof_sigma2(void *);
-void *zexe_bn382_fp_proof_sigma3(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_vbmul1(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_w_eval(void *);
-void *zexe_bn382_fp_proof_za_eval(void *);
-void *zexe_bn382_fp_proof_zb_eval(void *);
-void *zexe_bn382_fp_proof_h1_eval(void *);
-void *zexe_bn382_fp_proof_g1_eval(void *);
-void *zexe_bn382_fp_proof_h2_eval(void *);
-void *zexe_bn382_fp_proof_g2_eval(void *);
-void *zexe_bn382_fp_proof_h3_eval(void *);
-void *zexe_bn382_fp_proof_g3_eval(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_vbmul2(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_row_evals_nocopy(void *);
-void *zexe_bn382_fp_proof_col_evals_nocopy(void *);
-void *zexe_bn382_fp_proof_val_evals_nocopy(void *);
-void *zexe_bn382_fp_proof_rc_evals_nocopy(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_vbmul3(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_evals_0(void *);
-void *zexe_bn382_fp_proof_evals_1(void *);
-void *zexe_bn382_fp_proof_evals_2(void *);
-void zexe_bn382_fp_proof_evals_delete(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_endomul1(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-// Fp proof vector
+void zexe_tweedle_plonk_fq_gate_vector_add_endomul2(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void *zexe_bn382_fp_proof_vector_create();
+void zexe_tweedle_plonk_fq_gate_vector_add_endomul3(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-int zexe_bn382_fp_proof_vector_length(void *);
+void zexe_tweedle_plonk_fq_gate_vector_add_endomul4(Vec_Gate_Fq *v, uintptr_t row, uintptr_t lrow, int lcol, uintptr_t rrow, int rcol, uintptr_t orow, int ocol, const Vec_Fq *c);
 
-void zexe_bn382_fp_proof_vector_emplace_back(void *, void *);
+void zexe_tweedle_plonk_fq_gate_vector_wrap(Vec_Gate_Fq *v, uintptr_t trow, int tcol, uintptr_t hrow, int hcol);
 
-void *zexe_bn382_fp_proof_vector_get(void *, int);
+void zexe_tweedle_plonk_fq_gate_vector_delete(Vec_Gate_Fq *x);
 
-void zexe_bn382_fp_proof_vector_delete(void *);
+ConstraintSystem_Fq *zexe_tweedle_plonk_fq_constraint_system_create(Vec_CircuitGate_Fq *v, uintptr_t public_);
 
-// Fq proof
+void zexe_tweedle_plonk_fq_constraint_system_delete(ConstraintSystem_Fq *x);
 
-void zexe_bn382_fq_proof_delete(void *);
-void *zexe_bn382_fq_proof_create(void *, void* , void*, void*, void*);
-bool zexe_bn382_fq_proof_batch_verify(void *, void*);
-bool zexe_bn382_fq_proof_verify(void *, void*);
-void *zexe_bn382_fq_proof_make(
-    void*,
-
-    void*, void*, void*,
-    void*, void*, void*,
-    void*, void*, void*,
-
-    void*, void*,
-
-    void*, void*, void*, void*, void*,
-
-    void*, void*, void*,
-    void*, void*
-);
-
-void *zexe_bn382_fq_proof_w_comm(void *);
-void *zexe_bn382_fq_proof_za_comm(void *);
-void *zexe_bn382_fq_proof_zb_comm(void *);
-void *zexe_bn382_fq_proof_h1_comm(void *);
-void *zexe_bn382_fq_proof_h2_comm(void *);
-void *zexe_bn382_fq_proof_h3_comm(void *);
-
-void *zexe_bn382_fq_proof_g1_comm_nocopy(void *);
-void *zexe_bn382_fq_proof_g2_comm_nocopy(void *);
-void *zexe_bn382_fq_proof_g3_comm_nocopy(void *);
-
-void *zexe_bn382_fq_proof_evals_nocopy(void *);
-
-void *zexe_bn382_fq_proof_proof(void *);
-
-void *zexe_bn382_fq_proof_sigma2(void *);
-void *zexe_bn382_fq_proof_sigma3(void *);
-
-// Fp proof vector
-
-void *zexe_bn382_fq_proof_vector_create();
-
-int zexe_bn382_fq_proof_vector_length(void *);
-
-void zexe_bn382_fq_proof_vector_emplace_back(void *, void *);
-
-void *zexe_bn382_fq_proof_vector_get(void *, int);
-
-void zexe_bn382_fq_proof_vector_delete(void *);
-
-// Fq proof evaluations
-
-void *zexe_bn382_fq_proof_evaluations_w(void *);
-void *zexe_bn382_fq_proof_evaluations_za(void *);
-void *zexe_bn382_fq_proof_evaluations_zb(void *);
-void *zexe_bn382_fq_proof_evaluations_h1(void *);
-void *zexe_bn382_fq_proof_evaluations_h2(void *);
-void *zexe_bn382_fq_proof_evaluations_h3(void *);
-void *zexe_bn382_fq_proof_evaluations_g1(void *);
-void *zexe_bn382_fq_proof_evaluations_g2(void *);
-void *zexe_bn382_fq_proof_evaluations_g3(void *);
-
-void *zexe_bn382_fq_proof_evaluations_make(
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *
-);
-void zexe_bn382_fq_proof_evaluations_delete(void *);
-
-void *zexe_bn382_fq_proof_evaluations_row_nocopy(void *);
-void *zexe_bn382_fq_proof_evaluations_col_nocopy(void *);
-void *zexe_bn382_fq_proof_evaluations_val_nocopy(void *);
-void *zexe_bn382_fq_proof_evaluations_rc_nocopy(void *);
-
-void *zexe_bn382_fq_proof_evaluations_triple_0(void *);
-void *zexe_bn382_fq_proof_evaluations_triple_1(void *);
-void *zexe_bn382_fq_proof_evaluations_triple_2(void *);
-void *zexe_bn382_fq_proof_evaluations_triple_delete(void *);
-
-// Fq opening proof
-void zexe_bn382_fq_opening_proof_delete(void *);
-void *zexe_bn382_fq_opening_proof_lr(void *);
-void *zexe_bn382_fq_opening_proof_z1(void *);
-void *zexe_bn382_fq_opening_proof_z2(void *);
-void *zexe_bn382_fq_opening_proof_delta(void *);
-void *zexe_bn382_fq_opening_proof_sg(void *);
-void zexe_bn382_fq_opening_proof_delete(void *);
-
-// G
-void *zexe_bn382_g_one();
-void *zexe_bn382_g_random();
-void zexe_bn382_g_delete(void *);
-void *zexe_bn382_g_add(void *, void *);
-void *zexe_bn382_g_double(void *);
-void *zexe_bn382_g_scale(void *, void *);
-void *zexe_bn382_g_sub(void *, void *);
-void *zexe_bn382_g_negate(void *);
-void *zexe_bn382_g_to_affine(void *);
-void *zexe_bn382_g_of_affine(void *);
-void *zexe_bn382_g_of_affine_coordinates(void *, void*);
-void *zexe_bn382_g_affine_create(void *, void*);
-void *zexe_bn382_g_affine_x(void *);
-void *zexe_bn382_g_affine_y(void *);
-bool zexe_bn382_g_affine_is_zero(void *);
-void zexe_bn382_g_affine_delete(void *);
-
-void *zexe_bn382_g_affine_vector_create();
-int zexe_bn382_g_affine_vector_length(void *);
-void zexe_bn382_g_affine_vector_emplace_back(void *, void *);
-void *zexe_bn382_g_affine_vector_get(void *, int);
-void zexe_bn382_g_affine_vector_delete(void *);
-
-void *zexe_bn382_g_affine_pair_0(void *);
-void *zexe_bn382_g_affine_pair_1(void *);
-void *zexe_bn382_g_affine_pair_make(void *, void*);
-void zexe_bn382_g_affine_pair_delete(void *);
-
-void *zexe_bn382_g_affine_pair_vector_create();
-int zexe_bn382_g_affine_pair_vector_length(void *);
-void zexe_bn382_g_affine_pair_vector_emplace_back(void *, void *);
-void *zexe_bn382_g_affine_pair_vector_get(void *, int);
-void zexe_bn382_g_affine_pair_vector_delete(void *);
-
-// G1
-void *zexe_bn382_g1_one();
-void *zexe_bn382_g1_random();
-void zexe_bn382_g1_delete(void *);
-void *zexe_bn382_g1_add(void *, void *);
-void *zexe_bn382_g1_double(void *);
-void *zexe_bn382_g1_scale(void *, void *);
-void *zexe_bn382_g1_sub(void *, void *);
-void *zexe_bn382_g1_negate(void *);
-void *zexe_bn382_g1_to_affine(void *);
-void *zexe_bn382_g1_of_affine(void *);
-void *zexe_bn382_g1_of_affine_coordinates(void *, void*);
-void *zexe_bn382_g1_affine_create(void *, void*);
-void *zexe_bn382_g1_affine_x(void *);
-void *zexe_bn382_g1_affine_y(void *);
-bool zexe_bn382_g1_affine_is_zero(void *);
-void zexe_bn382_g1_affine_delete(void *);
-
-void *zexe_bn382_g1_affine_vector_create();
-int zexe_bn382_g1_affine_vector_length(void *);
-void zexe_bn382_g1_affine_vector_emplace_back(void *, void *);
-void *zexe_bn382_g1_affine_vector_get(void *, int);
-void zexe_bn382_g1_affine_vector_delete(void *);
-
-void *zexe_bn382_g1_affine_pair_0(void *);
-void *zexe_bn382_g1_affine_pair_1(void *);
-void *zexe_bn382_g1_affine_pair_make(void *, void *);
-void zexe_bn382_g1_affine_pair_delete(void *);
-
-void *zexe_bn382_g1_affine_pair_vector_create();
-int zexe_bn382_g1_affine_pair_vector_length(void *);
-void zexe_bn382_g1_affine_pair_vector_emplace_back(void *, void *);
-void *zexe_bn382_g1_affine_pair_vector_get(void *, int);
-void zexe_bn382_g1_affine_pair_vector_delete(void *);
-
-// Fp triple
-void *zexe_bn382_fp_triple_0(void *);
-void *zexe_bn382_fp_triple_1(void *);
-void *zexe_bn382_fp_triple_2(void *);
-void zexe_bn382_fp_triple_delete(void *);
-void *zexe_bn382_fp_vector_triple_0(void *);
-void *zexe_bn382_fp_vector_triple_1(void *);
-void *zexe_bn382_fp_vector_triple_2(void *);
-void zexe_bn382_fp_vector_triple_delete(void *);
-
-// Fq triple
-void *zexe_bn382_fq_triple_0(void *);
-void *zexe_bn382_fq_triple_1(void *);
-void *zexe_bn382_fq_triple_2(void *);
-void zexe_bn382_fq_triple_delete(void *);
-void *zexe_bn382_fq_vector_triple_0(void *);
-void *zexe_bn382_fq_vector_triple_1(void *);
-void *zexe_bn382_fq_vector_triple_2(void *);
-void zexe_bn382_fq_vector_triple_delete(void *);
-
-// Fq poly comm
-void *zexe_bn382_fq_poly_comm_shifted(void *);
-void *zexe_bn382_fq_poly_comm_unshifted(void *);
-void *zexe_bn382_fq_poly_comm_make(void *, void *);
-void zexe_bn382_fq_poly_comm_delete(void *);
-
-// Fq poly comm vector
-void *zexe_bn382_fq_poly_comm_vector_create();
-int zexe_bn382_fq_poly_comm_vector_length(void *);
-void zexe_bn382_fq_poly_comm_vector_emplace_back(void *, void *);
-void *zexe_bn382_fq_poly_comm_vector_get(void *, int);
-void zexe_bn382_fq_poly_comm_vector_delete(void *);
-
-// Misc
-bool zexe_bn382_batch_pairing_check(void *,void *,void *,void *,void *,void *);
-
-// Tweedle
-
-// Fq
-
-int zexe_tweedle_fq_size_in_bits();
-void* zexe_tweedle_fq_endo_base();
-void* zexe_tweedle_fq_endo_scalar();
-void *zexe_tweedle_fq_size();
-bool zexe_tweedle_fq_is_square(void *);
-void *zexe_tweedle_fq_sqrt(void *);
-void *zexe_tweedle_fq_random();
-void *zexe_tweedle_fq_two_adic_root_of_unity();
-void *zexe_tweedle_fq_of_int(uint64_t);
-void *zexe_tweedle_fq_domain_generator(size_t);
-char *zexe_tweedle_fq_to_string(void *);
-void *zexe_tweedle_fq_inv(void *);
-void *zexe_tweedle_fq_square(void *);
-void *zexe_tweedle_fq_add(void *, void *);
-void *zexe_tweedle_fq_negate(void *);
-void *zexe_tweedle_fq_mul(void *, void *);
-void *zexe_tweedle_fq_div(void *, void *);
-void *zexe_tweedle_fq_sub(void *, void *);
-void zexe_tweedle_fq_mut_add(void *, void *);
-void zexe_tweedle_fq_mut_mul(void *, void *);
-void zexe_tweedle_fq_mut_square(void *);
-void zexe_tweedle_fq_mut_sub(void *, void *);
-void zexe_tweedle_fq_copy(void *, void *);
-void *zexe_tweedle_fq_rng(int i);
-void zexe_tweedle_fq_delete(void *);
-void zexe_tweedle_fq_print(void *);
-bool zexe_tweedle_fq_equal(void *, void *);
-void *zexe_tweedle_fq_to_bigint(void *);
-void *zexe_tweedle_fq_of_bigint(void *);
-void *zexe_tweedle_fq_to_bigint_raw(void *);
-void *zexe_tweedle_fq_to_bigint_raw_noalloc(void *);
-void *zexe_tweedle_fq_of_bigint_raw(void *);
-
-void *zexe_tweedle_fq_det_sqrt(void *);
-struct det_sqrt_witness zexe_tweedle_fq_det_sqrt_witness(void *);
-
-// Fq vector
-
-void *zexe_tweedle_fq_vector_create();
-int zexe_tweedle_fq_vector_length(void *);
-void zexe_tweedle_fq_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fq_vector_get(void *, int);
-void zexe_tweedle_fq_vector_delete(void *);
-
-// Fq CsMat
-
-void *zexe_tweedle_fq_csmat_create(int, int);
-void *zexe_tweedle_fq_csmat_append_row(void *, void *, void *);
-void zexe_tweedle_fq_csmat_delete(void *);
-
-// Fq constraint matrix
-
-void *zexe_tweedle_fq_constraint_matrix_create();
-void zexe_tweedle_fq_constraint_matrix_append_row(void *, void*, void*);
-void zexe_tweedle_fq_constraint_matrix_delete(void *);
-
-// Fq oracles
-void *zexe_tweedle_fq_oracles_create(void*, void*);
-void zexe_tweedle_fq_oracles_delete(void*);
-
-void* zexe_tweedle_fq_oracles_opening_prechallenges(void*);
-void* zexe_tweedle_fq_oracles_alpha(void*);
-void* zexe_tweedle_fq_oracles_eta_a(void*);
-void* zexe_tweedle_fq_oracles_eta_b(void*);
-void* zexe_tweedle_fq_oracles_eta_c(void*);
-void* zexe_tweedle_fq_oracles_beta1(void*);
-void* zexe_tweedle_fq_oracles_beta2(void*);
-void* zexe_tweedle_fq_oracles_beta3(void*);
-void* zexe_tweedle_fq_oracles_polys(void*);
-void* zexe_tweedle_fq_oracles_evals(void*);
-void* zexe_tweedle_fq_oracles_digest_before_evaluations(void*);
-void* zexe_tweedle_fq_oracles_x_hat_nocopy(void*);
-
-// Fq verifier index
-void *zexe_tweedle_fq_verifier_index_create(void*);
-void zexe_tweedle_fq_verifier_index_delete(void*);
-void *zexe_tweedle_fq_verifier_index_urs(void*);
-
-void *zexe_tweedle_fq_verifier_index_make(
-    size_t, size_t, size_t, size_t, size_t,
-    void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void* );
-
-void zexe_tweedle_fq_verifier_index_write(void*, void*);
-void* zexe_tweedle_fq_verifier_index_read(void*, void*);
-
-void *zexe_tweedle_fq_verifier_index_a_row_comm(void*);
-void *zexe_tweedle_fq_verifier_index_a_col_comm(void*);
-void *zexe_tweedle_fq_verifier_index_a_val_comm(void*);
-void *zexe_tweedle_fq_verifier_index_a_rc_comm(void*);
-
-void *zexe_tweedle_fq_verifier_index_b_row_comm(void*);
-void *zexe_tweedle_fq_verifier_index_b_col_comm(void*);
-void *zexe_tweedle_fq_verifier_index_b_val_comm(void*);
-void *zexe_tweedle_fq_verifier_index_b_rc_comm(void*);
-
-void *zexe_tweedle_fq_verifier_index_c_row_comm(void*);
-void *zexe_tweedle_fq_verifier_index_c_col_comm(void*);
-void *zexe_tweedle_fq_verifier_index_c_val_comm(void*);
-void *zexe_tweedle_fq_verifier_index_c_rc_comm(void*);
-
-void *zexe_tweedle_fq_verifier_index_vector_create();
-int zexe_tweedle_fq_verifier_index_vector_length(void *);
-void zexe_tweedle_fq_verifier_index_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fq_verifier_index_vector_get(void *, int);
-void zexe_tweedle_fq_verifier_index_vector_delete(void *);
-
-// Fq URS
-void *zexe_tweedle_fq_urs_create(size_t);
-void zexe_tweedle_fq_urs_delete(void*);
-void zexe_tweedle_fq_urs_write(void*, char*);
-void* zexe_tweedle_fq_urs_read(char*);
-void* zexe_tweedle_fq_urs_lagrange_commitment(void*, size_t, size_t);
-void* zexe_tweedle_fq_urs_commit_evaluations(void*, size_t, void*);
-void* zexe_tweedle_fq_urs_b_poly_commitment(void*, void*);
-void* zexe_tweedle_fq_urs_h(void*);
-void* zexe_tweedle_fq_urs_batch_accumulator_check(void*, void*, void*);
-
-// Fq index
-
-size_t zexe_tweedle_fq_index_domain_h_size(void*);
-size_t zexe_tweedle_fq_index_domain_k_size(void*);
-
-void *zexe_tweedle_fq_index_create(void*, void*, void*, size_t, size_t, void*);
-
-void zexe_tweedle_fq_index_delete(void *);
-
-size_t zexe_tweedle_fq_index_num_variables(void*);
-size_t zexe_tweedle_fq_index_public_inputs(void*);
-size_t zexe_tweedle_fq_index_nonzero_entries(void*);
-size_t zexe_tweedle_fq_index_max_degree(void*);
-
-void zexe_tweedle_fq_index_write(void*, char*);
-void* zexe_tweedle_fq_index_read(void*, void*, void*, void*, size_t, char*);
-
-// Fq proof
-
-void zexe_tweedle_fq_proof_delete(void *);
-void *zexe_tweedle_fq_proof_create(void *, void* , void*, void*, void*);
-bool zexe_tweedle_fq_proof_batch_verify(void *, void*);
-bool zexe_tweedle_fq_proof_verify(void *, void*);
-void *zexe_tweedle_fq_proof_make(
-    void*,
-
-    void*, void*, void*,
-    void*, void*, void*,
-    void*, void*, void*,
-
-    void*, void*,
-
-    void*, void*, void*, void*, void*,
-
-    void*, void*, void*,
-    void*, void*
-);
-
-void *zexe_tweedle_fq_proof_w_comm(void *);
-void *zexe_tweedle_fq_proof_za_comm(void *);
-void *zexe_tweedle_fq_proof_zb_comm(void *);
-void *zexe_tweedle_fq_proof_h1_comm(void *);
-void *zexe_tweedle_fq_proof_h2_comm(void *);
-void *zexe_tweedle_fq_proof_h3_comm(void *);
-
-void *zexe_tweedle_fq_proof_g1_comm_nocopy(void *);
-void *zexe_tweedle_fq_proof_g2_comm_nocopy(void *);
-void *zexe_tweedle_fq_proof_g3_comm_nocopy(void *);
-
-void *zexe_tweedle_fq_proof_evals_nocopy(void *);
-
-void *zexe_tweedle_fq_proof_proof(void *);
-
-void *zexe_tweedle_fq_proof_sigma2(void *);
-void *zexe_tweedle_fq_proof_sigma3(void *);
-
-// Fq proof vector
-
-void *zexe_tweedle_fq_proof_vector_create();
-
-int zexe_tweedle_fq_proof_vector_length(void *);
-
-void zexe_tweedle_fq_proof_vector_emplace_back(void *, void *);
-
-void *zexe_tweedle_fq_proof_vector_get(void *, int);
-
-void zexe_tweedle_fq_proof_vector_delete(void *);
-
-// Fq proof evaluations
-
-void *zexe_tweedle_fq_proof_evaluations_w(void *);
-void *zexe_tweedle_fq_proof_evaluations_za(void *);
-void *zexe_tweedle_fq_proof_evaluations_zb(void *);
-void *zexe_tweedle_fq_proof_evaluations_h1(void *);
-void *zexe_tweedle_fq_proof_evaluations_h2(void *);
-void *zexe_tweedle_fq_proof_evaluations_h3(void *);
-void *zexe_tweedle_fq_proof_evaluations_g1(void *);
-void *zexe_tweedle_fq_proof_evaluations_g2(void *);
-void *zexe_tweedle_fq_proof_evaluations_g3(void *);
-
-void *zexe_tweedle_fq_proof_evaluations_make(
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *
-);
-void zexe_tweedle_fq_proof_evaluations_delete(void *);
-
-void *zexe_tweedle_fq_proof_evaluations_row_nocopy(void *);
-void *zexe_tweedle_fq_proof_evaluations_col_nocopy(void *);
-void *zexe_tweedle_fq_proof_evaluations_val_nocopy(void *);
-void *zexe_tweedle_fq_proof_evaluations_rc_nocopy(void *);
-
-void *zexe_tweedle_fq_proof_evaluations_triple_0(void *);
-void *zexe_tweedle_fq_proof_evaluations_triple_1(void *);
-void *zexe_tweedle_fq_proof_evaluations_triple_2(void *);
-void zexe_tweedle_fq_proof_evaluations_triple_delete(void *);
-
-// Fq opening proof
-void zexe_tweedle_fq_opening_proof_delete(void *);
-void *zexe_tweedle_fq_opening_proof_lr(void *);
-void *zexe_tweedle_fq_opening_proof_z1(void *);
-void *zexe_tweedle_fq_opening_proof_z2(void *);
-void *zexe_tweedle_fq_opening_proof_delta(void *);
-void *zexe_tweedle_fq_opening_proof_sg(void *);
-void zexe_tweedle_fq_opening_proof_delete(void *);
-
-// Fq triple
-void *zexe_tweedle_fq_triple_0(void *);
-void *zexe_tweedle_fq_triple_1(void *);
-void *zexe_tweedle_fq_triple_2(void *);
-void zexe_tweedle_fq_triple_delete(void *);
-void *zexe_tweedle_fq_vector_triple_0(void *);
-void *zexe_tweedle_fq_vector_triple_1(void *);
-void *zexe_tweedle_fq_vector_triple_2(void *);
-void zexe_tweedle_fq_vector_triple_delete(void *);
-
-// Fq poly comm
-void *zexe_tweedle_fq_poly_comm_shifted(void *);
-void *zexe_tweedle_fq_poly_comm_unshifted(void *);
-void *zexe_tweedle_fq_poly_comm_make(void *, void *);
-void zexe_tweedle_fq_poly_comm_delete(void *);
-
-// Fq poly comm vector
-void *zexe_tweedle_fq_poly_comm_vector_create();
-int zexe_tweedle_fq_poly_comm_vector_length(void *);
-void zexe_tweedle_fq_poly_comm_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fq_poly_comm_vector_get(void *, int);
-void zexe_tweedle_fq_poly_comm_vector_delete(void *);
-
-// dumm
-void *zexe_tweedle_dum_one();
-void *zexe_tweedle_dum_random();
-void zexe_tweedle_dum_delete(void *);
-void *zexe_tweedle_dum_add(void *, void *);
-void *zexe_tweedle_dum_double(void *);
-void *zexe_tweedle_dum_scale(void *, void *);
-void *zexe_tweedle_dum_sub(void *, void *);
-void *zexe_tweedle_dum_negate(void *);
-void *zexe_tweedle_dum_to_affine(void *);
-void *zexe_tweedle_dum_of_affine(void *);
-void *zexe_tweedle_dum_of_affine_coordinates(void *, void*);
-void *zexe_tweedle_dum_affine_create(void *, void*);
-void *zexe_tweedle_dum_affine_x(void *);
-void *zexe_tweedle_dum_affine_y(void *);
-bool zexe_tweedle_dum_affine_is_zero(void *);
-void zexe_tweedle_dum_affine_delete(void *);
-
-void *zexe_tweedle_dum_affine_vector_create();
-int zexe_tweedle_dum_affine_vector_length(void *);
-void zexe_tweedle_dum_affine_vector_emplace_back(void *, void *);
-void *zexe_tweedle_dum_affine_vector_get(void *, int);
-void zexe_tweedle_dum_affine_vector_delete(void *);
-
-void *zexe_tweedle_dum_affine_pair_0(void *);
-void *zexe_tweedle_dum_affine_pair_1(void *);
-void *zexe_tweedle_dum_affine_pair_make(void *, void*);
-void zexe_tweedle_dum_affine_pair_delete(void *);
-
-void *zexe_tweedle_dum_affine_pair_vector_create();
-int zexe_tweedle_dum_affine_pair_vector_length(void *);
-void zexe_tweedle_dum_affine_pair_vector_emplace_back(void *, void *);
-void *zexe_tweedle_dum_affine_pair_vector_get(void *, int);
-void zexe_tweedle_dum_affine_pair_vector_delete(void *);
-
-// Fp
-
-int zexe_tweedle_fp_size_in_bits();
-void* zexe_tweedle_fp_endo_base();
-void* zexe_tweedle_fp_endo_scalar();
-void *zexe_tweedle_fp_size();
-bool zexe_tweedle_fp_is_square(void *);
-void *zexe_tweedle_fp_sqrt(void *);
-void *zexe_tweedle_fp_random();
-void *zexe_tweedle_fp_two_adic_root_of_unity();
-void *zexe_tweedle_fp_of_int(uint64_t);
-void *zexe_tweedle_fp_domain_generator(size_t);
-char *zexe_tweedle_fp_to_string(void *);
-void *zexe_tweedle_fp_inv(void *);
-void *zexe_tweedle_fp_square(void *);
-void *zexe_tweedle_fp_add(void *, void *);
-void *zexe_tweedle_fp_negate(void *);
-void *zexe_tweedle_fp_mul(void *, void *);
-void *zexe_tweedle_fp_div(void *, void *);
-void *zexe_tweedle_fp_sub(void *, void *);
-void zexe_tweedle_fp_mut_add(void *, void *);
-void zexe_tweedle_fp_mut_mul(void *, void *);
-void zexe_tweedle_fp_mut_square(void *);
-void zexe_tweedle_fp_mut_sub(void *, void *);
-void zexe_tweedle_fp_copy(void *, void *);
-void *zexe_tweedle_fp_rng(int i);
-void zexe_tweedle_fp_delete(void *);
-void zexe_tweedle_fp_print(void *);
-bool zexe_tweedle_fp_equal(void *, void *);
-void *zexe_tweedle_fp_to_bigint(void *);
-void *zexe_tweedle_fp_of_bigint(void *);
-void *zexe_tweedle_fp_to_bigint_raw(void *);
-void *zexe_tweedle_fp_to_bigint_raw_noalloc(void *);
-void *zexe_tweedle_fp_of_bigint_raw(void *);
-
-void *zexe_tweedle_fp_det_sqrt(void *);
-struct det_sqrt_witness zexe_tweedle_fp_det_sqrt_witness(void *);
-
-// Fp vector
-
-void *zexe_tweedle_fp_vector_create();
-int zexe_tweedle_fp_vector_length(void *);
-void zexe_tweedle_fp_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fp_vector_get(void *, int);
-void zexe_tweedle_fp_vector_delete(void *);
-
-// Fp CsMat
-
-void *zexe_tweedle_fp_csmat_create(int, int);
-void *zexe_tweedle_fp_csmat_append_row(void *, void *, void *);
-void zexe_tweedle_fp_csmat_delete(void *);
-
-// Fp constraint matrix
-
-void *zexe_tweedle_fp_constraint_matrix_create();
-void zexe_tweedle_fp_constraint_matrix_append_row(void *, void*, void*);
-void zexe_tweedle_fp_constraint_matrix_delete(void *);
-
-// Fp oracles
-void *zexe_tweedle_fp_oracles_create(void*, void*);
-void zexe_tweedle_fp_oracles_delete(void*);
-
-void* zexe_tweedle_fp_oracles_opening_prechallenges(void*);
-void* zexe_tweedle_fp_oracles_alpha(void*);
-void* zexe_tweedle_fp_oracles_eta_a(void*);
-void* zexe_tweedle_fp_oracles_eta_b(void*);
-void* zexe_tweedle_fp_oracles_eta_c(void*);
-void* zexe_tweedle_fp_oracles_beta1(void*);
-void* zexe_tweedle_fp_oracles_beta2(void*);
-void* zexe_tweedle_fp_oracles_beta3(void*);
-void* zexe_tweedle_fp_oracles_polys(void*);
-void* zexe_tweedle_fp_oracles_evals(void*);
-void* zexe_tweedle_fp_oracles_digest_before_evaluations(void*);
-void* zexe_tweedle_fp_oracles_x_hat_nocopy(void*);
-
-// Fp verifier index
-void *zexe_tweedle_fp_verifier_index_create(void*);
-void zexe_tweedle_fp_verifier_index_delete(void*);
-void *zexe_tweedle_fp_verifier_index_urs(void*);
-
-void *zexe_tweedle_fp_verifier_index_make(
-    size_t, size_t, size_t, size_t, size_t,
-    void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void*,
-    void*, void*, void*, void* );
-
-void zexe_tweedle_fp_verifier_index_write(void*, void*);
-void* zexe_tweedle_fp_verifier_index_read(void*, void*);
-
-void *zexe_tweedle_fp_verifier_index_a_row_comm(void*);
-void *zexe_tweedle_fp_verifier_index_a_col_comm(void*);
-void *zexe_tweedle_fp_verifier_index_a_val_comm(void*);
-void *zexe_tweedle_fp_verifier_index_a_rc_comm(void*);
-
-void *zexe_tweedle_fp_verifier_index_b_row_comm(void*);
-void *zexe_tweedle_fp_verifier_index_b_col_comm(void*);
-void *zexe_tweedle_fp_verifier_index_b_val_comm(void*);
-void *zexe_tweedle_fp_verifier_index_b_rc_comm(void*);
-
-void *zexe_tweedle_fp_verifier_index_c_row_comm(void*);
-void *zexe_tweedle_fp_verifier_index_c_col_comm(void*);
-void *zexe_tweedle_fp_verifier_index_c_val_comm(void*);
-void *zexe_tweedle_fp_verifier_index_c_rc_comm(void*);
-
-void *zexe_tweedle_fp_verifier_index_vector_create();
-int zexe_tweedle_fp_verifier_index_vector_length(void *);
-void zexe_tweedle_fp_verifier_index_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fp_verifier_index_vector_get(void *, int);
-void zexe_tweedle_fp_verifier_index_vector_delete(void *);
-
-// Fp URS
-void *zexe_tweedle_fp_urs_create(size_t);
-void zexe_tweedle_fp_urs_delete(void*);
-void zexe_tweedle_fp_urs_write(void*, char*);
-void* zexe_tweedle_fp_urs_read(char*);
-void* zexe_tweedle_fp_urs_lagrange_commitment(void*, size_t, size_t);
-void* zexe_tweedle_fp_urs_commit_evaluations(void*, size_t, void*);
-void* zexe_tweedle_fp_urs_b_poly_commitment(void*, void*);
-void* zexe_tweedle_fp_urs_h(void*);
-void* zexe_tweedle_fp_urs_batch_accumulator_check(void*, void*, void*);
-
-// Fp index
-
-size_t zexe_tweedle_fp_index_domain_h_size(void*);
-size_t zexe_tweedle_fp_index_domain_k_size(void*);
-
-void *zexe_tweedle_fp_index_create(void*, void*, void*, size_t, size_t, void*);
-
-void zexe_tweedle_fp_index_delete(void *);
-
-size_t zexe_tweedle_fp_index_num_variables(void*);
-size_t zexe_tweedle_fp_index_public_inputs(void*);
-size_t zexe_tweedle_fp_index_nonzero_entries(void*);
-size_t zexe_tweedle_fp_index_max_degree(void*);
-
-void zexe_tweedle_fp_index_write(void*, char*);
-void* zexe_tweedle_fp_index_read(void*, void*, void*, void*, size_t, char*);
-
-// Fp proof
-
-void zexe_tweedle_fp_proof_delete(void *);
-void *zexe_tweedle_fp_proof_create(void *, void* , void*, void*, void*);
-bool zexe_tweedle_fp_proof_batch_verify(void *, void*);
-bool zexe_tweedle_fp_proof_verify(void *, void*);
-void *zexe_tweedle_fp_proof_make(
-    void*,
-
-    void*, void*, void*,
-    void*, void*, void*,
-    void*, void*, void*,
-
-    void*, void*,
-
-    void*, void*, void*, void*, void*,
-
-    void*, void*, void*,
-    void*, void*
-);
-
-void *zexe_tweedle_fp_proof_w_comm(void *);
-void *zexe_tweedle_fp_proof_za_comm(void *);
-void *zexe_tweedle_fp_proof_zb_comm(void *);
-void *zexe_tweedle_fp_proof_h1_comm(void *);
-void *zexe_tweedle_fp_proof_h2_comm(void *);
-void *zexe_tweedle_fp_proof_h3_comm(void *);
-
-void *zexe_tweedle_fp_proof_g1_comm_nocopy(void *);
-void *zexe_tweedle_fp_proof_g2_comm_nocopy(void *);
-void *zexe_tweedle_fp_proof_g3_comm_nocopy(void *);
-
-void *zexe_tweedle_fp_proof_evals_nocopy(void *);
-
-void *zexe_tweedle_fp_proof_proof(void *);
-
-void *zexe_tweedle_fp_proof_sigma2(void *);
-void *zexe_tweedle_fp_proof_sigma3(void *);
-
-// Fp proof vector
-
-void *zexe_tweedle_fp_proof_vector_create();
-
-int zexe_tweedle_fp_proof_vector_length(void *);
-
-void zexe_tweedle_fp_proof_vector_emplace_back(void *, void *);
-
-void *zexe_tweedle_fp_proof_vector_get(void *, int);
-
-void zexe_tweedle_fp_proof_vector_delete(void *);
-
-// Fp proof evaluations
-
-void *zexe_tweedle_fp_proof_evaluations_w(void *);
-void *zexe_tweedle_fp_proof_evaluations_za(void *);
-void *zexe_tweedle_fp_proof_evaluations_zb(void *);
-void *zexe_tweedle_fp_proof_evaluations_h1(void *);
-void *zexe_tweedle_fp_proof_evaluations_h2(void *);
-void *zexe_tweedle_fp_proof_evaluations_h3(void *);
-void *zexe_tweedle_fp_proof_evaluations_g1(void *);
-void *zexe_tweedle_fp_proof_evaluations_g2(void *);
-void *zexe_tweedle_fp_proof_evaluations_g3(void *);
-
-void *zexe_tweedle_fp_proof_evaluations_make(
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *,
-    void *, void *, void *
-);
-void zexe_tweedle_fp_proof_evaluations_delete(void *);
-
-void *zexe_tweedle_fp_proof_evaluations_row_nocopy(void *);
-void *zexe_tweedle_fp_proof_evaluations_col_nocopy(void *);
-void *zexe_tweedle_fp_proof_evaluations_val_nocopy(void *);
-void *zexe_tweedle_fp_proof_evaluations_rc_nocopy(void *);
-
-void *zexe_tweedle_fp_proof_evaluations_triple_0(void *);
-void *zexe_tweedle_fp_proof_evaluations_triple_1(void *);
-void *zexe_tweedle_fp_proof_evaluations_triple_2(void *);
-void zexe_tweedle_fp_proof_evaluations_triple_delete(void *);
-
-// Fp opening proof
-void zexe_tweedle_fp_opening_proof_delete(void *);
-void *zexe_tweedle_fp_opening_proof_lr(void *);
-void *zexe_tweedle_fp_opening_proof_z1(void *);
-void *zexe_tweedle_fp_opening_proof_z2(void *);
-void *zexe_tweedle_fp_opening_proof_delta(void *);
-void *zexe_tweedle_fp_opening_proof_sg(void *);
-void zexe_tweedle_fp_opening_proof_delete(void *);
-
-// Fp triple
-void *zexe_tweedle_fp_triple_0(void *);
-void *zexe_tweedle_fp_triple_1(void *);
-void *zexe_tweedle_fp_triple_2(void *);
-void *zexe_tweedle_fp_triple_delete(void *);
-void *zexe_tweedle_fp_vector_triple_0(void *);
-void *zexe_tweedle_fp_vector_triple_1(void *);
-void *zexe_tweedle_fp_vector_triple_2(void *);
-void zexe_tweedle_fp_vector_triple_delete(void *);
-
-// Fp poly comm
-void *zexe_tweedle_fp_poly_comm_shifted(void *);
-void *zexe_tweedle_fp_poly_comm_unshifted(void *);
-void *zexe_tweedle_fp_poly_comm_make(void *, void *);
-void zexe_tweedle_fp_poly_comm_delete(void *);
-
-// Fp poly comm vector
-void *zexe_tweedle_fp_poly_comm_vector_create();
-int zexe_tweedle_fp_poly_comm_vector_length(void *);
-void zexe_tweedle_fp_poly_comm_vector_emplace_back(void *, void *);
-void *zexe_tweedle_fp_poly_comm_vector_get(void *, int);
-void zexe_tweedle_fp_poly_comm_vector_delete(void *);
-
-// dee
-void *zexe_tweedle_dee_one();
-void *zexe_tweedle_dee_random();
-void zexe_tweedle_dee_delete(void *);
-void *zexe_tweedle_dee_add(void *, void *);
-void *zexe_tweedle_dee_double(void *);
-void *zexe_tweedle_dee_scale(void *, void *);
-void *zexe_tweedle_dee_sub(void *, void *);
-void *zexe_tweedle_dee_negate(void *);
-void *zexe_tweedle_dee_to_affine(void *);
-void *zexe_tweedle_dee_of_affine(void *);
-void *zexe_tweedle_dee_of_affine_coordinates(void *, void*);
-void *zexe_tweedle_dee_affine_create(void *, void*);
-void *zexe_tweedle_dee_affine_x(void *);
-void *zexe_tweedle_dee_affine_y(void *);
-bool zexe_tweedle_dee_affine_is_zero(void *);
-void zexe_tweedle_dee_affine_delete(void *);
-
-void *zexe_tweedle_dee_affine_vector_create();
-int zexe_tweedle_dee_affine_vector_length(void *);
-void zexe_tweedle_dee_affine_vector_emplace_back(void *, void *);
-void *zexe_tweedle_dee_affine_vector_get(void *, int);
-void zexe_tweedle_dee_affine_vector_delete(void *);
-
-void *zexe_tweedle_dee_affine_pair_0(void *);
-void *zexe_tweedle_dee_affine_pair_1(void *);
-void *zexe_tweedle_dee_affine_pair_make(void *, void*);
-void zexe_tweedle_dee_affine_pair_delete(void *);
-
-void *zexe_tweedle_dee_affine_pair_vector_create();
-int zexe_tweedle_dee_affine_pair_vector_length(void *);
-void zexe_tweedle_dee_affine_pair_vector_emplace_back(void *, void *);
-void *zexe_tweedle_dee_affine_pair_vector_get(void *, int);
-void zexe_tweedle_dee_affine_pair_vector_delete(void *);
-
-// Plonk
-
-// Fp index stubs
-size_t zexe_tweedle_plonk_fp_index_domain_d1_size(void *);
-size_t zexe_tweedle_plonk_fp_index_domain_d4_size(void *);
-size_t zexe_tweedle_plonk_fp_index_domain_d8_size(void *);
-
-void *zexe_tweedle_plonk_fp_index_create(void *, size_t, void *);
-void zexe_tweedle_plonk_fp_index_delete(void *);
-void zexe_tweedle_plonk_fp_index_write(void *, void *);
-void* zexe_tweedle_plonk_fp_index_read(void *, void*);
-
-size_t zexe_tweedle_plonk_fp_index_max_degree(void *);
-size_t zexe_tweedle_plonk_fp_index_public_inputs(void *);
-
-// Fp verifier index stubs
-struct pointer_pair zexe_tweedle_plonk_fp_verifier_index_shifts(size_t);
-
-void *zexe_tweedle_plonk_fp_verifier_index_create(void *);
-
-void *zexe_tweedle_plonk_fp_verifier_index_urs(void *);
-
-void *zexe_tweedle_plonk_fp_verifier_index_make(
-    size_t max_poly_size,
-    size_t max_quot_size,
-    void *urs,
-    void *sigma_comm0,
-    void *sigma_comm1,
-    void *sigma_comm2,
-    void *ql_comm,
-    void *qr_comm,
-    void *qo_comm,
-    void *qm_comm,
-    void *qc_comm,
-    void *rcm_comm0,
-    void *rcm_comm1,
-    void *rcm_comm2,
-    void *psm_comm,
-    void *add_comm,
-    void *mul1_comm,
-    void *mul2_comm,
-    void *emul1_comm,
-    void *emul2_comm,
-    void *emul3_comm,
-    void *r,
-    void *o);
-
-void zexe_tweedle_plonk_fp_verifier_index_delete(void *x);
-
-void *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_0(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_1(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_sigma_comm_2(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_ql_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_qr_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_qo_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_qm_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_qc_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_0(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_1(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_rcm_comm_2(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_psm_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_add_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_mul1_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_mul2_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_emul1_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_emul2_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_emul3_comm(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_r(void *);
-void *zexe_tweedle_plonk_fp_verifier_index_o(void *);
-uint32_t zexe_tweedle_plonk_fp_verifier_index_domain_log2(void *);
-void* zexe_tweedle_plonk_fp_verifier_index_domain_group_gen(void *);
-void zexe_tweedle_plonk_fp_verifier_index_write(void *, void *);
-void* zexe_tweedle_plonk_fp_verifier_index_read(void *, void *);
-
-// Fp proof
-void *zexe_tweedle_plonk_fp_proof_create(
-    void *index,
-    void *primary_input,
-    void *auxiliary_input,
-    void *prev_challenges,
-    void *prev_sgs);
-
-bool zexe_tweedle_plonk_fp_proof_verify(void* lgr_comm, void *index, void *proof);
-
-bool zexe_tweedle_plonk_fp_proof_batch_verify(void* lgr_comm, void *index, void *proofs);
-
-void *zexe_tweedle_plonk_fp_proof_make(
-    void *primary_input,
-
-    void *l_comm,
-    void *r_comm,
-    void *o_comm,
-    void *z_comm,
-    void *t_comm,
-
-    void *lr,
-    void *z1,
-    void *z2,
-    void *delta,
-    void *sg,
-
-    void *evals0,
-    void *evals1,
-    void *prev_challenges,
-    void *prev_sgs);
-
-void *zexe_tweedle_plonk_fp_proof_delete(void *);
-
-void *zexe_tweedle_plonk_fp_proof_l_comm(void *);
-void *zexe_tweedle_plonk_fp_proof_r_comm(void *);
-void *zexe_tweedle_plonk_fp_proof_o_comm(void *);
-void *zexe_tweedle_plonk_fp_proof_z_comm(void *);
-void *zexe_tweedle_plonk_fp_proof_t_comm(void *);
-void *zexe_tweedle_plonk_fp_proof_proof(void *);
-void *zexe_tweedle_plonk_fp_proof_evals_nocopy(void *);
-
-// Fp proof vector
-
-void *zexe_tweedle_plonk_fp_proof_vector_create(void);
-int zexe_tweedle_plonk_fp_proof_vector_length(void *);
-void zexe_tweedle_plonk_fp_proof_vector_emplace_back(void *v, void *x);
-void *zexe_tweedle_plonk_fp_proof_vector_get(void *v, int i);
-void zexe_tweedle_plonk_fp_proof_vector_delete(void *);
-
-// Fp opening proof
-void zexe_tweedle_plonk_fp_opening_proof_delete(void *);
-
-void *zexe_tweedle_plonk_fp_opening_proof_sg(void *);
-void *zexe_tweedle_plonk_fp_opening_proof_lr(void *);
-void *zexe_tweedle_plonk_fp_opening_proof_z1(void *);
-void *zexe_tweedle_plonk_fp_opening_proof_z2(void *);
-void *zexe_tweedle_plonk_fp_opening_proof_delta(void *);
-
-// Fp proof evaluations
-
-void *zexe_tweedle_plonk_fp_proof_evaluations_l(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_r(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_o(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_z(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_t(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_f(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_sigma1(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_sigma2(void *);
-
-void *zexe_tweedle_plonk_fp_proof_evaluations_make(
-    void *l,
-    void *r,
-    void *o,
-    void *z,
-    void *t,
-    void *f,
-    void *sigma1,
-    void *sigma2);
-void zexe_tweedle_plonk_fp_proof_evaluations_delete(void *);
-
-void *zexe_tweedle_plonk_fp_proof_evaluations_pair_0(void *);
-void *zexe_tweedle_plonk_fp_proof_evaluations_pair_1(void *);
-void zexe_tweedle_plonk_fp_proof_evaluations_pair_delete(void *);
-
-// Fp oracles
-
-void *zexe_tweedle_plonk_fp_oracles_create(void* lgr_comm, void *index, void *proof);
-
-void *zexe_tweedle_plonk_fp_oracles_opening_prechallenges(void *);
-void *zexe_tweedle_plonk_fp_oracles_p_eval1(void *);
-void *zexe_tweedle_plonk_fp_oracles_p_eval2(void *);
-void *zexe_tweedle_plonk_fp_oracles_alpha(void *);
-void *zexe_tweedle_plonk_fp_oracles_beta(void *);
-void *zexe_tweedle_plonk_fp_oracles_gamma(void *);
-void *zexe_tweedle_plonk_fp_oracles_zeta(void *);
-void *zexe_tweedle_plonk_fp_oracles_v(void *);
-void *zexe_tweedle_plonk_fp_oracles_u(void *);
-void* zexe_tweedle_plonk_fp_oracles_digest_before_evaluations(void*);
-void zexe_tweedle_plonk_fp_oracles_delete(void *);
-
-// Fp gate vector
-void *zexe_tweedle_plonk_fp_gate_vector_create(void);
-int zexe_tweedle_plonk_fp_gate_vector_length(void *);
-void zexe_tweedle_plonk_fp_gate_vector_delete(void *);
-void zexe_tweedle_plonk_fp_gate_vector_add(
-    void *v,
-    int gate_type,
-    size_t row,
-    size_t lrow,
-    int lcol,
-    size_t rrow,
-    int rcol,
-    size_t orow,
-    int ocol,
-    void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_zero(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_generic(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_poseidon(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_add1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_add2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_vbmul1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_vbmul2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_vbmul3(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_endomul1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_endomul2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_endomul3(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_add_endomul4(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fp_gate_vector_wrap(
-    void *,
-    size_t,
-    int,
-    size_t,
-    int);
-
-// Fp constraint system
-void *zexe_tweedle_plonk_fp_constraint_system_create(void *, size_t);
-void zexe_tweedle_plonk_fp_constraint_system_delete(void *);
-
-// Fq index stubs
-size_t zexe_tweedle_plonk_fq_index_domain_d1_size(void *);
-size_t zexe_tweedle_plonk_fq_index_domain_d4_size(void *);
-size_t zexe_tweedle_plonk_fq_index_domain_d8_size(void *);
-
-void *zexe_tweedle_plonk_fq_index_create(void *, size_t, void *);
-void zexe_tweedle_plonk_fq_index_delete(void *);
-void zexe_tweedle_plonk_fq_index_write(void *, void *);
-void* zexe_tweedle_plonk_fq_index_read(void *, void*);
-
-size_t zexe_tweedle_plonk_fq_index_max_degree(void *);
-size_t zexe_tweedle_plonk_fq_index_public_inputs(void *);
-
-// Fq verifier index stubs
-struct pointer_pair zexe_tweedle_plonk_fq_verifier_index_shifts(size_t);
-
-void zexe_tweedle_plonk_fq_verifier_index_write(void *, void *);
-void* zexe_tweedle_plonk_fq_verifier_index_read(void *, void*);
-void *zexe_tweedle_plonk_fq_verifier_index_create(void *);
-
-void *zexe_tweedle_plonk_fq_verifier_index_urs(void *);
-
-void *zexe_tweedle_plonk_fq_verifier_index_make(
-    size_t max_poly_size,
-    size_t max_quot_size,
-    void *urs,
-    void *sigma_comm0,
-    void *sigma_comm1,
-    void *sigma_comm2,
-    void *ql_comm,
-    void *qr_comm,
-    void *qo_comm,
-    void *qm_comm,
-    void *qc_comm,
-    void *rcm_comm0,
-    void *rcm_comm1,
-    void *rcm_comm2,
-    void *psm_comm,
-    void *add_comm,
-    void *mul1_comm,
-    void *mul2_comm,
-    void *emul1_comm,
-    void *emul2_comm,
-    void *emul3_comm,
-    void *r,
-    void *o);
-
-void zexe_tweedle_plonk_fq_verifier_index_delete(void *x);
-
-void *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_0(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_1(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_sigma_comm_2(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_ql_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_qr_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_qo_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_qm_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_qc_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_0(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_1(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_rcm_comm_2(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_psm_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_add_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_mul1_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_mul2_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_emul1_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_emul2_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_emul3_comm(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_r(void *);
-void *zexe_tweedle_plonk_fq_verifier_index_o(void *);
-uint32_t zexe_tweedle_plonk_fq_verifier_index_domain_log2(void *);
-void* zexe_tweedle_plonk_fq_verifier_index_domain_group_gen(void *);
-
-// Fq proof
-void *zexe_tweedle_plonk_fq_proof_create(
-    void *index,
-    void *primary_input,
-    void *auxiliary_input,
-    void *prev_challenges,
-    void *prev_sgs);
-
-bool zexe_tweedle_plonk_fq_proof_verify(void* lgr_comm, void *index, void *proof);
-
-bool zexe_tweedle_plonk_fq_proof_batch_verify(void* lgr_comm, void *index, void *proofs);
-
-void *zexe_tweedle_plonk_fq_proof_make(
-    void *primary_input,
-
-    void *l_comm,
-    void *r_comm,
-    void *o_comm,
-    void *z_comm,
-    void *t_comm,
-
-    void *lr,
-    void *z1,
-    void *z2,
-    void *delta,
-    void *sg,
-
-    void *evals0,
-    void *evals1,
-    void *prev_challenges,
-    void *prev_sgs);
-
-void *zexe_tweedle_plonk_fq_proof_delete(void *);
-
-void *zexe_tweedle_plonk_fq_proof_l_comm(void *);
-void *zexe_tweedle_plonk_fq_proof_r_comm(void *);
-void *zexe_tweedle_plonk_fq_proof_o_comm(void *);
-void *zexe_tweedle_plonk_fq_proof_z_comm(void *);
-void *zexe_tweedle_plonk_fq_proof_t_comm(void *);
-void *zexe_tweedle_plonk_fq_proof_proof(void *);
-void *zexe_tweedle_plonk_fq_proof_evals_nocopy(void *);
-
-// Fq proof vector
-
-void *zexe_tweedle_plonk_fq_proof_vector_create(void);
-int zexe_tweedle_plonk_fq_proof_vector_length(void *);
-void zexe_tweedle_plonk_fq_proof_vector_emplace_back(void *v, void *x);
-void *zexe_tweedle_plonk_fq_proof_vector_get(void *v, int i);
-void zexe_tweedle_plonk_fq_proof_vector_delete(void *);
-
-// Fq opening proof
-void zexe_tweedle_plonk_fq_opening_proof_delete(void *);
-
-void *zexe_tweedle_plonk_fq_opening_proof_sg(void *);
-void *zexe_tweedle_plonk_fq_opening_proof_lr(void *);
-void *zexe_tweedle_plonk_fq_opening_proof_z1(void *);
-void *zexe_tweedle_plonk_fq_opening_proof_z2(void *);
-void *zexe_tweedle_plonk_fq_opening_proof_delta(void *);
-
-// Fq proof evaluations
-
-void *zexe_tweedle_plonk_fq_proof_evaluations_l(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_r(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_o(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_z(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_t(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_f(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_sigma1(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_sigma2(void *);
-
-void *zexe_tweedle_plonk_fq_proof_evaluations_make(
-    void *l,
-    void *r,
-    void *o,
-    void *z,
-    void *t,
-    void *f,
-    void *sigma1,
-    void *sigma2);
-void zexe_tweedle_plonk_fq_proof_evaluations_delete(void *);
-
-void *zexe_tweedle_plonk_fq_proof_evaluations_pair_0(void *);
-void *zexe_tweedle_plonk_fq_proof_evaluations_pair_1(void *);
-void zexe_tweedle_plonk_fq_proof_evaluations_pair_delete(void *);
-
-// Fq oracles
-
-void *zexe_tweedle_plonk_fq_oracles_create(void* lgr_comm, void *index, void *proof);
-
-void *zexe_tweedle_plonk_fq_oracles_opening_prechallenges(void *);
-void *zexe_tweedle_plonk_fq_oracles_p_eval1(void *);
-void *zexe_tweedle_plonk_fq_oracles_p_eval2(void *);
-void *zexe_tweedle_plonk_fq_oracles_alpha(void *);
-void *zexe_tweedle_plonk_fq_oracles_beta(void *);
-void *zexe_tweedle_plonk_fq_oracles_gamma(void *);
-void *zexe_tweedle_plonk_fq_oracles_zeta(void *);
-void *zexe_tweedle_plonk_fq_oracles_v(void *);
-void *zexe_tweedle_plonk_fq_oracles_u(void *);
-void* zexe_tweedle_plonk_fq_oracles_digest_before_evaluations(void*);
-void zexe_tweedle_plonk_fq_oracles_delete(void *);
-
-// Fq gate vector
-void *zexe_tweedle_plonk_fq_gate_vector_create(void);
-int zexe_tweedle_plonk_fq_gate_vector_length(void *);
-void zexe_tweedle_plonk_fq_gate_vector_delete(void *);
-void zexe_tweedle_plonk_fq_gate_vector_add(
-    void *v,
-    int gate_type,
-    size_t row,
-    size_t lrow,
-    int lcol,
-    size_t rrow,
-    int rcol,
-    size_t orow,
-    int ocol,
-    void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_zero(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_generic(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_poseidon(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_add1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_add2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_vbmul1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_vbmul2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_vbmul3(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_endomul1(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_endomul2(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_endomul3(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_add_endomul4(
-    void *v, size_t row, size_t lrow, int lcol, size_t rrow, int rcol, size_t orow,
-    int ocol, void *c);
-
-void zexe_tweedle_plonk_fq_gate_vector_wrap(
-    void *,
-    size_t,
-    int,
-    size_t,
-    int);
-
-// Fq constraint system
-void *zexe_tweedle_plonk_fq_constraint_system_create(void *, size_t);
-void zexe_tweedle_plonk_fq_constraint_system_delete(void *);
+#endif /* SNARKY_BN382_H */
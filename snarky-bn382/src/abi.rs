@@ -0,0 +1,32 @@
+use crate::common::catch_ffi;
+use std::os::raw::c_char;
+
+// ABI manifest
+//
+// The names and signatures of the stubs, generated by build.rs from the header
+// and the sources, for bindings to check at startup that they match the
+// loaded library rather than failing on the first mismatched call.
+
+const ABI_MANIFEST: &str = concat!(
+    include_str!(concat!(env!("OUT_DIR"), "/abi_manifest.json")),
+    "\0"
+);
+
+// The version of the ABI. Bump it whenever a stub changes incompatibly.
+// build.rs reads it from here, for the manifest and, with the
+// versioned-symbols feature, the symbol version of every stub,
+// SNARKY_BN382_<version>.
+pub const ABI_VERSION: u32 = 5;
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_abi_version() -> u32 {
+    catch_ffi("zexe_bn382_abi_version", 0, || ABI_VERSION)
+}
+
+// Returns the manifest as a static JSON string, which must not be freed.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_abi_manifest_json() -> *const c_char {
+    catch_ffi("zexe_bn382_abi_manifest_json", std::ptr::null(), || {
+        ABI_MANIFEST.as_ptr() as *const c_char
+    })
+}
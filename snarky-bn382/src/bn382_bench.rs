@@ -0,0 +1,177 @@
+use crate::bn382_dlog::{fq_lagrange_commitments, fq_opening_batch_check, fq_verify_phase1};
+use crate::common::*;
+use algebra::bn_382::g::Affine as GAffine;
+use marlin_protocol_dlog::index::VerifierIndex as DlogVerifierIndex;
+use marlin_protocol_dlog::prover::ProverProof as DlogProof;
+use rayon::prelude::*;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::time::{Duration, Instant};
+
+// Fq verification benchmark
+//
+// Measures the throughput of verifying Fq proofs from inside the crate, so
+// that the numbers are those of the code the library was built from. As with
+// criterion, each configuration is run once to warm up, then timed over a
+// number of samples, and summarized by the median sample.
+//
+// A configuration is a batch size and whether the precomputed tables are used.
+// The tables are the Lagrange commitments of the x domain: without them, each
+// proof commits to its public input with an MSM over the SRS, with them with
+// a short MSM over the commitments. They are computed before timing starts.
+//
+// Each run is timed in its two phases: phase 1, the checks of each proof
+// against its oracles, in parallel, as zexe_bn382_fq_proof_verify_phase1 does,
+// which returns the inputs of the opening check; then the opening check of the
+// whole batch alone, from those inputs, as zexe_bn382_fq_proof_verify_phase2
+// does. A batch is the proofs given, repeated as needed, and is laid out
+// before its runs. Since the tables only change phase 1, the report compares
+// each phase with and without them.
+
+struct Sample {
+    phase1: Duration,
+    opening: Duration,
+}
+
+impl Sample {
+    fn total(&self) -> Duration {
+        self.phase1 + self.opening
+    }
+}
+
+fn run(
+    index: &DlogVerifierIndex<GAffine>,
+    batch: &[(&DlogVerifierIndex<GAffine>, &DlogProof<GAffine>)],
+    lagrange: Option<&[GAffine]>,
+) -> Result<Sample, String> {
+    let start = Instant::now();
+    let inputs = in_pool(|| {
+        batch
+            .par_iter()
+            .map(|(_, proof)| fq_verify_phase1(index, proof, lagrange))
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    let phase1 = start.elapsed();
+
+    let batch: Vec<_> = batch
+        .iter()
+        .zip(inputs.iter())
+        .map(|(&(index, proof), inputs)| (index, proof, inputs))
+        .collect();
+    let start = Instant::now();
+    if !in_pool(|| fq_opening_batch_check(&batch)) {
+        return Err("The openings of the batch do not verify.".to_string());
+    }
+    Ok(Sample {
+        phase1,
+        opening: start.elapsed(),
+    })
+}
+
+fn median(mut v: Vec<Duration>) -> Duration {
+    v.sort();
+    v[v.len() / 2]
+}
+
+// The median of a measure of the samples without and with the tables, and how
+// many times faster the tables make it.
+fn comparison_json(
+    without: &[Sample],
+    with: &[Sample],
+    measure: fn(&Sample) -> Duration,
+) -> String {
+    let without = median(without.iter().map(measure).collect());
+    let with = median(with.iter().map(measure).collect());
+    format!(
+        "{{\"without_tables\":{},\"with_tables\":{},\"speedup\":{:.2}}}",
+        without.as_nanos(),
+        with.as_nanos(),
+        without.as_secs_f64() / with.as_secs_f64()
+    )
+}
+
+fn batch_size_json(batch_size: usize, without: &[Sample], with: &[Sample]) -> String {
+    let proofs_per_sec = |samples: &[Sample]| {
+        batch_size as f64 / median(samples.iter().map(Sample::total).collect()).as_secs_f64()
+    };
+    format!(
+        "{{\"batch_size\":{},\"phase1_ns\":{},\"opening_ns\":{},\"total_ns\":{},\
+         \"proofs_per_sec\":{{\"without_tables\":{:.1},\"with_tables\":{:.1}}}}}",
+        batch_size,
+        comparison_json(without, with, |s| s.phase1),
+        comparison_json(without, with, |s| s.opening),
+        comparison_json(without, with, Sample::total),
+        proofs_per_sec(without),
+        proofs_per_sec(with)
+    )
+}
+
+// Runs the benchmark for each batch size, without then with the tables, and
+// reports the batch sizes as a JSON array. Fails if any proof does not verify.
+pub fn fq_verify_bench(
+    index: &DlogVerifierIndex<GAffine>,
+    proofs: &[DlogProof<GAffine>],
+    batch_sizes: &[usize],
+    samples: usize,
+) -> Result<String, String> {
+    if proofs.is_empty() {
+        return Err("No proofs to verify.".to_string());
+    }
+    if samples == 0 {
+        return Err("The number of samples must be positive.".to_string());
+    }
+    if batch_sizes.contains(&0) {
+        return Err("The batch sizes must be positive.".to_string());
+    }
+
+    let lagrange = fq_lagrange_commitments(index.srs.get_ref(), index.domains.x);
+    let mut res = vec![];
+    for &batch_size in batch_sizes {
+        let batch: Vec<_> = (0..batch_size)
+            .map(|i| (index, &proofs[i % proofs.len()]))
+            .collect();
+        let mut configurations = vec![];
+        for &tables in &[false, true] {
+            let lagrange = if tables { Some(&lagrange[..]) } else { None };
+            run(index, &batch, lagrange)?;
+            let samples = (0..samples)
+                .map(|_| run(index, &batch, lagrange))
+                .collect::<Result<Vec<_>, _>>()?;
+            configurations.push(samples);
+        }
+        res.push(batch_size_json(
+            batch_size,
+            &configurations[0],
+            &configurations[1],
+        ));
+    }
+    Ok(format!("[{}]", res.join(",")))
+}
+
+// Returns the report as a newly allocated string, which must be freed with
+// zexe_bn382_fq_verify_bench_report_delete, or null with the last error set.
+// The benchmark takes as long as the verification of samples + 1 batches of
+// each size, twice.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verify_bench(
+    index: *const DlogVerifierIndex<GAffine>,
+    proofs: *const Vec<DlogProof<GAffine>>,
+    batch_sizes: *const usize,
+    batch_sizes_len: usize,
+    samples: usize,
+) -> *mut c_char {
+    const NAME: &str = "zexe_bn382_fq_verify_bench";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let proofs = handle(proofs);
+        let index = unsafe { &*index };
+        let proofs = unsafe { &*proofs };
+        let batch_sizes = unsafe { std::slice::from_raw_parts(batch_sizes, batch_sizes_len) };
+        match fq_verify_bench(index, proofs, batch_sizes, samples) {
+            Ok(report) => CString::new(report).unwrap().into_raw(),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_verify_bench_report_delete, CString);
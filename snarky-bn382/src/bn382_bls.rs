@@ -0,0 +1,453 @@
+use crate::common::*;
+use algebra::{
+    bn_382::{fp::Fp, Bn_382, Fq2, G1Affine, G2Affine},
+    curves::{AffineCurve, PairingEngine, ProjectiveCurve},
+    fields::Field,
+    FromBytes, One, ToBytes, UniformRand, Zero,
+};
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+
+// BLS signatures over Bn_382
+//
+// Public keys live in G1, so that they are cheap to represent in circuits,
+// and signatures live in G2. A secret key is a scalar sk, its public key is
+// sk * g1 and the signature of a message m is sk * H(m), where H hashes onto
+// G2. Signatures are checked with e(g1, sig) = e(pk, H(m)).
+//
+// Signatures on the same message aggregate by addition, and so do the public
+// keys. To rule out rogue key attacks, an aggregate public key must only be
+// built from keys whose proof of possession has been checked. A proof of
+// possession is a signature on the public key itself, under a separate hash
+// domain so that it can never be mistaken for a message signature.
+
+const SIG_DOMAIN: &[u8] = b"zexe-bn382-bls-sig";
+const POP_DOMAIN: &[u8] = b"zexe-bn382-bls-pop";
+
+// Size of the hash output used for each coordinate of the Fq2 x-coordinate.
+const HASH_COORD_SIZE: usize = 48;
+
+fn hash_coord(domain: &[u8], msg: &[u8], ctr: u32, coord: u8) -> Vec<u8> {
+    let mut h = VarBlake2b::new(HASH_COORD_SIZE).unwrap();
+    h.input(&(domain.len() as u64).to_le_bytes());
+    h.input(domain);
+    h.input(&ctr.to_le_bytes());
+    h.input(&[coord]);
+    h.input(msg);
+    let mut res = vec![];
+    h.variable_result(|d| res.extend_from_slice(d));
+    res
+}
+
+// Hashes onto the prime order subgroup of G2 by try-and-increment: candidate
+// x-coordinates are derived from the message and a counter until one lies on
+// the curve, and the point is then multiplied by the cofactor.
+fn hash_to_g2(domain: &[u8], msg: &[u8]) -> G2Affine {
+    let mut ctr = 0u32;
+    loop {
+        let mut bytes = hash_coord(domain, msg, ctr, 0);
+        bytes.extend(hash_coord(domain, msg, ctr, 1));
+        ctr += 1;
+        let x = match Fq2::from_random_bytes(&bytes) {
+            Some(x) => x,
+            None => continue,
+        };
+        if let Some(p) = G2Affine::get_point_from_x(x, bytes[0] & 1 == 1) {
+            let p = p.scale_by_cofactor();
+            if !p.is_zero() {
+                return p.into_affine();
+            }
+        }
+    }
+}
+
+fn public_key(sk: Fp) -> G1Affine {
+    G1Affine::prime_subgroup_generator().mul(sk).into_affine()
+}
+
+fn public_key_bytes(pk: &G1Affine) -> Vec<u8> {
+    let mut res = vec![];
+    pk.write(&mut res).unwrap();
+    res
+}
+
+// A public key must be a nonzero point of the prime order subgroup: the
+// signatures of the zero key verify on any message, and a point outside the
+// subgroup makes the pairing check meaningless.
+fn public_key_valid(pk: &G1Affine) -> bool {
+    !pk.is_zero() && pk.is_on_curve() && pk.is_in_correct_subgroup_assuming_on_curve()
+}
+
+// Checks e(g1, sig) = e(pk, H(m)) as e(-g1, sig) * e(pk, H(m)) = 1.
+fn check(pk: G1Affine, h: G2Affine, sig: G2Affine) -> bool {
+    if !public_key_valid(&pk)
+        || !sig.is_on_curve()
+        || !sig.is_in_correct_subgroup_assuming_on_curve()
+    {
+        return false;
+    }
+    let table = vec![
+        ((-G1Affine::prime_subgroup_generator()).into(), sig.into()),
+        (pk.into(), h.into()),
+    ];
+    Bn_382::final_exponentiation(&Bn_382::miller_loop(&table)).unwrap()
+        == <Bn_382 as PairingEngine>::Fqk::one()
+}
+
+fn sum<G: AffineCurve>(xs: &[G]) -> G {
+    xs.iter()
+        .fold(G::Projective::zero(), |acc, x| {
+            let mut acc = acc;
+            acc.add_assign_mixed(x);
+            acc
+        })
+        .into_affine()
+}
+
+fn message<'a>(msg: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(msg, len) }
+    }
+}
+
+// Keys
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_secret_key_random() -> *const Fp {
+    catch_ffi("zexe_bn382_bls_secret_key_random", std::ptr::null(), || {
+        let mut sk = Fp::zero();
+        while sk.is_zero() {
+            sk = Fp::rand(&mut system_rng());
+        }
+        into_handle(Box::new(sk))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_public_key(sk: *const Fp) -> *const G1Affine {
+    catch_ffi("zexe_bn382_bls_public_key", std::ptr::null(), || {
+        let sk = handle(sk);
+        let sk = unsafe { *sk };
+        into_handle(Box::new(public_key(sk)))
+    })
+}
+
+// Signing
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_hash_to_g2(msg: *const u8, len: usize) -> *const G2Affine {
+    catch_ffi("zexe_bn382_bls_hash_to_g2", std::ptr::null(), || {
+        into_handle(Box::new(hash_to_g2(SIG_DOMAIN, message(msg, len))))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_sign(
+    sk: *const Fp,
+    msg: *const u8,
+    len: usize,
+) -> *const G2Affine {
+    catch_ffi("zexe_bn382_bls_sign", std::ptr::null(), || {
+        let sk = handle(sk);
+        let sk = unsafe { *sk };
+        let h = hash_to_g2(SIG_DOMAIN, message(msg, len));
+        into_handle(Box::new(h.mul(sk).into_affine()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_verify(
+    pk: *const G1Affine,
+    msg: *const u8,
+    len: usize,
+    sig: *const G2Affine,
+) -> bool {
+    catch_ffi("zexe_bn382_bls_verify", false, || {
+        let pk = handle(pk);
+        let sig = handle(sig);
+        let pk = unsafe { *pk };
+        let sig = unsafe { *sig };
+        check(pk, hash_to_g2(SIG_DOMAIN, message(msg, len)), sig)
+    })
+}
+
+// Aggregation
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_aggregate_signatures(
+    sigs: *const Vec<G2Affine>,
+) -> *const G2Affine {
+    catch_ffi("zexe_bn382_bls_aggregate_signatures", std::ptr::null(), || {
+        let sigs = handle(sigs);
+        let sigs = unsafe { &*sigs };
+        into_handle(Box::new(sum(sigs)))
+    })
+}
+
+// The public keys must all have had their proof of possession checked.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_aggregate_public_keys(
+    pks: *const Vec<G1Affine>,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_bls_aggregate_public_keys", std::ptr::null(), || {
+        let pks = handle(pks);
+        let pks = unsafe { &*pks };
+        into_handle(Box::new(sum(pks)))
+    })
+}
+
+// Checks an aggregate of signatures on the same message by the given keys,
+// whose proofs of possession must have been checked.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_verify_aggregate(
+    pks: *const Vec<G1Affine>,
+    msg: *const u8,
+    len: usize,
+    sig: *const G2Affine,
+) -> bool {
+    catch_ffi("zexe_bn382_bls_verify_aggregate", false, || {
+        let pks = handle(pks);
+        let sig = handle(sig);
+        let pks = unsafe { &*pks };
+        let sig = unsafe { *sig };
+        if pks.is_empty() {
+            set_last_error("zexe_bn382_bls_verify_aggregate: No public keys".to_string());
+            return false;
+        }
+        if let Some(i) = pks.iter().position(|pk| !public_key_valid(pk)) {
+            set_last_error(format!(
+                "zexe_bn382_bls_verify_aggregate: Public key {} is invalid",
+                i
+            ));
+            return false;
+        }
+        check(sum(pks), hash_to_g2(SIG_DOMAIN, message(msg, len)), sig)
+    })
+}
+
+// Proofs of possession
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_pop_prove(sk: *const Fp) -> *const G2Affine {
+    catch_ffi("zexe_bn382_bls_pop_prove", std::ptr::null(), || {
+        let sk = handle(sk);
+        let sk = unsafe { *sk };
+        let h = hash_to_g2(POP_DOMAIN, &public_key_bytes(&public_key(sk)));
+        into_handle(Box::new(h.mul(sk).into_affine()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_bls_pop_verify(pk: *const G1Affine, pop: *const G2Affine) -> bool {
+    catch_ffi("zexe_bn382_bls_pop_verify", false, || {
+        let pk = handle(pk);
+        let pop = handle(pop);
+        let pk = unsafe { *pk };
+        let pop = unsafe { *pop };
+        check(pk, hash_to_g2(POP_DOMAIN, &public_key_bytes(&pk)), pop)
+    })
+}
+
+// G2 affine stubs, for signatures
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_to_bytes(p: *const G2Affine) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_g2_affine_to_bytes", std::ptr::null_mut(), || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        let mut res = vec![];
+        p.write(&mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+// Rejects points which are not on the curve or not in the prime order
+// subgroup.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_of_bytes(data: *const u8, len: usize) -> *const G2Affine {
+    catch_ffi("zexe_bn382_g2_affine_of_bytes", std::ptr::null(), || {
+        let mut data = message(data, len);
+        match G2Affine::read(&mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_g2_affine_of_bytes: trailing bytes after point".to_string(),
+            ),
+            Ok(p) if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() => {
+                null_with_error("zexe_bn382_g2_affine_of_bytes: invalid point".to_string())
+            }
+            Ok(p) => into_handle(Box::new(p)),
+            Err(e) => null_with_error(format!("zexe_bn382_g2_affine_of_bytes: {}", e)),
+        }
+    })
+}
+
+crate::delete_stub!(zexe_bn382_g2_affine_delete, G2Affine);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_vector_create() -> *mut Vec<G2Affine> {
+    catch_ffi("zexe_bn382_g2_affine_vector_create", std::ptr::null_mut(), || {
+        into_handle(Box::new(vec![]))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_vector_length(v: *const Vec<G2Affine>) -> i32 {
+    catch_ffi("zexe_bn382_g2_affine_vector_length", 0, || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        v.len() as i32
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_vector_emplace_back(
+    v: *mut Vec<G2Affine>,
+    x: *const G2Affine,
+) {
+    catch_ffi("zexe_bn382_g2_affine_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v = unsafe { &mut *v };
+        v.push(unsafe { *x });
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g2_affine_vector_get(
+    v: *const Vec<G2Affine>,
+    i: u32,
+) -> *const G2Affine {
+    catch_ffi("zexe_bn382_g2_affine_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        into_handle(Box::new(v[i as usize]))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_g2_affine_vector_delete, Vec<G2Affine>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(sk: Fp, msg: &[u8]) -> G2Affine {
+        hash_to_g2(SIG_DOMAIN, msg).mul(sk).into_affine()
+    }
+
+    // Answers known from the definitions: the key of 1 is the generator and
+    // its signature is the hash itself, and keys and signatures add up as
+    // their secret keys do.
+    #[test]
+    fn known_answers() {
+        let msg = b"known answer";
+        let one = Fp::one();
+        assert_eq!(public_key(one), G1Affine::prime_subgroup_generator());
+        assert_eq!(sign(one, msg), hash_to_g2(SIG_DOMAIN, msg));
+
+        let (sk1, sk2) = (Fp::from(3u64), Fp::from(5u64));
+        assert_eq!(
+            sum(&[public_key(sk1), public_key(sk2)]),
+            public_key(Fp::from(8u64))
+        );
+        assert_eq!(
+            sum(&[sign(sk1, msg), sign(sk2, msg)]),
+            sign(Fp::from(8u64), msg)
+        );
+    }
+
+    #[test]
+    fn hash_is_deterministic_and_domain_separated() {
+        assert_eq!(hash_to_g2(SIG_DOMAIN, b"m"), hash_to_g2(SIG_DOMAIN, b"m"));
+        assert_ne!(hash_to_g2(SIG_DOMAIN, b"m"), hash_to_g2(POP_DOMAIN, b"m"));
+        assert_ne!(hash_to_g2(SIG_DOMAIN, b"m"), hash_to_g2(SIG_DOMAIN, b"n"));
+        let h = hash_to_g2(SIG_DOMAIN, b"");
+        assert!(h.is_on_curve() && h.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = Fp::from(12345u64);
+        let pk = public_key(sk);
+        let msg = b"message";
+        let sig = sign(sk, msg);
+        assert!(check(pk, hash_to_g2(SIG_DOMAIN, msg), sig));
+        assert!(!check(pk, hash_to_g2(SIG_DOMAIN, b"other"), sig));
+        assert!(!check(
+            public_key(Fp::from(54321u64)),
+            hash_to_g2(SIG_DOMAIN, msg),
+            sig
+        ));
+
+        let pop = hash_to_g2(POP_DOMAIN, &public_key_bytes(&pk))
+            .mul(sk)
+            .into_affine();
+        assert!(check(
+            pk,
+            hash_to_g2(POP_DOMAIN, &public_key_bytes(&pk)),
+            pop
+        ));
+        assert!(!check(
+            pk,
+            hash_to_g2(SIG_DOMAIN, &public_key_bytes(&pk)),
+            pop
+        ));
+    }
+
+    #[test]
+    fn aggregate_verify() {
+        let msg = b"block";
+        let sks = [Fp::from(7u64), Fp::from(11u64), Fp::from(13u64)];
+        let pks: Vec<_> = sks.iter().map(|sk| public_key(*sk)).collect();
+        let sig = sum(&sks.iter().map(|sk| sign(*sk, msg)).collect::<Vec<_>>());
+        assert!(zexe_bn382_bls_verify_aggregate(
+            &pks,
+            msg.as_ptr(),
+            msg.len(),
+            &sig
+        ));
+        let two = pks[..2].to_vec();
+        assert!(!zexe_bn382_bls_verify_aggregate(
+            &two,
+            msg.as_ptr(),
+            msg.len(),
+            &sig
+        ));
+    }
+
+    #[test]
+    fn bad_keys_are_rejected() {
+        let msg = b"message";
+        let h = hash_to_g2(SIG_DOMAIN, msg);
+
+        // The zero key would accept the zero signature on any message.
+        let zero = G1Affine::zero();
+        assert!(!zexe_bn382_bls_verify(
+            &zero,
+            msg.as_ptr(),
+            msg.len(),
+            &G2Affine::zero()
+        ));
+        assert!(!check(zero, h, G2Affine::zero()));
+
+        // A point off the curve.
+        let g = G1Affine::prime_subgroup_generator();
+        let mut off = g;
+        off.y.double_in_place();
+        assert!(!off.is_on_curve());
+        assert!(!check(off, h, sign(Fp::one(), msg)));
+        assert!(!zexe_bn382_bls_pop_verify(&off, &G2Affine::zero()));
+
+        // An aggregate with one bad key is rejected even if the keys sum to a
+        // valid one.
+        let pks = vec![g, zero];
+        assert!(!zexe_bn382_bls_verify_aggregate(
+            &pks,
+            msg.as_ptr(),
+            msg.len(),
+            &h
+        ));
+    }
+}
@@ -0,0 +1,647 @@
+use crate::bn382_dlog::*;
+use crate::bn382_pairing::*;
+use crate::common::*;
+use crate::gadgets::Row;
+use algebra::bn_382::{
+    fp::Fp,
+    fq::Fq,
+    g::Affine as GAffine,
+    Bn_382,
+};
+use commitment_dlog::srs::SRS;
+use commitment_pairing::urs::URS;
+use ff_fft::EvaluationDomain;
+use marlin_protocol_dlog::index::{Index as DlogIndex, VerifierIndex as DlogVerifierIndex};
+use marlin_protocol_dlog::prover::ProverProof as DlogProof;
+use marlin_protocol_pairing::index::{Index, VerifierIndex};
+use marlin_protocol_pairing::prover::ProverProof;
+use oracle::poseidon::ArithmeticSpongeParams;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+
+// Bn382 cycle context
+//
+// Recursive proving alternates between the pairing side (over Fp) and the
+// dlog side (over Fq), and both sides must agree on their parameters. A
+// cycle context owns everything the two sides share: the Fp URS, the Fq SRS,
+// the sponge parameters of both fields, and the thread pool in which the
+// expensive operations run. Indexes created through a context borrow its URS
+// and use its sponge parameters, and proving with an index from another
+// context is rejected. The context records the verifier indexes created
+// through it, so that verifying with any other verifier index is rejected as
+// well; those must be deleted through the context.
+
+pub struct Bn382Cycle {
+    pub fp_urs: URS<Bn_382>,
+    pub fq_srs: SRS<GAffine>,
+    pub fp_sponge_params: ArithmeticSpongeParams<Fp>,
+    pub fq_sponge_params: ArithmeticSpongeParams<Fq>,
+    pub pool: ThreadPool,
+    // The addresses of the live verifier indexes created through the context,
+    // without their handle tags.
+    verifier_indexes: Mutex<HashSet<usize>>,
+}
+
+impl Bn382Cycle {
+    fn record<T>(&self, index: *const T) -> *const T {
+        if !index.is_null() {
+            self.verifier_indexes.lock().unwrap().insert(handle_address(index));
+        }
+        index
+    }
+
+    fn owns<T>(&self, name: &str, index: *const T) -> bool {
+        let owned = self.verifier_indexes.lock().unwrap().contains(&handle_address(index));
+        if !owned {
+            set_last_error(format!(
+                "{}: The verifier index was not created with this context.",
+                name
+            ));
+        }
+        owned
+    }
+
+    fn forget<T>(&self, name: &str, index: *const T) -> bool {
+        let forgotten = self.verifier_indexes.lock().unwrap().remove(&handle_address(index));
+        if !forgotten {
+            set_last_error(format!(
+                "{}: The verifier index was not created with this context.",
+                name
+            ));
+        }
+        forgotten
+    }
+}
+
+fn install<T, R>(pool: &ThreadPool, args: T, f: fn(T) -> R) -> R {
+    let args = AssertSend(args);
+    pool.install(move || {
+        let AssertSend(args) = args;
+        AssertSend(f(args))
+    })
+    .0
+}
+
+// Takes ownership of the URS and the SRS, which must not be deleted
+// separately afterwards. A thread count of 0 uses rayon's default.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_create(
+    fp_urs: *mut URS<Bn_382>,
+    fq_srs: *mut SRS<GAffine>,
+    num_threads: usize,
+) -> *mut Bn382Cycle {
+    catch_ffi("zexe_bn382_cycle_create", std::ptr::null_mut(), || {
+        let fp_urs = handle(fp_urs);
+        let fq_srs = handle(fq_srs);
+        if !check_handle("zexe_bn382_cycle_create", fp_urs)
+            || !check_handle("zexe_bn382_cycle_create", fq_srs)
+        {
+            return std::ptr::null_mut();
+        }
+        let pool = match ThreadPoolBuilder::new().num_threads(num_threads).build() {
+            Ok(pool) => pool,
+            Err(e) => {
+                return null_with_error(format!(
+                    "zexe_bn382_cycle_create: Could not build thread pool: {}",
+                    e
+                ))
+            }
+        };
+        let fp_urs = take_handle("zexe_bn382_cycle_create", fp_urs).unwrap();
+        let fq_srs = take_handle("zexe_bn382_cycle_create", fq_srs).unwrap();
+        into_handle(Box::new(Bn382Cycle {
+            fp_urs: *fp_urs,
+            fq_srs: *fq_srs,
+            fp_sponge_params: oracle::bn_382::fp::params(),
+            fq_sponge_params: oracle::bn_382::fq::params(),
+            pool,
+            verifier_indexes: Mutex::new(HashSet::new()),
+        }))
+    })
+}
+
+// Indexes created with the context must be deleted before it, the verifier
+// indexes with zexe_bn382_cycle_fp_verifier_index_delete and
+// zexe_bn382_cycle_fq_verifier_index_delete.
+crate::delete_stub!(zexe_bn382_cycle_delete, Bn382Cycle);
+
+// The accessors below return pointers borrowed from the context, which must
+// not be deleted.
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_urs(ctx: *const Bn382Cycle) -> *const URS<Bn_382> {
+    catch_ffi("zexe_bn382_cycle_fp_urs", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        unsafe { &(*ctx).fp_urs }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_urs(ctx: *const Bn382Cycle) -> *const SRS<GAffine> {
+    catch_ffi("zexe_bn382_cycle_fq_urs", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        unsafe { &(*ctx).fq_srs }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_sponge_params(
+    ctx: *const Bn382Cycle,
+) -> *const ArithmeticSpongeParams<Fp> {
+    catch_ffi("zexe_bn382_cycle_fp_sponge_params", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        unsafe { &(*ctx).fp_sponge_params }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_sponge_params(
+    ctx: *const Bn382Cycle,
+) -> *const ArithmeticSpongeParams<Fq> {
+    catch_ffi("zexe_bn382_cycle_fq_sponge_params", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        unsafe { &(*ctx).fq_sponge_params }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_num_threads(ctx: *const Bn382Cycle) -> usize {
+    catch_ffi("zexe_bn382_cycle_num_threads", 0, || {
+        let ctx = handle(ctx);
+        unsafe { &(*ctx).pool }.current_num_threads()
+    })
+}
+
+// Fp side
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_index_create<'a>(
+    ctx: *const Bn382Cycle,
+    a: *mut Vec<Row<Fp>>,
+    b: *mut Vec<Row<Fp>>,
+    c: *mut Vec<Row<Fp>>,
+    vars: usize,
+    public_inputs: usize,
+) -> *mut Index<'a, Bn_382> {
+    catch_ffi("zexe_bn382_cycle_fp_index_create", std::ptr::null_mut(), || {
+        let ctx = handle(ctx);
+        let a = handle(a);
+        let b = handle(b);
+        let c = handle(c);
+        let ctx = unsafe { &*ctx };
+        let urs = &ctx.fp_urs as *const URS<Bn_382> as *mut URS<Bn_382>;
+        let index = install(
+            &ctx.pool,
+            (a, b, c, vars, public_inputs, urs),
+            |(a, b, c, vars, public_inputs, urs)| {
+                zexe_bn382_fp_index_create(a, b, c, vars, public_inputs, urs)
+            },
+        );
+        fp_index_use_params(ctx, index)
+    })
+}
+
+// Makes the index use the sponge parameters of the context, which its
+// verifier index then copies.
+fn fp_index_use_params<'a>(
+    ctx: &Bn382Cycle,
+    index: *mut Index<'a, Bn_382>,
+) -> *mut Index<'a, Bn_382> {
+    if let Some(index) = unsafe { handle(index).as_mut() } {
+        index.fr_sponge_params = ctx.fp_sponge_params.clone();
+        index.fq_sponge_params = ctx.fq_sponge_params.clone();
+    }
+    index
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_verifier_index_create(
+    ctx: *const Bn382Cycle,
+    index: *const Index<Bn_382>,
+) -> *const VerifierIndex<Bn_382> {
+    catch_ffi("zexe_bn382_cycle_fp_verifier_index_create", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let ctx = unsafe { &*ctx };
+        if !std::ptr::eq(unsafe { &*index }.urs.get_ref(), &ctx.fp_urs) {
+            return null_with_error(
+                "zexe_bn382_cycle_fp_verifier_index_create: The index was not created with this \
+                 context."
+                    .to_string(),
+            );
+        }
+        ctx.record(zexe_bn382_fp_verifier_index_create(index))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_verifier_index_delete(
+    ctx: *const Bn382Cycle,
+    index: *mut VerifierIndex<Bn_382>,
+) {
+    catch_ffi("zexe_bn382_cycle_fp_verifier_index_delete", (), || {
+        const NAME: &str = "zexe_bn382_cycle_fp_verifier_index_delete";
+        let ctx = handle(ctx);
+        let index = handle(index);
+        if unsafe { &*ctx }.forget(NAME, index) {
+            release_handle(NAME, index);
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_proof_create(
+    ctx: *const Bn382Cycle,
+    index: *const Index<Bn_382>,
+    primary_input: *const Vec<Fp>,
+    auxiliary_input: *const Vec<Fp>,
+) -> *const ProverProof<Bn_382> {
+    catch_ffi("zexe_bn382_cycle_fp_proof_create", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let ctx = unsafe { &*ctx };
+        if !std::ptr::eq(unsafe { &*index }.urs.get_ref(), &ctx.fp_urs) {
+            return null_with_error(
+                "zexe_bn382_cycle_fp_proof_create: The index was not created with this context."
+                    .to_string(),
+            );
+        }
+        install(
+            &ctx.pool,
+            (index, primary_input, auxiliary_input),
+            |(index, primary_input, auxiliary_input)| {
+                zexe_bn382_fp_proof_create(index, primary_input, auxiliary_input)
+            },
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fp_proof_verify(
+    ctx: *const Bn382Cycle,
+    index: *const VerifierIndex<Bn_382>,
+    proof: *const ProverProof<Bn_382>,
+) -> bool {
+    catch_ffi("zexe_bn382_cycle_fp_proof_verify", false, || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let proof = handle(proof);
+        let ctx = unsafe { &*ctx };
+        if !ctx.owns("zexe_bn382_cycle_fp_proof_verify", index) {
+            return false;
+        }
+        install(&ctx.pool, (index, proof), |(index, proof)| {
+            zexe_bn382_fp_proof_verify(index, proof)
+        })
+    })
+}
+
+// Fq side
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_index_create<'a>(
+    ctx: *const Bn382Cycle,
+    a: *mut Vec<Row<Fq>>,
+    b: *mut Vec<Row<Fq>>,
+    c: *mut Vec<Row<Fq>>,
+    vars: usize,
+    public_inputs: usize,
+) -> *mut DlogIndex<'a, GAffine> {
+    catch_ffi("zexe_bn382_cycle_fq_index_create", std::ptr::null_mut(), || {
+        let ctx = handle(ctx);
+        let a = handle(a);
+        let b = handle(b);
+        let c = handle(c);
+        let ctx = unsafe { &*ctx };
+        let srs = &ctx.fq_srs as *const SRS<GAffine> as *mut SRS<GAffine>;
+        let index = install(
+            &ctx.pool,
+            (a, b, c, vars, public_inputs, srs),
+            |(a, b, c, vars, public_inputs, srs)| {
+                zexe_bn382_fq_index_create(a, b, c, vars, public_inputs, srs)
+            },
+        );
+        fq_index_use_params(ctx, index)
+    })
+}
+
+// On the dlog side, the sponge over the scalar field Fq is the Fr sponge.
+fn fq_index_use_params<'a>(
+    ctx: &Bn382Cycle,
+    index: *mut DlogIndex<'a, GAffine>,
+) -> *mut DlogIndex<'a, GAffine> {
+    if let Some(index) = unsafe { handle(index).as_mut() } {
+        index.fr_sponge_params = ctx.fq_sponge_params.clone();
+        index.fq_sponge_params = ctx.fp_sponge_params.clone();
+    }
+    index
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_verifier_index_create(
+    ctx: *const Bn382Cycle,
+    index: *const DlogIndex<GAffine>,
+) -> *const DlogVerifierIndex<GAffine> {
+    catch_ffi("zexe_bn382_cycle_fq_verifier_index_create", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let ctx = unsafe { &*ctx };
+        if !std::ptr::eq(unsafe { &*index }.srs.get_ref(), &ctx.fq_srs) {
+            return null_with_error(
+                "zexe_bn382_cycle_fq_verifier_index_create: The index was not created with this \
+                 context."
+                    .to_string(),
+            );
+        }
+        ctx.record(zexe_bn382_fq_verifier_index_create(index))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_verifier_index_delete(
+    ctx: *const Bn382Cycle,
+    index: *mut DlogVerifierIndex<GAffine>,
+) {
+    catch_ffi("zexe_bn382_cycle_fq_verifier_index_delete", (), || {
+        const NAME: &str = "zexe_bn382_cycle_fq_verifier_index_delete";
+        let ctx = handle(ctx);
+        let index = handle(index);
+        if unsafe { &*ctx }.forget(NAME, index) {
+            release_handle(NAME, index);
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_proof_create(
+    ctx: *const Bn382Cycle,
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+) -> *const DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_cycle_fq_proof_create", std::ptr::null(), || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let ctx = unsafe { &*ctx };
+        if !std::ptr::eq(unsafe { &*index }.srs.get_ref(), &ctx.fq_srs) {
+            return null_with_error(
+                "zexe_bn382_cycle_fq_proof_create: The index was not created with this context."
+                    .to_string(),
+            );
+        }
+        install(
+            &ctx.pool,
+            (index, primary_input, auxiliary_input, prev_challenges, prev_sgs),
+            |(index, primary_input, auxiliary_input, prev_challenges, prev_sgs)| {
+                zexe_bn382_fq_proof_create(
+                    index,
+                    primary_input,
+                    auxiliary_input,
+                    prev_challenges,
+                    prev_sgs,
+                )
+            },
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_fq_proof_verify(
+    ctx: *const Bn382Cycle,
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> bool {
+    catch_ffi("zexe_bn382_cycle_fq_proof_verify", false, || {
+        let ctx = handle(ctx);
+        let index = handle(index);
+        let proof = handle(proof);
+        let ctx = unsafe { &*ctx };
+        if !ctx.owns("zexe_bn382_cycle_fq_proof_verify", index) {
+            return false;
+        }
+        install(&ctx.pool, (index, proof), |(index, proof)| {
+            zexe_bn382_fq_proof_verify(index, proof)
+        })
+    })
+}
+
+// Differential testing
+//
+// Builds the index of one circuit on both sides of the cycle, proves and
+// verifies on both, and reports how they differ. The circuit is given over
+// Fp and moved to Fq by its canonical representation, so every coefficient
+// and witness value must be below the Fq modulus. The circuit is only
+// satisfied on both sides if its arithmetic does not wrap around either
+// modulus, which is part of what this checks.
+
+struct SideReport {
+    domains: Option<[usize; 3]>,
+    verified: bool,
+    error: Option<String>,
+}
+
+impl SideReport {
+    fn failed(e: String) -> Self {
+        SideReport {
+            domains: None,
+            verified: false,
+            error: Some(e),
+        }
+    }
+
+    fn json(&self) -> String {
+        let domains = match self.domains {
+            Some([h, k, x]) => format!("{{\"h\":{},\"k\":{},\"x\":{}}}", h, k, x),
+            None => "null".to_string(),
+        };
+        let error = match &self.error {
+            Some(e) => format!("\"{}\"", e.replace('\\', "\\\\").replace('"', "\\\"")),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"domains\":{},\"verified\":{},\"error\":{}}}",
+            domains, self.verified, error
+        )
+    }
+}
+
+// A handle returned by a stub, deleted when dropped. It stays registered
+// until then, so that it can be passed to stubs which check their handles.
+struct Owned<T>(*mut T);
+
+impl<T> std::ops::Deref for Owned<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*handle(self.0) }
+    }
+}
+
+impl<T> Drop for Owned<T> {
+    fn drop(&mut self) {
+        release_handle("zexe_bn382_cycle_differential_test", self.0);
+    }
+}
+
+// Takes ownership of a pointer returned by a stub, or the last error if it is
+// null.
+fn owned<T>(p: *const T) -> Result<Owned<T>, String> {
+    if p.is_null() {
+        Err(take_last_error().unwrap_or_else(|| "unknown error".to_string()))
+    } else {
+        Ok(Owned(p as *mut T))
+    }
+}
+
+fn fq_rows_of_fp(rows: &[Row<Fp>]) -> Result<Vec<Row<Fq>>, String> {
+    rows.iter()
+        .map(|(i, c)| Ok((i.clone(), fq_vector_of_fp(c)?)))
+        .collect()
+}
+
+fn fq_vector_of_fp(v: &[Fp]) -> Result<Vec<Fq>, String> {
+    v.iter()
+        .map(|x| {
+            convert_field(x).ok_or_else(|| "A value does not fit below the Fq modulus".to_string())
+        })
+        .collect()
+}
+
+fn fp_side(
+    ctx: &Bn382Cycle,
+    m: [&mut Vec<Row<Fp>>; 3],
+    vars: usize,
+    public_inputs: usize,
+    primary_input: &Vec<Fp>,
+    auxiliary_input: &Vec<Fp>,
+) -> Result<SideReport, String> {
+    let [a, b, c] = m;
+    let urs = &ctx.fp_urs as *const URS<Bn_382> as *mut URS<Bn_382>;
+    let index = zexe_bn382_fp_index_create(a, b, c, vars, public_inputs, urs);
+    let index = owned(fp_index_use_params(ctx, index) as *const _)?;
+    let d = &index.domains;
+    let domains = [d.h.size(), d.k.size(), d.x.size()];
+    let proof = owned(zexe_bn382_fp_proof_create(&*index, primary_input, auxiliary_input))?;
+    let verifier_index = owned(zexe_bn382_fp_verifier_index_create(&*index))?;
+    Ok(SideReport {
+        domains: Some(domains),
+        verified: zexe_bn382_fp_proof_verify(&*verifier_index, &*proof),
+        error: None,
+    })
+}
+
+fn fq_side(
+    ctx: &Bn382Cycle,
+    m: [&mut Vec<Row<Fq>>; 3],
+    vars: usize,
+    public_inputs: usize,
+    primary_input: &Vec<Fq>,
+    auxiliary_input: &Vec<Fq>,
+) -> Result<SideReport, String> {
+    let [a, b, c] = m;
+    let srs = &ctx.fq_srs as *const SRS<GAffine> as *mut SRS<GAffine>;
+    let index = zexe_bn382_fq_index_create(a, b, c, vars, public_inputs, srs);
+    let index = owned(fq_index_use_params(ctx, index) as *const _)?;
+    let d = &index.domains;
+    let domains = [d.h.size(), d.k.size(), d.x.size()];
+    let proof = owned(zexe_bn382_fq_proof_create(
+        &*index,
+        primary_input,
+        auxiliary_input,
+        &vec![],
+        &vec![],
+    ))?;
+    let verifier_index = owned(zexe_bn382_fq_verifier_index_create(&*index))?;
+    Ok(SideReport {
+        domains: Some(domains),
+        verified: zexe_bn382_fq_proof_verify(&*verifier_index, &*proof),
+        error: None,
+    })
+}
+
+// Returns a JSON object with the domain sizes and verification result of
+// each side, and the list of divergences between them, as a newly allocated
+// string which must be freed with zexe_bn382_cycle_differential_report_delete.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_cycle_differential_test(
+    ctx: *const Bn382Cycle,
+    a: *const Vec<Row<Fp>>,
+    b: *const Vec<Row<Fp>>,
+    c: *const Vec<Row<Fp>>,
+    vars: usize,
+    public_inputs: usize,
+    primary_input: *const Vec<Fp>,
+    auxiliary_input: *const Vec<Fp>,
+) -> *mut c_char {
+    catch_ffi("zexe_bn382_cycle_differential_test", std::ptr::null_mut(), || {
+        let ctx = handle(ctx);
+        let a = handle(a);
+        let b = handle(b);
+        let c = handle(c);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let ctx = unsafe { &*ctx };
+        let (a, b, c) = unsafe { (&*a, &*b, &*c) };
+        let primary_input = unsafe { &*primary_input };
+        let auxiliary_input = unsafe { &*auxiliary_input };
+
+        let fp = {
+            let (mut a, mut b, mut c) = (a.clone(), b.clone(), c.clone());
+            fp_side(
+                ctx,
+                [&mut a, &mut b, &mut c],
+                vars,
+                public_inputs,
+                primary_input,
+                auxiliary_input,
+            )
+            .unwrap_or_else(SideReport::failed)
+        };
+
+        let fq = (|| {
+            let mut a = fq_rows_of_fp(a)?;
+            let mut b = fq_rows_of_fp(b)?;
+            let mut c = fq_rows_of_fp(c)?;
+            fq_side(
+                ctx,
+                [&mut a, &mut b, &mut c],
+                vars,
+                public_inputs,
+                &fq_vector_of_fp(primary_input)?,
+                &fq_vector_of_fp(auxiliary_input)?,
+            )
+        })()
+        .unwrap_or_else(SideReport::failed);
+
+        let mut divergences = vec![];
+        if fp.domains != fq.domains {
+            divergences.push("\"domains\"");
+        }
+        if fp.verified != fq.verified {
+            divergences.push("\"verified\"");
+        }
+        if fp.error.is_some() != fq.error.is_some() {
+            divergences.push("\"error\"");
+        }
+
+        let report = format!(
+            "{{\"fp\":{},\"fq\":{},\"divergences\":[{}]}}",
+            fp.json(),
+            fq.json(),
+            divergences.join(",")
+        );
+        CString::new(report).unwrap().into_raw()
+    })
+}
+
+crate::delete_stub!(zexe_bn382_cycle_differential_report_delete, CString);
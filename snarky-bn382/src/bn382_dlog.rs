@@ -1,15 +1,18 @@
 use crate::common::*;
+use crate::gadgets::Row;
+use crate::labels::{check_labelled_system, forget_labelled_system};
 use algebra::{
     FftField,
-    biginteger::BigInteger384,
+    biginteger::{BigInteger, BigInteger384},
     bn_382::{
         fp::Fp,
         fq::{Fq, FqParameters as Fq_params},
         g::{Affine as GAffine, Bn_382GParameters, Projective as GProjective},
+        glv::glv_multi_scalar_mul,
     },
-    curves::{AffineCurve, ProjectiveCurve},
+    curves::{models::SWModelParameters, AffineCurve, ProjectiveCurve},
     fields::{Field, FpParameters, PrimeField, SquareRootField},
-    FromBytes, One, ToBytes, UniformRand, Zero,
+    FromBytes, One, ToBytes, UniformRand, VariableBaseMSM, Zero,
 };
 use dlog_solver::{DetSquareRootField, decompose};
 
@@ -20,24 +23,33 @@ use ff_fft::{DensePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDom
 use oracle::{
     self,
     poseidon::MarlinSpongeConstants as SC,
-    sponge::{DefaultFqSponge, DefaultFrSponge, ScalarChallenge},
+    sponge::{DefaultFqSponge, DefaultFrSponge, FqSponge, ScalarChallenge},
 };
 
-use rand::rngs::StdRng;
-use rand_core;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{self, CryptoRng, RngCore, SeedableRng};
+use rayon::prelude::*;
 
 use groupmap::GroupMap;
+use lazy_static::lazy_static;
 use std::{
-    ffi::CStr,
+    cell::RefCell,
+    collections::HashMap,
+    ffi::{c_void, CStr},
     fs::File,
     io::{BufReader, BufWriter, Read, Result as IoResult, Write},
     os::raw::c_char,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use commitment_dlog::{
-    commitment::{b_poly_coefficients, product, CommitmentCurve, OpeningProof, PolyComm},
+    commitment::{b_poly, b_poly_coefficients, product, CommitmentCurve, OpeningProof, PolyComm},
     srs::SRS,
 };
+use marlin_protocol_dlog::marlin_sponge::FrSponge;
 use marlin_protocol_dlog::index::{
     Index as DlogIndex, SRSSpec, SRSValue, VerifierIndex as DlogVerifierIndex,
 };
@@ -52,32 +64,174 @@ use algebra::bn_382::g::Affine;
 pub extern "C" fn zexe_bn382_fq_urs_create(
     depth: usize,
 ) -> *const SRS<GAffine> {
-    Box::into_raw(Box::new(SRS::create(depth)))
+    catch_ffi("zexe_bn382_fq_urs_create", std::ptr::null(), || {
+        in_pool(|| into_handle(Box::new(SRS::create(depth))))
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_urs_delete(x: *mut SRS<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_urs_delete, SRS<GAffine>);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_urs_write(urs: *mut SRS<GAffine>, path: *mut c_char) {
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let file = BufWriter::new(File::create(path).unwrap());
-    let urs = unsafe { &*urs };
-    let _ = urs.write(file);
+    catch_ffi("zexe_bn382_fq_urs_write", (), || {
+        let urs = handle(urs);
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = BufWriter::new(File::create(path).unwrap());
+        let urs = unsafe { &*urs };
+        let _ = urs.write(file);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_urs_read(path: *mut c_char) -> *const SRS<GAffine> {
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let file = BufReader::new(File::open(path).unwrap());
-    let res = SRS::<GAffine>::read(file).unwrap();
-    return Box::into_raw(Box::new(res));
+    catch_ffi("zexe_bn382_fq_urs_read", std::ptr::null(), || {
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = BufReader::new(File::open(path).unwrap());
+        let res = SRS::<GAffine>::read(file).unwrap();
+        return into_handle(Box::new(res));
+    })
+}
+
+fn fq_point_valid(p: &GAffine) -> bool {
+    p.is_zero() || (p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve())
+}
+
+// As fq_point_valid, but skipping the subgroup check, which costs a scalar
+// multiplication per point, when the cofactor is 1 and every point on the
+// curve is in the subgroup.
+pub(crate) fn fq_proof_point_valid(p: &GAffine) -> bool {
+    p.is_zero()
+        || (p.is_on_curve()
+            && (Bn_382GParameters::COFACTOR == &[1u64][..]
+                || p.is_in_correct_subgroup_assuming_on_curve()))
+}
+
+// As SRS::read, from the layout SRS::write produces, but with the points
+// decoded and checked with valid across the thread pool.
+fn read_srs_par<R: Read, V: Fn(&GAffine) -> bool + Sync>(
+    mut r: R,
+    valid: V,
+) -> IoResult<SRS<GAffine>> {
+    let n = u64::read(&mut r)? as usize;
+    let g = read_points_par(&mut r, n, &valid)?;
+    let h = GAffine::read(&mut r)?;
+    if !valid(&h) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "invalid curve point",
+        ));
+    }
+    let (endo_q, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+    Ok(SRS {
+        g,
+        h,
+        endo_r,
+        endo_q,
+    })
+}
+
+// As zexe_bn382_fq_urs_read, for large files on machines with many cores.
+// Unlike it, this also checks that every point is on the curve.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_read_parallel(path: *mut c_char) -> *const SRS<GAffine> {
+    const NAME: &str = "zexe_bn382_fq_urs_read_parallel";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let res = File::open(path)
+            .and_then(|f| in_pool(|| read_srs_par(BufReader::new(f), fq_point_valid)));
+        match res {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+// Reads an SRS without checking its points, for a cache which this process
+// wrote itself, for instance with zexe_bn382_fq_urs_write_with_digest.
+// Returns null if the digest of the file is not the expected one, which is
+// the only check made. This is unsafe on any file which might have been
+// tampered with: invalid points break the soundness of the commitments.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_read_unchecked(
+    path: *mut c_char,
+    digest: *const UrsDigest,
+) -> *const SRS<GAffine> {
+    const NAME: &str = "zexe_bn382_fq_urs_read_unchecked";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let digest = handle(digest);
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let digest = unsafe { &*digest };
+        let res = File::open(path).and_then(|f| {
+            let mut r = DigestReader::new(BufReader::new(f));
+            let srs = in_pool(|| read_srs_par(&mut r, |_| true))?;
+            Ok((srs, r.finish()))
+        });
+        match res {
+            Ok((srs, d)) if &d == digest => into_handle(Box::new(srs)),
+            Ok(_) => null_with_error(format!("{}: The digest does not match.", NAME)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_digest(urs: *const SRS<GAffine>) -> *mut UrsDigest {
+    catch_ffi("zexe_bn382_fq_urs_digest", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let mut w = DigestWriter::new(std::io::sink());
+        urs.write(&mut w).unwrap();
+        into_handle(Box::new(w.finish().unwrap()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_write_with_digest(
+    urs: *const SRS<GAffine>,
+    path: *mut c_char,
+) -> *mut UrsDigest {
+    catch_ffi("zexe_bn382_fq_urs_write_with_digest", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = DigestWriter::new(BufWriter::new(File::create(path).unwrap()));
+        let urs = unsafe { &*urs };
+        urs.write(&mut w).unwrap();
+        into_handle(Box::new(w.finish().unwrap()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_read_with_digest(
+    path: *mut c_char,
+) -> PointerPair<SRS<GAffine>, UrsDigest> {
+    catch_ffi(
+        "zexe_bn382_fq_urs_read_with_digest",
+        PointerPair {
+            a: std::ptr::null(),
+            b: std::ptr::null(),
+        },
+        || {
+            let path = (unsafe { CStr::from_ptr(path) })
+                .to_string_lossy()
+                .into_owned();
+            let mut r = DigestReader::new(BufReader::new(File::open(path).unwrap()));
+            let res = SRS::<GAffine>::read(&mut r).unwrap();
+            PointerPair {
+                a: into_handle(Box::new(res)),
+                b: into_handle(Box::new(r.finish())),
+            }
+        },
+    )
 }
 
 #[no_mangle]
@@ -86,32 +240,173 @@ pub extern "C" fn zexe_bn382_fq_urs_lagrange_commitment(
     domain_size: usize,
     i: usize,
 ) -> *const PolyComm<GAffine> {
-    let urs = unsafe { &*urs };
-    let x_domain = EvaluationDomain::<Fq>::new(domain_size).unwrap();
+    catch_ffi("zexe_bn382_fq_urs_lagrange_commitment", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let x_domain = EvaluationDomain::<Fq>::new(domain_size).unwrap();
 
-    let evals = (0..domain_size)
-        .map(|j| if i == j { Fq::one() } else { Fq::zero() })
-        .collect();
-    let p = Evaluations::<Fq>::from_vec_and_domain(evals, x_domain).interpolate();
-    let res = urs.commit(&p, None);
+        let evals = (0..domain_size)
+            .map(|j| if i == j { Fq::one() } else { Fq::zero() })
+            .collect();
+        let p = Evaluations::<Fq>::from_vec_and_domain(evals, x_domain).interpolate();
+        let res = urs.commit(&p, None);
+
+        into_handle(Box::new(res))
+    })
+}
+
+// The commitments to all the Lagrange basis polynomials of the domain, in
+// order, as zexe_bn382_fq_urs_lagrange_commitment would give them one by one.
+// The domain may not be larger than the SRS.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_lagrange_commitments(
+    urs: *const SRS<GAffine>,
+    domain_size: usize,
+) -> *const Vec<PolyComm<GAffine>> {
+    catch_ffi("zexe_bn382_fq_urs_lagrange_commitments", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let x_domain = Domain::<Fq>::new(domain_size).unwrap();
+        let res: Vec<_> = lagrange_commitments(&urs.g, x_domain)
+            .into_iter()
+            .map(|c| PolyComm {
+                unshifted: vec![c],
+                shifted: None,
+            })
+            .collect();
+        into_handle(Box::new(res))
+    })
+}
+
+// The fixed commitments of the SRS, for the domains H and K of the given
+// sizes, rounded up to powers of two. Commitments to polynomials longer than
+// the SRS have several chunks.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_fixed_commitments_create(
+    urs: *const SRS<GAffine>,
+    h_size: usize,
+    k_size: usize,
+) -> *const FixedCommitments<PolyComm<GAffine>> {
+    const NAME: &str = "zexe_bn382_fq_urs_fixed_commitments_create";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let (h, k) = match (Domain::<Fq>::new(h_size), Domain::<Fq>::new(k_size)) {
+            (Some(h), Some(k)) => (h, k),
+            _ => return null_with_error(format!("{}: Invalid domain size", NAME)),
+        };
+        let res = in_pool(|| FixedCommitments::create(h, k, |p| Ok(urs.commit(p, None))));
+        match res {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_fixed_commitments_z_h(
+    c: *const FixedCommitments<PolyComm<GAffine>>,
+) -> *const PolyComm<GAffine> {
+    catch_ffi("zexe_bn382_fq_urs_fixed_commitments_z_h", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        into_handle(Box::new(c.z_h.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_fixed_commitments_z_k(
+    c: *const FixedCommitments<PolyComm<GAffine>>,
+) -> *const PolyComm<GAffine> {
+    catch_ffi("zexe_bn382_fq_urs_fixed_commitments_z_k", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        into_handle(Box::new(c.z_k.clone()))
+    })
+}
 
-    Box::into_raw(Box::new(res))
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_fixed_commitments_monomial(
+    c: *const FixedCommitments<PolyComm<GAffine>>,
+    i: usize,
+) -> *const PolyComm<GAffine> {
+    const NAME: &str = "zexe_bn382_fq_urs_fixed_commitments_monomial";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        match c.monomial(i) {
+            Ok(m) => into_handle(Box::new(m.clone())),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
+crate::delete_stub!(zexe_bn382_fq_urs_fixed_commitments_delete, FixedCommitments<PolyComm<GAffine>>);
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_urs_commit_evaluations(
     urs: *const SRS<GAffine>,
     domain_size: usize,
     evals: *const Vec<Fq>,
 ) -> *const PolyComm<GAffine> {
-    let urs = unsafe { &*urs };
-    let x_domain = EvaluationDomain::<Fq>::new(domain_size).unwrap();
+    catch_ffi("zexe_bn382_fq_urs_commit_evaluations", std::ptr::null(), || {
+        let urs = handle(urs);
+        let evals = handle(evals);
+        let urs = unsafe { &*urs };
+        let x_domain = EvaluationDomain::<Fq>::new(domain_size).unwrap();
 
-    let evals = unsafe { &*evals };
-    let p = Evaluations::<Fq>::from_vec_and_domain(evals.clone(), x_domain).interpolate();
-    let res = urs.commit(&p, None);
+        let evals = unsafe { &*evals };
+        let p = Evaluations::<Fq>::from_vec_and_domain(evals.clone(), x_domain).interpolate();
+        let res = urs.commit(&p, None);
 
-    Box::into_raw(Box::new(res))
+        into_handle(Box::new(res))
+    })
+}
+
+// As zexe_bn382_fq_urs_commit_evaluations, for each evaluation vector. When
+// the domain is no larger than the SRS, its Lagrange commitments are computed
+// once, so that each commitment is a single multi-scalar multiplication.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_commit_evaluations_batch(
+    urs: *const SRS<GAffine>,
+    domain_size: usize,
+    evals: *const Vec<*const Vec<Fq>>,
+) -> *const Vec<PolyComm<GAffine>> {
+    const NAME: &str = "zexe_bn382_fq_urs_commit_evaluations_batch";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let urs = handle(urs);
+        let evals = handle(evals);
+        let urs = unsafe { &*urs };
+        let evals: Vec<&Vec<Fq>> = (unsafe { &*evals }).iter().map(|&e| unsafe { &*handle(e) }).collect();
+        let x_domain = Domain::<Fq>::new(domain_size).unwrap();
+        let res: Result<Vec<PolyComm<GAffine>>, String> = in_pool(|| {
+            if x_domain.size() > urs.g.len() {
+                // The commitments are split into chunks, so there are no
+                // Lagrange commitments to share.
+                return Ok(evals
+                    .par_iter()
+                    .map(|e| {
+                        let p = Evaluations::<Fq>::from_vec_and_domain((*e).clone(), x_domain)
+                            .interpolate();
+                        urs.commit(&p, None)
+                    })
+                    .collect());
+            }
+            let lagrange = fq_lagrange_commitments(urs, x_domain);
+            commit_evaluations_batch(&lagrange, &evals).map(|res| {
+                res.into_iter()
+                    .map(|c| PolyComm {
+                        unshifted: vec![c],
+                        shifted: None,
+                    })
+                    .collect()
+            })
+        });
+        match res {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
 #[no_mangle]
@@ -119,14 +414,18 @@ pub extern "C" fn zexe_bn382_fq_urs_b_poly_commitment(
     urs: *const SRS<GAffine>,
     chals: *const Vec<Fq>,
 ) -> *const PolyComm<GAffine> {
-    let chals = unsafe { &*chals };
-    let urs = unsafe { &*urs };
+    catch_ffi("zexe_bn382_fq_urs_b_poly_commitment", std::ptr::null(), || {
+        let urs = handle(urs);
+        let chals = handle(chals);
+        let chals = unsafe { &*chals };
+        let urs = unsafe { &*urs };
 
-    let coeffs = b_poly_coefficients(&chals);
-    let p = DensePolynomial::<Fq>::from_coefficients_vec(coeffs);
-    let g = urs.commit(&p, None);
+        let coeffs = b_poly_coefficients(&chals);
+        let p = DensePolynomial::<Fq>::from_coefficients_vec(coeffs);
+        let g = urs.commit(&p, None);
 
-    Box::into_raw(Box::new(g))
+        into_handle(Box::new(g))
+    })
 }
 
 #[no_mangle]
@@ -135,107 +434,548 @@ pub extern "C" fn zexe_bn382_fq_urs_batch_accumulator_check(
     comms: *const Vec<GAffine>,
     chals: *const Vec<Fq>,
 ) -> bool {
-    let urs = unsafe { &*urs };
-    let comms = unsafe { &*comms };
-    let chals = unsafe { &*chals };
-    batch_dlog_accumulator_check(urs, comms, chals)
+    catch_ffi("zexe_bn382_fq_urs_batch_accumulator_check", false, || {
+        let urs = handle(urs);
+        let comms = handle(comms);
+        let chals = handle(chals);
+        let urs = unsafe { &*urs };
+        let comms = unsafe { &*comms };
+        let chals = unsafe { &*chals };
+        batch_dlog_accumulator_check(urs, comms, chals)
+    })
+}
+
+// Fq opening accumulator
+//
+// Verifies inner product openings of single commitments jointly, except for
+// the one expensive part of each: the check that the sg point of the opening
+// is the commitment to the polynomial b of its round challenges. Those checks
+// are folded with powers of r into the single pending point sum_i r^i sg_i,
+// which is left for the outer circuit to compare with the commitment to
+// sum_i r^i b_i, or for fq_opening_accumulator_holds.
+//
+// commitment_dlog only verifies openings as part of a proof, with the sponge
+// of its transcript, so the succinct part of its check is reproduced here for
+// openings made with a fresh sponge which absorbed the commitment, then the
+// evaluation point.
+pub struct FqOpeningAccumulator {
+    pending: GAffine,
+    // The challenges of the rounds of each opening in turn.
+    challenges: Vec<Fq>,
+    r: Fq,
+}
+
+// Checks everything about the opening of comm at point to value except its sg
+// point, and returns the challenges of its rounds if that holds.
+pub fn fq_opening_succinct_check(
+    srs: &SRS<GAffine>,
+    group_map: &<GAffine as CommitmentCurve>::Map,
+    comm: GAffine,
+    point: Fq,
+    value: Fq,
+    proof: &OpeningProof<GAffine>,
+) -> Option<Vec<Fq>> {
+    if proof.lr.len() != lr_rounds(srs) {
+        return None;
+    }
+    let (_, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+    let mut sponge = DefaultFqSponge::<Bn_382GParameters, SC>::new(oracle::bn_382::fp::params());
+    sponge.absorb_g(&[comm]);
+    sponge.absorb_fr(&[point]);
+
+    // As in the verifier of commitment_dlog, the evaluation is absorbed and
+    // determines the point U which it is committed with.
+    sponge.absorb_fr(&[value]);
+    let (x, y) = group_map.to_group(sponge.challenge_fq());
+    let u = GAffine::new(x, y, false);
+
+    let chals: Vec<Fq> = proof
+        .prechallenges(&mut sponge)
+        .iter()
+        .map(|c| c.to_field(&endo_r))
+        .collect();
+    let mut chal_invs = chals.clone();
+    algebra::fields::batch_inversion(&mut chal_invs);
+
+    sponge.absorb_g(&[proof.delta]);
+    let c = ScalarChallenge(sponge.challenge()).to_field(&endo_r);
+    let b0 = b_poly(&chals, point);
+
+    // c (C + v U + sum_j (L_j / u_j + u_j R_j)) + delta = z1 (sg + b0 U) + z2 H
+    let mut points = vec![comm, u, proof.delta, proof.sg, srs.h];
+    let mut scalars = vec![
+        c,
+        c * &value - &(proof.z1 * &b0),
+        Fq::one(),
+        -proof.z1,
+        -proof.z2,
+    ];
+    for ((l, r), (u_inv, u)) in proof.lr.iter().zip(chal_invs.iter().zip(chals.iter())) {
+        points.push(*l);
+        scalars.push(c * u_inv);
+        points.push(*r);
+        scalars.push(c * u);
+    }
+    let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
+    if VariableBaseMSM::multi_scalar_mul(&points, &scalars) == GProjective::zero() {
+        Some(chals)
+    } else {
+        None
+    }
+}
+
+// Checks the openings of comms[i] at points[i] to values[i] up to their sg
+// points, and folds those with powers of r into the pending point.
+pub fn fq_opening_accumulator(
+    srs: &SRS<GAffine>,
+    comms: &[GAffine],
+    points: &[Fq],
+    values: &[Fq],
+    proofs: &[&OpeningProof<GAffine>],
+    r: Fq,
+) -> Result<FqOpeningAccumulator, String> {
+    let n = comms.len();
+    if points.len() != n || values.len() != n || proofs.len() != n {
+        return Err(format!(
+            "{} commitments, {} points, {} values and {} proofs do not match",
+            n,
+            points.len(),
+            values.len(),
+            proofs.len()
+        ));
+    }
+    let group_map = <GAffine as CommitmentCurve>::Map::setup();
+    let mut pending = GProjective::zero();
+    let mut challenges = vec![];
+    let mut r_i = Fq::one();
+    for i in 0..n {
+        let chals =
+            fq_opening_succinct_check(srs, &group_map, comms[i], points[i], values[i], proofs[i]);
+        match chals {
+            Some(chals) => challenges.extend(chals),
+            None => return Err(format!("Opening {} does not verify", i)),
+        }
+        pending += &proofs[i].sg.mul(r_i);
+        r_i *= &r;
+    }
+    Ok(FqOpeningAccumulator {
+        pending: pending.into_affine(),
+        challenges,
+        r,
+    })
+}
+
+// Checks that the pending point is the commitment to sum_i r^i b_i.
+pub fn fq_opening_accumulator_holds(srs: &SRS<GAffine>, acc: &FqOpeningAccumulator) -> bool {
+    let rounds = lr_rounds(srs);
+    if acc.challenges.len() % rounds != 0 {
+        return false;
+    }
+    let mut s = vec![Fq::zero(); srs.g.len()];
+    let mut r_i = Fq::one();
+    for chals in acc.challenges.chunks(rounds) {
+        for (s, b) in s.iter_mut().zip(b_poly_coefficients(chals)) {
+            *s += &(r_i * &b);
+        }
+        r_i *= &acc.r;
+    }
+    let s: Vec<_> = s.iter().map(|x| x.into_repr()).collect();
+    VariableBaseMSM::multi_scalar_mul(&srs.g, &s) == acc.pending.into_projective()
+}
+
+// Verifies the openings, and returns their accumulator, or null with the last
+// error set if one of them does not verify. r must be unpredictable to
+// whoever made the openings.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_opening_accumulator(
+    urs: *const SRS<GAffine>,
+    comms: *const Vec<GAffine>,
+    points: *const Vec<Fq>,
+    values: *const Vec<Fq>,
+    proofs: *const Vec<*const OpeningProof<GAffine>>,
+    r: *const Fq,
+) -> *mut FqOpeningAccumulator {
+    catch_ffi("zexe_bn382_fq_urs_opening_accumulator", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let comms = handle(comms);
+        let points = handle(points);
+        let values = handle(values);
+        let proofs = handle(proofs);
+        let r = handle(r);
+        let proofs: Vec<_> = (unsafe { &*proofs })
+            .iter()
+            .map(|&p| unsafe { &*handle(p) })
+            .collect();
+        match fq_opening_accumulator(
+            unsafe { &*urs },
+            unsafe { &*comms },
+            unsafe { &*points },
+            unsafe { &*values },
+            &proofs,
+            unsafe { *r },
+        ) {
+            Ok(acc) => into_handle(Box::new(acc)),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_urs_opening_accumulator: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_opening_accumulator_check(
+    urs: *const SRS<GAffine>,
+    acc: *const FqOpeningAccumulator,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_urs_opening_accumulator_check", false, || {
+        let urs = handle(urs);
+        let acc = handle(acc);
+        fq_opening_accumulator_holds(unsafe { &*urs }, unsafe { &*acc })
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_accumulator_pending(
+    acc: *const FqOpeningAccumulator,
+) -> *const GAffine {
+    catch_ffi("zexe_bn382_fq_opening_accumulator_pending", std::ptr::null(), || {
+        let acc = handle(acc);
+        into_handle(Box::new(unsafe { (*acc).pending }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_accumulator_challenges(
+    acc: *const FqOpeningAccumulator,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_accumulator_challenges", std::ptr::null(), || {
+        let acc = handle(acc);
+        let x = (unsafe { &(*acc).challenges }).clone();
+        into_handle(Box::new(x))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_opening_accumulator_delete, FqOpeningAccumulator);
+
+// The number of inner product argument rounds, and so the number of
+// challenges per previous accumulator, of proofs using this SRS.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_lr_rounds(urs: *const SRS<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_urs_lr_rounds", 0, || {
+        let urs = handle(urs);
+        lr_rounds(unsafe { &*urs })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_urs_h(urs: *const SRS<GAffine>) -> *const GAffine {
-    let urs = unsafe { &*urs };
-    let res = urs.h;
-    Box::into_raw(Box::new(res))
+    catch_ffi("zexe_bn382_fq_urs_h", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let res = urs.h;
+        into_handle(Box::new(res))
+    })
 }
 
 // Fq index stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_domain_h_size<'a>(i: *const DlogIndex<'a, GAffine>) -> usize {
-    (unsafe { &*i }).domains.h.size()
+    catch_ffi("zexe_bn382_fq_index_domain_h_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).domains.h.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_domain_k_size<'a>(i: *const DlogIndex<'a, GAffine>) -> usize {
-    (unsafe { &*i }).domains.k.size()
+    catch_ffi("zexe_bn382_fq_index_domain_k_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).domains.k.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_create<'a>(
-    a: *mut Vec<(Vec<usize>, Vec<Fq>)>,
-    b: *mut Vec<(Vec<usize>, Vec<Fq>)>,
-    c: *mut Vec<(Vec<usize>, Vec<Fq>)>,
+    a: *mut Vec<Row<Fq>>,
+    b: *mut Vec<Row<Fq>>,
+    c: *mut Vec<Row<Fq>>,
+    vars: usize,
+    public_inputs: usize,
+    srs: *mut SRS<GAffine>,
+) -> *mut DlogIndex<'a, GAffine> {
+    catch_ffi("zexe_bn382_fq_index_create", std::ptr::null_mut(), || in_pool(|| {
+        let a = handle(a);
+        let b = handle(b);
+        let c = handle(c);
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+        let a = unsafe { &*a };
+        let b = unsafe { &*b };
+        let c = unsafe { &*c };
+
+        let (h_group_size, h_to_x_ratio) = match h_domain_sizes(a.len(), vars, public_inputs) {
+            Ok(sizes) => sizes,
+            Err(e) => return null_with_error(format!("zexe_bn382_fq_index_create: {}", e)),
+        };
+
+        return fq_index_into_handle(
+            DlogIndex::<GAffine>::create(
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, a),
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, b),
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, c),
+                public_inputs,
+                srs.max_degree(),
+                oracle::bn_382::fq::params(),
+                oracle::bn_382::fp::params(),
+                SRSSpec::Use(srs),
+            )
+            .unwrap(),
+        );
+    }))
+}
+
+// As zexe_bn382_fq_index_create, but pulls the rows of the constraints from the
+// callback, as described at RowSource, so that the matrices are never held as
+// vectors of rows.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_create_streaming<'a>(
+    rows: usize,
     vars: usize,
     public_inputs: usize,
     srs: *mut SRS<GAffine>,
+    source: RowSource,
+    ctx: *mut c_void,
 ) -> *mut DlogIndex<'a, GAffine> {
-    assert!(public_inputs > 0);
+    catch_ffi("zexe_bn382_fq_index_create_streaming", std::ptr::null_mut(), || {
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+
+        let (h_group_size, h_to_x_ratio) = match h_domain_sizes(rows, vars, public_inputs) {
+            Ok(sizes) => sizes,
+            Err(e) => {
+                return null_with_error(format!("zexe_bn382_fq_index_create_streaming: {}", e))
+            }
+        };
+
+        let mut matrices = vec![];
+        for matrix in 0..3 {
+            match csmat_of_row_source::<Fq>(
+                source,
+                ctx,
+                matrix,
+                rows,
+                public_inputs,
+                h_group_size,
+                h_to_x_ratio,
+            ) {
+                Ok(m) => matrices.push(m),
+                Err(e) => {
+                    return null_with_error(format!("zexe_bn382_fq_index_create_streaming: {}", e))
+                }
+            }
+        }
+        let c = matrices.pop().unwrap();
+        let b = matrices.pop().unwrap();
+        let a = matrices.pop().unwrap();
+
+        in_pool(|| {
+            fq_index_into_handle(
+                DlogIndex::<GAffine>::create(
+                    a,
+                    b,
+                    c,
+                    public_inputs,
+                    srs.max_degree(),
+                    oracle::bn_382::fq::params(),
+                    oracle::bn_382::fp::params(),
+                    SRSSpec::Use(srs),
+                )
+                .unwrap(),
+            )
+        })
+    })
+}
 
-    let srs = unsafe { &*srs };
-    let a = unsafe { &*a };
-    let b = unsafe { &*b };
-    let c = unsafe { &*c };
+// Whether any constraint of the index refers to an auxiliary variable. Only
+// computed when the index is created or read, and recorded with it.
+fn fq_index_scans_auxiliary(index: &DlogIndex<GAffine>) -> bool {
+    let c = &index.compiled;
+    constraints_use_auxiliary(
+        &[&c[0].constraints, &c[1].constraints, &c[2].constraints],
+        index.domains.h.size() / index.domains.x.size(),
+    )
+}
+
+fn fq_index_into_handle<'a>(index: DlogIndex<'a, GAffine>) -> *mut DlogIndex<'a, GAffine> {
+    let uses_auxiliary = fq_index_scans_auxiliary(&index);
+    let index = into_handle(Box::new(index));
+    record_auxiliary_use(index, uses_auxiliary);
+    index
+}
 
-    let num_constraints = a.len();
+fn forget_fq_index(index: *const DlogIndex<GAffine>) {
+    forget_labelled_system(index);
+    forget_auxiliary_use(index);
+}
 
-    let m = if num_constraints > vars {
-        num_constraints
-    } else {
-        vars
-    };
+crate::delete_stub!(
+    zexe_bn382_fq_index_delete,
+    DlogIndex<'static, GAffine>,
+    forget_fq_index
+);
 
-    let h_group_size = Domain::<Fq>::compute_size_of_domain(m).unwrap();
-    let h_to_x_ratio = {
-        let x_group_size = Domain::<Fq>::compute_size_of_domain(public_inputs).unwrap();
-        h_group_size / x_group_size
-    };
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_nonzero_entries(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_nonzero_entries", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index
+            .compiled
+            .iter()
+            .map(|x| x.constraints.nnz())
+            .max()
+            .unwrap()
+    })
+}
 
-    return Box::into_raw(Box::new(
-        DlogIndex::<GAffine>::create(
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, a),
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, b),
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, c),
-            public_inputs,
-            srs.max_degree(),
-            oracle::bn_382::fq::params(),
-            oracle::bn_382::fp::params(),
-            SRSSpec::Use(srs),
-        )
-        .unwrap(),
-    ));
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_nonzero_entries_a(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_nonzero_entries_a", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[0].constraints.nnz()
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_index_delete(x: *mut DlogIndex<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fq_index_nonzero_entries_b(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_nonzero_entries_b", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[1].constraints.nnz()
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_index_nonzero_entries(index: *const DlogIndex<GAffine>) -> usize {
-    let index = unsafe { &*index };
-    index
-        .compiled
-        .iter()
-        .map(|x| x.constraints.nnz())
-        .max()
-        .unwrap()
+pub extern "C" fn zexe_bn382_fq_index_nonzero_entries_c(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_nonzero_entries_c", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[2].constraints.nnz()
+    })
+}
+
+// Exports compiled matrix A (0), B (1) or C (2) of the index, as used by the
+// prover and verifier.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_csr(index: *const DlogIndex<GAffine>, matrix: usize) -> *mut Csr<Fq> {
+    catch_ffi("zexe_bn382_fq_index_csr", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        if matrix > 2 {
+            return null_with_error(format!("zexe_bn382_fq_index_csr: No matrix {}", matrix));
+        }
+        into_handle(Box::new(csr_of_csmat(&index.compiled[matrix].constraints)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_csr_rows(m: *const Csr<Fq>) -> usize {
+    catch_ffi("zexe_bn382_fq_csr_rows", 0, || {
+        let m = handle(m);
+        (unsafe { &*m }).rows
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_csr_cols(m: *const Csr<Fq>) -> usize {
+    catch_ffi("zexe_bn382_fq_csr_cols", 0, || {
+        let m = handle(m);
+        (unsafe { &*m }).cols
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_csr_indptr(m: *const Csr<Fq>) -> *mut Vec<usize> {
+    catch_ffi("zexe_bn382_fq_csr_indptr", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).indptr.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_csr_indices(m: *const Csr<Fq>) -> *mut Vec<usize> {
+    catch_ffi("zexe_bn382_fq_csr_indices", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).indices.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_csr_values(m: *const Csr<Fq>) -> *mut Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_csr_values", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).values.clone()))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_csr_delete, Csr<Fq>);
+
+// The size of the k domain needed by the matrices, which is the maximum of
+// their numbers of nonzero entries rounded up to a power of two.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_domain_k_padded_size(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_domain_k_padded_size", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let nnz = index
+            .compiled
+            .iter()
+            .map(|x| x.constraints.nnz())
+            .max()
+            .unwrap();
+        match domain_size("non-zero entries", nnz) {
+            Ok(size) => size,
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fq_index_domain_k_padded_size: {}", e));
+                0
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_max_degree(index: *const DlogIndex<GAffine>) -> usize {
-    let index = unsafe { &*index };
-    index.srs.get_ref().max_degree()
+    catch_ffi("zexe_bn382_fq_index_max_degree", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.srs.get_ref().max_degree()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_index_lr_rounds(index: *const DlogIndex<GAffine>) -> usize {
+    catch_ffi("zexe_bn382_fq_index_lr_rounds", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        lr_rounds(index.srs.get_ref())
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_num_variables(index: *const DlogIndex<GAffine>) -> usize {
-    let index = unsafe { &*index };
-    index.compiled[0].constraints.shape().0
+    catch_ffi("zexe_bn382_fq_index_num_variables", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[0].constraints.shape().0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_public_inputs(index: *const DlogIndex<GAffine>) -> usize {
-    let index = unsafe { &*index };
-    index.public_inputs
+    catch_ffi("zexe_bn382_fq_index_public_inputs", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.public_inputs
+    })
 }
 
 #[no_mangle]
@@ -243,134 +983,147 @@ pub extern "C" fn zexe_bn382_fq_index_write<'a>(
     index: *const DlogIndex<'a, GAffine>,
     path: *const c_char,
 ) {
-    fn write_compiled<W: Write>(
-        c: &marlin_protocol_dlog::compiled::Compiled<GAffine>,
-        mut w: W,
-    ) -> IoResult<()> {
-        write_poly_comm(&c.col_comm, &mut w)?;
-        write_poly_comm(&c.row_comm, &mut w)?;
-        write_poly_comm(&c.val_comm, &mut w)?;
-        write_poly_comm(&c.rc_comm, &mut w)?;
-        write_dense_polynomial(&c.rc, &mut w)?;
-        write_dense_polynomial(&c.row, &mut w)?;
-        write_dense_polynomial(&c.col, &mut w)?;
-        write_dense_polynomial(&c.val, &mut w)?;
-        write_evaluations(&c.row_eval_k, &mut w)?;
-        write_evaluations(&c.col_eval_k, &mut w)?;
-        write_evaluations(&c.val_eval_k, &mut w)?;
-        write_evaluations(&c.row_eval_b, &mut w)?;
-        write_evaluations(&c.col_eval_b, &mut w)?;
-        write_evaluations(&c.val_eval_b, &mut w)?;
-        write_evaluations(&c.rc_eval_b, &mut w)?;
-        Ok(())
-    }
+    catch_ffi("zexe_bn382_fq_index_write", (), || {
+        let index = handle(index);
+        fn write_compiled<W: Write>(
+            c: &marlin_protocol_dlog::compiled::Compiled<GAffine>,
+            mut w: W,
+        ) -> IoResult<()> {
+            write_cs_mat(&c.constraints, &mut w)?;
+            write_poly_comm(&c.col_comm, &mut w)?;
+            write_poly_comm(&c.row_comm, &mut w)?;
+            write_poly_comm(&c.val_comm, &mut w)?;
+            write_poly_comm(&c.rc_comm, &mut w)?;
+            write_dense_polynomial(&c.rc, &mut w)?;
+            write_dense_polynomial(&c.row, &mut w)?;
+            write_dense_polynomial(&c.col, &mut w)?;
+            write_dense_polynomial(&c.val, &mut w)?;
+            write_evaluations(&c.row_eval_k, &mut w)?;
+            write_evaluations(&c.col_eval_k, &mut w)?;
+            write_evaluations(&c.val_eval_k, &mut w)?;
+            write_evaluations(&c.row_eval_b, &mut w)?;
+            write_evaluations(&c.col_eval_b, &mut w)?;
+            write_evaluations(&c.val_eval_b, &mut w)?;
+            write_evaluations(&c.rc_eval_b, &mut w)?;
+            Ok(())
+        }
+
+        let index = unsafe { &*index };
 
-    let index = unsafe { &*index };
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = match File::create(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fq_index_write: {}: {}", path, e));
+                return;
+            }
+        };
+        let mut w = BufWriter::new(file);
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let t: IoResult<()> = (|| {
+            write_index_file_version(&mut w)?;
+            write_evaluation_domains(&index.domains, &mut w)?;
 
-    let t: IoResult<()> = (|| {
-        write_evaluation_domains(&index.domains, &mut w)?;
+            for c in index.compiled.iter() {
+                write_compiled(c, &mut w)?;
+            }
 
-        for c in index.compiled.iter() {
-            write_compiled(c, &mut w)?;
+            u64::write(&(index.public_inputs as u64), &mut w)?;
+            w.flush()
+        })();
+        if let Err(e) = t {
+            set_last_error(format!("zexe_bn382_fq_index_write: {}", e));
         }
-
-        u64::write(&(index.public_inputs as u64), &mut w)?;
-        Ok(())
-    })();
-    t.unwrap()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_index_read<'a>(
     srs: *const SRS<GAffine>,
-    a: *const Vec<(Vec<usize>, Vec<Fq>)>,
-    b: *const Vec<(Vec<usize>, Vec<Fq>)>,
-    c: *const Vec<(Vec<usize>, Vec<Fq>)>,
-    public_inputs: usize,
     path: *const c_char,
 ) -> *const DlogIndex<'a, GAffine> {
-    fn read_compiled<R: Read>(
-        public_inputs: usize,
-        ds: EvaluationDomains<Fq>,
-        m: *const Vec<(Vec<usize>, Vec<Fq>)>,
-        mut r: R,
-    ) -> IoResult<marlin_protocol_dlog::compiled::Compiled<GAffine>> {
-        let constraints = rows_to_csmat(
-            public_inputs,
-            ds.h.size(),
-            ds.h.size() / ds.x.size(),
-            unsafe { &*m },
-        );
-
-        let col_comm = read_poly_comm(&mut r)?;
-        let row_comm = read_poly_comm(&mut r)?;
-        let val_comm = read_poly_comm(&mut r)?;
-        let rc_comm = read_poly_comm(&mut r)?;
-        let rc = read_dense_polynomial(&mut r)?;
-        let row = read_dense_polynomial(&mut r)?;
-        let col = read_dense_polynomial(&mut r)?;
-        let val = read_dense_polynomial(&mut r)?;
-        let row_eval_k = read_evaluations(&mut r)?;
-        let col_eval_k = read_evaluations(&mut r)?;
-        let val_eval_k = read_evaluations(&mut r)?;
-        let row_eval_b = read_evaluations(&mut r)?;
-        let col_eval_b = read_evaluations(&mut r)?;
-        let val_eval_b = read_evaluations(&mut r)?;
-        let rc_eval_b = read_evaluations(&mut r)?;
-
-        Ok(marlin_protocol_dlog::compiled::Compiled {
-            constraints,
-            col_comm,
-            row_comm,
-            val_comm,
-            rc_comm,
-            rc,
-            row,
-            col,
-            val,
-            row_eval_k,
-            col_eval_k,
-            val_eval_k,
-            row_eval_b,
-            col_eval_b,
-            val_eval_b,
-            rc_eval_b,
-        })
-    }
+    catch_ffi("zexe_bn382_fq_index_read", std::ptr::null(), || {
+        let srs = handle(srs);
+        fn read_compiled<R: Read>(
+            mut r: R,
+        ) -> IoResult<marlin_protocol_dlog::compiled::Compiled<GAffine>> {
+            let constraints = read_cs_mat(&mut r)?;
+            let col_comm = read_poly_comm(&mut r)?;
+            let row_comm = read_poly_comm(&mut r)?;
+            let val_comm = read_poly_comm(&mut r)?;
+            let rc_comm = read_poly_comm(&mut r)?;
+            let rc = read_dense_polynomial(&mut r)?;
+            let row = read_dense_polynomial(&mut r)?;
+            let col = read_dense_polynomial(&mut r)?;
+            let val = read_dense_polynomial(&mut r)?;
+            let row_eval_k = read_evaluations(&mut r)?;
+            let col_eval_k = read_evaluations(&mut r)?;
+            let val_eval_k = read_evaluations(&mut r)?;
+            let row_eval_b = read_evaluations(&mut r)?;
+            let col_eval_b = read_evaluations(&mut r)?;
+            let val_eval_b = read_evaluations(&mut r)?;
+            let rc_eval_b = read_evaluations(&mut r)?;
+
+            Ok(marlin_protocol_dlog::compiled::Compiled {
+                constraints,
+                col_comm,
+                row_comm,
+                val_comm,
+                rc_comm,
+                rc,
+                row,
+                col,
+                val,
+                row_eval_k,
+                col_eval_k,
+                val_eval_k,
+                row_eval_b,
+                col_eval_b,
+                val_eval_b,
+                rc_eval_b,
+            })
+        }
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                return null_with_error(format!("zexe_bn382_fq_index_read: {}: {}", path, e))
+            }
+        };
+        let mut r = BufReader::new(file);
 
-    let srs = unsafe { &*srs };
+        let srs = unsafe { &*srs };
 
-    let t: IoResult<_> = (|| {
-        let domains = read_evaluation_domains(&mut r)?;
+        let t: IoResult<_> = (|| {
+            read_index_file_version(&mut r)?;
+            let domains = read_evaluation_domains(&mut r)?;
 
-        let c0 = read_compiled(public_inputs, domains, a, &mut r)?;
-        let c1 = read_compiled(public_inputs, domains, b, &mut r)?;
-        let c2 = read_compiled(public_inputs, domains, c, &mut r)?;
+            let c0 = read_compiled(&mut r)?;
+            let c1 = read_compiled(&mut r)?;
+            let c2 = read_compiled(&mut r)?;
 
-        let public_inputs = u64::read(&mut r)? as usize;
+            let public_inputs = u64::read(&mut r)? as usize;
 
-        Ok(DlogIndex::<GAffine> {
-            compiled: [c0, c1, c2],
-            domains,
-            public_inputs,
-            max_poly_size: srs.max_degree(),
-            srs: SRSValue::Ref(srs),
-            fr_sponge_params: oracle::bn_382::fq::params(),
-            fq_sponge_params: oracle::bn_382::fp::params(),
-        })
-    })();
-    let res = Box::into_raw(Box::new(t.unwrap()));
-    res
+            Ok(DlogIndex::<GAffine> {
+                compiled: [c0, c1, c2],
+                domains,
+                public_inputs,
+                max_poly_size: srs.max_degree(),
+                srs: SRSValue::Ref(srs),
+                fr_sponge_params: oracle::bn_382::fq::params(),
+                fq_sponge_params: oracle::bn_382::fp::params(),
+            })
+        })();
+        match t {
+            Ok(index) => fq_index_into_handle(index),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_index_read: {}", e)),
+        }
+    })
 }
 
 // Fq verifier index stubs
@@ -378,26 +1131,44 @@ pub extern "C" fn zexe_bn382_fq_index_read<'a>(
 pub extern "C" fn zexe_bn382_fq_verifier_index_create(
     index: *const DlogIndex<GAffine>,
 ) -> *const DlogVerifierIndex<GAffine> {
-    Box::into_raw(Box::new(unsafe { &(*index) }.verifier_index()))
+    catch_ffi("zexe_bn382_fq_verifier_index_create", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(unsafe { &(*index) }.verifier_index()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_urs<'a>(
     index: *const DlogVerifierIndex<'a, GAffine>,
 ) -> *const SRS<GAffine> {
-    let index = unsafe { &*index };
-    let urs = index.srs.get_ref().clone();
-    Box::into_raw(Box::new(urs))
+    catch_ffi("zexe_bn382_fq_verifier_index_urs", std::ptr::null(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let urs = index.srs.get_ref().clone();
+        into_handle(Box::new(urs))
+    })
 }
 
+// Returns a pointer to the SRS held by the verifier index without copying it. The
+// pointer is borrowed: it must not be deleted and is only valid while the index is.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_verifier_index_make<'a>(
+pub extern "C" fn zexe_bn382_fq_verifier_index_urs_ref<'a>(
+    index: *const DlogVerifierIndex<'a, GAffine>,
+) -> *const SRS<GAffine> {
+    catch_ffi("zexe_bn382_fq_verifier_index_urs_ref", std::ptr::null(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.srs.get_ref()
+    })
+}
+
+fn verifier_index_of_parts<'a>(
     public_inputs: usize,
     variables: usize,
     constraints: usize,
     nonzero_entries: usize,
     max_poly_size: usize,
-    urs: *const SRS<GAffine>,
+    srs: SRSValue<'a, GAffine>,
     row_a: *const PolyComm<GAffine>,
     col_a: *const PolyComm<GAffine>,
     val_a: *const PolyComm<GAffine>,
@@ -412,9 +1183,8 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_make<'a>(
     col_c: *const PolyComm<GAffine>,
     val_c: *const PolyComm<GAffine>,
     rc_c: *const PolyComm<GAffine>,
-) -> *const DlogVerifierIndex<'a, GAffine> {
-    let srs: SRS<GAffine> = (unsafe { &*urs }).clone();
-    let index = DlogVerifierIndex::<GAffine> {
+) -> DlogVerifierIndex<'a, GAffine> {
+    DlogVerifierIndex::<GAffine> {
         domains: EvaluationDomains::create(variables, constraints, public_inputs, nonzero_entries)
             .unwrap(),
         matrix_commitments: [
@@ -441,14 +1211,177 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_make<'a>(
         fr_sponge_params: oracle::bn_382::fq::params(),
         max_poly_size,
         public_inputs,
-        srs: SRSValue::Value(srs),
-    };
-    Box::into_raw(Box::new(index))
+        srs,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_lr_rounds<'a>(
+    index: *const DlogVerifierIndex<'a, GAffine>,
+) -> usize {
+    catch_ffi("zexe_bn382_fq_verifier_index_lr_rounds", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        lr_rounds(index.srs.get_ref())
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_verifier_index_delete(x: *mut DlogVerifierIndex<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fq_verifier_index_make<'a>(
+    public_inputs: usize,
+    variables: usize,
+    constraints: usize,
+    nonzero_entries: usize,
+    max_poly_size: usize,
+    urs: *const SRS<GAffine>,
+    row_a: *const PolyComm<GAffine>,
+    col_a: *const PolyComm<GAffine>,
+    val_a: *const PolyComm<GAffine>,
+    rc_a: *const PolyComm<GAffine>,
+
+    row_b: *const PolyComm<GAffine>,
+    col_b: *const PolyComm<GAffine>,
+    val_b: *const PolyComm<GAffine>,
+    rc_b: *const PolyComm<GAffine>,
+
+    row_c: *const PolyComm<GAffine>,
+    col_c: *const PolyComm<GAffine>,
+    val_c: *const PolyComm<GAffine>,
+    rc_c: *const PolyComm<GAffine>,
+) -> *const DlogVerifierIndex<'a, GAffine> {
+    catch_ffi("zexe_bn382_fq_verifier_index_make", std::ptr::null(), || {
+        let urs = handle(urs);
+        let row_a = handle(row_a);
+        let col_a = handle(col_a);
+        let val_a = handle(val_a);
+        let rc_a = handle(rc_a);
+        let row_b = handle(row_b);
+        let col_b = handle(col_b);
+        let val_b = handle(val_b);
+        let rc_b = handle(rc_b);
+        let row_c = handle(row_c);
+        let col_c = handle(col_c);
+        let val_c = handle(val_c);
+        let rc_c = handle(rc_c);
+        let srs: SRS<GAffine> = (unsafe { &*urs }).clone();
+        into_handle(Box::new(verifier_index_of_parts(
+            public_inputs,
+            variables,
+            constraints,
+            nonzero_entries,
+            max_poly_size,
+            SRSValue::Value(srs),
+            row_a,
+            col_a,
+            val_a,
+            rc_a,
+            row_b,
+            col_b,
+            val_b,
+            rc_b,
+            row_c,
+            col_c,
+            val_c,
+            rc_c,
+        )))
+    })
+}
+
+// Like zexe_bn382_fq_verifier_index_make, but borrows the SRS instead of copying it.
+// The SRS must outlive the returned verifier index.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_make_shared<'a>(
+    public_inputs: usize,
+    variables: usize,
+    constraints: usize,
+    nonzero_entries: usize,
+    max_poly_size: usize,
+    urs: *const SRS<GAffine>,
+    row_a: *const PolyComm<GAffine>,
+    col_a: *const PolyComm<GAffine>,
+    val_a: *const PolyComm<GAffine>,
+    rc_a: *const PolyComm<GAffine>,
+
+    row_b: *const PolyComm<GAffine>,
+    col_b: *const PolyComm<GAffine>,
+    val_b: *const PolyComm<GAffine>,
+    rc_b: *const PolyComm<GAffine>,
+
+    row_c: *const PolyComm<GAffine>,
+    col_c: *const PolyComm<GAffine>,
+    val_c: *const PolyComm<GAffine>,
+    rc_c: *const PolyComm<GAffine>,
+) -> *const DlogVerifierIndex<'a, GAffine> {
+    catch_ffi("zexe_bn382_fq_verifier_index_make_shared", std::ptr::null(), || {
+        let urs = handle(urs);
+        let row_a = handle(row_a);
+        let col_a = handle(col_a);
+        let val_a = handle(val_a);
+        let rc_a = handle(rc_a);
+        let row_b = handle(row_b);
+        let col_b = handle(col_b);
+        let val_b = handle(val_b);
+        let rc_b = handle(rc_b);
+        let row_c = handle(row_c);
+        let col_c = handle(col_c);
+        let val_c = handle(val_c);
+        let rc_c = handle(rc_c);
+        into_handle(Box::new(verifier_index_of_parts(
+            public_inputs,
+            variables,
+            constraints,
+            nonzero_entries,
+            max_poly_size,
+            SRSValue::Ref(unsafe { &*urs }),
+            row_a,
+            col_a,
+            val_a,
+            rc_a,
+            row_b,
+            col_b,
+            val_b,
+            rc_b,
+            row_c,
+            col_c,
+            val_c,
+            rc_c,
+        )))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_verifier_index_delete, DlogVerifierIndex<GAffine>);
+
+fn write_verifier_index<W: Write>(
+    index: &DlogVerifierIndex<GAffine>,
+    mut w: W,
+) -> IoResult<()> {
+    for c in index.matrix_commitments.iter() {
+        write_dlog_matrix_values(c, &mut w)?;
+    }
+    write_evaluation_domains(&index.domains, &mut w)?;
+    u64::write(&(index.public_inputs as u64), &mut w)?;
+    u64::write(&(index.max_poly_size as u64), &mut w)
+}
+
+fn read_verifier_index<'a, R: Read>(
+    srs: &'a SRS<GAffine>,
+    mut r: R,
+) -> IoResult<DlogVerifierIndex<'a, GAffine>> {
+    let m0 = read_dlog_matrix_values(&mut r)?;
+    let m1 = read_dlog_matrix_values(&mut r)?;
+    let m2 = read_dlog_matrix_values(&mut r)?;
+    let domains = read_evaluation_domains(&mut r)?;
+    let public_inputs = u64::read(&mut r)? as usize;
+    let max_poly_size = u64::read(&mut r)? as usize;
+    Ok(DlogVerifierIndex {
+        matrix_commitments: [m0, m1, m2],
+        domains,
+        public_inputs,
+        max_poly_size,
+        srs: SRSValue::Ref(srs),
+        fr_sponge_params: oracle::bn_382::fq::params(),
+        fq_sponge_params: oracle::bn_382::fp::params(),
+    })
 }
 
 #[no_mangle]
@@ -456,23 +1389,17 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_write<'a>(
     index: *const DlogVerifierIndex<GAffine>,
     path: *const c_char,
 ) {
-    let index = unsafe { &*index };
+    catch_ffi("zexe_bn382_fq_verifier_index_write", (), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = BufWriter::new(File::create(path).unwrap());
 
-    let t: IoResult<()> = (|| {
-        for c in index.matrix_commitments.iter() {
-            write_dlog_matrix_values(c, &mut w)?;
-        }
-        write_evaluation_domains(&index.domains, &mut w)?;
-        u64::write(&(index.public_inputs as u64), &mut w)?;
-        u64::write(&(index.max_poly_size as u64), &mut w)?;
-        Ok(())
-    })();
-    t.unwrap()
+        write_verifier_index(index, &mut w).unwrap()
+    })
 }
 
 #[no_mangle]
@@ -480,154 +1407,217 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_read<'a>(
     srs: *const SRS<GAffine>,
     path: *const c_char,
 ) -> *const DlogVerifierIndex<'a, GAffine> {
-    let srs = unsafe { &*srs };
-
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(path).unwrap());
-
-    let t: IoResult<_> = (|| {
-        let m0 = read_dlog_matrix_values(&mut r)?;
-        let m1 = read_dlog_matrix_values(&mut r)?;
-        let m2 = read_dlog_matrix_values(&mut r)?;
-        let domains = read_evaluation_domains(&mut r)?;
-        let public_inputs = u64::read(&mut r)? as usize;
-        let max_poly_size = u64::read(&mut r)? as usize;
-        Ok(DlogVerifierIndex {
-            matrix_commitments: [m0, m1, m2],
-            domains,
-            public_inputs,
-            max_poly_size,
-            srs: SRSValue::Ref(srs),
-            fr_sponge_params: oracle::bn_382::fq::params(),
-            fq_sponge_params: oracle::bn_382::fp::params(),
-        })
-    })();
-    Box::into_raw(Box::new(t.unwrap()))
+    catch_ffi("zexe_bn382_fq_verifier_index_read", std::ptr::null(), || {
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut r = BufReader::new(File::open(path).unwrap());
+
+        into_handle(Box::new(read_verifier_index(srs, &mut r).unwrap()))
+    })
+}
+
+// The encoding is the same as that of zexe_bn382_fq_verifier_index_write. It
+// does not include the SRS, which must be supplied when decoding and is
+// borrowed by the resulting index.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_to_bytes(
+    index: *const DlogVerifierIndex<GAffine>,
+) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fq_verifier_index_to_bytes", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let mut res = vec![];
+        write_verifier_index(index, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_of_bytes<'a>(
+    srs: *const SRS<GAffine>,
+    data: *const u8,
+    len: usize,
+) -> *const DlogVerifierIndex<'a, GAffine> {
+    catch_ffi("zexe_bn382_fq_verifier_index_of_bytes", std::ptr::null(), || {
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+        let mut data = unsafe { std::slice::from_raw_parts(data, len) };
+        match read_verifier_index(srs, &mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_fq_verifier_index_of_bytes: trailing bytes after index".to_string(),
+            ),
+            Ok(index) => into_handle(Box::new(index)),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_verifier_index_of_bytes: {}", e)),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_a_row_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[0].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_a_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[0].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_a_col_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[0].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_a_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[0].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_a_val_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[0].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_a_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[0].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_a_rc_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[0].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_a_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[0].rc }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_b_row_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[1].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_b_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[1].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_b_col_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[1].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_b_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[1].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_b_val_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[1].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_b_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[1].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_b_rc_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[1].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_b_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[1].rc }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_c_row_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[2].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_c_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[2].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_c_col_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[2].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_c_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[2].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_c_val_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[2].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_c_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[2].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_c_rc_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new(
-        (unsafe { &(*index).matrix_commitments[2].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fq_verifier_index_c_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { &(*index).matrix_commitments[2].rc }).clone(),
+        ))
+    })
 }
 
 // verifier index vector stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_vector_create<'a>(
 ) -> *const Vec<*const DlogVerifierIndex<'a, GAffine>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fq_verifier_index_vector_create", std::ptr::null(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_verifier_index_vector_length(
     v: *const Vec<*const DlogVerifierIndex<GAffine>>,
 ) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fq_verifier_index_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -635,8 +1625,11 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_vector_emplace_back<'a>(
     v: *mut Vec<*const DlogVerifierIndex<'a, GAffine>>,
     x: *const DlogVerifierIndex<'a, GAffine>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    v_.push(x);
+    catch_ffi("zexe_bn382_fq_verifier_index_vector_emplace_back", (), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        v_.push(x);
+    })
 }
 
 #[no_mangle]
@@ -644,394 +1637,772 @@ pub extern "C" fn zexe_bn382_fq_verifier_index_vector_get<'a>(
     v: *mut Vec<*const DlogVerifierIndex<'a, GAffine>>,
     i: u32,
 ) -> *const DlogVerifierIndex<GAffine> {
-    let v_ = unsafe { &mut (*v) };
-    return v_[i as usize];
+    catch_ffi("zexe_bn382_fq_verifier_index_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return v_[i as usize];
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_verifier_index_vector_delete(
-    v: *mut Vec<*const DlogVerifierIndex<GAffine>>,
-) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_bn382_fq_verifier_index_vector_delete, Vec<*const DlogVerifierIndex<GAffine>>);
 
 // Fq stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_endo_base() -> *const Fp {
-    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffine>();
-    return Box::into_raw(Box::new(endo_q));
+    catch_ffi("zexe_bn382_fq_endo_base", std::ptr::null(), || {
+        let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffine>();
+        return into_handle(Box::new(endo_q));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_endo_scalar() -> *const Fq {
-    let (_endo_q, endo_r) = commitment_dlog::srs::endos::<GAffine>();
-    return Box::into_raw(Box::new(endo_r));
+    catch_ffi("zexe_bn382_fq_endo_scalar", std::ptr::null(), || {
+        let (_endo_q, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+        return into_handle(Box::new(endo_r));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_size_in_bits() -> i32 {
-    return Fq_params::MODULUS_BITS as i32;
+    catch_ffi("zexe_bn382_fq_size_in_bits", 0, || {
+        return Fq_params::MODULUS_BITS as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_size() -> *mut BigInteger384 {
-    let ret = Fq_params::MODULUS;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_size", std::ptr::null_mut(), || {
+        let ret = Fq_params::MODULUS;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_characteristic() -> *mut BigInteger384 {
+    catch_ffi("zexe_bn382_fq_characteristic", std::ptr::null_mut(), || {
+        let mut ret = BigInteger384::default();
+        ret.0.copy_from_slice(Fq::characteristic());
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_size_in_bytes() -> i32 {
+    catch_ffi("zexe_bn382_fq_size_in_bytes", 0, || {
+        return (BigInteger384::NUM_LIMBS * 8) as i32;
+    })
+}
+
+// Writes the little-endian bytes of the modulus to out, which must have room
+// for zexe_bn382_fq_size_in_bytes() bytes.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_modulus_bytes(out: *mut u8) {
+    catch_ffi("zexe_bn382_fq_modulus_bytes", (), || {
+        let out = unsafe { std::slice::from_raw_parts_mut(out, BigInteger384::NUM_LIMBS * 8) };
+        Fq_params::MODULUS.write(out).unwrap();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_zero() -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_zero", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fq::zero()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_one() -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_one", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fq::one()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_multiplicative_generator() -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_multiplicative_generator", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fq::multiplicative_generator()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_is_square(x: *const Fq) -> bool {
-    let x_ = unsafe { &(*x) };
-    let s0 = x_.pow(Fq_params::MODULUS_MINUS_ONE_DIV_TWO);
-    s0.is_zero() || s0.is_one()
+    catch_ffi("zexe_bn382_fq_is_square", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let s0 = x_.pow(Fq_params::MODULUS_MINUS_ONE_DIV_TWO);
+        s0.is_zero() || s0.is_one()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_sqrt(x: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.sqrt() {
-        Some(x) => x,
-        None => Fq::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.sqrt() {
+            Some(x) => x,
+            None => Fq::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_det_sqrt(x: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.det_sqrt() {
-        Some(x) => x,
-        None => Fp::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_det_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.det_sqrt() {
+            Some(x) => x,
+            None => Fp::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_det_sqrt_witness(x: *const Fp) -> DetSqrtWitness<Fp> {
-    let x_ = unsafe { &(*x) };
-    match x_.det_sqrt() {
-        Some(y) => {
-            let (c, d) = decompose(&y);
-            DetSqrtWitness {
-                c:Box::into_raw(Box::new(c)),
-                d,
-                square_root: Box::into_raw(Box::new(y)),
-                success: true
-            }
-        },
-        None =>
-            DetSqrtWitness {
-                c:Box::into_raw(Box::new(Fp::zero())),
-                d:0,
-                square_root: Box::into_raw(Box::new(Fp::zero())),
-                success: false
-            }
-    }
+    catch_ffi("zexe_bn382_fp_det_sqrt_witness", Default::default(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        match x_.det_sqrt() {
+            Some(y) => {
+                let (c, d) = decompose(&y);
+                DetSqrtWitness {
+                    c:into_handle(Box::new(c)),
+                    d,
+                    square_root: into_handle(Box::new(y)),
+                    success: true
+                }
+            },
+            None =>
+                DetSqrtWitness {
+                    c:into_handle(Box::new(Fp::zero())),
+                    d:0,
+                    square_root: into_handle(Box::new(Fp::zero())),
+                    success: false
+                }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_two_adic_root_of_unity() -> *mut Fq {
-    Box::into_raw(Box::new(FftField::two_adic_root_of_unity()))
+    catch_ffi("zexe_bn382_fq_two_adic_root_of_unity", std::ptr::null_mut(), || {
+        into_handle(Box::new(FftField::two_adic_root_of_unity()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_random() -> *mut Fq {
-    let ret: Fq = UniformRand::rand(&mut rand::thread_rng());
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_random", std::ptr::null_mut(), || {
+        let ret: Fq = UniformRand::rand(&mut system_rng());
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_of_int(i: u64) -> *mut Fq {
-    let ret = Fq::from(i);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_of_int", std::ptr::null_mut(), || {
+        let ret = Fq::from(i);
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_domain_generator(log2_size: usize) -> *mut Fq {
-    let ret = Domain::new(1 << log2_size).unwrap().group_gen;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_domain_generator", std::ptr::null_mut(), || {
+        let ret = Domain::new(1 << log2_size).unwrap().group_gen;
+        return into_handle(Box::new(ret));
+    })
 }
 
-// TODO: Leaky
+// The string must be freed with zexe_string_delete.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_to_string(x: *const Fq) -> *const u8 {
-    let x = unsafe { *x };
-    let s: String = format!("{}", x);
-    s.as_ptr()
+pub extern "C" fn zexe_bn382_fq_to_string(x: *const Fq) -> *mut c_char {
+    catch_ffi("zexe_bn382_fq_to_string", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x = unsafe { *x };
+        into_c_string(format!("{}", x))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_of_decimal_string(s: *const c_char) -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_of_decimal_string", std::ptr::null_mut(), || {
+        let s = unsafe { CStr::from_ptr(s) }.to_bytes();
+        match field_of_digits::<Fq>(s, 10) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_of_decimal_string: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_of_hex_string(s: *const c_char) -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_of_hex_string", std::ptr::null_mut(), || {
+        let s = unsafe { CStr::from_ptr(s) }.to_bytes();
+        match field_of_digits::<Fq>(s, 16) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_of_hex_string: {}", e)),
+        }
+    })
+}
+
+// Writes the canonical little-endian bytes of x to out, which must have room
+// for zexe_bn382_fq_size_in_bytes() bytes.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_to_bytes(x: *const Fq, out: *mut u8) {
+    catch_ffi("zexe_bn382_fq_to_bytes", (), || {
+        let x = handle(x);
+        let bytes = (unsafe { &*x }).to_bytes();
+        let out = unsafe { std::slice::from_raw_parts_mut(out, bytes.len()) };
+        out.copy_from_slice(&bytes);
+    })
+}
+
+// Reads the canonical little-endian bytes of an element, rejecting a wrong
+// length and encodings of integers which are not less than the modulus,
+// rather than reducing them.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_of_bytes(bytes: *const u8, len: usize) -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_of_bytes", std::ptr::null_mut(), || {
+        let bytes = if len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(bytes, len) }
+        };
+        match Fq::from_bytes_checked(bytes) {
+            Some(x) => into_handle(Box::new(x)),
+            None => null_with_error(format!(
+                "zexe_bn382_fq_of_bytes: {} bytes are not a canonical element",
+                len
+            )),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_inv(x: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.inverse() {
-        Some(x) => x,
-        None => Fq::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_inv", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.inverse() {
+            Some(x) => x,
+            None => Fq::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_square(x: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let ret = x_.square();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_square", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.square();
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_add(x: *const Fq, y: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ + y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_add", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_negate(x: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let ret = -*x_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_negate", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_mul(x: *const Fq, y: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ * y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_mul", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ * y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_div(x: *const Fq, y: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ / y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ / y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_sub(x: *const Fq, y: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ - y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_sub", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_mut_add(x: *mut Fq, y: *const Fq) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ += y_;
+    catch_ffi("zexe_bn382_fq_mut_add", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ += y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_mut_mul(x: *mut Fq, y: *const Fq) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ *= y_;
+    catch_ffi("zexe_bn382_fq_mut_mul", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ *= y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_mut_square(x: *mut Fq) {
-    let x_ = unsafe { &mut (*x) };
-    x_.square_in_place();
+    catch_ffi("zexe_bn382_fq_mut_square", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        x_.square_in_place();
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_mut_sub(x: *mut Fq, y: *const Fq) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ -= y_;
+    catch_ffi("zexe_bn382_fq_mut_sub", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ -= y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_copy(x: *mut Fq, y: *const Fq) {
-    unsafe { (*x) = *y };
+    catch_ffi("zexe_bn382_fq_copy", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        unsafe { (*x) = *y };
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_rng(i: i32) -> *mut Fq {
-    // We only care about entropy here, so we force a conversion i32 -> u32.
-    let i: u64 = (i as u32).into();
-    let mut rng: StdRng = rand::SeedableRng::seed_from_u64(i);
-    let ret: Fq = UniformRand::rand(&mut rng);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_rng", std::ptr::null_mut(), || {
+        // We only care about entropy here, so we force a conversion i32 -> u32.
+        let i: u64 = (i as u32).into();
+        let mut rng = seeded_rng(i);
+        let ret: Fq = UniformRand::rand(&mut rng);
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_delete(x: *mut Fq) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_delete, Fq);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_print(x: *const Fq) {
-    let x_ = unsafe { &(*x) };
-    println!("{}", x_);
+    catch_ffi("zexe_bn382_fq_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", x_));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_equal(x: *const Fq, y: *const Fq) -> bool {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    return *x_ == *y_;
+    catch_ffi("zexe_bn382_fq_equal", false, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return *x_ == *y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_is_zero(x: *const Fq) -> bool {
+    catch_ffi("zexe_bn382_fq_is_zero", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_zero();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_is_one(x: *const Fq) -> bool {
+    catch_ffi("zexe_bn382_fq_is_one", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_one();
+    })
+}
+
+// Writes the canonical value of x to out and returns true if it fits in a
+// u64; otherwise returns false and leaves out untouched.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_to_u64_checked(x: *const Fq, out: *mut u64) -> bool {
+    catch_ffi("zexe_bn382_fq_to_u64_checked", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let repr = x_.into_repr();
+        if repr.0[1..].iter().any(|l| *l != 0) {
+            return false;
+        }
+        unsafe { *out = repr.0[0] };
+        true
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_to_bigint(x: *const Fq) -> *mut BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.into_repr()));
+    catch_ffi("zexe_bn382_fq_to_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.into_repr()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_of_bigint(x: *const BigInteger384) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(Fq::from_repr(*x_)));
+    catch_ffi("zexe_bn382_fq_of_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fq::from_repr(*x_)));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_to_bigint_raw(x: *const Fq) -> *mut BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.0));
+    catch_ffi("zexe_bn382_fq_to_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.0));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_to_bigint_raw_noalloc(x: *const Fq) -> *const BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    &x_.0 as *const BigInteger384
+    catch_ffi("zexe_bn382_fq_to_bigint_raw_noalloc", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        &x_.0 as *const BigInteger384
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_of_bigint_raw(x: *const BigInteger384) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(Fq::new(*x_)));
+    catch_ffi("zexe_bn382_fq_of_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fq::new(*x_)));
+    })
+}
+
+// Splits x into little-endian chunks of chunk_bits bits, each as an element of
+// Fp. Returns null, with the last error set, if chunk_bits is 0 or does not
+// fit in Fp.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_to_fp_chunks(x: *const Fq, chunk_bits: usize) -> *mut Vec<Fp> {
+    catch_ffi("zexe_bn382_fq_to_fp_chunks", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fq_to_fp_chunks";
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        match field_to_chunks::<Fq, Fp>(x_, chunk_bits) {
+            Ok(v) => into_handle(Box::new(v)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+// Returns null, with the last error set, if chunk_bits is invalid, a chunk is
+// out of range or the result is not a canonical Fq element.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_of_fp_chunks(v: *const Vec<Fp>, chunk_bits: usize) -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_of_fp_chunks", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fq_of_fp_chunks";
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        match field_of_chunks::<Fq, Fp>(v_, chunk_bits) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
 // Fq vector stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_create() -> *mut Vec<Fq> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fq_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_length(v: *const Vec<Fq>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fq_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_emplace_back(v: *mut Vec<Fq>, x: *const Fq) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
+    catch_ffi("zexe_bn382_fq_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_get(v: *mut Vec<Fq>, i: u32) -> *mut Fq {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
+    catch_ffi("zexe_bn382_fq_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_vector_delete, Vec<Fq>);
+
+// A new vector of the inverses of the elements, computed with a single
+// inversion. Zeros stay zero, as with zexe_bn382_fq_inv.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_vector_batch_inverse(v: *const Vec<Fq>) -> *mut Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_vector_batch_inverse", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let mut res = (unsafe { &*v }).clone();
+        Fq::batch_inversion(&mut res);
+        into_handle(Box::new(res))
+    })
 }
 
+// Builds a vector of len elements from len * NUM_LIMBS canonical limbs,
+// rejecting non-canonical elements.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_vector_delete(v: *mut Vec<Fq>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_bn382_fq_vector_of_raw(limbs: *const u64, len: usize) -> *mut Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_vector_of_raw", std::ptr::null_mut(), || {
+        let n = <<Fq as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        let limbs = if len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(limbs, len * n) }
+        };
+        match field_vector_of_limbs(limbs) {
+            Ok(v) => into_handle(Box::new(v)),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_vector_of_raw: {}", e)),
+        }
+    })
+}
+
+// Writes the canonical limbs of the elements to a caller buffer of
+// length * NUM_LIMBS limbs.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_vector_to_raw(v: *const Vec<Fq>, limbs: *mut u64) {
+    catch_ffi("zexe_bn382_fq_vector_to_raw", (), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        let n = <<Fq as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        if v.is_empty() {
+            return;
+        }
+        let limbs = unsafe { std::slice::from_raw_parts_mut(limbs, v.len() * n) };
+        field_vector_to_limbs(v, limbs);
+    })
+}
+
+// Samples n elements from the ChaCha20 stream keyed by the 32 bytes at seed.
+// The stream is stable, so a seed gives the same elements in every release.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_vector_random(n: usize, seed: *const u8) -> *mut Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_vector_random", std::ptr::null_mut(), || {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(unsafe { std::slice::from_raw_parts(seed, 32) });
+        let mut rng = ChaCha20Rng::from_seed(s);
+        let ret: Vec<Fq> = (0..n).map(|_| UniformRand::rand(&mut rng)).collect();
+        return into_handle(Box::new(ret));
+    })
 }
 
 // Fq constraint-matrix stubs
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_constraint_matrix_create() -> *mut Vec<(Vec<usize>, Vec<Fq>)> {
-    return Box::into_raw(Box::new(vec![]));
+pub extern "C" fn zexe_bn382_fq_constraint_matrix_create() -> *mut Vec<Row<Fq>> {
+    catch_ffi("zexe_bn382_fq_constraint_matrix_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(vec![]));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_constraint_matrix_append_row(
-    m: *mut Vec<(Vec<usize>, Vec<Fq>)>,
+    m: *mut Vec<Row<Fq>>,
     indices: *mut Vec<usize>,
     coefficients: *mut Vec<Fq>,
 ) {
-    let m_ = unsafe { &mut (*m) };
-    let indices_ = unsafe { &mut (*indices) };
-    let coefficients_ = unsafe { &mut (*coefficients) };
-    m_.push((indices_.clone(), coefficients_.clone()));
+    catch_ffi("zexe_bn382_fq_constraint_matrix_append_row", (), || {
+        let m = handle(m);
+        let indices = handle(indices);
+        let coefficients = handle(coefficients);
+        let m_ = unsafe { &mut (*m) };
+        let indices_ = unsafe { &mut (*indices) };
+        let coefficients_ = unsafe { &mut (*coefficients) };
+        m_.push((indices_.clone(), coefficients_.clone()));
+    })
+}
+
+// Appends rows given as CSR arrays: rows + 1 row pointers into nnz column
+// indices and nnz values, the latter as nnz * NUM_LIMBS canonical limbs. This
+// avoids creating two vectors per row. On failure the last error is set and
+// nothing is appended.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_constraint_matrix_append_csr(
+    m: *mut Vec<Row<Fq>>,
+    row_ptrs: *const usize,
+    rows: usize,
+    cols: *const usize,
+    values: *const u64,
+    nnz: usize,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_constraint_matrix_append_csr", false, || {
+        let m = handle(m);
+        let m = unsafe { &mut *m };
+        let n = <<Fq as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        let row_ptrs = unsafe { std::slice::from_raw_parts(row_ptrs, rows + 1) };
+        let (cols, values) = if nnz == 0 {
+            (&[][..], &[][..])
+        } else {
+            unsafe {
+                (
+                    std::slice::from_raw_parts(cols, nnz),
+                    std::slice::from_raw_parts(values, nnz * n),
+                )
+            }
+        };
+        match rows_of_csr(row_ptrs, cols, values) {
+            Ok(rows) => {
+                m.extend(rows);
+                true
+            }
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fq_constraint_matrix_append_csr: {}", e));
+                false
+            }
+        }
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_constraint_matrix_delete(x: *mut Vec<(Vec<usize>, Vec<Fq>)>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_constraint_matrix_delete, Vec<Row<Fq>>);
 
 // Fq triple
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_triple_0(evals: *const [Fq; 3]) -> *const Fq {
-    let x = (unsafe { *evals })[0].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_triple_0", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[0].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_triple_1(evals: *const [Fq; 3]) -> *const Fq {
-    let x = (unsafe { *evals })[1].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_triple_1", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[1].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_triple_2(evals: *const [Fq; 3]) -> *const Fq {
-    let x = (unsafe { *evals })[2].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_triple_2", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[2].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_triple_delete(x: *mut [Fq; 3]) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_triple_delete, [Fq; 3]);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_triple_0(evals: *const [Vec<Fq>; 3]) -> *const Vec<Fq> {
-    let x = (unsafe { &(*evals) })[0].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_vector_triple_0", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[0].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_triple_1(evals: *const [Vec<Fq>; 3]) -> *const Vec<Fq> {
-    let x = (unsafe { &(*evals) })[1].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_vector_triple_1", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[1].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_vector_triple_2(evals: *const [Vec<Fq>; 3]) -> *const Vec<Fq> {
-    let x = (unsafe { &(*evals) })[2].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_vector_triple_2", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[2].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_vector_triple_delete(x: *mut [Fq; 3]) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_vector_triple_delete, [Vec<Fq>; 3]);
 
 // G / Fp stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_random() -> *const GProjective {
-    let rng = &mut rand_core::OsRng;
-    Box::into_raw(Box::new(GProjective::rand(rng)))
+    catch_ffi("zexe_bn382_g_random", std::ptr::null(), || {
+        let rng = &mut system_rng();
+        into_handle(Box::new(GProjective::rand(rng)))
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g_delete(x: *mut GProjective) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_g_delete, GProjective);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_one() -> *const GProjective {
-    let ret = GProjective::prime_subgroup_generator();
-    Box::into_raw(Box::new(ret))
+    catch_ffi("zexe_bn382_g_one", std::ptr::null(), || {
+        let ret = GProjective::prime_subgroup_generator();
+        into_handle(Box::new(ret))
+    })
 }
 
 #[no_mangle]
@@ -1039,25 +2410,51 @@ pub extern "C" fn zexe_bn382_g_add(
     x: *const GProjective,
     y: *const GProjective,
 ) -> *const GProjective {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ + y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g_add", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_double(x: *const GProjective) -> *const GProjective {
-    let x_ = unsafe { &(*x) };
-    let ret = x_.double();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g_double", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.double();
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_scale(x: *const GProjective, s: *const Fq) -> *const GProjective {
-    let x_ = unsafe { &(*x) };
-    let s_ = unsafe { &(*s) };
-    let ret = (*x_).mul(*s_);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g_scale", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = (*x_).mul(*s_);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_scale_many(
+    x: *const GProjective,
+    s: *const Vec<Fq>,
+) -> *const Vec<GProjective> {
+    catch_ffi("zexe_bn382_g_scale_many", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = scale_many(*x_, s_);
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
@@ -1065,31 +2462,44 @@ pub extern "C" fn zexe_bn382_g_sub(
     x: *const GProjective,
     y: *const GProjective,
 ) -> *const GProjective {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ - y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g_sub", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_negate(x: *const GProjective) -> *const GProjective {
-    let x_ = unsafe { &(*x) };
-    let ret = -*x_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g_negate", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_to_affine(p: *const GProjective) -> *const GAffine {
-    let p = unsafe { *p };
-    let q = p.clone().into_affine();
-    return Box::into_raw(Box::new(q));
+    catch_ffi("zexe_bn382_g_to_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_affine();
+        return into_handle(Box::new(q));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_of_affine(p: *const GAffine) -> *const GProjective {
-    let p = unsafe { *p };
-    let q = p.clone().into_projective();
-    return Box::into_raw(Box::new(q));
+    catch_ffi("zexe_bn382_g_of_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_projective();
+        return into_handle(Box::new(q));
+    })
 }
 
 #[no_mangle]
@@ -1097,335 +2507,2145 @@ pub extern "C" fn zexe_bn382_g_of_affine_coordinates(
     x: *const Fp,
     y: *const Fp,
 ) -> *const GProjective {
-    let x = (unsafe { *x }).clone();
-    let y = (unsafe { *y }).clone();
-    return Box::into_raw(Box::new(GProjective::new(x, y, Fp::one())));
+    catch_ffi("zexe_bn382_g_of_affine_coordinates", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        return into_handle(Box::new(GProjective::new(x, y, Fp::one())));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_affine_create(x: *const Fp, y: *const Fp) -> *const GAffine {
-    let x = (unsafe { *x }).clone();
-    let y = (unsafe { *y }).clone();
-    Box::into_raw(Box::new(GAffine::new(x, y, false)))
+    catch_ffi("zexe_bn382_g_affine_create", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        into_handle(Box::new(GAffine::new(x, y, false)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_affine_create_infinity() -> *const GAffine {
+    catch_ffi("zexe_bn382_g_affine_create_infinity", std::ptr::null(), || {
+        into_handle(Box::new(GAffine::zero()))
+    })
+}
+
+// As zexe_bn382_g_affine_create, but returns null, with the last error set,
+// unless the point is on the curve and in the prime order subgroup.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_affine_create_checked(x: *const Fp, y: *const Fp) -> *const GAffine {
+    catch_ffi("zexe_bn382_g_affine_create_checked", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let p = GAffine::new(unsafe { *x }, unsafe { *y }, false);
+        if !p.is_on_curve() {
+            return null_with_error(
+                "zexe_bn382_g_affine_create_checked: The point is not on the curve".to_string(),
+            );
+        }
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return null_with_error(
+                "zexe_bn382_g_affine_create_checked: The point is not in the subgroup".to_string(),
+            );
+        }
+        into_handle(Box::new(p))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_affine_is_on_curve(p: *const GAffine) -> bool {
+    catch_ffi("zexe_bn382_g_affine_is_on_curve", false, || {
+        let p = handle(p);
+        (unsafe { &*p }).is_on_curve()
+    })
+}
+
+// Whether the point is on the curve and in the prime order subgroup.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_affine_is_in_correct_subgroup(p: *const GAffine) -> bool {
+    catch_ffi("zexe_bn382_g_affine_is_in_correct_subgroup", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_affine_is_infinity(p: *const GAffine) -> bool {
+    catch_ffi("zexe_bn382_g_affine_is_infinity", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.infinity;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_affine_x(p: *const GAffine) -> *const Fp {
-    let p = unsafe { *p };
-    return Box::into_raw(Box::new(p.x.clone()));
+    catch_ffi("zexe_bn382_g_affine_x", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        // The point at infinity has no coordinates.
+        if p.infinity {
+            return std::ptr::null();
+        }
+        return into_handle(Box::new(p.x.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_affine_y(p: *const GAffine) -> *const Fp {
-    let p = unsafe { *p };
-    return Box::into_raw(Box::new(p.y.clone()));
+    catch_ffi("zexe_bn382_g_affine_y", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        // The point at infinity has no coordinates.
+        if p.infinity {
+            return std::ptr::null();
+        }
+        return into_handle(Box::new(p.y.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g_affine_is_zero(p: *const GAffine) -> bool {
-    let p = unsafe { &*p };
-    return p.is_zero();
+    catch_ffi("zexe_bn382_g_affine_is_zero", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.is_zero();
+    })
 }
 
+crate::delete_stub!(zexe_bn382_g_affine_delete, GAffine);
+
+// G affine pair
+crate::affine_pair_stubs!(
+    GAffine,
+    pair_0: zexe_bn382_g_affine_pair_0,
+    pair_1: zexe_bn382_g_affine_pair_1,
+    pair_make: zexe_bn382_g_affine_pair_make,
+    pair_delete: zexe_bn382_g_affine_pair_delete,
+    vector_create: zexe_bn382_g_affine_pair_vector_create,
+    vector_length: zexe_bn382_g_affine_pair_vector_length,
+    vector_emplace_back: zexe_bn382_g_affine_pair_vector_emplace_back,
+    vector_get: zexe_bn382_g_affine_pair_vector_get,
+    vector_delete: zexe_bn382_g_affine_pair_vector_delete,
+    vector_cursor_create: zexe_bn382_g_affine_pair_vector_cursor_create,
+    vector_cursor_next: zexe_bn382_g_affine_pair_vector_cursor_next,
+    vector_cursor_delete: zexe_bn382_g_affine_pair_vector_cursor_delete,
+);
+
+// G projective vector stubs
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_delete(x: *mut GAffine) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_g_vector_create() -> *mut Vec<GProjective> {
+    catch_ffi("zexe_bn382_g_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
-// G affine pair
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_0(p: *const (GAffine, GAffine)) -> *const GAffine {
-    let (x0, _) = unsafe { *p };
-    return Box::into_raw(Box::new(x0.clone()));
+pub extern "C" fn zexe_bn382_g_vector_length(v: *const Vec<GProjective>) -> i32 {
+    catch_ffi("zexe_bn382_g_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_1(p: *const (GAffine, GAffine)) -> *const GAffine {
-    let (_, x1) = unsafe { *p };
-    return Box::into_raw(Box::new(x1.clone()));
+pub extern "C" fn zexe_bn382_g_vector_emplace_back(v: *mut Vec<GProjective>, x: *const GProjective) {
+    catch_ffi("zexe_bn382_g_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_make(
-    x0: *const GAffine,
-    x1: *const GAffine,
-) -> *const (GAffine, GAffine) {
-    let res = ((unsafe { *x0 }), (unsafe { *x1 }));
-    return Box::into_raw(Box::new(res));
+pub extern "C" fn zexe_bn382_g_vector_get(v: *mut Vec<GProjective>, i: u32) -> *mut GProjective {
+    catch_ffi("zexe_bn382_g_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
 }
 
+crate::delete_stub!(zexe_bn382_g_vector_delete, Vec<GProjective>);
+
+// G vector stubs
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_delete(x: *mut (GAffine, GAffine)) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_g_affine_vector_create() -> *mut Vec<GAffine> {
+    catch_ffi("zexe_bn382_g_affine_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_vector_create() -> *mut Vec<(GAffine, GAffine)> {
-    return Box::into_raw(Box::new(Vec::new()));
+pub extern "C" fn zexe_bn382_g_affine_vector_length(v: *const Vec<GAffine>) -> i32 {
+    catch_ffi("zexe_bn382_g_affine_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_vector_length(v: *const Vec<(GAffine, GAffine)>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+pub extern "C" fn zexe_bn382_g_affine_vector_emplace_back(v: *mut Vec<GAffine>, x: *const GAffine) {
+    catch_ffi("zexe_bn382_g_affine_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_vector_emplace_back(
-    v: *mut Vec<(GAffine, GAffine)>,
-    x: *const (GAffine, GAffine),
-) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
+pub extern "C" fn zexe_bn382_g_affine_vector_get(v: *mut Vec<GAffine>, i: u32) -> *mut GAffine {
+    catch_ffi("zexe_bn382_g_affine_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
 }
 
+crate::delete_stub!(zexe_bn382_g_affine_vector_delete, Vec<GAffine>);
+
+// The sum of the points.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_vector_get(
-    v: *mut Vec<(GAffine, GAffine)>,
-    i: u32,
-) -> *mut (GAffine, GAffine) {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
+pub extern "C" fn zexe_bn382_g_affine_vector_sum(v: *const Vec<GAffine>) -> *const GAffine {
+    catch_ffi("zexe_bn382_g_affine_vector_sum", std::ptr::null(), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        into_handle(Box::new(in_pool(|| affine_sum(v)).into_affine()))
+    })
 }
 
+// The sum of the points multiplied by the scalars, which must be as many.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_pair_vector_delete(v: *mut Vec<(GAffine, GAffine)>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_bn382_g_affine_vector_linear_combination(
+    v: *const Vec<GAffine>,
+    s: *const Vec<Fq>,
+) -> *const GAffine {
+    const NAME: &str = "zexe_bn382_g_affine_vector_linear_combination";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let v = handle(v);
+        let s = handle(s);
+        let v = unsafe { &*v };
+        let s = unsafe { &*s };
+        match in_pool(|| affine_linear_combination(v, s)) {
+            Ok(res) => into_handle(Box::new(res.into_affine())),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
-// G vector stubs
+// Fq public input commitment cache
+//
+// The entries are shared by all threads, since the verifications of one
+// public input rarely run on the same thread. The lock is only held to look
+// up and insert entries, not while committing.
+
+static FQ_X_HAT_CACHE_CAPACITY: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static! {
+    static ref FQ_X_HAT_CACHE: Mutex<XHatCache<DensePolynomial<Fq>, PolyComm<GAffine>>> =
+        Mutex::new(XHatCache::new());
+}
+
+// Sets the maximum number of public inputs whose commitments are remembered.
+// The cache is disabled by default; a capacity of 0 disables it and drops
+// its entries.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_vector_create() -> *mut Vec<GAffine> {
-    return Box::into_raw(Box::new(Vec::new()));
+pub extern "C" fn zexe_bn382_fq_x_hat_cache_set_capacity(capacity: usize) {
+    catch_ffi("zexe_bn382_fq_x_hat_cache_set_capacity", (), || {
+        FQ_X_HAT_CACHE_CAPACITY.store(capacity, Ordering::SeqCst);
+        if capacity == 0 {
+            FQ_X_HAT_CACHE.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        }
+    })
+}
+
+// x_hat for the public input of a proof and its commitment, from the cache
+// if it is enabled. With the cache disabled the public input is not hashed.
+fn fq_x_hat(
+    index: &DlogVerifierIndex<GAffine>,
+    public: &[Fq],
+) -> (DensePolynomial<Fq>, PolyComm<GAffine>) {
+    let srs = index.srs.get_ref();
+    let compute = || {
+        let x_hat = evals_from_coeffs(public.to_vec(), index.domains.x).interpolate();
+        // TODO: Should have no degree bound when we add the correct degree bound method
+        let x_hat_comm = srs.commit(&x_hat, None);
+        (x_hat, x_hat_comm)
+    };
+    let capacity = FQ_X_HAT_CACHE_CAPACITY.load(Ordering::SeqCst);
+    if capacity == 0 {
+        return compute();
+    }
+    let key = x_hat_cache_key(&srs.h, index.domains.x.size, public);
+    if let Some(v) = FQ_X_HAT_CACHE.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return v;
+    }
+    let v = compute();
+    FQ_X_HAT_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(capacity, key, v.clone());
+    v
+}
+
+// Drops the public input commitments, and the calling thread's Lagrange
+// commitments, and disables the cache.
+pub(crate) fn clear_fq_caches() {
+    zexe_bn382_fq_x_hat_cache_set_capacity(0);
+    FQ_LAGRANGE_COMMITMENTS.with(|c| c.borrow_mut().clear());
+}
+
+// Fq public input
+//
+// Lays out a public input for an index, so that callers do not have to: the
+// caller pushes the primary input, without the constant one, and the builder
+// checks its length against the index and provides it in each of the forms
+// the stubs take. Finishing it against a verifier index also computes x_hat
+// and its commitment, the latter from the Lagrange commitments of the x domain,
+// which are computed once per SRS and domain size on each thread.
+//
+// A public input created against a verifier index instead commits to each
+// primary input as it is pushed, so that the commitment of an input which
+// streams in, such as the transactions of a block, is ready as soon as the
+// last one arrives, before the witness is. Finishing it then only
+// interpolates x_hat.
+pub struct FqPublicInput {
+    primary: Vec<Fq>,
+    x_hat: Option<(DensePolynomial<Fq>, PolyComm<GAffine>)>,
+    streaming: Option<FqStreamingCommitment>,
+}
+
+// The Lagrange commitments of the x domain of an index, keyed as in
+// fq_lagrange_commitments, and the commitment to the public input so far.
+struct FqStreamingCommitment {
+    key: XHatCacheKey,
+    lagrange: Vec<GAffine>,
+    comm: GProjective,
+}
+
+impl FqPublicInput {
+    // The public input of a proof: the constant one, then the primary input.
+    fn public(&self) -> Vec<Fq> {
+        let mut res = vec![Fq::one()];
+        res.extend_from_slice(&self.primary);
+        res
+    }
+
+    fn check_length(&self, public_inputs: usize) -> Result<(), String> {
+        if self.primary.len() + 1 != public_inputs {
+            Err(format!(
+                "The index expects {} primary inputs, got {}.",
+                public_inputs - 1,
+                self.primary.len()
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+thread_local! {
+    static FQ_LAGRANGE_COMMITMENTS: RefCell<HashMap<XHatCacheKey, Vec<GAffine>>> =
+        RefCell::new(HashMap::new());
+}
+
+pub(crate) fn fq_lagrange_commitments(srs: &SRS<GAffine>, domain: Domain<Fq>) -> Vec<GAffine> {
+    let key = x_hat_cache_key(&srs.h, domain.size, &[]);
+    FQ_LAGRANGE_COMMITMENTS.with(|c| {
+        c.borrow_mut()
+            .entry(key)
+            .or_insert_with(|| lagrange_commitments(&srs.g, domain))
+            .clone()
+    })
+}
+
+// The commitment to x_hat, as a linear combination of the Lagrange
+// commitments of the x domain.
+fn fq_x_hat_comm(lagrange: &[GAffine], public: &[Fq]) -> PolyComm<GAffine> {
+    let scalars: Vec<_> = public.iter().map(|x| x.into_repr()).collect();
+    PolyComm {
+        unshifted: vec![
+            glv_multi_scalar_mul(&lagrange[..public.len()], &scalars).into_affine(),
+        ],
+        shifted: None,
+    }
+}
+
+// Whether comm is the commitment to x_hat of the public input, with the
+// Lagrange commitments of the x domain.
+#[cfg(debug_assertions)]
+fn fq_x_hat_comm_matches(lagrange: &[GAffine], public: &[Fq], comm: &PolyComm<GAffine>) -> bool {
+    public.len() <= lagrange.len()
+        && comm.shifted.is_none()
+        && comm.unshifted == fq_x_hat_comm(lagrange, public).unshifted
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_vector_length(v: *const Vec<GAffine>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+pub extern "C" fn zexe_bn382_fq_public_input_create() -> *mut FqPublicInput {
+    catch_ffi("zexe_bn382_fq_public_input_create", std::ptr::null_mut(), || {
+        into_handle(Box::new(FqPublicInput {
+            primary: vec![],
+            x_hat: None,
+            streaming: None,
+        }))
+    })
 }
 
+// As zexe_bn382_fq_public_input_create, but commits to each primary input as
+// it is pushed, with the Lagrange commitments of the x domain of the index.
+// Finishing against a verifier index with another SRS or x domain falls back
+// to committing to the whole input.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_vector_emplace_back(v: *mut Vec<GAffine>, x: *const GAffine) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
+pub extern "C" fn zexe_bn382_fq_public_input_create_streaming(
+    index: *const DlogVerifierIndex<GAffine>,
+) -> *mut FqPublicInput {
+    catch_ffi("zexe_bn382_fq_public_input_create_streaming", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fq_public_input_create_streaming";
+        let index = handle(index);
+        if !check_handle(NAME, index) {
+            return std::ptr::null_mut();
+        }
+        catch_ffi(NAME, std::ptr::null_mut(), || {
+            let index = unsafe { &*index };
+            let srs = index.srs.get_ref();
+            let lagrange = fq_lagrange_commitments(srs, index.domains.x);
+            // The constant one.
+            let comm = lagrange[0].into_projective();
+            into_handle(Box::new(FqPublicInput {
+                primary: vec![],
+                x_hat: None,
+                streaming: Some(FqStreamingCommitment {
+                    key: x_hat_cache_key(&srs.h, index.domains.x.size, &[]),
+                    lagrange,
+                    comm,
+                }),
+            }))
+        })
+    })
+}
+
+// Appends a primary input. A public input which was finished must be finished
+// again.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_public_input_push(b: *mut FqPublicInput, x: *const Fq) {
+    catch_ffi("zexe_bn382_fq_public_input_push", (), || {
+        let b = handle(b);
+        let x = handle(x);
+        let b = unsafe { &mut *b };
+        let x = unsafe { *x };
+        b.primary.push(x);
+        b.x_hat = None;
+        // Past the x domain, finish rejects the input anyway.
+        let i = b.primary.len();
+        if let Some(s) = &mut b.streaming {
+            if i < s.lagrange.len() {
+                s.comm += &s.lagrange[i].mul(x);
+            } else {
+                b.streaming = None;
+            }
+        }
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_vector_get(v: *mut Vec<GAffine>, i: u32) -> *mut GAffine {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
+pub extern "C" fn zexe_bn382_fq_public_input_length(b: *const FqPublicInput) -> usize {
+    catch_ffi("zexe_bn382_fq_public_input_length", 0, || {
+        let b = handle(b);
+        (unsafe { &*b }).primary.len()
+    })
+}
+
+// Returns false, with the last error set, if the index expects a different
+// number of primary inputs.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_public_input_finish(
+    b: *mut FqPublicInput,
+    index: *const DlogVerifierIndex<GAffine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_public_input_finish", false, || {
+        let b = handle(b);
+        let index = handle(index);
+        let b = unsafe { &mut *b };
+        let index = unsafe { &*index };
+        if let Err(e) = b.check_length(index.public_inputs) {
+            set_last_error(format!("zexe_bn382_fq_public_input_finish: {}", e));
+            return false;
+        }
+        let srs = index.srs.get_ref();
+        let public = b.public();
+        let x_hat = evals_from_coeffs(public.clone(), index.domains.x).interpolate();
+
+        let key = x_hat_cache_key(&srs.h, index.domains.x.size, &[]);
+        let comm = match &b.streaming {
+            Some(s) if s.key == key => PolyComm {
+                unshifted: vec![s.comm.into_affine()],
+                shifted: None,
+            },
+            _ => fq_x_hat_comm(&fq_lagrange_commitments(srs, index.domains.x), &public),
+        };
+        b.x_hat = Some((x_hat, comm));
+        true
+    })
 }
 
+// The primary input, as the proof_create stubs take it.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g_affine_vector_delete(v: *mut Vec<GAffine>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_bn382_fq_public_input_primary(b: *const FqPublicInput) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_public_input_primary", std::ptr::null(), || {
+        let b = handle(b);
+        into_handle(Box::new((unsafe { &*b }).primary.clone()))
+    })
 }
 
+// The public input with the constant one, as zexe_bn382_fq_proof_make takes it.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_public_input_public(b: *const FqPublicInput) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_public_input_public", std::ptr::null(), || {
+        let b = handle(b);
+        into_handle(Box::new((unsafe { &*b }).public()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_public_input_x_hat_comm(
+    b: *const FqPublicInput,
+) -> *const PolyComm<GAffine> {
+    catch_ffi("zexe_bn382_fq_public_input_x_hat_comm", std::ptr::null(), || {
+        let b = handle(b);
+        match &(unsafe { &*b }).x_hat {
+            Some((_, comm)) => into_handle(Box::new(comm.clone())),
+            None => null_with_error(
+                "zexe_bn382_fq_public_input_x_hat_comm: The public input is not finished.".to_string(),
+            ),
+        }
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_public_input_delete, FqPublicInput);
+
 // Fq oracles
+#[derive(Clone)]
 pub struct FqOracles {
     o: marlin_protocol_dlog::prover::RandomOracles<Fq>,
     opening_prechallenges: Vec<ScalarChallenge<Fq>>,
 }
 
+fn fq_oracles(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+    x_hat: &DensePolynomial<Fq>,
+    x_hat_comm: PolyComm<GAffine>,
+) -> FqOracles {
+    let (mut sponge, o) = proof
+        .oracles::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+            index, x_hat_comm, x_hat,
+        );
+    let opening_prechallenges = proof.proof.prechallenges(&mut sponge);
+
+    FqOracles {
+        o,
+        opening_prechallenges,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_oracles_create(
     index: *const DlogVerifierIndex<GAffine>,
     proof: *const DlogProof<GAffine>,
 ) -> *const FqOracles {
-    let index = unsafe { &(*index) };
-    let proof = unsafe { &(*proof) };
+    catch_ffi("zexe_bn382_fq_oracles_create", std::ptr::null(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
 
-    let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
-    // TODO: Should have no degree bound when we add the correct degree bound method
-    let x_hat_comm = index.srs.get_ref().commit(&x_hat, None);
+        let (x_hat, x_hat_comm) = fq_x_hat(index, &proof.public);
+        return into_handle(Box::new(fq_oracles(index, proof, &x_hat, x_hat_comm)));
+    })
+}
 
-    let (mut sponge, o) = proof
-        .oracles::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
-            index, x_hat_comm, &x_hat,
-        );
-    let opening_prechallenges = proof.proof.prechallenges(&mut sponge);
+// As zexe_bn382_fq_oracles_create, with the x_hat commitment of a finished
+// public input, which must be that of the proof.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_create_with_public_input(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+    public_input: *const FqPublicInput,
+) -> *const FqOracles {
+    catch_ffi("zexe_bn382_fq_oracles_create_with_public_input", std::ptr::null(), || {
+        const NAME: &str = "zexe_bn382_fq_oracles_create_with_public_input";
+        let index = handle(index);
+        let proof = handle(proof);
+        let public_input = handle(public_input);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
+        let public_input = unsafe { &(*public_input) };
+
+        let (x_hat, x_hat_comm) = match &public_input.x_hat {
+            Some(x) => x.clone(),
+            None => return null_with_error(format!("{}: The public input is not finished.", NAME)),
+        };
+        if public_input.public() != proof.public {
+            return null_with_error(format!(
+                "{}: The public input is not that of the proof.",
+                NAME
+            ));
+        }
 
-    return Box::into_raw(Box::new(FqOracles {
-        o,
-        opening_prechallenges,
-    }));
+        return into_handle(Box::new(fq_oracles(index, proof, &x_hat, x_hat_comm)));
+    })
+}
+
+// As zexe_bn382_fq_oracles_create, with the x_hat commitment supplied by the
+// caller, e.g. that of a streaming public input, which is absorbed as given.
+// If it is not the commitment to the public input of the proof, the proof
+// fails its sumchecks against these oracles.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_create_with_x_hat_comm(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+    x_hat_comm: *const PolyComm<GAffine>,
+) -> *const FqOracles {
+    const NAME: &str = "zexe_bn382_fq_oracles_create_with_x_hat_comm";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let x_hat_comm = handle(x_hat_comm);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
+        let x_hat_comm = unsafe { &(*x_hat_comm) }.clone();
+
+        let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+        into_handle(Box::new(fq_oracles(index, proof, &x_hat, x_hat_comm)))
+    })
+}
+
+// The oracles of each proof, all against the same verifier index. The
+// Lagrange commitments of the x domain are computed once for all the proofs,
+// so that committing to each public input is a short MSM, and the proofs are
+// processed in parallel.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_create_batch(
+    index: *const DlogVerifierIndex<GAffine>,
+    proofs: *const Vec<DlogProof<GAffine>>,
+) -> *mut Vec<FqOracles> {
+    const NAME: &str = "zexe_bn382_fq_oracles_create_batch";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let proofs = handle(proofs);
+        let index = unsafe { &(*index) };
+        let proofs = unsafe { &(*proofs) };
+        if let Some(i) = proofs
+            .iter()
+            .position(|p| p.public.len() > index.domains.x.size())
+        {
+            return null_with_error(format!(
+                "{}: Proof {} has more public inputs than the x domain.",
+                NAME, i
+            ));
+        }
+        let lagrange = fq_lagrange_commitments(index.srs.get_ref(), index.domains.x);
+        let res = in_pool(|| {
+            proofs
+                .par_iter()
+                .map(|proof| {
+                    let x_hat =
+                        evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+                    fq_oracles(index, proof, &x_hat, fq_x_hat_comm(&lagrange, &proof.public))
+                })
+                .collect()
+        });
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_vector_length(v: *const Vec<FqOracles>) -> usize {
+    catch_ffi("zexe_bn382_fq_oracles_vector_length", 0, || {
+        let v = handle(v);
+        (unsafe { &*v }).len()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_vector_get(
+    v: *const Vec<FqOracles>,
+    i: usize,
+) -> *const FqOracles {
+    catch_ffi("zexe_bn382_fq_oracles_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        into_handle(Box::new((unsafe { &*v })[i].clone()))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_oracles_vector_delete, Vec<FqOracles>);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_opening_prechallenges(
+    oracles: *const FqOracles,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_oracles_opening_prechallenges", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new(
+            (unsafe { &(*oracles) })
+                .opening_prechallenges
+                .iter()
+                .map(|x| x.0)
+                .collect(),
+        ));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_alpha(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_alpha", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.alpha.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_eta_a(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_eta_a", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.eta_a.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_eta_b(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_eta_b", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.eta_b.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_eta_c(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_eta_c", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.eta_c.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_beta1(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_beta1", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.beta[0].0.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_beta2(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_beta2", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.beta[1].0.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_beta3(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_beta3", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.beta[2].0.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_polys(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_polys", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.polys.0.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_evals(oracles: *const FqOracles) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_evals", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.evals.0.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_x_hat_nocopy(
+    oracles: *const FqOracles,
+) -> *const [Vec<Fq>; 3] {
+    catch_ffi("zexe_bn382_fq_oracles_x_hat_nocopy", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.x_hat.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_oracles_digest_before_evaluations(
+    oracles: *const FqOracles,
+) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_oracles_digest_before_evaluations", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new(
+            (unsafe { &(*oracles) }).o.digest_before_evaluations.clone(),
+        ));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_oracles_delete, FqOracles);
+
+// Fq opening challenges
+//
+// The scalars of the final check of an opening proof, recomputed from a
+// serialized proof without the multi-scalar multiplication, so that a light
+// verifier, or the wrap circuit, can take them as precomputed values: the
+// challenges of the rounds and their inverses, the evaluation points, the
+// evaluations of the challenge polynomial b at them, and their combination
+// by evalscale.
+pub struct FqOpeningChallenges {
+    prechallenges: Vec<Fq>,
+    challenges: Vec<Fq>,
+    challenge_invs: Vec<Fq>,
+    evaluation_points: Vec<Fq>,
+    b_evals: Vec<Fq>,
+    combined_b: Fq,
+    polyscale: Fq,
+    evalscale: Fq,
+}
+
+fn fq_opening_challenges(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> FqOpeningChallenges {
+    let (x_hat, x_hat_comm) = fq_x_hat(index, &proof.public);
+    let oracles = fq_oracles(index, proof, &x_hat, x_hat_comm);
+
+    let (_, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+    let challenges: Vec<Fq> = oracles
+        .opening_prechallenges
+        .iter()
+        .map(|c| c.to_field(&endo_r))
+        .collect();
+    let mut challenge_invs = challenges.clone();
+    algebra::fields::batch_inversion(&mut challenge_invs);
+
+    let evaluation_points: Vec<Fq> = oracles.o.beta.iter().map(|b| b.to_field(&endo_r)).collect();
+    let b_evals: Vec<Fq> = evaluation_points
+        .iter()
+        .map(|x| b_poly(&challenges, *x))
+        .collect();
+    let evalscale = oracles.o.evals.0;
+    let combined_b = b_evals.iter().rev().fold(Fq::zero(), |acc, b| acc * &evalscale + b);
+
+    FqOpeningChallenges {
+        prechallenges: oracles.opening_prechallenges.iter().map(|c| c.0).collect(),
+        challenges,
+        challenge_invs,
+        evaluation_points,
+        b_evals,
+        combined_b,
+        polyscale: oracles.o.polys.0,
+        evalscale,
+    }
+}
+
+// Recomputes the opening challenges of a proof serialized by
+// zexe_bn382_fq_proof_to_bytes, with its public input encoded as for
+// zexe_bn382_fq_proof_verify_bytes. This replays the transcript but does not
+// verify the proof. Returns null, with the last error set, if the proof is
+// malformed.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const u8,
+    proof_len: usize,
+    public_input: *const u8,
+    public_input_len: usize,
+) -> *mut FqOpeningChallenges {
+    const NAME: &str = "zexe_bn382_fq_opening_challenges";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let proof = unsafe { std::slice::from_raw_parts(proof, proof_len) };
+        let public_input = unsafe { std::slice::from_raw_parts(public_input, public_input_len) };
+
+        let public = match read_field_elements::<Fq>(public_input) {
+            Ok(public) => public,
+            Err(e) => return null_with_error(format!("{}: public input: {}", NAME, e)),
+        };
+        let mut r = proof;
+        let proof = match read_dlog_proof::<GAffine, _, _>(&mut r, public, fq_proof_point_valid) {
+            Ok(proof) if r.is_empty() => proof,
+            Ok(_) => return null_with_error(format!("{}: Trailing bytes after the proof.", NAME)),
+            Err(e) => return null_with_error(format!("{}: proof: {}", NAME, e)),
+        };
+        if let Some(e) = fq_proof_structure_error(index, &proof) {
+            return null_with_error(format!("{}: {}", NAME, e));
+        }
+
+        into_handle(Box::new(fq_opening_challenges(index, &proof)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_prechallenges(
+    c: *const FqOpeningChallenges,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_challenges_prechallenges", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).prechallenges }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_challenges(
+    c: *const FqOpeningChallenges,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_challenges_challenges", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).challenges }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_challenge_invs(
+    c: *const FqOpeningChallenges,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_challenges_challenge_invs", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).challenge_invs }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_evaluation_points(
+    c: *const FqOpeningChallenges,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_challenges_evaluation_points", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).evaluation_points }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_b_evals(
+    c: *const FqOpeningChallenges,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_opening_challenges_b_evals", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).b_evals }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_combined_b(
+    c: *const FqOpeningChallenges,
+) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_opening_challenges_combined_b", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).combined_b }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_polyscale(
+    c: *const FqOpeningChallenges,
+) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_opening_challenges_polyscale", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).polyscale }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_opening_challenges_evalscale(
+    c: *const FqOpeningChallenges,
+) -> *const Fq {
+    catch_ffi("zexe_bn382_fq_opening_challenges_evalscale", std::ptr::null(), || {
+        let c = handle(c);
+        let x = (unsafe { &(*c).evalscale }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_opening_challenges_delete, FqOpeningChallenges);
+
+// Fq proof
+fn fq_witness_fast_path(index: *const DlogIndex<GAffine>, auxiliary_input: &[Fq]) -> bool {
+    let uses_auxiliary =
+        index_uses_auxiliary(index, || fq_index_scans_auxiliary(unsafe { &*index }));
+    witness_fast_path(uses_auxiliary, auxiliary_input)
+}
+
+// Whether zexe_bn382_fq_proof_create would prepare the witness from the
+// primary input alone, because the auxiliary input is empty or all zero or the
+// index does not refer to it.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_fast_path(
+    index: *const DlogIndex<GAffine>,
+    auxiliary_input: *const Vec<Fq>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_fast_path", false, || {
+        let index = handle(index);
+        let auxiliary_input = handle(auxiliary_input);
+        if !check_handle("zexe_bn382_fq_proof_fast_path", index) {
+            return false;
+        }
+        fq_witness_fast_path(index, unsafe { &*auxiliary_input })
+    })
+}
+
+fn fq_proof_create<R: RngCore + CryptoRng>(
+    name: &str,
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+    rng: &mut R,
+) -> *const DlogProof<GAffine> {
+    fq_proof_create_with_sponges::<
+        DefaultFqSponge<Bn_382GParameters, SC>,
+        DefaultFrSponge<Fq, SC>,
+        R,
+    >(
+        name,
+        index,
+        primary_input,
+        auxiliary_input,
+        prev_challenges,
+        prev_sgs,
+        rng,
+    )
+}
+
+// As fq_proof_create, with the sponges given, which must behave as the
+// default ones for the proof to verify.
+pub(crate) fn fq_proof_create_with_sponges<
+    EFqSponge: Clone + FqSponge<Fp, GAffine, Fq>,
+    EFrSponge: FrSponge<Fq>,
+    R: RngCore + CryptoRng,
+>(
+    name: &str,
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+    rng: &mut R,
+) -> *const DlogProof<GAffine> {
+    if !check_handle(name, index) {
+        return std::ptr::null();
+    }
+    in_pool(|| {
+        let primary_input = unsafe { &(*primary_input) };
+        let auxiliary_input = unsafe { &(*auxiliary_input) };
+        let fast_path = fq_witness_fast_path(index, auxiliary_input);
+        let index = unsafe { &(*index) };
+
+        if let Err(e) = check_labelled_system(index, primary_input, auxiliary_input) {
+            return null_with_error(format!("{}: {}", name, e));
+        }
+
+        let witness = if fast_path {
+            prepare_witness(index.domains, primary_input, &vec![])
+        } else {
+            prepare_witness(index.domains, primary_input, auxiliary_input)
+        };
+
+        let prev = match prev_challenges_of_flat(
+            unsafe { &*prev_challenges },
+            unsafe { &*prev_sgs },
+            lr_rounds(index.srs.get_ref()),
+        ) {
+            Ok(prev) => prev,
+            Err(e) => return null_with_error(format!("{}: {}", name, e)),
+        };
+
+        let map = <Affine as CommitmentCurve>::Map::setup();
+        let proof =
+            DlogProof::create::<EFqSponge, EFrSponge>(&map, &witness, &index, prev, rng).unwrap();
+
+        into_handle(Box::new(proof))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create(
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+) -> *const DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_fq_proof_create", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        fq_proof_create(
+            "zexe_bn382_fq_proof_create",
+            index,
+            primary_input,
+            auxiliary_input,
+            prev_challenges,
+            prev_sgs,
+            &mut system_rng(),
+        )
+    })
+}
+
+// As zexe_bn382_fq_proof_create, with the primary input of a public input
+// builder, whose length is checked against the index.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create_with_public_input(
+    index: *const DlogIndex<GAffine>,
+    public_input: *const FqPublicInput,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+) -> *const DlogProof<GAffine> {
+    const NAME: &str = "zexe_bn382_fq_proof_create_with_public_input";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let index = handle(index);
+        let public_input = handle(public_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let public_input = unsafe { &*public_input };
+        if let Err(e) = public_input.check_length(unsafe { &*index }.public_inputs) {
+            return null_with_error(format!("{}: {}", NAME, e));
+        }
+        fq_proof_create(
+            NAME,
+            index,
+            &public_input.primary,
+            auxiliary_input,
+            prev_challenges,
+            prev_sgs,
+            &mut system_rng(),
+        )
+    })
+}
+
+// As zexe_bn382_fq_proof_create, but draws all the prover's randomness,
+// including the blinding factors, from ChaCha20 seeded with the given 32
+// bytes. The same seed and inputs give bit-identical proofs, so a seed must
+// never be reused for different inputs.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create_with_seed(
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+    seed: *const u8,
+) -> *const DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_fq_proof_create_with_seed", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(unsafe { std::slice::from_raw_parts(seed, 32) });
+        fq_proof_create(
+            "zexe_bn382_fq_proof_create_with_seed",
+            index,
+            primary_input,
+            auxiliary_input,
+            prev_challenges,
+            prev_sgs,
+            &mut ChaCha20Rng::from_seed(s),
+        )
+    })
+}
+
+// A digest of what the index proves: its SRS, its number of public inputs and
+// its constraint matrices.
+fn fq_index_digest(index: &DlogIndex<GAffine>) -> UrsDigest {
+    let mut w = DigestWriter::new(std::io::sink());
+    index.srs.get_ref().h.write(&mut w).unwrap();
+    (index.public_inputs as u64).write(&mut w).unwrap();
+    for c in index.compiled.iter() {
+        write_cs_mat(&c.constraints, &mut w).unwrap();
+    }
+    w.finish().unwrap()
+}
+
+// As zexe_bn382_fq_proof_create_with_seed, with the seed derived from the
+// index, a secret key of at least PROOF_KEY_MIN_BYTES bytes and the inputs as
+// described at derive_proof_seed, so that the same inputs and key always give
+// the same proof. The proof hides the witness only while the key is secret.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create_deterministic(
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+    key: *const u8,
+    key_len: usize,
+) -> *const DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_fq_proof_create_deterministic", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        if !check_handle("zexe_bn382_fq_proof_create_deterministic", index) {
+            return std::ptr::null();
+        }
+        let prev_sg_coords: Vec<Fq> = (unsafe { &*prev_sgs })
+            .iter()
+            .flat_map(|g| {
+                let mut bytes = vec![];
+                g.write(&mut bytes).unwrap();
+                bytes
+                    .chunks(8)
+                    .map(|c| {
+                        let mut b = [0u8; 8];
+                        b[..c.len()].copy_from_slice(c);
+                        Fq::from(u64::from_le_bytes(b))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let key = if key_len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(key, key_len) }
+        };
+        let seed = match derive_proof_seed(
+            &oracle::bn_382::fq::params(),
+            &fq_index_digest(unsafe { &*index }),
+            key,
+            &[
+                unsafe { &*primary_input },
+                unsafe { &*auxiliary_input },
+                unsafe { &*prev_challenges },
+                &prev_sg_coords,
+            ],
+        ) {
+            Ok(seed) => seed,
+            Err(e) => {
+                return null_with_error(format!(
+                    "zexe_bn382_fq_proof_create_deterministic: {}",
+                    e
+                ))
+            }
+        };
+        fq_proof_create(
+            "zexe_bn382_fq_proof_create_deterministic",
+            index,
+            primary_input,
+            auxiliary_input,
+            prev_challenges,
+            prev_sgs,
+            &mut ChaCha20Rng::from_seed(seed),
+        )
+    })
+}
+
+// Randomness supplied by the caller, which must fill the buffer it is given
+// with the requested number of uniformly random bytes.
+pub type FillBytes = extern "C" fn(*mut u8, usize);
+
+struct CallbackRng(FillBytes);
+
+impl RngCore for CallbackRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (self.0)(dest.as_mut_ptr(), dest.len())
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// The callback is trusted to be a cryptographically secure source.
+impl CryptoRng for CallbackRng {}
+
+// As zexe_bn382_fq_proof_create, but draws all the prover's randomness from
+// the callback.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create_with_rng(
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+    fill_bytes: FillBytes,
+) -> *const DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_fq_proof_create_with_rng", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        fq_proof_create(
+            "zexe_bn382_fq_proof_create_with_rng",
+            index,
+            primary_input,
+            auxiliary_input,
+            prev_challenges,
+            prev_sgs,
+            &mut CallbackRng(fill_bytes),
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_verify(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_verify", false, || {
+        let index = handle(index);
+        let proof = handle(proof);
+        if !check_handle("zexe_bn382_fq_proof_verify", index)
+            || !check_handle("zexe_bn382_fq_proof_verify", proof)
+        {
+            return false;
+        }
+        in_pool(|| {
+            let index = unsafe { &(*index) };
+            let proof = unsafe { (*proof).clone() };
+            if let Some(e) = fq_proof_structure_error(index, &proof) {
+                set_last_error(format!("zexe_bn382_fq_proof_verify: {}", e));
+                return false;
+            }
+            let group_map = <Affine as CommitmentCurve>::Map::setup();
+
+            DlogProof::verify::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+                &group_map,
+                &[(index, proof)].to_vec(),
+                &mut system_rng(),
+            )
+        })
+    })
+}
+
+// As zexe_bn382_fq_proof_verify, over the given x_hat commitment, e.g. one
+// the verifier accepted before the proof existed. The commitment is used as
+// given rather than recomputed: it is absorbed into the oracles and opened
+// with the other polynomials, so the proof only verifies if it is the
+// commitment to the public input of the proof. Debug builds also check it
+// against the public input up front, to report a mismatch as such.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_verify_with_x_hat_comm(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+    x_hat_comm: *const PolyComm<GAffine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_verify_with_x_hat_comm", false, || {
+        const NAME: &str = "zexe_bn382_fq_proof_verify_with_x_hat_comm";
+        let index = handle(index);
+        let proof = handle(proof);
+        let x_hat_comm = handle(x_hat_comm);
+        if !check_handle(NAME, index) || !check_handle(NAME, proof) {
+            return false;
+        }
+        catch_ffi(NAME, false, || {
+            let index = unsafe { &*index };
+            let proof = unsafe { &*proof };
+            let x_hat_comm = unsafe { &*x_hat_comm };
+            #[cfg(debug_assertions)]
+            {
+                let lagrange = fq_lagrange_commitments(index.srs.get_ref(), index.domains.x);
+                if !fq_x_hat_comm_matches(&lagrange, &proof.public, x_hat_comm) {
+                    set_last_error(format!(
+                        "{}: The x_hat commitment is not that of the public input of the proof.",
+                        NAME
+                    ));
+                    return false;
+                }
+            }
+            in_pool(|| {
+                match fq_verify_phase1_with_x_hat_comm(index, proof, x_hat_comm.clone()) {
+                    Ok(inputs) => fq_opening_batch_check(&[(index, proof, &inputs)]),
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", NAME, e));
+                        false
+                    }
+                }
+            })
+        })
+    })
+}
+
+// As zexe_bn382_fq_proof_verify, but returns one of the VERIFY_* codes and
+// records the reason for a failure as the last error. The degree bounds are
+// checked as part of the opening proof on this side, so a failure of either
+// is reported as VERIFY_FAILED_OPENING.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_verify_diagnostic(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> i32 {
+    catch_ffi("zexe_bn382_fq_proof_verify_diagnostic", VERIFY_FAILED_MALFORMED, || {
+        const NAME: &str = "zexe_bn382_fq_proof_verify_diagnostic";
+        let index = handle(index);
+        let proof = handle(proof);
+        if !check_handle(NAME, index) || !check_handle(NAME, proof) {
+            return VERIFY_FAILED_MALFORMED;
+        }
+        catch_ffi(NAME, VERIFY_FAILED_MALFORMED, || {
+            if zexe_bn382_fq_proof_verify(index, proof) {
+                return VERIFY_OK;
+            }
+            let index = unsafe { &(*index) };
+            let proof = unsafe { &(*proof) };
+
+            if proof.public.len() != index.public_inputs {
+                return verify_failure(NAME, VERIFY_FAILED_PUBLIC_INPUT);
+            }
+            if fq_proof_structure_error(index, proof).is_some() {
+                return verify_failure(NAME, VERIFY_FAILED_MALFORMED);
+            }
+
+            let (x_hat, x_hat_comm) = fq_x_hat(index, &proof.public);
+            let (_, oracles) = proof
+                .oracles::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+                    index, x_hat_comm, &x_hat,
+                );
+
+            if !proof.sumcheck_1_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_1);
+            }
+            if !proof.sumcheck_2_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_2);
+            }
+            if !proof.sumcheck_3_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_3);
+            }
+
+            verify_failure(NAME, VERIFY_FAILED_OPENING)
+        })
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_opening_prechallenges(
-    oracles: *const FqOracles,
-) -> *const Vec<Fq> {
-    return Box::into_raw(Box::new(
-        (unsafe { &(*oracles) })
-            .opening_prechallenges
-            .iter()
-            .map(|x| x.0)
-            .collect(),
-    ));
+// The deferred accumulator of a verified proof, which the next layer of
+// recursion passes on as a previous challenge: the sg point of the opening
+// proof and the challenges of its rounds.
+pub struct FqAccumulator {
+    sg: GAffine,
+    challenges: Vec<Fq>,
 }
 
+// As zexe_bn382_fq_proof_verify, but returns the accumulator of the proof, or
+// null with the last error set if it does not verify.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_alpha(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.alpha.clone()));
-}
+pub extern "C" fn zexe_bn382_fq_proof_verify_accumulator(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> *mut FqAccumulator {
+    catch_ffi("zexe_bn382_fq_proof_verify_accumulator", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fq_proof_verify_accumulator";
+        let index = handle(index);
+        let proof = handle(proof);
+        if !zexe_bn382_fq_proof_verify(index, proof) {
+            return null_with_error(format!("{}: The proof does not verify.", NAME));
+        }
+        catch_ffi(NAME, std::ptr::null_mut(), || {
+            let index = unsafe { &(*index) };
+            let proof = unsafe { &(*proof) };
+
+            let (x_hat, x_hat_comm) = fq_x_hat(index, &proof.public);
+            let (mut sponge, _) = proof
+                .oracles::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+                    index, x_hat_comm, &x_hat,
+                );
+            let (_, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+            let challenges = proof
+                .proof
+                .prechallenges(&mut sponge)
+                .iter()
+                .map(|c| c.to_field(&endo_r))
+                .collect();
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_eta_a(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.eta_a.clone()));
+            into_handle(Box::new(FqAccumulator {
+                sg: proof.proof.sg,
+                challenges,
+            }))
+        })
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_eta_b(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.eta_b.clone()));
+pub extern "C" fn zexe_bn382_fq_accumulator_sg(a: *const FqAccumulator) -> *const GAffine {
+    catch_ffi("zexe_bn382_fq_accumulator_sg", std::ptr::null(), || {
+        let a = handle(a);
+        let x = (unsafe { &(*a).sg }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_eta_c(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.eta_c.clone()));
+pub extern "C" fn zexe_bn382_fq_accumulator_challenges(
+    a: *const FqAccumulator,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_accumulator_challenges", std::ptr::null(), || {
+        let a = handle(a);
+        let x = (unsafe { &(*a).challenges }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_beta1(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.beta[0].0.clone()));
-}
+crate::delete_stub!(zexe_bn382_fq_accumulator_delete, FqAccumulator);
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_beta2(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.beta[1].0.clone()));
+// Verifies the proofs of a batch, each against its verifier index, as
+// DlogProof::verify does: each proof is checked against its oracles, in
+// parallel, then the openings of the whole batch are checked together. The
+// proofs are borrowed rather than copied into the batch.
+pub(crate) fn fq_proof_batch_verify(
+    batch: &[(&DlogVerifierIndex<GAffine>, &DlogProof<GAffine>)],
+) -> bool {
+    in_pool(|| {
+        let inputs = batch
+            .par_iter()
+            .map(|(index, proof)| fq_verify_phase1(index, proof, None))
+            .collect::<Result<Vec<_>, _>>();
+        match inputs {
+            Ok(inputs) => fq_opening_batch_check(
+                &batch
+                    .iter()
+                    .zip(inputs.iter())
+                    .map(|(&(index, proof), inputs)| (index, proof, inputs))
+                    .collect::<Vec<_>>(),
+            ),
+            Err(_) => false,
+        }
+    })
 }
 
+// Verifies the i-th proof against the i-th verifier index, for all i. The
+// openings of all the proofs are checked together, with a single
+// multi-scalar multiplication, so this is much cheaper than verifying the
+// proofs one at a time.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_beta3(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.beta[2].0.clone()));
+pub extern "C" fn zexe_bn382_fq_proof_batch_verify(
+    indexes: *const Vec<*const DlogVerifierIndex<GAffine>>,
+    proofs: *const Vec<DlogProof<GAffine>>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_batch_verify", false, || {
+        let indexes = handle(indexes);
+        let proofs = handle(proofs);
+        let indexes = unsafe { &(*indexes) };
+        let proofs = unsafe { &(*proofs) };
+        if indexes.len() != proofs.len() {
+            set_last_error(format!(
+                "zexe_bn382_fq_proof_batch_verify: {} verifier indexes for {} proofs",
+                indexes.len(),
+                proofs.len()
+            ));
+            return false;
+        }
+        fq_proof_batch_verify(
+            &indexes
+                .iter()
+                .zip(proofs.iter())
+                .map(|(&index, p)| (unsafe { &(*handle(index)) }, p))
+                .collect::<Vec<_>>(),
+        )
+    })
 }
 
+// As zexe_bn382_fq_proof_batch_verify, for proofs which all share one
+// verifier index.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_polys(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.polys.0.clone()));
+pub extern "C" fn zexe_bn382_fq_proof_batch_verify_shared(
+    index: *const DlogVerifierIndex<GAffine>,
+    proofs: *const Vec<DlogProof<GAffine>>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_batch_verify_shared", false, || {
+        let index = handle(index);
+        let proofs = handle(proofs);
+        let index = unsafe { &(*index) };
+        let proofs = unsafe { &(*proofs) };
+        fq_proof_batch_verify(&proofs.iter().map(|p| (index, p)).collect::<Vec<_>>())
+    })
+}
+
+// Two-phase verification
+//
+// Phase 1 checks everything about a proof except its opening: its shape, and
+// the sumchecks against the oracles of its transcript. This needs a
+// commitment to the public input but none of the large multi-scalar
+// multiplications, so it is cheap enough for admission checks. Its result
+// records the proof with the digest of the verifier index it was checked
+// against, and the inputs of the opening check which the transcript
+// determines, and can be serialized. Phase 2 checks the openings of many
+// phase-1 results together, from those inputs alone, with a single
+// multi-scalar multiplication.
+
+// What the opening check of a proof needs besides the proof and its verifier
+// index: the Fq sponge at the end of the transcript, the evaluation points and
+// the scaling factors of the polynomials and of the evaluation points, and the
+// polynomials which are evaluated by the verifier rather than the prover, x_hat
+// and the b polynomials of the previous challenges.
+pub(crate) struct FqOpeningInputs {
+    sponge: DefaultFqSponge<Bn_382GParameters, SC>,
+    points: Vec<Fq>,
+    polyscale: Fq,
+    evalscale: Fq,
+    x_hat_comm: PolyComm<GAffine>,
+    x_hat_evals: [Vec<Fq>; 3],
+    // For each previous challenge, its b polynomial at each point.
+    prev_evals: Vec<Vec<Vec<Fq>>>,
+}
+
+pub struct FqVerifyPhase1 {
+    index_digest: UrsDigest,
+    proof: DlogProof<GAffine>,
+    opening: FqOpeningInputs,
+}
+
+fn fq_verifier_index_digest(index: &DlogVerifierIndex<GAffine>) -> UrsDigest {
+    let mut w = DigestWriter::new(std::io::sink());
+    write_verifier_index(index, &mut w).unwrap();
+    w.finish().unwrap()
+}
+
+// The inputs of the opening check of a proof, from its transcript.
+fn fq_opening_inputs(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+    x_hat: &DensePolynomial<Fq>,
+    x_hat_comm: PolyComm<GAffine>,
+) -> (
+    FqOpeningInputs,
+    marlin_protocol_dlog::prover::RandomOracles<Fq>,
+) {
+    let (sponge, o) = proof
+        .oracles::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+            index,
+            x_hat_comm.clone(),
+            x_hat,
+        );
+    let (_, endo_r) = commitment_dlog::srs::endos::<GAffine>();
+    let points: Vec<Fq> = o.beta.iter().map(|b| b.to_field(&endo_r)).collect();
+    let prev_evals = proof
+        .prev_challenges
+        .iter()
+        .map(|(chals, _)| points.iter().map(|x| vec![b_poly(chals, *x)]).collect())
+        .collect();
+    let inputs = FqOpeningInputs {
+        sponge,
+        points,
+        polyscale: o.polys.0,
+        evalscale: o.evals.0,
+        x_hat_comm,
+        x_hat_evals: o.x_hat.clone(),
+        prev_evals,
+    };
+    (inputs, o)
+}
+
+// With the Lagrange commitments of the x domain, x_hat is committed to with a
+// short MSM over them rather than over the SRS.
+pub(crate) fn fq_verify_phase1(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+    lagrange: Option<&[GAffine]>,
+) -> Result<FqOpeningInputs, String> {
+    if let Some(e) = fq_proof_structure_error(index, proof) {
+        return Err(e);
+    }
+    let (x_hat, x_hat_comm) = match lagrange {
+        Some(lagrange) => (
+            evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate(),
+            fq_x_hat_comm(lagrange, &proof.public),
+        ),
+        None => fq_x_hat(index, &proof.public),
+    };
+    fq_sumchecks(index, proof, &x_hat, x_hat_comm)
+}
+
+// As fq_verify_phase1, over an x_hat commitment supplied by the caller, which
+// the returned inputs open as given.
+fn fq_verify_phase1_with_x_hat_comm(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+    x_hat_comm: PolyComm<GAffine>,
+) -> Result<FqOpeningInputs, String> {
+    if let Some(e) = fq_proof_structure_error(index, proof) {
+        return Err(e);
+    }
+    let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+    fq_sumchecks(index, proof, &x_hat, x_hat_comm)
+}
+
+// Runs the sumchecks of a proof against its oracles, and returns the inputs of
+// its opening check.
+fn fq_sumchecks(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+    x_hat: &DensePolynomial<Fq>,
+    x_hat_comm: PolyComm<GAffine>,
+) -> Result<FqOpeningInputs, String> {
+    let (inputs, o) = fq_opening_inputs(index, proof, x_hat, x_hat_comm);
+    if !proof.sumcheck_1_verify(index, &o) {
+        return Err("The first sumcheck fails.".to_string());
+    }
+    if !proof.sumcheck_2_verify(index, &o) {
+        return Err("The second sumcheck fails.".to_string());
+    }
+    if !proof.sumcheck_3_verify(index, &o) {
+        return Err("The third sumcheck fails.".to_string());
+    }
+    Ok(inputs)
+}
+
+// Checks the openings of proofs which passed phase 1, each with the inputs
+// phase 1 returned for it, with a single multi-scalar multiplication. The
+// polynomials are listed in the order in which the prover opens them: the b
+// polynomials of the previous challenges, x_hat, w, za, zb, h1, h2 and h3,
+// then the row, col, val and rc polynomials of each matrix, and the
+// degree-bounded g1, g2 and g3. The proofs must all be over the same SRS.
+pub(crate) fn fq_opening_batch_check(
+    batch: &[(
+        &DlogVerifierIndex<GAffine>,
+        &DlogProof<GAffine>,
+        &FqOpeningInputs,
+    )],
+) -> bool {
+    let srs = match batch.first() {
+        Some((index, _, _)) => index.srs.get_ref(),
+        None => return true,
+    };
+    let mut params = batch
+        .iter()
+        .map(|&(index, proof, inputs)| {
+            let evals = |f: &dyn Fn(&DlogProofEvaluations<Fq>) -> &Vec<Fq>| {
+                proof.evals.iter().map(|e| f(e)).collect::<Vec<_>>()
+            };
+            let mut polys: Vec<(&PolyComm<GAffine>, Vec<&Vec<Fq>>, Option<usize>)> = proof
+                .prev_challenges
+                .iter()
+                .zip(inputs.prev_evals.iter())
+                .map(|((_, comm), evals)| (comm, evals.iter().collect(), None))
+                .collect();
+            polys.push((
+                &inputs.x_hat_comm,
+                inputs.x_hat_evals.iter().collect(),
+                None,
+            ));
+            polys.push((&proof.w_comm, evals(&|e| &e.w), None));
+            polys.push((&proof.za_comm, evals(&|e| &e.za), None));
+            polys.push((&proof.zb_comm, evals(&|e| &e.zb), None));
+            polys.push((&proof.h1_comm, evals(&|e| &e.h1), None));
+            polys.push((&proof.h2_comm, evals(&|e| &e.h2), None));
+            polys.push((&proof.h3_comm, evals(&|e| &e.h3), None));
+            for j in 0..3 {
+                let m = &index.matrix_commitments[j];
+                polys.push((&m.row, evals(&|e| &e.row[j]), None));
+            }
+            for j in 0..3 {
+                let m = &index.matrix_commitments[j];
+                polys.push((&m.col, evals(&|e| &e.col[j]), None));
+            }
+            for j in 0..3 {
+                let m = &index.matrix_commitments[j];
+                polys.push((&m.val, evals(&|e| &e.val[j]), None));
+            }
+            for j in 0..3 {
+                let m = &index.matrix_commitments[j];
+                polys.push((&m.rc, evals(&|e| &e.rc[j]), None));
+            }
+            let h_bound = Some(index.domains.h.size() - 1);
+            let k_bound = Some(index.domains.k.size() - 1);
+            polys.push((&proof.g1_comm, evals(&|e| &e.g1), h_bound));
+            polys.push((&proof.g2_comm, evals(&|e| &e.g2), h_bound));
+            polys.push((&proof.g3_comm, evals(&|e| &e.g3), k_bound));
+            (
+                inputs.sponge.clone(),
+                inputs.points.clone(),
+                inputs.polyscale,
+                inputs.evalscale,
+                polys,
+                &proof.proof,
+            )
+        })
+        .collect();
+    let group_map = <Affine as CommitmentCurve>::Map::setup();
+    srs.verify::<DefaultFqSponge<Bn_382GParameters, SC>>(&group_map, &mut params, &mut system_rng())
 }
 
+// Returns null, with the last error set, if the proof fails phase 1.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_evals(oracles: *const FqOracles) -> *const Fq {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.evals.0.clone()));
+pub extern "C" fn zexe_bn382_fq_proof_verify_phase1(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> *mut FqVerifyPhase1 {
+    catch_ffi("zexe_bn382_fq_proof_verify_phase1", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fq_proof_verify_phase1";
+        let index = handle(index);
+        let proof = handle(proof);
+        if !check_handle(NAME, index) || !check_handle(NAME, proof) {
+            return std::ptr::null_mut();
+        }
+        catch_ffi(NAME, std::ptr::null_mut(), || {
+            let index = unsafe { &(*index) };
+            let proof = unsafe { &(*proof) };
+            match fq_verify_phase1(index, proof, None) {
+                Ok(opening) => into_handle(Box::new(FqVerifyPhase1 {
+                    index_digest: fq_verifier_index_digest(index),
+                    proof: proof.clone(),
+                    opening,
+                })),
+                Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+            }
+        })
+    })
 }
 
+// Checks the openings of the phase-1 results in a pointer vector, which must
+// all have been checked against this verifier index.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_x_hat_nocopy(
-    oracles: *const FqOracles,
-) -> *const [Vec<Fq>; 3] {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.x_hat.clone()));
+pub extern "C" fn zexe_bn382_fq_proof_verify_phase2(
+    index: *const DlogVerifierIndex<GAffine>,
+    results: *const Vec<*const FqVerifyPhase1>,
+) -> bool {
+    const NAME: &str = "zexe_bn382_fq_proof_verify_phase2";
+    catch_ffi(NAME, false, || {
+        let index = handle(index);
+        let results = handle(results);
+        let index = unsafe { &(*index) };
+        let results = unsafe { &(*results) };
+        let digest = fq_verifier_index_digest(index);
+        if let Some(i) = results
+            .iter()
+            .position(|&r| unsafe { &*handle(r) }.index_digest != digest)
+        {
+            set_last_error(format!(
+                "{}: Result {} was checked against another verifier index.",
+                NAME, i
+            ));
+            return false;
+        }
+        in_pool(|| {
+            fq_opening_batch_check(
+                &results
+                    .iter()
+                    .map(|&r| {
+                        let r = unsafe { &*handle(r) };
+                        (index, &r.proof, &r.opening)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+    })
 }
 
+// The digest of the verifier index, then the public input and the proof as
+// zexe_bn382_fq_proof_to_bytes writes it. The inputs of the opening check are
+// not written, as they are recomputed from the transcript when read back.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_digest_before_evaluations(
-    oracles: *const FqOracles,
-) -> *const Fq {
-    return Box::into_raw(Box::new(
-        (unsafe { &(*oracles) }).o.digest_before_evaluations.clone(),
-    ));
+pub extern "C" fn zexe_bn382_fq_verify_phase1_to_bytes(r: *const FqVerifyPhase1) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fq_verify_phase1_to_bytes", std::ptr::null_mut(), || {
+        let r = handle(r);
+        let r = unsafe { &*r };
+        let mut res = r.index_digest.to_vec();
+        write_vec(&r.proof.public, &mut res).unwrap();
+        write_dlog_proof(&r.proof, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
 }
 
+// Reads a result written by zexe_bn382_fq_verify_phase1_to_bytes for this
+// verifier index, and replays the transcript of its proof for the inputs of
+// the opening check, without redoing the sumchecks. The result is only as
+// trustworthy as the bytes, which should come from this process or a trusted
+// store.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_oracles_delete(x: *mut FqOracles) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fq_verify_phase1_of_bytes(
+    index: *const DlogVerifierIndex<GAffine>,
+    data: *const u8,
+    len: usize,
+) -> *mut FqVerifyPhase1 {
+    const NAME: &str = "zexe_bn382_fq_verify_phase1_of_bytes";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let mut r = unsafe { std::slice::from_raw_parts(data, len) };
+        let read = |r: &mut &[u8]| -> IoResult<(UrsDigest, DlogProof<GAffine>)> {
+            let mut index_digest = [0u8; URS_DIGEST_SIZE];
+            r.read_exact(&mut index_digest)?;
+            let public = read_vec::<Fq, _>(&mut *r)?;
+            let proof = read_dlog_proof::<GAffine, _, _>(&mut *r, public, fq_proof_point_valid)?;
+            Ok((index_digest, proof))
+        };
+        let (index_digest, proof) = match read(&mut r) {
+            Ok(res) if r.is_empty() => res,
+            Ok(_) => return null_with_error(format!("{}: Trailing bytes after the result.", NAME)),
+            Err(e) => return null_with_error(format!("{}: {}", NAME, e)),
+        };
+        if index_digest != fq_verifier_index_digest(index) {
+            return null_with_error(format!(
+                "{}: The result was checked against another verifier index.",
+                NAME
+            ));
+        }
+        if let Some(e) = fq_proof_structure_error(index, &proof) {
+            return null_with_error(format!("{}: {}", NAME, e));
+        }
+        let (x_hat, x_hat_comm) = fq_x_hat(index, &proof.public);
+        let (opening, _) = fq_opening_inputs(index, &proof, &x_hat, x_hat_comm);
+        into_handle(Box::new(FqVerifyPhase1 {
+            index_digest,
+            proof,
+            opening,
+        }))
+    })
 }
 
-// Fq proof
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_create(
-    index: *const DlogIndex<GAffine>,
-    primary_input: *const Vec<Fq>,
-    auxiliary_input: *const Vec<Fq>,
-    prev_challenges: *const Vec<Fq>,
-    prev_sgs: *const Vec<GAffine>,
+pub extern "C" fn zexe_bn382_fq_verify_phase1_proof(
+    r: *const FqVerifyPhase1,
 ) -> *const DlogProof<GAffine> {
-    let index = unsafe { &(*index) };
-    let primary_input = unsafe { &(*primary_input) };
-    let auxiliary_input = unsafe { &(*auxiliary_input) };
-
-    let witness = prepare_witness(index.domains, primary_input, auxiliary_input);
-
-    let prev: Vec<(Vec<Fq>, PolyComm<GAffine>)> = {
-        let prev_challenges = unsafe { &*prev_challenges };
-        let prev_sgs = unsafe { &*prev_sgs };
-        if prev_challenges.len() == 0 {
-            Vec::new()
-        } else {
-            let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
-            prev_sgs
-                .iter()
-                .enumerate()
-                .map(|(i, sg)| {
-                    (
-                        prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
-                            .iter()
-                            .map(|x| *x)
-                            .collect(),
-                        PolyComm::<GAffine> {
-                            unshifted: vec![sg.clone()],
-                            shifted: None,
-                        },
-                    )
-                })
-                .collect()
+    catch_ffi("zexe_bn382_fq_verify_phase1_proof", std::ptr::null(), || {
+        let r = handle(r);
+        let x = (unsafe { &(*r).proof }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_verify_phase1_delete, FqVerifyPhase1);
+
+// Status codes of zexe_bn382_fq_proof_verify_bytes.
+pub const VERIFY_BYTES_VALID: i32 = 0;
+pub const VERIFY_BYTES_INVALID: i32 = 1;
+pub const VERIFY_BYTES_MALFORMED_PROOF: i32 = 2;
+pub const VERIFY_BYTES_MALFORMED_PUBLIC_INPUT: i32 = 3;
+pub const VERIFY_BYTES_REJECTED_LIMITS: i32 = 4;
+pub const VERIFY_BYTES_UNKNOWN_INDEX: i32 = 5;
+
+// The verifier indexes that proofs can be verified against from bytes, by the
+// digest of each. A caller names an index by its digest, which it can get
+// and pass around as bytes, rather than by a handle it must keep alive.
+type FqVerifierIndexes = HashMap<UrsDigest, Arc<DlogVerifierIndex<'static, GAffine>>>;
+
+lazy_static! {
+    static ref FQ_REGISTERED_VERIFIER_INDEXES: Mutex<FqVerifierIndexes> = Mutex::new(HashMap::new());
+}
+
+fn fq_registered_verifier_index(
+    digest: &UrsDigest,
+) -> Option<Arc<DlogVerifierIndex<'static, GAffine>>> {
+    FQ_REGISTERED_VERIFIER_INDEXES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(digest)
+        .cloned()
+}
+
+// Takes ownership of the verifier index, which must not be used or deleted
+// afterwards, and registers it for zexe_bn382_fq_proof_verify_bytes. Returns
+// its digest.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_register(
+    index: *mut DlogVerifierIndex<'static, GAffine>,
+) -> *mut UrsDigest {
+    const NAME: &str = "zexe_bn382_fq_verifier_index_register";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = match take_handle(NAME, index) {
+            Some(index) => index,
+            None => return std::ptr::null_mut(),
+        };
+        let digest = fq_verifier_index_digest(&index);
+        FQ_REGISTERED_VERIFIER_INDEXES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(digest, Arc::from(index));
+        into_handle(Box::new(digest))
+    })
+}
+
+// Unregisters the verifier index with the given digest, which is freed once
+// the verifications already using it finish. Returns false if there is none.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_verifier_index_unregister(digest: *const UrsDigest) -> bool {
+    catch_ffi("zexe_bn382_fq_verifier_index_unregister", false, || {
+        let digest = handle(digest);
+        FQ_REGISTERED_VERIFIER_INDEXES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(unsafe { &*digest })
+            .is_some()
+    })
+}
+
+// Bounds on the resources a proof may make the verifier spend, for
+// zexe_bn382_fq_proof_verify_bytes_bounded.
+struct VerifyLimits {
+    max_proof_bytes: usize,
+    max_public_inputs: usize,
+    max_lr_rounds: usize,
+}
+
+fn fq_proof_verify_bytes(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &[u8],
+    public_input: &[u8],
+    limits: Option<&VerifyLimits>,
+) -> i32 {
+    // The limits on sizes are checked before anything is decoded, so that
+    // decoding stays proportional to them.
+    let element_size = <<Fq as PrimeField>::BigInt as BigInteger>::NUM_LIMBS * 8;
+    if let Some(l) = limits {
+        if proof.len() > l.max_proof_bytes || public_input.len() / element_size > l.max_public_inputs {
+            return VERIFY_BYTES_REJECTED_LIMITS;
         }
-    };
-
-    let rng = &mut rand_core::OsRng;
-
-    let map = <Affine as CommitmentCurve>::Map::setup();
-    let proof =
-        DlogProof::create::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
-            &map, &witness, &index, prev, rng,
-        )
-        .unwrap();
+    }
 
-    return Box::into_raw(Box::new(proof));
-}
+    let public = match read_field_elements::<Fq>(public_input) {
+        Ok(public) => public,
+        Err(_) => return VERIFY_BYTES_MALFORMED_PUBLIC_INPUT,
+    };
+    let mut r = proof;
+    let proof = match read_dlog_proof::<GAffine, _, _>(&mut r, public, fq_proof_point_valid) {
+        Ok(proof) if r.is_empty() => proof,
+        _ => return VERIFY_BYTES_MALFORMED_PROOF,
+    };
+    if let Some(l) = limits {
+        if proof.proof.lr.len() > l.max_lr_rounds
+            || proof.prev_challenges.iter().any(|(c, _)| c.len() > l.max_lr_rounds)
+        {
+            return VERIFY_BYTES_REJECTED_LIMITS;
+        }
+    }
+    if fq_proof_structure_error(index, &proof).is_some() {
+        return VERIFY_BYTES_MALFORMED_PROOF;
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_verify(
-    index: *const DlogVerifierIndex<GAffine>,
-    proof: *const DlogProof<GAffine>,
-) -> bool {
-    let index = unsafe { &(*index) };
-    let proof = unsafe { (*proof).clone() };
     let group_map = <Affine as CommitmentCurve>::Map::setup();
-
-    DlogProof::verify::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
+    if DlogProof::verify::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
         &group_map,
         &[(index, proof)].to_vec(),
-        &mut rand_core::OsRng,
-    )
+        &mut system_rng(),
+    ) {
+        VERIFY_BYTES_VALID
+    } else {
+        VERIFY_BYTES_INVALID
+    }
+}
+
+fn fq_proof_verify_bytes_of_digest(
+    index_digest: *const u8,
+    proof: *const u8,
+    proof_len: usize,
+    public_input: *const u8,
+    public_input_len: usize,
+    limits: Option<&VerifyLimits>,
+) -> i32 {
+    let mut digest = [0u8; URS_DIGEST_SIZE];
+    digest.copy_from_slice(unsafe { std::slice::from_raw_parts(index_digest, URS_DIGEST_SIZE) });
+    let index = match fq_registered_verifier_index(&digest) {
+        Some(index) => index,
+        None => return VERIFY_BYTES_UNKNOWN_INDEX,
+    };
+    let proof = unsafe { std::slice::from_raw_parts(proof, proof_len) };
+    let public_input = unsafe { std::slice::from_raw_parts(public_input, public_input_len) };
+    fq_proof_verify_bytes(&index, proof, public_input, limits)
+}
+
+// Verifies a proof serialized by zexe_bn382_fq_proof_to_bytes against
+// public inputs given as concatenated field element serializations. The
+// verifier index is the registered one whose digest is the URS_DIGEST_SIZE
+// bytes at index_digest. The inputs are read in place and no handles are
+// created, so the common "verify this blob" case costs a single call.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_verify_bytes(
+    index_digest: *const u8,
+    proof: *const u8,
+    proof_len: usize,
+    public_input: *const u8,
+    public_input_len: usize,
+) -> i32 {
+    catch_ffi("zexe_bn382_fq_proof_verify_bytes", VERIFY_BYTES_INVALID, || {
+        fq_proof_verify_bytes_of_digest(
+            index_digest,
+            proof,
+            proof_len,
+            public_input,
+            public_input_len,
+            None,
+        )
+    })
+}
+
+// As zexe_bn382_fq_proof_verify_bytes, for verifiers facing untrusted input.
+// Returns VERIFY_BYTES_REJECTED_LIMITS, without verifying, if the proof is
+// longer than max_proof_bytes, has more than max_public_inputs public inputs,
+// or has an opening proof or previous accumulators of more than max_lr_rounds
+// rounds.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_verify_bytes_bounded(
+    index_digest: *const u8,
+    proof: *const u8,
+    proof_len: usize,
+    public_input: *const u8,
+    public_input_len: usize,
+    max_proof_bytes: usize,
+    max_public_inputs: usize,
+    max_lr_rounds: usize,
+) -> i32 {
+    catch_ffi("zexe_bn382_fq_proof_verify_bytes_bounded", VERIFY_BYTES_INVALID, || {
+        let limits = VerifyLimits {
+            max_proof_bytes,
+            max_public_inputs,
+            max_lr_rounds,
+        };
+        fq_proof_verify_bytes_of_digest(
+            index_digest,
+            proof,
+            proof_len,
+            public_input,
+            public_input_len,
+            Some(&limits),
+        )
+    })
 }
 
-// TODO: Batch verify across different indexes
+// Serializes a proof, without its public input, for
+// zexe_bn382_fq_proof_verify_bytes.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_batch_verify(
-    indexes: *const Vec<*const DlogVerifierIndex<GAffine>>,
-    proofs: *const Vec<DlogProof<GAffine>>,
-) -> bool {
-    let indexes = unsafe { &(*indexes) };
-    let proofs = unsafe { &(*proofs) };
-    let group_map = <Affine as CommitmentCurve>::Map::setup();
-    // TODO: Don't clone
-    let v: Vec<_> = indexes
-        .iter()
-        .zip(proofs.iter())
-        .map(|(&index, p)| (unsafe { &(*index) }, p.clone()))
-        .collect();
-    DlogProof::<GAffine>::verify::<DefaultFqSponge<Bn_382GParameters, SC>, DefaultFrSponge<Fq, SC>>(
-        &group_map,
-        &v,
-        &mut rand_core::OsRng,
-    )
+pub extern "C" fn zexe_bn382_fq_proof_to_bytes(proof: *const DlogProof<GAffine>) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fq_proof_to_bytes", std::ptr::null_mut(), || {
+        let proof = handle(proof);
+        let proof = unsafe { &*proof };
+        let mut res = vec![];
+        write_dlog_proof(proof, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
 }
 
 #[no_mangle]
@@ -1445,7 +4665,7 @@ pub extern "C" fn zexe_bn382_fq_proof_make(
     sigma2: *const Fq,
     sigma3: *const Fq,
 
-    lr: *const Vec<(GAffine, GAffine)>,
+    lr: *const Vec<AffinePair<GAffine>>,
     z1: *const Fq,
     z2: *const Fq,
     delta: *const GAffine,
@@ -1458,183 +4678,358 @@ pub extern "C" fn zexe_bn382_fq_proof_make(
     prev_challenges: *const Vec<Fq>,
     prev_sgs: *const Vec<GAffine>,
 ) -> *const DlogProof<GAffine> {
-    let public = unsafe { &(*primary_input) }.clone();
-    // public.resize(ceil_pow2(public.len()), Fq::zero());
-
-    let prev: Vec<(Vec<Fq>, PolyComm<GAffine>)> = {
-        let prev_challenges = unsafe { &*prev_challenges };
-        let prev_sgs = unsafe { &*prev_sgs };
-        if prev_challenges.len() == 0 {
-            Vec::new()
-        } else {
-            let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
-            prev_sgs
-                .iter()
-                .enumerate()
-                .map(|(i, sg)| {
-                    (
-                        prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
-                            .iter()
-                            .map(|x| *x)
-                            .collect(),
-                        PolyComm::<GAffine> {
-                            unshifted: vec![sg.clone()],
-                            shifted: None,
-                        },
-                    )
-                })
-                .collect()
-        }
-    };
+    catch_ffi("zexe_bn382_fq_proof_make", std::ptr::null(), || {
+        let primary_input = handle(primary_input);
+        let w_comm = handle(w_comm);
+        let za_comm = handle(za_comm);
+        let zb_comm = handle(zb_comm);
+        let h1_comm = handle(h1_comm);
+        let g1_comm = handle(g1_comm);
+        let h2_comm = handle(h2_comm);
+        let g2_comm = handle(g2_comm);
+        let h3_comm = handle(h3_comm);
+        let g3_comm = handle(g3_comm);
+        let sigma2 = handle(sigma2);
+        let sigma3 = handle(sigma3);
+        let lr = handle(lr);
+        let z1 = handle(z1);
+        let z2 = handle(z2);
+        let delta = handle(delta);
+        let sg = handle(sg);
+        let evals0 = handle(evals0);
+        let evals1 = handle(evals1);
+        let evals2 = handle(evals2);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let public = unsafe { &(*primary_input) }.clone();
+        // public.resize(ceil_pow2(public.len()), Fq::zero());
+
+        let prev = match prev_challenges_of_flat(
+            unsafe { &*prev_challenges },
+            unsafe { &*prev_sgs },
+            unsafe { &*lr }.len(),
+        ) {
+            Ok(prev) => prev,
+            Err(e) => return null_with_error(format!("zexe_bn382_fq_proof_make: {}", e)),
+        };
+
+        let res = DlogProof {
+            prev_challenges: prev,
+            proof: OpeningProof {
+                lr: (unsafe { &*lr }).clone(),
+                z1: (unsafe { *z1 }).clone(),
+                z2: (unsafe { *z2 }).clone(),
+                delta: (unsafe { *delta }).clone(),
+                sg: (unsafe { *sg }).clone(),
+            },
+            w_comm: (unsafe { &*w_comm }).clone(),
+            za_comm: (unsafe { &*za_comm }).clone(),
+            zb_comm: (unsafe { &*zb_comm }).clone(),
+            h1_comm: (unsafe { &*h1_comm }).clone(),
+            g1_comm: (unsafe { &*g1_comm }).clone(),
+            h2_comm: (unsafe { &*h2_comm }).clone(),
+            g2_comm: (unsafe { &*g2_comm }).clone(),
+            h3_comm: (unsafe { &*h3_comm }).clone(),
+            g3_comm: (unsafe { &*g3_comm }).clone(),
+
+            sigma2: (unsafe { *sigma2 }).clone(),
+            sigma3: (unsafe { *sigma3 }).clone(),
+
+            public,
+            evals: [
+                (unsafe { &*evals0 }).clone(),
+                (unsafe { &*evals1 }).clone(),
+                (unsafe { &*evals2 }).clone(),
+            ],
+        };
+        return into_handle(Box::new(res));
+    })
+}
+
+// Describes the first way in which a proof does not have the shape the
+// verifier index expects, if any. This is cheap compared to verification.
+fn fq_proof_structure_error(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> Option<String> {
+    if proof.public.len() != index.public_inputs {
+        return Some(format!(
+            "The proof has {} public inputs, the index expects {}.",
+            proof.public.len(),
+            index.public_inputs
+        ));
+    }
 
-    let res = DlogProof {
-        prev_challenges: prev,
-        proof: OpeningProof {
-            lr: (unsafe { &*lr }).clone(),
-            z1: (unsafe { *z1 }).clone(),
-            z2: (unsafe { *z2 }).clone(),
-            delta: (unsafe { *delta }).clone(),
-            sg: (unsafe { *sg }).clone(),
-        },
-        w_comm: (unsafe { &*w_comm }).clone(),
-        za_comm: (unsafe { &*za_comm }).clone(),
-        zb_comm: (unsafe { &*zb_comm }).clone(),
-        h1_comm: (unsafe { &*h1_comm }).clone(),
-        g1_comm: (unsafe { &*g1_comm }).clone(),
-        h2_comm: (unsafe { &*h2_comm }).clone(),
-        g2_comm: (unsafe { &*g2_comm }).clone(),
-        h3_comm: (unsafe { &*h3_comm }).clone(),
-        g3_comm: (unsafe { &*g3_comm }).clone(),
-
-        sigma2: (unsafe { *sigma2 }).clone(),
-        sigma3: (unsafe { *sigma3 }).clone(),
-
-        public,
-        evals: [
-            (unsafe { &*evals0 }).clone(),
-            (unsafe { &*evals1 }).clone(),
-            (unsafe { &*evals2 }).clone(),
-        ],
-    };
-    return Box::into_raw(Box::new(res));
+    let rounds = lr_rounds(index.srs.get_ref());
+    if proof.proof.lr.len() != rounds {
+        return Some(format!(
+            "The opening proof has {} rounds, the SRS requires {}.",
+            proof.proof.lr.len(),
+            rounds
+        ));
+    }
+    if let Some((chals, _)) = proof.prev_challenges.iter().find(|(c, _)| c.len() != rounds) {
+        return Some(format!(
+            "A previous accumulator has {} challenges, the SRS requires {}.",
+            chals.len(),
+            rounds
+        ));
+    }
+
+    // Each evaluation has one value per chunk of the committed polynomial.
+    for (k, e) in proof.evals.iter().enumerate() {
+        let polys = [
+            ("w", &e.w, &proof.w_comm),
+            ("za", &e.za, &proof.za_comm),
+            ("zb", &e.zb, &proof.zb_comm),
+            ("h1", &e.h1, &proof.h1_comm),
+            ("g1", &e.g1, &proof.g1_comm),
+            ("h2", &e.h2, &proof.h2_comm),
+            ("g2", &e.g2, &proof.g2_comm),
+            ("h3", &e.h3, &proof.h3_comm),
+            ("g3", &e.g3, &proof.g3_comm),
+        ];
+        for (name, evals, comm) in polys.iter() {
+            if evals.len() != comm.unshifted.len() {
+                return Some(format!(
+                    "Evaluation {} of {} has {} chunks, its commitment has {}.",
+                    k,
+                    name,
+                    evals.len(),
+                    comm.unshifted.len()
+                ));
+            }
+        }
+        for (j, m) in index.matrix_commitments.iter().enumerate() {
+            let polys = [
+                ("row", &e.row[j], &m.row),
+                ("col", &e.col[j], &m.col),
+                ("val", &e.val[j], &m.val),
+                ("rc", &e.rc[j], &m.rc),
+            ];
+            for (name, evals, comm) in polys.iter() {
+                if evals.len() != comm.unshifted.len() {
+                    return Some(format!(
+                        "Evaluation {} of {} {} has {} chunks, its commitment has {}.",
+                        k,
+                        name,
+                        j,
+                        evals.len(),
+                        comm.unshifted.len()
+                    ));
+                }
+            }
+        }
+    }
+    None
 }
 
+// Checks that a proof has the shape the verifier index expects before it is
+// verified: the number of public inputs, the number of opening rounds and the
+// number of chunks of each evaluation. On failure the reason is recorded as
+// the last error.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_delete(x: *mut DlogProof<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fq_proof_validate_structure(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fq_proof_validate_structure", false, || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
+        match fq_proof_structure_error(index, proof) {
+            None => true,
+            Some(e) => {
+                set_last_error(format!("zexe_bn382_fq_proof_validate_structure: {}", e));
+                false
+            }
+        }
+    })
 }
 
+crate::delete_stub!(zexe_bn382_fq_proof_delete, DlogProof<GAffine>);
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_w_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).w_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_w_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).w_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_za_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).za_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_za_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).za_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_zb_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).zb_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_zb_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).zb_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_h1_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).h1_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_h1_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).h1_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_g1_comm_nocopy(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &(*p).g1_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_g1_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).g1_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_h2_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).h2_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_h2_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).h2_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_g2_comm_nocopy(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &(*p).g2_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_g2_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).g2_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_h3_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &(*p).h3_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_h3_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).h3_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_g3_comm_nocopy(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &(*p).g3_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_g3_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).g3_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_sigma2(p: *mut DlogProof<GAffine>) -> *const Fq {
-    let x = (unsafe { (*p).sigma2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_sigma2", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).sigma2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_sigma3(p: *mut DlogProof<GAffine>) -> *const Fq {
-    let x = (unsafe { (*p).sigma3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_sigma3", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).sigma3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_proof(
     p: *mut DlogProof<GAffine>,
 ) -> *const OpeningProof<GAffine> {
-    let x = (unsafe { &(*p).proof }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_proof", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).proof }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evals_nocopy(
     p: *mut DlogProof<GAffine>,
 ) -> *const [DlogProofEvaluations<Fq>; 3] {
-    let x = (unsafe { &(*p).evals }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).evals }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+// The challenges of the previous accumulators of the proof, one accumulator
+// after the other, as zexe_bn382_fq_proof_make takes them.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_prev_challenges(
+    p: *const DlogProof<GAffine>,
+) -> *const Vec<Fq> {
+    catch_ffi("zexe_bn382_fq_proof_prev_challenges", std::ptr::null(), || {
+        let p = handle(p);
+        let (chals, _) = prev_challenges_to_flat(unsafe { &(*p).prev_challenges });
+        return into_handle(Box::new(chals));
+    })
+}
+
+// The sg points of the previous accumulators of the proof.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_prev_sgs(
+    p: *const DlogProof<GAffine>,
+) -> *const Vec<GAffine> {
+    catch_ffi("zexe_bn382_fq_proof_prev_sgs", std::ptr::null(), || {
+        let p = handle(p);
+        let (_, sgs) = prev_challenges_to_flat(unsafe { &(*p).prev_challenges });
+        return into_handle(Box::new(sgs));
+    })
 }
 
 // Fq proof vector
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_vector_create() -> *mut Vec<DlogProof<GAffine>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fq_proof_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_vector_length(v: *const Vec<DlogProof<GAffine>>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fq_proof_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -1642,9 +5037,13 @@ pub extern "C" fn zexe_bn382_fq_proof_vector_emplace_back(
     v: *mut Vec<DlogProof<GAffine>>,
     x: *const DlogProof<GAffine>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(x_.clone());
+    catch_ffi("zexe_bn382_fq_proof_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(x_.clone());
+    })
 }
 
 #[no_mangle]
@@ -1652,59 +5051,67 @@ pub extern "C" fn zexe_bn382_fq_proof_vector_get(
     v: *mut Vec<DlogProof<GAffine>>,
     i: u32,
 ) -> *mut DlogProof<GAffine> {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize].clone()));
+    catch_ffi("zexe_bn382_fq_proof_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize].clone()));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_vector_delete(v: *mut Vec<DlogProof<GAffine>>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_bn382_fq_proof_vector_delete, Vec<DlogProof<GAffine>>);
 
 // Fq opening proof
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_opening_proof_delete(p: *mut OpeningProof<GAffine>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(p) };
-}
+crate::delete_stub!(zexe_bn382_fq_opening_proof_delete, OpeningProof<GAffine>);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_opening_proof_sg(
     p: *const OpeningProof<GAffine>,
 ) -> *const GAffine {
-    let x = (unsafe { &(*p).sg }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_opening_proof_sg", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).sg }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_opening_proof_lr(
     p: *const OpeningProof<GAffine>,
-) -> *const Vec<(GAffine, GAffine)> {
-    let x = (unsafe { &(*p).lr }).clone();
-    return Box::into_raw(Box::new(x));
+) -> *const Vec<AffinePair<GAffine>> {
+    catch_ffi("zexe_bn382_fq_opening_proof_lr", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).lr }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_opening_proof_z1(p: *const OpeningProof<GAffine>) -> *const Fq {
-    let x = (unsafe { &(*p).z1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_opening_proof_z1", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).z1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_opening_proof_z2(p: *const OpeningProof<GAffine>) -> *const Fq {
-    let x = (unsafe { &(*p).z2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_opening_proof_z2", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).z2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_opening_proof_delta(
     p: *const OpeningProof<GAffine>,
 ) -> *const GAffine {
-    let x = (unsafe { &(*p).delta }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_opening_proof_delta", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).delta }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 // Fq proof evaluations
@@ -1713,136 +5120,179 @@ pub extern "C" fn zexe_bn382_fq_opening_proof_delta(
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_w(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).w }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_w", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).w }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_za(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).za }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_za", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).za }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_zb(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).zb }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_zb", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).zb }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_h1(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).h1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_h1", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).h1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_h2(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).h2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_h2", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).h2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_h3(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).h3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_h3", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).h3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_g1(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).g1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_g1", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).g1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_g2(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).g2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_g2", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).g2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_g3(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const Vec<Fq> {
-    let x = (unsafe { &(*e).g3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_g3", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).g3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_row_nocopy(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const [Vec<Fq>; 3] {
-    let x = (unsafe { &(*e).row }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_row_nocopy", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).row }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_val_nocopy(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const [Vec<Fq>; 3] {
-    let x = (unsafe { &(*e).val }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_val_nocopy", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).val }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_col_nocopy(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const [Vec<Fq>; 3] {
-    let x = (unsafe { &(*e).col }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_col_nocopy", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).col }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_rc_nocopy(
     e: *const DlogProofEvaluations<Fq>,
 ) -> *const [Vec<Fq>; 3] {
-    let x = (unsafe { &(*e).rc }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_rc_nocopy", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).rc }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_triple_0(
     e: *const [DlogProofEvaluations<Fq>; 3],
 ) -> *const DlogProofEvaluations<Fq> {
-    let x = (unsafe { &(*e)[0] }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_triple_0", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e)[0] }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_triple_1(
     e: *const [DlogProofEvaluations<Fq>; 3],
 ) -> *const DlogProofEvaluations<Fq> {
-    let x = (unsafe { &(*e)[1] }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_triple_1", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e)[1] }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_triple_2(
     e: *const [DlogProofEvaluations<Fq>; 3],
 ) -> *const DlogProofEvaluations<Fq> {
-    let x = (unsafe { &(*e)[2] }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fq_proof_evaluations_triple_2", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e)[2] }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_evaluations_triple_delete(
-    x: *mut [DlogProofEvaluations<Fq>; 3],
-) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_proof_evaluations_triple_delete, [DlogProofEvaluations<Fq>; 3]);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_proof_evaluations_make(
@@ -1872,62 +5322,235 @@ pub extern "C" fn zexe_bn382_fq_proof_evaluations_make(
     rc_1: *const Vec<Fq>,
     rc_2: *const Vec<Fq>,
 ) -> *const DlogProofEvaluations<Fq> {
-    let res: DlogProofEvaluations<Fq> = DlogProofEvaluations {
-        w: (unsafe { &*w }).clone(),
-        za: (unsafe { &*za }).clone(),
-        zb: (unsafe { &*zb }).clone(),
-        g1: (unsafe { &*g1 }).clone(),
-        g2: (unsafe { &*g2 }).clone(),
-        g3: (unsafe { &*g3 }).clone(),
-        h1: (unsafe { &*h1 }).clone(),
-        h2: (unsafe { &*h2 }).clone(),
-        h3: (unsafe { &*h3 }).clone(),
-        row: [
-            (unsafe { &*row_0 }).clone(),
-            (unsafe { &*row_1 }).clone(),
-            (unsafe { &*row_2 }).clone(),
-        ],
-        col: [
-            (unsafe { &*col_0 }).clone(),
-            (unsafe { &*col_1 }).clone(),
-            (unsafe { &*col_2 }).clone(),
-        ],
-        val: [
-            (unsafe { &*val_0 }).clone(),
-            (unsafe { &*val_1 }).clone(),
-            (unsafe { &*val_2 }).clone(),
-        ],
-        rc: [
-            (unsafe { &*rc_0 }).clone(),
-            (unsafe { &*rc_1 }).clone(),
-            (unsafe { &*rc_2 }).clone(),
-        ],
+    catch_ffi("zexe_bn382_fq_proof_evaluations_make", std::ptr::null(), || {
+        let w = handle(w);
+        let za = handle(za);
+        let zb = handle(zb);
+        let h1 = handle(h1);
+        let g1 = handle(g1);
+        let h2 = handle(h2);
+        let g2 = handle(g2);
+        let h3 = handle(h3);
+        let g3 = handle(g3);
+        let row_0 = handle(row_0);
+        let row_1 = handle(row_1);
+        let row_2 = handle(row_2);
+        let col_0 = handle(col_0);
+        let col_1 = handle(col_1);
+        let col_2 = handle(col_2);
+        let val_0 = handle(val_0);
+        let val_1 = handle(val_1);
+        let val_2 = handle(val_2);
+        let rc_0 = handle(rc_0);
+        let rc_1 = handle(rc_1);
+        let rc_2 = handle(rc_2);
+        let res: DlogProofEvaluations<Fq> = DlogProofEvaluations {
+            w: (unsafe { &*w }).clone(),
+            za: (unsafe { &*za }).clone(),
+            zb: (unsafe { &*zb }).clone(),
+            g1: (unsafe { &*g1 }).clone(),
+            g2: (unsafe { &*g2 }).clone(),
+            g3: (unsafe { &*g3 }).clone(),
+            h1: (unsafe { &*h1 }).clone(),
+            h2: (unsafe { &*h2 }).clone(),
+            h3: (unsafe { &*h3 }).clone(),
+            row: [
+                (unsafe { &*row_0 }).clone(),
+                (unsafe { &*row_1 }).clone(),
+                (unsafe { &*row_2 }).clone(),
+            ],
+            col: [
+                (unsafe { &*col_0 }).clone(),
+                (unsafe { &*col_1 }).clone(),
+                (unsafe { &*col_2 }).clone(),
+            ],
+            val: [
+                (unsafe { &*val_0 }).clone(),
+                (unsafe { &*val_1 }).clone(),
+                (unsafe { &*val_2 }).clone(),
+            ],
+            rc: [
+                (unsafe { &*rc_0 }).clone(),
+                (unsafe { &*rc_1 }).clone(),
+                (unsafe { &*rc_2 }).clone(),
+            ],
+        };
+
+        return into_handle(Box::new(res));
+    })
+}
+
+// Fq proof evaluations builder
+//
+// Builds proof evaluations field by field, as zexe_bn382_fq_proof_evaluations_make
+// does from its 21 positional arguments, which are easy to transpose. Each
+// setter copies the vector, and may be called again to replace it. Finishing
+// fails, naming the missing fields, until every field is set.
+#[derive(Default)]
+pub struct FqProofEvaluationsBuilder {
+    w: Option<Vec<Fq>>,
+    za: Option<Vec<Fq>>,
+    zb: Option<Vec<Fq>>,
+    h1: Option<Vec<Fq>>,
+    g1: Option<Vec<Fq>>,
+    h2: Option<Vec<Fq>>,
+    g2: Option<Vec<Fq>>,
+    h3: Option<Vec<Fq>>,
+    g3: Option<Vec<Fq>>,
+    row: [Option<Vec<Fq>>; 3],
+    col: [Option<Vec<Fq>>; 3],
+    val: [Option<Vec<Fq>>; 3],
+    rc: [Option<Vec<Fq>>; 3],
+}
+
+impl FqProofEvaluationsBuilder {
+    fn finish(&self) -> Result<DlogProofEvaluations<Fq>, String> {
+        let mut missing = vec![];
+        let mut get = |name: String, v: &Option<Vec<Fq>>| match v {
+            Some(v) => v.clone(),
+            None => {
+                missing.push(name);
+                vec![]
+            }
+        };
+        let mut triple = |name: &str, t: &[Option<Vec<Fq>>; 3]| {
+            [
+                get(format!("{}_0", name), &t[0]),
+                get(format!("{}_1", name), &t[1]),
+                get(format!("{}_2", name), &t[2]),
+            ]
+        };
+        let row = triple("row", &self.row);
+        let col = triple("col", &self.col);
+        let val = triple("val", &self.val);
+        let rc = triple("rc", &self.rc);
+        let res = DlogProofEvaluations {
+            w: get("w".to_string(), &self.w),
+            za: get("za".to_string(), &self.za),
+            zb: get("zb".to_string(), &self.zb),
+            h1: get("h1".to_string(), &self.h1),
+            g1: get("g1".to_string(), &self.g1),
+            h2: get("h2".to_string(), &self.h2),
+            g2: get("g2".to_string(), &self.g2),
+            h3: get("h3".to_string(), &self.h3),
+            g3: get("g3".to_string(), &self.g3),
+            row,
+            col,
+            val,
+            rc,
+        };
+        if missing.is_empty() {
+            Ok(res)
+        } else {
+            Err(format!("Missing fields: {}.", missing.join(", ")))
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_evaluations_builder_create() -> *mut FqProofEvaluationsBuilder
+{
+    catch_ffi("zexe_bn382_fq_proof_evaluations_builder_create", std::ptr::null_mut(), || {
+        into_handle(Box::new(FqProofEvaluationsBuilder::default()))
+    })
+}
+
+macro_rules! evaluations_setter {
+    ($name:ident, $field:ident) => {
+        #[no_mangle]
+        pub extern "C" fn $name(b: *mut FqProofEvaluationsBuilder, v: *const Vec<Fq>) {
+            $crate::common::catch_ffi(stringify!($name), (), || {
+                let b = $crate::common::handle(b);
+                let v = $crate::common::handle(v);
+                let b = unsafe { &mut *b };
+                b.$field = Some((unsafe { &*v }).clone());
+            })
+        }
     };
+}
 
-    return Box::into_raw(Box::new(res));
+macro_rules! evaluations_triple_setter {
+    ($name:ident, $field:ident) => {
+        // Returns false, with the last error set, unless i is 0, 1 or 2.
+        #[no_mangle]
+        pub extern "C" fn $name(
+            b: *mut FqProofEvaluationsBuilder,
+            i: usize,
+            v: *const Vec<Fq>,
+        ) -> bool {
+            $crate::common::catch_ffi(stringify!($name), false, || {
+                let b = $crate::common::handle(b);
+                let v = $crate::common::handle(v);
+                let b = unsafe { &mut *b };
+                if i >= 3 {
+                    set_last_error(format!("{}: Index {} out of 0..3.", stringify!($name), i));
+                    return false;
+                }
+                b.$field[i] = Some((unsafe { &*v }).clone());
+                true
+            })
+        }
+    };
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_proof_evaluations_delete(x: *mut DlogProofEvaluations<Fq>) {
-    let _box = unsafe { Box::from_raw(x) };
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_w, w);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_za, za);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_zb, zb);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_h1, h1);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_g1, g1);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_h2, h2);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_g2, g2);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_h3, h3);
+evaluations_setter!(zexe_bn382_fq_proof_evaluations_builder_set_g3, g3);
+evaluations_triple_setter!(zexe_bn382_fq_proof_evaluations_builder_set_row, row);
+evaluations_triple_setter!(zexe_bn382_fq_proof_evaluations_builder_set_col, col);
+evaluations_triple_setter!(zexe_bn382_fq_proof_evaluations_builder_set_val, val);
+evaluations_triple_setter!(zexe_bn382_fq_proof_evaluations_builder_set_rc, rc);
+
+// Returns new proof evaluations, or null with the last error naming the
+// missing fields. The builder is left as it is.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_evaluations_builder_finish(
+    b: *const FqProofEvaluationsBuilder,
+) -> *mut DlogProofEvaluations<Fq> {
+    catch_ffi("zexe_bn382_fq_proof_evaluations_builder_finish", std::ptr::null_mut(), || {
+        let b = handle(b);
+        match (unsafe { &*b }).finish() {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!(
+                "zexe_bn382_fq_proof_evaluations_builder_finish: {}",
+                e
+            )),
+        }
+    })
 }
 
+crate::delete_stub!(zexe_bn382_fq_proof_evaluations_builder_delete, FqProofEvaluationsBuilder);
+
+crate::delete_stub!(zexe_bn382_fq_proof_evaluations_delete, DlogProofEvaluations<Fq>);
+
 // fq poly comm
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_unshifted(
     c: *const PolyComm<GAffine>,
 ) -> *const Vec<GAffine> {
-    let c = unsafe { &(*c) };
-    return Box::into_raw(Box::new(c.unshifted.clone()));
+    catch_ffi("zexe_bn382_fq_poly_comm_unshifted", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &(*c) };
+        return into_handle(Box::new(c.unshifted.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_shifted(c: *const PolyComm<GAffine>) -> *const GAffine {
-    let c = unsafe { &(*c) };
-    match c.shifted {
-        Some(g) => Box::into_raw(Box::new(g.clone())),
-        None => std::ptr::null(),
-    }
+    catch_ffi("zexe_bn382_fq_poly_comm_shifted", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &(*c) };
+        match c.shifted {
+            Some(g) => into_handle(Box::new(g.clone())),
+            None => std::ptr::null(),
+        }
+    })
 }
 
 #[no_mangle]
@@ -1935,57 +5558,327 @@ pub extern "C" fn zexe_bn382_fq_poly_comm_make(
     unshifted: *const Vec<GAffine>,
     shifted: *const GAffine,
 ) -> *const PolyComm<GAffine> {
-    let unsh = unsafe { &(*unshifted) };
-
-    let commitment = PolyComm {
-        unshifted: unsh.clone(),
-        shifted: if shifted == std::ptr::null() {
-            None
-        } else {
-            Some({
-                let sh = unsafe { &(*shifted) };
-                *sh
-            })
-        },
-    };
+    catch_ffi("zexe_bn382_fq_poly_comm_make", std::ptr::null(), || {
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        let unsh = unsafe { &(*unshifted) };
+
+        let commitment = PolyComm {
+            unshifted: unsh.clone(),
+            shifted: if shifted == std::ptr::null() {
+                None
+            } else {
+                Some({
+                    let sh = unsafe { &(*shifted) };
+                    *sh
+                })
+            },
+        };
 
-    Box::into_raw(Box::new(commitment))
+        into_handle(Box::new(commitment))
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_poly_comm_delete(c: *mut PolyComm<GAffine>) {
-    let _box = unsafe { Box::from_raw(c) };
-}
+crate::delete_stub!(zexe_bn382_fq_poly_comm_delete, PolyComm<GAffine>);
 
 // Fq poly comm vector stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_vector_create() -> *mut Vec<PolyComm<GAffine>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fq_poly_comm_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_vector_length(v: *const Vec<PolyComm<GAffine>>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fq_poly_comm_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_vector_emplace_back(v: *mut Vec<PolyComm<GAffine>>, x: *const PolyComm<GAffine>) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(x_.clone());
+    catch_ffi("zexe_bn382_fq_poly_comm_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(x_.clone());
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_poly_comm_vector_get(v: *mut Vec<PolyComm<GAffine>>, i: u32) -> *mut PolyComm<GAffine> {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize].clone()));
+    catch_ffi("zexe_bn382_fq_poly_comm_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize].clone()));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_poly_comm_vector_delete(v: *mut Vec<PolyComm<GAffine>>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+crate::delete_stub!(zexe_bn382_fq_poly_comm_vector_delete, Vec<PolyComm<GAffine>>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_opening_accumulators_hold() {
+        let srs = SRS::<GAffine>::create(4);
+        let acc = fq_opening_accumulator(&srs, &[], &[], &[], &[], Fq::one()).unwrap();
+        assert!(acc.pending.is_zero());
+        assert!(fq_opening_accumulator_holds(&srs, &acc));
+    }
+
+    #[test]
+    fn opening_accumulators_check_the_pending_point() {
+        let srs = SRS::<GAffine>::create(4);
+        let chals: Vec<Fq> = (0..lr_rounds(&srs))
+            .map(|_| Fq::rand(&mut system_rng()))
+            .collect();
+        let b = DensePolynomial::from_coefficients_vec(b_poly_coefficients(&chals));
+        let mut acc = FqOpeningAccumulator {
+            pending: srs.commit(&b, None).unshifted[0],
+            challenges: chals,
+            r: Fq::rand(&mut system_rng()),
+        };
+        assert!(fq_opening_accumulator_holds(&srs, &acc));
+        acc.pending = GAffine::prime_subgroup_generator();
+        assert!(!fq_opening_accumulator_holds(&srs, &acc));
+        acc.challenges.pop();
+        assert!(!fq_opening_accumulator_holds(&srs, &acc));
+    }
+
+    #[test]
+    fn opening_accumulators_need_matching_lengths() {
+        let srs = SRS::<GAffine>::create(4);
+        let comm = GAffine::prime_subgroup_generator();
+        assert!(fq_opening_accumulator(&srs, &[comm], &[], &[], &[], Fq::one()).is_err());
+    }
+
+    // The index of a circuit with the public inputs 1 and x, the auxiliary
+    // input y and the constraint x * x = y, over an SRS of the given depth,
+    // and the proof for x.
+    struct Circuit {
+        srs: *mut SRS<GAffine>,
+        index: *mut DlogIndex<'static, GAffine>,
+        verifier_index: *const DlogVerifierIndex<'static, GAffine>,
+        proof: *const DlogProof<GAffine>,
+    }
+
+    impl Circuit {
+        fn new(depth: usize, x: u64) -> Self {
+            let row = |i: usize| into_handle(Box::new(vec![(vec![i], vec![Fq::one()])]));
+            let srs = into_handle(Box::new(SRS::<GAffine>::create(depth)));
+            let index = zexe_bn382_fq_index_create(row(1), row(1), row(2), 3, 2, srs);
+            let x = Fq::from(x);
+            let proof = zexe_bn382_fq_proof_create(
+                index,
+                into_handle(Box::new(vec![x])),
+                into_handle(Box::new(vec![x * &x])),
+                into_handle(Box::new(vec![])),
+                into_handle(Box::new(vec![])),
+            );
+            assert!(!proof.is_null());
+            Circuit {
+                srs,
+                index,
+                verifier_index: zexe_bn382_fq_verifier_index_create(index),
+                proof,
+            }
+        }
+    }
+
+    impl Drop for Circuit {
+        fn drop(&mut self) {
+            release_handle("test", self.proof as *mut DlogProof<GAffine>);
+            release_handle(
+                "test",
+                self.verifier_index as *mut DlogVerifierIndex<GAffine>,
+            );
+            release_handle("test", self.index);
+            release_handle("test", self.srs);
+        }
+    }
+
+    fn results(r: &[*mut FqVerifyPhase1]) -> *const Vec<*const FqVerifyPhase1> {
+        into_handle(Box::new(r.iter().map(|&r| r as *const _).collect()))
+    }
+
+    #[test]
+    fn phase_split_verification_checks_the_openings() {
+        let c = Circuit::new(64, 3);
+        let r = zexe_bn382_fq_proof_verify_phase1(c.verifier_index, c.proof);
+        assert!(!r.is_null());
+        assert!(zexe_bn382_fq_proof_verify_phase2(
+            c.verifier_index,
+            results(&[r])
+        ));
+        let proof = unsafe { &*c.proof };
+        assert!(fq_proof_batch_verify(&[(
+            unsafe { &*c.verifier_index },
+            proof
+        )]));
+
+        unsafe { (*r).opening.evalscale += &Fq::one() };
+        assert!(!zexe_bn382_fq_proof_verify_phase2(
+            c.verifier_index,
+            results(&[r])
+        ));
+    }
+
+    #[test]
+    fn phase_one_results_round_trip_through_bytes() {
+        let c = Circuit::new(64, 5);
+        let r = zexe_bn382_fq_proof_verify_phase1(c.verifier_index, c.proof);
+        let bytes = unsafe { &*zexe_bn382_fq_verify_phase1_to_bytes(r) };
+        let read =
+            zexe_bn382_fq_verify_phase1_of_bytes(c.verifier_index, bytes.as_ptr(), bytes.len());
+        assert!(!read.is_null());
+        assert!(zexe_bn382_fq_proof_verify_phase2(
+            c.verifier_index,
+            results(&[r, read])
+        ));
+
+        let read = zexe_bn382_fq_verify_phase1_of_bytes(c.verifier_index, bytes.as_ptr(), 1);
+        assert!(read.is_null());
+        assert!(take_last_error().is_some());
+
+        let other = Circuit::new(128, 5);
+        let read =
+            zexe_bn382_fq_verify_phase1_of_bytes(other.verifier_index, bytes.as_ptr(), bytes.len());
+        assert!(read.is_null());
+        assert!(take_last_error().is_some());
+    }
+
+    #[test]
+    fn phase_two_rejects_results_of_another_verifier_index() {
+        let c = Circuit::new(64, 7);
+        let r = zexe_bn382_fq_proof_verify_phase1(c.verifier_index, c.proof);
+        let other = Circuit::new(128, 7);
+        assert!(!zexe_bn382_fq_proof_verify_phase2(
+            other.verifier_index,
+            results(&[r])
+        ));
+        assert!(take_last_error().is_some());
+    }
+
+    #[test]
+    fn phase_one_rejects_a_proof_of_another_shape() {
+        let c = Circuit::new(64, 9);
+        let mut proof = unsafe { (*c.proof).clone() };
+        proof.public.push(Fq::one());
+        let r = zexe_bn382_fq_proof_verify_phase1(c.verifier_index, into_handle(Box::new(proof)));
+        assert!(r.is_null());
+        assert!(take_last_error().is_some());
+    }
+
+    #[test]
+    fn supplied_x_hat_commitments_are_opened_as_given() {
+        let c = Circuit::new(64, 11);
+        let index = unsafe { &*c.verifier_index };
+        let lagrange = fq_lagrange_commitments(index.srs.get_ref(), index.domains.x);
+        let comm = |public: &[Fq]| into_handle(Box::new(fq_x_hat_comm(&lagrange, public)));
+
+        let public = unsafe { &*c.proof }.public.clone();
+        assert!(zexe_bn382_fq_proof_verify_with_x_hat_comm(
+            c.verifier_index,
+            c.proof,
+            comm(&public)
+        ));
+
+        let mut other = public.clone();
+        other[1] += &Fq::one();
+        assert!(!zexe_bn382_fq_proof_verify_with_x_hat_comm(
+            c.verifier_index,
+            c.proof,
+            comm(&other)
+        ));
+        take_last_error();
+
+        // Without the check of debug builds, the openings reject it.
+        let proof = unsafe { &*c.proof };
+        let x_hat_comm = fq_x_hat_comm(&lagrange, &other);
+        if let Ok(inputs) = fq_verify_phase1_with_x_hat_comm(index, proof, x_hat_comm) {
+            assert!(!fq_opening_batch_check(&[(index, proof, &inputs)]));
+        }
+    }
+
+    // A file in the temporary directory, removed when dropped.
+    struct TempPath(std::ffi::CString);
+
+    impl TempPath {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+            TempPath(std::ffi::CString::new(path.to_str().unwrap()).unwrap())
+        }
+
+        fn ptr(&self) -> *mut c_char {
+            self.0.as_ptr() as *mut c_char
+        }
+
+        fn path(&self) -> &str {
+            self.0.to_str().unwrap()
+        }
+    }
+
+    impl Drop for TempPath {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(self.path());
+        }
+    }
+
+    fn same_srs(a: *const SRS<GAffine>, b: *const SRS<GAffine>) -> bool {
+        let (a, b) = unsafe { (&*a, &*b) };
+        a.g == b.g && a.h == b.h
+    }
+
+    #[test]
+    fn urs_readers_read_what_was_written() {
+        let file = TempPath::new("zexe-bn382-fq-urs-readers");
+        let urs = zexe_bn382_fq_urs_create(8);
+        let digest = zexe_bn382_fq_urs_write_with_digest(urs, file.ptr());
+        let expected = zexe_bn382_fq_urs_digest(urs);
+        assert_eq!(unsafe { *digest }, unsafe { *expected });
+
+        let read = zexe_bn382_fq_urs_read(file.ptr());
+        assert!(same_srs(urs, read));
+        let read = zexe_bn382_fq_urs_read_parallel(file.ptr());
+        assert!(!read.is_null());
+        assert!(same_srs(urs, read));
+        let read = zexe_bn382_fq_urs_read_unchecked(file.ptr(), digest);
+        assert!(!read.is_null());
+        assert!(same_srs(urs, read));
+        let read = zexe_bn382_fq_urs_read_with_digest(file.ptr());
+        assert!(same_srs(urs, read.a));
+        assert_eq!(unsafe { *read.b }, unsafe { *digest });
+    }
+
+    #[test]
+    fn urs_readers_reject_tampered_files() {
+        let file = TempPath::new("zexe-bn382-fq-urs-tampered");
+        let urs = zexe_bn382_fq_urs_create(8);
+        let digest = zexe_bn382_fq_urs_write_with_digest(urs, file.ptr());
+
+        // A coordinate of the first point, after the number of points.
+        let mut bytes = std::fs::read(file.path()).unwrap();
+        bytes[9] ^= 1;
+        std::fs::write(file.path(), &bytes).unwrap();
+        assert!(zexe_bn382_fq_urs_read_parallel(file.ptr()).is_null());
+        assert!(take_last_error().is_some());
+        assert!(zexe_bn382_fq_urs_read_unchecked(file.ptr(), digest).is_null());
+        assert!(take_last_error().unwrap().contains("digest"));
+
+        bytes.truncate(bytes.len() / 2);
+        std::fs::write(file.path(), &bytes).unwrap();
+        assert!(zexe_bn382_fq_urs_read_parallel(file.ptr()).is_null());
+        assert!(take_last_error().is_some());
+
+        let missing = TempPath::new("zexe-bn382-fq-urs-missing");
+        assert!(zexe_bn382_fq_urs_read_parallel(missing.ptr()).is_null());
+        assert!(take_last_error().is_some());
+    }
 }
@@ -0,0 +1,149 @@
+use crate::common::*;
+use algebra::{
+    biginteger::BigInteger384,
+    bn_382::{
+        g::{Affine as GAffine, Bn_382GParameters},
+        g1::Bn_382G1Parameters,
+        G1Affine,
+    },
+    curves::{
+        models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+        AffineCurve, ProjectiveCurve,
+    },
+    fields::PrimeField,
+};
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+use groupmap::{BWParameters, GroupMap};
+
+// Hashing to G and G1
+//
+// Hashes byte strings to points of the prime order groups of both bn_382
+// curves, for nothing-up-my-sleeve points and independent generators. The
+// message is hashed, under a domain separating the curves and uses, to two
+// field elements, each of which is mapped to the curve by the group map of
+// the commitments (Bowe and Wahby's variant of Shallue and van de Woestijne,
+// as both curves have a = 0). The sum of the two points is uniformly
+// distributed, unlike the image of a single field element.
+
+const G_DOMAIN: &[u8] = b"zexe-bn382-g-hash";
+const G1_DOMAIN: &[u8] = b"zexe-bn382-g1-hash";
+const G_GENERATORS_DOMAIN: &[u8] = b"zexe-bn382-g-generators";
+const G1_GENERATORS_DOMAIN: &[u8] = b"zexe-bn382-g1-generators";
+
+// 512 bits of hash, reduced modulo the 382 bit modulus, are within 2^-130 of
+// uniform.
+fn hash_to_field<F: PrimeField<BigInt = BigInteger384>>(domain: &[u8], msg: &[u8], i: u8) -> F {
+    let mut h = VarBlake2b::new(64).unwrap();
+    h.input(&(domain.len() as u64).to_le_bytes());
+    h.input(domain);
+    h.input(&[i]);
+    h.input(msg);
+    let mut limbs = [0u64; 8];
+    h.variable_result(|d| {
+        for (l, b) in limbs.iter_mut().zip(d.chunks(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(b);
+            *l = u64::from_le_bytes(bytes);
+        }
+    });
+    let lo = F::from_repr(BigInteger384([limbs[0], limbs[1], limbs[2], limbs[3], 0, 0]));
+    let hi = F::from_repr(BigInteger384([limbs[4], limbs[5], limbs[6], limbs[7], 0, 0]));
+    let two_to_256 = F::from_repr(BigInteger384([0, 0, 0, 0, 1, 0]));
+    hi * &two_to_256 + &lo
+}
+
+pub fn hash_to_curve<P: SWModelParameters>(domain: &[u8], msg: &[u8]) -> GroupAffine<P>
+where
+    P::BaseField: PrimeField<BigInt = BigInteger384>,
+{
+    let map = BWParameters::<P>::setup();
+    let point = |i| {
+        let (x, y) = map.to_group(hash_to_field(domain, msg, i));
+        GroupAffine::<P>::new(x, y, false)
+    };
+    let mut res = point(0).into_projective();
+    res.add_assign_mixed(&point(1));
+    res.into_affine().scale_by_cofactor().into_affine()
+}
+
+// n points, the i-th of which hashes the message followed by i as 8 little
+// endian bytes.
+fn hash_generators<P: SWModelParameters>(
+    domain: &[u8],
+    msg: &[u8],
+    n: usize,
+) -> Vec<GroupAffine<P>>
+where
+    P::BaseField: PrimeField<BigInt = BigInteger384>,
+{
+    (0..n)
+        .map(|i| {
+            let mut m = msg.to_vec();
+            m.extend_from_slice(&(i as u64).to_le_bytes());
+            hash_to_curve::<P>(domain, &m)
+        })
+        .collect()
+}
+
+fn message<'a>(msg: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(msg, len) }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_of_bytes_hash(msg: *const u8, len: usize) -> *const GAffine {
+    catch_ffi("zexe_bn382_g_of_bytes_hash", std::ptr::null(), || {
+        into_handle(Box::new(hash_to_curve::<Bn_382GParameters>(
+            G_DOMAIN,
+            message(msg, len),
+        )))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_of_bytes_hash(msg: *const u8, len: usize) -> *const G1Affine {
+    catch_ffi("zexe_bn382_g1_of_bytes_hash", std::ptr::null(), || {
+        into_handle(Box::new(hash_to_curve::<Bn_382G1Parameters>(
+            G1_DOMAIN,
+            message(msg, len),
+        )))
+    })
+}
+
+// n independent points of G derived from the message, for instance as
+// Pedersen generators.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g_hash_generators(
+    msg: *const u8,
+    len: usize,
+    n: usize,
+) -> *const Vec<GAffine> {
+    catch_ffi("zexe_bn382_g_hash_generators", std::ptr::null(), || {
+        into_handle(Box::new(hash_generators::<Bn_382GParameters>(
+            G_GENERATORS_DOMAIN,
+            message(msg, len),
+            n,
+        )))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_hash_generators(
+    msg: *const u8,
+    len: usize,
+    n: usize,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_g1_hash_generators", std::ptr::null(), || {
+        into_handle(Box::new(hash_generators::<Bn_382G1Parameters>(
+            G1_GENERATORS_DOMAIN,
+            message(msg, len),
+            n,
+        )))
+    })
+}
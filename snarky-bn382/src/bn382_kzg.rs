@@ -0,0 +1,365 @@
+use crate::bn382_pairing::{
+    batch_opening_check, degree_bound_accumulator_holds, degree_bound_accumulator_randomized,
+    degree_bound_holds, DegreeBoundedCommitment,
+};
+use crate::common::*;
+use algebra::{
+    bn_382::{fp::Fp, Bn_382, G1Affine},
+    FromBytes, One, ToBytes, UniformRand, Zero,
+};
+use commitment_pairing::urs::URS;
+use ff_fft::DensePolynomial;
+use std::io::{Read, Result as IoResult, Write};
+
+// KZG commitments over the Fp URS
+//
+// Plain polynomial commitments for protocols which only need to commit to a
+// polynomial and open it at points, without any of the Marlin index
+// machinery. Polynomials are given by their coefficients, lowest degree
+// first.
+
+#[derive(Clone, Copy)]
+pub struct KzgOpening {
+    pub point: Fp,
+    pub value: Fp,
+    pub proof: G1Affine,
+}
+
+impl KzgOpening {
+    fn write<W: Write>(&self, mut w: W) -> IoResult<()> {
+        self.point.write(&mut w)?;
+        self.value.write(&mut w)?;
+        self.proof.write(&mut w)
+    }
+
+    fn read<R: Read>(mut r: R) -> IoResult<Self> {
+        Ok(KzgOpening {
+            point: Fp::read(&mut r)?,
+            value: Fp::read(&mut r)?,
+            proof: G1Affine::read(&mut r)?,
+        })
+    }
+}
+
+fn commit(urs: &URS<Bn_382>, coeffs: &[Fp]) -> G1Affine {
+    urs.commit(&DensePolynomial::from_coefficients_slice(coeffs))
+        .unwrap()
+}
+
+// Divides p(X) - p(z) by X - z, returning the quotient and p(z).
+fn divide_by_linear(coeffs: &[Fp], z: Fp) -> (Vec<Fp>, Fp) {
+    let mut q = vec![Fp::zero(); coeffs.len().saturating_sub(1)];
+    let mut acc = Fp::zero();
+    for i in (0..coeffs.len()).rev() {
+        acc = coeffs[i] + &(acc * &z);
+        if i > 0 {
+            q[i - 1] = acc;
+        }
+    }
+    (q, acc)
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_commit(
+    urs: *const URS<Bn_382>,
+    coeffs: *const Vec<Fp>,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_kzg_commit", std::ptr::null(), || {
+        let urs = handle(urs);
+        let coeffs = handle(coeffs);
+        let urs = unsafe { &*urs };
+        let coeffs = unsafe { &*coeffs };
+        into_handle(Box::new(commit(urs, coeffs)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_open(
+    urs: *const URS<Bn_382>,
+    coeffs: *const Vec<Fp>,
+    point: *const Fp,
+) -> *const KzgOpening {
+    catch_ffi("zexe_bn382_fp_kzg_open", std::ptr::null(), || {
+        let urs = handle(urs);
+        let coeffs = handle(coeffs);
+        let point = handle(point);
+        let urs = unsafe { &*urs };
+        let coeffs = unsafe { &*coeffs };
+        let point = unsafe { *point };
+        let (q, value) = divide_by_linear(coeffs, point);
+        into_handle(Box::new(KzgOpening {
+            point,
+            value,
+            proof: commit(urs, &q),
+        }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_verify(
+    urs: *const URS<Bn_382>,
+    comm: *const G1Affine,
+    opening: *const KzgOpening,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_kzg_verify", false, || {
+        let urs = handle(urs);
+        let comm = handle(comm);
+        let opening = handle(opening);
+        let urs = unsafe { &*urs };
+        let comm = unsafe { *comm };
+        let o = unsafe { *opening };
+        batch_opening_check(
+            urs,
+            &[o.point],
+            &[comm],
+            &[o.value],
+            &[o.proof],
+            Fp::one(),
+            Fp::one(),
+        )
+    })
+}
+
+// Verifies the i-th opening against the i-th commitment, for all i, with a
+// single pairing check.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_batch_verify(
+    urs: *const URS<Bn_382>,
+    comms: *const Vec<G1Affine>,
+    openings: *const Vec<KzgOpening>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_kzg_batch_verify", false, || {
+        let urs = handle(urs);
+        let comms = handle(comms);
+        let openings = handle(openings);
+        let urs = unsafe { &*urs };
+        let comms = unsafe { &*comms };
+        let openings = unsafe { &*openings };
+        if comms.len() != openings.len() {
+            set_last_error(format!(
+                "zexe_bn382_fp_kzg_batch_verify: {} commitments for {} openings",
+                comms.len(),
+                openings.len()
+            ));
+            return false;
+        }
+        let points: Vec<_> = openings.iter().map(|o| o.point).collect();
+        let values: Vec<_> = openings.iter().map(|o| o.value).collect();
+        let proofs: Vec<_> = openings.iter().map(|o| o.proof).collect();
+        batch_opening_check(
+            urs,
+            &points,
+            comms,
+            &values,
+            &proofs,
+            Fp::one(),
+            Fp::rand(&mut system_rng()),
+        )
+    })
+}
+
+// The shifted commitment of a polynomial of degree less than bound, that is
+// the commitment to X^{depth - bound} p(X). Together with the commitment to p,
+// it shows that the degree of p is less than bound.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_commit_shifted(
+    urs: *const URS<Bn_382>,
+    coeffs: *const Vec<Fp>,
+    bound: usize,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_kzg_commit_shifted", std::ptr::null(), || {
+        let urs = handle(urs);
+        let coeffs = handle(coeffs);
+        let urs = unsafe { &*urs };
+        let coeffs = unsafe { &*coeffs };
+        if coeffs.len() > bound || bound > urs.depth {
+            return null_with_error(format!(
+                "zexe_bn382_fp_kzg_commit_shifted: Cannot shift a polynomial with {} coefficients under degree bound {}.",
+                coeffs.len(),
+                bound
+            ));
+        }
+        let p = DensePolynomial::from_coefficients_slice(coeffs);
+        let (shifted, _) = urs.commit_with_degree_bound(&p, bound).unwrap();
+        into_handle(Box::new(shifted))
+    })
+}
+
+// Checks that shifted is the shifted commitment of comm under bound, with a
+// single pairing check.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_verify_shifted(
+    urs: *const URS<Bn_382>,
+    comm: *const G1Affine,
+    shifted: *const G1Affine,
+    bound: usize,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_kzg_verify_shifted", false, || {
+        let urs = handle(urs);
+        let comm = handle(comm);
+        let shifted = handle(shifted);
+        let c = DegreeBoundedCommitment {
+            unshifted: unsafe { *comm },
+            shifted: unsafe { *shifted },
+        };
+        degree_bound_holds(unsafe { &*urs }, &c, bound)
+    })
+}
+
+// Checks the i-th shifted commitment against the i-th commitment and bound,
+// for all i, combined with random scalars into a single pairing product.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_batch_verify_shifted(
+    urs: *const URS<Bn_382>,
+    comms: *const Vec<G1Affine>,
+    shifted: *const Vec<G1Affine>,
+    bounds: *const Vec<usize>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_kzg_batch_verify_shifted", false, || {
+        let urs = handle(urs);
+        let comms = handle(comms);
+        let shifted = handle(shifted);
+        let bounds = handle(bounds);
+        let urs = unsafe { &*urs };
+        let comms = unsafe { &*comms };
+        let shifted = unsafe { &*shifted };
+        let bounds = unsafe { &*bounds };
+        if comms.len() != shifted.len() || comms.len() != bounds.len() {
+            set_last_error(format!(
+                "zexe_bn382_fp_kzg_batch_verify_shifted: {} commitments, {} shifted commitments and {} bounds",
+                comms.len(),
+                shifted.len(),
+                bounds.len()
+            ));
+            return false;
+        }
+        let cs: Vec<_> = comms
+            .iter()
+            .zip(shifted.iter())
+            .map(|(&unshifted, &shifted)| DegreeBoundedCommitment { unshifted, shifted })
+            .collect();
+        let rng = &mut system_rng();
+        let randomizers: Vec<Fp> = cs.iter().map(|_| Fp::rand(rng)).collect();
+        match degree_bound_accumulator_randomized(urs, bounds, &cs, &randomizers) {
+            Some(acc) => degree_bound_accumulator_holds(urs, bounds, &acc),
+            None => false,
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_make(
+    point: *const Fp,
+    value: *const Fp,
+    proof: *const G1Affine,
+) -> *const KzgOpening {
+    catch_ffi("zexe_bn382_fp_kzg_opening_make", std::ptr::null(), || {
+        let point = handle(point);
+        let value = handle(value);
+        let proof = handle(proof);
+        into_handle(Box::new(KzgOpening {
+            point: unsafe { *point },
+            value: unsafe { *value },
+            proof: unsafe { *proof },
+        }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_point(o: *const KzgOpening) -> *const Fp {
+    catch_ffi("zexe_bn382_fp_kzg_opening_point", std::ptr::null(), || {
+        let o = handle(o);
+        into_handle(Box::new(unsafe { (*o).point }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_value(o: *const KzgOpening) -> *const Fp {
+    catch_ffi("zexe_bn382_fp_kzg_opening_value", std::ptr::null(), || {
+        let o = handle(o);
+        into_handle(Box::new(unsafe { (*o).value }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_proof(o: *const KzgOpening) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_kzg_opening_proof", std::ptr::null(), || {
+        let o = handle(o);
+        into_handle(Box::new(unsafe { (*o).proof }))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_kzg_opening_delete, KzgOpening);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_to_bytes(o: *const KzgOpening) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fp_kzg_opening_to_bytes", std::ptr::null_mut(), || {
+        let o = handle(o);
+        let o = unsafe { &*o };
+        let mut res = vec![];
+        o.write(&mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_of_bytes(
+    data: *const u8,
+    len: usize,
+) -> *const KzgOpening {
+    catch_ffi("zexe_bn382_fp_kzg_opening_of_bytes", std::ptr::null(), || {
+        let mut data = unsafe { std::slice::from_raw_parts(data, len) };
+        match KzgOpening::read(&mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_fp_kzg_opening_of_bytes: trailing bytes after opening".to_string(),
+            ),
+            Ok(o) => into_handle(Box::new(o)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_kzg_opening_of_bytes: {}", e)),
+        }
+    })
+}
+
+// KZG opening vector stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_vector_create() -> *mut Vec<KzgOpening> {
+    catch_ffi("zexe_bn382_fp_kzg_opening_vector_create", std::ptr::null_mut(), || {
+        into_handle(Box::new(vec![]))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_vector_length(v: *const Vec<KzgOpening>) -> i32 {
+    catch_ffi("zexe_bn382_fp_kzg_opening_vector_length", 0, || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        v.len() as i32
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_vector_emplace_back(
+    v: *mut Vec<KzgOpening>,
+    x: *const KzgOpening,
+) {
+    catch_ffi("zexe_bn382_fp_kzg_opening_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v = unsafe { &mut *v };
+        v.push(unsafe { *x });
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_kzg_opening_vector_get(
+    v: *const Vec<KzgOpening>,
+    i: u32,
+) -> *const KzgOpening {
+    catch_ffi("zexe_bn382_fp_kzg_opening_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        into_handle(Box::new(v[i as usize]))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_kzg_opening_vector_delete, Vec<KzgOpening>);
@@ -1,7 +1,9 @@
 use crate::common::*;
+use crate::gadgets::Row;
+use crate::labels::{check_labelled_system, forget_labelled_system};
 use algebra::{
     FftField,
-    biginteger::BigInteger384,
+    biginteger::{BigInteger, BigInteger384},
     bn_382::{
         fp::{Fp, FpParameters as Fp_params},
         fq::Fq,
@@ -10,7 +12,7 @@ use algebra::{
     },
     curves::{AffineCurve, PairingEngine, ProjectiveCurve},
     fields::{Field, FpParameters, PrimeField, SquareRootField},
-    FromBytes, One, ToBytes, UniformRand, Zero,
+    FixedBaseWnafTable, FromBytes, One, ToBytes, UniformRand, Zero,
 };
 use commitment_pairing::urs::URS;
 use ff_fft::{DensePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDomain as Domain};
@@ -24,369 +26,759 @@ use oracle::{
     poseidon::{MarlinSpongeConstants as SC, Sponge},
     sponge::{DefaultFqSponge, DefaultFrSponge},
 };
-use rand::rngs::StdRng;
-use rand_core;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
 
 use std::{
-    ffi::CStr,
+    cell::RefCell,
+    collections::HashMap,
+    ffi::{c_void, CStr},
     fs::File,
     io::{BufReader, BufWriter, Read, Result as IoResult, Write},
     os::raw::c_char,
+    rc::Rc,
 };
 
 // Fp stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_endo_base() -> *const Fq {
-    let (endo_q, _endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
-    return Box::into_raw(Box::new(endo_q));
+    catch_ffi("zexe_bn382_fp_endo_base", std::ptr::null(), || {
+        let (endo_q, _endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
+        return into_handle(Box::new(endo_q));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_endo_scalar() -> *const Fp {
-    let (_endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
-    return Box::into_raw(Box::new(endo_r));
+    catch_ffi("zexe_bn382_fp_endo_scalar", std::ptr::null(), || {
+        let (_endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
+        return into_handle(Box::new(endo_r));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_size_in_bits() -> i32 {
-    return Fp_params::MODULUS_BITS as i32;
+    catch_ffi("zexe_bn382_fp_size_in_bits", 0, || {
+        return Fp_params::MODULUS_BITS as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_size() -> *mut BigInteger384 {
-    let ret = Fp_params::MODULUS;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_size", std::ptr::null_mut(), || {
+        let ret = Fp_params::MODULUS;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_characteristic() -> *mut BigInteger384 {
+    catch_ffi("zexe_bn382_fp_characteristic", std::ptr::null_mut(), || {
+        let mut ret = BigInteger384::default();
+        ret.0.copy_from_slice(Fp::characteristic());
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_size_in_bytes() -> i32 {
+    catch_ffi("zexe_bn382_fp_size_in_bytes", 0, || {
+        return (BigInteger384::NUM_LIMBS * 8) as i32;
+    })
+}
+
+// Writes the little-endian bytes of the modulus to out, which must have room
+// for zexe_bn382_fp_size_in_bytes() bytes.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_modulus_bytes(out: *mut u8) {
+    catch_ffi("zexe_bn382_fp_modulus_bytes", (), || {
+        let out = unsafe { std::slice::from_raw_parts_mut(out, BigInteger384::NUM_LIMBS * 8) };
+        Fp_params::MODULUS.write(out).unwrap();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_zero() -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_zero", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fp::zero()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_one() -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_one", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fp::one()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_multiplicative_generator() -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_multiplicative_generator", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Fp::multiplicative_generator()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_is_square(x: *const Fp) -> bool {
-    let x_ = unsafe { &(*x) };
-    let s0 = x_.pow(Fp_params::MODULUS_MINUS_ONE_DIV_TWO);
-    s0.is_zero() || s0.is_one()
+    catch_ffi("zexe_bn382_fp_is_square", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let s0 = x_.pow(Fp_params::MODULUS_MINUS_ONE_DIV_TWO);
+        s0.is_zero() || s0.is_one()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_sqrt(x: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.sqrt() {
-        Some(x) => x,
-        None => Fp::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.sqrt() {
+            Some(x) => x,
+            None => Fp::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_det_sqrt(x: *const Fq) -> *mut Fq {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.det_sqrt() {
-        Some(x) => x,
-        None => Fq::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_det_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.det_sqrt() {
+            Some(x) => x,
+            None => Fq::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_det_sqrt_witness(x: *const Fq) -> DetSqrtWitness<Fq> {
-    let x_ = unsafe { &(*x) };
-    match x_.det_sqrt() {
-        Some(y) => {
-            let (c, d) = decompose(&y);
-            DetSqrtWitness {
-                c:Box::into_raw(Box::new(c)),
-                d,
-                square_root: Box::into_raw(Box::new(y)),
-                success: true
-            }
-        },
-        None =>
-            DetSqrtWitness {
-                c:Box::into_raw(Box::new(Fq::zero())),
-                d:0,
-                square_root: Box::into_raw(Box::new(Fq::zero())),
-                success: false
-            }
-    }
+    catch_ffi("zexe_bn382_fq_det_sqrt_witness", Default::default(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        match x_.det_sqrt() {
+            Some(y) => {
+                let (c, d) = decompose(&y);
+                DetSqrtWitness {
+                    c:into_handle(Box::new(c)),
+                    d,
+                    square_root: into_handle(Box::new(y)),
+                    success: true
+                }
+            },
+            None =>
+                DetSqrtWitness {
+                    c:into_handle(Box::new(Fq::zero())),
+                    d:0,
+                    square_root: into_handle(Box::new(Fq::zero())),
+                    success: false
+                }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_two_adic_root_of_unity() -> *mut Fp {
-    Box::into_raw(Box::new(FftField::two_adic_root_of_unity()))
+    catch_ffi("zexe_bn382_fp_two_adic_root_of_unity", std::ptr::null_mut(), || {
+        into_handle(Box::new(FftField::two_adic_root_of_unity()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_random() -> *mut Fp {
-    let ret: Fp = UniformRand::rand(&mut rand::thread_rng());
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_random", std::ptr::null_mut(), || {
+        let ret: Fp = UniformRand::rand(&mut system_rng());
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_of_int(i: u64) -> *mut Fp {
-    let ret = Fp::from(i);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_of_int", std::ptr::null_mut(), || {
+        let ret = Fp::from(i);
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_domain_generator(log2_size: usize) -> *mut Fp {
-    let ret = Domain::new(1 << log2_size).unwrap().group_gen;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_domain_generator", std::ptr::null_mut(), || {
+        let ret = Domain::new(1 << log2_size).unwrap().group_gen;
+        return into_handle(Box::new(ret));
+    })
+}
+
+// The string must be freed with zexe_string_delete.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_to_string(x: *const Fp) -> *mut c_char {
+    catch_ffi("zexe_bn382_fp_to_string", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x = unsafe { *x };
+        into_c_string(format!("{}", x))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_of_decimal_string(s: *const c_char) -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_of_decimal_string", std::ptr::null_mut(), || {
+        let s = unsafe { CStr::from_ptr(s) }.to_bytes();
+        match field_of_digits::<Fp>(s, 10) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_of_decimal_string: {}", e)),
+        }
+    })
 }
 
-// TODO: Leaky
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_to_string(x: *const Fp) -> *const u8 {
-    let x = unsafe { *x };
-    let s: String = format!("{}", x);
-    s.as_ptr()
+pub extern "C" fn zexe_bn382_fp_of_hex_string(s: *const c_char) -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_of_hex_string", std::ptr::null_mut(), || {
+        let s = unsafe { CStr::from_ptr(s) }.to_bytes();
+        match field_of_digits::<Fp>(s, 16) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_of_hex_string: {}", e)),
+        }
+    })
+}
+
+// Writes the canonical little-endian bytes of x to out, which must have room
+// for zexe_bn382_fp_size_in_bytes() bytes.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_to_bytes(x: *const Fp, out: *mut u8) {
+    catch_ffi("zexe_bn382_fp_to_bytes", (), || {
+        let x = handle(x);
+        let bytes = (unsafe { &*x }).to_bytes();
+        let out = unsafe { std::slice::from_raw_parts_mut(out, bytes.len()) };
+        out.copy_from_slice(&bytes);
+    })
+}
+
+// Reads the canonical little-endian bytes of an element, rejecting a wrong
+// length and encodings of integers which are not less than the modulus,
+// rather than reducing them.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_of_bytes(bytes: *const u8, len: usize) -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_of_bytes", std::ptr::null_mut(), || {
+        let bytes = if len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(bytes, len) }
+        };
+        match Fp::from_bytes_checked(bytes) {
+            Some(x) => into_handle(Box::new(x)),
+            None => null_with_error(format!(
+                "zexe_bn382_fp_of_bytes: {} bytes are not a canonical element",
+                len
+            )),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_inv(x: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let ret = match x_.inverse() {
-        Some(x) => x,
-        None => Fp::zero(),
-    };
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_inv", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.inverse() {
+            Some(x) => x,
+            None => Fp::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_square(x: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let ret = x_.square();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_square", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.square();
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_add(x: *const Fp, y: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ + y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_add", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_negate(x: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let ret = -*x_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_negate", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_mul(x: *const Fp, y: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ * y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_mul", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ * y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_div(x: *const Fp, y: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ / y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ / y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_sub(x: *const Fp, y: *const Fp) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ - y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_sub", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_mut_add(x: *mut Fp, y: *const Fp) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ += y_;
+    catch_ffi("zexe_bn382_fp_mut_add", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ += y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_mut_mul(x: *mut Fp, y: *const Fp) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ *= y_;
+    catch_ffi("zexe_bn382_fp_mut_mul", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ *= y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_mut_square(x: *mut Fp) {
-    let x_ = unsafe { &mut (*x) };
-    x_.square_in_place();
+    catch_ffi("zexe_bn382_fp_mut_square", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        x_.square_in_place();
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_mut_sub(x: *mut Fp, y: *const Fp) {
-    let x_ = unsafe { &mut (*x) };
-    let y_ = unsafe { &(*y) };
-    *x_ -= y_;
+    catch_ffi("zexe_bn382_fp_mut_sub", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ -= y_;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_copy(x: *mut Fp, y: *const Fp) {
-    unsafe { (*x) = *y };
+    catch_ffi("zexe_bn382_fp_copy", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        unsafe { (*x) = *y };
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_rng(i: i32) -> *mut Fp {
-    // We only care about entropy here, so we force a conversion i32 -> u32.
-    let i: u64 = (i as u32).into();
-    let mut rng: StdRng = rand::SeedableRng::seed_from_u64(i);
-    let ret: Fp = UniformRand::rand(&mut rng);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fp_rng", std::ptr::null_mut(), || {
+        // We only care about entropy here, so we force a conversion i32 -> u32.
+        let i: u64 = (i as u32).into();
+        let mut rng = seeded_rng(i);
+        let ret: Fp = UniformRand::rand(&mut rng);
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_delete(x: *mut Fp) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_delete, Fp);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_print(x: *const Fp) {
-    let x_ = unsafe { &(*x) };
-    println!("{}", *x_);
+    catch_ffi("zexe_bn382_fp_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", *x_));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_equal(x: *const Fp, y: *const Fp) -> bool {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    return *x_ == *y_;
+    catch_ffi("zexe_bn382_fp_equal", false, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return *x_ == *y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_is_zero(x: *const Fp) -> bool {
+    catch_ffi("zexe_bn382_fp_is_zero", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_zero();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_is_one(x: *const Fp) -> bool {
+    catch_ffi("zexe_bn382_fp_is_one", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_one();
+    })
+}
+
+// Writes the canonical value of x to out and returns true if it fits in a
+// u64; otherwise returns false and leaves out untouched.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_to_u64_checked(x: *const Fp, out: *mut u64) -> bool {
+    catch_ffi("zexe_bn382_fp_to_u64_checked", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let repr = x_.into_repr();
+        if repr.0[1..].iter().any(|l| *l != 0) {
+            return false;
+        }
+        unsafe { *out = repr.0[0] };
+        true
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_to_bigint(x: *const Fp) -> *mut BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.into_repr()));
+    catch_ffi("zexe_bn382_fp_to_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.into_repr()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_of_bigint(x: *const BigInteger384) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(Fp::from_repr(*x_)));
+    catch_ffi("zexe_bn382_fp_of_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fp::from_repr(*x_)));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_to_bigint_raw(x: *const Fp) -> *const BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.0));
+    catch_ffi("zexe_bn382_fp_to_bigint_raw", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.0));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_to_bigint_raw_noalloc(x: *const Fp) -> *const BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    &x_.0 as *const BigInteger384
+    catch_ffi("zexe_bn382_fp_to_bigint_raw_noalloc", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        &x_.0 as *const BigInteger384
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_of_bigint_raw(x: *const BigInteger384) -> *mut Fp {
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(Fp::new(*x_)));
+    catch_ffi("zexe_bn382_fp_of_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fp::new(*x_)));
+    })
+}
+
+// Splits x into little-endian chunks of chunk_bits bits, each as an element of
+// Fq. Returns null, with the last error set, if chunk_bits is 0 or does not
+// fit in Fq.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_to_fq_chunks(x: *const Fp, chunk_bits: usize) -> *mut Vec<Fq> {
+    catch_ffi("zexe_bn382_fp_to_fq_chunks", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fp_to_fq_chunks";
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        match field_to_chunks::<Fp, Fq>(x_, chunk_bits) {
+            Ok(v) => into_handle(Box::new(v)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+// Returns null, with the last error set, if chunk_bits is invalid, a chunk is
+// out of range or the result is not a canonical Fp element.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_of_fq_chunks(v: *const Vec<Fq>, chunk_bits: usize) -> *mut Fp {
+    catch_ffi("zexe_bn382_fp_of_fq_chunks", std::ptr::null_mut(), || {
+        const NAME: &str = "zexe_bn382_fp_of_fq_chunks";
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        match field_of_chunks::<Fp, Fq>(v_, chunk_bits) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
 // Fp vector stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_create() -> *mut Vec<Fp> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fp_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_length(v: *const Vec<Fp>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fp_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_emplace_back(v: *mut Vec<Fp>, x: *const Fp) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
+    catch_ffi("zexe_bn382_fp_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_get(v: *mut Vec<Fp>, i: u32) -> *mut Fp {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
+    catch_ffi("zexe_bn382_fp_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_vector_delete, Vec<Fp>);
+
+// A new vector of the inverses of the elements, computed with a single
+// inversion. Zeros stay zero, as with zexe_bn382_fp_inv.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_vector_batch_inverse(v: *const Vec<Fp>) -> *mut Vec<Fp> {
+    catch_ffi("zexe_bn382_fp_vector_batch_inverse", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let mut res = (unsafe { &*v }).clone();
+        Fp::batch_inversion(&mut res);
+        into_handle(Box::new(res))
+    })
+}
+
+// Builds a vector of len elements from len * NUM_LIMBS canonical limbs,
+// rejecting non-canonical elements.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_vector_of_raw(limbs: *const u64, len: usize) -> *mut Vec<Fp> {
+    catch_ffi("zexe_bn382_fp_vector_of_raw", std::ptr::null_mut(), || {
+        let n = <<Fp as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        let limbs = if len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(limbs, len * n) }
+        };
+        match field_vector_of_limbs(limbs) {
+            Ok(v) => into_handle(Box::new(v)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_vector_of_raw: {}", e)),
+        }
+    })
+}
+
+// Writes the canonical limbs of the elements to a caller buffer of
+// length * NUM_LIMBS limbs.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_vector_to_raw(v: *const Vec<Fp>, limbs: *mut u64) {
+    catch_ffi("zexe_bn382_fp_vector_to_raw", (), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        let n = <<Fp as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        if v.is_empty() {
+            return;
+        }
+        let limbs = unsafe { std::slice::from_raw_parts_mut(limbs, v.len() * n) };
+        field_vector_to_limbs(v, limbs);
+    })
 }
 
+// Samples n elements from the ChaCha20 stream keyed by the 32 bytes at seed.
+// The stream is stable, so a seed gives the same elements in every release.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_vector_delete(v: *mut Vec<Fp>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_bn382_fp_vector_random(n: usize, seed: *const u8) -> *mut Vec<Fp> {
+    catch_ffi("zexe_bn382_fp_vector_random", std::ptr::null_mut(), || {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(unsafe { std::slice::from_raw_parts(seed, 32) });
+        let mut rng = ChaCha20Rng::from_seed(s);
+        let ret: Vec<Fp> = (0..n).map(|_| UniformRand::rand(&mut rng)).collect();
+        return into_handle(Box::new(ret));
+    })
 }
 
 // Fp constraint-matrix stubs
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_constraint_matrix_create() -> *mut Vec<(Vec<usize>, Vec<Fp>)> {
-    return Box::into_raw(Box::new(vec![]));
+pub extern "C" fn zexe_bn382_fp_constraint_matrix_create() -> *mut Vec<Row<Fp>> {
+    catch_ffi("zexe_bn382_fp_constraint_matrix_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(vec![]));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_constraint_matrix_append_row(
-    m: *mut Vec<(Vec<usize>, Vec<Fp>)>,
+    m: *mut Vec<Row<Fp>>,
     indices: *mut Vec<usize>,
     coefficients: *mut Vec<Fp>,
 ) {
-    let m_ = unsafe { &mut (*m) };
-    let indices_ = unsafe { &mut (*indices) };
-    let coefficients_ = unsafe { &mut (*coefficients) };
-    m_.push((indices_.clone(), coefficients_.clone()));
+    catch_ffi("zexe_bn382_fp_constraint_matrix_append_row", (), || {
+        let m = handle(m);
+        let indices = handle(indices);
+        let coefficients = handle(coefficients);
+        let m_ = unsafe { &mut (*m) };
+        let indices_ = unsafe { &mut (*indices) };
+        let coefficients_ = unsafe { &mut (*coefficients) };
+        m_.push((indices_.clone(), coefficients_.clone()));
+    })
+}
+
+// Appends rows given as CSR arrays: rows + 1 row pointers into nnz column
+// indices and nnz values, the latter as nnz * NUM_LIMBS canonical limbs. This
+// avoids creating two vectors per row. On failure the last error is set and
+// nothing is appended.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_constraint_matrix_append_csr(
+    m: *mut Vec<Row<Fp>>,
+    row_ptrs: *const usize,
+    rows: usize,
+    cols: *const usize,
+    values: *const u64,
+    nnz: usize,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_constraint_matrix_append_csr", false, || {
+        let m = handle(m);
+        let m = unsafe { &mut *m };
+        let n = <<Fp as PrimeField>::BigInt as BigInteger>::NUM_LIMBS;
+        let row_ptrs = unsafe { std::slice::from_raw_parts(row_ptrs, rows + 1) };
+        let (cols, values) = if nnz == 0 {
+            (&[][..], &[][..])
+        } else {
+            unsafe {
+                (
+                    std::slice::from_raw_parts(cols, nnz),
+                    std::slice::from_raw_parts(values, nnz * n),
+                )
+            }
+        };
+        match rows_of_csr(row_ptrs, cols, values) {
+            Ok(rows) => {
+                m.extend(rows);
+                true
+            }
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fp_constraint_matrix_append_csr: {}", e));
+                false
+            }
+        }
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_constraint_matrix_delete(x: *mut Vec<(Vec<usize>, Vec<Fp>)>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_constraint_matrix_delete, Vec<Row<Fp>>);
 
 // Fp triple
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_triple_0(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[0].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_triple_0", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[0].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_triple_1(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[1].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_triple_1", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[1].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_triple_2(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[2].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_triple_2", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[2].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_triple_0(evals: *const [Vec<Fp>; 3]) -> *const Vec<Fp> {
-    let x = (unsafe { &(*evals) })[0].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_vector_triple_0", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[0].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_triple_1(evals: *const [Vec<Fp>; 3]) -> *const Vec<Fp> {
-    let x = (unsafe { &(*evals) })[1].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_vector_triple_1", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[1].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_vector_triple_2(evals: *const [Vec<Fp>; 3]) -> *const Vec<Fp> {
-    let x = (unsafe { &(*evals) })[2].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_vector_triple_2", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { &(*evals) })[2].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_vector_triple_delete(x: *mut [Fp; 3]) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_triple_delete, [Fp; 3]);
+
+crate::delete_stub!(zexe_bn382_fp_vector_triple_delete, [Vec<Fp>; 3]);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_batch_pairing_check(
@@ -420,93 +812,702 @@ pub extern "C" fn zexe_bn382_batch_pairing_check(
     t: *const Vec<G1Affine>,
     p: *const Vec<G1Affine>,
 ) -> bool {
-    let urs = unsafe { &(*urs) };
-    let d = unsafe { &(*d) };
-    let s = unsafe { &(*s) };
-    let u = unsafe { &(*u) };
-    let t = unsafe { &(*t) };
-    let p = unsafe { &(*p) };
-
-    let n = s.len();
-    let k = d.len();
-    assert_eq!(n * k, u.len());
-    assert_eq!(n, t.len());
-    assert_eq!(n, p.len());
-
-    // Optimizations: These could both be 128 bits
-    let a: Fp = UniformRand::rand(&mut rand::thread_rng());
-    let b: Fp = UniformRand::rand(&mut rand::thread_rng());
-
-    // Final value: d[j] = - sum_i b^i u_{i,j}
-    let mut acc_d = vec![G1Projective::zero(); k];
-
-    // Final value: sum_i b^i (s_i + a t_i)
-    let mut acc_h = G1Projective::zero();
+    catch_ffi("zexe_bn382_batch_pairing_check", false, || {
+        let urs = handle(urs);
+        let d = handle(d);
+        let s = handle(s);
+        let u = handle(u);
+        let t = handle(t);
+        let p = handle(p);
+        let urs = unsafe { &(*urs) };
+        let d = unsafe { &(*d) };
+        let s = unsafe { &(*s) };
+        let u = unsafe { &(*u) };
+        let t = unsafe { &(*t) };
+        let p = unsafe { &(*p) };
+
+        let n = s.len();
+        let k = d.len();
+        assert_eq!(n * k, u.len());
+        assert_eq!(n, t.len());
+        assert_eq!(n, p.len());
+
+        // Optimizations: These could both be 128 bits
+        let a: Fp = UniformRand::rand(&mut system_rng());
+        let b: Fp = UniformRand::rand(&mut system_rng());
+
+        // Final value: d[j] = - sum_i b^i u_{i,j}
+        let mut acc_d = vec![G1Projective::zero(); k];
+
+        // Final value: sum_i b^i (s_i + a t_i)
+        let mut acc_h = G1Projective::zero();
+
+        // Final value: -a sum_i b^i p_i
+        let mut acc_beta_h = G1Projective::zero();
+
+        let u: Vec<Vec<G1Affine>> = (0..n)
+            .map(|i| (0..k).map(|j| u[k * i + j]).collect())
+            .collect();
+
+        // Optimization: Parallelize
+        // Optimization:
+        //   Experiment with scalar multiplying the affine point by b^i before adding
+        // into the   accumulator.
+        for ((p_i, (s_i, t_i)), u_i) in p.iter().zip(s.iter().zip(t)).zip(u) {
+            acc_beta_h *= b;
+            acc_beta_h.add_assign_mixed(p_i);
+
+            acc_h *= b;
+            acc_h.add_assign_mixed(s_i);
+            acc_h += &t_i.mul(a);
+
+            for (j, u_ij) in u_i.iter().enumerate() {
+                acc_d[j] *= b;
+                acc_d[j].add_assign_mixed(u_ij);
+            }
+        }
+        acc_beta_h *= -a;
+        for acc_j in acc_d.iter_mut() {
+            *acc_j = -(*acc_j);
+        }
 
-    // Final value: -a sum_i b^i p_i
-    let mut acc_beta_h = G1Projective::zero();
+        let mut g1 = vec![acc_h, acc_beta_h];
+        g1.extend(acc_d);
+        let g1 = G1Projective::batch_normalization_into_affine(&g1);
+
+        let prepared = fp_prepared_verifier_index(urs);
+        let mut hn = vec![];
+        for j in d {
+            match prepared.hn(urs, *j) {
+                Some(h) => hn.push(h),
+                None => return false,
+            }
+        }
+
+        let mut table = vec![(g1[0], prepared.h()), (g1[1], prepared.hx())];
+        for (acc_j, h) in g1[2..].iter().zip(hn.iter()) {
+            table.push((*acc_j, &**h));
+        }
+        prepared_pairings_vanish(&table)
+    })
+}
+
+// Checks a batch of KZG openings against the URS. For each point z_i there
+// are k commitments c_{i,j} with claimed values v_{i,j} and an opening proof
+// pi_i. With the polynomial batching scalar xi, let
+//
+// C_i = sum_j xi^j c_{i,j}
+// V_i = sum_j xi^j v_{i,j}
+//
+// then the opening at z_i is valid iff
+// e(C_i - V_i G + z_i pi_i, H) = e(pi_i, beta H)
+//
+// and, with the point batching scalar r, all openings are checked at once as
+// e(sum_i r^i (C_i - V_i G + z_i pi_i), H) - e(sum_i r^i pi_i, beta H) = 0.
+// This is the final check of the pairing verifier. The batching scalars must
+// be chosen after the commitments, values and proofs, e.g. from a sponge or
+// at random.
+pub fn batch_opening_check(
+    urs: &URS<Bn_382>,
+    points: &[Fp],
+    comms: &[G1Affine],
+    values: &[Fp],
+    proofs: &[G1Affine],
+    xi: Fp,
+    r: Fp,
+) -> bool {
+    match opening_accumulator(urs, points, comms, values, proofs, xi, r) {
+        Some(acc) => opening_accumulator_holds(urs, acc),
+        None => false,
+    }
+}
+
+// The pair (sum_i r^i (C_i - V_i G + z_i pi_i), sum_i r^i pi_i) of
+// batch_opening_check, which is left for a recursive verifier to check with
+// opening_accumulator_holds, or None if the lengths are inconsistent.
+pub fn opening_accumulator(
+    urs: &URS<Bn_382>,
+    points: &[Fp],
+    comms: &[G1Affine],
+    values: &[Fp],
+    proofs: &[G1Affine],
+    xi: Fp,
+    r: Fp,
+) -> Option<(G1Affine, G1Affine)> {
+    let n = points.len();
+    if n == 0 || proofs.len() != n || comms.len() != values.len() || comms.len() % n != 0 {
+        return None;
+    }
+    let k = comms.len() / n;
 
-    let u: Vec<Vec<G1Affine>> = (0..n)
-        .map(|i| (0..k).map(|j| u[k * i + j]).collect())
+    let mut acc_h = G1Projective::zero();
+    let mut acc_beta_h = G1Projective::zero();
+    let mut r_i = Fp::one();
+    for (i, (z, pi)) in points.iter().zip(proofs.iter()).enumerate() {
+        let mut c = G1Projective::zero();
+        let mut v = Fp::zero();
+        let mut xi_j = Fp::one();
+        for j in 0..k {
+            c += &comms[k * i + j].mul(xi_j);
+            v += &(values[k * i + j] * &xi_j);
+            xi_j *= &xi;
+        }
+        c -= &fp_generator_table(urs.gp[0]).mul(&v);
+        c += &pi.mul(*z);
+        acc_h += &c.mul(r_i);
+        acc_beta_h += &pi.mul(r_i);
+        r_i *= &r;
+    }
+    Some((acc_h.into_affine(), acc_beta_h.into_affine()))
+}
+
+// Checks e(acc_h, H) = e(acc_beta_h, beta H).
+pub fn opening_accumulator_holds(urs: &URS<Bn_382>, acc: (G1Affine, G1Affine)) -> bool {
+    let (acc_h, acc_beta_h) = acc;
+    let prepared = fp_prepared_verifier_index(urs);
+    prepared_pairings_vanish(&[(acc_h, prepared.h()), (-acc_beta_h, prepared.hx())])
+}
+
+// Combines the degree bound checks of the commitments, the i-th of which is
+// claimed to be below bounds[i], into the points
+//
+// [sum_i r^i shifted_i, r^0 unshifted_0, r^1 unshifted_1, ...]
+//
+// which satisfy e(res[0], H) = prod_i e(res[1 + i], beta^{depth - bounds[i]} H)
+// if all the bounds hold. Returns None if the lengths are inconsistent or a
+// bound exceeds the URS.
+pub fn degree_bound_accumulator(
+    urs: &URS<Bn_382>,
+    bounds: &[usize],
+    comms: &[DegreeBoundedCommitment],
+    r: Fp,
+) -> Option<Vec<G1Affine>> {
+    let randomizers: Vec<Fp> = comms
+        .iter()
+        .scan(Fp::one(), |r_i, _| {
+            let res = *r_i;
+            *r_i *= &r;
+            Some(res)
+        })
         .collect();
+    degree_bound_accumulator_randomized(urs, bounds, comms, &randomizers)
+}
+
+// As degree_bound_accumulator, with the i-th commitment scaled by
+// randomizers[i] instead of r^i. For the bounds to be sound, the randomizers
+// must be unpredictable to whoever chose the commitments.
+pub fn degree_bound_accumulator_randomized(
+    urs: &URS<Bn_382>,
+    bounds: &[usize],
+    comms: &[DegreeBoundedCommitment],
+    randomizers: &[Fp],
+) -> Option<Vec<G1Affine>> {
+    if comms.len() != bounds.len()
+        || randomizers.len() != comms.len()
+        || bounds
+            .iter()
+            .any(|&b| b > urs.depth || !urs.hn.contains_key(&(urs.depth - b)))
+    {
+        return None;
+    }
+    let mut res = vec![G1Projective::zero()];
+    for (c, r_i) in comms.iter().zip(randomizers.iter()) {
+        res[0] += &c.shifted.mul(*r_i);
+        res.push(c.unshifted.mul(*r_i));
+    }
+    Some(G1Projective::batch_normalization_into_affine(&res))
+}
 
-    // Optimization: Parallelize
-    // Optimization:
-    //   Experiment with scalar multiplying the affine point by b^i before adding
-    // into the   accumulator.
-    for ((p_i, (s_i, t_i)), u_i) in p.iter().zip(s.iter().zip(t)).zip(u) {
-        acc_beta_h *= b;
-        acc_beta_h.add_assign_mixed(p_i);
-
-        acc_h *= b;
-        acc_h.add_assign_mixed(s_i);
-        acc_h += &t_i.mul(a);
-
-        for (j, u_ij) in u_i.iter().enumerate() {
-            acc_d[j] *= b;
-            acc_d[j].add_assign_mixed(u_ij);
+pub fn degree_bound_accumulator_holds(
+    urs: &URS<Bn_382>,
+    bounds: &[usize],
+    acc: &[G1Affine],
+) -> bool {
+    if acc.len() != bounds.len() + 1 {
+        return false;
+    }
+    let prepared = fp_prepared_verifier_index(urs);
+    let mut hn = vec![];
+    for b in bounds.iter() {
+        match prepared.hn(urs, *b) {
+            Some(h) => hn.push(h),
+            None => return false,
         }
     }
-    acc_beta_h *= -a;
-    for acc_j in acc_d.iter_mut() {
-        *acc_j = -(*acc_j);
+    let mut table = vec![(acc[0], prepared.h())];
+    for (c, h) in acc[1..].iter().zip(hn.iter()) {
+        table.push((-*c, &**h));
     }
+    prepared_pairings_vanish(&table)
+}
+
+// See batch_opening_check. comms and values hold the k commitments and values
+// of the first point, then those of the second point, and so on.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_batch_opening_check(
+    urs: *const URS<Bn_382>,
+    points: *const Vec<Fp>,
+    comms: *const Vec<G1Affine>,
+    values: *const Vec<Fp>,
+    proofs: *const Vec<G1Affine>,
+    xi: *const Fp,
+    r: *const Fp,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_urs_batch_opening_check", false, || {
+        let urs = handle(urs);
+        let points = handle(points);
+        let comms = handle(comms);
+        let values = handle(values);
+        let proofs = handle(proofs);
+        let xi = handle(xi);
+        let r = handle(r);
+        batch_opening_check(
+            unsafe { &*urs },
+            unsafe { &*points },
+            unsafe { &*comms },
+            unsafe { &*values },
+            unsafe { &*proofs },
+            unsafe { *xi },
+            unsafe { *r },
+        )
+    })
+}
+
+// Batches KZG openings as zexe_bn382_fp_urs_batch_opening_check does, but
+// instead of checking them returns the pair (A, B) which is valid iff
+// e(A, H) = e(B, beta H), for the next layer of recursion to check. Returns
+// null if the lengths are inconsistent.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_opening_accumulator(
+    urs: *const URS<Bn_382>,
+    points: *const Vec<Fp>,
+    comms: *const Vec<G1Affine>,
+    values: *const Vec<Fp>,
+    proofs: *const Vec<G1Affine>,
+    xi: *const Fp,
+    r: *const Fp,
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_urs_opening_accumulator", std::ptr::null(), || {
+        let urs = handle(urs);
+        let points = handle(points);
+        let comms = handle(comms);
+        let values = handle(values);
+        let proofs = handle(proofs);
+        let xi = handle(xi);
+        let r = handle(r);
+        let points = unsafe { &*points };
+        let comms = unsafe { &*comms };
+        match opening_accumulator(
+            unsafe { &*urs },
+            points,
+            comms,
+            unsafe { &*values },
+            unsafe { &*proofs },
+            unsafe { *xi },
+            unsafe { *r },
+        ) {
+            Some(acc) => into_handle(Box::new(acc)),
+            None => null_with_error(format!(
+                "zexe_bn382_fp_urs_opening_accumulator: {} commitments and values do not split evenly over {} points and proofs",
+                comms.len(),
+                points.len()
+            )),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_opening_accumulator_check(
+    urs: *const URS<Bn_382>,
+    acc: *const AffinePair<G1Affine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_urs_opening_accumulator_check", false, || {
+        let urs = handle(urs);
+        let acc = handle(acc);
+        opening_accumulator_holds(unsafe { &*urs }, unsafe { *acc })
+    })
+}
+
+// Combines the checks that the i-th commitment, given by its unshifted and
+// shifted parts, is below the i-th bound, with powers of r, into the points
+// described at degree_bound_accumulator.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_degree_bound_accumulator(
+    urs: *const URS<Bn_382>,
+    bounds: *const Vec<usize>,
+    unshifted: *const Vec<G1Affine>,
+    shifted: *const Vec<G1Affine>,
+    r: *const Fp,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_fp_urs_degree_bound_accumulator", std::ptr::null(), || {
+        let urs = handle(urs);
+        let bounds = handle(bounds);
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        let r = handle(r);
+        let comms = match degree_bounded_commitments(unsafe { &*unshifted }, unsafe { &*shifted }) {
+            Some(comms) => comms,
+            None => return null_with_error(
+                "zexe_bn382_fp_urs_degree_bound_accumulator: The unshifted and shifted commitments differ in number".to_string(),
+            ),
+        };
+        match degree_bound_accumulator(unsafe { &*urs }, unsafe { &*bounds }, &comms, unsafe { *r }) {
+            Some(acc) => into_handle(Box::new(acc)),
+            None => null_with_error(
+                "zexe_bn382_fp_urs_degree_bound_accumulator: The bounds do not match the commitments or the URS".to_string(),
+            ),
+        }
+    })
+}
 
-    let mut table = vec![
-        (
-            acc_h.into_affine().into(),
-            G2Affine::prime_subgroup_generator().into(),
-        ),
-        (acc_beta_h.into_affine().into(), urs.hx.into()),
-    ];
-    for (acc_j, j) in acc_d.iter().zip(d) {
-        table.push((acc_j.into_affine().into(), urs.hn[&(urs.depth - j)].into()));
+fn degree_bounded_commitments(
+    unshifted: &[G1Affine],
+    shifted: &[G1Affine],
+) -> Option<Vec<DegreeBoundedCommitment>> {
+    if unshifted.len() != shifted.len() {
+        return None;
     }
+    Some(
+        unshifted
+            .iter()
+            .zip(shifted.iter())
+            .map(|(&unshifted, &shifted)| DegreeBoundedCommitment { unshifted, shifted })
+            .collect(),
+    )
+}
 
-    Bn_382::final_exponentiation(&Bn_382::miller_loop(&table)).unwrap()
-        == <Bn_382 as PairingEngine>::Fqk::one()
+// A degree bound accumulator together with the randomizers it was built with.
+pub struct DegreeBoundAccumulator {
+    acc: Vec<G1Affine>,
+    randomizers: Vec<Fp>,
+}
+
+// As zexe_bn382_fp_urs_degree_bound_accumulator, with one randomizer per
+// commitment given by the caller rather than the powers of r.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_degree_bound_accumulator_randomized(
+    urs: *const URS<Bn_382>,
+    bounds: *const Vec<usize>,
+    unshifted: *const Vec<G1Affine>,
+    shifted: *const Vec<G1Affine>,
+    randomizers: *const Vec<Fp>,
+) -> *const Vec<G1Affine> {
+    const NAME: &str = "zexe_bn382_fp_urs_degree_bound_accumulator_randomized";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let urs = handle(urs);
+        let bounds = handle(bounds);
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        let randomizers = handle(randomizers);
+        let comms = match degree_bounded_commitments(unsafe { &*unshifted }, unsafe { &*shifted }) {
+            Some(comms) => comms,
+            None => {
+                return null_with_error(format!(
+                    "{}: The unshifted and shifted commitments differ in number",
+                    NAME
+                ))
+            }
+        };
+        match degree_bound_accumulator_randomized(
+            unsafe { &*urs },
+            unsafe { &*bounds },
+            &comms,
+            unsafe { &*randomizers },
+        ) {
+            Some(acc) => into_handle(Box::new(acc)),
+            None => null_with_error(format!(
+                "{}: The bounds or randomizers do not match the commitments or the URS",
+                NAME
+            )),
+        }
+    })
+}
+
+// As zexe_bn382_fp_urs_degree_bound_accumulator_randomized, with randomizers
+// sampled from the system RNG, which are returned with the accumulator.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_degree_bound_accumulator_sampled(
+    urs: *const URS<Bn_382>,
+    bounds: *const Vec<usize>,
+    unshifted: *const Vec<G1Affine>,
+    shifted: *const Vec<G1Affine>,
+) -> *mut DegreeBoundAccumulator {
+    const NAME: &str = "zexe_bn382_fp_urs_degree_bound_accumulator_sampled";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let bounds = handle(bounds);
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        let comms = match degree_bounded_commitments(unsafe { &*unshifted }, unsafe { &*shifted }) {
+            Some(comms) => comms,
+            None => {
+                return null_with_error(format!(
+                    "{}: The unshifted and shifted commitments differ in number",
+                    NAME
+                ))
+            }
+        };
+        let rng = &mut system_rng();
+        let randomizers: Vec<Fp> = comms.iter().map(|_| Fp::rand(rng)).collect();
+        match degree_bound_accumulator_randomized(unsafe { &*urs }, unsafe { &*bounds }, &comms, &randomizers) {
+            Some(acc) => into_handle(Box::new(DegreeBoundAccumulator { acc, randomizers })),
+            None => null_with_error(format!(
+                "{}: The bounds do not match the commitments or the URS",
+                NAME
+            )),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_degree_bound_accumulator_points(
+    a: *const DegreeBoundAccumulator,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_fp_degree_bound_accumulator_points", std::ptr::null(), || {
+        let a = handle(a);
+        let x = (unsafe { &(*a).acc }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_degree_bound_accumulator_randomizers(
+    a: *const DegreeBoundAccumulator,
+) -> *const Vec<Fp> {
+    catch_ffi("zexe_bn382_fp_degree_bound_accumulator_randomizers", std::ptr::null(), || {
+        let a = handle(a);
+        let x = (unsafe { &(*a).randomizers }).clone();
+        return into_handle(Box::new(x));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_degree_bound_accumulator_delete, DegreeBoundAccumulator);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_degree_bound_accumulator_check(
+    urs: *const URS<Bn_382>,
+    bounds: *const Vec<usize>,
+    acc: *const Vec<G1Affine>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_urs_degree_bound_accumulator_check", false, || {
+        let urs = handle(urs);
+        let bounds = handle(bounds);
+        let acc = handle(acc);
+        degree_bound_accumulator_holds(unsafe { &*urs }, unsafe { &*bounds }, unsafe { &*acc })
+    })
+}
+
+// Both pairing-check accumulators of a batch of openings with degree bounds,
+// which is all a recursive verifier needs to defer the pairing checks of a
+// proof.
+pub struct PairingAccumulators {
+    bounds: Vec<usize>,
+    opening: (G1Affine, G1Affine),
+    degree_bounds: Vec<G1Affine>,
+}
+
+impl PairingAccumulators {
+    // Checks both accumulators with a single Miller loop. The degree bound
+    // terms are scaled by a random s so that they cannot cancel against the
+    // opening terms.
+    fn holds(&self, urs: &URS<Bn_382>) -> bool {
+        if self.degree_bounds.len() != self.bounds.len() + 1 {
+            return false;
+        }
+        let s = Fp::rand(&mut system_rng());
+        let (acc_h, acc_beta_h) = self.opening;
+        let prepared = fp_prepared_verifier_index(urs);
+        let mut hn = vec![];
+        for b in self.bounds.iter() {
+            match prepared.hn(urs, *b) {
+                Some(h) => hn.push(h),
+                None => return false,
+            }
+        }
+
+        let mut g1 = vec![acc_h.into_projective() + &self.degree_bounds[0].mul(s)];
+        g1.extend(self.degree_bounds[1..].iter().map(|c| -c.mul(s)));
+        let g1 = G1Projective::batch_normalization_into_affine(&g1);
+
+        let mut table = vec![(g1[0], prepared.h()), (-acc_beta_h, prepared.hx())];
+        for (c, h) in g1[1..].iter().zip(hn.iter()) {
+            table.push((*c, &**h));
+        }
+        prepared_pairings_vanish(&table)
+    }
+}
+
+// Combines zexe_bn382_fp_urs_opening_accumulator, on the openings, and
+// zexe_bn382_fp_urs_degree_bound_accumulator, on the degree bounded
+// commitments, both batched with r.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_pairing_accumulators(
+    urs: *const URS<Bn_382>,
+    points: *const Vec<Fp>,
+    comms: *const Vec<G1Affine>,
+    values: *const Vec<Fp>,
+    proofs: *const Vec<G1Affine>,
+    bounds: *const Vec<usize>,
+    unshifted: *const Vec<G1Affine>,
+    shifted: *const Vec<G1Affine>,
+    xi: *const Fp,
+    r: *const Fp,
+) -> *mut PairingAccumulators {
+    const NAME: &str = "zexe_bn382_fp_urs_pairing_accumulators";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let points = handle(points);
+        let comms = handle(comms);
+        let values = handle(values);
+        let proofs = handle(proofs);
+        let bounds = handle(bounds);
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        let xi = handle(xi);
+        let r = handle(r);
+        let urs = unsafe { &*urs };
+        let bounds = unsafe { &*bounds };
+        let unshifted = unsafe { &*unshifted };
+        let shifted = unsafe { &*shifted };
+        let opening = match opening_accumulator(
+            urs,
+            unsafe { &*points },
+            unsafe { &*comms },
+            unsafe { &*values },
+            unsafe { &*proofs },
+            unsafe { *xi },
+            unsafe { *r },
+        ) {
+            Some(acc) => acc,
+            None => {
+                return null_with_error(format!(
+                    "{}: The commitments and values do not split evenly over the points and proofs",
+                    NAME
+                ))
+            }
+        };
+        if unshifted.len() != shifted.len() {
+            return null_with_error(format!(
+                "{}: The unshifted and shifted commitments differ in number",
+                NAME
+            ));
+        }
+        let comms: Vec<_> = unshifted
+            .iter()
+            .zip(shifted.iter())
+            .map(|(&unshifted, &shifted)| DegreeBoundedCommitment { unshifted, shifted })
+            .collect();
+        match degree_bound_accumulator(urs, bounds, &comms, unsafe { *r }) {
+            Some(degree_bounds) => into_handle(Box::new(PairingAccumulators {
+                bounds: bounds.clone(),
+                opening,
+                degree_bounds,
+            })),
+            None => null_with_error(format!(
+                "{}: The bounds do not match the commitments or the URS",
+                NAME
+            )),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_pairing_accumulators_opening(
+    acc: *const PairingAccumulators,
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_pairing_accumulators_opening", std::ptr::null(), || {
+        let acc = handle(acc);
+        into_handle(Box::new((unsafe { &*acc }).opening))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_pairing_accumulators_degree_bounds(
+    acc: *const PairingAccumulators,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_fp_pairing_accumulators_degree_bounds", std::ptr::null(), || {
+        let acc = handle(acc);
+        into_handle(Box::new((unsafe { &*acc }).degree_bounds.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_pairing_accumulators_check(
+    urs: *const URS<Bn_382>,
+    acc: *const PairingAccumulators,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_pairing_accumulators_check", false, || {
+        let urs = handle(urs);
+        let acc = handle(acc);
+        (unsafe { &*acc }).holds(unsafe { &*urs })
+    })
 }
 
+crate::delete_stub!(zexe_bn382_fp_pairing_accumulators_delete, PairingAccumulators);
+
 // Fp proof
+fn fp_witness_fast_path(index: *const Index<Bn_382>, auxiliary_input: &[Fp]) -> bool {
+    let uses_auxiliary =
+        index_uses_auxiliary(index, || fp_index_scans_auxiliary(unsafe { &*index }));
+    witness_fast_path(uses_auxiliary, auxiliary_input)
+}
+
+// Whether zexe_bn382_fp_proof_create would prepare the witness from the
+// primary input alone, because the auxiliary input is empty or all zero or the
+// index does not refer to it.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_fast_path(
+    index: *const Index<Bn_382>,
+    auxiliary_input: *const Vec<Fp>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_proof_fast_path", false, || {
+        let index = handle(index);
+        let auxiliary_input = handle(auxiliary_input);
+        if !check_handle("zexe_bn382_fp_proof_fast_path", index) {
+            return false;
+        }
+        fp_witness_fast_path(index, unsafe { &*auxiliary_input })
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_create(
     index: *const Index<Bn_382>,
     primary_input: *const Vec<Fp>,
     auxiliary_input: *const Vec<Fp>,
 ) -> *const ProverProof<Bn_382> {
-    let index = unsafe { &(*index) };
-    let primary_input = unsafe { &(*primary_input) };
-    let auxiliary_input = unsafe { &(*auxiliary_input) };
+    catch_ffi("zexe_bn382_fp_proof_create", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        if !check_handle("zexe_bn382_fp_proof_create", index) {
+            return std::ptr::null();
+        }
+        catch_ffi("zexe_bn382_fp_proof_create", std::ptr::null(), || in_pool(|| {
+            let primary_input = unsafe { &(*primary_input) };
+            let auxiliary_input = unsafe { &(*auxiliary_input) };
+            let fast_path = fp_witness_fast_path(index, auxiliary_input);
+            let index = unsafe { &(*index) };
+
+            if let Err(e) = check_labelled_system(index, primary_input, auxiliary_input) {
+                return null_with_error(format!("zexe_bn382_fp_proof_create: {}", e));
+            }
 
-    let witness = prepare_witness(index.domains, primary_input, auxiliary_input);
+            let witness = if fast_path {
+                prepare_witness(index.domains, primary_input, &vec![])
+            } else {
+                prepare_witness(index.domains, primary_input, auxiliary_input)
+            };
 
-    let proof = ProverProof::create::<
-        DefaultFqSponge<Bn_382G1Parameters, SC>,
-        DefaultFrSponge<Fp, SC>,
-    >(&witness, &index)
-    .unwrap();
+            let proof = ProverProof::create::<
+                DefaultFqSponge<Bn_382G1Parameters, SC>,
+                DefaultFrSponge<Fp, SC>,
+            >(&witness, &index)
+            .unwrap();
 
-    return Box::into_raw(Box::new(proof));
+            return into_handle(Box::new(proof));
+        }))
+    })
 }
 
 // TODO: Batch verify across different indexes
@@ -515,17 +1516,23 @@ pub extern "C" fn zexe_bn382_fp_proof_batch_verify(
     index: *const VerifierIndex<Bn_382>,
     proofs: *const Vec<ProverProof<Bn_382>>,
 ) -> bool {
-    let index = unsafe { &(*index) };
-    let proofs = unsafe { &(*proofs) };
-
-    match ProverProof::<Bn_382>::verify::<
-        DefaultFqSponge<Bn_382G1Parameters, SC>,
-        DefaultFrSponge<Fp, SC>,
-    >(proofs, index, &mut rand_core::OsRng)
-    {
-        Ok(_) => true,
-        Err(_) => false,
-    }
+    catch_ffi("zexe_bn382_fp_proof_batch_verify", false, || {
+        let index = handle(index);
+        let proofs = handle(proofs);
+        in_pool(|| {
+            let index = unsafe { &(*index) };
+            let proofs = unsafe { &(*proofs) };
+
+            match ProverProof::<Bn_382>::verify::<
+                DefaultFqSponge<Bn_382G1Parameters, SC>,
+                DefaultFrSponge<Fp, SC>,
+            >(proofs, index, &mut system_rng())
+            {
+                Ok(status) => status,
+                Err(_) => false,
+            }
+        })
+    })
 }
 
 #[no_mangle]
@@ -533,17 +1540,103 @@ pub extern "C" fn zexe_bn382_fp_proof_verify(
     index: *const VerifierIndex<Bn_382>,
     proof: *const ProverProof<Bn_382>,
 ) -> bool {
-    let index = unsafe { &(*index) };
-    let proof = unsafe { (*proof).clone() };
-
-    match ProverProof::verify::<DefaultFqSponge<Bn_382G1Parameters, SC>, DefaultFrSponge<Fp, SC>>(
-        &[proof].to_vec(),
-        &index,
-        &mut rand_core::OsRng,
-    ) {
-        Ok(status) => status,
-        _ => false,
-    }
+    catch_ffi("zexe_bn382_fp_proof_verify", false, || {
+        let index = handle(index);
+        let proof = handle(proof);
+        if !check_handle("zexe_bn382_fp_proof_verify", index)
+            || !check_handle("zexe_bn382_fp_proof_verify", proof)
+        {
+            return false;
+        }
+        in_pool(|| {
+            let index = unsafe { &(*index) };
+            let proof = unsafe { (*proof).clone() };
+
+            match ProverProof::verify::<DefaultFqSponge<Bn_382G1Parameters, SC>, DefaultFrSponge<Fp, SC>>(
+                &[proof].to_vec(),
+                &index,
+                &mut system_rng(),
+            ) {
+                Ok(status) => status,
+                _ => false,
+            }
+        })
+    })
+}
+
+// Checks that the public input of a proof is padded to the size of the x
+// domain with zeros beyond the index's public inputs.
+fn public_input_is_consistent(public: &[Fp], x_size: usize, public_inputs: usize) -> bool {
+    public.len() == x_size && public.iter().skip(public_inputs).all(|x| x.is_zero())
+}
+
+// As zexe_bn382_fp_proof_verify, but returns one of the VERIFY_* codes and
+// records the reason for a failure as the last error.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_verify_diagnostic(
+    index: *const VerifierIndex<Bn_382>,
+    proof: *const ProverProof<Bn_382>,
+) -> i32 {
+    catch_ffi("zexe_bn382_fp_proof_verify_diagnostic", VERIFY_FAILED_MALFORMED, || {
+        const NAME: &str = "zexe_bn382_fp_proof_verify_diagnostic";
+        let index = handle(index);
+        let proof = handle(proof);
+        if !check_handle(NAME, index) || !check_handle(NAME, proof) {
+            return VERIFY_FAILED_MALFORMED;
+        }
+        catch_ffi(NAME, VERIFY_FAILED_MALFORMED, || {
+            if zexe_bn382_fp_proof_verify(index, proof) {
+                return VERIFY_OK;
+            }
+            let index = unsafe { &(*index) };
+            let proof = unsafe { &(*proof) };
+
+            if !public_input_is_consistent(&proof.public, index.domains.x.size(), index.public_inputs) {
+                return verify_failure(NAME, VERIFY_FAILED_PUBLIC_INPUT);
+            }
+
+            let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+            let x_hat_comm = match index.urs.commit(&x_hat) {
+                Ok(c) => c,
+                Err(_) => return verify_failure(NAME, VERIFY_FAILED_PUBLIC_INPUT),
+            };
+            let oracles = match proof
+                .oracles::<DefaultFqSponge<Bn_382G1Parameters, SC>, DefaultFrSponge<Fp, SC>>(
+                    index, x_hat_comm, &x_hat,
+                ) {
+                Ok(oracles) => oracles,
+                Err(_) => return verify_failure(NAME, VERIFY_FAILED_MALFORMED),
+            };
+
+            if !proof.sumcheck_1_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_1);
+            }
+            if !proof.sumcheck_2_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_2);
+            }
+            if !proof.sumcheck_3_verify(index, &oracles) {
+                return verify_failure(NAME, VERIFY_FAILED_SUMCHECK_3);
+            }
+
+            // The g polynomials of the three sumchecks are committed to with
+            // degree bounds; the first component of each pair is the shifted
+            // commitment.
+            let h = index.domains.h.size();
+            let k = index.domains.k.size();
+            let bounded = [(proof.g1_comm, h - 1), (proof.g2_comm, h - 1), (proof.g3_comm, k - 1)];
+            for ((shifted, unshifted), bound) in bounded.iter() {
+                let c = DegreeBoundedCommitment {
+                    unshifted: *unshifted,
+                    shifted: *shifted,
+                };
+                if !degree_bound_holds(&index.urs, &c, *bound) {
+                    return verify_failure(NAME, VERIFY_FAILED_DEGREE_BOUND);
+                }
+            }
+
+            verify_failure(NAME, VERIFY_FAILED_OPENING)
+        })
+    })
 }
 
 #[no_mangle]
@@ -595,306 +1688,557 @@ pub extern "C" fn zexe_bn382_fp_proof_make(
     rc_1: *const Fp,
     rc_2: *const Fp,
 ) -> *const ProverProof<Bn_382> {
-    let mut public = unsafe { &(*primary_input) }.clone();
-    public.resize(ceil_pow2(public.len()), Fp::zero());
-
-    let proof = ProverProof {
-        w_comm: (unsafe { *w_comm }).clone(),
-        za_comm: (unsafe { *za_comm }).clone(),
-        zb_comm: (unsafe { *zb_comm }).clone(),
-        h1_comm: (unsafe { *h1_comm }).clone(),
-        g1_comm: (
-            (unsafe { *g1_comm_0 }).clone(),
-            (unsafe { *g1_comm_1 }).clone(),
-        ),
-        h2_comm: (unsafe { *h2_comm }).clone(),
-        g2_comm: (
-            (unsafe { *g2_comm_0 }).clone(),
-            (unsafe { *g2_comm_1 }).clone(),
-        ),
-        h3_comm: (unsafe { *h3_comm }).clone(),
-        g3_comm: (
-            (unsafe { *g3_comm_0 }).clone(),
-            (unsafe { *g3_comm_1 }).clone(),
-        ),
-        proof1: (unsafe { *proof1 }).clone(),
-        proof2: (unsafe { *proof2 }).clone(),
-        proof3: (unsafe { *proof3 }).clone(),
-        public,
-        sigma2: (unsafe { *sigma2 }).clone(),
-        sigma3: (unsafe { *sigma3 }).clone(),
-        evals: ProofEvaluations {
-            w: (unsafe { *w }).clone(),
-            za: (unsafe { *za }).clone(),
-            zb: (unsafe { *zb }).clone(),
-            h1: (unsafe { *h1 }).clone(),
-            g1: (unsafe { *g1 }).clone(),
-            h2: (unsafe { *h2 }).clone(),
-            g2: (unsafe { *g2 }).clone(),
-            h3: (unsafe { *h3 }).clone(),
-            g3: (unsafe { *g3 }).clone(),
-            row: [
-                (unsafe { *row_0 }).clone(),
-                (unsafe { *row_1 }).clone(),
-                (unsafe { *row_2 }).clone(),
-            ],
-            col: [
-                (unsafe { *col_0 }).clone(),
-                (unsafe { *col_1 }).clone(),
-                (unsafe { *col_2 }).clone(),
-            ],
-            val: [
-                (unsafe { *val_0 }).clone(),
-                (unsafe { *val_1 }).clone(),
-                (unsafe { *val_2 }).clone(),
-            ],
-            rc: [
-                (unsafe { *rc_0 }).clone(),
-                (unsafe { *rc_1 }).clone(),
-                (unsafe { *rc_2 }).clone(),
-            ],
-        },
-    };
+    catch_ffi("zexe_bn382_fp_proof_make", std::ptr::null(), || {
+        let primary_input = handle(primary_input);
+        let w_comm = handle(w_comm);
+        let za_comm = handle(za_comm);
+        let zb_comm = handle(zb_comm);
+        let h1_comm = handle(h1_comm);
+        let g1_comm_0 = handle(g1_comm_0);
+        let g1_comm_1 = handle(g1_comm_1);
+        let h2_comm = handle(h2_comm);
+        let g2_comm_0 = handle(g2_comm_0);
+        let g2_comm_1 = handle(g2_comm_1);
+        let h3_comm = handle(h3_comm);
+        let g3_comm_0 = handle(g3_comm_0);
+        let g3_comm_1 = handle(g3_comm_1);
+        let proof1 = handle(proof1);
+        let proof2 = handle(proof2);
+        let proof3 = handle(proof3);
+        let sigma2 = handle(sigma2);
+        let sigma3 = handle(sigma3);
+        let w = handle(w);
+        let za = handle(za);
+        let zb = handle(zb);
+        let h1 = handle(h1);
+        let g1 = handle(g1);
+        let h2 = handle(h2);
+        let g2 = handle(g2);
+        let h3 = handle(h3);
+        let g3 = handle(g3);
+        let row_0 = handle(row_0);
+        let row_1 = handle(row_1);
+        let row_2 = handle(row_2);
+        let col_0 = handle(col_0);
+        let col_1 = handle(col_1);
+        let col_2 = handle(col_2);
+        let val_0 = handle(val_0);
+        let val_1 = handle(val_1);
+        let val_2 = handle(val_2);
+        let rc_0 = handle(rc_0);
+        let rc_1 = handle(rc_1);
+        let rc_2 = handle(rc_2);
+        let mut public = unsafe { &(*primary_input) }.clone();
+        match ceil_pow2(public.len()) {
+            Ok(n) => public.resize(n, Fp::zero()),
+            Err(e) => return null_with_error(format!("zexe_bn382_fp_proof_make: {}", e)),
+        }
+
+        let proof = ProverProof {
+            w_comm: (unsafe { *w_comm }).clone(),
+            za_comm: (unsafe { *za_comm }).clone(),
+            zb_comm: (unsafe { *zb_comm }).clone(),
+            h1_comm: (unsafe { *h1_comm }).clone(),
+            g1_comm: (
+                (unsafe { *g1_comm_0 }).clone(),
+                (unsafe { *g1_comm_1 }).clone(),
+            ),
+            h2_comm: (unsafe { *h2_comm }).clone(),
+            g2_comm: (
+                (unsafe { *g2_comm_0 }).clone(),
+                (unsafe { *g2_comm_1 }).clone(),
+            ),
+            h3_comm: (unsafe { *h3_comm }).clone(),
+            g3_comm: (
+                (unsafe { *g3_comm_0 }).clone(),
+                (unsafe { *g3_comm_1 }).clone(),
+            ),
+            proof1: (unsafe { *proof1 }).clone(),
+            proof2: (unsafe { *proof2 }).clone(),
+            proof3: (unsafe { *proof3 }).clone(),
+            public,
+            sigma2: (unsafe { *sigma2 }).clone(),
+            sigma3: (unsafe { *sigma3 }).clone(),
+            evals: ProofEvaluations {
+                w: (unsafe { *w }).clone(),
+                za: (unsafe { *za }).clone(),
+                zb: (unsafe { *zb }).clone(),
+                h1: (unsafe { *h1 }).clone(),
+                g1: (unsafe { *g1 }).clone(),
+                h2: (unsafe { *h2 }).clone(),
+                g2: (unsafe { *g2 }).clone(),
+                h3: (unsafe { *h3 }).clone(),
+                g3: (unsafe { *g3 }).clone(),
+                row: [
+                    (unsafe { *row_0 }).clone(),
+                    (unsafe { *row_1 }).clone(),
+                    (unsafe { *row_2 }).clone(),
+                ],
+                col: [
+                    (unsafe { *col_0 }).clone(),
+                    (unsafe { *col_1 }).clone(),
+                    (unsafe { *col_2 }).clone(),
+                ],
+                val: [
+                    (unsafe { *val_0 }).clone(),
+                    (unsafe { *val_1 }).clone(),
+                    (unsafe { *val_2 }).clone(),
+                ],
+                rc: [
+                    (unsafe { *rc_0 }).clone(),
+                    (unsafe { *rc_1 }).clone(),
+                    (unsafe { *rc_2 }).clone(),
+                ],
+            },
+        };
+
+        return into_handle(Box::new(proof));
+    })
+}
+
+fn g1_affine_is_valid(p: &G1Affine) -> bool {
+    p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve()
+}
+
+// Checks that a proof (typically one assembled with zexe_bn382_fp_proof_make)
+// is well-formed with respect to the given verifier index, so that assembly
+// mistakes are caught before they show up as verification failures.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_validate_structure(
+    index: *const VerifierIndex<Bn_382>,
+    proof: *const ProverProof<Bn_382>,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_proof_validate_structure", false, || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
+
+        // The public input is padded to the size of the x domain.
+        if proof.public.len() != index.domains.x.size() {
+            return false;
+        }
+
+        let comms = [
+            proof.w_comm,
+            proof.za_comm,
+            proof.zb_comm,
+            proof.h1_comm,
+            proof.g1_comm.0,
+            proof.g1_comm.1,
+            proof.h2_comm,
+            proof.g2_comm.0,
+            proof.g2_comm.1,
+            proof.h3_comm,
+            proof.g3_comm.0,
+            proof.g3_comm.1,
+            proof.proof1,
+            proof.proof2,
+            proof.proof3,
+        ];
+        comms.iter().all(g1_affine_is_valid)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_write(
+    proof: *const ProverProof<Bn_382>,
+    path: *const c_char,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_proof_write", false, || {
+        let proof = handle(proof);
+        let proof = unsafe { &*proof };
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let res: IoResult<()> = (|| {
+            let mut w = BufWriter::new(File::create(&path)?);
+            write_pairing_proof(proof, &mut w)?;
+            w.flush()
+        })();
+        match res {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fp_proof_write: {}: {}", path, e));
+                false
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_read(path: *const c_char) -> *const ProverProof<Bn_382> {
+    catch_ffi("zexe_bn382_fp_proof_read", std::ptr::null(), || {
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let res: IoResult<ProverProof<Bn_382>> = (|| {
+            let r = BufReader::new(File::open(&path)?);
+            read_pairing_proof(r)
+        })();
+        match res {
+            Ok(proof) => into_handle(Box::new(proof)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_proof_read: {}: {}", path, e)),
+        }
+    })
+}
 
-    return Box::into_raw(Box::new(proof));
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_proof_to_bytes(proof: *const ProverProof<Bn_382>) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fp_proof_to_bytes", std::ptr::null_mut(), || {
+        let proof = handle(proof);
+        let proof = unsafe { &*proof };
+        let mut res = vec![];
+        write_pairing_proof(proof, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_proof_delete(x: *mut ProverProof<Bn_382>) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fp_proof_of_bytes(
+    data: *const u8,
+    len: usize,
+) -> *const ProverProof<Bn_382> {
+    catch_ffi("zexe_bn382_fp_proof_of_bytes", std::ptr::null(), || {
+        let mut data = unsafe { std::slice::from_raw_parts(data, len) };
+        match read_pairing_proof(&mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_fp_proof_of_bytes: trailing bytes after proof".to_string(),
+            ),
+            Ok(proof) => into_handle(Box::new(proof)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_proof_of_bytes: {}", e)),
+        }
+    })
 }
 
+crate::delete_stub!(zexe_bn382_fp_proof_delete, ProverProof<Bn_382>);
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_w_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).w_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_w_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).w_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_za_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).za_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_za_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).za_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_zb_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).zb_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_zb_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).zb_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h1_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).h1_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h1_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).h1_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g1_comm_nocopy(
     p: *mut ProverProof<Bn_382>,
-) -> *const (G1Affine, G1Affine) {
-    let x = unsafe { (*p).g1_comm };
-    return Box::into_raw(Box::new(x));
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_proof_g1_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).g1_comm };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h2_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).h2_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h2_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).h2_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g2_comm_nocopy(
     p: *mut ProverProof<Bn_382>,
-) -> *const (G1Affine, G1Affine) {
-    let x = unsafe { (*p).g2_comm };
-    return Box::into_raw(Box::new(x));
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_proof_g2_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).g2_comm };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h3_comm(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).h3_comm }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h3_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).h3_comm }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g3_comm_nocopy(
     p: *mut ProverProof<Bn_382>,
-) -> *const (G1Affine, G1Affine) {
-    let x = unsafe { (*p).g3_comm };
-    return Box::into_raw(Box::new(x));
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_proof_g3_comm_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).g3_comm };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_commitment_with_degree_bound_0(
-    p: *const (G1Affine, G1Affine),
+    p: *const AffinePair<G1Affine>,
 ) -> *const G1Affine {
-    let (x0, _) = unsafe { *p };
-    return Box::into_raw(Box::new(x0.clone()));
+    catch_ffi("zexe_bn382_fp_proof_commitment_with_degree_bound_0", std::ptr::null(), || {
+        let p = handle(p);
+        let (x0, _) = unsafe { *p };
+        return into_handle(Box::new(x0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_commitment_with_degree_bound_1(
-    p: *const (G1Affine, G1Affine),
+    p: *const AffinePair<G1Affine>,
 ) -> *const G1Affine {
-    let (_, x1) = unsafe { *p };
-    return Box::into_raw(Box::new(x1.clone()));
+    catch_ffi("zexe_bn382_fp_proof_commitment_with_degree_bound_1", std::ptr::null(), || {
+        let p = handle(p);
+        let (_, x1) = unsafe { *p };
+        return into_handle(Box::new(x1.clone()));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_proof_commitment_with_degree_bound_delete(x: *mut G1Affine) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_proof_commitment_with_degree_bound_delete, G1Affine);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_proof1(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).proof1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_proof1", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).proof1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_proof2(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).proof2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_proof2", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).proof2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_proof3(p: *mut ProverProof<Bn_382>) -> *const G1Affine {
-    let x = (unsafe { (*p).proof3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_proof3", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).proof3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_sigma2(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).sigma2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_sigma2", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).sigma2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_sigma3(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).sigma3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_sigma3", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).sigma3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_w_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.w }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_w_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.w }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_za_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.za }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_za_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.za }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_zb_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.zb }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_zb_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.zb }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h1_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.h1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h1_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.h1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g1_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.g1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_g1_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.g1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h2_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.h2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h2_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.h2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g2_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.g2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_g2_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.g2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_h3_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.h3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_h3_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.h3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_g3_eval(p: *mut ProverProof<Bn_382>) -> *const Fp {
-    let x = (unsafe { (*p).evals.g3 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_g3_eval", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { (*p).evals.g3 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_row_evals_nocopy(
     p: *mut ProverProof<Bn_382>,
 ) -> *const [Fp; 3] {
-    let x = unsafe { (*p).evals.row };
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_row_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).evals.row };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_col_evals_nocopy(
     p: *mut ProverProof<Bn_382>,
 ) -> *const [Fp; 3] {
-    let x = unsafe { (*p).evals.col };
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_col_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).evals.col };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_val_evals_nocopy(
     p: *mut ProverProof<Bn_382>,
 ) -> *const [Fp; 3] {
-    let x = unsafe { (*p).evals.val };
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_val_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).evals.val };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_rc_evals_nocopy(
     p: *mut ProverProof<Bn_382>,
 ) -> *const [Fp; 3] {
-    let x = unsafe { (*p).evals.rc };
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_rc_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = unsafe { (*p).evals.rc };
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_evals_0(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[0].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_evals_0", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[0].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_evals_1(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[1].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_evals_1", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[1].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_evals_2(evals: *const [Fp; 3]) -> *const Fp {
-    let x = (unsafe { *evals })[2].clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_bn382_fp_proof_evals_2", std::ptr::null(), || {
+        let evals = handle(evals);
+        let x = (unsafe { *evals })[2].clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_proof_evals_delete(x: *mut [Fp; 3]) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_proof_evals_delete, [Fp; 3]);
 
 // Fp proof vector
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_vector_create() -> *mut Vec<ProverProof<Bn_382>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fp_proof_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_proof_vector_length(v: *const Vec<ProverProof<Bn_382>>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fp_proof_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -902,9 +2246,13 @@ pub extern "C" fn zexe_bn382_fp_proof_vector_emplace_back(
     v: *mut Vec<ProverProof<Bn_382>>,
     x: *const ProverProof<Bn_382>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(x_.clone());
+    catch_ffi("zexe_bn382_fp_proof_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(x_.clone());
+    })
 }
 
 #[no_mangle]
@@ -912,16 +2260,133 @@ pub extern "C" fn zexe_bn382_fp_proof_vector_get(
     v: *mut Vec<ProverProof<Bn_382>>,
     i: u32,
 ) -> *mut ProverProof<Bn_382> {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new(v_[i as usize].clone()));
-}
+    catch_ffi("zexe_bn382_fp_proof_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new(v_[i as usize].clone()));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_proof_vector_delete, Vec<ProverProof<Bn_382>>);
+
+// Fp wNAF tables
+//
+// The tables of the generator of a URS, and of the Lagrange commitments of an
+// x domain, which the verifier multiplies for each proof. Each thread builds
+// them once per base, or per URS and domain size.
+
+type FpWnafTable = FixedBaseWnafTable<G1Affine>;
+
+thread_local! {
+    static FP_GENERATOR_TABLES: RefCell<HashMap<G1Affine, Rc<FpWnafTable>>> =
+        RefCell::new(HashMap::new());
+    static FP_LAGRANGE_TABLES: RefCell<HashMap<XHatCacheKey, Rc<Vec<FpWnafTable>>>> =
+        RefCell::new(HashMap::new());
+}
+
+fn fp_generator_table(g: G1Affine) -> Rc<FpWnafTable> {
+    FP_GENERATOR_TABLES.with(|t| {
+        t.borrow_mut()
+            .entry(g)
+            .or_insert_with(|| Rc::new(FixedBaseWnafTable::new(g, WNAF_WINDOW)))
+            .clone()
+    })
+}
+
+fn fp_lagrange_tables(urs: &URS<Bn_382>, domain: Domain<Fp>) -> Rc<Vec<FpWnafTable>> {
+    let key = x_hat_cache_key(&urs.hx, domain.size, &[]);
+    FP_LAGRANGE_TABLES.with(|t| {
+        t.borrow_mut()
+            .entry(key)
+            .or_insert_with(|| Rc::new(wnaf_tables(&lagrange_commitments(&urs.gp, domain))))
+            .clone()
+    })
+}
+
+// Fp prepared verifier indexes
+//
+// The pairing checks of the verifier pair their accumulators with the same G2
+// elements of a URS for every proof: H, beta H and the beta^{depth - b} H of
+// the degree bounds. Preparing a G2 element for the Miller loop costs about
+// as much as the loop itself, so each thread prepares those of a URS once,
+// keyed by beta H and the depth, which determine them. The degree bound
+// elements are prepared as bounds are first checked, since a URS may hold
+// many of them.
+
+type G1Prepared = <Bn_382 as PairingEngine>::G1Prepared;
+type G2Prepared = <Bn_382 as PairingEngine>::G2Prepared;
+
+pub struct PreparedVerifierIndex {
+    depth: usize,
+    h: G2Prepared,
+    hx: G2Prepared,
+    hn: RefCell<HashMap<usize, Rc<G2Prepared>>>,
+}
+
+impl PreparedVerifierIndex {
+    pub fn new(urs: &URS<Bn_382>) -> Self {
+        PreparedVerifierIndex {
+            depth: urs.depth,
+            h: G2Affine::prime_subgroup_generator().into(),
+            hx: urs.hx.into(),
+            hn: RefCell::new(HashMap::new()),
+        }
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_proof_vector_delete(v: *mut Vec<ProverProof<Bn_382>>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+    // H
+    pub fn h(&self) -> &G2Prepared {
+        &self.h
+    }
+
+    // beta H
+    pub fn hx(&self) -> &G2Prepared {
+        &self.hx
+    }
+
+    // beta^{depth - bound} H, or None if the URS does not support the bound.
+    pub fn hn(&self, urs: &URS<Bn_382>, bound: usize) -> Option<Rc<G2Prepared>> {
+        if bound > self.depth {
+            return None;
+        }
+        let i = self.depth - bound;
+        if let Some(h) = self.hn.borrow().get(&i) {
+            return Some(h.clone());
+        }
+        let h = Rc::new(G2Prepared::from(*urs.hn.get(&i)?));
+        self.hn.borrow_mut().insert(i, h.clone());
+        Some(h)
+    }
+}
+
+thread_local! {
+    static FP_PREPARED_INDEXES: RefCell<HashMap<XHatCacheKey, Rc<PreparedVerifierIndex>>> =
+        RefCell::new(HashMap::new());
+}
+
+pub fn fp_prepared_verifier_index(urs: &URS<Bn_382>) -> Rc<PreparedVerifierIndex> {
+    let key = x_hat_cache_key(&urs.hx, urs.depth as u64, &[]);
+    FP_PREPARED_INDEXES.with(|t| {
+        t.borrow_mut()
+            .entry(key)
+            .or_insert_with(|| Rc::new(PreparedVerifierIndex::new(urs)))
+            .clone()
+    })
+}
+
+// Checks that the product of the pairings of the pairs is one, with a single
+// Miller loop.
+fn prepared_pairings_vanish(pairs: &[(G1Affine, &G2Prepared)]) -> bool {
+    let g1: Vec<G1Prepared> = pairs.iter().map(|(p, _)| (*p).into()).collect();
+    Bn_382::product_of_prepared_pairings(g1.iter().zip(pairs.iter().map(|(_, q)| *q)))
+        == <Bn_382 as PairingEngine>::Fqk::one()
+}
+
+// Drops the calling thread's tables and prepared verifier indexes.
+pub(crate) fn clear_fp_caches() {
+    FP_GENERATOR_TABLES.with(|t| t.borrow_mut().clear());
+    FP_LAGRANGE_TABLES.with(|t| t.borrow_mut().clear());
+    FP_PREPARED_INDEXES.with(|t| t.borrow_mut().clear());
+}
 
 // Fp oracles
 #[no_mangle]
@@ -929,106 +2394,162 @@ pub extern "C" fn zexe_bn382_fp_oracles_create(
     index: *const VerifierIndex<Bn_382>,
     proof: *const ProverProof<Bn_382>,
 ) -> *const RandomOracles<Fp> {
-    let index = unsafe { &(*index) };
-    let proof = unsafe { &(*proof) };
-
-    let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
-    let x_hat_comm = index.urs.commit(&x_hat).unwrap();
+    catch_ffi("zexe_bn382_fp_oracles_create", std::ptr::null(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let proof = unsafe { &(*proof) };
+        if proof.public.len() > index.domains.x.size() {
+            return null_with_error(format!(
+                "zexe_bn382_fp_oracles_create: {} public inputs for a domain of size {}",
+                proof.public.len(),
+                index.domains.x.size()
+            ));
+        }
 
-    let oracles = proof
-        .oracles::<DefaultFqSponge<Bn_382G1Parameters, SC>, DefaultFrSponge<Fp, SC>>(
-            index, x_hat_comm, &x_hat,
+        let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+        // The commitment to x_hat, as the combination of the Lagrange
+        // commitments with the public input.
+        let x_hat_comm = wnaf_linear_combination(
+            &fp_lagrange_tables(&index.urs, index.domains.x),
+            &proof.public,
         )
-        .unwrap();
-    return Box::into_raw(Box::new(oracles));
+        .into_affine();
+
+        let oracles = proof
+            .oracles::<DefaultFqSponge<Bn_382G1Parameters, SC>, DefaultFrSponge<Fp, SC>>(
+                index, x_hat_comm, &x_hat,
+            )
+            .unwrap();
+        return into_handle(Box::new(oracles));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_alpha(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).alpha.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_alpha", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).alpha.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_eta_a(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).eta_a.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_eta_a", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).eta_a.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_eta_b(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).eta_b.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_eta_b", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).eta_b.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_eta_c(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).eta_c.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_eta_c", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).eta_c.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_beta1(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).beta[0].0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_beta1", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).beta[0].0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_beta2(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).beta[1].0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_beta2", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).beta[1].0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_beta3(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).beta[2].0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_beta3", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).beta[2].0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_r_k(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).r_k.0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_r_k", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).r_k.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_batch(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).batch.0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_batch", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).batch.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_r(oracles: *const RandomOracles<Fp>) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).r.0.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_r", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).r.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_x_hat_beta1(
     oracles: *const RandomOracles<Fp>,
 ) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).x_hat_beta1.clone()));
+    catch_ffi("zexe_bn382_fp_oracles_x_hat_beta1", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).x_hat_beta1.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_oracles_digest_before_evaluations(
     oracles: *const RandomOracles<Fp>,
 ) -> *const Fp {
-    return Box::into_raw(Box::new(
-        (unsafe { &(*oracles) }).digest_before_evaluations.clone(),
-    ));
+    catch_ffi("zexe_bn382_fp_oracles_digest_before_evaluations", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new(
+            (unsafe { &(*oracles) }).digest_before_evaluations.clone(),
+        ));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_oracles_delete(x: *mut RandomOracles<Fp>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_oracles_delete, RandomOracles<Fp>);
 
 // Fp verifier index stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_create(
     index: *const Index<Bn_382>,
 ) -> *const VerifierIndex<Bn_382> {
-    Box::into_raw(Box::new(unsafe { &(*index) }.verifier_index()))
+    catch_ffi("zexe_bn382_fp_verifier_index_create", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(unsafe { &(*index) }.verifier_index()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_urs(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const URS<Bn_382> {
-    let index = unsafe { &*index };
-    let urs = index.urs.clone();
-    Box::into_raw(Box::new(urs))
+    catch_ffi("zexe_bn382_fp_verifier_index_urs", std::ptr::null(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let urs = index.urs.clone();
+        into_handle(Box::new(urs))
+    })
 }
 
 #[no_mangle]
@@ -1054,45 +2575,89 @@ pub extern "C" fn zexe_bn382_fp_verifier_index_make(
     val_c: *const G1Affine,
     rc_c: *const G1Affine,
 ) -> *const VerifierIndex<Bn_382> {
-    let urs: URS<Bn_382> = (unsafe { &*urs }).clone();
+    catch_ffi("zexe_bn382_fp_verifier_index_make", std::ptr::null(), || {
+        let urs = handle(urs);
+        let row_a = handle(row_a);
+        let col_a = handle(col_a);
+        let val_a = handle(val_a);
+        let rc_a = handle(rc_a);
+        let row_b = handle(row_b);
+        let col_b = handle(col_b);
+        let val_b = handle(val_b);
+        let rc_b = handle(rc_b);
+        let row_c = handle(row_c);
+        let col_c = handle(col_c);
+        let val_c = handle(val_c);
+        let rc_c = handle(rc_c);
+        let urs: URS<Bn_382> = (unsafe { &*urs }).clone();
+        let (endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
+        let index = VerifierIndex {
+            domains: EvaluationDomains::create(variables, constraints, public_inputs, nonzero_entries)
+                .unwrap(),
+            matrix_commitments: [
+                MatrixValues {
+                    row: (unsafe { *row_a }).clone(),
+                    col: (unsafe { *col_a }).clone(),
+                    val: (unsafe { *val_a }).clone(),
+                    rc: (unsafe { *rc_a }).clone(),
+                },
+                MatrixValues {
+                    row: (unsafe { *row_b }).clone(),
+                    col: (unsafe { *col_b }).clone(),
+                    val: (unsafe { *val_b }).clone(),
+                    rc: (unsafe { *rc_b }).clone(),
+                },
+                MatrixValues {
+                    row: (unsafe { *row_c }).clone(),
+                    col: (unsafe { *col_c }).clone(),
+                    val: (unsafe { *val_c }).clone(),
+                    rc: (unsafe { *rc_c }).clone(),
+                },
+            ],
+            fq_sponge_params: oracle::bn_382::fq::params(),
+            fr_sponge_params: oracle::bn_382::fp::params(),
+            max_degree,
+            public_inputs,
+            urs,
+            endo_q,
+            endo_r,
+        };
+        into_handle(Box::new(index))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_verifier_index_delete, VerifierIndex<Bn_382>);
+
+fn write_verifier_index<W: Write>(index: &VerifierIndex<Bn_382>, mut w: W) -> IoResult<()> {
+    for c in index.matrix_commitments.iter() {
+        write_matrix_values(c, &mut w)?;
+    }
+    write_evaluation_domains(&index.domains, &mut w)?;
+    u64::write(&(index.public_inputs as u64), &mut w)?;
+    u64::write(&(index.max_degree as u64), &mut w)?;
+    index.urs.write(&mut w)
+}
+
+fn read_verifier_index<R: Read>(mut r: R) -> IoResult<VerifierIndex<Bn_382>> {
+    let m0 = read_matrix_values(&mut r)?;
+    let m1 = read_matrix_values(&mut r)?;
+    let m2 = read_matrix_values(&mut r)?;
+    let domains = read_evaluation_domains(&mut r)?;
+    let public_inputs = u64::read(&mut r)? as usize;
+    let max_degree = u64::read(&mut r)? as usize;
+    let urs = URS::<Bn_382>::read(&mut r)?;
     let (endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
-    let index = VerifierIndex {
-        domains: EvaluationDomains::create(variables, constraints, public_inputs, nonzero_entries)
-            .unwrap(),
-        matrix_commitments: [
-            MatrixValues {
-                row: (unsafe { *row_a }).clone(),
-                col: (unsafe { *col_a }).clone(),
-                val: (unsafe { *val_a }).clone(),
-                rc: (unsafe { *rc_a }).clone(),
-            },
-            MatrixValues {
-                row: (unsafe { *row_b }).clone(),
-                col: (unsafe { *col_b }).clone(),
-                val: (unsafe { *val_b }).clone(),
-                rc: (unsafe { *rc_b }).clone(),
-            },
-            MatrixValues {
-                row: (unsafe { *row_c }).clone(),
-                col: (unsafe { *col_c }).clone(),
-                val: (unsafe { *val_c }).clone(),
-                rc: (unsafe { *rc_c }).clone(),
-            },
-        ],
-        fq_sponge_params: oracle::bn_382::fq::params(),
-        fr_sponge_params: oracle::bn_382::fp::params(),
-        max_degree,
+    Ok(VerifierIndex {
+        matrix_commitments: [m0, m1, m2],
+        domains,
         public_inputs,
+        max_degree,
         urs,
         endo_q,
         endo_r,
-    };
-    Box::into_raw(Box::new(index))
-}
-
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_verifier_index_delete(x: *mut VerifierIndex<Bn_382>) {
-    let _box = unsafe { Box::from_raw(x) };
+        fr_sponge_params: oracle::bn_382::fp::params(),
+        fq_sponge_params: oracle::bn_382::fq::params(),
+    })
 }
 
 #[no_mangle]
@@ -1100,180 +2665,228 @@ pub extern "C" fn zexe_bn382_fp_verifier_index_write<'a>(
     index: *const VerifierIndex<Bn_382>,
     path: *const c_char,
 ) {
-    let index = unsafe { &*index };
+    catch_ffi("zexe_bn382_fp_verifier_index_write", (), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = BufWriter::new(File::create(path).unwrap());
 
-    let t: IoResult<()> = (|| {
-        for c in index.matrix_commitments.iter() {
-            write_matrix_values(c, &mut w)?;
-        }
-        write_evaluation_domains(&index.domains, &mut w)?;
-        u64::write(&(index.public_inputs as u64), &mut w)?;
-        u64::write(&(index.max_degree as u64), &mut w)?;
-        index.urs.write(&mut w)?;
-        Ok(())
-    })();
-    t.unwrap()
+        write_verifier_index(index, &mut w).unwrap()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_read<'a>(
     path: *const c_char,
 ) -> *const VerifierIndex<Bn_382> {
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(path).unwrap());
-
-    let t: IoResult<_> = (|| {
-        let m0 = read_matrix_values(&mut r)?;
-        let m1 = read_matrix_values(&mut r)?;
-        let m2 = read_matrix_values(&mut r)?;
-        let domains = read_evaluation_domains(&mut r)?;
-        let public_inputs = u64::read(&mut r)? as usize;
-        let max_degree = u64::read(&mut r)? as usize;
-        let urs = URS::<Bn_382>::read(&mut r)?;
-        let (endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
-        Ok(VerifierIndex {
-            matrix_commitments: [m0, m1, m2],
-            domains,
-            public_inputs,
-            max_degree,
-            urs,
-            endo_q,
-            endo_r,
-            fr_sponge_params: oracle::bn_382::fp::params(),
-            fq_sponge_params: oracle::bn_382::fq::params(),
-        })
-    })();
-    Box::into_raw(Box::new(t.unwrap()))
+    catch_ffi("zexe_bn382_fp_verifier_index_read", std::ptr::null(), || {
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut r = BufReader::new(File::open(path).unwrap());
+
+        into_handle(Box::new(read_verifier_index(&mut r).unwrap()))
+    })
+}
+
+// The encoding is the same as that of zexe_bn382_fp_verifier_index_write,
+// and includes the verifier's part of the URS, so the bytes are enough to
+// verify proofs.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_verifier_index_to_bytes(
+    index: *const VerifierIndex<Bn_382>,
+) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fp_verifier_index_to_bytes", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let mut res = vec![];
+        write_verifier_index(index, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_verifier_index_of_bytes(
+    data: *const u8,
+    len: usize,
+) -> *const VerifierIndex<Bn_382> {
+    catch_ffi("zexe_bn382_fp_verifier_index_of_bytes", std::ptr::null(), || {
+        let mut data = unsafe { std::slice::from_raw_parts(data, len) };
+        match read_verifier_index(&mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_fp_verifier_index_of_bytes: trailing bytes after index".to_string(),
+            ),
+            Ok(index) => into_handle(Box::new(index)),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_verifier_index_of_bytes: {}", e)),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_a_row_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[0].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_a_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[0].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_a_col_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[0].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_a_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[0].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_a_val_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[0].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_a_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[0].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_a_rc_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[0].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_a_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[0].rc }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_b_row_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[1].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_b_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[1].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_b_col_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[1].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_b_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[1].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_b_val_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[1].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_b_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[1].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_b_rc_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[1].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_b_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[1].rc }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_c_row_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[2].row }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_c_row_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[2].row }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_c_col_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[2].col }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_c_col_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[2].col }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_c_val_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[2].val }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_c_val_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[2].val }).clone(),
+        ))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_c_rc_comm(
     index: *const VerifierIndex<Bn_382>,
 ) -> *const G1Affine {
-    Box::into_raw(Box::new(
-        (unsafe { (*index).matrix_commitments[2].rc }).clone(),
-    ))
+    catch_ffi("zexe_bn382_fp_verifier_index_c_rc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(
+            (unsafe { (*index).matrix_commitments[2].rc }).clone(),
+        ))
+    })
 }
 
 // verifier index vector stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_vector_create<'a>(
 ) -> *const Vec<*const VerifierIndex<Bn_382>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_fp_verifier_index_vector_create", std::ptr::null(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_verifier_index_vector_length(
     v: *const Vec<*const VerifierIndex<Bn_382>>,
 ) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_fp_verifier_index_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -1281,8 +2894,11 @@ pub extern "C" fn zexe_bn382_fp_verifier_index_vector_emplace_back<'a>(
     v: *mut Vec<*const VerifierIndex<Bn_382>>,
     x: *const VerifierIndex<Bn_382>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    v_.push(x);
+    catch_ffi("zexe_bn382_fp_verifier_index_vector_emplace_back", (), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        v_.push(x);
+    })
 }
 
 #[no_mangle]
@@ -1290,52 +2906,106 @@ pub extern "C" fn zexe_bn382_fp_verifier_index_vector_get<'a>(
     v: *mut Vec<*const VerifierIndex<Bn_382>>,
     i: u32,
 ) -> *const VerifierIndex<Bn_382> {
-    let v_ = unsafe { &mut (*v) };
-    return v_[i as usize];
+    catch_ffi("zexe_bn382_fp_verifier_index_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return v_[i as usize];
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_verifier_index_vector_delete(
-    v: *mut Vec<*const VerifierIndex<Bn_382>>,
-) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_bn382_fp_verifier_index_vector_delete, Vec<*const VerifierIndex<Bn_382>>);
 
 // Fp URS stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_urs_create(depth: usize) -> *const URS<Bn_382> {
-    Box::into_raw(Box::new(URS::create(
-        depth,
-        (0..depth).collect(),
-        &mut rand_core::OsRng,
-    )))
+    catch_ffi("zexe_bn382_fp_urs_create", std::ptr::null(), || {
+        in_pool(|| {
+            into_handle(Box::new(URS::create(
+                depth,
+                (0..depth).collect(),
+                &mut system_rng(),
+            )))
+        })
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fp_urs_delete(x: *mut URS<Bn_382>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fp_urs_delete, URS<Bn_382>);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_urs_write(urs: *mut URS<Bn_382>, path: *mut c_char) {
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let file = BufWriter::new(File::create(path).unwrap());
-    let urs = unsafe { &*urs };
-    let _ = urs.write(file);
+    catch_ffi("zexe_bn382_fp_urs_write", (), || {
+        let urs = handle(urs);
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = BufWriter::new(File::create(path).unwrap());
+        let urs = unsafe { &*urs };
+        let _ = urs.write(file);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_urs_read(path: *mut c_char) -> *const URS<Bn_382> {
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let file = BufReader::new(File::open(path).unwrap());
-    let res = URS::<Bn_382>::read(file).unwrap();
-    return Box::into_raw(Box::new(res));
+    catch_ffi("zexe_bn382_fp_urs_read", std::ptr::null(), || {
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = BufReader::new(File::open(path).unwrap());
+        let res = URS::<Bn_382>::read(file).unwrap();
+        return into_handle(Box::new(res));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_digest(urs: *const URS<Bn_382>) -> *mut UrsDigest {
+    catch_ffi("zexe_bn382_fp_urs_digest", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let mut w = DigestWriter::new(std::io::sink());
+        urs.write(&mut w).unwrap();
+        into_handle(Box::new(w.finish().unwrap()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_write_with_digest(
+    urs: *const URS<Bn_382>,
+    path: *mut c_char,
+) -> *mut UrsDigest {
+    catch_ffi("zexe_bn382_fp_urs_write_with_digest", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = DigestWriter::new(BufWriter::new(File::create(path).unwrap()));
+        let urs = unsafe { &*urs };
+        urs.write(&mut w).unwrap();
+        into_handle(Box::new(w.finish().unwrap()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_read_with_digest(
+    path: *mut c_char,
+) -> PointerPair<URS<Bn_382>, UrsDigest> {
+    catch_ffi(
+        "zexe_bn382_fp_urs_read_with_digest",
+        PointerPair {
+            a: std::ptr::null(),
+            b: std::ptr::null(),
+        },
+        || {
+            let path = (unsafe { CStr::from_ptr(path) })
+                .to_string_lossy()
+                .into_owned();
+            let mut r = DigestReader::new(BufReader::new(File::open(path).unwrap()));
+            let res = URS::<Bn_382>::read(&mut r).unwrap();
+            PointerPair {
+                a: into_handle(Box::new(res)),
+                b: into_handle(Box::new(r.finish())),
+            }
+        },
+    )
 }
 
 #[no_mangle]
@@ -1344,187 +3014,591 @@ pub extern "C" fn zexe_bn382_fp_urs_lagrange_commitment(
     domain_size: usize,
     i: usize,
 ) -> *const G1Affine {
-    let urs = unsafe { &*urs };
-    let x_domain = EvaluationDomain::<Fp>::new(domain_size).unwrap();
+    catch_ffi("zexe_bn382_fp_urs_lagrange_commitment", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let x_domain = EvaluationDomain::<Fp>::new(domain_size).unwrap();
 
-    let evals = (0..domain_size)
-        .map(|j| if i == j { Fp::one() } else { Fp::zero() })
-        .collect();
-    let p = Evaluations::<Fp>::from_vec_and_domain(evals, x_domain).interpolate();
-    let res = urs.commit(&p).unwrap();
+        let evals = (0..domain_size)
+            .map(|j| if i == j { Fp::one() } else { Fp::zero() })
+            .collect();
+        let p = Evaluations::<Fp>::from_vec_and_domain(evals, x_domain).interpolate();
+        let res = urs.commit(&p).unwrap();
 
-    Box::into_raw(Box::new(res))
+        into_handle(Box::new(res))
+    })
+}
+
+// The commitments to all the Lagrange basis polynomials of the domain, in
+// order, as zexe_bn382_fp_urs_lagrange_commitment would give them one by one.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_lagrange_commitments(
+    urs: *const URS<Bn_382>,
+    domain_size: usize,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_fp_urs_lagrange_commitments", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let x_domain = Domain::<Fp>::new(domain_size).unwrap();
+        into_handle(Box::new(lagrange_commitments(&urs.gp, x_domain)))
+    })
 }
 
+// The fixed commitments of the URS, for the domains H and K of the given
+// sizes, rounded up to powers of two. Fails if the URS is too short for Z_K.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_fixed_commitments_create(
+    urs: *const URS<Bn_382>,
+    h_size: usize,
+    k_size: usize,
+) -> *const FixedCommitments<G1Affine> {
+    const NAME: &str = "zexe_bn382_fp_urs_fixed_commitments_create";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let (h, k) = match (Domain::<Fp>::new(h_size), Domain::<Fp>::new(k_size)) {
+            (Some(h), Some(k)) => (h, k),
+            _ => return null_with_error(format!("{}: Invalid domain size", NAME)),
+        };
+        let res = in_pool(|| {
+            FixedCommitments::create(h, k, |p| urs.commit(p).map_err(|e| format!("{:?}", e)))
+        });
+        match res {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_fixed_commitments_z_h(
+    c: *const FixedCommitments<G1Affine>,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_urs_fixed_commitments_z_h", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        into_handle(Box::new(c.z_h))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_fixed_commitments_z_k(
+    c: *const FixedCommitments<G1Affine>,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_urs_fixed_commitments_z_k", std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        into_handle(Box::new(c.z_k))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_fixed_commitments_monomial(
+    c: *const FixedCommitments<G1Affine>,
+    i: usize,
+) -> *const G1Affine {
+    const NAME: &str = "zexe_bn382_fp_urs_fixed_commitments_monomial";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let c = handle(c);
+        let c = unsafe { &*c };
+        match c.monomial(i) {
+            Ok(m) => into_handle(Box::new(*m)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_urs_fixed_commitments_delete, FixedCommitments<G1Affine>);
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_urs_commit_evaluations(
     urs: *const URS<Bn_382>,
     domain_size: usize,
     evals: *const Vec<Fp>,
 ) -> *const G1Affine {
-    let urs = unsafe { &*urs };
-    let x_domain = EvaluationDomain::<Fp>::new(domain_size).unwrap();
+    catch_ffi("zexe_bn382_fp_urs_commit_evaluations", std::ptr::null(), || {
+        let urs = handle(urs);
+        let evals = handle(evals);
+        let urs = unsafe { &*urs };
+        let x_domain = EvaluationDomain::<Fp>::new(domain_size).unwrap();
 
-    let evals = unsafe { &*evals };
-    let p = Evaluations::<Fp>::from_vec_and_domain(evals.clone(), x_domain).interpolate();
-    let res = urs.commit(&p).unwrap();
+        let evals = unsafe { &*evals };
+        let p = Evaluations::<Fp>::from_vec_and_domain(evals.clone(), x_domain).interpolate();
+        let res = urs.commit(&p).unwrap();
 
-    Box::into_raw(Box::new(res))
+        into_handle(Box::new(res))
+    })
 }
 
+// As zexe_bn382_fp_urs_commit_evaluations, for each evaluation vector. The
+// Lagrange commitments of the domain are computed once, so that each
+// commitment is a single multi-scalar multiplication.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_urs_dummy_degree_bound_checks(
+pub extern "C" fn zexe_bn382_fp_urs_commit_evaluations_batch(
     urs: *const URS<Bn_382>,
-    bounds: *const Vec<usize>,
+    domain_size: usize,
+    evals: *const Vec<*const Vec<Fp>>,
 ) -> *const Vec<G1Affine> {
-    let urs = unsafe { &*urs };
-    let bounds = unsafe { &*bounds };
-    let comms: Vec<_> = bounds
-        .iter()
-        .map(|b| {
-            let p = DensePolynomial::<Fp>::from_coefficients_vec(
-                (0..*b)
-                    .map(|i| if i == 0 { Fp::one() } else { Fp::zero() })
-                    .collect(),
-            );
-            urs.commit_with_degree_bound(&p, *b).unwrap()
-        })
-        .collect();
+    const NAME: &str = "zexe_bn382_fp_urs_commit_evaluations_batch";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let urs = handle(urs);
+        let evals = handle(evals);
+        let urs = unsafe { &*urs };
+        let evals: Vec<&Vec<Fp>> = (unsafe { &*evals }).iter().map(|&e| unsafe { &*handle(e) }).collect();
+        let x_domain = Domain::<Fp>::new(domain_size).unwrap();
+        let res = in_pool(|| {
+            let lagrange = lagrange_commitments(&urs.gp, x_domain);
+            commit_evaluations_batch(&lagrange, &evals)
+        });
+        match res {
+            Ok(res) => into_handle(Box::new(res)),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
+}
 
-    let cs = comms.iter().map(|(_, c)| *c);
-    let ss = comms.iter().map(|(s, _)| *s);
+// A commitment to a polynomial together with its shifted counterpart, which
+// attests that the polynomial has degree less than some bound.
+#[derive(Clone, Copy)]
+pub struct DegreeBoundedCommitment {
+    pub unshifted: G1Affine,
+    pub shifted: G1Affine,
+}
 
-    let rs: Vec<Fp> = bounds
-        .iter()
-        .enumerate()
-        .map(|(_, i)| ((i + 2) as u64).into())
-        .collect();
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_commit_with_degree_bound(
+    urs: *const URS<Bn_382>,
+    coefficients: *const Vec<Fp>,
+    bound: usize,
+) -> *const DegreeBoundedCommitment {
+    catch_ffi("zexe_bn382_fp_urs_commit_with_degree_bound", std::ptr::null(), || {
+        let urs = handle(urs);
+        let coefficients = handle(coefficients);
+        let urs = unsafe { &*urs };
+        let coefficients = unsafe { &*coefficients };
+        if coefficients.len() > bound || bound > urs.depth {
+            return null_with_error(format!(
+                "zexe_bn382_fp_urs_commit_with_degree_bound: Cannot commit to a polynomial with {} coefficients under degree bound {}.",
+                coefficients.len(),
+                bound
+            ));
+        }
+        let p = DensePolynomial::<Fp>::from_coefficients_vec(coefficients.clone());
+        // The first component is the shifted commitment.
+        let (shifted, unshifted) = urs.commit_with_degree_bound(&p, bound).unwrap();
+        into_handle(Box::new(DegreeBoundedCommitment { unshifted, shifted }))
+    })
+}
 
-    let shifted: G1Affine = ss
-        .zip(rs.iter())
-        .map(|(s, r)| s.mul(*r))
-        .fold(G1Projective::zero(), |acc, x| acc + &x)
-        .into_affine();
+// Checks e(shifted, H) = e(unshifted, beta^{depth - bound} H), i.e. that the
+// committed polynomial has degree less than bound.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_check_degree_bound(
+    urs: *const URS<Bn_382>,
+    c: *const DegreeBoundedCommitment,
+    bound: usize,
+) -> bool {
+    catch_ffi("zexe_bn382_fp_urs_check_degree_bound", false, || {
+        let urs = handle(urs);
+        let c = handle(c);
+        degree_bound_holds(unsafe { &*urs }, unsafe { &*c }, bound)
+    })
+}
+
+pub fn degree_bound_holds(urs: &URS<Bn_382>, c: &DegreeBoundedCommitment, bound: usize) -> bool {
+    let prepared = fp_prepared_verifier_index(urs);
+    match prepared.hn(urs, bound) {
+        None => false,
+        Some(h) => prepared_pairings_vanish(&[(c.shifted, prepared.h()), (-c.unshifted, &*h)]),
+    }
+}
 
-    let mut res = vec![shifted];
-    res.extend(cs.zip(rs.iter()).map(|(c, r)| c.mul(*r).into_affine()));
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_degree_bounded_commitment_make(
+    unshifted: *const G1Affine,
+    shifted: *const G1Affine,
+) -> *const DegreeBoundedCommitment {
+    catch_ffi("zexe_bn382_fp_degree_bounded_commitment_make", std::ptr::null(), || {
+        let unshifted = handle(unshifted);
+        let shifted = handle(shifted);
+        into_handle(Box::new(DegreeBoundedCommitment {
+            unshifted: unsafe { *unshifted },
+            shifted: unsafe { *shifted },
+        }))
+    })
+}
 
-    Box::into_raw(Box::new(res))
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_degree_bounded_commitment_unshifted(
+    c: *const DegreeBoundedCommitment,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_degree_bounded_commitment_unshifted", std::ptr::null(), || {
+        let c = handle(c);
+        into_handle(Box::new(unsafe { (*c).unshifted }))
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_urs_dummy_opening_check(
+pub extern "C" fn zexe_bn382_fp_degree_bounded_commitment_shifted(
+    c: *const DegreeBoundedCommitment,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_fp_degree_bounded_commitment_shifted", std::ptr::null(), || {
+        let c = handle(c);
+        into_handle(Box::new(unsafe { (*c).shifted }))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_degree_bounded_commitment_delete, DegreeBoundedCommitment);
+
+// The degree bound accumulator of commitments to the constant polynomial 1,
+// one under each of the bounds, with bound + 2 as the randomizer of each. It
+// satisfies zexe_bn382_fp_urs_degree_bound_accumulator_check, and stands in
+// for the accumulator of a previous proof where there is none.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_dummy_degree_bound_checks(
     urs: *const URS<Bn_382>,
-) -> *const (G1Affine, G1Affine) {
-    // (f - [v] + z pi, pi)
-    //
-    // for the accumulator for the check
-    //
-    // e(f - [v] + z pi, H) = e(pi, beta*H)
-    let urs = unsafe { &*urs };
+    bounds: *const Vec<usize>,
+) -> *const Vec<G1Affine> {
+    catch_ffi("zexe_bn382_fp_urs_dummy_degree_bound_checks", std::ptr::null(), || {
+        let urs = handle(urs);
+        let bounds = handle(bounds);
+        let urs = unsafe { &*urs };
+        let bounds = unsafe { &*bounds };
+        if bounds.iter().any(|&b| b == 0 || b > urs.depth) {
+            return null_with_error(
+                "zexe_bn382_fp_urs_dummy_degree_bound_checks: The bounds must be positive and within the URS".to_string(),
+            );
+        }
+        let comms: Vec<_> = bounds
+            .iter()
+            .map(|&b| {
+                let p = DensePolynomial::<Fp>::from_coefficients_vec(vec![Fp::one()]);
+                let (shifted, unshifted) = urs.commit_with_degree_bound(&p, b).unwrap();
+                DegreeBoundedCommitment { unshifted, shifted }
+            })
+            .collect();
+        let randomizers: Vec<Fp> = bounds.iter().map(|&b| ((b + 2) as u64).into()).collect();
+        match degree_bound_accumulator_randomized(urs, bounds, &comms, &randomizers) {
+            Some(acc) => into_handle(Box::new(acc)),
+            None => null_with_error(
+                "zexe_bn382_fp_urs_dummy_degree_bound_checks: The bounds do not match the URS".to_string(),
+            ),
+        }
+    })
+}
 
-    let z = Fp::one();
-    let p = DensePolynomial::<Fp>::from_coefficients_vec(vec![Fp::one(), Fp::one()]);
-    let f = urs.commit(&p).unwrap();
-    let v = p.evaluate(z);
-    let pi = urs.open(vec![&p], Fp::one(), z).unwrap();
+// The opening accumulator of the opening of 1 + X at 1, which satisfies
+// zexe_bn382_fp_urs_opening_accumulator_check, and stands in for the
+// accumulator of a previous proof where there is none.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_dummy_opening_check(
+    urs: *const URS<Bn_382>,
+) -> *const AffinePair<G1Affine> {
+    catch_ffi("zexe_bn382_fp_urs_dummy_opening_check", std::ptr::null(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
 
-    let res = (
-        (f.into_projective() - &(G1Affine::prime_subgroup_generator().mul(v)) + &pi.mul(z))
-            .into_affine(),
-        pi,
-    );
+        let z = Fp::one();
+        let p = DensePolynomial::<Fp>::from_coefficients_vec(vec![Fp::one(), Fp::one()]);
+        let f = urs.commit(&p).unwrap();
+        let v = p.evaluate(z);
+        let pi = urs.open(vec![&p], Fp::one(), z).unwrap();
 
-    Box::into_raw(Box::new(res))
+        let acc = opening_accumulator(urs, &[z], &[f], &[v], &[pi], Fp::one(), Fp::one());
+        into_handle(Box::new(acc.unwrap()))
+    })
 }
 
 // Fp index stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_domain_h_size<'a>(i: *const Index<'a, Bn_382>) -> usize {
-    (unsafe { &*i }).domains.h.size()
+    catch_ffi("zexe_bn382_fp_index_domain_h_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).domains.h.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_domain_k_size<'a>(i: *const Index<'a, Bn_382>) -> usize {
-    (unsafe { &*i }).domains.k.size()
+    catch_ffi("zexe_bn382_fp_index_domain_k_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).domains.k.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_create<'a>(
-    a: *mut Vec<(Vec<usize>, Vec<Fp>)>,
-    b: *mut Vec<(Vec<usize>, Vec<Fp>)>,
-    c: *mut Vec<(Vec<usize>, Vec<Fp>)>,
+    a: *mut Vec<Row<Fp>>,
+    b: *mut Vec<Row<Fp>>,
+    c: *mut Vec<Row<Fp>>,
     vars: usize,
     public_inputs: usize,
     urs: *mut URS<Bn_382>,
 ) -> *mut Index<'a, Bn_382> {
-    assert!(public_inputs > 0);
-
-    let urs = unsafe { &*urs };
-    let a = unsafe { &*a };
-    let b = unsafe { &*b };
-    let c = unsafe { &*c };
-
-    let num_constraints = a.len();
-
-    let m = if num_constraints > vars {
-        num_constraints
-    } else {
-        vars
-    };
-
-    let h_group_size = Domain::<Fp>::compute_size_of_domain(m).unwrap();
-    let h_to_x_ratio = {
-        let x_group_size = Domain::<Fp>::compute_size_of_domain(public_inputs).unwrap();
-        h_group_size / x_group_size
-    };
-
-    return Box::into_raw(Box::new(
-        Index::<Bn_382>::create(
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, a),
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, b),
-            rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, c),
-            public_inputs,
-            oracle::bn_382::fp::params(),
-            oracle::bn_382::fq::params(),
-            URSSpec::Use(urs),
-        )
-        .unwrap(),
-    ));
+    catch_ffi("zexe_bn382_fp_index_create", std::ptr::null_mut(), || in_pool(|| {
+        let a = handle(a);
+        let b = handle(b);
+        let c = handle(c);
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+        let a = unsafe { &*a };
+        let b = unsafe { &*b };
+        let c = unsafe { &*c };
+
+        let (h_group_size, h_to_x_ratio) = match h_domain_sizes(a.len(), vars, public_inputs) {
+            Ok(sizes) => sizes,
+            Err(e) => return null_with_error(format!("zexe_bn382_fp_index_create: {}", e)),
+        };
+
+        return fp_index_into_handle(
+            Index::<Bn_382>::create(
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, a),
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, b),
+                rows_to_csmat(public_inputs, h_group_size, h_to_x_ratio, c),
+                public_inputs,
+                oracle::bn_382::fp::params(),
+                oracle::bn_382::fq::params(),
+                URSSpec::Use(urs),
+            )
+            .unwrap(),
+        );
+    }))
 }
 
+// As zexe_bn382_fp_index_create, but pulls the rows of the constraints from the
+// callback, as described at RowSource, so that the matrices are never held as
+// vectors of rows.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fp_index_delete(x: *mut Index<Bn_382>) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_fp_index_create_streaming<'a>(
+    rows: usize,
+    vars: usize,
+    public_inputs: usize,
+    urs: *mut URS<Bn_382>,
+    source: RowSource,
+    ctx: *mut c_void,
+) -> *mut Index<'a, Bn_382> {
+    catch_ffi("zexe_bn382_fp_index_create_streaming", std::ptr::null_mut(), || {
+        let urs = handle(urs);
+        let urs = unsafe { &*urs };
+
+        let (h_group_size, h_to_x_ratio) = match h_domain_sizes(rows, vars, public_inputs) {
+            Ok(sizes) => sizes,
+            Err(e) => {
+                return null_with_error(format!("zexe_bn382_fp_index_create_streaming: {}", e))
+            }
+        };
+
+        let mut matrices = vec![];
+        for matrix in 0..3 {
+            match csmat_of_row_source::<Fp>(
+                source,
+                ctx,
+                matrix,
+                rows,
+                public_inputs,
+                h_group_size,
+                h_to_x_ratio,
+            ) {
+                Ok(m) => matrices.push(m),
+                Err(e) => {
+                    return null_with_error(format!("zexe_bn382_fp_index_create_streaming: {}", e))
+                }
+            }
+        }
+        let c = matrices.pop().unwrap();
+        let b = matrices.pop().unwrap();
+        let a = matrices.pop().unwrap();
+
+        in_pool(|| {
+            fp_index_into_handle(
+                Index::<Bn_382>::create(
+                    a,
+                    b,
+                    c,
+                    public_inputs,
+                    oracle::bn_382::fp::params(),
+                    oracle::bn_382::fq::params(),
+                    URSSpec::Use(urs),
+                )
+                .unwrap(),
+            )
+        })
+    })
+}
+
+// Whether any constraint of the index refers to an auxiliary variable. Only
+// computed when the index is created or read, and recorded with it.
+fn fp_index_scans_auxiliary(index: &Index<Bn_382>) -> bool {
+    let c = &index.compiled;
+    constraints_use_auxiliary(
+        &[&c[0].constraints, &c[1].constraints, &c[2].constraints],
+        index.domains.h.size() / index.domains.x.size(),
+    )
+}
+
+fn fp_index_into_handle<'a>(index: Index<'a, Bn_382>) -> *mut Index<'a, Bn_382> {
+    let uses_auxiliary = fp_index_scans_auxiliary(&index);
+    let index = into_handle(Box::new(index));
+    record_auxiliary_use(index, uses_auxiliary);
+    index
 }
 
+fn forget_fp_index(index: *const Index<Bn_382>) {
+    forget_labelled_system(index);
+    forget_auxiliary_use(index);
+}
+
+crate::delete_stub!(
+    zexe_bn382_fp_index_delete,
+    Index<'static, Bn_382>,
+    forget_fp_index
+);
+
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_nonzero_entries(index: *const Index<Bn_382>) -> usize {
-    let index = unsafe { &*index };
-    index
-        .compiled
-        .iter()
-        .map(|x| x.constraints.nnz())
-        .max()
-        .unwrap()
+    catch_ffi("zexe_bn382_fp_index_nonzero_entries", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index
+            .compiled
+            .iter()
+            .map(|x| x.constraints.nnz())
+            .max()
+            .unwrap()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_index_nonzero_entries_a(index: *const Index<Bn_382>) -> usize {
+    catch_ffi("zexe_bn382_fp_index_nonzero_entries_a", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[0].constraints.nnz()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_index_nonzero_entries_b(index: *const Index<Bn_382>) -> usize {
+    catch_ffi("zexe_bn382_fp_index_nonzero_entries_b", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[1].constraints.nnz()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_index_nonzero_entries_c(index: *const Index<Bn_382>) -> usize {
+    catch_ffi("zexe_bn382_fp_index_nonzero_entries_c", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[2].constraints.nnz()
+    })
+}
+
+// Exports compiled matrix A (0), B (1) or C (2) of the index, as used by the
+// prover and verifier.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_index_csr(index: *const Index<Bn_382>, matrix: usize) -> *mut Csr<Fp> {
+    catch_ffi("zexe_bn382_fp_index_csr", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        if matrix > 2 {
+            return null_with_error(format!("zexe_bn382_fp_index_csr: No matrix {}", matrix));
+        }
+        into_handle(Box::new(csr_of_csmat(&index.compiled[matrix].constraints)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_csr_rows(m: *const Csr<Fp>) -> usize {
+    catch_ffi("zexe_bn382_fp_csr_rows", 0, || {
+        let m = handle(m);
+        (unsafe { &*m }).rows
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_csr_cols(m: *const Csr<Fp>) -> usize {
+    catch_ffi("zexe_bn382_fp_csr_cols", 0, || {
+        let m = handle(m);
+        (unsafe { &*m }).cols
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_csr_indptr(m: *const Csr<Fp>) -> *mut Vec<usize> {
+    catch_ffi("zexe_bn382_fp_csr_indptr", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).indptr.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_csr_indices(m: *const Csr<Fp>) -> *mut Vec<usize> {
+    catch_ffi("zexe_bn382_fp_csr_indices", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).indices.clone()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_csr_values(m: *const Csr<Fp>) -> *mut Vec<Fp> {
+    catch_ffi("zexe_bn382_fp_csr_values", std::ptr::null_mut(), || {
+        let m = handle(m);
+        into_handle(Box::new((unsafe { &*m }).values.clone()))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fp_csr_delete, Csr<Fp>);
+
+// The size of the k domain needed by the matrices, which is the maximum of
+// their numbers of nonzero entries rounded up to a power of two.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_index_domain_k_padded_size(index: *const Index<Bn_382>) -> usize {
+    catch_ffi("zexe_bn382_fp_index_domain_k_padded_size", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let nnz = index
+            .compiled
+            .iter()
+            .map(|x| x.constraints.nnz())
+            .max()
+            .unwrap();
+        match domain_size("non-zero entries", nnz) {
+            Ok(size) => size,
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fp_index_domain_k_padded_size: {}", e));
+                0
+            }
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_max_degree(index: *const Index<Bn_382>) -> usize {
-    let index = unsafe { &*index };
-    index.urs.get_ref().max_degree()
+    catch_ffi("zexe_bn382_fp_index_max_degree", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.urs.get_ref().max_degree()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_num_variables(index: *const Index<Bn_382>) -> usize {
-    let index = unsafe { &*index };
-    index.compiled[0].constraints.shape().0
+    catch_ffi("zexe_bn382_fp_index_num_variables", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.compiled[0].constraints.shape().0
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_public_inputs(index: *const Index<Bn_382>) -> usize {
-    let index = unsafe { &*index };
-    index.public_inputs
+    catch_ffi("zexe_bn382_fp_index_public_inputs", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.public_inputs
+    })
 }
 
 #[no_mangle]
@@ -1532,152 +3606,167 @@ pub extern "C" fn zexe_bn382_fp_index_write<'a>(
     index: *const Index<'a, Bn_382>,
     path: *const c_char,
 ) {
-    fn write_compiled<W: Write>(
-        c: &marlin_protocol_pairing::compiled::Compiled<Bn_382>,
-        mut w: W,
-    ) -> IoResult<()> {
-        c.col_comm.write(&mut w)?;
-        c.row_comm.write(&mut w)?;
-        c.val_comm.write(&mut w)?;
-        c.rc_comm.write(&mut w)?;
-        write_dense_polynomial(&c.rc, &mut w)?;
-        write_dense_polynomial(&c.row, &mut w)?;
-        write_dense_polynomial(&c.col, &mut w)?;
-        write_dense_polynomial(&c.val, &mut w)?;
-        write_evaluations(&c.row_eval_k, &mut w)?;
-        write_evaluations(&c.col_eval_k, &mut w)?;
-        write_evaluations(&c.val_eval_k, &mut w)?;
-        write_evaluations(&c.row_eval_b, &mut w)?;
-        write_evaluations(&c.col_eval_b, &mut w)?;
-        write_evaluations(&c.val_eval_b, &mut w)?;
-        write_evaluations(&c.rc_eval_b, &mut w)?;
-        Ok(())
-    }
+    catch_ffi("zexe_bn382_fp_index_write", (), || {
+        let index = handle(index);
+        fn write_compiled<W: Write>(
+            c: &marlin_protocol_pairing::compiled::Compiled<Bn_382>,
+            mut w: W,
+        ) -> IoResult<()> {
+            write_cs_mat(&c.constraints, &mut w)?;
+            c.col_comm.write(&mut w)?;
+            c.row_comm.write(&mut w)?;
+            c.val_comm.write(&mut w)?;
+            c.rc_comm.write(&mut w)?;
+            write_dense_polynomial(&c.rc, &mut w)?;
+            write_dense_polynomial(&c.row, &mut w)?;
+            write_dense_polynomial(&c.col, &mut w)?;
+            write_dense_polynomial(&c.val, &mut w)?;
+            write_evaluations(&c.row_eval_k, &mut w)?;
+            write_evaluations(&c.col_eval_k, &mut w)?;
+            write_evaluations(&c.val_eval_k, &mut w)?;
+            write_evaluations(&c.row_eval_b, &mut w)?;
+            write_evaluations(&c.col_eval_b, &mut w)?;
+            write_evaluations(&c.val_eval_b, &mut w)?;
+            write_evaluations(&c.rc_eval_b, &mut w)?;
+            Ok(())
+        }
 
-    let index = unsafe { &*index };
+        let index = unsafe { &*index };
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = match File::create(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                set_last_error(format!("zexe_bn382_fp_index_write: {}: {}", path, e));
+                return;
+            }
+        };
+        let mut w = BufWriter::new(file);
 
-    let t: IoResult<()> = (|| {
-        write_evaluation_domains(&index.domains, &mut w)?;
+        let t: IoResult<()> = (|| {
+            write_index_file_version(&mut w)?;
+            write_evaluation_domains(&index.domains, &mut w)?;
 
-        for c in index.compiled.iter() {
-            write_compiled(c, &mut w)?;
-        }
+            for c in index.compiled.iter() {
+                write_compiled(c, &mut w)?;
+            }
 
-        u64::write(&(index.public_inputs as u64), &mut w)?;
-        Ok(())
-    })();
-    t.unwrap()
+            u64::write(&(index.public_inputs as u64), &mut w)?;
+            w.flush()
+        })();
+        if let Err(e) = t {
+            set_last_error(format!("zexe_bn382_fp_index_write: {}", e));
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fp_index_read<'a>(
     srs: *const URS<Bn_382>,
-    a: *const Vec<(Vec<usize>, Vec<Fp>)>,
-    b: *const Vec<(Vec<usize>, Vec<Fp>)>,
-    c: *const Vec<(Vec<usize>, Vec<Fp>)>,
-    public_inputs: usize,
     path: *const c_char,
 ) -> *const Index<'a, Bn_382> {
-    fn read_compiled<R: Read>(
-        public_inputs: usize,
-        ds: EvaluationDomains<Fp>,
-        m: *const Vec<(Vec<usize>, Vec<Fp>)>,
-        mut r: R,
-    ) -> IoResult<marlin_protocol_pairing::compiled::Compiled<Bn_382>> {
-        let constraints = rows_to_csmat(
-            public_inputs,
-            ds.h.size(),
-            ds.h.size() / ds.x.size(),
-            unsafe { &*m },
-        );
-
-        let col_comm = G1Affine::read(&mut r)?;
-        let row_comm = G1Affine::read(&mut r)?;
-        let val_comm = G1Affine::read(&mut r)?;
-        let rc_comm = G1Affine::read(&mut r)?;
-        let rc = read_dense_polynomial(&mut r)?;
-        let row = read_dense_polynomial(&mut r)?;
-        let col = read_dense_polynomial(&mut r)?;
-        let val = read_dense_polynomial(&mut r)?;
-        let row_eval_k = read_evaluations(&mut r)?;
-        let col_eval_k = read_evaluations(&mut r)?;
-        let val_eval_k = read_evaluations(&mut r)?;
-        let row_eval_b = read_evaluations(&mut r)?;
-        let col_eval_b = read_evaluations(&mut r)?;
-        let val_eval_b = read_evaluations(&mut r)?;
-        let rc_eval_b = read_evaluations(&mut r)?;
-
-        Ok(marlin_protocol_pairing::compiled::Compiled {
-            constraints,
-            col_comm,
-            row_comm,
-            val_comm,
-            rc_comm,
-            rc,
-            row,
-            col,
-            val,
-            row_eval_k,
-            col_eval_k,
-            val_eval_k,
-            row_eval_b,
-            col_eval_b,
-            val_eval_b,
-            rc_eval_b,
-        })
-    }
-
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(path).unwrap());
-
-    let srs = unsafe { &*srs };
-
-    let t: IoResult<_> = (|| {
-        let domains = read_evaluation_domains(&mut r)?;
-
-        let c0 = read_compiled(public_inputs, domains, a, &mut r)?;
-        let c1 = read_compiled(public_inputs, domains, b, &mut r)?;
-        let c2 = read_compiled(public_inputs, domains, c, &mut r)?;
+    catch_ffi("zexe_bn382_fp_index_read", std::ptr::null(), || {
+        let srs = handle(srs);
+        fn read_compiled<R: Read>(
+            mut r: R,
+        ) -> IoResult<marlin_protocol_pairing::compiled::Compiled<Bn_382>> {
+            let constraints = read_cs_mat(&mut r)?;
+            let col_comm = G1Affine::read(&mut r)?;
+            let row_comm = G1Affine::read(&mut r)?;
+            let val_comm = G1Affine::read(&mut r)?;
+            let rc_comm = G1Affine::read(&mut r)?;
+            let rc = read_dense_polynomial(&mut r)?;
+            let row = read_dense_polynomial(&mut r)?;
+            let col = read_dense_polynomial(&mut r)?;
+            let val = read_dense_polynomial(&mut r)?;
+            let row_eval_k = read_evaluations(&mut r)?;
+            let col_eval_k = read_evaluations(&mut r)?;
+            let val_eval_k = read_evaluations(&mut r)?;
+            let row_eval_b = read_evaluations(&mut r)?;
+            let col_eval_b = read_evaluations(&mut r)?;
+            let val_eval_b = read_evaluations(&mut r)?;
+            let rc_eval_b = read_evaluations(&mut r)?;
+
+            Ok(marlin_protocol_pairing::compiled::Compiled {
+                constraints,
+                col_comm,
+                row_comm,
+                val_comm,
+                rc_comm,
+                rc,
+                row,
+                col,
+                val,
+                row_eval_k,
+                col_eval_k,
+                val_eval_k,
+                row_eval_b,
+                col_eval_b,
+                val_eval_b,
+                rc_eval_b,
+            })
+        }
 
-        let public_inputs = u64::read(&mut r)? as usize;
-        let (endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
-        Ok(Index::<Bn_382> {
-            compiled: [c0, c1, c2],
-            domains,
-            public_inputs,
-            urs: marlin_protocol_pairing::index::URSValue::Ref(srs),
-            fr_sponge_params: oracle::bn_382::fp::params(),
-            fq_sponge_params: oracle::bn_382::fq::params(),
-            endo_q,
-            endo_r,
-        })
-    })();
-    Box::into_raw(Box::new(t.unwrap()))
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                return null_with_error(format!("zexe_bn382_fp_index_read: {}: {}", path, e))
+            }
+        };
+        let mut r = BufReader::new(file);
+
+        let srs = unsafe { &*srs };
+
+        let t: IoResult<_> = (|| {
+            read_index_file_version(&mut r)?;
+            let domains = read_evaluation_domains(&mut r)?;
+
+            let c0 = read_compiled(&mut r)?;
+            let c1 = read_compiled(&mut r)?;
+            let c2 = read_compiled(&mut r)?;
+
+            let public_inputs = u64::read(&mut r)? as usize;
+            let (endo_q, endo_r) = marlin_protocol_pairing::index::endos::<Bn_382>();
+            Ok(Index::<Bn_382> {
+                compiled: [c0, c1, c2],
+                domains,
+                public_inputs,
+                urs: marlin_protocol_pairing::index::URSValue::Ref(srs),
+                fr_sponge_params: oracle::bn_382::fp::params(),
+                fq_sponge_params: oracle::bn_382::fq::params(),
+                endo_q,
+                endo_r,
+            })
+        })();
+        match t {
+            Ok(index) => fp_index_into_handle(index),
+            Err(e) => null_with_error(format!("zexe_bn382_fp_index_read: {}", e)),
+        }
+    })
 }
 
 // G1 / Fq stubs
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_random() -> *const G1Projective {
-    let rng = &mut rand_core::OsRng;
-    Box::into_raw(Box::new(G1Projective::rand(rng)))
+    catch_ffi("zexe_bn382_g1_random", std::ptr::null(), || {
+        let rng = &mut system_rng();
+        into_handle(Box::new(G1Projective::rand(rng)))
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_delete(x: *mut G1Projective) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_g1_delete, G1Projective);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_one() -> *const G1Projective {
-    let ret = G1Projective::prime_subgroup_generator();
-    Box::into_raw(Box::new(ret))
+    catch_ffi("zexe_bn382_g1_one", std::ptr::null(), || {
+        let ret = G1Projective::prime_subgroup_generator();
+        into_handle(Box::new(ret))
+    })
 }
 
 #[no_mangle]
@@ -1685,25 +3774,51 @@ pub extern "C" fn zexe_bn382_g1_add(
     x: *const G1Projective,
     y: *const G1Projective,
 ) -> *const G1Projective {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ + y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g1_add", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_double(x: *const G1Projective) -> *const G1Projective {
-    let x_ = unsafe { &(*x) };
-    let ret = x_.double();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g1_double", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.double();
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_scale(x: *const G1Projective, s: *const Fp) -> *const G1Projective {
-    let x_ = unsafe { &(*x) };
-    let s_ = unsafe { &(*s) };
-    let ret = (*x_).mul(*s_);
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g1_scale", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = (*x_).mul(*s_);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_scale_many(
+    x: *const G1Projective,
+    s: *const Vec<Fp>,
+) -> *const Vec<G1Projective> {
+    catch_ffi("zexe_bn382_g1_scale_many", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = scale_many(*x_, s_);
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
@@ -1711,31 +3826,44 @@ pub extern "C" fn zexe_bn382_g1_sub(
     x: *const G1Projective,
     y: *const G1Projective,
 ) -> *const G1Projective {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let ret = *x_ - y_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g1_sub", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_negate(x: *const G1Projective) -> *const G1Projective {
-    let x_ = unsafe { &(*x) };
-    let ret = -*x_;
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_g1_negate", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_to_affine(p: *const G1Projective) -> *const G1Affine {
-    let p = unsafe { *p };
-    let q = p.clone().into_affine();
-    return Box::into_raw(Box::new(q));
+    catch_ffi("zexe_bn382_g1_to_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_affine();
+        return into_handle(Box::new(q));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_of_affine(p: *const G1Affine) -> *const G1Projective {
-    let p = unsafe { *p };
-    let q = p.clone().into_projective();
-    return Box::into_raw(Box::new(q));
+    catch_ffi("zexe_bn382_g1_of_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_projective();
+        return into_handle(Box::new(q));
+    })
 }
 
 #[no_mangle]
@@ -1743,52 +3871,177 @@ pub extern "C" fn zexe_bn382_g1_of_affine_coordinates(
     x: *const Fq,
     y: *const Fq,
 ) -> *const G1Projective {
-    let x = (unsafe { *x }).clone();
-    let y = (unsafe { *y }).clone();
-    return Box::into_raw(Box::new(G1Projective::new(x, y, Fq::one())));
+    catch_ffi("zexe_bn382_g1_of_affine_coordinates", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        return into_handle(Box::new(G1Projective::new(x, y, Fq::one())));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_create(x: *const Fq, y: *const Fq) -> *const G1Affine {
-    let x = (unsafe { *x }).clone();
-    let y = (unsafe { *y }).clone();
-    Box::into_raw(Box::new(G1Affine::new(x, y, false)))
+    catch_ffi("zexe_bn382_g1_affine_create", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        into_handle(Box::new(G1Affine::new(x, y, false)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_create_infinity() -> *const G1Affine {
+    catch_ffi("zexe_bn382_g1_affine_create_infinity", std::ptr::null(), || {
+        into_handle(Box::new(G1Affine::zero()))
+    })
+}
+
+// As zexe_bn382_g1_affine_create, but returns null, with the last error set,
+// unless the point is on the curve and in the prime order subgroup.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_create_checked(
+    x: *const Fq,
+    y: *const Fq,
+) -> *const G1Affine {
+    catch_ffi("zexe_bn382_g1_affine_create_checked", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let p = G1Affine::new(unsafe { *x }, unsafe { *y }, false);
+        if !p.is_on_curve() {
+            return null_with_error(
+                "zexe_bn382_g1_affine_create_checked: The point is not on the curve".to_string(),
+            );
+        }
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return null_with_error(
+                "zexe_bn382_g1_affine_create_checked: The point is not in the subgroup".to_string(),
+            );
+        }
+        into_handle(Box::new(p))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_is_on_curve(p: *const G1Affine) -> bool {
+    catch_ffi("zexe_bn382_g1_affine_is_on_curve", false, || {
+        let p = handle(p);
+        (unsafe { &*p }).is_on_curve()
+    })
+}
+
+// Whether the point is on the curve and in the prime order subgroup.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_is_in_correct_subgroup(p: *const G1Affine) -> bool {
+    catch_ffi("zexe_bn382_g1_affine_is_in_correct_subgroup", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_is_infinity(p: *const G1Affine) -> bool {
+    catch_ffi("zexe_bn382_g1_affine_is_infinity", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.infinity;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_x(p: *const G1Affine) -> *const Fq {
-    let p = unsafe { *p };
-    return Box::into_raw(Box::new(p.x.clone()));
+    catch_ffi("zexe_bn382_g1_affine_x", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        // The point at infinity has no coordinates.
+        if p.infinity {
+            return std::ptr::null();
+        }
+        return into_handle(Box::new(p.x.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_y(p: *const G1Affine) -> *const Fq {
-    let p = unsafe { *p };
-    return Box::into_raw(Box::new(p.y.clone()));
+    catch_ffi("zexe_bn382_g1_affine_y", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        // The point at infinity has no coordinates.
+        if p.infinity {
+            return std::ptr::null();
+        }
+        return into_handle(Box::new(p.y.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_is_zero(p: *const G1Affine) -> bool {
-    let p = unsafe { &*p };
-    return p.is_zero();
+    catch_ffi("zexe_bn382_g1_affine_is_zero", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.is_zero();
+    })
+}
+
+crate::delete_stub!(zexe_bn382_g1_affine_delete, G1Affine);
+
+// G1 projective vector stubs
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_vector_create() -> *mut Vec<G1Projective> {
+    catch_ffi("zexe_bn382_g1_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_vector_length(v: *const Vec<G1Projective>) -> i32 {
+    catch_ffi("zexe_bn382_g1_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_delete(x: *mut G1Affine) {
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bn382_g1_vector_emplace_back(v: *mut Vec<G1Projective>, x: *const G1Projective) {
+    catch_ffi("zexe_bn382_g1_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_vector_get(v: *mut Vec<G1Projective>, i: u32) -> *mut G1Projective {
+    catch_ffi("zexe_bn382_g1_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_g1_vector_delete, Vec<G1Projective>);
+
 // G1 vector stubs
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_vector_create() -> *mut Vec<G1Affine> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_bn382_g1_affine_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_vector_length(v: *const Vec<G1Affine>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_bn382_g1_affine_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -1796,22 +4049,53 @@ pub extern "C" fn zexe_bn382_g1_affine_vector_emplace_back(
     v: *mut Vec<G1Affine>,
     x: *const G1Affine,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
+    catch_ffi("zexe_bn382_g1_affine_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_g1_affine_vector_get(v: *mut Vec<G1Affine>, i: u32) -> *mut G1Affine {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
+    catch_ffi("zexe_bn382_g1_affine_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_bn382_g1_affine_vector_delete, Vec<G1Affine>);
+
+// The sum of the points.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_g1_affine_vector_sum(v: *const Vec<G1Affine>) -> *const G1Affine {
+    catch_ffi("zexe_bn382_g1_affine_vector_sum", std::ptr::null(), || {
+        let v = handle(v);
+        let v = unsafe { &*v };
+        into_handle(Box::new(in_pool(|| affine_sum(v)).into_affine()))
+    })
 }
 
+// The sum of the points multiplied by the scalars, which must be as many.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_vector_delete(v: *mut Vec<G1Affine>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_bn382_g1_affine_vector_linear_combination(
+    v: *const Vec<G1Affine>,
+    s: *const Vec<Fp>,
+) -> *const G1Affine {
+    const NAME: &str = "zexe_bn382_g1_affine_vector_linear_combination";
+    catch_ffi(NAME, std::ptr::null(), || {
+        let v = handle(v);
+        let s = handle(s);
+        let v = unsafe { &*v };
+        let s = unsafe { &*s };
+        match in_pool(|| affine_linear_combination(v, s)) {
+            Ok(res) => into_handle(Box::new(res.into_affine())),
+            Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+        }
+    })
 }
 
 // Fq sponge stubs
@@ -1819,25 +4103,23 @@ pub extern "C" fn zexe_bn382_g1_affine_vector_delete(v: *mut Vec<G1Affine>) {
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_sponge_params_create() -> *mut poseidon::ArithmeticSpongeParams<Fq>
 {
-    let ret = oracle::bn_382::fq::params();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_sponge_params_create", std::ptr::null_mut(), || {
+        let ret = oracle::bn_382::fq::params();
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_sponge_params_delete(x: *mut poseidon::ArithmeticSpongeParams<Fq>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_sponge_params_delete, poseidon::ArithmeticSpongeParams<Fq>);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_sponge_create() -> *mut poseidon::ArithmeticSponge<Fq, SC> {
-    let ret = oracle::poseidon::ArithmeticSponge::<Fq, SC>::new();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("zexe_bn382_fq_sponge_create", std::ptr::null_mut(), || {
+        let ret = oracle::poseidon::ArithmeticSponge::<Fq, SC>::new();
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_fq_sponge_delete(x: *mut poseidon::ArithmeticSponge<Fp, SC>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_bn382_fq_sponge_delete, poseidon::ArithmeticSponge<Fq, SC>);
 
 #[no_mangle]
 pub extern "C" fn zexe_bn382_fq_sponge_absorb(
@@ -1845,87 +4127,202 @@ pub extern "C" fn zexe_bn382_fq_sponge_absorb(
     params: *const poseidon::ArithmeticSpongeParams<Fq>,
     x: *const Fq,
 ) {
-    let sponge = unsafe { &mut (*sponge) };
-    let params = unsafe { &(*params) };
-    let x = unsafe { *x };
+    catch_ffi("zexe_bn382_fq_sponge_absorb", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let x = handle(x);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let x = unsafe { *x };
 
-    sponge.absorb(params, &[x]);
+        sponge.absorb(params, &[x]);
+    })
 }
 
+// Absorbs every element of the vector, in order, as repeated calls to
+// zexe_bn382_fq_sponge_absorb would.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_fq_sponge_squeeze(
+pub extern "C" fn zexe_bn382_fq_sponge_absorb_vector(
     sponge: *mut poseidon::ArithmeticSponge<Fq, SC>,
     params: *const poseidon::ArithmeticSpongeParams<Fq>,
-) -> *mut Fq {
-    let sponge = unsafe { &mut (*sponge) };
-    let params = unsafe { &(*params) };
-
-    let ret = sponge.squeeze(params);
-    Box::into_raw(Box::new(ret))
-}
-
-// G1 affine pair#[no_mangle]
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_0(p: *const (G1Affine, G1Affine)) -> *const G1Affine {
-    let (x0, _) = unsafe { *p };
-    return Box::into_raw(Box::new(x0.clone()));
-}
+    v: *const Vec<Fq>,
+) {
+    catch_ffi("zexe_bn382_fq_sponge_absorb_vector", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let v = handle(v);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let v = unsafe { &(*v) };
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_1(p: *const (G1Affine, G1Affine)) -> *const G1Affine {
-    let (_, x1) = unsafe { *p };
-    return Box::into_raw(Box::new(x1.clone()));
+        sponge.absorb(params, &v[..]);
+    })
 }
 
+// As zexe_bn382_fq_sponge_absorb_vector, for an array of len elements.
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_make(
-    x0: *const G1Affine,
-    x1: *const G1Affine,
-) -> *const (G1Affine, G1Affine) {
-    let res = ((unsafe { *x0 }), (unsafe { *x1 }));
-    return Box::into_raw(Box::new(res));
-}
+pub extern "C" fn zexe_bn382_fq_sponge_absorb_raw(
+    sponge: *mut poseidon::ArithmeticSponge<Fq, SC>,
+    params: *const poseidon::ArithmeticSpongeParams<Fq>,
+    xs: *const Fq,
+    len: usize,
+) {
+    catch_ffi("zexe_bn382_fq_sponge_absorb_raw", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let xs = handle(xs);
+        if len == 0 {
+            return;
+        }
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let xs = unsafe { std::slice::from_raw_parts(xs, len) };
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_delete(x: *mut (G1Affine, G1Affine)) {
-    let _box = unsafe { Box::from_raw(x) };
+        sponge.absorb(params, xs);
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_vector_create() -> *mut Vec<(G1Affine, G1Affine)> {
-    return Box::into_raw(Box::new(Vec::new()));
-}
+pub extern "C" fn zexe_bn382_fq_sponge_squeeze(
+    sponge: *mut poseidon::ArithmeticSponge<Fq, SC>,
+    params: *const poseidon::ArithmeticSpongeParams<Fq>,
+) -> *mut Fq {
+    catch_ffi("zexe_bn382_fq_sponge_squeeze", std::ptr::null_mut(), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+
+        let ret = sponge.squeeze(params);
+        into_handle(Box::new(ret))
+    })
+}
+
+// Serializes the state of the sponge, to resume it with
+// zexe_bn382_fq_sponge_of_bytes and the same parameters.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_sponge_to_bytes(
+    sponge: *const poseidon::ArithmeticSponge<Fq, SC>,
+) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_fq_sponge_to_bytes", std::ptr::null_mut(), || {
+        let sponge = handle(sponge);
+        let sponge = unsafe { &(*sponge) };
+        let mut res = vec![];
+        write_sponge(sponge, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_sponge_of_bytes(
+    data: *const u8,
+    len: usize,
+) -> *mut poseidon::ArithmeticSponge<Fq, SC> {
+    catch_ffi("zexe_bn382_fq_sponge_of_bytes", std::ptr::null_mut(), || {
+        let mut r = unsafe { std::slice::from_raw_parts(data, len) };
+        match read_sponge(&mut r) {
+            Ok(res) if r.is_empty() => into_handle(Box::new(res)),
+            Ok(_) => null_with_error("zexe_bn382_fq_sponge_of_bytes: Trailing bytes.".to_string()),
+            Err(e) => null_with_error(format!("zexe_bn382_fq_sponge_of_bytes: {}", e)),
+        }
+    })
+}
+
+// G1 affine pair
+crate::affine_pair_stubs!(
+    G1Affine,
+    pair_0: zexe_bn382_g1_affine_pair_0,
+    pair_1: zexe_bn382_g1_affine_pair_1,
+    pair_make: zexe_bn382_g1_affine_pair_make,
+    pair_delete: zexe_bn382_g1_affine_pair_delete,
+    vector_create: zexe_bn382_g1_affine_pair_vector_create,
+    vector_length: zexe_bn382_g1_affine_pair_vector_length,
+    vector_emplace_back: zexe_bn382_g1_affine_pair_vector_emplace_back,
+    vector_get: zexe_bn382_g1_affine_pair_vector_get,
+    vector_delete: zexe_bn382_g1_affine_pair_vector_delete,
+    vector_cursor_create: zexe_bn382_g1_affine_pair_vector_cursor_create,
+    vector_cursor_next: zexe_bn382_g1_affine_pair_vector_cursor_next,
+    vector_cursor_delete: zexe_bn382_g1_affine_pair_vector_cursor_delete,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urs() -> URS<Bn_382> {
+        URS::create(4, (0..4).collect(), &mut system_rng())
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_vector_length(
-    v: *const Vec<(G1Affine, G1Affine)>,
-) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
-}
+    #[test]
+    fn dummy_accumulators_pass_their_checks() {
+        let urs = into_handle(Box::new(urs()));
+        let acc = zexe_bn382_fp_urs_dummy_opening_check(urs);
+        assert!(zexe_bn382_fp_urs_opening_accumulator_check(urs, acc));
+
+        let bounds = into_handle(Box::new(vec![1, 3, 4]));
+        let acc_db = zexe_bn382_fp_urs_dummy_degree_bound_checks(urs, bounds);
+        assert!(zexe_bn382_fp_urs_degree_bound_accumulator_check(
+            urs, bounds, acc_db
+        ));
+
+        release_handle("test", acc as *mut AffinePair<G1Affine>);
+        release_handle("test", acc_db as *mut Vec<G1Affine>);
+        release_handle("test", bounds);
+        release_handle("test", urs as *mut URS<Bn_382>);
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_vector_emplace_back(
-    v: *mut Vec<(G1Affine, G1Affine)>,
-    x: *const (G1Affine, G1Affine),
-) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(*x_);
-}
+    #[test]
+    fn opening_accumulators_check_the_values() {
+        let urs = urs();
+        let mut rng = system_rng();
+        let p = DensePolynomial::<Fp>::rand(3, &mut rng);
+        let z = Fp::rand(&mut rng);
+        let f = urs.commit(&p).unwrap();
+        let v = p.evaluate(z);
+        let pi = urs.open(vec![&p], Fp::one(), z).unwrap();
+        let r = Fp::rand(&mut rng);
+
+        let acc = opening_accumulator(&urs, &[z], &[f], &[v], &[pi], Fp::one(), r).unwrap();
+        assert!(opening_accumulator_holds(&urs, acc));
+        let acc =
+            opening_accumulator(&urs, &[z], &[f], &[v + &Fp::one()], &[pi], Fp::one(), r).unwrap();
+        assert!(!opening_accumulator_holds(&urs, acc));
+        assert!(opening_accumulator(&urs, &[z], &[f, f], &[v], &[pi], Fp::one(), r).is_none());
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_vector_get(
-    v: *mut Vec<(G1Affine, G1Affine)>,
-    i: u32,
-) -> *mut (G1Affine, G1Affine) {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize]));
-}
+    #[test]
+    fn degree_bound_accumulators_check_the_bounds() {
+        let urs = urs();
+        let mut rng = system_rng();
+        let p = DensePolynomial::<Fp>::rand(1, &mut rng);
+        let (shifted, unshifted) = urs.commit_with_degree_bound(&p, 2).unwrap();
+        let comms = [DegreeBoundedCommitment { unshifted, shifted }];
+        let r = Fp::rand(&mut rng);
+
+        let acc = degree_bound_accumulator(&urs, &[2], &comms, r).unwrap();
+        assert!(degree_bound_accumulator_holds(&urs, &[2], &acc));
+        assert!(!degree_bound_accumulator_holds(&urs, &[3], &acc));
+        assert!(degree_bound_accumulator(&urs, &[2, 3], &comms, r).is_none());
+    }
 
-#[no_mangle]
-pub extern "C" fn zexe_bn382_g1_affine_pair_vector_delete(v: *mut Vec<(G1Affine, G1Affine)>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+    #[test]
+    fn urs_read_with_digest_reads_what_was_written() {
+        let path = std::env::temp_dir().join(format!("zexe-bn382-fp-urs-{}", std::process::id()));
+        let path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        let ptr = path.as_ptr() as *mut c_char;
+
+        let urs = zexe_bn382_fp_urs_create(4);
+        let digest = zexe_bn382_fp_urs_write_with_digest(urs, ptr);
+        let read = zexe_bn382_fp_urs_read_with_digest(ptr);
+        assert_eq!(unsafe { *read.b }, unsafe { *digest });
+        let read_digest = zexe_bn382_fp_urs_digest(read.a);
+        assert_eq!(unsafe { *read_digest }, unsafe { *digest });
+
+        let bytes = std::fs::read(path.to_str().unwrap()).unwrap();
+        std::fs::write(path.to_str().unwrap(), &bytes[..bytes.len() / 2]).unwrap();
+        let read = zexe_bn382_fp_urs_read_with_digest(ptr);
+        assert!(read.a.is_null() && read.b.is_null());
+        assert!(take_last_error().is_some());
+        let _ = std::fs::remove_file(path.to_str().unwrap());
+    }
 }
@@ -0,0 +1,474 @@
+use crate::bn382_dlog::fq_proof_create_with_sponges;
+use crate::common::*;
+use algebra::{
+    bn_382::{
+        fp::Fp,
+        fq::Fq,
+        g::{Affine as GAffine, Bn_382GParameters},
+    },
+    ToBytes,
+};
+use ff_fft::EvaluationDomain;
+use marlin_protocol_dlog::index::{Index as DlogIndex, VerifierIndex as DlogVerifierIndex};
+use marlin_protocol_dlog::marlin_sponge::FrSponge;
+use marlin_protocol_dlog::prover::{
+    ProofEvaluations as DlogProofEvaluations, ProverProof as DlogProof,
+};
+use oracle::{
+    poseidon::{ArithmeticSpongeParams, MarlinSpongeConstants as SC},
+    sponge::{DefaultFqSponge, DefaultFrSponge, FqSponge, ScalarChallenge},
+};
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+// Fq verifier transcript simulator
+//
+// Reports the sequence of sponge operations the verifier of an Fq proof
+// performs, for laying out the recursion circuit which replays them. The
+// report is recorded from the verifier's own oracle computation, run with
+// sponges which log each operation before forwarding it, so it cannot drift
+// from what the verifier does.
+//
+// The sequence only depends on the shape of the proof (the number of chunks
+// of each commitment and evaluation, of previous challenges and of opening
+// rounds), not on its values, so any proof for the index will do, including
+// one with dummy values.
+//
+// The "fq" sponge works over Fp, the base field of G, and absorbs group
+// elements; the "fr" sponge works over Fq. Each event records the sponge, the
+// operation, the number of elements involved, and for challenges the field
+// they are returned in.
+//
+// The group elements absorbed by the "fq" sponge, which are all the
+// commitments of the proof and of the index, are also kept, in the order they
+// are absorbed, so that bindings can reproduce the digest the evaluations are
+// checked against without knowing that order.
+//
+// Each event also records the values absorbed or squeezed, serialized, so
+// that the transcripts of the prover and of the verifier of a proof can be
+// recorded and compared to find the first operation at which they disagree.
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    AbsorbG,
+    AbsorbFr,
+    AbsorbEvaluations,
+    Challenge,
+    ChallengeFq,
+    Digest,
+}
+
+#[derive(Clone, PartialEq)]
+struct Event {
+    sponge: &'static str,
+    op: Op,
+    count: usize,
+    data: Vec<u8>,
+}
+
+thread_local! {
+    static TRANSCRIPT: RefCell<Vec<Event>> = RefCell::new(vec![]);
+    static ABSORBED_G: RefCell<Vec<GAffine>> = RefCell::new(vec![]);
+}
+
+fn record<T: ToBytes>(sponge: &'static str, op: Op, values: &[T]) {
+    let mut data = vec![];
+    for x in values {
+        x.write(&mut data).unwrap();
+    }
+    let count = values.len();
+    TRANSCRIPT.with(|t| {
+        t.borrow_mut().push(Event {
+            sponge,
+            op,
+            count,
+            data,
+        })
+    });
+}
+
+// Runs f with the events of the recording sponges going to a fresh
+// transcript, and returns its result, the events and the absorbed group
+// elements. The sponges must be used on the calling thread.
+fn recording<T, F: FnOnce() -> T>(f: F) -> (T, Vec<Event>, Vec<GAffine>) {
+    TRANSCRIPT.with(|t| t.borrow_mut().clear());
+    ABSORBED_G.with(|a| a.borrow_mut().clear());
+    let res = f();
+    (
+        res,
+        TRANSCRIPT.with(|t| t.replace(vec![])),
+        ABSORBED_G.with(|a| a.replace(vec![])),
+    )
+}
+
+// The event as a JSON object, with its values in hexadecimal if with_data.
+fn event_json(e: &Event, with_data: bool) -> String {
+    let (op, field) = match e.op {
+        Op::AbsorbG => ("absorb_g", None),
+        Op::AbsorbFr => ("absorb_fr", None),
+        Op::AbsorbEvaluations => ("absorb_evaluations", None),
+        Op::Challenge => ("challenge", Some("fq")),
+        Op::ChallengeFq => ("challenge", Some("fp")),
+        Op::Digest => ("digest", Some("fq")),
+    };
+    let mut res = format!(
+        "{{\"sponge\":\"{}\",\"op\":\"{}\",\"count\":{}",
+        e.sponge, op, e.count
+    );
+    if let Some(field) = field {
+        res.push_str(&format!(",\"field\":\"{}\"", field));
+    }
+    if with_data {
+        let data: String = e.data.iter().map(|b| format!("{:02x}", b)).collect();
+        res.push_str(&format!(",\"data\":\"{}\"", data));
+    }
+    res.push('}');
+    res
+}
+
+#[derive(Clone)]
+struct RecordingFqSponge(DefaultFqSponge<Bn_382GParameters, SC>);
+
+impl FqSponge<Fp, GAffine, Fq> for RecordingFqSponge {
+    fn new(p: ArithmeticSpongeParams<Fp>) -> Self {
+        RecordingFqSponge(DefaultFqSponge::new(p))
+    }
+
+    fn absorb_g(&mut self, g: &[GAffine]) {
+        record("fq", Op::AbsorbG, g);
+        ABSORBED_G.with(|a| a.borrow_mut().extend_from_slice(g));
+        self.0.absorb_g(g)
+    }
+
+    fn absorb_fr(&mut self, x: &[Fq]) {
+        record("fq", Op::AbsorbFr, x);
+        self.0.absorb_fr(x)
+    }
+
+    fn challenge(&mut self) -> Fq {
+        let c = self.0.challenge();
+        record("fq", Op::Challenge, &[c]);
+        c
+    }
+
+    fn challenge_fq(&mut self) -> Fp {
+        let c = self.0.challenge_fq();
+        record("fq", Op::ChallengeFq, &[c]);
+        c
+    }
+
+    fn digest(self) -> Fq {
+        let d = self.0.digest();
+        record("fq", Op::Digest, &[d]);
+        d
+    }
+}
+
+struct RecordingFrSponge(DefaultFrSponge<Fq, SC>);
+
+impl FrSponge<Fq> for RecordingFrSponge {
+    fn new(p: ArithmeticSpongeParams<Fq>) -> Self {
+        RecordingFrSponge(<DefaultFrSponge<Fq, SC> as FrSponge<Fq>>::new(p))
+    }
+
+    fn absorb(&mut self, x: &Fq) {
+        record("fr", Op::AbsorbFr, std::slice::from_ref(x));
+        self.0.absorb(x)
+    }
+
+    fn challenge(&mut self) -> ScalarChallenge<Fq> {
+        let c = self.0.challenge();
+        record("fr", Op::Challenge, &[c.0]);
+        c
+    }
+
+    fn absorb_evaluations(&mut self, x_hat: &[Fq], e: &DlogProofEvaluations<Vec<Fq>>) {
+        let mut values = x_hat.to_vec();
+        for v in &[&e.w, &e.za, &e.zb, &e.h1, &e.g1, &e.h2, &e.g2, &e.h3, &e.g3] {
+            values.extend_from_slice(v);
+        }
+        for t in &[&e.row, &e.col, &e.val, &e.rc] {
+            for v in t.iter() {
+                values.extend_from_slice(v);
+            }
+        }
+        record("fr", Op::AbsorbEvaluations, &values);
+        self.0.absorb_evaluations(x_hat, e)
+    }
+}
+
+// Runs the verifier's oracles, including the opening prechallenges, and
+// returns the recorded events and absorbed group elements.
+fn record_oracles(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> (Vec<Event>, Vec<GAffine>) {
+    let srs = index.srs.get_ref();
+    let x_hat = evals_from_coeffs(proof.public.clone(), index.domains.x).interpolate();
+    let x_hat_comm = srs.commit(&x_hat, None);
+    let ((), events, absorbed) = recording(|| {
+        let (mut sponge, _) =
+            proof.oracles::<RecordingFqSponge, RecordingFrSponge>(index, x_hat_comm, &x_hat);
+        proof.proof.prechallenges(&mut sponge);
+    });
+    (events, absorbed)
+}
+
+// The recorded events as a JSON array.
+pub fn fq_transcript_report(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> String {
+    let (events, _) = record_oracles(index, proof);
+    let events: Vec<String> = events.iter().map(|e| event_json(e, false)).collect();
+    format!("[{}]", events.join(","))
+}
+
+// The group elements absorbed by the "fq" sponge, in order. Unlike the
+// report, these depend on the values of the proof.
+pub fn fq_transcript_commitments(
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> Vec<GAffine> {
+    record_oracles(index, proof).1
+}
+
+fn check_public(
+    name: &str,
+    index: &DlogVerifierIndex<GAffine>,
+    proof: &DlogProof<GAffine>,
+) -> Result<(), String> {
+    if proof.public.len() > index.domains.x.size() {
+        return Err(format!(
+            "{}: {} public inputs for a domain of size {}",
+            name,
+            proof.public.len(),
+            index.domains.x.size()
+        ));
+    }
+    Ok(())
+}
+
+// Returns the transcript report as a newly allocated string, which must be
+// freed with zexe_bn382_fq_transcript_report_delete.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_report(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> *mut c_char {
+    catch_ffi("zexe_bn382_fq_transcript_report", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &*index };
+        let proof = unsafe { &*proof };
+        if let Err(e) = check_public("zexe_bn382_fq_transcript_report", index, proof) {
+            return null_with_error(e);
+        }
+        CString::new(fq_transcript_report(index, proof))
+            .unwrap()
+            .into_raw()
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_transcript_report_delete, CString);
+
+// The group elements the verifier of the proof absorbs into its "fq" sponge,
+// in order, for camlsnark_bn382_fp_sponge_absorb_g_vector. The scalars absorbed in
+// between, at the positions given by the report, must be absorbed as well to
+// obtain the same digest.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_commitments(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> *mut Vec<GAffine> {
+    catch_ffi("zexe_bn382_fq_transcript_commitments", std::ptr::null_mut(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &*index };
+        let proof = unsafe { &*proof };
+        if let Err(e) = check_public("zexe_bn382_fq_transcript_commitments", index, proof) {
+            return null_with_error(e);
+        }
+        into_handle(Box::new(fq_transcript_commitments(index, proof)))
+    })
+}
+
+// Fq transcript recording and replay
+//
+// A transcript is the sequence of events, with their values, of the sponges
+// of one run of the prover or of the verifier's oracles. Comparing the
+// transcripts of the prover and the verifier of a proof finds the first
+// operation at which they disagree, which is where to look when a proof does
+// not verify.
+
+#[derive(Clone)]
+pub struct FqTranscript(Vec<Event>);
+
+// The position of the first event at which the transcripts differ, if any. A
+// transcript which is a strict prefix of the other differs at its end.
+fn first_divergence(a: &[Event], b: &[Event]) -> Option<usize> {
+    match a.iter().zip(b.iter()).position(|(x, y)| x != y) {
+        Some(i) => Some(i),
+        None if a.len() != b.len() => Some(a.len().min(b.len())),
+        None => None,
+    }
+}
+
+fn divergence_json(a: &[Event], b: &[Event]) -> String {
+    let side = |t: &[Event], i: usize| match t.get(i) {
+        Some(e) => event_json(e, true),
+        None => "null".to_string(),
+    };
+    match first_divergence(a, b) {
+        None => "{\"equal\":true}".to_string(),
+        Some(i) => format!(
+            "{{\"equal\":false,\"position\":{},\"expected\":{},\"actual\":{}}}",
+            i,
+            side(a, i),
+            side(b, i)
+        ),
+    }
+}
+
+pub struct FqRecordedProof {
+    proof: DlogProof<GAffine>,
+    transcript: FqTranscript,
+}
+
+// The transcript of the verifier's oracles for the proof, including the
+// challenges of the opening proof.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_record_verify(
+    index: *const DlogVerifierIndex<GAffine>,
+    proof: *const DlogProof<GAffine>,
+) -> *mut FqTranscript {
+    const NAME: &str = "zexe_bn382_fq_transcript_record_verify";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &*index };
+        let proof = unsafe { &*proof };
+        if let Err(e) = check_public(NAME, index, proof) {
+            return null_with_error(e);
+        }
+        into_handle(Box::new(FqTranscript(record_oracles(index, proof).0)))
+    })
+}
+
+// As zexe_bn382_fq_proof_create, also recording the transcript of the prover.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_proof_create_recorded(
+    index: *const DlogIndex<GAffine>,
+    primary_input: *const Vec<Fq>,
+    auxiliary_input: *const Vec<Fq>,
+    prev_challenges: *const Vec<Fq>,
+    prev_sgs: *const Vec<GAffine>,
+) -> *mut FqRecordedProof {
+    const NAME: &str = "zexe_bn382_fq_proof_create_recorded";
+    catch_ffi(NAME, std::ptr::null_mut(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let (proof, events, _) = in_pool(|| {
+            recording(|| {
+                fq_proof_create_with_sponges::<RecordingFqSponge, RecordingFrSponge, _>(
+                    NAME,
+                    index,
+                    primary_input,
+                    auxiliary_input,
+                    prev_challenges,
+                    prev_sgs,
+                    &mut system_rng(),
+                )
+            })
+        });
+        // On failure, the error is already set.
+        match take_handle(NAME, proof as *mut DlogProof<GAffine>) {
+            Some(proof) => into_handle(Box::new(FqRecordedProof {
+                proof: *proof,
+                transcript: FqTranscript(events),
+            })),
+            None => std::ptr::null_mut(),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_recorded_proof_proof(
+    p: *const FqRecordedProof,
+) -> *mut DlogProof<GAffine> {
+    catch_ffi("zexe_bn382_fq_recorded_proof_proof", std::ptr::null_mut(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).proof }).clone();
+        into_handle(Box::new(x))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_recorded_proof_transcript(
+    p: *const FqRecordedProof,
+) -> *mut FqTranscript {
+    catch_ffi("zexe_bn382_fq_recorded_proof_transcript", std::ptr::null_mut(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).transcript }).clone();
+        into_handle(Box::new(x))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_recorded_proof_delete, FqRecordedProof);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_length(t: *const FqTranscript) -> usize {
+    catch_ffi("zexe_bn382_fq_transcript_length", 0, || {
+        let t = handle(t);
+        (unsafe { &*t }).0.len()
+    })
+}
+
+// The events of the transcript, with their values, as a JSON array. The
+// string must be freed with zexe_string_delete.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_to_json(t: *const FqTranscript) -> *mut c_char {
+    catch_ffi("zexe_bn382_fq_transcript_to_json", std::ptr::null_mut(), || {
+        let t = handle(t);
+        let events: Vec<String> = (unsafe { &*t }).0.iter().map(|e| event_json(e, true)).collect();
+        into_c_string(format!("[{}]", events.join(",")))
+    })
+}
+
+// Replays the transcript b against the expected transcript a. Returns the
+// position of the first event at which they differ, or -1 if they are equal.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_first_divergence(
+    a: *const FqTranscript,
+    b: *const FqTranscript,
+) -> i64 {
+    catch_ffi("zexe_bn382_fq_transcript_first_divergence", 0, || {
+        let a = handle(a);
+        let b = handle(b);
+        match first_divergence(&(unsafe { &*a }).0, &(unsafe { &*b }).0) {
+            Some(i) => i as i64,
+            None => -1,
+        }
+    })
+}
+
+// As zexe_bn382_fq_transcript_first_divergence, as a JSON object with the
+// position and the events of both transcripts there, or null past the end of
+// one. The string must be freed with zexe_string_delete.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_transcript_compare(
+    a: *const FqTranscript,
+    b: *const FqTranscript,
+) -> *mut c_char {
+    catch_ffi("zexe_bn382_fq_transcript_compare", std::ptr::null_mut(), || {
+        let a = handle(a);
+        let b = handle(b);
+        into_c_string(divergence_json(&(unsafe { &*a }).0, &(unsafe { &*b }).0))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_fq_transcript_delete, FqTranscript);
@@ -0,0 +1,336 @@
+use crate::common::*;
+use algebra::{
+    biginteger::BigInteger384,
+    bn_382::{
+        fp::Fp,
+        fq::Fq,
+        g::{Affine as GAffine, Projective as GProjective},
+    },
+    curves::{AffineCurve, ProjectiveCurve},
+    fields::PrimeField,
+    FromBytes, ToBytes, UniformRand, Zero,
+};
+use commitment_dlog::commitment::CommitmentCurve;
+use groupmap::GroupMap;
+use oracle::poseidon::{ArithmeticSponge, MarlinSpongeConstants as SC, Sponge};
+use std::io::{Read, Result as IoResult, Write};
+
+// VRF over the G curve
+//
+// An ECVRF-style verifiable random function whose hashes are all Poseidon
+// over Fp, the base field of G, so that proofs can be checked and outputs
+// recomputed in a circuit over Fp. Messages are vectors of Fp elements.
+//
+// With secret key sk and public key pk = sk * g, the proof for a message m is
+// (gamma, c, s) where
+//   h = map(H(0, pk, |m|, m)), gamma = sk * h,
+//   c = H(1, h, gamma, k * g, k * h, 0) truncated to 128 bits, s = k + c * sk
+// for a random nonce k, and the output is H(2, gamma, 0). The leading
+// constants separate the three uses of the hash, and the length of the
+// message, absorbed before it as in derive_proof_seed, keeps messages which
+// differ only by trailing zeros apart.
+
+const HASH_TO_CURVE_TAG: u64 = 0;
+const CHALLENGE_TAG: u64 = 1;
+const OUTPUT_TAG: u64 = 2;
+
+#[derive(Clone, Copy)]
+pub struct VrfProof {
+    pub gamma: GAffine,
+    pub c: Fq,
+    pub s: Fq,
+}
+
+impl VrfProof {
+    fn write<W: Write>(&self, mut w: W) -> IoResult<()> {
+        self.gamma.write(&mut w)?;
+        self.c.write(&mut w)?;
+        self.s.write(&mut w)
+    }
+
+    fn read<R: Read>(mut r: R) -> IoResult<Self> {
+        Ok(VrfProof {
+            gamma: GAffine::read(&mut r)?,
+            c: Fq::read(&mut r)?,
+            s: Fq::read(&mut r)?,
+        })
+    }
+}
+
+fn hash(tag: u64, points: &[GAffine], xs: &[Fp]) -> Fp {
+    let params = oracle::bn_382::fp::params();
+    let mut sponge = ArithmeticSponge::<Fp, SC>::new();
+    sponge.absorb(&params, &[Fp::from(tag)]);
+    for p in points {
+        sponge.absorb(&params, &[p.x]);
+        sponge.absorb(&params, &[p.y]);
+    }
+    sponge.absorb(&params, &[Fp::from(xs.len() as u64)]);
+    for x in xs {
+        sponge.absorb(&params, &[*x]);
+    }
+    sponge.squeeze(&params)
+}
+
+fn hash_to_curve(pk: &GAffine, msg: &[Fp]) -> GAffine {
+    let map = <GAffine as CommitmentCurve>::Map::setup();
+    let (x, y) = map.to_group(hash(HASH_TO_CURVE_TAG, &[*pk], msg));
+    GAffine::new(x, y, false)
+}
+
+// Keeps the low 128 bits of the hash, which are then a valid scalar.
+fn challenge(h: &GAffine, gamma: &GAffine, u: &GAffine, v: &GAffine) -> Fq {
+    let c = hash(CHALLENGE_TAG, &[*h, *gamma, *u, *v], &[]).into_repr();
+    let mut limbs = [0u64; 6];
+    limbs[..2].copy_from_slice(&c.0[..2]);
+    Fq::from_repr(BigInteger384(limbs))
+}
+
+fn is_valid_point(p: &GAffine) -> bool {
+    !p.is_zero() && p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve()
+}
+
+fn prove(sk: Fq, msg: &[Fp]) -> VrfProof {
+    let g = GAffine::prime_subgroup_generator();
+    let pk = g.mul(sk).into_affine();
+    let h = hash_to_curve(&pk, msg);
+    let gamma = h.mul(sk).into_affine();
+    let k = Fq::rand(&mut system_rng());
+    let u = g.mul(k).into_affine();
+    let v = h.mul(k).into_affine();
+    let c = challenge(&h, &gamma, &u, &v);
+    VrfProof {
+        gamma,
+        c,
+        s: k + &(c * &sk),
+    }
+}
+
+fn verify(pk: &GAffine, msg: &[Fp], proof: &VrfProof) -> bool {
+    if !is_valid_point(pk) || !is_valid_point(&proof.gamma) {
+        return false;
+    }
+    let g = GAffine::prime_subgroup_generator();
+    let h = hash_to_curve(pk, msg);
+    let u = (g.mul(proof.s) - &pk.mul(proof.c)).into_affine();
+    let v = (h.mul(proof.s) - &proof.gamma.mul(proof.c)).into_affine();
+    challenge(&h, &proof.gamma, &u, &v) == proof.c
+}
+
+fn output(proof: &VrfProof) -> Fp {
+    hash(OUTPUT_TAG, &[proof.gamma], &[])
+}
+
+// Keys
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_secret_key_random() -> *const Fq {
+    catch_ffi("zexe_bn382_vrf_secret_key_random", std::ptr::null(), || {
+        let mut sk = Fq::zero();
+        while sk.is_zero() {
+            sk = Fq::rand(&mut system_rng());
+        }
+        into_handle(Box::new(sk))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_public_key(sk: *const Fq) -> *const GAffine {
+    catch_ffi("zexe_bn382_vrf_public_key", std::ptr::null(), || {
+        let sk = handle(sk);
+        let sk = unsafe { *sk };
+        let pk: GProjective = GAffine::prime_subgroup_generator().mul(sk);
+        into_handle(Box::new(pk.into_affine()))
+    })
+}
+
+// Proving and verifying
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_prove(sk: *const Fq, msg: *const Vec<Fp>) -> *const VrfProof {
+    catch_ffi("zexe_bn382_vrf_prove", std::ptr::null(), || {
+        let sk = handle(sk);
+        let msg = handle(msg);
+        let sk = unsafe { *sk };
+        let msg = unsafe { &*msg };
+        if sk.is_zero() {
+            return null_with_error("zexe_bn382_vrf_prove: Zero secret key".to_string());
+        }
+        into_handle(Box::new(prove(sk, msg)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_verify(
+    pk: *const GAffine,
+    msg: *const Vec<Fp>,
+    proof: *const VrfProof,
+) -> bool {
+    catch_ffi("zexe_bn382_vrf_verify", false, || {
+        let pk = handle(pk);
+        let msg = handle(msg);
+        let proof = handle(proof);
+        let pk = unsafe { &*pk };
+        let msg = unsafe { &*msg };
+        let proof = unsafe { &*proof };
+        verify(pk, msg, proof)
+    })
+}
+
+// The output of a proof, which is only meaningful once the proof has been
+// verified.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_output(proof: *const VrfProof) -> *const Fp {
+    catch_ffi("zexe_bn382_vrf_proof_output", std::ptr::null(), || {
+        let proof = handle(proof);
+        let proof = unsafe { &*proof };
+        into_handle(Box::new(output(proof)))
+    })
+}
+
+// Verifies the proof and returns its output, or null if it is invalid.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_verify_output(
+    pk: *const GAffine,
+    msg: *const Vec<Fp>,
+    proof: *const VrfProof,
+) -> *const Fp {
+    catch_ffi("zexe_bn382_vrf_verify_output", std::ptr::null(), || {
+        let pk = handle(pk);
+        let msg = handle(msg);
+        let proof = handle(proof);
+        let pk = unsafe { &*pk };
+        let msg = unsafe { &*msg };
+        let proof = unsafe { &*proof };
+        if !verify(pk, msg, proof) {
+            return null_with_error("zexe_bn382_vrf_verify_output: Invalid proof".to_string());
+        }
+        into_handle(Box::new(output(proof)))
+    })
+}
+
+// VRF proof stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_make(
+    gamma: *const GAffine,
+    c: *const Fq,
+    s: *const Fq,
+) -> *const VrfProof {
+    catch_ffi("zexe_bn382_vrf_proof_make", std::ptr::null(), || {
+        let gamma = handle(gamma);
+        let c = handle(c);
+        let s = handle(s);
+        into_handle(Box::new(VrfProof {
+            gamma: unsafe { *gamma },
+            c: unsafe { *c },
+            s: unsafe { *s },
+        }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_gamma(p: *const VrfProof) -> *const GAffine {
+    catch_ffi("zexe_bn382_vrf_proof_gamma", std::ptr::null(), || {
+        let p = handle(p);
+        into_handle(Box::new(unsafe { (*p).gamma }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_c(p: *const VrfProof) -> *const Fq {
+    catch_ffi("zexe_bn382_vrf_proof_c", std::ptr::null(), || {
+        let p = handle(p);
+        into_handle(Box::new(unsafe { (*p).c }))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_s(p: *const VrfProof) -> *const Fq {
+    catch_ffi("zexe_bn382_vrf_proof_s", std::ptr::null(), || {
+        let p = handle(p);
+        into_handle(Box::new(unsafe { (*p).s }))
+    })
+}
+
+crate::delete_stub!(zexe_bn382_vrf_proof_delete, VrfProof);
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_to_bytes(p: *const VrfProof) -> *mut Vec<u8> {
+    catch_ffi("zexe_bn382_vrf_proof_to_bytes", std::ptr::null_mut(), || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        let mut res = vec![];
+        p.write(&mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_vrf_proof_of_bytes(data: *const u8, len: usize) -> *const VrfProof {
+    catch_ffi("zexe_bn382_vrf_proof_of_bytes", std::ptr::null(), || {
+        let mut data = unsafe { std::slice::from_raw_parts(data, len) };
+        match VrfProof::read(&mut data) {
+            Ok(_) if !data.is_empty() => null_with_error(
+                "zexe_bn382_vrf_proof_of_bytes: trailing bytes after proof".to_string(),
+            ),
+            Ok(p) => into_handle(Box::new(p)),
+            Err(e) => null_with_error(format!("zexe_bn382_vrf_proof_of_bytes: {}", e)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_length_is_absorbed() {
+        assert_ne!(
+            hash(HASH_TO_CURVE_TAG, &[], &[]),
+            hash(HASH_TO_CURVE_TAG, &[], &[Fp::zero()])
+        );
+        let pk = GAffine::prime_subgroup_generator();
+        assert_ne!(hash_to_curve(&pk, &[]), hash_to_curve(&pk, &[Fp::zero()]));
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let sk = Fq::from(42u64);
+        let pk = GAffine::prime_subgroup_generator().mul(sk).into_affine();
+        let msg = vec![Fp::from(1u64), Fp::from(2u64)];
+        let proof = prove(sk, &msg);
+        assert!(verify(&pk, &msg, &proof));
+        assert!(!verify(&pk, &msg[..1], &proof));
+        assert!(!verify(&pk, &[msg[0], msg[1], Fp::zero()], &proof));
+
+        // The output only depends on the key and message, not on the nonce.
+        assert_eq!(output(&proof), output(&prove(sk, &msg)));
+
+        let mut bytes = vec![];
+        proof.write(&mut bytes).unwrap();
+        let read = VrfProof::read(&bytes[..]).unwrap();
+        assert!(verify(&pk, &msg, &read));
+    }
+
+    #[test]
+    fn bad_proofs_are_rejected() {
+        let sk = Fq::from(42u64);
+        let pk = GAffine::prime_subgroup_generator().mul(sk).into_affine();
+        let msg = vec![Fp::from(1u64)];
+        let proof = prove(sk, &msg);
+
+        let other = GAffine::prime_subgroup_generator()
+            .mul(Fq::from(43u64))
+            .into_affine();
+        assert!(!verify(&other, &msg, &proof));
+        assert!(!verify(&GAffine::zero(), &msg, &proof));
+
+        let mut p = proof;
+        p.s += &Fq::from(1u64);
+        assert!(!verify(&pk, &msg, &p));
+        let mut p = proof;
+        p.gamma = GAffine::zero();
+        assert!(!verify(&pk, &msg, &p));
+    }
+}
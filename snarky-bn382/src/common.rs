@@ -1,11 +1,16 @@
 use algebra::{
-    curves::AffineCurve,
-    fields::{FftField, Field, PrimeField},
-    FromBytes, One, ToBytes, UniformRand, VariableBaseMSM, Zero,
+    biginteger::{BigInteger, BigInteger384},
+    bn_382::glv::{glv_multi_scalar_mul, GLVParameters},
+    curves::{
+        models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        AffineCurve, PairingEngine, ProjectiveCurve,
+    },
+    fields::{FftField, Field, FpParameters, PrimeField},
+    FixedBaseMSM, FixedBaseWnafTable, FromBytes, One, ToBytes, UniformRand, VariableBaseMSM, Zero,
 };
 
 use commitment_dlog::{
-    commitment::{b_poly_coefficients, CommitmentField, CommitmentCurve, PolyComm},
+    commitment::{b_poly_coefficients, CommitmentField, CommitmentCurve, OpeningProof, PolyComm},
     srs::SRS,
 };
 use ff_fft::{
@@ -13,11 +18,28 @@ use ff_fft::{
     Radix2EvaluationDomain as Domain,
 };
 use marlin_circuits::domains::EvaluationDomains;
-use marlin_protocol_pairing::index::MatrixValues;
+use marlin_protocol_dlog::prover::{
+    ProofEvaluations as DlogProofEvaluations, ProverProof as DlogProof,
+};
+use marlin_protocol_pairing::{
+    index::MatrixValues,
+    prover::{ProofEvaluations as PairingProofEvaluations, ProverProof as PairingProof},
+};
+use blake2::{digest::{Input, VariableOutput}, VarBlake2b};
+use lazy_static::lazy_static;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRng, OsRng, RngCore, SeedableRng};
 use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use sprs::{CsMat, CsVecView, CSR};
 use std::io::{Read, Result as IoResult, Write, Error, ErrorKind};
-use std::ffi::c_void;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use crate::gadgets::Row;
 use plonk_protocol_dlog::index::{
     Index as PlonkIndex, VerifierIndex as PlonkVerifierIndex,
     SRSValue as PlonkSRSValue
@@ -26,7 +48,10 @@ use plonk_circuits::{
     constraints::{ConstraintSystem as PlonkConstraintSystem},
     domains::EvaluationDomains as PlonkEvaluationDomains,
 };
-use oracle::poseidon::ArithmeticSpongeParams;
+use oracle::poseidon::{
+    ArithmeticSponge, ArithmeticSpongeParams, MarlinSpongeConstants, PlonkSpongeConstants, Sponge,
+    SpongeConstants, SpongeState,
+};
 
 #[repr(C)]
 pub struct DetSqrtWitness<F> {
@@ -42,6 +67,871 @@ pub struct PointerPair<A, B> {
     pub b: *const B
 }
 
+// A pair of points, such as an opening accumulator or a round of an opening
+// proof. Stubs name it rather than the tuple, which cbindgen cannot declare.
+pub type AffinePair<G> = (G, G);
+
+// What a stub returning these by value returns on a panic (see catch_ffi).
+impl<F> Default for DetSqrtWitness<F> {
+    fn default() -> Self {
+        DetSqrtWitness {
+            c: std::ptr::null_mut(),
+            d: 0,
+            square_root: std::ptr::null_mut(),
+            success: false,
+        }
+    }
+}
+
+impl<A, B> Default for PointerPair<A, B> {
+    fn default() -> Self {
+        PointerPair {
+            a: std::ptr::null(),
+            b: std::ptr::null(),
+        }
+    }
+}
+
+// URS digests
+//
+// The digest of a URS is the BLAKE2b-256 hash of its serialization, which is
+// little-endian regardless of the host. It can be computed while the URS is
+// being read from or written to disk, so that parameter files don't need to be
+// hashed separately.
+
+pub const URS_DIGEST_SIZE: usize = 32;
+
+pub type UrsDigest = [u8; URS_DIGEST_SIZE];
+
+fn urs_hasher() -> VarBlake2b {
+    VarBlake2b::new(URS_DIGEST_SIZE).unwrap()
+}
+
+fn urs_hasher_result(h: VarBlake2b) -> UrsDigest {
+    let mut res = [0u8; URS_DIGEST_SIZE];
+    h.variable_result(|d| res.copy_from_slice(d));
+    res
+}
+
+pub struct DigestWriter<W> {
+    inner: W,
+    hasher: VarBlake2b,
+}
+
+impl<W: Write> DigestWriter<W> {
+    pub fn new(inner: W) -> Self {
+        DigestWriter {
+            inner,
+            hasher: urs_hasher(),
+        }
+    }
+
+    pub fn finish(mut self) -> IoResult<UrsDigest> {
+        self.inner.flush()?;
+        Ok(urs_hasher_result(self.hasher))
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.input(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+pub struct DigestReader<R> {
+    inner: R,
+    hasher: VarBlake2b,
+}
+
+impl<R: Read> DigestReader<R> {
+    pub fn new(inner: R) -> Self {
+        DigestReader {
+            inner,
+            hasher: urs_hasher(),
+        }
+    }
+
+    pub fn finish(self) -> UrsDigest {
+        urs_hasher_result(self.hasher)
+    }
+}
+
+impl<R: Read> Read for DigestReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.input(&buf[..n]);
+        Ok(n)
+    }
+}
+
+// Error reporting
+//
+// Fallible stubs return null and record a message here, which the caller can
+// retrieve with zexe_last_error. The message is per-thread.
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+pub fn set_last_error(msg: String) {
+    let msg = CString::new(msg).unwrap_or_else(|_| CString::new("invalid error message").unwrap());
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+// Records the error and returns null, for stubs returning a boxed value.
+pub fn null_with_error<T>(msg: String) -> *mut T {
+    set_last_error(msg);
+    std::ptr::null_mut()
+}
+
+// Returns the last error recorded on this thread, or null if there is none.
+// The string remains valid until the next error is recorded or cleared.
+#[no_mangle]
+pub extern "C" fn zexe_last_error() -> *const c_char {
+    catch_ffi("zexe_last_error", std::ptr::null(), || {
+        LAST_ERROR.with(|e| match &*e.borrow() {
+            Some(msg) => msg.as_ptr(),
+            None => std::ptr::null(),
+        })
+    })
+}
+
+// Removes and returns the last error recorded on this thread.
+pub fn take_last_error() -> Option<String> {
+    LAST_ERROR.with(|e| e.borrow_mut().take()).map(|msg| msg.to_string_lossy().into_owned())
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_clear_last_error() {
+    catch_ffi("zexe_clear_last_error", (), || {
+        LAST_ERROR.with(|e| *e.borrow_mut() = None);
+    })
+}
+
+// Every stub runs its body through catch_ffi, so that a panic is recorded as
+// the last error instead of unwinding into the caller, which is undefined
+// behaviour. The stub then returns `default`: null for handles, false for
+// predicates, 0 for counts and a failure code for the stubs returning one.
+pub fn catch_ffi<T, F: FnOnce() -> T>(name: &str, default: T, f: F) -> T {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(x) => x,
+        Err(e) => {
+            let msg = if let Some(s) = e.downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = e.downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "unknown error".to_string()
+            };
+            set_last_error(format!("{}: {}", name, msg));
+            default
+        }
+    }
+}
+
+// Randomness
+//
+// Randomness which the caller did not seed comes from system_rng rather than
+// from thread_rng or OsRng directly. It reads the OS entropy source and, once
+// the caller has mixed in a seed with zexe_rng_mix_seed, XORs it with a
+// ChaCha20 stream keyed by the seeds, so that its output is at least as
+// unpredictable as either. Before its first use, and on request, the OS source
+// is checked for repeated or all-zero outputs. Until a check passes, the
+// generator fails rather than produce randomness from a source which failed:
+// try_fill_bytes returns an error and fill_bytes panics, which catch_ffi
+// reports as the last error of the stub. A failed check is run again on the
+// next use, so a source which recovers becomes usable again.
+
+struct RngState {
+    healthy: Option<bool>,
+    key: [u8; 32],
+    mix: Option<ChaCha20Rng>,
+}
+
+// The state stays consistent if a thread panics while holding the lock, so a
+// poisoned lock is taken over rather than making randomness unavailable.
+fn rng_state() -> MutexGuard<'static, RngState> {
+    lazy_static! {
+        static ref STATE: Mutex<RngState> = Mutex::new(RngState {
+            healthy: None,
+            key: [0u8; 32],
+            mix: None,
+        });
+    }
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn os_rng_health_check() -> bool {
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    if OsRng.try_fill_bytes(&mut a).is_err() || OsRng.try_fill_bytes(&mut b).is_err() {
+        return false;
+    }
+    a != b && a != [0u8; 32] && b != [0u8; 32]
+}
+
+#[derive(Clone, Copy)]
+pub struct SystemRng;
+
+pub fn system_rng() -> SystemRng {
+    SystemRng
+}
+
+impl RngCore for SystemRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.try_fill_bytes(dest) {
+            panic!("{}", e)
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        let mut state = rng_state();
+        if state.healthy != Some(true) {
+            let healthy = os_rng_health_check();
+            state.healthy = Some(healthy);
+            if !healthy {
+                return Err(rand_core::Error::new(
+                    "The OS randomness source failed its health check.",
+                ));
+            }
+        }
+        OsRng.try_fill_bytes(dest)?;
+        if let Some(mix) = state.mix.as_mut() {
+            let mut m = vec![0u8; dest.len()];
+            mix.fill_bytes(&mut m);
+            for (d, m) in dest.iter_mut().zip(m) {
+                *d ^= m;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CryptoRng for SystemRng {}
+
+// Mixes a seed into all later randomness. Seeds accumulate: the stream is
+// keyed by the hash of the previous key and the new seed.
+#[no_mangle]
+pub extern "C" fn zexe_rng_mix_seed(seed: *const u8, len: usize) {
+    catch_ffi("zexe_rng_mix_seed", (), || {
+        let seed = if len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(seed, len) }
+        };
+        let mut state = rng_state();
+        let mut h = urs_hasher();
+        h.input(&state.key[..]);
+        h.input(seed);
+        state.key = urs_hasher_result(h);
+        state.mix = Some(ChaCha20Rng::from_seed(state.key));
+    })
+}
+
+// Runs the health check of the OS source again, and returns whether it passed.
+// Randomness is unavailable until a check passes, which is also retried on the
+// next use.
+#[no_mangle]
+pub extern "C" fn zexe_rng_health_check() -> bool {
+    catch_ffi("zexe_rng_health_check", false, || {
+        let healthy = os_rng_health_check();
+        rng_state().healthy = Some(healthy);
+        healthy
+    })
+}
+
+// Forgets the seeds mixed in and the result of the last health check.
+pub(crate) fn reset_system_rng() {
+    let mut state = rng_state();
+    state.healthy = None;
+    state.key = [0u8; 32];
+    state.mix = None;
+}
+
+// Describes the source of randomness: "os", or "os+seed" once a seed was mixed
+// in, followed by " (unhealthy)" if the last health check failed.
+#[no_mangle]
+pub extern "C" fn zexe_rng_source() -> *mut c_char {
+    catch_ffi("zexe_rng_source", std::ptr::null_mut(), || {
+        let state = rng_state();
+        let mut res = if state.mix.is_some() { "os+seed" } else { "os" }.to_string();
+        if state.healthy == Some(false) {
+            res.push_str(" (unhealthy)");
+        }
+        into_c_string(res)
+    })
+}
+
+// Seeded randomness
+//
+// The stubs drawing from a seed of the caller, such as the _rng stubs, use
+// seeded_rng rather than the randomness above, which ignores seeds. It is a
+// ChaCha20 stream whose key is expanded from the seed, and unlike StdRng,
+// whose algorithm may change between versions of rand, gives the same values
+// for the same seed in every release.
+pub fn seeded_rng(seed: u64) -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(seed)
+}
+
+// Deterministic blinding
+//
+// Derives the seed of the prover's randomness from everything which determines
+// the proof, in the manner of RFC 6979: a digest of the index, a secret key
+// chosen by the caller and the inputs are absorbed into a Poseidon sponge, and
+// the seed is taken from its output. Identical inputs and key give identical
+// proofs, so a retried or duplicated job yields the same proof.
+//
+// The proof is only hiding, that is zero knowledge, as long as the key stays
+// secret: anyone who knows it and guesses the witness can recompute the
+// blinding and check the guess against the proof. The key must therefore be
+// drawn uniformly at random and have at least 128 bits, and keys shorter than
+// PROOF_KEY_MIN_BYTES are rejected. Since the witness is absorbed, different
+// statements never share blinding, so one key may serve many proofs.
+pub const PROOF_KEY_MIN_BYTES: usize = 16;
+
+pub fn derive_proof_seed<F: PrimeField>(
+    params: &ArithmeticSpongeParams<F>,
+    index_digest: &UrsDigest,
+    key: &[u8],
+    inputs: &[&[F]],
+) -> Result<[u8; 32], String> {
+    if key.len() < PROOF_KEY_MIN_BYTES {
+        return Err(format!(
+            "The key has {} bytes, but at least {} are needed.",
+            key.len(),
+            PROOF_KEY_MIN_BYTES
+        ));
+    }
+    let limbs = |bytes: &[u8]| -> Vec<F> {
+        bytes
+            .chunks(8)
+            .map(|c| {
+                let mut b = [0u8; 8];
+                b[..c.len()].copy_from_slice(c);
+                F::from(u64::from_le_bytes(b))
+            })
+            .collect()
+    };
+    let mut absorbed = limbs(index_digest);
+    absorbed.push(F::from(key.len() as u64));
+    absorbed.extend(limbs(key));
+    for v in inputs {
+        absorbed.push(F::from(v.len() as u64));
+        absorbed.extend_from_slice(v);
+    }
+
+    let mut sponge = ArithmeticSponge::<F, MarlinSpongeConstants>::new();
+    sponge.absorb(params, &absorbed);
+    let mut bytes = vec![];
+    sponge.squeeze(params).into_repr().write(&mut bytes).unwrap();
+
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&bytes[..32]);
+    Ok(seed)
+}
+
+// Sponge state
+//
+// A sponge is serialized as its mode, 0 for absorbing and 1 for squeezing,
+// as one byte, then the number of elements absorbed or squeezed in that mode
+// and its state vector, so that a partially absorbed transcript can be
+// checkpointed and resumed elsewhere. The parameters are not included: the
+// sponge must be resumed with the same ones.
+
+pub fn write_sponge<F: PrimeField, SC: SpongeConstants, W: Write>(
+    s: &ArithmeticSponge<F, SC>,
+    mut w: W,
+) -> IoResult<()> {
+    let (mode, n) = match s.sponge_state {
+        SpongeState::Absorbed(n) => (0u8, n),
+        SpongeState::Squeezed(n) => (1u8, n),
+    };
+    mode.write(&mut w)?;
+    (n as u64).write(&mut w)?;
+    write_vec(&s.state, &mut w)
+}
+
+pub fn read_sponge<F: PrimeField, SC: SpongeConstants, R: Read>(
+    mut r: R,
+) -> IoResult<ArithmeticSponge<F, SC>> {
+    let mut s = ArithmeticSponge::<F, SC>::new();
+    let mode = u8::read(&mut r)?;
+    let n = u64::read(&mut r)? as usize;
+    let state: Vec<F> = read_vec(&mut r)?;
+    if n > SC::SPONGE_WIDTH - SC::SPONGE_CAPACITY || state.len() != s.state.len() {
+        return Err(Error::new(ErrorKind::InvalidData, "read_sponge: invalid state"));
+    }
+    s.sponge_state = match mode {
+        0 => SpongeState::Absorbed(n),
+        1 => SpongeState::Squeezed(n),
+        _ => return Err(Error::new(ErrorKind::InvalidData, "read_sponge: invalid mode")),
+    };
+    s.state = state;
+    Ok(s)
+}
+
+// Handle registry
+//
+// Every handle a stub returns is created with into_handle and destroyed with
+// release_handle. Without the handle-registry feature these are plain
+// Box::into_raw and Box::from_raw. With it, each live handle is recorded
+// together with its type and a generation number, and the generation is also
+// kept in the top 16 bits of the handle itself, which no user space address
+// uses. Every stub passes the handles it is given through `handle`, which
+// strips the tag and checks that the handle is live, of the expected type and
+// of the generation its address currently holds, so that deleting a handle
+// twice, using a deleted handle, or using a handle whose address was reissued
+// to a later one is reported through the last error and printed instead of
+// corrupting the heap. Pointers without a tag, such as the ones returned by
+// the *_ref stubs, are borrowed and are not checked. Tags repeat every 65535
+// generations, which is how far apart a stale handle and the handle reissued
+// at its address may be before the reuse goes unnoticed.
+
+#[cfg(all(feature = "handle-registry", not(target_pointer_width = "64")))]
+compile_error!("the handle-registry feature needs 64-bit pointers");
+
+// A raw pointer to a handle, constant or mutable.
+pub trait HandlePtr: Copy {
+    type Target;
+    fn addr(self) -> usize;
+    fn with_addr(self, addr: usize) -> Self;
+}
+
+impl<T> HandlePtr for *const T {
+    type Target = T;
+    fn addr(self) -> usize {
+        self as usize
+    }
+    fn with_addr(self, addr: usize) -> Self {
+        addr as *const T
+    }
+}
+
+impl<T> HandlePtr for *mut T {
+    type Target = T;
+    fn addr(self) -> usize {
+        self as usize
+    }
+    fn with_addr(self, addr: usize) -> Self {
+        addr as *mut T
+    }
+}
+
+#[cfg(feature = "handle-registry")]
+mod registry {
+    use lazy_static::lazy_static;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    const TAG_SHIFT: u32 = 48;
+
+    pub struct Entry {
+        pub type_name: &'static str,
+        pub generation: u64,
+        pub live: bool,
+    }
+
+    pub struct Registry {
+        pub next_generation: u64,
+        // Deleted handles stay here, with live set to false, until
+        // reset_handle_registry or until their address is reissued.
+        pub entries: HashMap<usize, Entry>,
+    }
+
+    pub fn get() -> &'static Mutex<Registry> {
+        lazy_static! {
+            static ref REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+                next_generation: 0,
+                entries: HashMap::new(),
+            });
+        }
+        &REGISTRY
+    }
+
+    // The tag of a generation, never 0.
+    pub fn tag(generation: u64) -> usize {
+        (generation % 0xffff + 1) as usize
+    }
+
+    pub fn tagged(addr: usize, generation: u64) -> usize {
+        assert!(addr >> TAG_SHIFT == 0, "address {:#x} does not leave room for a tag", addr);
+        addr | tag(generation) << TAG_SHIFT
+    }
+
+    // Splits a handle into its address and its tag, 0 if it has none.
+    pub fn split(x: usize) -> (usize, usize) {
+        (x & ((1 << TAG_SHIFT) - 1), x >> TAG_SHIFT)
+    }
+
+    // Describes why a handle may not be used, or None if it is live. An
+    // untagged handle is looked up by its address only.
+    pub fn misuse(r: &Registry, x: usize, type_name: &str) -> Option<String> {
+        let (addr, t) = split(x);
+        if addr == 0 {
+            return Some("null handle".to_string());
+        }
+        match r.entries.get(&addr) {
+            None => Some(format!("unknown handle {:#x}", x)),
+            Some(e) if t != 0 && t != tag(e.generation) => Some(format!(
+                "handle {:#x} is stale, its address now holds a {} (generation {})",
+                x, e.type_name, e.generation
+            )),
+            Some(e) if !e.live => Some(format!(
+                "handle {:#x} ({} generation {}) was already deleted",
+                x, e.type_name, e.generation
+            )),
+            Some(e) if e.type_name != type_name => Some(format!(
+                "handle {:#x} is a {} (generation {}), not a {}",
+                x, e.type_name, e.generation, type_name
+            )),
+            Some(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "handle-registry")]
+fn report_misuse(name: &str, msg: String) {
+    let msg = format!("{}: {}", name, msg);
+    print_line(msg.clone());
+    set_last_error(msg);
+}
+
+#[cfg(not(feature = "handle-registry"))]
+pub fn into_handle<T>(x: Box<T>) -> *mut T {
+    Box::into_raw(x)
+}
+
+#[cfg(feature = "handle-registry")]
+pub fn into_handle<T>(x: Box<T>) -> *mut T {
+    let p = Box::into_raw(x);
+    // Boxes of zero-sized types all share one dangling address.
+    if std::mem::size_of::<T>() == 0 {
+        return p;
+    }
+    let mut r = registry::get().lock().unwrap();
+    let generation = r.next_generation;
+    r.next_generation += 1;
+    r.entries.insert(
+        p as usize,
+        registry::Entry {
+            type_name: std::any::type_name::<T>(),
+            generation,
+            live: true,
+        },
+    );
+    registry::tagged(p as usize, generation) as *mut T
+}
+
+// Checks a handle given to a stub and returns the pointer to dereference. A
+// handle which may not be used panics, which catch_ffi turns into the last
+// error. The identity without the handle-registry feature.
+#[cfg(not(feature = "handle-registry"))]
+pub fn handle<P: HandlePtr>(x: P) -> P {
+    x
+}
+
+#[cfg(feature = "handle-registry")]
+pub fn handle<P: HandlePtr>(x: P) -> P {
+    let (addr, tag) = registry::split(x.addr());
+    if tag == 0 || std::mem::size_of::<P::Target>() == 0 {
+        return x;
+    }
+    let r = registry::get().lock().unwrap();
+    if let Some(msg) = registry::misuse(&r, x.addr(), std::any::type_name::<P::Target>()) {
+        drop(r);
+        print_line(msg.clone());
+        panic!("{}", msg);
+    }
+    x.with_addr(addr)
+}
+
+// The address of a handle, tagged or not, to key the tables which refer to
+// handles.
+pub fn handle_address<P: HandlePtr>(x: P) -> usize {
+    #[cfg(feature = "handle-registry")]
+    {
+        registry::split(x.addr()).0
+    }
+    #[cfg(not(feature = "handle-registry"))]
+    {
+        x.addr()
+    }
+}
+
+// Takes back ownership of a handle, for stubs which consume their argument.
+// Returns None, with the last error set, if the handle may not be used.
+#[cfg(not(feature = "handle-registry"))]
+pub fn take_handle<T>(_name: &str, x: *mut T) -> Option<Box<T>> {
+    Some(unsafe { Box::from_raw(x) })
+}
+
+#[cfg(feature = "handle-registry")]
+pub fn take_handle<T>(name: &str, x: *mut T) -> Option<Box<T>> {
+    let addr = registry::split(x as usize).0;
+    if std::mem::size_of::<T>() != 0 {
+        let mut r = registry::get().lock().unwrap();
+        if let Some(msg) = registry::misuse(&r, x as usize, std::any::type_name::<T>()) {
+            drop(r);
+            report_misuse(name, msg);
+            return None;
+        }
+        // The memory is freed and its address may be reissued, so the entry
+        // stays behind with its generation, against which the tag of a stale
+        // handle is compared.
+        r.entries.get_mut(&addr).unwrap().live = false;
+    }
+    Some(unsafe { Box::from_raw(addr as *mut T) })
+}
+
+#[cfg(not(feature = "handle-registry"))]
+pub fn release_handle<T>(_name: &str, x: *mut T) {
+    let _box = unsafe { Box::from_raw(x) };
+}
+
+#[cfg(feature = "handle-registry")]
+pub fn release_handle<T>(name: &str, x: *mut T) {
+    let addr = registry::split(x as usize).0;
+    if std::mem::size_of::<T>() == 0 {
+        let _box = unsafe { Box::from_raw(addr as *mut T) };
+        return;
+    }
+    let mut r = registry::get().lock().unwrap();
+    if let Some(msg) = registry::misuse(&r, x as usize, std::any::type_name::<T>()) {
+        drop(r);
+        report_misuse(name, msg);
+        return;
+    }
+    // As in take_handle, the entry outlives the memory, which is freed.
+    r.entries.get_mut(&addr).unwrap().live = false;
+    drop(r);
+    let _box = unsafe { Box::from_raw(addr as *mut T) };
+}
+
+// Forgets the handles which were deleted, and returns the number of live ones.
+// Always 0 without the handle-registry feature.
+#[cfg(not(feature = "handle-registry"))]
+pub(crate) fn reset_handle_registry() -> usize {
+    0
+}
+
+#[cfg(feature = "handle-registry")]
+pub(crate) fn reset_handle_registry() -> usize {
+    let mut r = registry::get().lock().unwrap();
+    r.entries.retain(|_, e| e.live);
+    r.entries.len()
+}
+
+// Checks that a handle is live before a stub uses it, for the stubs which
+// report a bad handle through their result rather than through catch_ffi.
+// Always true without the handle-registry feature.
+#[cfg(not(feature = "handle-registry"))]
+pub fn check_handle<T>(_name: &str, _x: *const T) -> bool {
+    true
+}
+
+#[cfg(feature = "handle-registry")]
+pub fn check_handle<T>(name: &str, x: *const T) -> bool {
+    if std::mem::size_of::<T>() == 0 {
+        return true;
+    }
+    let r = registry::get().lock().unwrap();
+    match registry::misuse(&r, x as usize, std::any::type_name::<T>()) {
+        None => true,
+        Some(msg) => {
+            drop(r);
+            report_misuse(name, msg);
+            false
+        }
+    }
+}
+
+// Number of live handles, or 0 without the handle-registry feature. Useful to
+// find leaks in bindings.
+#[no_mangle]
+pub extern "C" fn zexe_live_handles() -> usize {
+    catch_ffi("zexe_live_handles", 0, || {
+        #[cfg(feature = "handle-registry")]
+        {
+            registry::get().lock().unwrap().entries.values().filter(|e| e.live).count()
+        }
+        #[cfg(not(feature = "handle-registry"))]
+        {
+            0
+        }
+    })
+}
+
+// Verification failure reasons
+//
+// The *_proof_verify_diagnostic stubs return one of these instead of a bool.
+// They run the ordinary verifier first, so a valid proof is always reported
+// as VERIFY_OK, and only on failure re-run the checks one at a time to find
+// the first that does not hold.
+
+pub const VERIFY_OK: i32 = 0;
+pub const VERIFY_FAILED_PUBLIC_INPUT: i32 = 1;
+pub const VERIFY_FAILED_SUMCHECK_1: i32 = 2;
+pub const VERIFY_FAILED_SUMCHECK_2: i32 = 3;
+pub const VERIFY_FAILED_SUMCHECK_3: i32 = 4;
+pub const VERIFY_FAILED_DEGREE_BOUND: i32 = 5;
+pub const VERIFY_FAILED_OPENING: i32 = 6;
+pub const VERIFY_FAILED_MALFORMED: i32 = 7;
+
+// The descriptions are NUL-terminated so that they can be handed to C as is.
+fn verify_failure_description_c(code: i32) -> &'static str {
+    match code {
+        VERIFY_OK => "The proof is valid\0",
+        VERIFY_FAILED_PUBLIC_INPUT => "The public input does not match the verifier index\0",
+        VERIFY_FAILED_SUMCHECK_1 => "The first sumcheck identity does not hold\0",
+        VERIFY_FAILED_SUMCHECK_2 => "The second sumcheck identity does not hold\0",
+        VERIFY_FAILED_SUMCHECK_3 => "The third sumcheck identity does not hold\0",
+        VERIFY_FAILED_DEGREE_BOUND => "A degree bound check does not hold\0",
+        VERIFY_FAILED_OPENING => "The opening proof does not verify\0",
+        VERIFY_FAILED_MALFORMED => "The proof is malformed\0",
+        _ => "Unknown verification status\0",
+    }
+}
+
+pub fn verify_failure_description(code: i32) -> &'static str {
+    verify_failure_description_c(code).trim_end_matches('\0')
+}
+
+// Returns a static string, which must not be freed.
+#[no_mangle]
+pub extern "C" fn zexe_verify_failure_description(code: i32) -> *const c_char {
+    catch_ffi("zexe_verify_failure_description", std::ptr::null(), || {
+        verify_failure_description_c(code).as_ptr() as *const c_char
+    })
+}
+
+// Records the reason for a failed verification as the last error and returns
+// its code.
+pub fn verify_failure(name: &str, code: i32) -> i32 {
+    set_last_error(format!("{}: {}", name, verify_failure_description(code)));
+    code
+}
+
+// Thread pool
+//
+// The expensive stubs (URS and index creation, proving and verifying) run in
+// a crate-owned rayon pool when one has been configured with
+// zexe_bn382_set_num_threads, so that their parallelism can be bounded
+// independently of rayon's global pool. Without one they run in the global
+// pool, and stubs called from within a rayon pool, such as that of a cycle
+// context, stay in it.
+
+// The stubs' raw pointer arguments are not Send, but a pool only runs the
+// closure while the calling thread blocks on it, so they can be moved across.
+pub(crate) struct AssertSend<T>(pub T);
+unsafe impl<T> Send for AssertSend<T> {}
+
+fn pool() -> &'static RwLock<Option<Arc<ThreadPool>>> {
+    lazy_static! {
+        static ref POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+    }
+    &POOL
+}
+
+pub fn in_pool<T, F: FnOnce() -> T>(f: F) -> T {
+    if rayon::current_thread_index().is_some() {
+        return f();
+    }
+    let pool = pool().read().unwrap().clone();
+    match pool {
+        Some(pool) => {
+            let f = AssertSend(f);
+            pool.install(move || {
+                let AssertSend(f) = f;
+                AssertSend(f())
+            })
+            .0
+        }
+        None => f(),
+    }
+}
+
+// Replaces the crate's pool with one of n threads, or goes back to rayon's
+// global pool if n is 0. Calls already running keep their pool.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_set_num_threads(n: usize) -> bool {
+    catch_ffi("zexe_bn382_set_num_threads", false, || {
+        let new_pool = if n == 0 {
+            None
+        } else {
+            match ThreadPoolBuilder::new().num_threads(n).build() {
+                Ok(p) => Some(Arc::new(p)),
+                Err(e) => {
+                    set_last_error(format!(
+                        "zexe_bn382_set_num_threads: Could not build thread pool: {}",
+                        e
+                    ));
+                    return false;
+                }
+            }
+        };
+        *pool().write().unwrap() = new_pool;
+        true
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_get_num_threads() -> usize {
+    catch_ffi("zexe_bn382_get_num_threads", 0, || {
+        match &*pool().read().unwrap() {
+            Some(p) => p.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    })
+}
+
+// Printing
+//
+// Everything the stubs print goes through print_line. By default lines go to
+// stdout; an embedder whose stdout is not available can install a callback
+// with zexe_set_print_callback, which then receives each line (without the
+// trailing newline). The string passed to the callback is only valid for the
+// duration of the call.
+
+pub type PrintCallback = extern "C" fn(*const c_char);
+
+// Holds the current callback as a function pointer, or 0 for stdout.
+static PRINT_CALLBACK: AtomicUsize = AtomicUsize::new(0);
+
+pub fn print_line(line: String) {
+    match PRINT_CALLBACK.load(Ordering::SeqCst) {
+        0 => println!("{}", line),
+        f => {
+            let f: PrintCallback = unsafe { std::mem::transmute(f) };
+            let line = CString::new(line.replace('\0', "")).unwrap();
+            f(line.as_ptr())
+        }
+    }
+}
+
+// Passing null restores printing to stdout. The argument spells out the type
+// of PrintCallback, which cbindgen only declares as a nullable function
+// pointer when it sees the function type inside the Option.
+#[no_mangle]
+pub extern "C" fn zexe_set_print_callback(f: Option<extern "C" fn(*const c_char)>) {
+    catch_ffi("zexe_set_print_callback", (), || {
+        PRINT_CALLBACK.store(f.map_or(0, |f| f as usize), Ordering::SeqCst);
+    })
+}
+
 // TODO: Not compatible with variable rounds
 pub fn batch_dlog_accumulator_check<G: CommitmentCurve>(
     urs: &SRS<G>,
@@ -59,7 +949,7 @@ pub fn batch_dlog_accumulator_check<G: CommitmentCurve>(
     assert_eq!(chals.len() % rounds, 0);
 
     let rs = {
-        let r = G::ScalarField::rand(&mut rand_core::OsRng);
+        let r = G::ScalarField::rand(&mut system_rng());
         let mut rs = vec![G::ScalarField::one(); k];
         for i in 1..k {
             rs[i] = r * &rs[i - 1];
@@ -101,7 +991,40 @@ pub fn batch_dlog_accumulator_check<G: CommitmentCurve>(
     }
 
     let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
-    VariableBaseMSM::multi_scalar_mul(&points, &scalars) == G::Projective::zero()
+    VariableBaseMSM::multi_scalar_mul_parallel(&points, &scalars) == G::Projective::zero()
+}
+
+// Multiplies a single base by many scalars, sharing one window table for the
+// base across all of them.
+pub fn scale_many<G: ProjectiveCurve>(base: G, scalars: &[G::ScalarField]) -> Vec<G> {
+    let scalar_size = G::ScalarField::size_in_bits();
+    let window = FixedBaseMSM::get_mul_window_size(scalars.len());
+    let table = FixedBaseMSM::get_window_table(scalar_size, window, base);
+    FixedBaseMSM::multi_scalar_mul(scalar_size, window, &table, scalars)
+}
+
+// The size of the digits of the wNAF tables the verifier keeps for the bases
+// it multiplies again and again. Each table has 16 points per 5 bits of the
+// scalar, about 130kB for BN-382.
+pub const WNAF_WINDOW: usize = 5;
+
+pub fn wnaf_tables<G: AffineCurve>(bases: &[G]) -> Vec<FixedBaseWnafTable<G>> {
+    bases
+        .par_iter()
+        .map(|b| FixedBaseWnafTable::new(*b, WNAF_WINDOW))
+        .collect()
+}
+
+// The linear combination of the bases with the scalars, with their tables.
+pub fn wnaf_linear_combination<G: AffineCurve>(
+    tables: &[FixedBaseWnafTable<G>],
+    scalars: &[G::ScalarField],
+) -> G::Projective {
+    let mut res = G::Projective::zero();
+    for (t, s) in tables.iter().zip(scalars.iter()) {
+        res += &t.mul(s);
+    }
+    res
 }
 
 pub fn evals_from_coeffs<F: FftField>(
@@ -111,12 +1034,416 @@ pub fn evals_from_coeffs<F: FftField>(
     Evaluations::<F>::from_vec_and_domain(v, GeneralEvaluationDomain::Radix2(d))
 }
 
-pub fn ceil_pow2(x: usize) -> usize {
-    let mut res = 1;
-    while x > res {
-        res *= 2;
+// The smallest power of two which is at least x, or an error if it does not
+// fit a usize.
+pub fn ceil_pow2(x: usize) -> Result<usize, String> {
+    x.checked_next_power_of_two()
+        .ok_or_else(|| format!("{} has no power of two above it that fits a usize.", x))
+}
+
+// The base 2 logarithm of ceil_pow2(x), which always fits.
+pub fn ceil_log2(x: usize) -> usize {
+    if x <= 1 {
+        0
+    } else {
+        (8 * std::mem::size_of::<usize>()) - (x - 1).leading_zeros() as usize
     }
-    res
+}
+
+// The number of rounds of the inner product argument for an SRS, which is also
+// the number of challenges each previous accumulator contributes to a proof.
+pub fn lr_rounds<G: CommitmentCurve>(srs: &SRS<G>) -> usize {
+    ceil_log2(srs.g.len())
+}
+
+// Circuit size limits
+//
+// The numbers of constraints, variables, public inputs and non-zero matrix
+// entries of a circuit are each rounded up to a power of two to size its
+// domains, and the provers work over small multiples of those, so the sizes
+// are bounded well below what overflows a usize, on 32-bit targets too.
+// Index creation checks them with checked arithmetic and fails with the last
+// error set, rather than computing a wrapped size.
+
+pub const MAX_DOMAIN_SIZE_LOG2: usize = 28;
+
+pub const MAX_CIRCUIT_SIZE: usize = 1 << MAX_DOMAIN_SIZE_LOG2;
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_max_domain_size_log2() -> usize {
+    catch_ffi("zexe_bn382_max_domain_size_log2", 0, || {
+        MAX_DOMAIN_SIZE_LOG2
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_max_circuit_size() -> usize {
+    catch_ffi("zexe_bn382_max_circuit_size", 0, || {
+        MAX_CIRCUIT_SIZE
+    })
+}
+
+// The size of the domain of n elements, where what names them, or an error if
+// n exceeds MAX_CIRCUIT_SIZE.
+pub fn domain_size(what: &str, n: usize) -> Result<usize, String> {
+    if n > MAX_CIRCUIT_SIZE {
+        return Err(format!(
+            "{} {} exceed the maximum supported circuit size of {}.",
+            n, what, MAX_CIRCUIT_SIZE
+        ));
+    }
+    ceil_pow2(n)
+}
+
+// The size of the h domain of a circuit and its ratio to the size of the x
+// domain, which must not be the larger of the two.
+pub fn h_domain_sizes(
+    constraints: usize,
+    vars: usize,
+    public_inputs: usize,
+) -> Result<(usize, usize), String> {
+    if public_inputs == 0 {
+        return Err("A circuit needs at least one public input.".to_string());
+    }
+    let h_group_size = domain_size("constraints or variables", std::cmp::max(constraints, vars))?;
+    let x_group_size = domain_size("public inputs", public_inputs)?;
+    if x_group_size > h_group_size {
+        return Err(format!(
+            "The {} public inputs do not fit the h domain of size {}.",
+            public_inputs, h_group_size
+        ));
+    }
+    Ok((h_group_size, h_group_size / x_group_size))
+}
+
+// Splits the flattened challenges of the previous accumulators into one chunk
+// of `rounds` challenges per accumulator.
+pub fn prev_challenges_of_flat<G: CommitmentCurve>(
+    prev_challenges: &[G::ScalarField],
+    prev_sgs: &[G],
+    rounds: usize,
+) -> Result<Vec<(Vec<G::ScalarField>, PolyComm<G>)>, String> {
+    if prev_challenges.len() != prev_sgs.len() * rounds {
+        return Err(format!(
+            "Expected {} challenges for {} previous accumulators of {} rounds, got {}.",
+            prev_sgs.len() * rounds,
+            prev_sgs.len(),
+            rounds,
+            prev_challenges.len()
+        ));
+    }
+    Ok(prev_sgs
+        .iter()
+        .zip(prev_challenges.chunks(rounds.max(1)))
+        .map(|(sg, chals)| {
+            (
+                chals.to_vec(),
+                PolyComm::<G> {
+                    unshifted: vec![*sg],
+                    shifted: None,
+                },
+            )
+        })
+        .collect())
+}
+
+// Commitments to all the Lagrange basis polynomials of the domain, given the
+// commitments g to the monomials. The commitment to L_i is
+// (1/n) sum_j omega^{-ij} g_j, so together they are the inverse FFT of g over
+// the group, which costs O(n log n) group operations rather than an
+// interpolation and a commitment per basis polynomial.
+pub fn lagrange_commitments<G: AffineCurve>(g: &[G], domain: Domain<G::ScalarField>) -> Vec<G> {
+    let n = domain.size();
+    assert!(g.len() >= n, "The URS has {} points, the domain {}.", g.len(), n);
+    let log_n = n.trailing_zeros();
+    let bits = usize::MAX.count_ones();
+
+    // Bit-reversal permutation, then the butterflies of each layer.
+    let mut a: Vec<G::Projective> = g[..n].iter().map(|x| x.into_projective()).collect();
+    for k in 1..n {
+        let rk = k.reverse_bits() >> (bits - log_n);
+        if k < rk {
+            a.swap(k, rk);
+        }
+    }
+
+    let mut m = 1;
+    while m < n {
+        let w_m = domain.group_gen_inv.pow(&[(n / (2 * m)) as u64]);
+        let ws: Vec<G::ScalarField> = (0..m)
+            .scan(G::ScalarField::one(), |w, _| {
+                let res = *w;
+                *w *= &w_m;
+                Some(res)
+            })
+            .collect();
+        a.par_chunks_mut(2 * m).for_each(|block| {
+            let (lo, hi) = block.split_at_mut(m);
+            for ((x, y), w) in lo.iter_mut().zip(hi.iter_mut()).zip(ws.iter()) {
+                let t = y.mul(*w);
+                *y = *x - &t;
+                *x += &t;
+            }
+        });
+        m *= 2;
+    }
+
+    a.par_iter_mut().for_each(|x| *x = x.mul(domain.size_inv));
+    G::Projective::batch_normalization_into_affine(&a)
+}
+
+// Commitments to the polynomials with the given evaluations over a domain,
+// given the Lagrange commitments of the domain. Each commitment is a
+// multi-scalar multiplication against the same bases, with no interpolation,
+// and the commitments are computed in parallel, using the GLV endomorphism of
+// the bn_382 curves. Missing evaluations are zero.
+pub fn commit_evaluations_batch<P: GLVParameters>(
+    lagrange: &[GroupAffine<P>],
+    evals: &[&Vec<P::ScalarField>],
+) -> Result<Vec<GroupAffine<P>>, String>
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    if let Some(i) = evals.iter().position(|e| e.len() > lagrange.len()) {
+        return Err(format!(
+            "Evaluation vector {} has {} entries, the domain {}.",
+            i,
+            evals[i].len(),
+            lagrange.len()
+        ));
+    }
+    let comms: Vec<GroupProjective<P>> = evals
+        .par_iter()
+        .map(|e| {
+            let scalars: Vec<_> = e.iter().map(|x| x.into_repr()).collect();
+            glv_multi_scalar_mul(&lagrange[..e.len()], &scalars)
+        })
+        .collect();
+    Ok(GroupProjective::batch_normalization_into_affine(&comms))
+}
+
+// The sum of the points, accumulated with mixed additions across the thread
+// pool.
+pub fn affine_sum<G: AffineCurve>(points: &[G]) -> G::Projective {
+    points
+        .par_iter()
+        .fold(G::Projective::zero, |mut acc, p| {
+            acc.add_assign_mixed(p);
+            acc
+        })
+        .reduce(G::Projective::zero, |a, b| a + &b)
+}
+
+// The linear combination of the points with the scalars, as one multi-scalar
+// multiplication using the GLV endomorphism of the bn_382 curves.
+pub fn affine_linear_combination<P: GLVParameters>(
+    points: &[GroupAffine<P>],
+    scalars: &[P::ScalarField],
+) -> Result<GroupProjective<P>, String>
+where
+    P::ScalarField: PrimeField<BigInt = BigInteger384>,
+{
+    if points.len() != scalars.len() {
+        return Err(format!("{} points for {} scalars.", points.len(), scalars.len()));
+    }
+    let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
+    Ok(glv_multi_scalar_mul(points, &scalars))
+}
+
+// Fixed commitments
+//
+// Commitments to the polynomials which protocols built on top of the proof
+// systems commit to again and again: the vanishing polynomials Z_H and Z_K of
+// two domains, and the monomials x^i for i < FIXED_MONOMIALS, of which x^0 is
+// the constant one. They are computed once, in parallel, and kept in a handle.
+
+pub const FIXED_MONOMIALS: usize = 16;
+
+pub struct FixedCommitments<C> {
+    pub z_h: C,
+    pub z_k: C,
+    pub monomials: Vec<C>,
+}
+
+impl<C: Send> FixedCommitments<C> {
+    pub fn create<F: FftField, E: Fn(&DensePolynomial<F>) -> Result<C, String> + Sync>(
+        h: Domain<F>,
+        k: Domain<F>,
+        commit: E,
+    ) -> Result<Self, String> {
+        let mut polys: Vec<DensePolynomial<F>> =
+            vec![h.vanishing_polynomial().into(), k.vanishing_polynomial().into()];
+        for i in 0..FIXED_MONOMIALS {
+            let mut coeffs = vec![F::zero(); i + 1];
+            coeffs[i] = F::one();
+            polys.push(DensePolynomial::from_coefficients_vec(coeffs));
+        }
+        let mut comms = polys
+            .par_iter()
+            .map(&commit)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        Ok(FixedCommitments {
+            z_h: comms.next().unwrap(),
+            z_k: comms.next().unwrap(),
+            monomials: comms.collect(),
+        })
+    }
+
+    pub fn monomial(&self, i: usize) -> Result<&C, String> {
+        self.monomials
+            .get(i)
+            .ok_or_else(|| format!("x^{} is not among the {} fixed monomials.", i, FIXED_MONOMIALS))
+    }
+}
+
+// The inverse of prev_challenges_of_flat: the challenges of all the previous
+// accumulators, one after the other, and their sg points.
+pub fn prev_challenges_to_flat<G: CommitmentCurve>(
+    prev_challenges: &[(Vec<G::ScalarField>, PolyComm<G>)],
+) -> (Vec<G::ScalarField>, Vec<G>) {
+    let chals = prev_challenges
+        .iter()
+        .flat_map(|(c, _)| c.iter().cloned())
+        .collect();
+    let sgs = prev_challenges
+        .iter()
+        .map(|(_, sg)| sg.unshifted[0])
+        .collect();
+    (chals, sgs)
+}
+
+// Public input commitment cache
+//
+// Verifiers often see the same public input many times, and interpolating and
+// committing to it is an MSM per verification. An XHatCache remembers the
+// interpolated polynomial and its commitment, keyed by a digest of the SRS,
+// the domain and the public input, evicting the oldest entries beyond its
+// capacity. A capacity of 0 disables the cache.
+
+pub type XHatCacheKey = UrsDigest;
+
+pub fn x_hat_cache_key<G: AffineCurve>(
+    h: &G,
+    domain_size: u64,
+    public: &[G::ScalarField],
+) -> XHatCacheKey {
+    let mut w = DigestWriter::new(std::io::sink());
+    h.write(&mut w).unwrap();
+    domain_size.write(&mut w).unwrap();
+    for x in public {
+        x.write(&mut w).unwrap();
+    }
+    w.finish().unwrap()
+}
+
+pub struct XHatCache<P, C> {
+    entries: HashMap<XHatCacheKey, (P, C)>,
+    order: VecDeque<XHatCacheKey>,
+}
+
+impl<P: Clone, C: Clone> Default for XHatCache<P, C> {
+    fn default() -> Self {
+        XHatCache {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<P: Clone, C: Clone> XHatCache<P, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &XHatCacheKey) -> Option<(P, C)> {
+        self.entries.get(key).cloned()
+    }
+
+    // Lookups and insertions are separate so that callers can compute the
+    // value without holding a lock on a shared cache.
+    pub fn insert(&mut self, capacity: usize, key: XHatCacheKey, v: (P, C)) {
+        if capacity == 0 || self.entries.contains_key(&key) {
+            return;
+        }
+        while self.order.len() >= capacity {
+            let oldest = self.order.pop_front().unwrap();
+            self.entries.remove(&oldest);
+        }
+        self.order.push_back(key);
+        self.entries.insert(key, v);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+// Chunks must fit in `G` below its modulus, so at most `G::size_in_bits() - 1`
+// bits, and must not be empty.
+fn check_chunk_bits<G: PrimeField>(chunk_bits: usize) -> Result<(), String> {
+    if chunk_bits == 0 || chunk_bits >= G::size_in_bits() {
+        return Err(format!(
+            "Chunk size {} is not between 1 and {} bits",
+            chunk_bits,
+            G::size_in_bits() - 1
+        ));
+    }
+    Ok(())
+}
+
+// Splits the canonical representation of `x` into little-endian chunks of
+// `chunk_bits` bits, each embedded into the field `G`. This is how the
+// circuits pack an element of one field into the other.
+pub fn field_to_chunks<F: PrimeField, G: PrimeField>(
+    x: &F,
+    chunk_bits: usize,
+) -> Result<Vec<G>, String> {
+    check_chunk_bits::<G>(chunk_bits)?;
+    let x = x.into_repr();
+    let num_chunks = (F::size_in_bits() + chunk_bits - 1) / chunk_bits;
+    Ok((0..num_chunks)
+        .map(|i| {
+            let mut bits: Vec<bool> = (0..chunk_bits)
+                .map(|j| {
+                    let k = i * chunk_bits + j;
+                    k < F::size_in_bits() && x.get_bit(k)
+                })
+                .collect();
+            bits.reverse();
+            G::from_repr(G::BigInt::from_bits(&bits))
+        })
+        .collect())
+}
+
+// Reassembles an element of `F` from little-endian chunks produced by
+// `field_to_chunks`. Fails if some chunk does not fit in `chunk_bits` bits or
+// if the reassembled value is not less than the modulus of `F`.
+pub fn field_of_chunks<F: PrimeField, G: PrimeField>(
+    v: &[G],
+    chunk_bits: usize,
+) -> Result<F, String> {
+    check_chunk_bits::<G>(chunk_bits)?;
+    let mut bits = vec![];
+    for (i, c) in v.iter().enumerate() {
+        let c = c.into_repr();
+        if (chunk_bits..G::size_in_bits()).any(|j| c.get_bit(j)) {
+            return Err(format!("Chunk {} does not fit in {} bits", i, chunk_bits));
+        }
+        bits.extend((0..chunk_bits).map(|j| c.get_bit(j)));
+    }
+    let num_bits = F::size_in_bits();
+    if bits.iter().skip(num_bits).any(|b| *b) {
+        return Err("The chunks do not fit in the field".to_string());
+    }
+    bits.truncate(num_bits);
+    bits.reverse();
+    let repr = F::BigInt::from_bits(&bits);
+    if repr >= F::Params::MODULUS {
+        return Err("The chunks are not a canonical field element".to_string());
+    }
+    Ok(F::from_repr(repr))
 }
 
 pub fn write_vec<A: ToBytes, W: Write>(v: &Vec<A>, mut writer: W) -> IoResult<()> {
@@ -133,106 +1460,637 @@ pub fn read_vec<A: FromBytes, R: Read>(mut reader: R) -> IoResult<Vec<A>> {
     for _ in 0..n {
         v.push(A::read(&mut reader)?);
     }
-    Ok(v)
+    Ok(v)
+}
+
+// Points decoded per round by read_points_par, which bounds the memory used
+// for a count read from untrusted input.
+const READ_POINTS_CHUNK: usize = 1 << 16;
+
+// Reads n points written one after another, as write_vec writes them after
+// the length. Each chunk is read sequentially and then decoded and checked
+// with valid across the thread pool, which dominates the cost of loading
+// large parameters.
+pub fn read_points_par<G: AffineCurve, R: Read, V: Fn(&G) -> bool + Sync>(
+    mut r: R,
+    n: usize,
+    valid: V,
+) -> IoResult<Vec<G>> {
+    let mut size = vec![];
+    G::zero().write(&mut size)?;
+    let size = size.len();
+
+    let mut res = Vec::with_capacity(std::cmp::min(n, READ_POINTS_CHUNK));
+    let mut buf = vec![];
+    while res.len() < n {
+        let m = std::cmp::min(n - res.len(), READ_POINTS_CHUNK);
+        buf.resize(m * size, 0);
+        r.read_exact(&mut buf)?;
+        let points = buf
+            .par_chunks(size)
+            .map(|mut b| {
+                let p = G::read(&mut b)?;
+                if valid(&p) {
+                    Ok(p)
+                } else {
+                    Err(Error::new(ErrorKind::InvalidData, "invalid curve point"))
+                }
+            })
+            .collect::<IoResult<Vec<G>>>()?;
+        res.extend(points);
+    }
+    Ok(res)
+}
+
+pub fn write_cs_mat<A: ToBytes + Clone, W: Write>(m: &CsMat<A>, mut w: W) -> IoResult<()> {
+    fn v(s: &[usize]) -> Vec<u64> {
+        s.iter().map(|x| *x as u64).collect()
+    }
+
+    let (a, b) = m.shape();
+    u64::write(&(a as u64), &mut w)?;
+    u64::write(&(b as u64), &mut w)?;
+
+    write_vec::<u64, _>(&v(m.indptr()), &mut w)?;
+    write_vec(&v(m.indices()), &mut w)?;
+    write_vec(&m.data().to_vec(), &mut w)?;
+    Ok(())
+}
+
+pub fn read_cs_mat<A: FromBytes + Copy, R: Read>(mut r: R) -> IoResult<CsMat<A>> {
+    fn v(s: Vec<u64>) -> Vec<usize> {
+        s.iter().map(|x| *x as usize).collect()
+    }
+
+    let a = u64::read(&mut r)? as usize;
+    let b = u64::read(&mut r)? as usize;
+    let shape = (a, b);
+
+    let indptr = v(read_vec(&mut r)?);
+    let indices = v(read_vec(&mut r)?);
+    let data: Vec<A> = read_vec(&mut r)?;
+    Ok(CsMat::new(shape, indptr, indices, data))
+}
+
+// A compiled constraint matrix in CSR form: the entries of row i are at
+// positions indptr[i]..indptr[i + 1] of indices and values, sorted by column,
+// so that the export of a given index is always the same. The columns are
+// witness positions, not variable indices.
+pub struct Csr<F> {
+    pub rows: usize,
+    pub cols: usize,
+    pub indptr: Vec<usize>,
+    pub indices: Vec<usize>,
+    pub values: Vec<F>,
+}
+
+pub fn csr_of_csmat<F: Copy>(m: &CsMat<F>) -> Csr<F> {
+    let (rows, cols) = m.shape();
+    let mut indptr = vec![0];
+    let mut indices = Vec::with_capacity(m.nnz());
+    let mut values = Vec::with_capacity(m.nnz());
+    for row in m.outer_iterator() {
+        let mut entries: Vec<(usize, F)> = row.iter().map(|(j, &x)| (j, x)).collect();
+        entries.sort_by_key(|&(j, _)| j);
+        indices.extend(entries.iter().map(|&(j, _)| j));
+        values.extend(entries.iter().map(|&(_, x)| x));
+        indptr.push(indices.len());
+    }
+    Csr {
+        rows,
+        cols,
+        indptr,
+        indices,
+        values,
+    }
+}
+
+pub fn write_matrix_values<A: ToBytes, W: Write>(m: &MatrixValues<A>, mut w: W) -> IoResult<()> {
+    A::write(&m.row, &mut w)?;
+    A::write(&m.col, &mut w)?;
+    A::write(&m.val, &mut w)?;
+    A::write(&m.rc, &mut w)?;
+    Ok(())
+}
+
+pub fn read_matrix_values<A: FromBytes, R: Read>(mut r: R) -> IoResult<MatrixValues<A>> {
+    let row = A::read(&mut r)?;
+    let col = A::read(&mut r)?;
+    let val = A::read(&mut r)?;
+    let rc = A::read(&mut r)?;
+    Ok(MatrixValues { row, col, val, rc })
+}
+
+pub fn write_option<A: ToBytes, W: Write>(a: &Option<A>, mut w: W) -> IoResult<()> {
+    match a {
+        None => u8::write(&0, &mut w),
+        Some(a) => {
+            u8::write(&1, &mut w)?;
+            A::write(a, &mut w)
+        }
+    }
+}
+
+pub fn read_option<A: FromBytes, R: Read>(mut r: R) -> IoResult<Option<A>> {
+    match u8::read(&mut r)? {
+        0 => Ok(None),
+        1 => Ok(Some(A::read(&mut r)?)),
+        _ => Err(Error::new(ErrorKind::InvalidData, "read_option: expected 0 or 1")),
+    }
+}
+
+pub fn write_poly_comm<A: ToBytes + AffineCurve, W: Write>(
+    p: &PolyComm<A>,
+    mut w: W,
+) -> IoResult<()> {
+    write_vec(&p.unshifted, &mut w)?;
+    write_option(&p.shifted, &mut w)
+}
+
+pub fn read_poly_comm<A: FromBytes + AffineCurve, R: Read>(mut r: R) -> IoResult<PolyComm<A>> {
+    let unshifted = read_vec(&mut r)?;
+    let shifted = read_option(&mut r)?;
+    Ok(PolyComm { unshifted, shifted })
+}
+
+pub fn write_dlog_matrix_values<A: ToBytes + AffineCurve, W: Write>(
+    m: &marlin_protocol_dlog::index::MatrixValues<A>,
+    mut w: W,
+) -> IoResult<()> {
+    write_poly_comm(&m.row, &mut w)?;
+    write_poly_comm(&m.col, &mut w)?;
+    write_poly_comm(&m.val, &mut w)?;
+    write_poly_comm(&m.rc, &mut w)?;
+    Ok(())
+}
+
+pub fn read_dlog_matrix_values<A: FromBytes + AffineCurve, R: Read>(
+    mut r: R,
+) -> IoResult<marlin_protocol_dlog::index::MatrixValues<A>> {
+    let row = read_poly_comm(&mut r)?;
+    let col = read_poly_comm(&mut r)?;
+    let val = read_poly_comm(&mut r)?;
+    let rc = read_poly_comm(&mut r)?;
+    Ok(marlin_protocol_dlog::index::MatrixValues { row, col, val, rc })
+}
+
+fn write_triple<A: ToBytes, W: Write>(t: &[Vec<A>; 3], mut w: W) -> IoResult<()> {
+    for v in t.iter() {
+        write_vec(v, &mut w)?;
+    }
+    Ok(())
+}
+
+fn read_triple<A: FromBytes, R: Read>(mut r: R) -> IoResult<[Vec<A>; 3]> {
+    Ok([read_vec(&mut r)?, read_vec(&mut r)?, read_vec(&mut r)?])
+}
+
+pub fn write_dlog_proof_evaluations<A: ToBytes, W: Write>(
+    e: &DlogProofEvaluations<A>,
+    mut w: W,
+) -> IoResult<()> {
+    for v in [&e.w, &e.za, &e.zb, &e.h1, &e.g1, &e.h2, &e.g2, &e.h3, &e.g3].iter() {
+        write_vec(v, &mut w)?;
+    }
+    for t in [&e.row, &e.col, &e.val, &e.rc].iter() {
+        write_triple(t, &mut w)?;
+    }
+    Ok(())
+}
+
+pub fn read_dlog_proof_evaluations<A: FromBytes, R: Read>(
+    mut r: R,
+) -> IoResult<DlogProofEvaluations<A>> {
+    Ok(DlogProofEvaluations {
+        w: read_vec(&mut r)?,
+        za: read_vec(&mut r)?,
+        zb: read_vec(&mut r)?,
+        h1: read_vec(&mut r)?,
+        g1: read_vec(&mut r)?,
+        h2: read_vec(&mut r)?,
+        g2: read_vec(&mut r)?,
+        h3: read_vec(&mut r)?,
+        g3: read_vec(&mut r)?,
+        row: read_triple(&mut r)?,
+        col: read_triple(&mut r)?,
+        val: read_triple(&mut r)?,
+        rc: read_triple(&mut r)?,
+    })
+}
+
+// Serializes a dlog proof without its public input, which verifiers supply
+// themselves.
+pub fn write_dlog_proof<G: CommitmentCurve, W: Write>(p: &DlogProof<G>, mut w: W) -> IoResult<()> {
+    u64::write(&(p.prev_challenges.len() as u64), &mut w)?;
+    for (chals, comm) in p.prev_challenges.iter() {
+        write_vec(chals, &mut w)?;
+        write_poly_comm(comm, &mut w)?;
+    }
+
+    u64::write(&(p.proof.lr.len() as u64), &mut w)?;
+    for (l, r) in p.proof.lr.iter() {
+        l.write(&mut w)?;
+        r.write(&mut w)?;
+    }
+    p.proof.z1.write(&mut w)?;
+    p.proof.z2.write(&mut w)?;
+    p.proof.delta.write(&mut w)?;
+    p.proof.sg.write(&mut w)?;
+
+    for c in [
+        &p.w_comm, &p.za_comm, &p.zb_comm, &p.h1_comm, &p.g1_comm, &p.h2_comm, &p.g2_comm,
+        &p.h3_comm, &p.g3_comm,
+    ]
+    .iter()
+    {
+        write_poly_comm(c, &mut w)?;
+    }
+    p.sigma2.write(&mut w)?;
+    p.sigma3.write(&mut w)?;
+
+    for e in p.evals.iter() {
+        write_dlog_proof_evaluations(e, &mut w)?;
+    }
+    Ok(())
+}
+
+fn poly_comm_points<G: AffineCurve>(c: &PolyComm<G>) -> impl Iterator<Item = &G> {
+    c.unshifted.iter().chain(c.shifted.iter())
+}
+
+// Every group element of a dlog proof.
+pub fn dlog_proof_points<G: CommitmentCurve>(p: &DlogProof<G>) -> Vec<&G> {
+    let mut res: Vec<&G> = vec![];
+    for (_, comm) in p.prev_challenges.iter() {
+        res.extend(poly_comm_points(comm));
+    }
+    for (l, r) in p.proof.lr.iter() {
+        res.push(l);
+        res.push(r);
+    }
+    res.push(&p.proof.delta);
+    res.push(&p.proof.sg);
+    for c in [
+        &p.w_comm, &p.za_comm, &p.zb_comm, &p.h1_comm, &p.g1_comm, &p.h2_comm, &p.g2_comm,
+        &p.h3_comm, &p.g3_comm,
+    ]
+    .iter()
+    {
+        res.extend(poly_comm_points(c));
+    }
+    res
+}
+
+// Reads a proof written by write_dlog_proof. The proof is rejected unless
+// valid accepts each of its points, since the decoding of a point does not
+// check that it is on the curve, let alone in the subgroup.
+pub fn read_dlog_proof<G: CommitmentCurve, R: Read, V: Fn(&G) -> bool>(
+    mut r: R,
+    public: Vec<G::ScalarField>,
+    valid: V,
+) -> IoResult<DlogProof<G>> {
+    let mut prev_challenges = vec![];
+    let n = u64::read(&mut r)?;
+    for _ in 0..n {
+        let chals = read_vec(&mut r)?;
+        let comm = read_poly_comm(&mut r)?;
+        prev_challenges.push((chals, comm));
+    }
+
+    let mut lr = vec![];
+    let n = u64::read(&mut r)?;
+    for _ in 0..n {
+        let l = G::read(&mut r)?;
+        let r = G::read(&mut r)?;
+        lr.push((l, r));
+    }
+    let proof = OpeningProof {
+        lr,
+        z1: G::ScalarField::read(&mut r)?,
+        z2: G::ScalarField::read(&mut r)?,
+        delta: G::read(&mut r)?,
+        sg: G::read(&mut r)?,
+    };
+
+    let p = DlogProof {
+        prev_challenges,
+        proof,
+        w_comm: read_poly_comm(&mut r)?,
+        za_comm: read_poly_comm(&mut r)?,
+        zb_comm: read_poly_comm(&mut r)?,
+        h1_comm: read_poly_comm(&mut r)?,
+        g1_comm: read_poly_comm(&mut r)?,
+        h2_comm: read_poly_comm(&mut r)?,
+        g2_comm: read_poly_comm(&mut r)?,
+        h3_comm: read_poly_comm(&mut r)?,
+        g3_comm: read_poly_comm(&mut r)?,
+        sigma2: G::ScalarField::read(&mut r)?,
+        sigma3: G::ScalarField::read(&mut r)?,
+        public,
+        evals: [
+            read_dlog_proof_evaluations(&mut r)?,
+            read_dlog_proof_evaluations(&mut r)?,
+            read_dlog_proof_evaluations(&mut r)?,
+        ],
+    };
+    if !dlog_proof_points(&p).into_iter().all(|g| valid(g)) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "read_dlog_proof: a point is not on the curve or not in the subgroup",
+        ));
+    }
+    Ok(p)
 }
 
-pub fn write_cs_mat<A: ToBytes + Clone, W: Write>(m: &CsMat<A>, mut w: W) -> IoResult<()> {
-    fn v(s: &[usize]) -> Vec<u64> {
-        s.iter().map(|x| *x as u64).collect()
+// Pairing proofs are serialized as a version number and the length of the
+// body, followed by the body. Readers reject other versions, and the length
+// lets them reject truncated or overlong input before parsing.
+pub const PAIRING_PROOF_VERSION: u32 = 1;
+
+fn write_pairing_proof_body<E: PairingEngine, W: Write>(
+    p: &PairingProof<E>,
+    mut w: W,
+) -> IoResult<()> {
+    p.w_comm.write(&mut w)?;
+    p.za_comm.write(&mut w)?;
+    p.zb_comm.write(&mut w)?;
+    p.h1_comm.write(&mut w)?;
+    p.g1_comm.0.write(&mut w)?;
+    p.g1_comm.1.write(&mut w)?;
+    p.h2_comm.write(&mut w)?;
+    p.g2_comm.0.write(&mut w)?;
+    p.g2_comm.1.write(&mut w)?;
+    p.h3_comm.write(&mut w)?;
+    p.g3_comm.0.write(&mut w)?;
+    p.g3_comm.1.write(&mut w)?;
+    p.proof1.write(&mut w)?;
+    p.proof2.write(&mut w)?;
+    p.proof3.write(&mut w)?;
+    write_vec(&p.public, &mut w)?;
+    p.sigma2.write(&mut w)?;
+    p.sigma3.write(&mut w)?;
+
+    let e = &p.evals;
+    for x in [&e.w, &e.za, &e.zb, &e.h1, &e.g1, &e.h2, &e.g2, &e.h3, &e.g3].iter() {
+        x.write(&mut w)?;
     }
+    for t in [&e.row, &e.col, &e.val, &e.rc].iter() {
+        for x in t.iter() {
+            x.write(&mut w)?;
+        }
+    }
+    Ok(())
+}
 
-    let (a, b) = m.shape();
-    u64::write(&(a as u64), &mut w)?;
-    u64::write(&(b as u64), &mut w)?;
+fn read_pairing_proof_body<E: PairingEngine, R: Read>(mut r: R) -> IoResult<PairingProof<E>> {
+    let mut g1 = || E::G1Affine::read(&mut r);
+    let w_comm = g1()?;
+    let za_comm = g1()?;
+    let zb_comm = g1()?;
+    let h1_comm = g1()?;
+    let g1_comm = (g1()?, g1()?);
+    let h2_comm = g1()?;
+    let g2_comm = (g1()?, g1()?);
+    let h3_comm = g1()?;
+    let g3_comm = (g1()?, g1()?);
+    let proof1 = g1()?;
+    let proof2 = g1()?;
+    let proof3 = g1()?;
+    let public = read_vec(&mut r)?;
+
+    let mut fr = || E::Fr::read(&mut r);
+    let sigma2 = fr()?;
+    let sigma3 = fr()?;
+    let evals = PairingProofEvaluations {
+        w: fr()?,
+        za: fr()?,
+        zb: fr()?,
+        h1: fr()?,
+        g1: fr()?,
+        h2: fr()?,
+        g2: fr()?,
+        h3: fr()?,
+        g3: fr()?,
+        row: [fr()?, fr()?, fr()?],
+        col: [fr()?, fr()?, fr()?],
+        val: [fr()?, fr()?, fr()?],
+        rc: [fr()?, fr()?, fr()?],
+    };
+    Ok(PairingProof {
+        w_comm,
+        za_comm,
+        zb_comm,
+        h1_comm,
+        g1_comm,
+        h2_comm,
+        g2_comm,
+        h3_comm,
+        g3_comm,
+        proof1,
+        proof2,
+        proof3,
+        public,
+        sigma2,
+        sigma3,
+        evals,
+    })
+}
 
-    write_vec::<u64, _>(&v(m.indptr()), &mut w)?;
-    write_vec(&v(m.indices()), &mut w)?;
-    write_vec(&m.data().to_vec(), &mut w)?;
-    Ok(())
+pub fn write_pairing_proof<E: PairingEngine, W: Write>(
+    p: &PairingProof<E>,
+    mut w: W,
+) -> IoResult<()> {
+    let mut body = vec![];
+    write_pairing_proof_body(p, &mut body)?;
+    PAIRING_PROOF_VERSION.write(&mut w)?;
+    (body.len() as u64).write(&mut w)?;
+    w.write_all(&body)
 }
 
-pub fn read_cs_mat<A: FromBytes + Copy, R: Read>(mut r: R) -> IoResult<CsMat<A>> {
-    fn v(s: Vec<u64>) -> Vec<usize> {
-        s.iter().map(|x| *x as usize).collect()
+pub fn read_pairing_proof<E: PairingEngine, R: Read>(mut r: R) -> IoResult<PairingProof<E>> {
+    let version = u32::read(&mut r)?;
+    if version != PAIRING_PROOF_VERSION {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("read_pairing_proof: unsupported version {}", version),
+        ));
     }
+    let len = u64::read(&mut r)?;
+    let mut body = r.take(len);
+    let res = read_pairing_proof_body(&mut body)?;
+    if body.limit() != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "read_pairing_proof: trailing bytes in proof body",
+        ));
+    }
+    Ok(res)
+}
 
-    let a = u64::read(&mut r)? as usize;
-    let b = u64::read(&mut r)? as usize;
-    let shape = (a, b);
+// Prover index files start with a version number, which readers check.
+// Version 1 stores the constraint matrices alongside the compiled index, so
+// that an index can be read back without rebuilding them.
+pub const INDEX_FILE_VERSION: u32 = 1;
 
-    let indptr = v(read_vec(&mut r)?);
-    let indices = v(read_vec(&mut r)?);
-    let data: Vec<A> = read_vec(&mut r)?;
-    Ok(CsMat::new(shape, indptr, indices, data))
+pub fn write_index_file_version<W: Write>(mut w: W) -> IoResult<()> {
+    INDEX_FILE_VERSION.write(&mut w)
 }
 
-pub fn write_matrix_values<A: ToBytes, W: Write>(m: &MatrixValues<A>, mut w: W) -> IoResult<()> {
-    A::write(&m.row, &mut w)?;
-    A::write(&m.col, &mut w)?;
-    A::write(&m.val, &mut w)?;
-    A::write(&m.rc, &mut w)?;
+pub fn read_index_file_version<R: Read>(mut r: R) -> IoResult<()> {
+    let version = u32::read(&mut r)?;
+    if version != INDEX_FILE_VERSION {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("read_index_file_version: unsupported version {}", version),
+        ));
+    }
     Ok(())
 }
 
-pub fn read_matrix_values<A: FromBytes, R: Read>(mut r: R) -> IoResult<MatrixValues<A>> {
-    let row = A::read(&mut r)?;
-    let col = A::read(&mut r)?;
-    let val = A::read(&mut r)?;
-    let rc = A::read(&mut r)?;
-    Ok(MatrixValues { row, col, val, rc })
+// Moves a field element to another field with the same representation, if
+// it is below the modulus of that field.
+pub fn convert_field<F: PrimeField, G: PrimeField<BigInt = F::BigInt>>(x: &F) -> Option<G> {
+    let repr = x.into_repr();
+    if repr < G::Params::MODULUS {
+        Some(G::from_repr(repr))
+    } else {
+        None
+    }
 }
 
-pub fn write_option<A: ToBytes, W: Write>(a: &Option<A>, mut w: W) -> IoResult<()> {
-    match a {
-        None => u8::write(&0, &mut w),
-        Some(a) => {
-            u8::write(&1, &mut w)?;
-            A::write(a, &mut w)
-        }
+// Strings
+//
+// Stubs returning text return a newly allocated NUL-terminated string, which
+// the caller frees with zexe_string_delete.
+
+pub fn into_c_string(s: String) -> *mut c_char {
+    CString::new(s.replace('\0', "")).unwrap().into_raw()
+}
+
+crate::delete_stub!(zexe_string_delete, CString);
+
+// Parses a field element written in the given radix, such as 10 or 16 (with
+// or without a 0x prefix). Values not below the modulus are rejected rather
+// than reduced.
+// BigUint::parse_bytes panics on other bases.
+pub fn check_radix(radix: u32) -> Result<(), String> {
+    if radix < 2 || radix > 36 {
+        return Err(format!("Base {} is not between 2 and 36.", radix));
     }
+    Ok(())
 }
 
-pub fn read_option<A: FromBytes, R: Read>(mut r: R) -> IoResult<Option<A>> {
-    match u8::read(&mut r)? {
-        0 => Ok(None),
-        1 => Ok(Some(A::read(&mut r)?)),
-        _ => panic!("read_option: expected 0 or 1"),
+pub fn field_of_digits<F: PrimeField>(s: &[u8], radix: u32) -> Result<F, String> {
+    check_radix(radix)?;
+    let s = if radix == 16 && (s.starts_with(b"0x") || s.starts_with(b"0X")) {
+        &s[2..]
+    } else {
+        s
+    };
+    let x = match num_bigint::BigUint::parse_bytes(s, radix) {
+        Some(x) => x,
+        None => return Err(format!("Could not convert numeral in base {}.", radix)),
+    };
+    let mut bytes = x.to_bytes_le();
+    let mut repr = F::BigInt::default();
+    if bytes.len() > repr.as_ref().len() * 8 {
+        return Err("The value is not below the modulus.".to_string());
+    }
+    bytes.resize(repr.as_ref().len() * 8, 0);
+    for (l, b) in repr.as_mut().iter_mut().zip(bytes.chunks(8)) {
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(b);
+        *l = u64::from_le_bytes(limb);
+    }
+    if repr < F::Params::MODULUS {
+        Ok(F::from_repr(repr))
+    } else {
+        Err("The value is not below the modulus.".to_string())
     }
 }
 
-pub fn write_poly_comm<A: ToBytes + AffineCurve, W: Write>(
-    p: &PolyComm<A>,
-    mut w: W,
-) -> IoResult<()> {
-    write_vec(&p.unshifted, &mut w)?;
-    write_option(&p.shifted, &mut w)
+// Raw field vectors
+//
+// Field vectors can be passed in bulk as contiguous buffers of canonical
+// (non-Montgomery) little-endian u64 limbs, NUM_LIMBS per element.
+
+pub fn field_vector_of_limbs<F: PrimeField>(limbs: &[u64]) -> Result<Vec<F>, String> {
+    let n = <F::BigInt as BigInteger>::NUM_LIMBS;
+    if limbs.len() % n != 0 {
+        return Err(format!(
+            "{} limbs is not a multiple of {} limbs per element",
+            limbs.len(),
+            n
+        ));
+    }
+    limbs
+        .par_chunks(n)
+        .enumerate()
+        .map(|(i, l)| {
+            let mut repr = F::BigInt::default();
+            repr.as_mut().copy_from_slice(l);
+            if repr < F::Params::MODULUS {
+                Ok(F::from_repr(repr))
+            } else {
+                Err(format!("element {} is not canonical", i))
+            }
+        })
+        .collect()
 }
 
-pub fn read_poly_comm<A: FromBytes + AffineCurve, R: Read>(mut r: R) -> IoResult<PolyComm<A>> {
-    let unshifted = read_vec(&mut r)?;
-    let shifted = read_option(&mut r)?;
-    Ok(PolyComm { unshifted, shifted })
+// Builds constraint matrix rows from CSR arrays: row i has the columns and
+// values at positions row_ptrs[i]..row_ptrs[i + 1], and values holds the
+// canonical limbs of one element per column index.
+pub fn rows_of_csr<F: PrimeField>(
+    row_ptrs: &[usize],
+    cols: &[usize],
+    limbs: &[u64],
+) -> Result<Vec<Row<F>>, String> {
+    let values = field_vector_of_limbs::<F>(limbs)?;
+    if values.len() != cols.len() {
+        return Err(format!(
+            "{} values for {} column indices",
+            values.len(),
+            cols.len()
+        ));
+    }
+    if row_ptrs.first() != Some(&0) || row_ptrs.last() != Some(&cols.len()) {
+        return Err(format!(
+            "row pointers must run from 0 to the number of entries, {}",
+            cols.len()
+        ));
+    }
+    if let Some(i) = row_ptrs.windows(2).position(|w| w[0] > w[1]) {
+        return Err(format!("row pointer {} is decreasing", i + 1));
+    }
+    Ok(row_ptrs
+        .windows(2)
+        .map(|w| (cols[w[0]..w[1]].to_vec(), values[w[0]..w[1]].to_vec()))
+        .collect())
 }
 
-pub fn write_dlog_matrix_values<A: ToBytes + AffineCurve, W: Write>(
-    m: &marlin_protocol_dlog::index::MatrixValues<A>,
-    mut w: W,
-) -> IoResult<()> {
-    write_poly_comm(&m.row, &mut w)?;
-    write_poly_comm(&m.col, &mut w)?;
-    write_poly_comm(&m.val, &mut w)?;
-    write_poly_comm(&m.rc, &mut w)?;
-    Ok(())
+pub fn field_vector_to_limbs<F: PrimeField>(v: &[F], limbs: &mut [u64]) {
+    let n = <F::BigInt as BigInteger>::NUM_LIMBS;
+    limbs
+        .par_chunks_mut(n)
+        .zip(v.par_iter())
+        .for_each(|(l, x)| l.copy_from_slice(x.into_repr().as_ref()));
 }
 
-pub fn read_dlog_matrix_values<A: FromBytes + AffineCurve, R: Read>(
-    mut r: R,
-) -> IoResult<marlin_protocol_dlog::index::MatrixValues<A>> {
-    let row = read_poly_comm(&mut r)?;
-    let col = read_poly_comm(&mut r)?;
-    let val = read_poly_comm(&mut r)?;
-    let rc = read_poly_comm(&mut r)?;
-    Ok(marlin_protocol_dlog::index::MatrixValues { row, col, val, rc })
+// Reads a slice holding the concatenated serializations of field elements.
+pub fn read_field_elements<F: PrimeField>(mut bytes: &[u8]) -> IoResult<Vec<F>> {
+    let size = <F::BigInt as BigInteger>::NUM_LIMBS * 8;
+    if bytes.len() % size != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "read_field_elements: length is not a multiple of the element size",
+        ));
+    }
+    let mut v = Vec::with_capacity(bytes.len() / size);
+    while !bytes.is_empty() {
+        v.push(F::read(&mut bytes)?);
+    }
+    Ok(v)
 }
 
 pub fn write_dense_polynomial<A: ToBytes + Field, W: Write>(
@@ -345,13 +2203,13 @@ where G::ScalarField : CommitmentField + FromBytes
 fn print_comm<G: CommitmentCurve>(s : &str, g: &PolyComm<G>) {
     for (i, t) in g.unshifted.iter().enumerate() {
         let (x, y) = t.to_coordinates().unwrap();
-        println!("{}.unshifted[{}] = {}, {}", s, i, x, y);
+        print_line(format!("{}.unshifted[{}] = {}, {}", s, i, x, y));
     }
     match g.shifted {
         None => (),
         Some(s) => {
             let (x, y) = s.to_coordinates().unwrap();
-            println!("{}.shifted = {}, {}", s, x, y);
+            print_line(format!("{}.shifted = {}, {}", s, x, y));
         }
     }
 }
@@ -359,9 +2217,9 @@ fn print_comm<G: CommitmentCurve>(s : &str, g: &PolyComm<G>) {
 pub fn print_plonk_verifier_index<'a, G: CommitmentCurve>(
     vk: & PlonkVerifierIndex<'a, G>) {
 
-    println!("domain {}", vk.domain.size);
-    println!("max_poly_size {}", vk.max_poly_size);
-    println!("max_quot_size {}", vk.max_quot_size);
+    print_line(format!("domain {}", vk.domain.size));
+    print_line(format!("max_poly_size {}", vk.max_poly_size));
+    print_line(format!("max_quot_size {}", vk.max_quot_size));
 
     print_comm("s0", &vk.sigma_comm[0]);
     print_comm("s1", &vk.sigma_comm[1]);
@@ -385,8 +2243,8 @@ pub fn print_plonk_verifier_index<'a, G: CommitmentCurve>(
     print_comm("emul2_comm ", &vk.emul2_comm);
     print_comm("emul3_comm ", &vk.emul3_comm);
 
-    println!("r {}", vk.r);
-    println!("o {}", vk.o);
+    print_line(format!("r {}", vk.r));
+    print_line(format!("o {}", vk.o));
 }
 
 pub fn write_plonk_verifier_index<'a, G: CommitmentCurve, W: Write>(
@@ -736,34 +2594,53 @@ pub fn rows_to_csmat<F: Clone + Copy + std::fmt::Debug>(
     public_inputs: usize,
     h_group_size: usize,
     h_to_x_ratio: usize,
-    v: &Vec<(Vec<usize>, Vec<F>)>,
+    v: &Vec<Row<F>>,
 ) -> CsMat<F> {
     let mut m = CsMat::empty(CSR, /* number of columns */ h_group_size);
     m.reserve_outer_dim(h_group_size);
 
     for (indices, coefficients) in v.iter() {
-        let mut shifted: Vec<(usize, F)> = indices
-            .iter()
-            .map(|&i| index_to_witness_position(public_inputs, h_to_x_ratio, i))
-            .zip(coefficients)
-            .map(|(i, &x)| (i, x))
-            .collect();
+        m = append_csmat_row(m, public_inputs, h_group_size, h_to_x_ratio, indices, coefficients);
+    }
+
+    pad_csmat(m, v.len(), h_group_size)
+}
 
-        shifted.sort_by(|(i, _), (j, _)| i.cmp(j));
+fn append_csmat_row<F: Clone + Copy + std::fmt::Debug>(
+    m: CsMat<F>,
+    public_inputs: usize,
+    h_group_size: usize,
+    h_to_x_ratio: usize,
+    indices: &[usize],
+    coefficients: &[F],
+) -> CsMat<F> {
+    let mut shifted: Vec<(usize, F)> = indices
+        .iter()
+        .map(|&i| index_to_witness_position(public_inputs, h_to_x_ratio, i))
+        .zip(coefficients)
+        .map(|(i, &x)| (i, x))
+        .collect();
 
-        let shifted_indices: Vec<usize> = shifted.iter().map(|(i, _)| *i).collect();
-        let shifted_coefficients: Vec<F> = shifted.iter().map(|(_, x)| *x).collect();
+    shifted.sort_by(|(i, _), (j, _)| i.cmp(j));
 
-        match CsVecView::<F>::new_view(h_group_size, &shifted_indices, &shifted_coefficients) {
-            Ok(r) => m = m.append_outer_csvec(r),
-            Err(e) => panic!(
-                "new_view failed {} ({:?}, {:?})",
-                e, shifted_indices, shifted_coefficients
-            ),
-        };
+    let shifted_indices: Vec<usize> = shifted.iter().map(|(i, _)| *i).collect();
+    let shifted_coefficients: Vec<F> = shifted.iter().map(|(_, x)| *x).collect();
+
+    match CsVecView::<F>::new_view(h_group_size, &shifted_indices, &shifted_coefficients) {
+        Ok(r) => m.append_outer_csvec(r),
+        Err(e) => panic!(
+            "new_view failed {} ({:?}, {:?})",
+            e, shifted_indices, shifted_coefficients
+        ),
     }
+}
 
-    for _ in 0..(h_group_size - v.len()) {
+fn pad_csmat<F: Clone + Copy + std::fmt::Debug>(
+    mut m: CsMat<F>,
+    rows: usize,
+    h_group_size: usize,
+) -> CsMat<F> {
+    for _ in 0..(h_group_size - rows) {
         match CsVecView::<F>::new_view(h_group_size, &vec![], &vec![]) {
             Ok(v) => m = m.append_outer_csvec(v),
             Err(e) => panic!("new_view failed {}", e),
@@ -773,6 +2650,73 @@ pub fn rows_to_csmat<F: Clone + Copy + std::fmt::Debug>(
     m
 }
 
+// Streaming index creation
+//
+// Instead of materializing the matrices as vectors of rows, the caller may
+// supply the rows one at a time through a callback, which is asked for row
+// `row` of matrix `matrix` (0, 1 and 2 for A, B and C) and sets the length of
+// the row and pointers to its variable indices and to its coefficients, as
+// NUM_LIMBS canonical limbs each. The pointers only need to stay valid until
+// the next call, so the caller can reuse one buffer, e.g. while reading a
+// compiled circuit from disk. The callback returns false to abort.
+//
+// The callback is always called on the thread which called the stub, before
+// any work is handed to the thread pool.
+pub type RowSource = extern "C" fn(
+    *mut c_void,
+    usize,
+    usize,
+    *mut usize,
+    *mut *const usize,
+    *mut *const u64,
+) -> bool;
+
+pub fn csmat_of_row_source<F: PrimeField>(
+    source: RowSource,
+    ctx: *mut c_void,
+    matrix: usize,
+    rows: usize,
+    public_inputs: usize,
+    h_group_size: usize,
+    h_to_x_ratio: usize,
+) -> Result<CsMat<F>, String> {
+    let n = <F::BigInt as BigInteger>::NUM_LIMBS;
+    let mut m = CsMat::empty(CSR, /* number of columns */ h_group_size);
+    m.reserve_outer_dim(h_group_size);
+
+    for row in 0..rows {
+        let mut len = 0;
+        let mut indices: *const usize = std::ptr::null();
+        let mut limbs: *const u64 = std::ptr::null();
+        if !source(ctx, matrix, row, &mut len, &mut indices, &mut limbs) {
+            return Err(format!("The row source failed at row {} of matrix {}", row, matrix));
+        }
+        let (indices, limbs) = if len == 0 {
+            (&[][..], &[][..])
+        } else {
+            unsafe {
+                (
+                    std::slice::from_raw_parts(indices, len),
+                    std::slice::from_raw_parts(limbs, len * n),
+                )
+            }
+        };
+        if let Some(i) = indices.iter().find(|&&i| {
+            index_to_witness_position(public_inputs, h_to_x_ratio, i) >= h_group_size
+        }) {
+            return Err(format!(
+                "Variable {} in row {} of matrix {} is out of range",
+                i, row, matrix
+            ));
+        }
+        let coefficients = field_vector_of_limbs::<F>(limbs)
+            .map_err(|e| format!("Row {} of matrix {}: {}", row, matrix, e))?;
+        m = append_csmat_row(m, public_inputs, h_group_size, h_to_x_ratio, indices, &coefficients);
+    }
+
+    Ok(pad_csmat(m, rows, h_group_size))
+}
+
 pub fn prepare_witness<F: PrimeField>(
     domains: EvaluationDomains<F>,
     primary_input: &Vec<F>,
@@ -798,6 +2742,71 @@ pub fn prepare_witness<F: PrimeField>(
     witness
 }
 
+// Whether any of the matrices refers to an auxiliary variable. The auxiliary
+// variables are at the positions of the witness which are not multiples of
+// h_to_x_ratio.
+pub fn constraints_use_auxiliary<F>(matrices: &[&CsMat<F>], h_to_x_ratio: usize) -> bool {
+    matrices
+        .iter()
+        .any(|m| m.indices().iter().any(|&j| j % h_to_x_ratio != 0))
+}
+
+// Whether the constraints of each index refer to auxiliary variables, by the
+// address of the index. The flag is recorded when the index is created or
+// read, so that proving does not scan the matrices again, and is forgotten
+// when the index is deleted.
+lazy_static! {
+    static ref AUXILIARY_USE: Mutex<HashMap<usize, bool>> = Mutex::new(HashMap::new());
+}
+
+pub fn record_auxiliary_use<P: HandlePtr>(index: P, uses_auxiliary: bool) {
+    AUXILIARY_USE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(handle_address(index), uses_auxiliary);
+}
+
+// The flag recorded for an index, or, for an index built outside the stubs,
+// the one computed by scan, which is then recorded.
+pub fn index_uses_auxiliary<P: HandlePtr>(index: P, scan: impl FnOnce() -> bool) -> bool {
+    let recorded = AUXILIARY_USE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&handle_address(index))
+        .copied();
+    match recorded {
+        Some(uses_auxiliary) => uses_auxiliary,
+        None => {
+            let uses_auxiliary = scan();
+            record_auxiliary_use(index, uses_auxiliary);
+            uses_auxiliary
+        }
+    }
+}
+
+pub fn forget_auxiliary_use<P: HandlePtr>(index: P) {
+    AUXILIARY_USE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&handle_address(index));
+}
+
+pub(crate) fn forget_auxiliary_uses() {
+    AUXILIARY_USE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+// Whether a proof can leave the auxiliary positions of its witness zero, and
+// prepare the witness from the primary input alone: when no constraint refers
+// to the auxiliary input, as for the pure public-input statements used to
+// chain proofs, in which case it is not read at all, or when it is empty or
+// all zero.
+pub fn witness_fast_path<F: Field>(uses_auxiliary: bool, auxiliary_input: &[F]) -> bool {
+    !uses_auxiliary || auxiliary_input.iter().all(|x| x.is_zero())
+}
+
 pub fn prepare_plonk_witness<F: PrimeField>(
     primary_input: &Vec<F>,
     auxiliary_input: &Vec<F>,
@@ -819,47 +2828,80 @@ pub fn prepare_plonk_witness<F: PrimeField>(
 // usize vector stubs
 #[no_mangle]
 pub extern "C" fn zexe_usize_vector_create() -> *mut Vec<usize> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_usize_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_usize_vector_length(v: *const Vec<usize>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_usize_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_usize_vector_emplace_back(v: *mut Vec<usize>, x: usize) {
-    let v_ = unsafe { &mut (*v) };
-    v_.push(x);
+    catch_ffi("zexe_usize_vector_emplace_back", (), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        v_.push(x);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_usize_vector_get(v: *mut Vec<usize>, i: u32) -> usize {
-    let v = unsafe { &mut (*v) };
-    v[i as usize]
+    catch_ffi("zexe_usize_vector_get", 0, || {
+        let v = handle(v);
+        let v = unsafe { &mut (*v) };
+        v[i as usize]
+    })
+}
+
+crate::delete_stub!(zexe_usize_vector_delete, Vec<usize>);
+
+// byte vector stubs
+#[no_mangle]
+pub extern "C" fn zexe_byte_vector_length(v: *const Vec<u8>) -> usize {
+    catch_ffi("zexe_byte_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        v_.len()
+    })
 }
 
+// The pointer is borrowed from the vector and valid until it is deleted.
 #[no_mangle]
-pub extern "C" fn zexe_usize_vector_delete(v: *mut Vec<usize>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_byte_vector_data(v: *const Vec<u8>) -> *const u8 {
+    catch_ffi("zexe_byte_vector_data", std::ptr::null(), || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        v_.as_ptr()
+    })
 }
 
+crate::delete_stub!(zexe_byte_vector_delete, Vec<u8>);
+
 // pointer vector stubs
 #[no_mangle]
 pub extern "C" fn zexe_pointer_vector_create<'a>(
 ) -> *const Vec<*const c_void> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_pointer_vector_create", std::ptr::null(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_pointer_vector_length(
     v: *const Vec<*const c_void>,
 ) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_pointer_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -867,8 +2909,11 @@ pub extern "C" fn zexe_pointer_vector_emplace_back<'a>(
     v: *mut Vec<*const c_void>,
     x: *const c_void,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    v_.push(x);
+    catch_ffi("zexe_pointer_vector_emplace_back", (), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        v_.push(x);
+    })
 }
 
 #[no_mangle]
@@ -876,16 +2921,488 @@ pub extern "C" fn zexe_pointer_vector_get<'a>(
     v: *mut Vec<*const c_void>,
     i: u32,
 ) -> *const c_void {
-    let v_ = unsafe { &mut (*v) };
-    return v_[i as usize];
+    catch_ffi("zexe_pointer_vector_get", std::ptr::null(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return v_[i as usize];
+    })
+}
+
+crate::delete_stub!(zexe_pointer_vector_delete, Vec<*const c_void>);
+
+// Generates a deleter for a boxed value. The type is spelled out at each use,
+// next to the stubs returning it, so that the deleter frees the same type the
+// constructors allocate. With a third argument, the deleter first passes the
+// handle to that function, to forget what the library keeps about it. The
+// CString form generates a deleter for strings returned by into_raw, which
+// accepts null like zexe_string_delete.
+#[macro_export]
+macro_rules! delete_stub {
+    ($name:ident, CString) => {
+        #[no_mangle]
+        pub extern "C" fn $name(s: *mut std::os::raw::c_char) {
+            $crate::common::catch_ffi(stringify!($name), (), || {
+                if !s.is_null() {
+                    let _s = unsafe { std::ffi::CString::from_raw(s) };
+                }
+            })
+        }
+    };
+    ($name:ident, $T:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $name(x: *mut $T) {
+            $crate::common::catch_ffi(stringify!($name), (), || {
+                let x = $crate::common::handle(x);
+                $crate::common::release_handle(stringify!($name), x);
+            })
+        }
+    };
+    ($name:ident, $T:ty, $forget:path) => {
+        #[no_mangle]
+        pub extern "C" fn $name(x: *mut $T) {
+            $crate::common::catch_ffi(stringify!($name), (), || {
+                let x = $crate::common::handle(x);
+                $forget(x);
+                $crate::common::release_handle(stringify!($name), x);
+            })
+        }
+    };
+}
+
+// affine pair stubs
+
+// A cursor over a vector of pairs, so that bindings can walk the vector
+// without boxing each element by index.
+pub struct PairVectorCursor<G> {
+    pub v: *const Vec<(G, G)>,
+    pub i: usize,
+}
+
+// Generates the pair and pair-vector stubs for an affine group element type.
+// The names are passed explicitly since we have no way to concatenate
+// identifiers here.
+#[macro_export]
+macro_rules! affine_pair_stubs {
+    (
+        $G:ty,
+        pair_0: $pair_0:ident,
+        pair_1: $pair_1:ident,
+        pair_make: $pair_make:ident,
+        pair_delete: $pair_delete:ident,
+        vector_create: $vector_create:ident,
+        vector_length: $vector_length:ident,
+        vector_emplace_back: $vector_emplace_back:ident,
+        vector_get: $vector_get:ident,
+        vector_delete: $vector_delete:ident,
+        vector_cursor_create: $vector_cursor_create:ident,
+        vector_cursor_next: $vector_cursor_next:ident,
+        vector_cursor_delete: $vector_cursor_delete:ident,
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn $pair_0(p: *const AffinePair<$G>) -> *const $G {
+            $crate::common::catch_ffi(stringify!($pair_0), std::ptr::null(), || {
+                let p = $crate::common::handle(p);
+                let (x0, _) = unsafe { *p };
+                return $crate::common::into_handle(Box::new(x0.clone()));
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $pair_1(p: *const AffinePair<$G>) -> *const $G {
+            $crate::common::catch_ffi(stringify!($pair_1), std::ptr::null(), || {
+                let p = $crate::common::handle(p);
+                let (_, x1) = unsafe { *p };
+                return $crate::common::into_handle(Box::new(x1.clone()));
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $pair_make(x0: *const $G, x1: *const $G) -> *const AffinePair<$G> {
+            $crate::common::catch_ffi(stringify!($pair_make), std::ptr::null(), || {
+                let x0 = $crate::common::handle(x0);
+                let x1 = $crate::common::handle(x1);
+                let res = ((unsafe { *x0 }), (unsafe { *x1 }));
+                return $crate::common::into_handle(Box::new(res));
+            })
+        }
+
+        $crate::delete_stub!($pair_delete, AffinePair<$G>);
+
+        #[no_mangle]
+        pub extern "C" fn $vector_create() -> *mut Vec<AffinePair<$G>> {
+            $crate::common::catch_ffi(stringify!($vector_create), std::ptr::null_mut(), || {
+                return $crate::common::into_handle(Box::new(Vec::new()));
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $vector_length(v: *const Vec<AffinePair<$G>>) -> i32 {
+            $crate::common::catch_ffi(stringify!($vector_length), 0, || {
+                let v = $crate::common::handle(v);
+                let v_ = unsafe { &(*v) };
+                return v_.len() as i32;
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $vector_emplace_back(
+            v: *mut Vec<AffinePair<$G>>,
+            x: *const AffinePair<$G>,
+        ) {
+            $crate::common::catch_ffi(stringify!($vector_emplace_back), (), || {
+                let v = $crate::common::handle(v);
+                let x = $crate::common::handle(x);
+                let v_ = unsafe { &mut (*v) };
+                let x_ = unsafe { &(*x) };
+                v_.push(*x_);
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $vector_get(v: *mut Vec<AffinePair<$G>>, i: u32) -> *mut AffinePair<$G> {
+            $crate::common::catch_ffi(stringify!($vector_get), std::ptr::null_mut(), || {
+                let v = $crate::common::handle(v);
+                let v_ = unsafe { &mut (*v) };
+                return $crate::common::into_handle(Box::new((*v_)[i as usize]));
+            })
+        }
+
+        $crate::delete_stub!($vector_delete, Vec<AffinePair<$G>>);
+
+        // The cursor borrows the vector, which must outlive it.
+        #[no_mangle]
+        pub extern "C" fn $vector_cursor_create(
+            v: *const Vec<AffinePair<$G>>,
+        ) -> *mut $crate::common::PairVectorCursor<$G> {
+            $crate::common::catch_ffi(stringify!($vector_cursor_create), std::ptr::null_mut(), || {
+                let v = $crate::common::handle(v);
+                $crate::common::into_handle(Box::new($crate::common::PairVectorCursor { v, i: 0 }))
+            })
+        }
+
+        // Returns a pointer to the next pair, owned by the vector, or null
+        // once the vector is exhausted.
+        #[no_mangle]
+        pub extern "C" fn $vector_cursor_next(
+            c: *mut $crate::common::PairVectorCursor<$G>,
+        ) -> *const AffinePair<$G> {
+            $crate::common::catch_ffi(stringify!($vector_cursor_next), std::ptr::null(), || {
+                let c = $crate::common::handle(c);
+                let c = unsafe { &mut (*c) };
+                let v = unsafe { &(*c.v) };
+                match v.get(c.i) {
+                    Some(x) => {
+                        c.i += 1;
+                        x as *const AffinePair<$G>
+                    }
+                    None => std::ptr::null(),
+                }
+            })
+        }
+
+        $crate::delete_stub!($vector_cursor_delete, $crate::common::PairVectorCursor<$G>);
+    };
 }
 
+// URS digest stubs
 #[no_mangle]
-pub extern "C" fn zexe_pointer_vector_delete(
-    v: *mut Vec<*const c_void>,
-) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
+pub extern "C" fn zexe_urs_digest_size() -> i32 {
+    catch_ffi("zexe_urs_digest_size", 0, || {
+        URS_DIGEST_SIZE as i32
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_urs_digest_to_data(d: *const UrsDigest) -> *const u8 {
+    catch_ffi("zexe_urs_digest_to_data", std::ptr::null(), || {
+        let d = handle(d);
+        let d = unsafe { &(*d) };
+        d.as_ptr()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_urs_digest_equal(x: *const UrsDigest, y: *const UrsDigest) -> bool {
+    catch_ffi("zexe_urs_digest_equal", false, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = unsafe { &(*x) };
+        let y = unsafe { &(*y) };
+        x == y
+    })
+}
+
+crate::delete_stub!(zexe_urs_digest_delete, UrsDigest);
+
+// Parameter report
+//
+// A JSON description of the protocol constants compiled into this library,
+// so that a given binary can be checked against a specification without
+// reading its source. Sponge constants are summarized by their BLAKE2b-256
+// digest over the little-endian serialization of the round constants followed
+// by the MDS matrix, both in row-major order.
+
+fn hex_of_bytes(b: &[u8]) -> String {
+    b.iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+fn modulus_hex<F: PrimeField>() -> String {
+    let mut bytes = vec![];
+    F::Params::MODULUS.write(&mut bytes).unwrap();
+    bytes.reverse();
+    format!("0x{}", hex_of_bytes(&bytes))
+}
+
+fn field_report<F: PrimeField>(name: &str) -> String {
+    format!(
+        "{{\"name\":\"{}\",\"modulus\":\"{}\",\"size_in_bits\":{},\"two_adicity\":{}}}",
+        name,
+        modulus_hex::<F>(),
+        F::size_in_bits(),
+        F::Params::TWO_ADICITY
+    )
+}
+
+fn sponge_params_report<F: PrimeField>(name: &str, params: &ArithmeticSpongeParams<F>) -> String {
+    let mut h = urs_hasher();
+    let mut bytes = vec![];
+    for x in params.round_constants.iter().chain(params.mds.iter()).flatten() {
+        x.write(&mut bytes).unwrap();
+    }
+    h.input(&bytes);
+    format!(
+        "{{\"name\":\"{}\",\"round_constants\":{},\"mds_rows\":{},\"constants_digest\":\"{}\"}}",
+        name,
+        params.round_constants.len(),
+        params.mds.len(),
+        hex_of_bytes(&urs_hasher_result(h))
+    )
+}
+
+fn sponge_constants_report<SC: SpongeConstants>(name: &str) -> String {
+    format!(
+        "{{\"name\":\"{}\",\"rounds_full\":{},\"rounds_partial\":{},\"width\":{},\"capacity\":{},\"sbox\":{},\"full_mds\":{}}}",
+        name,
+        SC::ROUNDS_FULL,
+        SC::ROUNDS_PARTIAL,
+        SC::SPONGE_WIDTH,
+        SC::SPONGE_CAPACITY,
+        SC::SPONGE_BOX,
+        SC::FULL_MDS
+    )
+}
+
+pub fn parameter_report() -> String {
+    use algebra::{bn_382, tweedle};
+    let fields = [
+        field_report::<bn_382::Fp>("bn_382_fp"),
+        field_report::<bn_382::Fq>("bn_382_fq"),
+        field_report::<tweedle::Fp>("tweedle_fp"),
+        field_report::<tweedle::Fq>("tweedle_fq"),
+    ];
+    let sponge_params = [
+        sponge_params_report("bn_382_fp", &oracle::bn_382::fp::params()),
+        sponge_params_report("bn_382_fq", &oracle::bn_382::fq::params()),
+        sponge_params_report("tweedle_fp", &oracle::tweedle::fp::params()),
+        sponge_params_report("tweedle_fq", &oracle::tweedle::fq::params()),
+    ];
+    let sponge_constants = [
+        sponge_constants_report::<MarlinSpongeConstants>("marlin"),
+        sponge_constants_report::<PlonkSpongeConstants>("plonk"),
+    ];
+    format!(
+        "{{\"version\":\"{}\",\"fields\":[{}],\"sponge_params\":[{}],\"sponge_constants\":[{}],\
+\"domains\":\"radix-2 multiplicative subgroups of the scalar field; h has size ceil_pow2(max(variables, constraints)), k has size ceil_pow2(nonzero_entries), x has size ceil_pow2(public_inputs)\",\
+\"serialization\":{{\"endianness\":\"little\",\"urs_digest\":\"blake2b-{}\"}}}}",
+        env!("CARGO_PKG_VERSION"),
+        fields.join(","),
+        sponge_params.join(","),
+        sponge_constants.join(","),
+        URS_DIGEST_SIZE * 8
+    )
+}
+
+// Returns the parameter report as a newly allocated string, which must be
+// freed with zexe_parameter_report_delete.
+#[no_mangle]
+pub extern "C" fn zexe_parameter_report() -> *mut c_char {
+    catch_ffi("zexe_parameter_report", std::ptr::null_mut(), || {
+        CString::new(parameter_report()).unwrap().into_raw()
+    })
 }
 
+crate::delete_stub!(zexe_parameter_report_delete, CString);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_rng_fills_bytes() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        system_rng().try_fill_bytes(&mut a).unwrap();
+        system_rng().fill_bytes(&mut b);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn failed_health_check_is_retried() {
+        rng_state().healthy = Some(false);
+        system_rng().try_fill_bytes(&mut [0u8; 32]).unwrap();
+        assert_eq!(rng_state().healthy, Some(true));
+    }
+
+    #[test]
+    fn poisoned_rng_state_is_taken_over() {
+        let _ = std::thread::spawn(|| {
+            let _state = rng_state();
+            panic!("poisoning the randomness state");
+        })
+        .join();
+        assert!(system_rng().try_fill_bytes(&mut [0u8; 32]).is_ok());
+        assert!(zexe_rng_health_check());
+    }
+
+    #[test]
+    fn seeds_are_mixed_in() {
+        let seed = [7u8; 16];
+        zexe_rng_mix_seed(seed.as_ptr(), seed.len());
+        let source = zexe_rng_source();
+        assert_eq!(
+            unsafe { std::ffi::CStr::from_ptr(source) }
+                .to_str()
+                .unwrap(),
+            "os+seed"
+        );
+        zexe_string_delete(source);
+        let mut a = [0u8; 32];
+        system_rng().fill_bytes(&mut a);
+        assert_ne!(a, [0u8; 32]);
+    }
+
+    #[test]
+    fn proof_seeds_need_a_long_key() {
+        use algebra::bn_382::fq::Fq;
+        let params = oracle::bn_382::fq::params();
+        let digest = [1u8; 32];
+        let inputs: [&[Fq]; 1] = [&[Fq::from(2u64)]];
+        assert!(derive_proof_seed(&params, &digest, &[3u8; 15], &inputs).is_err());
+
+        let seed = derive_proof_seed(&params, &digest, &[3u8; 16], &inputs).unwrap();
+        assert_eq!(
+            derive_proof_seed(&params, &digest, &[3u8; 16], &inputs),
+            Ok(seed)
+        );
+        assert_ne!(
+            derive_proof_seed(&params, &digest, &[4u8; 16], &inputs),
+            Ok(seed)
+        );
+        assert_ne!(
+            derive_proof_seed(&params, &digest, &[3u8; 17], &inputs),
+            Ok(seed)
+        );
+        assert_ne!(
+            derive_proof_seed(&params, &digest, &[3u8; 16], &[]),
+            Ok(seed)
+        );
+    }
+
+    #[test]
+    fn untagged_pointers_are_borrowed() {
+        let x = 7u64;
+        let p = &x as *const u64;
+        assert_eq!(handle(p), p);
+        assert_eq!(handle_address(p), p as usize);
+    }
+
+    #[test]
+    fn handles_round_trip() {
+        let v = zexe_usize_vector_create();
+        zexe_usize_vector_emplace_back(v, 3);
+        assert_eq!(zexe_usize_vector_get(v, 0), 3);
+        assert_eq!(unsafe { &*handle(v) }, &vec![3]);
+        zexe_usize_vector_delete(v);
+    }
+
+    #[cfg(feature = "handle-registry")]
+    #[test]
+    fn handles_carry_their_generation() {
+        let p = into_handle(Box::new(1u64));
+        let q = into_handle(Box::new(2u64));
+        assert_ne!(p as usize, handle_address(p));
+        assert_ne!(p as usize >> 48, q as usize >> 48);
+        assert_eq!(unsafe { *handle(p) }, 1);
+        release_handle("test", p);
+        release_handle("test", q);
+    }
+
+    #[cfg(feature = "handle-registry")]
+    #[test]
+    fn deleted_handles_are_rejected() {
+        let v = zexe_usize_vector_create();
+        zexe_usize_vector_emplace_back(v, 3);
+        zexe_usize_vector_delete(v);
+        take_last_error();
+
+        assert_eq!(zexe_usize_vector_get(v, 0), 0);
+        assert!(take_last_error().unwrap().contains("already deleted"));
+        zexe_usize_vector_delete(v);
+        assert!(take_last_error().unwrap().contains("already deleted"));
+    }
+
+    #[cfg(feature = "handle-registry")]
+    #[test]
+    fn stale_handles_are_rejected() {
+        let p = into_handle(Box::new([1u64; 4]));
+        let q = into_handle(Box::new([2u64; 4]));
+        drop(take_handle("test", p).unwrap());
+        take_last_error();
+
+        // The tag of another generation, as a stale handle would carry once
+        // its address is reissued.
+        let stale = (q as usize ^ 1 << 48) as *mut [u64; 4];
+        assert_eq!(catch_ffi("test", 0, || unsafe { (*handle(stale))[0] }), 0);
+        assert!(take_last_error().unwrap().contains("is stale"));
+        assert!(take_handle("test", stale).is_none());
+        assert!(take_last_error().unwrap().contains("is stale"));
+
+        // Whether or not the allocator reissued the address of p, the handle
+        // is no longer usable.
+        let r = into_handle(Box::new([3u64; 4]));
+        assert_eq!(catch_ffi("test", 0, || unsafe { (*handle(p))[0] }), 0);
+        assert!(take_last_error().is_some());
+        assert_eq!(unsafe { (*handle(r))[0] }, 3);
+        release_handle("test", q);
+        release_handle("test", r);
+    }
+
+    #[cfg(feature = "handle-registry")]
+    #[test]
+    fn handles_of_another_type_are_rejected() {
+        let p = into_handle(Box::new(1u64));
+        assert_eq!(
+            catch_ffi("test", 0, || unsafe { *handle(p as *const u32) }),
+            0
+        );
+        assert!(take_last_error().unwrap().contains("not a u32"));
+        release_handle("test", p);
+    }
+
+    #[test]
+    fn auxiliary_use_is_scanned_once_per_index() {
+        use algebra::bn_382::fp::Fp;
+
+        let index = into_handle(Box::new(0u64));
+        record_auxiliary_use(index, false);
+        assert!(!index_uses_auxiliary(index, || panic!("scanned again")));
+        assert!(witness_fast_path(false, &[Fp::one()]));
+
+        forget_auxiliary_use(index);
+        assert!(index_uses_auxiliary(index, || true));
+        assert!(index_uses_auxiliary(index, || panic!("scanned again")));
+        assert!(!witness_fast_path(true, &[Fp::one()]));
+        forget_auxiliary_use(index);
+        release_handle("test", index);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::common::*;
+use algebra::bn_382::{fp::Fp, fq::Fq, g::Affine as GAffine, Bn_382};
+use marlin_protocol_dlog::index::{Index as DlogIndex, VerifierIndex as DlogVerifierIndex};
+use marlin_protocol_pairing::index::{Index, VerifierIndex};
+
+// Evaluation domain parameters
+//
+// The parameters of the x, h, k and b domains of an index or verifier index,
+// as the prover and verifier use them, so that frontends do not recompute the
+// roots of unity themselves. The sizes of the h and k domains of an index
+// already have stubs of their own.
+
+macro_rules! domain_stubs {
+    (
+        $F:ty,
+        $Index:ty,
+        domain: $domain:ident,
+        generator: $generator:ident,
+        generator_inv: $generator_inv:ident,
+        size_inv: $size_inv:ident,
+        $(size: $size:ident,)?
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn $generator(i: *const $Index) -> *mut $F {
+            $crate::common::catch_ffi(stringify!($generator), std::ptr::null_mut(), || {
+                let i = $crate::common::handle(i);
+                let x = (unsafe { &*i }).domains.$domain.group_gen;
+                into_handle(Box::new(x))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $generator_inv(i: *const $Index) -> *mut $F {
+            $crate::common::catch_ffi(stringify!($generator_inv), std::ptr::null_mut(), || {
+                let i = $crate::common::handle(i);
+                let x = (unsafe { &*i }).domains.$domain.group_gen_inv;
+                into_handle(Box::new(x))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $size_inv(i: *const $Index) -> *mut $F {
+            $crate::common::catch_ffi(stringify!($size_inv), std::ptr::null_mut(), || {
+                let i = $crate::common::handle(i);
+                let x = (unsafe { &*i }).domains.$domain.size_inv;
+                into_handle(Box::new(x))
+            })
+        }
+
+        $(
+            #[no_mangle]
+            pub extern "C" fn $size(i: *const $Index) -> usize {
+                $crate::common::catch_ffi(stringify!($size), 0, || {
+                    let i = $crate::common::handle(i);
+                    (unsafe { &*i }).domains.$domain.size()
+                })
+            }
+        )?
+    };
+}
+
+// Fp index
+domain_stubs!(
+    Fp,
+    Index<'static, Bn_382>,
+    domain: x,
+    generator: zexe_bn382_fp_index_domain_x_generator,
+    generator_inv: zexe_bn382_fp_index_domain_x_generator_inv,
+    size_inv: zexe_bn382_fp_index_domain_x_size_inv,
+    size: zexe_bn382_fp_index_domain_x_size,
+);
+domain_stubs!(
+    Fp,
+    Index<'static, Bn_382>,
+    domain: h,
+    generator: zexe_bn382_fp_index_domain_h_generator,
+    generator_inv: zexe_bn382_fp_index_domain_h_generator_inv,
+    size_inv: zexe_bn382_fp_index_domain_h_size_inv,
+);
+domain_stubs!(
+    Fp,
+    Index<'static, Bn_382>,
+    domain: k,
+    generator: zexe_bn382_fp_index_domain_k_generator,
+    generator_inv: zexe_bn382_fp_index_domain_k_generator_inv,
+    size_inv: zexe_bn382_fp_index_domain_k_size_inv,
+);
+domain_stubs!(
+    Fp,
+    Index<'static, Bn_382>,
+    domain: b,
+    generator: zexe_bn382_fp_index_domain_b_generator,
+    generator_inv: zexe_bn382_fp_index_domain_b_generator_inv,
+    size_inv: zexe_bn382_fp_index_domain_b_size_inv,
+    size: zexe_bn382_fp_index_domain_b_size,
+);
+
+// Fp verifier index
+domain_stubs!(
+    Fp,
+    VerifierIndex<Bn_382>,
+    domain: x,
+    generator: zexe_bn382_fp_verifier_index_domain_x_generator,
+    generator_inv: zexe_bn382_fp_verifier_index_domain_x_generator_inv,
+    size_inv: zexe_bn382_fp_verifier_index_domain_x_size_inv,
+    size: zexe_bn382_fp_verifier_index_domain_x_size,
+);
+domain_stubs!(
+    Fp,
+    VerifierIndex<Bn_382>,
+    domain: h,
+    generator: zexe_bn382_fp_verifier_index_domain_h_generator,
+    generator_inv: zexe_bn382_fp_verifier_index_domain_h_generator_inv,
+    size_inv: zexe_bn382_fp_verifier_index_domain_h_size_inv,
+    size: zexe_bn382_fp_verifier_index_domain_h_size,
+);
+domain_stubs!(
+    Fp,
+    VerifierIndex<Bn_382>,
+    domain: k,
+    generator: zexe_bn382_fp_verifier_index_domain_k_generator,
+    generator_inv: zexe_bn382_fp_verifier_index_domain_k_generator_inv,
+    size_inv: zexe_bn382_fp_verifier_index_domain_k_size_inv,
+    size: zexe_bn382_fp_verifier_index_domain_k_size,
+);
+domain_stubs!(
+    Fp,
+    VerifierIndex<Bn_382>,
+    domain: b,
+    generator: zexe_bn382_fp_verifier_index_domain_b_generator,
+    generator_inv: zexe_bn382_fp_verifier_index_domain_b_generator_inv,
+    size_inv: zexe_bn382_fp_verifier_index_domain_b_size_inv,
+    size: zexe_bn382_fp_verifier_index_domain_b_size,
+);
+
+// Fq index
+domain_stubs!(
+    Fq,
+    DlogIndex<'static, GAffine>,
+    domain: x,
+    generator: zexe_bn382_fq_index_domain_x_generator,
+    generator_inv: zexe_bn382_fq_index_domain_x_generator_inv,
+    size_inv: zexe_bn382_fq_index_domain_x_size_inv,
+    size: zexe_bn382_fq_index_domain_x_size,
+);
+domain_stubs!(
+    Fq,
+    DlogIndex<'static, GAffine>,
+    domain: h,
+    generator: zexe_bn382_fq_index_domain_h_generator,
+    generator_inv: zexe_bn382_fq_index_domain_h_generator_inv,
+    size_inv: zexe_bn382_fq_index_domain_h_size_inv,
+);
+domain_stubs!(
+    Fq,
+    DlogIndex<'static, GAffine>,
+    domain: k,
+    generator: zexe_bn382_fq_index_domain_k_generator,
+    generator_inv: zexe_bn382_fq_index_domain_k_generator_inv,
+    size_inv: zexe_bn382_fq_index_domain_k_size_inv,
+);
+domain_stubs!(
+    Fq,
+    DlogIndex<'static, GAffine>,
+    domain: b,
+    generator: zexe_bn382_fq_index_domain_b_generator,
+    generator_inv: zexe_bn382_fq_index_domain_b_generator_inv,
+    size_inv: zexe_bn382_fq_index_domain_b_size_inv,
+    size: zexe_bn382_fq_index_domain_b_size,
+);
+
+// Fq verifier index
+domain_stubs!(
+    Fq,
+    DlogVerifierIndex<'static, GAffine>,
+    domain: x,
+    generator: zexe_bn382_fq_verifier_index_domain_x_generator,
+    generator_inv: zexe_bn382_fq_verifier_index_domain_x_generator_inv,
+    size_inv: zexe_bn382_fq_verifier_index_domain_x_size_inv,
+    size: zexe_bn382_fq_verifier_index_domain_x_size,
+);
+domain_stubs!(
+    Fq,
+    DlogVerifierIndex<'static, GAffine>,
+    domain: h,
+    generator: zexe_bn382_fq_verifier_index_domain_h_generator,
+    generator_inv: zexe_bn382_fq_verifier_index_domain_h_generator_inv,
+    size_inv: zexe_bn382_fq_verifier_index_domain_h_size_inv,
+    size: zexe_bn382_fq_verifier_index_domain_h_size,
+);
+domain_stubs!(
+    Fq,
+    DlogVerifierIndex<'static, GAffine>,
+    domain: k,
+    generator: zexe_bn382_fq_verifier_index_domain_k_generator,
+    generator_inv: zexe_bn382_fq_verifier_index_domain_k_generator_inv,
+    size_inv: zexe_bn382_fq_verifier_index_domain_k_size_inv,
+    size: zexe_bn382_fq_verifier_index_domain_k_size,
+);
+domain_stubs!(
+    Fq,
+    DlogVerifierIndex<'static, GAffine>,
+    domain: b,
+    generator: zexe_bn382_fq_verifier_index_domain_b_generator,
+    generator_inv: zexe_bn382_fq_verifier_index_domain_b_generator_inv,
+    size_inv: zexe_bn382_fq_verifier_index_domain_b_size_inv,
+    size: zexe_bn382_fq_verifier_index_domain_b_size,
+);
@@ -0,0 +1,478 @@
+use crate::common::*;
+use algebra::{
+    biginteger::BigInteger,
+    bn_382::{fp::Fp, fq::Fq},
+    fields::{Field, FpParameters, PrimeField},
+    One, Zero,
+};
+use oracle::poseidon::{
+    ArithmeticSponge, ArithmeticSpongeParams, MarlinSpongeConstants as SC, Sponge,
+    SpongeConstants,
+};
+
+// Constraint gadgets
+//
+// Generates the rows of common gadgets and appends them to the caller's A, B
+// and C matrices, one row to each per constraint, so that the row patterns
+// are defined in one place rather than re-derived by every frontend.
+//
+// Variables are indexed as in the matrices, with the constant one at index
+// ONE. Gadgets which need new variables allocate them consecutively from a
+// first free index given by the caller, and return the indexes they
+// allocated. The values of the new variables are computed by the matching
+// witness functions.
+
+pub const ONE: usize = 0;
+
+pub type Row<F> = (Vec<usize>, Vec<F>);
+
+pub struct Rows<'a, F> {
+    pub a: &'a mut Vec<Row<F>>,
+    pub b: &'a mut Vec<Row<F>>,
+    pub c: &'a mut Vec<Row<F>>,
+}
+
+impl<'a, F: PrimeField> Rows<'a, F> {
+    fn push(&mut self, a: Row<F>, b: Row<F>, c: Row<F>) {
+        self.a.push(a);
+        self.b.push(b);
+        self.c.push(c);
+    }
+
+    // x * (x - 1) = 0
+    pub fn boolean(&mut self, x: usize) {
+        self.push(
+            (vec![x], vec![F::one()]),
+            (vec![x, ONE], vec![F::one(), -F::one()]),
+            (vec![], vec![]),
+        );
+    }
+
+    // (sum_i 2^i bits_i) * 1 = x
+    fn packing(&mut self, bits: &[usize], x: usize) {
+        let mut coeffs = Vec::with_capacity(bits.len());
+        let mut pow = F::one();
+        for _ in bits {
+            coeffs.push(pow);
+            pow.double_in_place();
+        }
+        self.push(
+            (bits.to_vec(), coeffs),
+            (vec![ONE], vec![F::one()]),
+            (vec![x], vec![F::one()]),
+        );
+    }
+
+    // Allocates the n bits of x, little-endian, from first_var on. This also
+    // checks that x fits in n bits, and n must be at most the capacity of the
+    // field for the decomposition to be unique.
+    pub fn unpack(&mut self, x: usize, n: usize, first_var: usize) -> Vec<usize> {
+        assert!(n as u32 <= F::Params::CAPACITY);
+        let bits: Vec<usize> = (first_var..first_var + n).collect();
+        for &b in bits.iter() {
+            self.boolean(b);
+        }
+        self.packing(&bits, x);
+        bits
+    }
+
+    // Allocates the number whose little-endian bits are the given variables,
+    // which must already be constrained to be boolean, as first_var.
+    pub fn pack(&mut self, bits: &[usize], first_var: usize) -> usize {
+        assert!(bits.len() as u32 <= F::Params::CAPACITY);
+        self.packing(bits, first_var);
+        first_var
+    }
+
+    // Checks that x fits in n bits, returning the variables of its bits.
+    pub fn range_check(&mut self, x: usize, n: usize, first_var: usize) -> Vec<usize> {
+        self.unpack(x, n, first_var)
+    }
+}
+
+// Poseidon
+//
+// The rows of the hash computed by a fresh oracle::poseidon::ArithmeticSponge
+// which absorbs the inputs and squeezes once, with the given parameters. The
+// gadget follows the sponge step by step: inputs are added to the rate part
+// of the state, and each permutation applies, in every round, the S-box to
+// each element of the state, then the MDS matrix, then the round constants.
+//
+// The S-box x^SPONGE_BOX is computed by square-and-multiply, with one
+// constraint per step. The linear layers are folded into the linear
+// combinations of the following constraints, so they cost nothing.
+//
+// The rows and their witness are computed by the same code, and poseidon_check
+// compares the result against the native sponge, so that a change of
+// parameters or of the sponge is caught rather than silently producing
+// mismatched hashes.
+
+// A linear combination of variables, along with its value.
+#[derive(Clone)]
+struct Lc<F> {
+    terms: Vec<(usize, F)>,
+    value: F,
+}
+
+impl<F: PrimeField> Lc<F> {
+    fn var(v: usize, value: F) -> Self {
+        Lc {
+            terms: vec![(v, F::one())],
+            value,
+        }
+    }
+
+    fn constant(c: F) -> Self {
+        Lc {
+            terms: vec![(ONE, c)],
+            value: c,
+        }
+    }
+
+    fn add_scaled(&mut self, k: F, x: &Lc<F>) {
+        for (v, c) in x.terms.iter() {
+            self.terms.push((*v, k * c));
+        }
+        self.value += &(k * &x.value);
+    }
+
+    fn row(&self) -> Row<F> {
+        let mut terms = self.terms.clone();
+        terms.sort_by(|(i, _), (j, _)| i.cmp(j));
+        let mut row: Row<F> = (vec![], vec![]);
+        for (v, c) in terms {
+            if row.0.last() == Some(&v) {
+                *row.1.last_mut().unwrap() += &c;
+            } else {
+                row.0.push(v);
+                row.1.push(c);
+            }
+        }
+        row
+    }
+}
+
+// Allocates variables from first_var on, recording their values.
+struct Alloc<F> {
+    next_var: usize,
+    values: Vec<F>,
+}
+
+impl<'a, F: PrimeField> Rows<'a, F> {
+    // a * b = c for a new variable c
+    fn mul(&mut self, alloc: &mut Alloc<F>, a: &Lc<F>, b: &Lc<F>) -> Lc<F> {
+        let c = Lc::var(alloc.next_var, a.value * &b.value);
+        alloc.next_var += 1;
+        alloc.values.push(c.value);
+        self.push(a.row(), b.row(), c.row());
+        c
+    }
+
+    fn sbox(&mut self, alloc: &mut Alloc<F>, x: &Lc<F>) -> Lc<F> {
+        let alpha = SC::SPONGE_BOX as u64;
+        let top = 63 - alpha.leading_zeros();
+        let mut acc = x.clone();
+        for i in (0..top).rev() {
+            acc = self.mul(alloc, &acc, &acc);
+            if (alpha >> i) & 1 == 1 {
+                acc = self.mul(alloc, &acc, x);
+            }
+        }
+        acc
+    }
+
+    fn permutation(
+        &mut self,
+        alloc: &mut Alloc<F>,
+        params: &ArithmeticSpongeParams<F>,
+        state: &mut Vec<Lc<F>>,
+    ) {
+        for r in 0..SC::ROUNDS_FULL {
+            let boxed: Vec<Lc<F>> = state.iter().map(|x| self.sbox(alloc, x)).collect();
+            for (i, s) in state.iter_mut().enumerate() {
+                let mut acc = Lc::constant(params.round_constants[r][i]);
+                for (m, x) in params.mds[i].iter().zip(boxed.iter()) {
+                    acc.add_scaled(*m, x);
+                }
+                *s = acc;
+            }
+        }
+    }
+
+    fn poseidon_lc(
+        &mut self,
+        alloc: &mut Alloc<F>,
+        params: &ArithmeticSpongeParams<F>,
+        inputs: &[Lc<F>],
+    ) -> Lc<F> {
+        let rate = SC::SPONGE_WIDTH - SC::SPONGE_CAPACITY;
+        let mut state = vec![Lc::constant(F::zero()); SC::SPONGE_WIDTH];
+        let mut absorbed = 0;
+        for x in inputs {
+            if absorbed == rate {
+                self.permutation(alloc, params, &mut state);
+                absorbed = 0;
+            }
+            state[absorbed].add_scaled(F::one(), x);
+            absorbed += 1;
+        }
+        self.permutation(alloc, params, &mut state);
+        // Pins the output to a variable of its own.
+        self.mul(alloc, &state[0], &Lc::constant(F::one()))
+    }
+
+    // Allocates the variables of the hash of the inputs from first_var on,
+    // the last of which is the output.
+    pub fn poseidon(
+        &mut self,
+        params: &ArithmeticSpongeParams<F>,
+        inputs: &[usize],
+        first_var: usize,
+    ) -> Vec<usize> {
+        assert_eq!(SC::ROUNDS_PARTIAL, 0);
+        let mut alloc = Alloc {
+            next_var: first_var,
+            values: vec![],
+        };
+        let inputs: Vec<_> = inputs.iter().map(|&v| Lc::var(v, F::zero())).collect();
+        self.poseidon_lc(&mut alloc, params, &inputs);
+        (first_var..alloc.next_var).collect()
+    }
+}
+
+// The values of the variables allocated by Rows::poseidon for the given
+// inputs, the last of which is the hash.
+pub fn poseidon_witness<F: PrimeField>(params: &ArithmeticSpongeParams<F>, inputs: &[F]) -> Vec<F> {
+    assert_eq!(SC::ROUNDS_PARTIAL, 0);
+    let (mut a, mut b, mut c) = (vec![], vec![], vec![]);
+    let mut rows = Rows {
+        a: &mut a,
+        b: &mut b,
+        c: &mut c,
+    };
+    let mut alloc = Alloc {
+        next_var: 1,
+        values: vec![],
+    };
+    let inputs: Vec<_> = inputs.iter().map(|&x| Lc::var(1, x)).collect();
+    rows.poseidon_lc(&mut alloc, params, &inputs);
+    alloc.values
+}
+
+// Checks that the gadget's witness agrees with the native sponge, for inputs
+// of every length up to a few permutations.
+pub fn poseidon_check<F: PrimeField>(params: &ArithmeticSpongeParams<F>) -> bool {
+    let rate = SC::SPONGE_WIDTH - SC::SPONGE_CAPACITY;
+    (0..=3 * rate).all(|n| {
+        let inputs: Vec<F> = (0..n).map(|i| F::from((i + 1) as u64)).collect();
+        let mut sponge = ArithmeticSponge::<F, SC>::new();
+        sponge.absorb(params, &inputs);
+        poseidon_witness(params, &inputs).last() == Some(&sponge.squeeze(params))
+    })
+}
+
+// The bits of x, little-endian, or None if x does not fit in n bits.
+pub fn unpack_witness<F: PrimeField>(x: F, n: usize) -> Option<Vec<F>> {
+    let x = x.into_repr();
+    if (n..F::size_in_bits()).any(|i| x.get_bit(i)) {
+        return None;
+    }
+    Some(
+        (0..n)
+            .map(|i| if x.get_bit(i) { F::one() } else { F::zero() })
+            .collect(),
+    )
+}
+
+pub fn pack_witness<F: PrimeField>(bits: &[F]) -> F {
+    bits.iter().rev().fold(F::zero(), |acc, b| acc.double() + b)
+}
+
+macro_rules! gadget_stubs {
+    (
+        $F:ty,
+        boolean: $boolean:ident,
+        unpack: $unpack:ident,
+        pack: $pack:ident,
+        range_check: $range_check:ident,
+        unpack_witness: $unpack_witness:ident,
+        pack_witness: $pack_witness:ident,
+        poseidon_params: $params:path,
+        poseidon: $poseidon:ident,
+        poseidon_witness: $poseidon_witness:ident,
+        poseidon_check: $poseidon_check:ident,
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn $boolean(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            x: usize,
+        ) {
+            $crate::common::catch_ffi(stringify!($boolean), (), || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let mut rows = Rows {
+                    a: unsafe { &mut *a },
+                    b: unsafe { &mut *b },
+                    c: unsafe { &mut *c },
+                };
+                rows.boolean(x);
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $unpack(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            x: usize,
+            n: usize,
+            first_var: usize,
+        ) -> *mut Vec<usize> {
+            catch_ffi(stringify!($unpack), std::ptr::null_mut(), || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let mut rows = Rows {
+                    a: unsafe { &mut *a },
+                    b: unsafe { &mut *b },
+                    c: unsafe { &mut *c },
+                };
+                $crate::common::into_handle(Box::new(rows.unpack(x, n, first_var)))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $pack(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            bits: *const Vec<usize>,
+            first_var: usize,
+        ) -> usize {
+            catch_ffi(stringify!($pack), 0, || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let bits = $crate::common::handle(bits);
+                let mut rows = Rows {
+                    a: unsafe { &mut *a },
+                    b: unsafe { &mut *b },
+                    c: unsafe { &mut *c },
+                };
+                rows.pack(unsafe { &*bits }, first_var)
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $range_check(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            x: usize,
+            n: usize,
+            first_var: usize,
+        ) -> *mut Vec<usize> {
+            catch_ffi(stringify!($range_check), std::ptr::null_mut(), || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let mut rows = Rows {
+                    a: unsafe { &mut *a },
+                    b: unsafe { &mut *b },
+                    c: unsafe { &mut *c },
+                };
+                $crate::common::into_handle(Box::new(rows.range_check(x, n, first_var)))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $unpack_witness(x: *const $F, n: usize) -> *mut Vec<$F> {
+            $crate::common::catch_ffi(stringify!($unpack_witness), std::ptr::null_mut(), || {
+                let x = $crate::common::handle(x);
+                match unpack_witness(unsafe { *x }, n) {
+                    Some(bits) => $crate::common::into_handle(Box::new(bits)),
+                    None => null_with_error(format!(
+                        "{}: The value does not fit in {} bits",
+                        stringify!($unpack_witness),
+                        n
+                    )),
+                }
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $pack_witness(bits: *const Vec<$F>) -> *mut $F {
+            $crate::common::catch_ffi(stringify!($pack_witness), std::ptr::null_mut(), || {
+                let bits = $crate::common::handle(bits);
+                $crate::common::into_handle(Box::new(pack_witness(unsafe { &*bits })))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $poseidon(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            inputs: *const Vec<usize>,
+            first_var: usize,
+        ) -> *mut Vec<usize> {
+            catch_ffi(stringify!($poseidon), std::ptr::null_mut(), || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let inputs = $crate::common::handle(inputs);
+                let mut rows = Rows {
+                    a: unsafe { &mut *a },
+                    b: unsafe { &mut *b },
+                    c: unsafe { &mut *c },
+                };
+                let vars = rows.poseidon(&$params(), unsafe { &*inputs }, first_var);
+                $crate::common::into_handle(Box::new(vars))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $poseidon_witness(inputs: *const Vec<$F>) -> *mut Vec<$F> {
+            catch_ffi(stringify!($poseidon_witness), std::ptr::null_mut(), || {
+                let inputs = $crate::common::handle(inputs);
+                $crate::common::into_handle(Box::new(poseidon_witness(&$params(), unsafe { &*inputs })))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $poseidon_check() -> bool {
+            catch_ffi(stringify!($poseidon_check), false, || poseidon_check(&$params()))
+        }
+    };
+}
+
+gadget_stubs!(
+    Fp,
+    boolean: zexe_bn382_fp_gadget_boolean,
+    unpack: zexe_bn382_fp_gadget_unpack,
+    pack: zexe_bn382_fp_gadget_pack,
+    range_check: zexe_bn382_fp_gadget_range_check,
+    unpack_witness: zexe_bn382_fp_gadget_unpack_witness,
+    pack_witness: zexe_bn382_fp_gadget_pack_witness,
+    poseidon_params: oracle::bn_382::fp::params,
+    poseidon: zexe_bn382_fp_gadget_poseidon,
+    poseidon_witness: zexe_bn382_fp_gadget_poseidon_witness,
+    poseidon_check: zexe_bn382_fp_gadget_poseidon_check,
+);
+
+gadget_stubs!(
+    Fq,
+    boolean: zexe_bn382_fq_gadget_boolean,
+    unpack: zexe_bn382_fq_gadget_unpack,
+    pack: zexe_bn382_fq_gadget_pack,
+    range_check: zexe_bn382_fq_gadget_range_check,
+    unpack_witness: zexe_bn382_fq_gadget_unpack_witness,
+    pack_witness: zexe_bn382_fq_gadget_pack_witness,
+    poseidon_params: oracle::bn_382::fq::params,
+    poseidon: zexe_bn382_fq_gadget_poseidon,
+    poseidon_witness: zexe_bn382_fq_gadget_poseidon_witness,
+    poseidon_check: zexe_bn382_fq_gadget_poseidon_check,
+);
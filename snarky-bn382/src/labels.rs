@@ -0,0 +1,522 @@
+use crate::bn382_dlog::zexe_bn382_fq_index_create;
+use crate::bn382_pairing::zexe_bn382_fp_index_create;
+use crate::common::*;
+use crate::gadgets::Row;
+use algebra::{
+    bn_382::{fp::Fp, fq::Fq, g::Affine as GAffine, Bn_382},
+    fields::{Field, PrimeField},
+};
+use commitment_dlog::srs::SRS;
+use commitment_pairing::urs::URS;
+use ff_fft::EvaluationDomain;
+use marlin_circuits::domains::EvaluationDomains;
+use marlin_protocol_dlog::index::Index as DlogIndex;
+use marlin_protocol_pairing::index::Index;
+use sprs::CsMat;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+// Constraint labels
+//
+// Names for the rows of a constraint system, given in the same order as the
+// rows of its matrices, so that an unsatisfied constraint is reported as
+// "constraint 'transaction.signature.check'" rather than by its number.
+// Frontends typically build a label from the namespaces the constraint was
+// generated in.
+//
+// The constraint_system_check stubs report the first unsatisfied row of a
+// system by its label. With the constraint-labels feature, an index created
+// with the labelled index_create stub also keeps its rows and labels, and the
+// proof_create stubs check the witness against them before proving, so that
+// an unsatisfiable witness fails with the label of the offending constraint
+// instead of producing a proof which the verifier rejects. Without the
+// feature the labels are only used by the checker.
+//
+// The index_check_witness stubs check a witness against the compiled matrices
+// of an index instead, which are the ones the prover uses, and the
+// index_matrix_apply stubs return the products Az, Bz and Cz of the matrices
+// with the witness, for the rows which the checks report.
+
+#[no_mangle]
+pub extern "C" fn zexe_constraint_labels_create() -> *mut Vec<String> {
+    catch_ffi("zexe_constraint_labels_create", std::ptr::null_mut(), || {
+        into_handle(Box::new(vec![]))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_constraint_labels_push(v: *mut Vec<String>, label: *const c_char) {
+    catch_ffi("zexe_constraint_labels_push", (), || {
+        let v = handle(v);
+        let v = unsafe { &mut *v };
+        let label = unsafe { CStr::from_ptr(label) };
+        v.push(label.to_string_lossy().into_owned());
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_constraint_labels_length(v: *const Vec<String>) -> usize {
+    catch_ffi("zexe_constraint_labels_length", 0, || {
+        let v = handle(v);
+        (unsafe { &*v }).len()
+    })
+}
+
+crate::delete_stub!(zexe_constraint_labels_delete, Vec<String>);
+
+pub fn constraint_name(labels: Option<&[String]>, i: usize) -> String {
+    match labels.and_then(|l| l.get(i)) {
+        Some(l) => format!("constraint '{}'", l),
+        None => format!("constraint {}", i),
+    }
+}
+
+fn row_value<F: Field>(row: &Row<F>, assignment: &[F]) -> Result<F, usize> {
+    let mut res = F::zero();
+    for (&i, c) in row.0.iter().zip(row.1.iter()) {
+        res += &(*assignment.get(i).ok_or(i)? * c);
+    }
+    Ok(res)
+}
+
+// Checks that the assignment of the constant one, then the primary input,
+// then the auxiliary input satisfies every row, and otherwise describes the
+// first row which it does not.
+pub fn check_constraints<F: Field>(
+    a: &[Row<F>],
+    b: &[Row<F>],
+    c: &[Row<F>],
+    labels: Option<&[String]>,
+    primary_input: &[F],
+    auxiliary_input: &[F],
+) -> Result<(), String> {
+    if b.len() != a.len() || c.len() != a.len() {
+        return Err(format!(
+            "The matrices have {}, {} and {} rows.",
+            a.len(),
+            b.len(),
+            c.len()
+        ));
+    }
+    let mut assignment = vec![F::one()];
+    assignment.extend_from_slice(primary_input);
+    assignment.extend_from_slice(auxiliary_input);
+    for (i, ((a, b), c)) in a.iter().zip(b.iter()).zip(c.iter()).enumerate() {
+        let values = row_value(a, &assignment)
+            .and_then(|a| Ok((a, row_value(b, &assignment)?, row_value(c, &assignment)?)));
+        match values {
+            Ok((a, b, c)) if a * &b == c => (),
+            Ok(_) => return Err(format!("The {} is not satisfied.", constraint_name(labels, i))),
+            Err(v) => {
+                return Err(format!(
+                    "The {} uses variable {}, which is not assigned.",
+                    constraint_name(labels, i),
+                    v
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+// The witness of a proof, laid out as the proof_create stubs lay it out, or a
+// description of why the inputs do not fit the domains.
+pub fn index_witness<F: PrimeField>(
+    domains: EvaluationDomains<F>,
+    primary_input: &Vec<F>,
+    auxiliary_input: &Vec<F>,
+) -> Result<Vec<F>, String> {
+    let (h, x) = (domains.h.size(), domains.x.size());
+    if primary_input.len() >= x {
+        return Err(format!(
+            "{} primary inputs for an x domain of size {}.",
+            primary_input.len(),
+            x
+        ));
+    }
+    if auxiliary_input.len() > h - x {
+        return Err(format!(
+            "{} auxiliary inputs for {} auxiliary variables.",
+            auxiliary_input.len(),
+            h - x
+        ));
+    }
+    Ok(prepare_witness(domains, primary_input, auxiliary_input))
+}
+
+// The product of a compiled matrix with a witness, with one entry per row.
+pub fn matrix_apply<F: Field>(m: &CsMat<F>, witness: &[F]) -> Vec<F> {
+    m.outer_iterator()
+        .map(|row| row.iter().fold(F::zero(), |acc, (j, c)| acc + &(witness[j] * c)))
+        .collect()
+}
+
+// The first row on which Az * Bz differs from Cz, if any.
+pub fn first_failing_row<F: Field>(m: [&CsMat<F>; 3], witness: &[F]) -> Option<usize> {
+    let [a, b, c] = m;
+    let (az, bz, cz) = (
+        matrix_apply(a, witness),
+        matrix_apply(b, witness),
+        matrix_apply(c, witness),
+    );
+    az.iter()
+        .zip(bz.iter())
+        .zip(cz.iter())
+        .position(|((a, b), c)| *a * b != *c)
+}
+
+// Rows and labels kept for an index, keyed by its address.
+#[cfg(feature = "constraint-labels")]
+mod systems {
+    use lazy_static::lazy_static;
+    use std::any::Any;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    pub fn get() -> &'static Mutex<HashMap<usize, Box<dyn Any + Send>>> {
+        lazy_static! {
+            static ref SYSTEMS: Mutex<HashMap<usize, Box<dyn Any + Send>>> =
+                Mutex::new(HashMap::new());
+        }
+        &SYSTEMS
+    }
+}
+
+#[cfg(feature = "constraint-labels")]
+struct LabelledSystem<F> {
+    a: Vec<Row<F>>,
+    b: Vec<Row<F>>,
+    c: Vec<Row<F>>,
+    labels: Vec<String>,
+}
+
+#[cfg(not(feature = "constraint-labels"))]
+fn keep_labelled_system<I, F>(_index: *const I, _m: [&[Row<F>]; 3], _labels: &[String]) {}
+
+#[cfg(feature = "constraint-labels")]
+fn keep_labelled_system<I, F: Clone + Send + 'static>(
+    index: *const I,
+    m: [&[Row<F>]; 3],
+    labels: &[String],
+) {
+    let [a, b, c] = m;
+    systems::get().lock().unwrap().insert(
+        handle_address(index),
+        Box::new(LabelledSystem {
+            a: a.to_vec(),
+            b: b.to_vec(),
+            c: c.to_vec(),
+            labels: labels.to_vec(),
+        }),
+    );
+}
+
+// Checks a witness against the rows kept for the index, if any. Always Ok
+// without the constraint-labels feature.
+#[cfg(not(feature = "constraint-labels"))]
+pub fn check_labelled_system<I, F>(
+    _index: *const I,
+    _primary_input: &[F],
+    _auxiliary_input: &[F],
+) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "constraint-labels")]
+pub fn check_labelled_system<I, F: Field>(
+    index: *const I,
+    primary_input: &[F],
+    auxiliary_input: &[F],
+) -> Result<(), String> {
+    let systems = systems::get().lock().unwrap();
+    match systems
+        .get(&handle_address(index))
+        .and_then(|s| s.downcast_ref::<LabelledSystem<F>>())
+    {
+        Some(s) => check_constraints(
+            &s.a,
+            &s.b,
+            &s.c,
+            Some(&s.labels),
+            primary_input,
+            auxiliary_input,
+        ),
+        None => Ok(()),
+    }
+}
+
+// The labels kept for an index, if any. Always None without the
+// constraint-labels feature.
+#[cfg(not(feature = "constraint-labels"))]
+pub fn labels_of<I, F>(_index: *const I) -> Option<Vec<String>> {
+    None
+}
+
+#[cfg(feature = "constraint-labels")]
+pub fn labels_of<I, F: 'static>(index: *const I) -> Option<Vec<String>> {
+    let systems = systems::get().lock().unwrap();
+    systems
+        .get(&handle_address(index))
+        .and_then(|s| s.downcast_ref::<LabelledSystem<F>>())
+        .map(|s| s.labels.clone())
+}
+
+// Drops the rows kept for an index, when it is deleted.
+#[cfg(not(feature = "constraint-labels"))]
+pub fn forget_labelled_system<I>(_index: *const I) {}
+
+#[cfg(feature = "constraint-labels")]
+pub fn forget_labelled_system<I>(index: *const I) {
+    systems::get().lock().unwrap().remove(&handle_address(index));
+}
+
+// Forgets the rows and labels kept for all indexes.
+#[cfg(not(feature = "constraint-labels"))]
+pub(crate) fn forget_labelled_systems() {}
+
+#[cfg(feature = "constraint-labels")]
+pub(crate) fn forget_labelled_systems() {
+    systems::get().lock().unwrap().clear();
+}
+
+macro_rules! constraint_labels_stubs {
+    (
+        $F:ty,
+        $Urs:ty,
+        $Index:ty,
+        index_create: $index_create:path,
+        check: $check:ident,
+        labelled_index_create: $labelled_index_create:ident,
+    ) => {
+        // Returns false, with the last error naming the first unsatisfied
+        // constraint, if the inputs do not satisfy the system. labels may be
+        // null.
+        #[no_mangle]
+        pub extern "C" fn $check(
+            a: *const Vec<Row<$F>>,
+            b: *const Vec<Row<$F>>,
+            c: *const Vec<Row<$F>>,
+            labels: *const Vec<String>,
+            primary_input: *const Vec<$F>,
+            auxiliary_input: *const Vec<$F>,
+        ) -> bool {
+            $crate::common::catch_ffi(stringify!($check), false, || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let labels = $crate::common::handle(labels);
+                let primary_input = $crate::common::handle(primary_input);
+                let auxiliary_input = $crate::common::handle(auxiliary_input);
+                let labels = if labels.is_null() {
+                    None
+                } else {
+                    Some(&(unsafe { &*labels })[..])
+                };
+                match check_constraints(
+                    unsafe { &*a },
+                    unsafe { &*b },
+                    unsafe { &*c },
+                    labels,
+                    unsafe { &*primary_input },
+                    unsafe { &*auxiliary_input },
+                ) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", stringify!($check), e));
+                        false
+                    }
+                }
+            })
+        }
+
+        // As the index_create stub, with one label per row of the matrices.
+        #[no_mangle]
+        pub extern "C" fn $labelled_index_create(
+            a: *mut Vec<Row<$F>>,
+            b: *mut Vec<Row<$F>>,
+            c: *mut Vec<Row<$F>>,
+            labels: *const Vec<String>,
+            vars: usize,
+            public_inputs: usize,
+            urs: *mut $Urs,
+        ) -> *mut $Index {
+            $crate::common::catch_ffi(stringify!($labelled_index_create), std::ptr::null_mut(), || {
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let labels = $crate::common::handle(labels);
+                let urs = $crate::common::handle(urs);
+                let labels = unsafe { &*labels };
+                let rows = (unsafe { &*a }).len();
+                if labels.len() != rows {
+                    return null_with_error(format!(
+                        "{}: {} labels for {} constraints",
+                        stringify!($labelled_index_create),
+                        labels.len(),
+                        rows
+                    ));
+                }
+                let index = $index_create(a, b, c, vars, public_inputs, urs);
+                if !index.is_null() {
+                    keep_labelled_system(
+                        index,
+                        [unsafe { &*a }, unsafe { &*b }, unsafe { &*c }],
+                        labels,
+                    );
+                }
+                index
+            })
+        }
+    };
+}
+
+constraint_labels_stubs!(
+    Fp,
+    URS<Bn_382>,
+    Index<'static, Bn_382>,
+    index_create: zexe_bn382_fp_index_create,
+    check: zexe_bn382_fp_constraint_system_check,
+    labelled_index_create: zexe_bn382_fp_index_create_labelled,
+);
+
+constraint_labels_stubs!(
+    Fq,
+    SRS<GAffine>,
+    DlogIndex<'static, GAffine>,
+    index_create: zexe_bn382_fq_index_create,
+    check: zexe_bn382_fq_constraint_system_check,
+    labelled_index_create: zexe_bn382_fq_index_create_labelled,
+);
+
+macro_rules! index_witness_stubs {
+    (
+        $F:ty,
+        $Index:ty,
+        check_witness: $check_witness:ident,
+        first_failing_row: $first_failing_row:ident,
+        matrix_apply: $matrix_apply:ident,
+    ) => {
+        // The first row of the compiled matrices which the witness does not
+        // satisfy, or -1 if it satisfies them all or the inputs do not fit the
+        // index, in which case the last error is set.
+        #[no_mangle]
+        pub extern "C" fn $first_failing_row(
+            index: *const $Index,
+            primary_input: *const Vec<$F>,
+            auxiliary_input: *const Vec<$F>,
+        ) -> i64 {
+            const NAME: &str = stringify!($first_failing_row);
+            catch_ffi(NAME, -1, || {
+                let index = $crate::common::handle(index);
+                let primary_input = $crate::common::handle(primary_input);
+                let auxiliary_input = $crate::common::handle(auxiliary_input);
+                let index = unsafe { &*index };
+                let witness = match index_witness(
+                    index.domains,
+                    unsafe { &*primary_input },
+                    unsafe { &*auxiliary_input },
+                ) {
+                    Ok(w) => w,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", NAME, e));
+                        return -1;
+                    }
+                };
+                let c = &index.compiled;
+                let m = [&c[0].constraints, &c[1].constraints, &c[2].constraints];
+                first_failing_row(m, &witness).map_or(-1, |i| i as i64)
+            })
+        }
+
+        // Returns false, with the last error naming the first failing row, by
+        // its label if the index was created with the labelled index_create
+        // stub and the constraint-labels feature.
+        #[no_mangle]
+        pub extern "C" fn $check_witness(
+            index: *const $Index,
+            primary_input: *const Vec<$F>,
+            auxiliary_input: *const Vec<$F>,
+        ) -> bool {
+            const NAME: &str = stringify!($check_witness);
+            catch_ffi(NAME, false, || {
+                let index = $crate::common::handle(index);
+                let primary_input = $crate::common::handle(primary_input);
+                let auxiliary_input = $crate::common::handle(auxiliary_input);
+                let index_ = unsafe { &*index };
+                let witness = match index_witness(
+                    index_.domains,
+                    unsafe { &*primary_input },
+                    unsafe { &*auxiliary_input },
+                ) {
+                    Ok(w) => w,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", NAME, e));
+                        return false;
+                    }
+                };
+                let c = &index_.compiled;
+                let m = [&c[0].constraints, &c[1].constraints, &c[2].constraints];
+                match first_failing_row(m, &witness) {
+                    None => true,
+                    Some(i) => {
+                        let labels = labels_of::<_, $F>(index);
+                        set_last_error(format!(
+                            "{}: The {} is not satisfied.",
+                            NAME,
+                            constraint_name(labels.as_deref(), i)
+                        ));
+                        false
+                    }
+                }
+            })
+        }
+
+        // The product of compiled matrix A (0), B (1) or C (2) of the index
+        // with the witness, with one entry per row.
+        #[no_mangle]
+        pub extern "C" fn $matrix_apply(
+            index: *const $Index,
+            matrix: usize,
+            primary_input: *const Vec<$F>,
+            auxiliary_input: *const Vec<$F>,
+        ) -> *mut Vec<$F> {
+            const NAME: &str = stringify!($matrix_apply);
+            catch_ffi(NAME, std::ptr::null_mut(), || {
+                let index = $crate::common::handle(index);
+                let primary_input = $crate::common::handle(primary_input);
+                let auxiliary_input = $crate::common::handle(auxiliary_input);
+                let index = unsafe { &*index };
+                if matrix > 2 {
+                    return null_with_error(format!("{}: No matrix {}", NAME, matrix));
+                }
+                match index_witness(
+                    index.domains,
+                    unsafe { &*primary_input },
+                    unsafe { &*auxiliary_input },
+                ) {
+                    Ok(w) => into_handle(Box::new(matrix_apply(
+                        &index.compiled[matrix].constraints,
+                        &w,
+                    ))),
+                    Err(e) => null_with_error(format!("{}: {}", NAME, e)),
+                }
+            })
+        }
+    };
+}
+
+index_witness_stubs!(
+    Fp,
+    Index<'static, Bn_382>,
+    check_witness: zexe_bn382_fp_index_check_witness,
+    first_failing_row: zexe_bn382_fp_index_first_failing_row,
+    matrix_apply: zexe_bn382_fp_index_matrix_apply,
+);
+
+index_witness_stubs!(
+    Fq,
+    DlogIndex<'static, GAffine>,
+    check_witness: zexe_bn382_fq_index_check_witness,
+    first_failing_row: zexe_bn382_fq_index_first_failing_row,
+    matrix_apply: zexe_bn382_fq_index_matrix_apply,
+);
@@ -1,9 +1,26 @@
 #![allow(non_snake_case)]
 extern crate libc;
 
+pub mod abi;
+pub mod bn382_bench;
+pub mod bn382_bls;
+pub mod bn382_cycle;
 pub mod bn382_dlog;
+pub mod bn382_hash;
+pub mod bn382_kzg;
 pub mod bn382_pairing;
+pub mod bn382_transcript;
+pub mod bn382_vrf;
 pub mod common;
+pub mod domains;
+pub mod gadgets;
+pub mod labels;
+pub mod lifecycle;
+pub mod merge;
+pub mod params;
+pub mod pasta;
+#[cfg(feature = "test-params")]
+pub mod test_params;
 pub mod tweedledee;
 pub mod tweedledee_plonk;
 pub mod tweedledum;
@@ -11,13 +28,21 @@ pub mod tweedledum_plonk;
 
 use algebra::{
     biginteger::{BigInteger, BigInteger256, BigInteger384},
-    bn_382::fp::Fp,
+    bn_382::{
+        fp::Fp,
+        g::{Affine as GAffine, Bn_382GParameters},
+    },
 };
 
+use crate::common::{
+    catch_ffi, check_radix, handle, into_handle, null_with_error, print_line, read_sponge,
+    release_handle, write_sponge,
+};
 use num_bigint::BigUint;
 use oracle::{
     self, poseidon,
     poseidon::{MarlinSpongeConstants as SC, Sponge},
+    sponge::{DefaultFqSponge, FqSponge},
 };
 
 // Bigint stubs
@@ -44,41 +69,63 @@ fn biginteger384_of_bigint(x: &BigUint) -> BigInteger384 {
     BigInteger384(*limbs)
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bigint384_of_decimal_string(s: *const i8) -> *mut BigInteger384 {
-    let c_str: &std::ffi::CStr = unsafe { std::ffi::CStr::from_ptr(s) };
-    let s_: &[u8] = c_str.to_bytes();
-    let res = match BigUint::parse_bytes(s_, 10) {
+fn biginteger384_of_digits(s: &[u8], radix: u32) -> Result<BigInteger384, String> {
+    check_radix(radix)?;
+    let res = match BigUint::parse_bytes(s, radix) {
         Some(x) => x,
-        None => panic!("zexe_bigint384_of_numeral: Could not convert numeral."),
+        None => return Err(format!("Could not convert numeral in base {}.", radix)),
     };
-    return Box::into_raw(Box::new(biginteger384_of_bigint(&res)));
+    if res.bits() > BIGINT384_NUM_BITS as usize {
+        return Err(format!("Value does not fit in {} bits.", BIGINT384_NUM_BITS));
+    }
+    Ok(biginteger384_of_bigint(&res))
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_of_decimal_string(s: *const i8) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_of_decimal_string", std::ptr::null_mut(), || {
+        let c_str: &std::ffi::CStr = unsafe { std::ffi::CStr::from_ptr(s) };
+        let s_: &[u8] = c_str.to_bytes();
+        match biginteger384_of_digits(s_, 10) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint384_of_decimal_string: {}", e)),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_num_limbs() -> i32 {
-    // HACK: Manually compute the number of limbs.
-    return (BIGINT384_NUM_BITS + BIGINT384_LIMB_BITS - 1) / BIGINT384_LIMB_BITS;
+    catch_ffi("zexe_bigint384_num_limbs", 0, || {
+        // HACK: Manually compute the number of limbs.
+        return (BIGINT384_NUM_BITS + BIGINT384_LIMB_BITS - 1) / BIGINT384_LIMB_BITS;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_to_data(x: *mut BigInteger384) -> *mut u64 {
-    let x_ = unsafe { &mut (*x) };
-    return (*x_).0.as_mut_ptr();
+    catch_ffi("zexe_bigint384_to_data", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        return (*x_).0.as_mut_ptr();
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_of_data(x: *mut u64) -> *mut BigInteger384 {
-    let x_ = unsafe { std::slice::from_raw_parts(x, BIGINT384_NUM_LIMBS as usize) };
-    let mut ret: std::boxed::Box<BigInteger384> = Box::new(Default::default());
-    (*ret).0.copy_from_slice(x_);
-    return Box::into_raw(ret);
+    catch_ffi("zexe_bigint384_of_data", std::ptr::null_mut(), || {
+        let x_ = unsafe { std::slice::from_raw_parts(x, BIGINT384_NUM_LIMBS as usize) };
+        let mut ret: std::boxed::Box<BigInteger384> = Box::new(Default::default());
+        (*ret).0.copy_from_slice(x_);
+        return into_handle(ret);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_bytes_per_limb() -> i32 {
-    // HACK: Manually compute the bytes per limb.
-    return BIGINT384_LIMB_BITS / 8;
+    catch_ffi("zexe_bigint384_bytes_per_limb", 0, || {
+        // HACK: Manually compute the bytes per limb.
+        return BIGINT384_LIMB_BITS / 8;
+    })
 }
 
 #[no_mangle]
@@ -86,10 +133,14 @@ pub extern "C" fn zexe_bigint384_div(
     x: *const BigInteger384,
     y: *const BigInteger384,
 ) -> *mut BigInteger384 {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let res = bigint_of_biginteger384(&x_) / &bigint_of_biginteger384(&y_);
-    return Box::into_raw(Box::new(biginteger384_of_bigint(&res)));
+    catch_ffi("zexe_bigint384_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let res = bigint_of_biginteger384(&x_) / &bigint_of_biginteger384(&y_);
+        return into_handle(Box::new(biginteger384_of_bigint(&res)));
+    })
 }
 
 #[no_mangle]
@@ -98,44 +149,137 @@ pub extern "C" fn zexe_bigint384_of_numeral(
     len: u32,
     base: u32,
 ) -> *mut BigInteger384 {
-    let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
-    let res = match BigUint::parse_bytes(s_, base) {
-        Some(x) => x,
-        None => panic!("zexe_bigint384_of_numeral: Could not convert numeral."),
-    };
-    return Box::into_raw(Box::new(biginteger384_of_bigint(&res)));
+    catch_ffi("zexe_bigint384_of_numeral", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        match biginteger384_of_digits(s_, base) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint384_of_numeral: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_of_hex(s: *const u8, len: u32) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_of_hex", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        let s_ = if s_.starts_with(b"0x") || s_.starts_with(b"0X") {
+            &s_[2..]
+        } else {
+            s_
+        };
+        match biginteger384_of_digits(s_, 16) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint384_of_hex: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_of_bytes_le(s: *const u8, len: u32) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_of_bytes_le", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        let res = BigUint::from_bytes_le(s_);
+        if res.bits() > BIGINT384_NUM_BITS as usize {
+            return null_with_error(format!(
+                "zexe_bigint384_of_bytes_le: Value does not fit in {} bits.",
+                BIGINT384_NUM_BITS
+            ));
+        }
+        into_handle(Box::new(biginteger384_of_bigint(&res)))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_compare(x: *const BigInteger384, y: *const BigInteger384) -> u8 {
-    let _x = unsafe { &(*x) };
-    let _y = unsafe { &(*y) };
-    if _x < _y {
-        255
-    } else if _x == _y {
-        0
-    } else {
-        1
-    }
+    catch_ffi("zexe_bigint384_compare", 0, || {
+        let x = handle(x);
+        let y = handle(y);
+        let _x = unsafe { &(*x) };
+        let _y = unsafe { &(*y) };
+        if _x < _y {
+            255
+        } else if _x == _y {
+            0
+        } else {
+            1
+        }
+    })
+}
+
+// Returns -1, 0 or 1 as x is less than, equal to or greater than y.
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_cmp(x: *const BigInteger384, y: *const BigInteger384) -> i32 {
+    catch_ffi("zexe_bigint384_cmp", 0, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        match x_.cmp(y_) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bigint384_test_bit(x: *const BigInteger384, i: i32) -> bool {
-    let _x = unsafe { &(*x) };
-    return _x.get_bit(i as usize);
+pub extern "C" fn zexe_bigint384_is_zero(x: *const BigInteger384) -> bool {
+    catch_ffi("zexe_bigint384_is_zero", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_zero();
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bigint384_delete(x: *mut BigInteger384) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bigint384_min(x: *const BigInteger384, y: *const BigInteger384) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_min", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return into_handle(Box::new(*std::cmp::min(x_, y_)));
+    })
 }
 
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_max(x: *const BigInteger384, y: *const BigInteger384) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_max", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return into_handle(Box::new(*std::cmp::max(x_, y_)));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_copy(x: *const BigInteger384) -> *mut BigInteger384 {
+    catch_ffi("zexe_bigint384_copy", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(*x_));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint384_test_bit(x: *const BigInteger384, i: i32) -> bool {
+    catch_ffi("zexe_bigint384_test_bit", false, || {
+        let x = handle(x);
+        let _x = unsafe { &(*x) };
+        return _x.get_bit(i as usize);
+    })
+}
+
+crate::delete_stub!(zexe_bigint384_delete, BigInteger384);
+
 #[no_mangle]
 pub extern "C" fn zexe_bigint384_print(x: *const BigInteger384) {
-    let x_ = unsafe { &(*x) };
-    println!("{}", *x_);
+    catch_ffi("zexe_bigint384_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", *x_));
+    })
 }
 
 #[no_mangle]
@@ -143,15 +287,18 @@ pub extern "C" fn zexe_bigint384_find_wnaf(
     _size: usize,
     x: *const BigInteger384,
 ) -> *const Vec<i64> {
-    // FIXME:
-    // - as it stands, we have to ignore the first parameter
-    // - in snarky the return type will be a Long_vector.t, which is a C++ vector,
-    //   not a rust one
-    if true {
-        panic!("zexe_bigint384_find_wnaf is not implemented");
-    }
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.find_wnaf()));
+    catch_ffi("zexe_bigint384_find_wnaf", std::ptr::null(), || {
+        let x = handle(x);
+        // FIXME:
+        // - as it stands, we have to ignore the first parameter
+        // - in snarky the return type will be a Long_vector.t, which is a C++ vector,
+        //   not a rust one
+        if true {
+            panic!("zexe_bigint384_find_wnaf is not implemented");
+        }
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.find_wnaf()));
+    })
 }
 
 const BIGINT256_NUM_BITS: i32 = 256;
@@ -176,41 +323,63 @@ fn biginteger256_of_bigint(x: &BigUint) -> BigInteger256 {
     BigInteger256(*limbs)
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_bigint256_of_decimal_string(s: *const i8) -> *mut BigInteger256 {
-    let c_str: &std::ffi::CStr = unsafe { std::ffi::CStr::from_ptr(s) };
-    let s_: &[u8] = c_str.to_bytes();
-    let res = match BigUint::parse_bytes(s_, 10) {
+fn biginteger256_of_digits(s: &[u8], radix: u32) -> Result<BigInteger256, String> {
+    check_radix(radix)?;
+    let res = match BigUint::parse_bytes(s, radix) {
         Some(x) => x,
-        None => panic!("zexe_bigint256_of_numeral: Could not convert numeral."),
+        None => return Err(format!("Could not convert numeral in base {}.", radix)),
     };
-    return Box::into_raw(Box::new(biginteger256_of_bigint(&res)));
+    if res.bits() > BIGINT256_NUM_BITS as usize {
+        return Err(format!("Value does not fit in {} bits.", BIGINT256_NUM_BITS));
+    }
+    Ok(biginteger256_of_bigint(&res))
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_of_decimal_string(s: *const i8) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_of_decimal_string", std::ptr::null_mut(), || {
+        let c_str: &std::ffi::CStr = unsafe { std::ffi::CStr::from_ptr(s) };
+        let s_: &[u8] = c_str.to_bytes();
+        match biginteger256_of_digits(s_, 10) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint256_of_decimal_string: {}", e)),
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_num_limbs() -> i32 {
-    // HACK: Manually compute the number of limbs.
-    return (BIGINT256_NUM_BITS + BIGINT256_LIMB_BITS - 1) / BIGINT256_LIMB_BITS;
+    catch_ffi("zexe_bigint256_num_limbs", 0, || {
+        // HACK: Manually compute the number of limbs.
+        return (BIGINT256_NUM_BITS + BIGINT256_LIMB_BITS - 1) / BIGINT256_LIMB_BITS;
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_to_data(x: *mut BigInteger256) -> *mut u64 {
-    let x_ = unsafe { &mut (*x) };
-    return (*x_).0.as_mut_ptr();
+    catch_ffi("zexe_bigint256_to_data", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        return (*x_).0.as_mut_ptr();
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_of_data(x: *mut u64) -> *mut BigInteger256 {
-    let x_ = unsafe { std::slice::from_raw_parts(x, BIGINT256_NUM_LIMBS as usize) };
-    let mut ret: std::boxed::Box<BigInteger256> = Box::new(Default::default());
-    (*ret).0.copy_from_slice(x_);
-    return Box::into_raw(ret);
+    catch_ffi("zexe_bigint256_of_data", std::ptr::null_mut(), || {
+        let x_ = unsafe { std::slice::from_raw_parts(x, BIGINT256_NUM_LIMBS as usize) };
+        let mut ret: std::boxed::Box<BigInteger256> = Box::new(Default::default());
+        (*ret).0.copy_from_slice(x_);
+        return into_handle(ret);
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_bytes_per_limb() -> i32 {
-    // HACK: Manually compute the bytes per limb.
-    return BIGINT256_LIMB_BITS / 8;
+    catch_ffi("zexe_bigint256_bytes_per_limb", 0, || {
+        // HACK: Manually compute the bytes per limb.
+        return BIGINT256_LIMB_BITS / 8;
+    })
 }
 
 #[no_mangle]
@@ -218,10 +387,14 @@ pub extern "C" fn zexe_bigint256_div(
     x: *const BigInteger256,
     y: *const BigInteger256,
 ) -> *mut BigInteger256 {
-    let x_ = unsafe { &(*x) };
-    let y_ = unsafe { &(*y) };
-    let res = bigint_of_biginteger256(&x_) / &bigint_of_biginteger256(&y_);
-    return Box::into_raw(Box::new(biginteger256_of_bigint(&res)));
+    catch_ffi("zexe_bigint256_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let res = bigint_of_biginteger256(&x_) / &bigint_of_biginteger256(&y_);
+        return into_handle(Box::new(biginteger256_of_bigint(&res)));
+    })
 }
 
 #[no_mangle]
@@ -230,44 +403,137 @@ pub extern "C" fn zexe_bigint256_of_numeral(
     len: u32,
     base: u32,
 ) -> *mut BigInteger256 {
-    let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
-    let res = match BigUint::parse_bytes(s_, base) {
-        Some(x) => x,
-        None => panic!("zexe_bigint256_of_numeral: Could not convert numeral."),
-    };
-    return Box::into_raw(Box::new(biginteger256_of_bigint(&res)));
+    catch_ffi("zexe_bigint256_of_numeral", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        match biginteger256_of_digits(s_, base) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint256_of_numeral: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_of_hex(s: *const u8, len: u32) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_of_hex", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        let s_ = if s_.starts_with(b"0x") || s_.starts_with(b"0X") {
+            &s_[2..]
+        } else {
+            s_
+        };
+        match biginteger256_of_digits(s_, 16) {
+            Ok(x) => into_handle(Box::new(x)),
+            Err(e) => null_with_error(format!("zexe_bigint256_of_hex: {}", e)),
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_of_bytes_le(s: *const u8, len: u32) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_of_bytes_le", std::ptr::null_mut(), || {
+        let s_ = unsafe { std::slice::from_raw_parts(s, len as usize) };
+        let res = BigUint::from_bytes_le(s_);
+        if res.bits() > BIGINT256_NUM_BITS as usize {
+            return null_with_error(format!(
+                "zexe_bigint256_of_bytes_le: Value does not fit in {} bits.",
+                BIGINT256_NUM_BITS
+            ));
+        }
+        into_handle(Box::new(biginteger256_of_bigint(&res)))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_compare(x: *const BigInteger256, y: *const BigInteger256) -> u8 {
-    let _x = unsafe { &(*x) };
-    let _y = unsafe { &(*y) };
-    if _x < _y {
-        255
-    } else if _x == _y {
-        0
-    } else {
-        1
-    }
+    catch_ffi("zexe_bigint256_compare", 0, || {
+        let x = handle(x);
+        let y = handle(y);
+        let _x = unsafe { &(*x) };
+        let _y = unsafe { &(*y) };
+        if _x < _y {
+            255
+        } else if _x == _y {
+            0
+        } else {
+            1
+        }
+    })
+}
+
+// Returns -1, 0 or 1 as x is less than, equal to or greater than y.
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_cmp(x: *const BigInteger256, y: *const BigInteger256) -> i32 {
+    catch_ffi("zexe_bigint256_cmp", 0, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        match x_.cmp(y_) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bigint256_test_bit(x: *const BigInteger256, i: i32) -> bool {
-    let _x = unsafe { &(*x) };
-    return _x.get_bit(i as usize);
+pub extern "C" fn zexe_bigint256_is_zero(x: *const BigInteger256) -> bool {
+    catch_ffi("zexe_bigint256_is_zero", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return x_.is_zero();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_min(x: *const BigInteger256, y: *const BigInteger256) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_min", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return into_handle(Box::new(*std::cmp::min(x_, y_)));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_max(x: *const BigInteger256, y: *const BigInteger256) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_max", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return into_handle(Box::new(*std::cmp::max(x_, y_)));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bigint256_copy(x: *const BigInteger256) -> *mut BigInteger256 {
+    catch_ffi("zexe_bigint256_copy", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(*x_));
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn zexe_bigint256_delete(x: *mut BigInteger256) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(x) };
+pub extern "C" fn zexe_bigint256_test_bit(x: *const BigInteger256, i: i32) -> bool {
+    catch_ffi("zexe_bigint256_test_bit", false, || {
+        let x = handle(x);
+        let _x = unsafe { &(*x) };
+        return _x.get_bit(i as usize);
+    })
 }
 
+crate::delete_stub!(zexe_bigint256_delete, BigInteger256);
+
 #[no_mangle]
 pub extern "C" fn zexe_bigint256_print(x: *const BigInteger256) {
-    let x_ = unsafe { &(*x) };
-    println!("{}", *x_);
+    catch_ffi("zexe_bigint256_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", *x_));
+    })
 }
 
 #[no_mangle]
@@ -275,15 +541,18 @@ pub extern "C" fn zexe_bigint256_find_wnaf(
     _size: usize,
     x: *const BigInteger256,
 ) -> *const Vec<i64> {
-    // FIXME:
-    // - as it stands, we have to ignore the first parameter
-    // - in snarky the return type will be a Long_vector.t, which is a C++ vector,
-    //   not a rust one
-    if true {
-        panic!("zexe_bigint384_find_wnaf is not implemented");
-    }
-    let x_ = unsafe { &(*x) };
-    return Box::into_raw(Box::new(x_.find_wnaf()));
+    catch_ffi("zexe_bigint256_find_wnaf", std::ptr::null(), || {
+        let x = handle(x);
+        // FIXME:
+        // - as it stands, we have to ignore the first parameter
+        // - in snarky the return type will be a Long_vector.t, which is a C++ vector,
+        //   not a rust one
+        if true {
+            panic!("zexe_bigint384_find_wnaf is not implemented");
+        }
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.find_wnaf()));
+    })
 }
 
 // Fp sponge stubs
@@ -291,27 +560,23 @@ pub extern "C" fn zexe_bigint256_find_wnaf(
 #[no_mangle]
 pub extern "C" fn camlsnark_bn382_fp_sponge_params_create(
 ) -> *mut poseidon::ArithmeticSpongeParams<Fp> {
-    let ret = oracle::bn_382::fp::params();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("camlsnark_bn382_fp_sponge_params_create", std::ptr::null_mut(), || {
+        let ret = oracle::bn_382::fp::params();
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn camlsnark_bn382_fp_sponge_params_delete(
-    x: *mut poseidon::ArithmeticSpongeParams<Fp>,
-) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(camlsnark_bn382_fp_sponge_params_delete, poseidon::ArithmeticSpongeParams<Fp>);
 
 #[no_mangle]
 pub extern "C" fn camlsnark_bn382_fp_sponge_create() -> *mut poseidon::ArithmeticSponge<Fp, SC> {
-    let ret = oracle::poseidon::ArithmeticSponge::<Fp, SC>::new();
-    return Box::into_raw(Box::new(ret));
+    catch_ffi("camlsnark_bn382_fp_sponge_create", std::ptr::null_mut(), || {
+        let ret = oracle::poseidon::ArithmeticSponge::<Fp, SC>::new();
+        return into_handle(Box::new(ret));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn camlsnark_bn382_fp_sponge_delete(x: *mut poseidon::ArithmeticSponge<Fp, SC>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(camlsnark_bn382_fp_sponge_delete, poseidon::ArithmeticSponge<Fp, SC>);
 
 #[no_mangle]
 pub extern "C" fn camlsnark_bn382_fp_sponge_absorb(
@@ -319,11 +584,85 @@ pub extern "C" fn camlsnark_bn382_fp_sponge_absorb(
     params: *const poseidon::ArithmeticSpongeParams<Fp>,
     x: *const Fp,
 ) {
-    let sponge = unsafe { &mut (*sponge) };
-    let params = unsafe { &(*params) };
-    let x = unsafe { &(*x) };
+    catch_ffi("camlsnark_bn382_fp_sponge_absorb", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let x = handle(x);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let x = unsafe { &(*x) };
+
+        sponge.absorb(params, &[*x]);
+    })
+}
+
+// Absorbs every element of the vector, in order, as repeated calls to
+// camlsnark_bn382_fp_sponge_absorb would.
+#[no_mangle]
+pub extern "C" fn camlsnark_bn382_fp_sponge_absorb_vector(
+    sponge: *mut poseidon::ArithmeticSponge<Fp, SC>,
+    params: *const poseidon::ArithmeticSpongeParams<Fp>,
+    v: *const Vec<Fp>,
+) {
+    catch_ffi("camlsnark_bn382_fp_sponge_absorb_vector", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let v = handle(v);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let v = unsafe { &(*v) };
 
-    sponge.absorb(params, &[*x]);
+        sponge.absorb(params, &v[..]);
+    })
+}
+
+// As camlsnark_bn382_fp_sponge_absorb_vector, for an array of len elements.
+#[no_mangle]
+pub extern "C" fn camlsnark_bn382_fp_sponge_absorb_raw(
+    sponge: *mut poseidon::ArithmeticSponge<Fp, SC>,
+    params: *const poseidon::ArithmeticSpongeParams<Fp>,
+    xs: *const Fp,
+    len: usize,
+) {
+    catch_ffi("camlsnark_bn382_fp_sponge_absorb_raw", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let xs = handle(xs);
+        if len == 0 {
+            return;
+        }
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let xs = unsafe { std::slice::from_raw_parts(xs, len) };
+
+        sponge.absorb(params, xs);
+    })
+}
+
+// Absorbs the points of G in the vector, in order, encoded exactly as the
+// prover's and verifier's "fq" sponge encodes the commitments it absorbs.
+#[no_mangle]
+pub extern "C" fn camlsnark_bn382_fp_sponge_absorb_g_vector(
+    sponge: *mut poseidon::ArithmeticSponge<Fp, SC>,
+    params: *const poseidon::ArithmeticSpongeParams<Fp>,
+    v: *const Vec<GAffine>,
+) {
+    catch_ffi("camlsnark_bn382_fp_sponge_absorb_g_vector", (), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let v = handle(v);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
+        let v = unsafe { &(*v) };
+
+        let mut s = DefaultFqSponge::<Bn_382GParameters, SC> {
+            params: params.clone(),
+            sponge: sponge.clone(),
+            last_squeezed: vec![],
+        };
+        s.absorb_g(&v[..]);
+        *sponge = s.sponge;
+    })
 }
 
 #[no_mangle]
@@ -331,9 +670,43 @@ pub extern "C" fn camlsnark_bn382_fp_sponge_squeeze(
     sponge: *mut poseidon::ArithmeticSponge<Fp, SC>,
     params: *const poseidon::ArithmeticSpongeParams<Fp>,
 ) -> *mut Fp {
-    let sponge = unsafe { &mut (*sponge) };
-    let params = unsafe { &(*params) };
+    catch_ffi("camlsnark_bn382_fp_sponge_squeeze", std::ptr::null_mut(), || {
+        let sponge = handle(sponge);
+        let params = handle(params);
+        let sponge = unsafe { &mut (*sponge) };
+        let params = unsafe { &(*params) };
 
-    let ret = sponge.squeeze(params);
-    Box::into_raw(Box::new(ret))
+        let ret = sponge.squeeze(params);
+        into_handle(Box::new(ret))
+    })
+}
+
+// Serializes the state of the sponge, to resume it with
+// camlsnark_bn382_fp_sponge_of_bytes and the same parameters.
+#[no_mangle]
+pub extern "C" fn camlsnark_bn382_fp_sponge_to_bytes(
+    sponge: *const poseidon::ArithmeticSponge<Fp, SC>,
+) -> *mut Vec<u8> {
+    catch_ffi("camlsnark_bn382_fp_sponge_to_bytes", std::ptr::null_mut(), || {
+        let sponge = handle(sponge);
+        let sponge = unsafe { &(*sponge) };
+        let mut res = vec![];
+        write_sponge(sponge, &mut res).unwrap();
+        into_handle(Box::new(res))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn camlsnark_bn382_fp_sponge_of_bytes(
+    data: *const u8,
+    len: usize,
+) -> *mut poseidon::ArithmeticSponge<Fp, SC> {
+    catch_ffi("camlsnark_bn382_fp_sponge_of_bytes", std::ptr::null_mut(), || {
+        let mut r = unsafe { std::slice::from_raw_parts(data, len) };
+        match read_sponge(&mut r) {
+            Ok(res) if r.is_empty() => into_handle(Box::new(res)),
+            Ok(_) => null_with_error("camlsnark_bn382_fp_sponge_of_bytes: Trailing bytes.".to_string()),
+            Err(e) => null_with_error(format!("camlsnark_bn382_fp_sponge_of_bytes: {}", e)),
+        }
+    })
 }
@@ -0,0 +1,82 @@
+use crate::bn382_dlog::{clear_fq_caches, zexe_bn382_fq_x_hat_cache_set_capacity};
+use crate::bn382_pairing::clear_fp_caches;
+use crate::common::*;
+use crate::labels::forget_labelled_systems;
+use crate::params::unregister_all_params;
+
+// Initialization and shutdown
+//
+// The library sets up its process-wide state lazily, so calling
+// zexe_bn382_init is optional; it configures that state in one call instead.
+// zexe_bn382_shutdown releases everything the library holds on its own
+// behalf, so that an embedding runtime can tear it down deterministically,
+// before re-executing or checking for leaks:
+//
+// - the crate's thread pool, once the calls running in it return,
+// - the cached public input commitments,
+// - the Lagrange commitments, the wNAF tables and the prepared verifier
+//   indexes, cached by the calling thread (the threads of the pool free
+//   theirs when they exit),
+// - the named parameters, each of which is deleted once its outstanding gets
+//   are released,
+// - the rows and labels kept for indexes with the constraint-labels feature,
+// - the seeds mixed into the randomness, and
+// - the print callback, so that printing goes back to stdout.
+//
+// The handles returned to the caller stay the caller's to delete. With the
+// handle-registry feature, shutdown also forgets the deleted handles, and
+// reports those still live, which are then leaks if the caller believes it
+// deleted everything. The library may be used again after shutdown.
+
+#[repr(C)]
+pub struct Config {
+    // The number of threads of the crate's pool, or 0 for rayon's global pool.
+    pub num_threads: usize,
+    // The capacity of the public input commitment cache, or 0 to disable it.
+    pub x_hat_cache_capacity: usize,
+}
+
+// A null config applies the defaults, which are all zeros.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_init(config: *const Config) -> bool {
+    catch_ffi("zexe_bn382_init", false, || {
+        let (num_threads, x_hat_cache_capacity) = match unsafe { config.as_ref() } {
+            Some(c) => (c.num_threads, c.x_hat_cache_capacity),
+            None => (0, 0),
+        };
+        zexe_bn382_fq_x_hat_cache_set_capacity(x_hat_cache_capacity);
+        zexe_bn382_set_num_threads(num_threads)
+    })
+}
+
+// Returns false, with the last error describing what is still in use, if
+// named parameters are still referenced or, with the handle-registry feature,
+// handles are still live. Everything else is released regardless.
+#[no_mangle]
+pub extern "C" fn zexe_bn382_shutdown() -> bool {
+    catch_ffi("zexe_bn382_shutdown", false, || {
+        zexe_bn382_set_num_threads(0);
+        clear_fq_caches();
+        clear_fp_caches();
+        let params = unregister_all_params();
+        forget_labelled_systems();
+        forget_auxiliary_uses();
+        reset_system_rng();
+        zexe_set_print_callback(None);
+        let live_handles = reset_handle_registry();
+
+        let mut in_use = params;
+        if live_handles > 0 {
+            in_use.push(format!("{} live handles", live_handles));
+        }
+        if in_use.is_empty() {
+            true
+        } else {
+            set_last_error(format!(
+                "zexe_bn382_shutdown: Still in use: {}",
+                in_use.join(", ")
+            ));
+            false
+        }
+    })
+}
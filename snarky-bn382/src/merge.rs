@@ -0,0 +1,337 @@
+use crate::bn382_dlog::zexe_bn382_fq_index_create;
+use crate::bn382_pairing::zexe_bn382_fp_index_create;
+use crate::common::*;
+use crate::gadgets::{Row, ONE};
+use algebra::bn_382::{fp::Fp, fq::Fq, g::Affine as GAffine, Bn_382};
+use commitment_dlog::srs::SRS;
+use commitment_pairing::urs::URS;
+use marlin_protocol_dlog::index::Index as DlogIndex;
+use marlin_protocol_pairing::index::Index;
+
+// Circuit merging
+//
+// Composes several constraint systems into one, so that several small
+// statements can be proven with a single proof. The rows of the circuits are
+// concatenated, and their variables are laid out as the index expects: the
+// constant one, shared by all circuits, then the public inputs of each circuit
+// in turn, then the auxiliary variables of each circuit in turn.
+//
+// Accordingly, the primary input of the merged circuit is the concatenation
+// of the primary inputs of the circuits, in the order they were added, and
+// likewise for the auxiliary inputs.
+
+struct Part<F> {
+    a: Vec<Row<F>>,
+    b: Vec<Row<F>>,
+    c: Vec<Row<F>>,
+    vars: usize,
+    public_inputs: usize,
+}
+
+pub struct CircuitMerge<F> {
+    parts: Vec<Part<F>>,
+}
+
+impl<F: Clone> CircuitMerge<F> {
+    pub fn new() -> Self {
+        CircuitMerge { parts: vec![] }
+    }
+
+    // Adds a circuit with the given number of variables and of public inputs,
+    // both counting the constant one, and returns its number.
+    pub fn add(
+        &mut self,
+        a: &[Row<F>],
+        b: &[Row<F>],
+        c: &[Row<F>],
+        vars: usize,
+        public_inputs: usize,
+    ) -> Result<usize, String> {
+        if public_inputs == 0 || vars < public_inputs {
+            return Err(format!(
+                "A circuit with {} variables cannot have {} public inputs.",
+                vars, public_inputs
+            ));
+        }
+        for m in [a, b, c].iter() {
+            if let Some(i) = m.iter().flat_map(|(i, _)| i.iter()).find(|&&i| i >= vars) {
+                return Err(format!(
+                    "Variable {} is out of range for a circuit with {} variables.",
+                    i, vars
+                ));
+            }
+        }
+        self.parts.push(Part {
+            a: a.to_vec(),
+            b: b.to_vec(),
+            c: c.to_vec(),
+            vars,
+            public_inputs,
+        });
+        Ok(self.parts.len() - 1)
+    }
+
+    pub fn circuits(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn public_inputs(&self) -> usize {
+        1 + self.parts.iter().map(|p| p.public_inputs - 1).sum::<usize>()
+    }
+
+    pub fn vars(&self) -> usize {
+        self.public_inputs() + self.parts.iter().map(|p| p.vars - p.public_inputs).sum::<usize>()
+    }
+
+    // The merged index of the first public input of circuit k.
+    pub fn public_offset(&self, k: usize) -> usize {
+        1 + self.parts[..k].iter().map(|p| p.public_inputs - 1).sum::<usize>()
+    }
+
+    // The merged index of the first auxiliary variable of circuit k.
+    pub fn aux_offset(&self, k: usize) -> usize {
+        self.public_inputs()
+            + self.parts[..k].iter().map(|p| p.vars - p.public_inputs).sum::<usize>()
+    }
+
+    // The merged index of variable i of circuit k.
+    pub fn variable(&self, k: usize, i: usize) -> usize {
+        assert!(i < self.parts[k].vars, "Variable {} is out of range.", i);
+        remap(&self.parts[k], self.public_offset(k), self.aux_offset(k), i)
+    }
+
+    // The rows of the merged A, B or C matrix.
+    pub fn matrix(&self, which: usize) -> Vec<Row<F>> {
+        let mut res = vec![];
+        for (k, p) in self.parts.iter().enumerate() {
+            let public_offset = self.public_offset(k);
+            let aux_offset = self.aux_offset(k);
+            let m = match which {
+                0 => &p.a,
+                1 => &p.b,
+                _ => &p.c,
+            };
+            res.extend(m.iter().map(|(i, x)| {
+                let i = i.iter().map(|&i| remap(p, public_offset, aux_offset, i)).collect();
+                (i, x.clone())
+            }));
+        }
+        res
+    }
+}
+
+fn remap<F>(p: &Part<F>, public_offset: usize, aux_offset: usize, i: usize) -> usize {
+    if i == ONE {
+        ONE
+    } else if i < p.public_inputs {
+        public_offset + i - 1
+    } else {
+        aux_offset + i - p.public_inputs
+    }
+}
+
+macro_rules! circuit_merge_stubs {
+    (
+        $F:ty,
+        $Urs:ty,
+        $Index:ty,
+        index_create: $index_create:path,
+        create: $create:ident,
+        add: $add:ident,
+        circuits: $circuits:ident,
+        vars: $vars:ident,
+        public_inputs: $public_inputs:ident,
+        public_offset: $public_offset:ident,
+        aux_offset: $aux_offset:ident,
+        variable: $variable:ident,
+        a: $a:ident,
+        b: $b:ident,
+        c: $c:ident,
+        merged_index_create: $merged_index_create:ident,
+        delete: $delete:ident,
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn $create() -> *mut CircuitMerge<$F> {
+            $crate::common::catch_ffi(stringify!($create), std::ptr::null_mut(), || {
+                into_handle(Box::new(CircuitMerge::new()))
+            })
+        }
+
+        // Returns false, with the last error set, if the circuit is
+        // inconsistent, in which case it is not added.
+        #[no_mangle]
+        pub extern "C" fn $add(
+            m: *mut CircuitMerge<$F>,
+            a: *const Vec<Row<$F>>,
+            b: *const Vec<Row<$F>>,
+            c: *const Vec<Row<$F>>,
+            vars: usize,
+            public_inputs: usize,
+        ) -> bool {
+            $crate::common::catch_ffi(stringify!($add), false, || {
+                let m = $crate::common::handle(m);
+                let a = $crate::common::handle(a);
+                let b = $crate::common::handle(b);
+                let c = $crate::common::handle(c);
+                let m = unsafe { &mut *m };
+                match m.add(
+                    unsafe { &*a },
+                    unsafe { &*b },
+                    unsafe { &*c },
+                    vars,
+                    public_inputs,
+                ) {
+                    Ok(_) => true,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", stringify!($add), e));
+                        false
+                    }
+                }
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $circuits(m: *const CircuitMerge<$F>) -> usize {
+            $crate::common::catch_ffi(stringify!($circuits), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).circuits()
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $vars(m: *const CircuitMerge<$F>) -> usize {
+            $crate::common::catch_ffi(stringify!($vars), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).vars()
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $public_inputs(m: *const CircuitMerge<$F>) -> usize {
+            $crate::common::catch_ffi(stringify!($public_inputs), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).public_inputs()
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $public_offset(m: *const CircuitMerge<$F>, k: usize) -> usize {
+            catch_ffi(stringify!($public_offset), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).public_offset(k)
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $aux_offset(m: *const CircuitMerge<$F>, k: usize) -> usize {
+            catch_ffi(stringify!($aux_offset), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).aux_offset(k)
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $variable(m: *const CircuitMerge<$F>, k: usize, i: usize) -> usize {
+            catch_ffi(stringify!($variable), 0, || {
+                let m = $crate::common::handle(m);
+                (unsafe { &*m }).variable(k, i)
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $a(m: *const CircuitMerge<$F>) -> *mut Vec<Row<$F>> {
+            $crate::common::catch_ffi(stringify!($a), std::ptr::null_mut(), || {
+                let m = $crate::common::handle(m);
+                into_handle(Box::new((unsafe { &*m }).matrix(0)))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $b(m: *const CircuitMerge<$F>) -> *mut Vec<Row<$F>> {
+            $crate::common::catch_ffi(stringify!($b), std::ptr::null_mut(), || {
+                let m = $crate::common::handle(m);
+                into_handle(Box::new((unsafe { &*m }).matrix(1)))
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $c(m: *const CircuitMerge<$F>) -> *mut Vec<Row<$F>> {
+            $crate::common::catch_ffi(stringify!($c), std::ptr::null_mut(), || {
+                let m = $crate::common::handle(m);
+                into_handle(Box::new((unsafe { &*m }).matrix(2)))
+            })
+        }
+
+        // Creates the index of the merged circuit, as the index_create stub
+        // would from its matrices.
+        #[no_mangle]
+        pub extern "C" fn $merged_index_create(
+            m: *const CircuitMerge<$F>,
+            urs: *mut $Urs,
+        ) -> *mut $Index {
+            $crate::common::catch_ffi(stringify!($merged_index_create), std::ptr::null_mut(), || {
+                let m = $crate::common::handle(m);
+                let urs = $crate::common::handle(urs);
+                let m = unsafe { &*m };
+                if m.circuits() == 0 {
+                    return null_with_error(format!(
+                        "{}: No circuits were added.",
+                        stringify!($merged_index_create)
+                    ));
+                }
+                let mut a = m.matrix(0);
+                let mut b = m.matrix(1);
+                let mut c = m.matrix(2);
+                $index_create(&mut a, &mut b, &mut c, m.vars(), m.public_inputs(), urs)
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $delete(m: *mut CircuitMerge<$F>) {
+            $crate::common::catch_ffi(stringify!($delete), (), || {
+                let m = $crate::common::handle(m);
+                release_handle(stringify!($delete), m);
+            })
+        }
+    };
+}
+
+circuit_merge_stubs!(
+    Fp,
+    URS<Bn_382>,
+    Index<'static, Bn_382>,
+    index_create: zexe_bn382_fp_index_create,
+    create: zexe_bn382_fp_circuit_merge_create,
+    add: zexe_bn382_fp_circuit_merge_add,
+    circuits: zexe_bn382_fp_circuit_merge_circuits,
+    vars: zexe_bn382_fp_circuit_merge_vars,
+    public_inputs: zexe_bn382_fp_circuit_merge_public_inputs,
+    public_offset: zexe_bn382_fp_circuit_merge_public_offset,
+    aux_offset: zexe_bn382_fp_circuit_merge_aux_offset,
+    variable: zexe_bn382_fp_circuit_merge_variable,
+    a: zexe_bn382_fp_circuit_merge_a,
+    b: zexe_bn382_fp_circuit_merge_b,
+    c: zexe_bn382_fp_circuit_merge_c,
+    merged_index_create: zexe_bn382_fp_circuit_merge_index_create,
+    delete: zexe_bn382_fp_circuit_merge_delete,
+);
+
+circuit_merge_stubs!(
+    Fq,
+    SRS<GAffine>,
+    DlogIndex<'static, GAffine>,
+    index_create: zexe_bn382_fq_index_create,
+    create: zexe_bn382_fq_circuit_merge_create,
+    add: zexe_bn382_fq_circuit_merge_add,
+    circuits: zexe_bn382_fq_circuit_merge_circuits,
+    vars: zexe_bn382_fq_circuit_merge_vars,
+    public_inputs: zexe_bn382_fq_circuit_merge_public_inputs,
+    public_offset: zexe_bn382_fq_circuit_merge_public_offset,
+    aux_offset: zexe_bn382_fq_circuit_merge_aux_offset,
+    variable: zexe_bn382_fq_circuit_merge_variable,
+    a: zexe_bn382_fq_circuit_merge_a,
+    b: zexe_bn382_fq_circuit_merge_b,
+    c: zexe_bn382_fq_circuit_merge_c,
+    merged_index_create: zexe_bn382_fq_circuit_merge_index_create,
+    delete: zexe_bn382_fq_circuit_merge_delete,
+);
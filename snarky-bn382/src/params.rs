@@ -0,0 +1,276 @@
+use crate::common::*;
+use algebra::bn_382::{g::Affine as GAffine, Bn_382};
+use commitment_dlog::srs::SRS;
+use commitment_pairing::urs::URS;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+
+// Named parameters
+//
+// Lets one process keep several URSs and SRSs at once, for instance of the
+// different depths used by different families of circuits, and lets bindings
+// refer to them by name instead of passing the handles around. register takes
+// over a handle. get returns that handle and takes a reference to it, which
+// release gives back. unregister removes the name, and the handle is deleted
+// once neither the registry nor any get still refers to it. A name may only be
+// registered again after that.
+
+struct Entry {
+    handle: usize,
+    refs: usize,
+    registered: bool,
+}
+
+struct Registry<T> {
+    entries: HashMap<String, Entry>,
+    _params: PhantomData<T>,
+}
+
+impl<T> Registry<T> {
+    fn new() -> Self {
+        Registry {
+            entries: HashMap::new(),
+            _params: PhantomData,
+        }
+    }
+
+    fn check_free(&self, name: &str) -> Result<(), String> {
+        match self.entries.get(name) {
+            None => Ok(()),
+            Some(e) if e.registered => Err(format!("'{}' is already registered", name)),
+            Some(e) => Err(format!(
+                "'{}' was unregistered but is still used by {} references",
+                name, e.refs
+            )),
+        }
+    }
+
+    fn register(&mut self, name: &str, handle: *mut T) -> Result<(), String> {
+        self.check_free(name)?;
+        if let Some((other, _)) = self.entries.iter().find(|(_, e)| e.handle == handle as usize) {
+            return Err(format!("The handle is already registered as '{}'", other));
+        }
+        self.entries.insert(
+            name.to_string(),
+            Entry {
+                handle: handle as usize,
+                refs: 1,
+                registered: true,
+            },
+        );
+        Ok(())
+    }
+
+    fn get(&mut self, name: &str) -> Result<*const T, String> {
+        match self.entries.get_mut(name) {
+            Some(e) if e.registered => {
+                e.refs += 1;
+                Ok(e.handle as *const T)
+            }
+            _ => Err(format!("'{}' is not registered", name)),
+        }
+    }
+
+    fn drop_reference(&mut self, name: &str) {
+        let e = self.entries.get_mut(name).unwrap();
+        e.refs -= 1;
+        if e.refs == 0 {
+            let handle = e.handle as *mut T;
+            self.entries.remove(name);
+            release_handle("Registry::drop_reference", handle);
+        }
+    }
+
+    fn release(&mut self, name: &str) -> Result<(), String> {
+        match self.entries.get(name) {
+            Some(e) if e.refs > e.registered as usize => {
+                self.drop_reference(name);
+                Ok(())
+            }
+            _ => Err(format!("'{}' has no references to release", name)),
+        }
+    }
+
+    // Unregisters every name, and returns those which are still referenced.
+    fn unregister_all(&mut self) -> Vec<String> {
+        let names: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, e)| e.registered)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in &names {
+            self.unregister(name).unwrap();
+        }
+        self.entries.keys().cloned().collect()
+    }
+
+    fn unregister(&mut self, name: &str) -> Result<(), String> {
+        match self.entries.get_mut(name) {
+            Some(e) if e.registered => {
+                e.registered = false;
+                self.drop_reference(name);
+                Ok(())
+            }
+            _ => Err(format!("'{}' is not registered", name)),
+        }
+    }
+}
+
+fn fp_urs_registry() -> &'static Mutex<Registry<URS<Bn_382>>> {
+    lazy_static! {
+        static ref REGISTRY: Mutex<Registry<URS<Bn_382>>> = Mutex::new(Registry::new());
+    }
+    &REGISTRY
+}
+
+fn fq_urs_registry() -> &'static Mutex<Registry<SRS<GAffine>>> {
+    lazy_static! {
+        static ref REGISTRY: Mutex<Registry<SRS<GAffine>>> = Mutex::new(Registry::new());
+    }
+    &REGISTRY
+}
+
+// Unregisters every name, and describes those which are still referenced.
+pub(crate) fn unregister_all_params() -> Vec<String> {
+    let fp = fp_urs_registry().lock().unwrap().unregister_all();
+    let fq = fq_urs_registry().lock().unwrap().unregister_all();
+    fp.iter()
+        .map(|name| format!("fp urs '{}'", name))
+        .chain(fq.iter().map(|name| format!("fq urs '{}'", name)))
+        .collect()
+}
+
+// The name given to a stub, which must not be null.
+fn name_of(name: *const c_char) -> Result<String, String> {
+    if name.is_null() {
+        return Err("The name is null".to_string());
+    }
+    Ok(unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned())
+}
+
+macro_rules! registry_stubs {
+    (
+        $T:ty,
+        $registry:ident,
+        register: $register:ident,
+        get: $get:ident,
+        release: $release:ident,
+        unregister: $unregister:ident,
+    ) => {
+        // Takes over the handle, which must not be deleted afterwards. Returns
+        // false, leaving the handle to the caller, if the name is in use.
+        #[no_mangle]
+        pub extern "C" fn $register(name: *const c_char, params: *mut $T) -> bool {
+            $crate::common::catch_ffi(stringify!($register), false, || {
+                // The handle is kept as it was given, tag included, for $get
+                // to return.
+                if !check_handle(stringify!($register), params) {
+                    return false;
+                }
+                let res = name_of(name)
+                    .and_then(|name| $registry().lock().unwrap().register(&name, params));
+                match res {
+                    Ok(()) => true,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", stringify!($register), e));
+                        false
+                    }
+                }
+            })
+        }
+
+        // Null if the name is not registered. Every successful get must be
+        // paired with a release of the same name.
+        #[no_mangle]
+        pub extern "C" fn $get(name: *const c_char) -> *const $T {
+            $crate::common::catch_ffi(stringify!($get), std::ptr::null(), || {
+                match name_of(name).and_then(|name| $registry().lock().unwrap().get(&name)) {
+                    Ok(p) => p,
+                    Err(e) => null_with_error(format!("{}: {}", stringify!($get), e)),
+                }
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $release(name: *const c_char) -> bool {
+            $crate::common::catch_ffi(stringify!($release), false, || {
+                match name_of(name).and_then(|name| $registry().lock().unwrap().release(&name)) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", stringify!($release), e));
+                        false
+                    }
+                }
+            })
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $unregister(name: *const c_char) -> bool {
+            $crate::common::catch_ffi(stringify!($unregister), false, || {
+                match name_of(name).and_then(|name| $registry().lock().unwrap().unregister(&name)) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        set_last_error(format!("{}: {}", stringify!($unregister), e));
+                        false
+                    }
+                }
+            })
+        }
+    };
+}
+
+registry_stubs!(
+    URS<Bn_382>,
+    fp_urs_registry,
+    register: zexe_bn382_fp_urs_register,
+    get: zexe_bn382_fp_urs_get,
+    release: zexe_bn382_fp_urs_release,
+    unregister: zexe_bn382_fp_urs_unregister,
+);
+
+registry_stubs!(
+    SRS<GAffine>,
+    fq_urs_registry,
+    register: zexe_bn382_fq_urs_register,
+    get: zexe_bn382_fq_urs_get,
+    release: zexe_bn382_fq_urs_release,
+    unregister: zexe_bn382_fq_urs_unregister,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_names_are_rejected() {
+        assert!(zexe_bn382_fq_urs_get(std::ptr::null()).is_null());
+        assert!(take_last_error().unwrap().contains("The name is null"));
+        assert!(!zexe_bn382_fq_urs_release(std::ptr::null()));
+        assert!(take_last_error().unwrap().contains("The name is null"));
+        assert!(!zexe_bn382_fq_urs_unregister(std::ptr::null()));
+        assert!(take_last_error().unwrap().contains("The name is null"));
+
+        let srs = into_handle(Box::new(SRS::<GAffine>::create(2)));
+        assert!(!zexe_bn382_fq_urs_register(std::ptr::null(), srs));
+        assert!(take_last_error().unwrap().contains("The name is null"));
+        release_handle("test", srs);
+    }
+
+    #[test]
+    fn registered_params_are_shared() {
+        let name = std::ffi::CString::new("params test").unwrap();
+        let srs = into_handle(Box::new(SRS::<GAffine>::create(2)));
+        assert!(zexe_bn382_fq_urs_register(name.as_ptr(), srs));
+        assert!(!zexe_bn382_fq_urs_register(name.as_ptr(), srs));
+        assert_eq!(zexe_bn382_fq_urs_get(name.as_ptr()), srs as *const _);
+        assert!(zexe_bn382_fq_urs_unregister(name.as_ptr()));
+        assert!(zexe_bn382_fq_urs_get(name.as_ptr()).is_null());
+        assert!(zexe_bn382_fq_urs_release(name.as_ptr()));
+        assert!(!zexe_bn382_fq_urs_release(name.as_ptr()));
+    }
+}
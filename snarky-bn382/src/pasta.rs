@@ -0,0 +1,1088 @@
+use crate::common::*;
+use algebra::{
+    FftField,
+    biginteger::BigInteger256 as BigInteger,
+    curves::{AffineCurve, ProjectiveCurve},
+    fields::{Field, FpParameters, PrimeField, SquareRootField},
+    pasta::{
+        fp::{Fp, FpParameters as Fp_params},
+        fq::{Fq, FqParameters as Fq_params},
+        pallas::{Affine as PallasAffine, Projective as PallasProjective},
+        vesta::{Affine as VestaAffine, Projective as VestaProjective},
+    },
+    One, UniformRand, Zero,
+};
+
+use ff_fft::{EvaluationDomain, Radix2EvaluationDomain as Domain};
+
+
+use std::os::raw::c_char;
+
+// Pasta stubs
+//
+// The Pallas and Vesta curves form a cycle: the base field of each is the
+// scalar field of the other, Fp for Pallas and Fq for Vesta. The field and
+// group stubs follow the tweedle ones. There are no proof system stubs yet, as
+// those need Poseidon parameters for the two fields.
+
+// Fp stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_size_in_bits() -> i32 {
+    catch_ffi("zexe_pasta_fp_size_in_bits", 0, || {
+        return Fp_params::MODULUS_BITS as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_size() -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fp_size", std::ptr::null_mut(), || {
+        let ret = Fp_params::MODULUS;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_is_square(x: *const Fp) -> bool {
+    catch_ffi("zexe_pasta_fp_is_square", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let s0 = x_.pow(Fp_params::MODULUS_MINUS_ONE_DIV_TWO);
+        s0.is_zero() || s0.is_one()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_sqrt(x: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.sqrt() {
+            Some(x) => x,
+            None => Fp::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_two_adic_root_of_unity() -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_two_adic_root_of_unity", std::ptr::null_mut(), || {
+        into_handle(Box::new(FftField::two_adic_root_of_unity()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_random() -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_random", std::ptr::null_mut(), || {
+        let ret: Fp = UniformRand::rand(&mut system_rng());
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_of_int(i: u64) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_of_int", std::ptr::null_mut(), || {
+        let ret = Fp::from(i);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_domain_generator(log2_size: usize) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_domain_generator", std::ptr::null_mut(), || {
+        let ret = Domain::new(1 << log2_size).unwrap().group_gen;
+        return into_handle(Box::new(ret));
+    })
+}
+
+// The string must be freed with zexe_string_delete.
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_to_string(x: *const Fp) -> *mut c_char {
+    catch_ffi("zexe_pasta_fp_to_string", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x = unsafe { *x };
+        into_c_string(format!("{}", x))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_inv(x: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_inv", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.inverse() {
+            Some(x) => x,
+            None => Fp::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_square(x: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_square", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.square();
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_add(x: *const Fp, y: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_add", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_negate(x: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_negate", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_mul(x: *const Fp, y: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_mul", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ * y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_div(x: *const Fp, y: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ / y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_sub(x: *const Fp, y: *const Fp) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_sub", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_mut_add(x: *mut Fp, y: *const Fp) {
+    catch_ffi("zexe_pasta_fp_mut_add", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ += y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_mut_mul(x: *mut Fp, y: *const Fp) {
+    catch_ffi("zexe_pasta_fp_mut_mul", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ *= y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_mut_square(x: *mut Fp) {
+    catch_ffi("zexe_pasta_fp_mut_square", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        x_.square_in_place();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_mut_sub(x: *mut Fp, y: *const Fp) {
+    catch_ffi("zexe_pasta_fp_mut_sub", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ -= y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_copy(x: *mut Fp, y: *const Fp) {
+    catch_ffi("zexe_pasta_fp_copy", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        unsafe { (*x) = *y };
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_rng(i: i32) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_rng", std::ptr::null_mut(), || {
+        // We only care about entropy here, so we force a conversion i32 -> u32.
+        let i: u64 = (i as u32).into();
+        let mut rng = seeded_rng(i);
+        let ret: Fp = UniformRand::rand(&mut rng);
+        return into_handle(Box::new(ret));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_fp_delete, Fp);
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_print(x: *const Fp) {
+    catch_ffi("zexe_pasta_fp_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", x_));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_equal(x: *const Fp, y: *const Fp) -> bool {
+    catch_ffi("zexe_pasta_fp_equal", false, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return *x_ == *y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_to_bigint(x: *const Fp) -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fp_to_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.into_repr()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_of_bigint(x: *const BigInteger) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_of_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fp::from_repr(*x_)));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_to_bigint_raw(x: *const Fp) -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fp_to_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.0));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_to_bigint_raw_noalloc(x: *const Fp) -> *const BigInteger {
+    catch_ffi("zexe_pasta_fp_to_bigint_raw_noalloc", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        &x_.0 as *const BigInteger
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_of_bigint_raw(x: *const BigInteger) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_of_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fp::new(*x_)));
+    })
+}
+
+// Fp vector stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_vector_create() -> *mut Vec<Fp> {
+    catch_ffi("zexe_pasta_fp_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_vector_length(v: *const Vec<Fp>) -> i32 {
+    catch_ffi("zexe_pasta_fp_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_vector_emplace_back(v: *mut Vec<Fp>, x: *const Fp) {
+    catch_ffi("zexe_pasta_fp_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fp_vector_get(v: *mut Vec<Fp>, i: u32) -> *mut Fp {
+    catch_ffi("zexe_pasta_fp_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_fp_vector_delete, Vec<Fp>);
+
+// Fq stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_size_in_bits() -> i32 {
+    catch_ffi("zexe_pasta_fq_size_in_bits", 0, || {
+        return Fq_params::MODULUS_BITS as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_size() -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fq_size", std::ptr::null_mut(), || {
+        let ret = Fq_params::MODULUS;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_is_square(x: *const Fq) -> bool {
+    catch_ffi("zexe_pasta_fq_is_square", false, || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let s0 = x_.pow(Fq_params::MODULUS_MINUS_ONE_DIV_TWO);
+        s0.is_zero() || s0.is_one()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_sqrt(x: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_sqrt", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.sqrt() {
+            Some(x) => x,
+            None => Fq::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_two_adic_root_of_unity() -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_two_adic_root_of_unity", std::ptr::null_mut(), || {
+        into_handle(Box::new(FftField::two_adic_root_of_unity()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_random() -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_random", std::ptr::null_mut(), || {
+        let ret: Fq = UniformRand::rand(&mut system_rng());
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_of_int(i: u64) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_of_int", std::ptr::null_mut(), || {
+        let ret = Fq::from(i);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_domain_generator(log2_size: usize) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_domain_generator", std::ptr::null_mut(), || {
+        let ret = Domain::new(1 << log2_size).unwrap().group_gen;
+        return into_handle(Box::new(ret));
+    })
+}
+
+// The string must be freed with zexe_string_delete.
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_to_string(x: *const Fq) -> *mut c_char {
+    catch_ffi("zexe_pasta_fq_to_string", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x = unsafe { *x };
+        into_c_string(format!("{}", x))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_inv(x: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_inv", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = match x_.inverse() {
+            Some(x) => x,
+            None => Fq::zero(),
+        };
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_square(x: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_square", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.square();
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_add(x: *const Fq, y: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_add", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_negate(x: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_negate", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_mul(x: *const Fq, y: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_mul", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ * y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_div(x: *const Fq, y: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_div", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ / y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_sub(x: *const Fq, y: *const Fq) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_sub", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_mut_add(x: *mut Fq, y: *const Fq) {
+    catch_ffi("zexe_pasta_fq_mut_add", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ += y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_mut_mul(x: *mut Fq, y: *const Fq) {
+    catch_ffi("zexe_pasta_fq_mut_mul", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ *= y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_mut_square(x: *mut Fq) {
+    catch_ffi("zexe_pasta_fq_mut_square", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &mut (*x) };
+        x_.square_in_place();
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_mut_sub(x: *mut Fq, y: *const Fq) {
+    catch_ffi("zexe_pasta_fq_mut_sub", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &mut (*x) };
+        let y_ = unsafe { &(*y) };
+        *x_ -= y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_copy(x: *mut Fq, y: *const Fq) {
+    catch_ffi("zexe_pasta_fq_copy", (), || {
+        let x = handle(x);
+        let y = handle(y);
+        unsafe { (*x) = *y };
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_rng(i: i32) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_rng", std::ptr::null_mut(), || {
+        // We only care about entropy here, so we force a conversion i32 -> u32.
+        let i: u64 = (i as u32).into();
+        let mut rng = seeded_rng(i);
+        let ret: Fq = UniformRand::rand(&mut rng);
+        return into_handle(Box::new(ret));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_fq_delete, Fq);
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_print(x: *const Fq) {
+    catch_ffi("zexe_pasta_fq_print", (), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        print_line(format!("{}", x_));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_equal(x: *const Fq, y: *const Fq) -> bool {
+    catch_ffi("zexe_pasta_fq_equal", false, || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        return *x_ == *y_;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_to_bigint(x: *const Fq) -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fq_to_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.into_repr()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_of_bigint(x: *const BigInteger) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_of_bigint", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fq::from_repr(*x_)));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_to_bigint_raw(x: *const Fq) -> *mut BigInteger {
+    catch_ffi("zexe_pasta_fq_to_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(x_.0));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_to_bigint_raw_noalloc(x: *const Fq) -> *const BigInteger {
+    catch_ffi("zexe_pasta_fq_to_bigint_raw_noalloc", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        &x_.0 as *const BigInteger
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_of_bigint_raw(x: *const BigInteger) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_of_bigint_raw", std::ptr::null_mut(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        return into_handle(Box::new(Fq::new(*x_)));
+    })
+}
+
+// Fq vector stubs
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_vector_create() -> *mut Vec<Fq> {
+    catch_ffi("zexe_pasta_fq_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_vector_length(v: *const Vec<Fq>) -> i32 {
+    catch_ffi("zexe_pasta_fq_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_vector_emplace_back(v: *mut Vec<Fq>, x: *const Fq) {
+    catch_ffi("zexe_pasta_fq_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_fq_vector_get(v: *mut Vec<Fq>, i: u32) -> *mut Fq {
+    catch_ffi("zexe_pasta_fq_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_fq_vector_delete, Vec<Fq>);
+
+// Pallas stubs
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_random() -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_random", std::ptr::null(), || {
+        let rng = &mut system_rng();
+        into_handle(Box::new(PallasProjective::rand(rng)))
+    })
+}
+
+crate::delete_stub!(zexe_pasta_pallas_delete, PallasProjective);
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_one() -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_one", std::ptr::null(), || {
+        let ret = PallasProjective::prime_subgroup_generator();
+        into_handle(Box::new(ret))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_add(
+    x: *const PallasProjective,
+    y: *const PallasProjective,
+) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_add", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_double(x: *const PallasProjective) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_double", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.double();
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_scale(
+    x: *const PallasProjective,
+    s: *const Fq,
+) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_scale", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = (*x_).mul(*s_);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_sub(
+    x: *const PallasProjective,
+    y: *const PallasProjective,
+) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_sub", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_negate(x: *const PallasProjective) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_negate", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_to_affine(p: *const PallasProjective) -> *const PallasAffine {
+    catch_ffi("zexe_pasta_pallas_to_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_affine();
+        return into_handle(Box::new(q));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_of_affine(p: *const PallasAffine) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_of_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_projective();
+        return into_handle(Box::new(q));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_of_affine_coordinates(
+    x: *const Fp,
+    y: *const Fp,
+) -> *const PallasProjective {
+    catch_ffi("zexe_pasta_pallas_of_affine_coordinates", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        return into_handle(Box::new(PallasProjective::new(x, y, Fp::one())));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_create(x: *const Fp, y: *const Fp) -> *const PallasAffine {
+    catch_ffi("zexe_pasta_pallas_affine_create", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        into_handle(Box::new(PallasAffine::new(x, y, false)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_x(p: *const PallasAffine) -> *const Fp {
+    catch_ffi("zexe_pasta_pallas_affine_x", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        return into_handle(Box::new(p.x.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_y(p: *const PallasAffine) -> *const Fp {
+    catch_ffi("zexe_pasta_pallas_affine_y", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        return into_handle(Box::new(p.y.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_is_zero(p: *const PallasAffine) -> bool {
+    catch_ffi("zexe_pasta_pallas_affine_is_zero", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.is_zero();
+    })
+}
+
+crate::delete_stub!(zexe_pasta_pallas_affine_delete, PallasAffine);
+
+// Pallas affine vector stubs
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_vector_create() -> *mut Vec<PallasAffine> {
+    catch_ffi("zexe_pasta_pallas_affine_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_vector_length(v: *const Vec<PallasAffine>) -> i32 {
+    catch_ffi("zexe_pasta_pallas_affine_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_vector_emplace_back(
+    v: *mut Vec<PallasAffine>,
+    x: *const PallasAffine,
+) {
+    catch_ffi("zexe_pasta_pallas_affine_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_pallas_affine_vector_get(v: *mut Vec<PallasAffine>, i: u32) -> *mut PallasAffine {
+    catch_ffi("zexe_pasta_pallas_affine_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_pallas_affine_vector_delete, Vec<PallasAffine>);
+
+// Vesta stubs
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_random() -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_random", std::ptr::null(), || {
+        let rng = &mut system_rng();
+        into_handle(Box::new(VestaProjective::rand(rng)))
+    })
+}
+
+crate::delete_stub!(zexe_pasta_vesta_delete, VestaProjective);
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_one() -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_one", std::ptr::null(), || {
+        let ret = VestaProjective::prime_subgroup_generator();
+        into_handle(Box::new(ret))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_add(
+    x: *const VestaProjective,
+    y: *const VestaProjective,
+) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_add", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ + y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_double(x: *const VestaProjective) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_double", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = x_.double();
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_scale(
+    x: *const VestaProjective,
+    s: *const Fp,
+) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_scale", std::ptr::null(), || {
+        let x = handle(x);
+        let s = handle(s);
+        let x_ = unsafe { &(*x) };
+        let s_ = unsafe { &(*s) };
+        let ret = (*x_).mul(*s_);
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_sub(
+    x: *const VestaProjective,
+    y: *const VestaProjective,
+) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_sub", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x_ = unsafe { &(*x) };
+        let y_ = unsafe { &(*y) };
+        let ret = *x_ - y_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_negate(x: *const VestaProjective) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_negate", std::ptr::null(), || {
+        let x = handle(x);
+        let x_ = unsafe { &(*x) };
+        let ret = -*x_;
+        return into_handle(Box::new(ret));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_to_affine(p: *const VestaProjective) -> *const VestaAffine {
+    catch_ffi("zexe_pasta_vesta_to_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_affine();
+        return into_handle(Box::new(q));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_of_affine(p: *const VestaAffine) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_of_affine", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        let q = p.clone().into_projective();
+        return into_handle(Box::new(q));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_of_affine_coordinates(
+    x: *const Fq,
+    y: *const Fq,
+) -> *const VestaProjective {
+    catch_ffi("zexe_pasta_vesta_of_affine_coordinates", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        return into_handle(Box::new(VestaProjective::new(x, y, Fq::one())));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_create(x: *const Fq, y: *const Fq) -> *const VestaAffine {
+    catch_ffi("zexe_pasta_vesta_affine_create", std::ptr::null(), || {
+        let x = handle(x);
+        let y = handle(y);
+        let x = (unsafe { *x }).clone();
+        let y = (unsafe { *y }).clone();
+        into_handle(Box::new(VestaAffine::new(x, y, false)))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_x(p: *const VestaAffine) -> *const Fq {
+    catch_ffi("zexe_pasta_vesta_affine_x", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        return into_handle(Box::new(p.x.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_y(p: *const VestaAffine) -> *const Fq {
+    catch_ffi("zexe_pasta_vesta_affine_y", std::ptr::null(), || {
+        let p = handle(p);
+        let p = unsafe { *p };
+        return into_handle(Box::new(p.y.clone()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_is_zero(p: *const VestaAffine) -> bool {
+    catch_ffi("zexe_pasta_vesta_affine_is_zero", false, || {
+        let p = handle(p);
+        let p = unsafe { &*p };
+        return p.is_zero();
+    })
+}
+
+crate::delete_stub!(zexe_pasta_vesta_affine_delete, VestaAffine);
+
+// Vesta affine vector stubs
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_vector_create() -> *mut Vec<VestaAffine> {
+    catch_ffi("zexe_pasta_vesta_affine_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_vector_length(v: *const Vec<VestaAffine>) -> i32 {
+    catch_ffi("zexe_pasta_vesta_affine_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_vector_emplace_back(
+    v: *mut Vec<VestaAffine>,
+    x: *const VestaAffine,
+) {
+    catch_ffi("zexe_pasta_vesta_affine_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(*x_);
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_pasta_vesta_affine_vector_get(v: *mut Vec<VestaAffine>, i: u32) -> *mut VestaAffine {
+    catch_ffi("zexe_pasta_vesta_affine_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize]));
+    })
+}
+
+crate::delete_stub!(zexe_pasta_vesta_affine_vector_delete, Vec<VestaAffine>);
@@ -0,0 +1,53 @@
+use crate::common::*;
+use algebra::bn_382::{g::Affine as GAffine, Bn_382};
+use commitment_dlog::srs::SRS;
+use commitment_pairing::urs::URS;
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+// Test parameters
+//
+// Small parameters built into the library with the test-params feature, so
+// that the tests of a binding can prove and verify without generating or
+// reading parameter files. They are derived from constants alone: the Fq SRS
+// is hashed to the curve as always, and the toxic waste of the Fp URS comes
+// from a ChaCha20 stream with a fixed seed, so every build returns the same
+// parameters. That seed is public, so anyone can forge proofs against the Fp
+// URS: it must never be used outside of tests.
+//
+// Each call returns a new handle, to delete with the usual deleter.
+
+// Large enough for the circuits of binding tests.
+pub const TEST_PARAMS_DEPTH: usize = 1 << 10;
+
+const TEST_URS_SEED: [u8; 32] = *b"snarky-bn382 test parameters v1.";
+
+pub fn test_fp_urs() -> URS<Bn_382> {
+    let mut rng = ChaCha20Rng::from_seed(TEST_URS_SEED);
+    URS::create(TEST_PARAMS_DEPTH, (0..TEST_PARAMS_DEPTH).collect(), &mut rng)
+}
+
+pub fn test_fq_srs() -> SRS<GAffine> {
+    SRS::create(TEST_PARAMS_DEPTH)
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_test_params_depth() -> usize {
+    catch_ffi("zexe_bn382_test_params_depth", 0, || {
+        TEST_PARAMS_DEPTH
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fp_urs_test_params() -> *const URS<Bn_382> {
+    catch_ffi("zexe_bn382_fp_urs_test_params", std::ptr::null(), || {
+        in_pool(|| into_handle(Box::new(test_fp_urs())))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn zexe_bn382_fq_urs_test_params() -> *const SRS<GAffine> {
+    catch_ffi("zexe_bn382_fq_urs_test_params", std::ptr::null(), || {
+        in_pool(|| into_handle(Box::new(test_fq_srs())))
+    })
+}
@@ -42,19 +42,22 @@ pub extern "C" fn zexe_tweedle_plonk_fp_index_read<'a>(
     srs: *const SRS<GAffine>,
     path: *const c_char,
 ) -> *const DlogIndex<'a, GAffine> {
-    let srs = unsafe { &*srs };
-
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(&path).unwrap());
-
-    let t = read_plonk_index(
-        oracle::tweedle::fp::params(),
-        oracle::tweedle::fq::params(),
-        srs,
-        &mut r);
-    Box::into_raw(Box::new(t.unwrap()))
+    catch_ffi("zexe_tweedle_plonk_fp_index_read", std::ptr::null(), || {
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut r = BufReader::new(File::open(&path).unwrap());
+
+        let t = read_plonk_index(
+            oracle::tweedle::fp::params(),
+            oracle::tweedle::fq::params(),
+            srs,
+            &mut r);
+        into_handle(Box::new(t.unwrap()))
+    })
 }
 
 #[no_mangle]
@@ -62,35 +65,47 @@ pub extern "C" fn zexe_tweedle_plonk_fp_index_write<'a>(
     index: *const DlogIndex<GAffine>,
     path: *const c_char,
 ) {
-    let index = unsafe { &*index };
+    catch_ffi("zexe_tweedle_plonk_fp_index_write", (), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = BufWriter::new(File::create(path).unwrap());
 
-    write_plonk_index(index, &mut w).unwrap()
+        write_plonk_index(index, &mut w).unwrap()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_index_domain_d1_size<'a>(
     i: *const DlogIndex<'a, GAffine>,
 ) -> usize {
-    (unsafe { &*i }).cs.domain.d1.size()
+    catch_ffi("zexe_tweedle_plonk_fp_index_domain_d1_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).cs.domain.d1.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_index_domain_d4_size<'a>(
     i: *const DlogIndex<'a, GAffine>,
 ) -> usize {
-    (unsafe { &*i }).cs.domain.d4.size()
+    catch_ffi("zexe_tweedle_plonk_fp_index_domain_d4_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).cs.domain.d4.size()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_index_domain_d8_size<'a>(
     i: *const DlogIndex<'a, GAffine>,
 ) -> usize {
-    (unsafe { &*i }).cs.domain.d8.size()
+    catch_ffi("zexe_tweedle_plonk_fp_index_domain_d8_size", 0, || {
+        let i = handle(i);
+        (unsafe { &*i }).cs.domain.d8.size()
+    })
 }
 
 #[no_mangle]
@@ -99,69 +114,78 @@ pub extern "C" fn zexe_tweedle_plonk_fp_index_create<'a>(
     public: usize,
     srs: *const SRS<GAffine>,
 ) -> *mut DlogIndex<'a, GAffine> {
-    let gates = unsafe { &*gates };
-    let srs = unsafe { &*srs };
-
-    let n = Domain::<Fp>::compute_size_of_domain(gates.len()).unwrap();
-    let wire = |w: Wire| -> usize {
-        match w.col {
-            L => w.row,
-            R => w.row + n,
-            O => w.row + 2 * n,
-        }
-    };
-
-    let gates : Vec<_> = gates
-        .iter()
-        .map(|gate| CircuitGate::<Fp> {
-            typ: gate.typ.clone(),
-            wires: GateWires {
-                l: (gate.wires.row, wire(gate.wires.l)),
-                r: (gate.wires.row + n, wire(gate.wires.r)),
-                o: (gate.wires.row + 2 * n, wire(gate.wires.o)),
-            },
-            c: gate.c.clone(),
-        })
-        .collect();
-
-    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
-    return Box::into_raw(Box::new(DlogIndex::<GAffine>::create(
-        ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params(), public).unwrap(),
-        oracle::tweedle::fq::params(),
-        endo_q,
-        SRSSpec::Use(srs),
-    )));
+    catch_ffi("zexe_tweedle_plonk_fp_index_create", std::ptr::null_mut(), || {
+        let gates = handle(gates);
+        let srs = handle(srs);
+        let gates = unsafe { &*gates };
+        let srs = unsafe { &*srs };
+
+        let n = Domain::<Fp>::compute_size_of_domain(gates.len()).unwrap();
+        let wire = |w: Wire| -> usize {
+            match w.col {
+                L => w.row,
+                R => w.row + n,
+                O => w.row + 2 * n,
+            }
+        };
+
+        let gates : Vec<_> = gates
+            .iter()
+            .map(|gate| CircuitGate::<Fp> {
+                typ: gate.typ.clone(),
+                wires: GateWires {
+                    l: (gate.wires.row, wire(gate.wires.l)),
+                    r: (gate.wires.row + n, wire(gate.wires.r)),
+                    o: (gate.wires.row + 2 * n, wire(gate.wires.o)),
+                },
+                c: gate.c.clone(),
+            })
+            .collect();
+
+        let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
+        return into_handle(Box::new(DlogIndex::<GAffine>::create(
+            ConstraintSystem::<Fp>::create(gates, oracle::tweedle::fp::params(), public).unwrap(),
+            oracle::tweedle::fq::params(),
+            endo_q,
+            SRSSpec::Use(srs),
+        )));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_index_delete(x: *mut DlogIndex<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_index_delete, DlogIndex<GAffine>);
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_index_max_degree(
     index: *const DlogIndex<GAffine>,
 ) -> usize {
-    let index = unsafe { &*index };
-    index.srs.get_ref().max_degree()
+    catch_ffi("zexe_tweedle_plonk_fp_index_max_degree", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.srs.get_ref().max_degree()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_index_public_inputs(
     index: *const DlogIndex<GAffine>,
 ) -> usize {
-    let index = unsafe { &*index };
-    index.cs.public
+    catch_ffi("zexe_tweedle_plonk_fp_index_public_inputs", 0, || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        index.cs.public
+    })
 }
 
 // Fp verifier index stubs
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_shifts(log2_size : usize) -> PointerPair<Fp, Fp> {
-    let (a, b) = ConstraintSystem::sample_shifts(&Domain::new(1 << log2_size).unwrap());
-    PointerPair {
-        a: Box::into_raw(Box::new(a)),
-        b: Box::into_raw(Box::new(b)),
-    }
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_shifts", Default::default(), || {
+        let (a, b) = ConstraintSystem::sample_shifts(&Domain::new(1 << log2_size).unwrap());
+        PointerPair {
+            a: into_handle(Box::new(a)),
+            b: into_handle(Box::new(b)),
+        }
+    })
 }
 
 #[no_mangle]
@@ -169,21 +193,24 @@ pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_read<'a>(
     srs: *const SRS<GAffine>,
     path: *const c_char,
 ) -> *const DlogVerifierIndex<'a, GAffine> {
-    let srs = unsafe { &*srs };
-
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut r = BufReader::new(File::open(path).unwrap());
-
-    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
-    let t = read_plonk_verifier_index(
-        oracle::tweedle::fp::params(),
-        oracle::tweedle::fq::params(),
-        endo_q,
-        srs,
-        &mut r);
-    Box::into_raw(Box::new(t.unwrap()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_read", std::ptr::null(), || {
+        let srs = handle(srs);
+        let srs = unsafe { &*srs };
+
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut r = BufReader::new(File::open(path).unwrap());
+
+        let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
+        let t = read_plonk_verifier_index(
+            oracle::tweedle::fp::params(),
+            oracle::tweedle::fq::params(),
+            endo_q,
+            srs,
+            &mut r);
+        into_handle(Box::new(t.unwrap()))
+    })
 }
 
 #[no_mangle]
@@ -191,30 +218,39 @@ pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_write<'a>(
     index: *const DlogVerifierIndex<GAffine>,
     path: *const c_char,
 ) {
-    let index = unsafe { &*index };
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_write", (), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
 
-    let path = (unsafe { CStr::from_ptr(path) })
-        .to_string_lossy()
-        .into_owned();
-    let mut w = BufWriter::new(File::create(path).unwrap());
+        let path = (unsafe { CStr::from_ptr(path) })
+            .to_string_lossy()
+            .into_owned();
+        let mut w = BufWriter::new(File::create(path).unwrap());
 
-    write_plonk_verifier_index(index, &mut w).unwrap()
+        write_plonk_verifier_index(index, &mut w).unwrap()
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_create(
     index: *const DlogIndex<GAffine>,
 ) -> *const DlogVerifierIndex<GAffine> {
-    Box::into_raw(Box::new(unsafe { &(*index) }.verifier_index()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_create", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new(unsafe { &(*index) }.verifier_index()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_urs<'a>(
     index: *const DlogVerifierIndex<'a, GAffine>,
 ) -> *const SRS<GAffine> {
-    let index = unsafe { &*index };
-    let urs = index.srs.get_ref().clone();
-    Box::into_raw(Box::new(urs))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_urs", std::ptr::null(), || {
+        let index = handle(index);
+        let index = unsafe { &*index };
+        let urs = index.srs.get_ref().clone();
+        into_handle(Box::new(urs))
+    })
 }
 
 #[no_mangle]
@@ -243,63 +279,88 @@ pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_make<'a>(
     r: *const Fp,
     o: *const Fp,
 ) -> *const DlogVerifierIndex<'a, GAffine> {
-    let srs = unsafe { &*urs };
-    let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
-    let index = DlogVerifierIndex::<GAffine> {
-        domain: Domain::<Fp>::new(max_poly_size).unwrap(),
-        max_poly_size,
-        max_quot_size,
-        srs: SRSValue::Ref(srs),
-        sigma_comm: [
-            (unsafe { &*sigma_comm0 }).clone(),
-            (unsafe { &*sigma_comm1 }).clone(),
-            (unsafe { &*sigma_comm2 }).clone(),
-        ],
-        ql_comm: (unsafe { &*ql_comm }).clone(),
-        qr_comm: (unsafe { &*qr_comm }).clone(),
-        qo_comm: (unsafe { &*qo_comm }).clone(),
-        qm_comm: (unsafe { &*qm_comm }).clone(),
-        qc_comm: (unsafe { &*qc_comm }).clone(),
-        rcm_comm: [
-            (unsafe { &*rcm_comm0 }).clone(),
-            (unsafe { &*rcm_comm1 }).clone(),
-            (unsafe { &*rcm_comm2 }).clone(),
-        ],
-        psm_comm: (unsafe { &*psm_comm }).clone(),
-        add_comm: (unsafe { &*add_comm }).clone(),
-        mul1_comm: (unsafe { &*mul1_comm }).clone(),
-        mul2_comm: (unsafe { &*mul2_comm }).clone(),
-        emul1_comm: (unsafe { &*emul1_comm }).clone(),
-        emul2_comm: (unsafe { &*emul2_comm }).clone(),
-        emul3_comm: (unsafe { &*emul3_comm }).clone(),
-        r: (unsafe { &*r }).clone(),
-        o: (unsafe { &*o }).clone(),
-        fr_sponge_params: oracle::tweedle::fp::params(),
-        fq_sponge_params: oracle::tweedle::fq::params(),
-        endo: endo_q
-    };
-    Box::into_raw(Box::new(index))
-}
-
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_delete(x: *mut DlogVerifierIndex<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_make", std::ptr::null(), || {
+        let urs = handle(urs);
+        let sigma_comm0 = handle(sigma_comm0);
+        let sigma_comm1 = handle(sigma_comm1);
+        let sigma_comm2 = handle(sigma_comm2);
+        let ql_comm = handle(ql_comm);
+        let qr_comm = handle(qr_comm);
+        let qo_comm = handle(qo_comm);
+        let qm_comm = handle(qm_comm);
+        let qc_comm = handle(qc_comm);
+        let rcm_comm0 = handle(rcm_comm0);
+        let rcm_comm1 = handle(rcm_comm1);
+        let rcm_comm2 = handle(rcm_comm2);
+        let psm_comm = handle(psm_comm);
+        let add_comm = handle(add_comm);
+        let mul1_comm = handle(mul1_comm);
+        let mul2_comm = handle(mul2_comm);
+        let emul1_comm = handle(emul1_comm);
+        let emul2_comm = handle(emul2_comm);
+        let emul3_comm = handle(emul3_comm);
+        let r = handle(r);
+        let o = handle(o);
+        let srs = unsafe { &*urs };
+        let (endo_q, _endo_r) = commitment_dlog::srs::endos::<GAffineOther>();
+        let index = DlogVerifierIndex::<GAffine> {
+            domain: Domain::<Fp>::new(max_poly_size).unwrap(),
+            max_poly_size,
+            max_quot_size,
+            srs: SRSValue::Ref(srs),
+            sigma_comm: [
+                (unsafe { &*sigma_comm0 }).clone(),
+                (unsafe { &*sigma_comm1 }).clone(),
+                (unsafe { &*sigma_comm2 }).clone(),
+            ],
+            ql_comm: (unsafe { &*ql_comm }).clone(),
+            qr_comm: (unsafe { &*qr_comm }).clone(),
+            qo_comm: (unsafe { &*qo_comm }).clone(),
+            qm_comm: (unsafe { &*qm_comm }).clone(),
+            qc_comm: (unsafe { &*qc_comm }).clone(),
+            rcm_comm: [
+                (unsafe { &*rcm_comm0 }).clone(),
+                (unsafe { &*rcm_comm1 }).clone(),
+                (unsafe { &*rcm_comm2 }).clone(),
+            ],
+            psm_comm: (unsafe { &*psm_comm }).clone(),
+            add_comm: (unsafe { &*add_comm }).clone(),
+            mul1_comm: (unsafe { &*mul1_comm }).clone(),
+            mul2_comm: (unsafe { &*mul2_comm }).clone(),
+            emul1_comm: (unsafe { &*emul1_comm }).clone(),
+            emul2_comm: (unsafe { &*emul2_comm }).clone(),
+            emul3_comm: (unsafe { &*emul3_comm }).clone(),
+            r: (unsafe { &*r }).clone(),
+            o: (unsafe { &*o }).clone(),
+            fr_sponge_params: oracle::tweedle::fp::params(),
+            fq_sponge_params: oracle::tweedle::fq::params(),
+            endo: endo_q
+        };
+        into_handle(Box::new(index))
+    })
 }
 
+crate::delete_stub!(zexe_tweedle_plonk_fp_verifier_index_delete, DlogVerifierIndex<GAffine>);
+
 /*
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_write<'a>(
     index: *const DlogVerifierIndex<GAffine>,
     path: *const c_char,
 ) {
-    // TODO
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_write", (), || {
+        let index = handle(index);
+        // TODO
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_read<'a>(
     path: *const c_char,
 ) -> *const DlogVerifierIndex<'a, GAffine> {
-    // TODO
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_read", std::ptr::null(), || {
+        // TODO
+    })
 }
 */
 
@@ -307,150 +368,216 @@ pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_read<'a>(
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_sigma_comm_0(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).sigma_comm[0] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_sigma_comm_0", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).sigma_comm[0] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_sigma_comm_1(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).sigma_comm[1] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_sigma_comm_1", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).sigma_comm[1] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_sigma_comm_2(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).sigma_comm[2] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_sigma_comm_2", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).sigma_comm[2] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_ql_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).ql_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_ql_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).ql_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_qr_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).qr_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_qr_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).qr_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_qo_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).qo_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_qo_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).qo_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_qm_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).qm_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_qm_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).qm_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_qc_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).qc_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_qc_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).qc_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_rcm_comm_0(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).rcm_comm[0] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_rcm_comm_0", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).rcm_comm[0] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_rcm_comm_1(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).rcm_comm[1] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_rcm_comm_1", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).rcm_comm[1] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_rcm_comm_2(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).rcm_comm[2] }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_rcm_comm_2", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).rcm_comm[2] }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_psm_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).psm_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_psm_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).psm_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_add_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).add_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_add_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).add_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_mul1_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).mul1_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_mul1_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).mul1_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_mul2_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).mul2_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_mul2_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).mul2_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_emul1_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).emul1_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_emul1_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).emul1_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_emul2_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).emul2_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_emul2_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).emul2_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_emul3_comm(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    Box::into_raw(Box::new((unsafe { &(*index).emul3_comm }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_emul3_comm", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).emul3_comm }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_r(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const Fp {
-    Box::into_raw(Box::new((unsafe { &(*index).r }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_r", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).r }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_o(
     index: *const DlogVerifierIndex<GAffine>,
 ) -> *const Fp {
-    Box::into_raw(Box::new((unsafe { &(*index).o }).clone()))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_o", std::ptr::null(), || {
+        let index = handle(index);
+        into_handle(Box::new((unsafe { &(*index).o }).clone()))
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_domain_log2(x: *const DlogVerifierIndex<GAffine>) -> u32 {
-    (unsafe { &(*x) }).domain.log_size_of_group
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_domain_log2", 0, || {
+        let x = handle(x);
+        (unsafe { &(*x) }).domain.log_size_of_group
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_verifier_index_domain_group_gen(x: *const DlogVerifierIndex<GAffine>) -> *const Fp {
-    Box::into_raw(Box::new((unsafe { &(*x) }).domain.group_gen))
+    catch_ffi("zexe_tweedle_plonk_fp_verifier_index_domain_group_gen", std::ptr::null(), || {
+        let x = handle(x);
+        into_handle(Box::new((unsafe { &(*x) }).domain.group_gen))
+    })
 }
 
 // Fp proof
@@ -462,47 +589,54 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_create(
     prev_challenges: *const Vec<Fp>,
     prev_sgs: *const Vec<GAffine>,
 ) -> *const DlogProof<GAffine> {
-    let index = unsafe { &(*index) };
-    let primary_input = unsafe { &(*primary_input) };
-    let auxiliary_input = unsafe { &(*auxiliary_input) };
-
-    let witness = auxiliary_input;
-//    let witness = prepare_plonk_witness(primary_input, auxiliary_input);
-
-    let prev: Vec<(Vec<Fp>, PolyComm<GAffine>)> = {
-        let prev_challenges = unsafe { &*prev_challenges };
-        let prev_sgs = unsafe { &*prev_sgs };
-        if prev_challenges.len() == 0 {
-            Vec::new()
-        } else {
-            let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
-            prev_sgs
-                .iter()
-                .enumerate()
-                .map(|(i, sg)| {
-                    (
-                        prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
-                            .iter()
-                            .map(|x| *x)
-                            .collect(),
-                        PolyComm::<GAffine> {
-                            unshifted: vec![sg.clone()],
-                            shifted: None,
-                        },
-                    )
-                })
-                .collect()
-        }
-    };
-
-    let map = <GAffine as CommitmentCurve>::Map::setup();
-    let proof = DlogProof::create::<
-        DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
-        DefaultFrSponge<Fp, PlonkSpongeConstants>,
-    >(&map, &witness, &index, prev)
-    .unwrap();
-
-    return Box::into_raw(Box::new(proof));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_create", std::ptr::null(), || {
+        let index = handle(index);
+        let primary_input = handle(primary_input);
+        let auxiliary_input = handle(auxiliary_input);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let index = unsafe { &(*index) };
+        let primary_input = unsafe { &(*primary_input) };
+        let auxiliary_input = unsafe { &(*auxiliary_input) };
+
+        let witness = auxiliary_input;
+    //    let witness = prepare_plonk_witness(primary_input, auxiliary_input);
+
+        let prev: Vec<(Vec<Fp>, PolyComm<GAffine>)> = {
+            let prev_challenges = unsafe { &*prev_challenges };
+            let prev_sgs = unsafe { &*prev_sgs };
+            if prev_challenges.len() == 0 {
+                Vec::new()
+            } else {
+                let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
+                prev_sgs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, sg)| {
+                        (
+                            prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
+                                .iter()
+                                .map(|x| *x)
+                                .collect(),
+                            PolyComm::<GAffine> {
+                                unshifted: vec![sg.clone()],
+                                shifted: None,
+                            },
+                        )
+                    })
+                    .collect()
+            }
+        };
+
+        let map = <GAffine as CommitmentCurve>::Map::setup();
+        let proof = DlogProof::create::<
+            DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
+            DefaultFrSponge<Fp, PlonkSpongeConstants>,
+        >(&map, &witness, &index, prev)
+        .unwrap();
+
+        return into_handle(Box::new(proof));
+    })
 }
 
 #[no_mangle]
@@ -512,16 +646,21 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_verify(
     index: *const DlogVerifierIndex<GAffine>,
     proof: *const DlogProof<GAffine>,
 ) -> bool {
-    let index = unsafe { &(*index) };
-    let lgr_comm = unsafe { &(*lgr_comm) };
-    let proof = unsafe { &(*proof) };
-    let group_map = <GAffine as CommitmentCurve>::Map::setup();
-
-    DlogProof::verify::<
-        DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
-        DefaultFrSponge<Fp, PlonkSpongeConstants>,
-    >(&group_map, &[(index, lgr_comm, proof)].to_vec())
-    .is_ok()
+    catch_ffi("zexe_tweedle_plonk_fp_proof_verify", false, || {
+        let lgr_comm = handle(lgr_comm);
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let lgr_comm = unsafe { &(*lgr_comm) };
+        let proof = unsafe { &(*proof) };
+        let group_map = <GAffine as CommitmentCurve>::Map::setup();
+
+        DlogProof::verify::<
+            DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
+            DefaultFrSponge<Fp, PlonkSpongeConstants>,
+        >(&group_map, &[(index, lgr_comm, proof)].to_vec())
+        .is_ok()
+    })
 }
 
 // TODO: Batch verify across different indexes
@@ -532,22 +671,27 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_batch_verify(
     indexes: *const Vec<*const DlogVerifierIndex<GAffine>>,
     proofs: *const Vec<DlogProof<GAffine>>,
 ) -> bool {
-    let indexes = unsafe { &(*indexes) };
-    let lgr_comms = unsafe { &(*lgr_comms) };
-    let proofs = unsafe { &(*proofs) };
-    let group_map = <GAffine as CommitmentCurve>::Map::setup();
-
-    let ts : Vec<_> = indexes.iter().zip(lgr_comms.iter()).zip(proofs.iter()).map(|((i, l), p)| {
-        let i = unsafe { &(**i) };
-        let l = unsafe { &(**l) };
-        (i, l, p)
-    }).collect();
-
-    DlogProof::<GAffine>::verify::<
-        DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
-        DefaultFrSponge<Fp, PlonkSpongeConstants>,
-    >(&group_map, &ts)
-    .is_ok()
+    catch_ffi("zexe_tweedle_plonk_fp_proof_batch_verify", false, || {
+        let lgr_comms = handle(lgr_comms);
+        let indexes = handle(indexes);
+        let proofs = handle(proofs);
+        let indexes = unsafe { &(*indexes) };
+        let lgr_comms = unsafe { &(*lgr_comms) };
+        let proofs = unsafe { &(*proofs) };
+        let group_map = <GAffine as CommitmentCurve>::Map::setup();
+
+        let ts : Vec<_> = indexes.iter().zip(lgr_comms.iter()).zip(proofs.iter()).map(|((i, l), p)| {
+            let i = unsafe { &(*handle(*i)) };
+            let l = unsafe { &(*handle(*l)) };
+            (i, l, p)
+        }).collect();
+
+        DlogProof::<GAffine>::verify::<
+            DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>,
+            DefaultFrSponge<Fp, PlonkSpongeConstants>,
+        >(&group_map, &ts)
+        .is_ok()
+    })
 }
 
 #[no_mangle]
@@ -560,7 +704,7 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_make(
     z_comm: *const PolyComm<GAffine>,
     t_comm: *const PolyComm<GAffine>,
 
-    lr: *const Vec<(GAffine, GAffine)>,
+    lr: *const Vec<AffinePair<GAffine>>,
     z1: *const Fp,
     z2: *const Fp,
     delta: *const GAffine,
@@ -572,130 +716,170 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_make(
     prev_challenges: *const Vec<Fp>,
     prev_sgs: *const Vec<GAffine>,
 ) -> *const DlogProof<GAffine> {
-    let public = unsafe { &(*primary_input) }.clone();
-    // public.resize(ceil_pow2(public.len()), Fp::zero());
-
-    let prev: Vec<(Vec<Fp>, PolyComm<GAffine>)> = {
-        let prev_challenges = unsafe { &*prev_challenges };
-        let prev_sgs = unsafe { &*prev_sgs };
-        if prev_challenges.len() == 0 {
-            Vec::new()
-        } else {
-            let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
-            prev_sgs
-                .iter()
-                .enumerate()
-                .map(|(i, sg)| {
-                    (
-                        prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
-                            .iter()
-                            .map(|x| *x)
-                            .collect(),
-                        PolyComm::<GAffine> {
-                            unshifted: vec![sg.clone()],
-                            shifted: None,
-                        },
-                    )
-                })
-                .collect()
-        }
-    };
-
-    let res = DlogProof {
-        prev_challenges: prev,
-        proof: OpeningProof {
-            lr: (unsafe { &*lr }).clone(),
-            z1: (unsafe { *z1 }).clone(),
-            z2: (unsafe { *z2 }).clone(),
-            delta: (unsafe { *delta }).clone(),
-            sg: (unsafe { *sg }).clone(),
-        },
-        l_comm: (unsafe { &*l_comm }).clone(),
-        r_comm: (unsafe { &*r_comm }).clone(),
-        o_comm: (unsafe { &*o_comm }).clone(),
-        z_comm: (unsafe { &*z_comm }).clone(),
-        t_comm: (unsafe { &*t_comm }).clone(),
-
-        public,
-        evals: [(unsafe { &*evals0 }).clone(), (unsafe { &*evals1 }).clone()],
-    };
-    return Box::into_raw(Box::new(res));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_make", std::ptr::null(), || {
+        let primary_input = handle(primary_input);
+        let l_comm = handle(l_comm);
+        let r_comm = handle(r_comm);
+        let o_comm = handle(o_comm);
+        let z_comm = handle(z_comm);
+        let t_comm = handle(t_comm);
+        let lr = handle(lr);
+        let z1 = handle(z1);
+        let z2 = handle(z2);
+        let delta = handle(delta);
+        let sg = handle(sg);
+        let evals0 = handle(evals0);
+        let evals1 = handle(evals1);
+        let prev_challenges = handle(prev_challenges);
+        let prev_sgs = handle(prev_sgs);
+        let public = unsafe { &(*primary_input) }.clone();
+        // public.resize(ceil_pow2(public.len()), Fp::zero());
+
+        let prev: Vec<(Vec<Fp>, PolyComm<GAffine>)> = {
+            let prev_challenges = unsafe { &*prev_challenges };
+            let prev_sgs = unsafe { &*prev_sgs };
+            if prev_challenges.len() == 0 {
+                Vec::new()
+            } else {
+                let challenges_per_sg = prev_challenges.len() / prev_sgs.len();
+                prev_sgs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, sg)| {
+                        (
+                            prev_challenges[(i * challenges_per_sg)..(i + 1) * challenges_per_sg]
+                                .iter()
+                                .map(|x| *x)
+                                .collect(),
+                            PolyComm::<GAffine> {
+                                unshifted: vec![sg.clone()],
+                                shifted: None,
+                            },
+                        )
+                    })
+                    .collect()
+            }
+        };
+
+        let res = DlogProof {
+            prev_challenges: prev,
+            proof: OpeningProof {
+                lr: (unsafe { &*lr }).clone(),
+                z1: (unsafe { *z1 }).clone(),
+                z2: (unsafe { *z2 }).clone(),
+                delta: (unsafe { *delta }).clone(),
+                sg: (unsafe { *sg }).clone(),
+            },
+            l_comm: (unsafe { &*l_comm }).clone(),
+            r_comm: (unsafe { &*r_comm }).clone(),
+            o_comm: (unsafe { &*o_comm }).clone(),
+            z_comm: (unsafe { &*z_comm }).clone(),
+            t_comm: (unsafe { &*t_comm }).clone(),
+
+            public,
+            evals: [(unsafe { &*evals0 }).clone(), (unsafe { &*evals1 }).clone()],
+        };
+        return into_handle(Box::new(res));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_proof_delete(x: *mut DlogProof<GAffine>) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_proof_delete, DlogProof<GAffine>);
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_l_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).l_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_l_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).l_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_r_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).r_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_r_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).r_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_o_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).o_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_o_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).o_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_z_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).z_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_z_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).z_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_t_comm(
     p: *mut DlogProof<GAffine>,
 ) -> *const PolyComm<GAffine> {
-    let x = (unsafe { &((*p).t_comm) }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_t_comm", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &((*p).t_comm) }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_proof(
     p: *mut DlogProof<GAffine>,
 ) -> *const OpeningProof<GAffine> {
-    let x = (unsafe { &(*p).proof }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_proof", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).proof }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evals_nocopy(
     p: *mut DlogProof<GAffine>,
 ) -> *const [DlogProofEvaluations<Vec<Fp>>; 2] {
-    let x = (unsafe { &(*p).evals }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evals_nocopy", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).evals }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 // Fp proof vector
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_vector_create() -> *mut Vec<DlogProof<GAffine>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_vector_length(
     v: *const Vec<DlogProof<GAffine>>,
 ) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_tweedle_plonk_fp_proof_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -703,9 +887,13 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_vector_emplace_back(
     v: *mut Vec<DlogProof<GAffine>>,
     x: *const DlogProof<GAffine>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    let x_ = unsafe { &(*x) };
-    v_.push(x_.clone());
+    catch_ffi("zexe_tweedle_plonk_fp_proof_vector_emplace_back", (), || {
+        let v = handle(v);
+        let x = handle(x);
+        let v_ = unsafe { &mut (*v) };
+        let x_ = unsafe { &(*x) };
+        v_.push(x_.clone());
+    })
 }
 
 #[no_mangle]
@@ -713,63 +901,71 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_vector_get(
     v: *mut Vec<DlogProof<GAffine>>,
     i: u32,
 ) -> *mut DlogProof<GAffine> {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new((*v_)[i as usize].clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_vector_get", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new((*v_)[i as usize].clone()));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_proof_vector_delete(v: *mut Vec<DlogProof<GAffine>>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_proof_vector_delete, Vec<DlogProof<GAffine>>);
 
 // Fp opening proof
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_delete(p: *mut OpeningProof<GAffine>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(p) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_opening_proof_delete, OpeningProof<GAffine>);
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_sg(
     p: *const OpeningProof<GAffine>,
 ) -> *const GAffine {
-    let x = (unsafe { &(*p).sg }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_opening_proof_sg", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).sg }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_lr(
     p: *const OpeningProof<GAffine>,
-) -> *const Vec<(GAffine, GAffine)> {
-    let x = (unsafe { &(*p).lr }).clone();
-    return Box::into_raw(Box::new(x));
+) -> *const Vec<AffinePair<GAffine>> {
+    catch_ffi("zexe_tweedle_plonk_fp_opening_proof_lr", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).lr }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_z1(
     p: *const OpeningProof<GAffine>,
 ) -> *const Fp {
-    let x = (unsafe { &(*p).z1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_opening_proof_z1", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).z1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_z2(
     p: *const OpeningProof<GAffine>,
 ) -> *const Fp {
-    let x = (unsafe { &(*p).z2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_opening_proof_z2", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).z2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_delta(
     p: *const OpeningProof<GAffine>,
 ) -> *const GAffine {
-    let x = (unsafe { &(*p).delta }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_opening_proof_delta", std::ptr::null(), || {
+        let p = handle(p);
+        let x = (unsafe { &(*p).delta }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 // Fp proof evaluations
@@ -778,64 +974,88 @@ pub extern "C" fn zexe_tweedle_plonk_fp_opening_proof_delta(
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_l(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).l }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_l", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).l }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_r(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).r }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_r", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).r }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_o(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).o }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_o", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).o }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_z(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).z }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_z", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).z }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_t(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).t }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_t", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).t }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_f(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).f }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_f", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).f }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_sigma1(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).sigma1 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_sigma1", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).sigma1 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_sigma2(
     e: *const DlogProofEvaluations<Vec<Fp>>,
 ) -> *const Vec<Fp> {
-    let x = (unsafe { &(*e).sigma2 }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_sigma2", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e).sigma2 }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
@@ -849,49 +1069,55 @@ pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_make(
     sigma1: *const Vec<Fp>,
     sigma2: *const Vec<Fp>,
 ) -> *const DlogProofEvaluations<Vec<Fp>> {
-    let res: DlogProofEvaluations<Vec<Fp>> = DlogProofEvaluations {
-        l: (unsafe { &*l }).clone(),
-        r: (unsafe { &*r }).clone(),
-        o: (unsafe { &*o }).clone(),
-        z: (unsafe { &*z }).clone(),
-        t: (unsafe { &*t }).clone(),
-        f: (unsafe { &*f }).clone(),
-        sigma1: (unsafe { &*sigma1 }).clone(),
-        sigma2: (unsafe { &*sigma2 }).clone(),
-    };
-
-    return Box::into_raw(Box::new(res));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_make", std::ptr::null(), || {
+        let l = handle(l);
+        let r = handle(r);
+        let o = handle(o);
+        let z = handle(z);
+        let t = handle(t);
+        let f = handle(f);
+        let sigma1 = handle(sigma1);
+        let sigma2 = handle(sigma2);
+        let res: DlogProofEvaluations<Vec<Fp>> = DlogProofEvaluations {
+            l: (unsafe { &*l }).clone(),
+            r: (unsafe { &*r }).clone(),
+            o: (unsafe { &*o }).clone(),
+            z: (unsafe { &*z }).clone(),
+            t: (unsafe { &*t }).clone(),
+            f: (unsafe { &*f }).clone(),
+            sigma1: (unsafe { &*sigma1 }).clone(),
+            sigma2: (unsafe { &*sigma2 }).clone(),
+        };
+
+        return into_handle(Box::new(res));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_delete(
-    x: *mut DlogProofEvaluations<Vec<Fp>>,
-) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_proof_evaluations_delete, DlogProofEvaluations<Vec<Fp>>);
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_pair_0(
     e: *const [DlogProofEvaluations<Vec<Fp>>; 2],
 ) -> *const DlogProofEvaluations<Vec<Fp>> {
-    let x = (unsafe { &(*e)[0] }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_pair_0", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e)[0] }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_pair_1(
     e: *const [DlogProofEvaluations<Vec<Fp>>; 2],
 ) -> *const DlogProofEvaluations<Vec<Fp>> {
-    let x = (unsafe { &(*e)[1] }).clone();
-    return Box::into_raw(Box::new(x));
+    catch_ffi("zexe_tweedle_plonk_fp_proof_evaluations_pair_1", std::ptr::null(), || {
+        let e = handle(e);
+        let x = (unsafe { &(*e)[1] }).clone();
+        return into_handle(Box::new(x));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_proof_evaluations_pair_delete(
-    x: *mut [DlogProofEvaluations<Vec<Fp>>; 2],
-) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_proof_evaluations_pair_delete, [DlogProofEvaluations<Vec<Fp>>; 2]);
 
 // Fp oracles
 pub struct FpOracles {
@@ -907,110 +1133,147 @@ pub extern "C" fn zexe_tweedle_plonk_fp_oracles_create(
     index: *const DlogVerifierIndex<GAffine>,
     proof: *const DlogProof<GAffine>,
 ) -> *const FpOracles {
-    let index = unsafe { &(*index) };
-    let lgr_comm = unsafe { &(*lgr_comm) };
-    let proof = unsafe { &(*proof) };
-
-    let p_comm = PolyComm::<GAffine>::multi_scalar_mul(
-        &lgr_comm.iter().take(proof.public.len()).map(|l| l).collect(),
-        &proof.public.iter().map(|s| -*s).collect(),
-    );
-    let (mut sponge, digest_before_evaluations, o, _, p_eval, _, _) =
-        proof.oracles::<DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>, DefaultFrSponge<Fp, PlonkSpongeConstants>>(index, &p_comm);
-
-    return Box::into_raw(Box::new(FpOracles {
-        o,
-        p_eval,
-        opening_prechallenges: proof.proof.prechallenges(&mut sponge),
-        digest_before_evaluations,
-    }));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_create", std::ptr::null(), || {
+        let lgr_comm = handle(lgr_comm);
+        let index = handle(index);
+        let proof = handle(proof);
+        let index = unsafe { &(*index) };
+        let lgr_comm = unsafe { &(*lgr_comm) };
+        let proof = unsafe { &(*proof) };
+
+        let p_comm = PolyComm::<GAffine>::multi_scalar_mul(
+            &lgr_comm.iter().take(proof.public.len()).map(|l| l).collect(),
+            &proof.public.iter().map(|s| -*s).collect(),
+        );
+        let (mut sponge, digest_before_evaluations, o, _, p_eval, _, _) =
+            proof.oracles::<DefaultFqSponge<TweedledeeParameters, PlonkSpongeConstants>, DefaultFrSponge<Fp, PlonkSpongeConstants>>(index, &p_comm);
+
+        return into_handle(Box::new(FpOracles {
+            o,
+            p_eval,
+            opening_prechallenges: proof.proof.prechallenges(&mut sponge),
+            digest_before_evaluations,
+        }));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_opening_prechallenges(
     oracles: *const FpOracles,
 ) -> *const Vec<Fp> {
-    return Box::into_raw(Box::new(
-        (unsafe { &(*oracles) })
-            .opening_prechallenges
-            .iter()
-            .map(|x| x.0)
-            .collect(),
-    ));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_opening_prechallenges", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new(
+            (unsafe { &(*oracles) })
+                .opening_prechallenges
+                .iter()
+                .map(|x| x.0)
+                .collect(),
+        ));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_digest_before_evaluations(
     oracles: *const FpOracles,
 ) -> *const Fp {
-    return Box::into_raw(Box::new(
-        (unsafe { &(*oracles) }).digest_before_evaluations.clone(),
-    ));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_digest_before_evaluations", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new(
+            (unsafe { &(*oracles) }).digest_before_evaluations.clone(),
+        ));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_p_eval1(
     oracles: *const FpOracles,
 ) -> *const Fp {
-    let v = &(unsafe { &(*oracles) }).p_eval[0];
-    assert_eq!(v.len(), 1);
-    return Box::into_raw(Box::new(v[0]));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_p_eval1", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        let v = &(unsafe { &(*oracles) }).p_eval[0];
+        assert_eq!(v.len(), 1);
+        return into_handle(Box::new(v[0]));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_p_eval2(
     oracles: *const FpOracles,
 ) -> *const Fp {
-    let v = &(unsafe { &(*oracles) }).p_eval[1];
-    assert_eq!(v.len(), 1);
-    return Box::into_raw(Box::new(v[0]));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_p_eval2", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        let v = &(unsafe { &(*oracles) }).p_eval[1];
+        assert_eq!(v.len(), 1);
+        return into_handle(Box::new(v[0]));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_alpha(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.alpha_chal.0.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_alpha", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.alpha_chal.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_beta(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.beta.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_beta", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.beta.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_gamma(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.gamma.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_gamma", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.gamma.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_zeta(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.zeta_chal.0.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_zeta", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.zeta_chal.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_v(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.v_chal.0.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_v", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.v_chal.0.clone()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_oracles_u(oracles: *const FpOracles) -> *const Fp {
-    return Box::into_raw(Box::new((unsafe { &(*oracles) }).o.u_chal.0.clone()));
+    catch_ffi("zexe_tweedle_plonk_fp_oracles_u", std::ptr::null(), || {
+        let oracles = handle(oracles);
+        return into_handle(Box::new((unsafe { &(*oracles) }).o.u_chal.0.clone()));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_oracles_delete(x: *mut FpOracles) {
-    let _box = unsafe { Box::from_raw(x) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_oracles_delete, FpOracles);
 
 // Fp circuit gate vector
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_create() -> *mut Vec<Gate<Fp>> {
-    return Box::into_raw(Box::new(Vec::new()));
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_create", std::ptr::null_mut(), || {
+        return into_handle(Box::new(Vec::new()));
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_length(v: *const Vec<Gate<Fp>>) -> i32 {
-    let v_ = unsafe { &(*v) };
-    return v_.len() as i32;
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_length", 0, || {
+        let v = handle(v);
+        let v_ = unsafe { &(*v) };
+        return v_.len() as i32;
+    })
 }
 
 #[no_mangle]
@@ -1026,26 +1289,30 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    let v_ = unsafe { &mut (*v) };
-    let c_ = unsafe { &(*c) };
-    v_.push(Gate {
-        typ,
-        wires: Wires {
-            row,
-            l: Wire {
-                row: lrow,
-                col: lcol,
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        let v_ = unsafe { &mut (*v) };
+        let c_ = unsafe { &(*c) };
+        v_.push(Gate {
+            typ,
+            wires: Wires {
+                row,
+                l: Wire {
+                    row: lrow,
+                    col: lcol,
+                },
+                r: Wire {
+                    row: rrow,
+                    col: rcol,
+                },
+                o: Wire {
+                    row: orow,
+                    col: ocol,
+                },
             },
-            r: Wire {
-                row: rrow,
-                col: rcol,
-            },
-            o: Wire {
-                row: orow,
-                col: ocol,
-            },
-        },
-        c: c_.clone(),
+            c: c_.clone(),
+        })
     })
 }
 
@@ -1061,7 +1328,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_zero(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Zero, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_zero", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Zero, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1076,7 +1347,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_generic(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Generic, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_generic", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Generic, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1091,7 +1366,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_poseidon(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Poseidon, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_poseidon", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Poseidon, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1106,7 +1385,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_add1(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Add1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_add1", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Add1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1121,7 +1404,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_add2(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Add2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_add2", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Add2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1136,7 +1423,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_vbmul1(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_vbmul1", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1151,7 +1442,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_vbmul2(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_vbmul2", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1166,7 +1461,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_vbmul3(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul3, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_vbmul3", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Vbmul3, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1181,7 +1480,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_endomul1(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_endomul1", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul1, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1196,7 +1499,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_endomul2(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_endomul2", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul2, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1211,7 +1518,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_endomul3(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul3, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_endomul3", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul3, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1226,7 +1537,11 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_add_endomul4(
     ocol: Col,
     c: *const Vec<Fp>,
 ) {
-    zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul4, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_add_endomul4", (), || {
+        let v = handle(v);
+        let c = handle(c);
+        zexe_tweedle_plonk_fp_gate_vector_add(v, Endomul4, row, lrow, lcol, rrow, rcol, orow, ocol, c);
+    })
 }
 
 #[no_mangle]
@@ -1237,21 +1552,19 @@ pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_wrap(
     hrow: usize,
     hcol: Col,
 ) {
-    let v = unsafe { &mut (*v) };
-    match tcol
-    {
-        Col::L => (v[trow]).wires.l = Wire {row: hrow, col: hcol},
-        Col::R => (v[trow]).wires.r = Wire {row: hrow, col: hcol},
-        Col::O => (v[trow]).wires.o = Wire {row: hrow, col: hcol},
-    }
+    catch_ffi("zexe_tweedle_plonk_fp_gate_vector_wrap", (), || {
+        let v = handle(v);
+        let v = unsafe { &mut (*v) };
+        match tcol
+        {
+            Col::L => (v[trow]).wires.l = Wire {row: hrow, col: hcol},
+            Col::R => (v[trow]).wires.r = Wire {row: hrow, col: hcol},
+            Col::O => (v[trow]).wires.o = Wire {row: hrow, col: hcol},
+        }
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_gate_vector_delete(v: *mut Vec<Gate<Fp>>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_gate_vector_delete, Vec<Gate<Fp>>);
 
 // Fp constraint system
 #[no_mangle]
@@ -1259,15 +1572,13 @@ pub extern "C" fn zexe_tweedle_plonk_fp_constraint_system_create(
     v: *mut Vec<CircuitGate<Fp>>,
     public: usize,
 ) -> *mut ConstraintSystem<Fp> {
-    let v_ = unsafe { &mut (*v) };
-    return Box::into_raw(Box::new(
-        ConstraintSystem::<Fp>::create(v_.clone(), oracle::tweedle::fp::params(), public).unwrap(),
-    ));
+    catch_ffi("zexe_tweedle_plonk_fp_constraint_system_create", std::ptr::null_mut(), || {
+        let v = handle(v);
+        let v_ = unsafe { &mut (*v) };
+        return into_handle(Box::new(
+            ConstraintSystem::<Fp>::create(v_.clone(), oracle::tweedle::fp::params(), public).unwrap(),
+        ));
+    })
 }
 
-#[no_mangle]
-pub extern "C" fn zexe_tweedle_plonk_fp_constraint_system_delete(v: *mut ConstraintSystem<Fp>) {
-    // Deallocation happens automatically when a box variable goes out of
-    // scope.
-    let _box = unsafe { Box::from_raw(v) };
-}
+crate::delete_stub!(zexe_tweedle_plonk_fp_constraint_system_delete, ConstraintSystem<Fp>);